@@ -0,0 +1,145 @@
+//! Per-rule and whole-engine benchmarks over a representative corpus
+//!
+//! The corpus is this repository's own book (`docs/src`) plus the
+//! comprehensive test fixtures. Run with `cargo bench --bench rules`, or
+//! `cargo bench --bench rules -- rules/MD013` for a single rule.
+
+use comrak::Arena;
+use criterion::{Criterion, criterion_group, criterion_main};
+use mdbook_lint_core::{Config, Document, LintEngine, PluginRegistry};
+use mdbook_lint_rulesets::{
+    AdrRuleProvider, ChangelogRuleProvider, ContentRuleProvider, FrontMatterRuleProvider,
+    MdBookRuleProvider, ReadmeRuleProvider, RfcRuleProvider, SpellingRuleProvider,
+    StandardRuleProvider,
+};
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use walkdir::WalkDir;
+
+fn corpus() -> Vec<Document> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let dirs = [
+        root.join("../../docs/src"),
+        root.join("tests/fixtures/comprehensive"),
+    ];
+
+    let mut paths: Vec<PathBuf> = dirs
+        .iter()
+        .flat_map(|dir| WalkDir::new(dir).into_iter().filter_map(|e| e.ok()))
+        .map(|entry| entry.into_path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "md"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let content = std::fs::read_to_string(&path).ok()?;
+            Document::new(content, path).ok()
+        })
+        .collect()
+}
+
+fn engine() -> LintEngine {
+    let mut registry = PluginRegistry::new();
+    registry
+        .register_provider(Box::new(StandardRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(MdBookRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(ContentRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(AdrRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(FrontMatterRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(RfcRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(ChangelogRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(ReadmeRuleProvider))
+        .unwrap();
+    registry
+        .register_provider(Box::new(SpellingRuleProvider))
+        .unwrap();
+    registry.create_engine().unwrap()
+}
+
+fn bench_engine(c: &mut Criterion) {
+    let documents = corpus();
+    let engine = engine();
+    let config = Config::default();
+
+    let mut group = c.benchmark_group("engine");
+    group.sample_size(10);
+
+    group.bench_function("parse", |b| {
+        b.iter(|| {
+            for document in &documents {
+                let arena = Arena::new();
+                black_box(document.parse_ast(&arena));
+            }
+        })
+    });
+
+    group.bench_function("lint_sequential", |b| {
+        b.iter(|| {
+            for document in &documents {
+                black_box(engine.lint_document_with_config(document, &config).unwrap());
+            }
+        })
+    });
+
+    group.bench_function("lint_parallel", |b| {
+        b.iter(|| {
+            black_box(
+                engine
+                    .lint_documents_with_config(&documents, &config)
+                    .unwrap(),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+fn bench_rules(c: &mut Criterion) {
+    let documents = corpus();
+    let engine = engine();
+
+    // Parse once up front so only rule time is measured
+    let arenas: Vec<Arena<_>> = documents.iter().map(|_| Arena::new()).collect();
+    let asts: Vec<_> = documents
+        .iter()
+        .zip(&arenas)
+        .map(|(document, arena)| document.parse_ast(arena))
+        .collect();
+
+    let mut group = c.benchmark_group("rules");
+    group.sample_size(10);
+    group.warm_up_time(Duration::from_millis(200));
+    group.measurement_time(Duration::from_secs(1));
+
+    for rule in engine.registry().rules() {
+        group.bench_function(rule.id(), |b| {
+            b.iter(|| {
+                for (document, ast) in documents.iter().zip(&asts) {
+                    black_box(rule.check_with_ast(document, Some(ast)).unwrap());
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_engine, bench_rules);
+criterion_main!(benches);
@@ -0,0 +1,193 @@
+//! Newline-delimited JSON batch protocol for `lint --stdin-batch`
+//!
+//! Editors and tools that hold many documents in memory can lint them all
+//! with one process. Each line of input is a request with a document's path
+//! and contents:
+//!
+//! ```text
+//! {"id": 1, "path": "src/intro.md", "content": "# Intro\n"}
+//! ```
+//!
+//! Each request gets one line of output, in order, with its violations or an
+//! error. The optional `id` is echoed back so callers can match them up:
+//!
+//! ```text
+//! {"id": 1, "path": "src/intro.md", "violations": []}
+//! ```
+
+use mdbook_lint_core::{Severity, Violation};
+use serde::{Deserialize, Serialize};
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+/// One document to lint
+#[derive(Debug, Deserialize)]
+pub struct Request {
+    /// Opaque value echoed back in the response
+    #[serde(default)]
+    pub id: Option<serde_json::Value>,
+    /// Path the document is linted as; it need not exist on disk
+    pub path: PathBuf,
+    pub content: String,
+}
+
+#[derive(Serialize)]
+struct Response<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<&'a serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<&'a Path>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    violations: Option<&'a [Violation]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+/// Totals over every document in a batch
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub documents: usize,
+    pub violations: usize,
+    pub errors: usize,
+    /// Severity of the most severe violation, if there are any
+    pub worst: Option<Severity>,
+}
+
+/// Answer each request read from `input` with a line on `output`
+///
+/// `lint` returns a document's violations, or a message that is sent back as
+/// the response's `error`. Malformed requests are answered with an error too,
+/// so one bad line doesn't end the batch.
+pub fn run(
+    input: impl BufRead,
+    mut output: impl Write,
+    mut lint: impl FnMut(&Request) -> Result<Vec<Violation>, String>,
+) -> io::Result<Summary> {
+    let mut summary = Summary::default();
+
+    for (index, line) in input.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                let result = lint(&request);
+                let json = match &result {
+                    Ok(violations) => {
+                        summary.documents += 1;
+                        summary.violations += violations.len();
+                        summary.errors += violations
+                            .iter()
+                            .filter(|v| v.severity == Severity::Error)
+                            .count();
+                        summary.worst = violations
+                            .iter()
+                            .map(|v| v.severity)
+                            .chain(summary.worst)
+                            .max();
+                        serde_json::to_string(&Response {
+                            id: request.id.as_ref(),
+                            path: Some(&request.path),
+                            violations: Some(violations),
+                            error: None,
+                        })
+                    }
+                    Err(message) => serde_json::to_string(&Response {
+                        id: request.id.as_ref(),
+                        path: Some(&request.path),
+                        violations: None,
+                        error: Some(message.clone()),
+                    }),
+                };
+                json.map_err(io::Error::other)?
+            }
+            Err(e) => serde_json::to_string(&Response {
+                id: None,
+                path: None,
+                violations: None,
+                error: Some(format!("invalid request on line {}: {e}", index + 1)),
+            })
+            .map_err(io::Error::other)?,
+        };
+
+        writeln!(output, "{response}")?;
+        // Callers may wait for each answer before sending the next request
+        output.flush()?;
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lint(request: &Request) -> Result<Vec<Violation>, String> {
+        if request.content.is_empty() {
+            return Err("empty document".to_string());
+        }
+        Ok(request
+            .content
+            .lines()
+            .filter(|line| line.ends_with(' '))
+            .map(|_| Violation {
+                rule_id: "MD009".to_string(),
+                rule_name: "no-trailing-spaces".to_string(),
+                message: "Trailing spaces".to_string(),
+                line: 1,
+                column: 1,
+                severity: Severity::Error,
+                fix: None,
+                url: None,
+                path: None,
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_batch_responses() {
+        let input = concat!(
+            r##"{"id": 1, "path": "src/a.md", "content": "# A \n"}"##,
+            "\n\n",
+            r##"{"path": "src/b.md", "content": "# B\n"}"##,
+            "\n",
+            "not json\n",
+            r#"{"id": "c", "path": "src/c.md", "content": ""}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+        let summary = run(input.as_bytes(), &mut output, lint).unwrap();
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(lines[0]["id"], 1);
+        assert_eq!(lines[0]["violations"][0]["rule_id"], "MD009");
+        assert_eq!(lines[1]["path"], "src/b.md");
+        assert_eq!(lines[1]["violations"], serde_json::json!([]));
+        assert!(lines[1].get("id").is_none());
+        assert!(
+            lines[2]["error"]
+                .as_str()
+                .unwrap()
+                .starts_with("invalid request on line 4")
+        );
+        assert_eq!(lines[3]["id"], "c");
+        assert_eq!(lines[3]["error"], "empty document");
+
+        assert_eq!(
+            summary,
+            Summary {
+                documents: 2,
+                violations: 1,
+                errors: 1,
+                worst: Some(Severity::Error),
+            }
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Type-check Rust code blocks for `mdbook-lint check-code`
+//!
+//! Each ` ```rust ` block is turned into a small program the way rustdoc does
+//! it: hidden `# ` lines are included, and the code is wrapped in `fn main`
+//! unless it defines one. The program is type-checked with
+//! `rustc --emit=metadata` in a temporary directory, so nothing is linked or
+//! run, and rustc's errors are reported at the matching Markdown lines.
+
+use comrak::nodes::NodeValue;
+use mdbook_lint_core::{Document, Severity, Violation};
+use serde::Deserialize;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+/// Rule ID reported for compile errors
+pub const RULE_ID: &str = "RUSTC";
+
+/// Block attributes that mean the code is not expected to compile
+const SKIP_ATTRIBUTES: &[&str] = &["ignore", "compile_fail", "text"];
+
+/// rustdoc attributes that mark an untagged block as Rust
+const RUST_ATTRIBUTES: &[&str] = &[
+    "ignore",
+    "should_panic",
+    "no_run",
+    "compile_fail",
+    "edition2015",
+    "edition2018",
+    "edition2021",
+    "edition2024",
+    "editable",
+    "noplayground",
+];
+
+/// A Rust code block, ready to compile
+#[derive(Debug, Clone)]
+pub struct RustBlock {
+    /// Markdown line of the opening fence (1-based)
+    pub line: usize,
+    /// Edition from an `editionXXXX` attribute
+    pub edition: Option<String>,
+    /// The program passed to rustc
+    pub source: String,
+    /// Markdown line and column offset for each line of `source`, or `None`
+    /// for wrapper lines
+    line_map: Vec<Option<(usize, usize)>>,
+}
+
+impl RustBlock {
+    /// Markdown position of a 1-based line and column of the generated
+    /// program, unless the line was added by the wrapper
+    fn markdown_position(&self, source_line: usize, column: usize) -> Option<(usize, usize)> {
+        let (line, offset) = self.line_map.get(source_line.checked_sub(1)?).copied()??;
+        Some((line, column + offset))
+    }
+}
+
+/// Extract the Rust code blocks that should compile
+///
+/// Blocks tagged `ignore`, `compile_fail`, or `text` are skipped, since
+/// `mdbook test` does not expect them to build.
+pub fn extract_rust_blocks(content: &str) -> Vec<RustBlock> {
+    let Ok(document) = Document::new(content.to_string(), "check-code.md".into()) else {
+        return Vec::new();
+    };
+    let arena = comrak::Arena::new();
+    let ast = document.parse_ast(&arena);
+
+    let mut blocks = Vec::new();
+    for node in ast.descendants() {
+        let data = node.data.borrow();
+        let NodeValue::CodeBlock(code) = &data.value else {
+            continue;
+        };
+        if !code.fenced {
+            continue;
+        }
+
+        let attributes: Vec<&str> = code
+            .info
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|a| !a.is_empty())
+            .collect();
+        let is_rust = match attributes.first() {
+            Some(&"rust") => true,
+            Some(first) => RUST_ATTRIBUTES.contains(first),
+            None => false,
+        };
+        if !is_rust || attributes.iter().any(|a| SKIP_ATTRIBUTES.contains(a)) {
+            continue;
+        }
+
+        let edition = attributes
+            .iter()
+            .find_map(|a| a.strip_prefix("edition"))
+            .map(str::to_string);
+        blocks.push(build_program(
+            &code.literal,
+            data.sourcepos.start.line,
+            data.sourcepos.start.column - 1,
+            edition,
+        ));
+    }
+    blocks
+}
+
+/// Turn a code block into a program, keeping track of where each line came
+/// from
+fn build_program(
+    literal: &str,
+    fence_line: usize,
+    indent: usize,
+    edition: Option<String>,
+) -> RustBlock {
+    // The code starts on the line after the opening fence
+    let lines: Vec<((usize, usize), String)> = literal
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let (text, hidden) = unhide(line);
+            ((fence_line + 1 + i, indent + hidden), text)
+        })
+        .collect();
+
+    let mut source = Vec::new();
+    let mut line_map = Vec::new();
+    let mut push = |text: &str, position: Option<(usize, usize)>| {
+        source.push(text.to_string());
+        line_map.push(position);
+    };
+
+    push("#![allow(unused)]", None);
+    // Crate attributes have to stay at the top of the crate
+    let (crate_attrs, body): (Vec<_>, Vec<_>) = lines
+        .iter()
+        .partition(|(_, line)| line.trim_start().starts_with("#!["));
+    for (position, line) in &crate_attrs {
+        push(line, Some(*position));
+    }
+
+    let has_main = body.iter().any(|(_, line)| line.contains("fn main"));
+    if !has_main {
+        push("fn main() {", None);
+    }
+    for (position, line) in &body {
+        push(line, Some(*position));
+    }
+    if !has_main {
+        push("}", None);
+    }
+
+    RustBlock {
+        line: fence_line,
+        edition,
+        source: source.join("\n") + "\n",
+        line_map,
+    }
+}
+
+/// Reveal an mdBook hidden line: `# code` becomes `code` and `## x` becomes
+/// `# x`. Also returns how many characters were removed before the code.
+fn unhide(line: &str) -> (String, usize) {
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    if trimmed == "#" {
+        (String::new(), 0)
+    } else if let Some(rest) = trimmed.strip_prefix("##") {
+        (format!("{indent}#{rest}"), 1)
+    } else if let Some(rest) = trimmed.strip_prefix("# ") {
+        (format!("{indent}{rest}"), 2)
+    } else {
+        (line.to_string(), 0)
+    }
+}
+
+/// Type-check a block with rustc and return its errors as violations
+pub fn check_block(
+    block: &RustBlock,
+    rustc: &str,
+    default_edition: &str,
+) -> io::Result<Vec<Violation>> {
+    let dir = tempfile::tempdir()?;
+    let file = dir.path().join("block.rs");
+    std::fs::write(&file, &block.source)?;
+
+    let edition = block.edition.as_deref().unwrap_or(default_edition);
+    let output = Command::new(rustc)
+        .current_dir(dir.path())
+        .args(["--edition", edition])
+        .args(["--crate-type", "bin", "--crate-name", "block"])
+        .args([
+            "--emit=metadata",
+            "--error-format=json",
+            "--cap-lints=allow",
+        ])
+        .arg("--out-dir")
+        .arg(dir.path())
+        .arg(&file)
+        .output()
+        .map_err(|e| io::Error::new(e.kind(), format!("failed to run {rustc}: {e}")))?;
+
+    Ok(parse_diagnostics(
+        &String::from_utf8_lossy(&output.stderr),
+        block,
+        &file,
+    ))
+}
+
+/// A diagnostic from `rustc --error-format=json`
+#[derive(Deserialize)]
+struct Diagnostic {
+    message: String,
+    level: String,
+    code: Option<DiagnosticCode>,
+    spans: Vec<DiagnosticSpan>,
+}
+
+#[derive(Deserialize)]
+struct DiagnosticCode {
+    code: String,
+}
+
+#[derive(Deserialize)]
+struct DiagnosticSpan {
+    file_name: String,
+    is_primary: bool,
+    line_start: usize,
+    column_start: usize,
+}
+
+/// Convert rustc's JSON errors into violations at Markdown positions
+fn parse_diagnostics(stderr: &str, block: &RustBlock, file: &Path) -> Vec<Violation> {
+    let file_name = file.file_name().map(|n| n.to_string_lossy().to_string());
+
+    stderr
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Diagnostic>(line).ok())
+        .filter(|d| d.level == "error")
+        // "aborting due to N previous errors" has no span and adds nothing
+        .filter(|d| !d.spans.is_empty() || !d.message.starts_with("aborting due to"))
+        .map(|d| {
+            let primary = d.spans.iter().find(|s| {
+                s.is_primary
+                    && file_name
+                        .as_deref()
+                        .is_some_and(|n| s.file_name.ends_with(n))
+            });
+            let (line, column) = primary
+                .and_then(|span| block.markdown_position(span.line_start, span.column_start))
+                .unwrap_or((block.line, 1));
+            let message = match d.code {
+                Some(code) => format!("{} ({})", d.message, code.code),
+                None => d.message,
+            };
+            Violation {
+                rule_id: RULE_ID.to_string(),
+                rule_name: "rust-code-compiles".to_string(),
+                message,
+                line,
+                column,
+                severity: Severity::Error,
+                fix: None,
+                url: None,
+                path: None,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_rust_blocks() {
+        let content = "# Chapter\n\n```rust\nlet x = 1;\n```\n\n\
+                       ```rust,ignore\nnot rust\n```\n\n\
+                       ```toml\n[book]\n```\n\n\
+                       ```no_run,edition2018\nfn main() {}\n```\n";
+        let blocks = extract_rust_blocks(content);
+
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].line, 3);
+        assert_eq!(
+            blocks[0].source,
+            "#![allow(unused)]\nfn main() {\nlet x = 1;\n}\n"
+        );
+        assert_eq!(blocks[0].markdown_position(3, 5), Some((4, 5)));
+        assert_eq!(blocks[0].markdown_position(2, 1), None);
+        assert_eq!(blocks[1].edition.as_deref(), Some("2018"));
+        assert_eq!(blocks[1].source, "#![allow(unused)]\nfn main() {}\n");
+    }
+
+    #[test]
+    fn test_hidden_lines_and_crate_attributes() {
+        let content =
+            "```rust\n#![deny(warnings)]\n# use std::fmt;\n##[derive(Debug)]\nstruct S;\n#\n```\n";
+        let block = &extract_rust_blocks(content)[0];
+
+        assert_eq!(
+            block.source,
+            "#![allow(unused)]\n#![deny(warnings)]\nfn main() {\nuse std::fmt;\n#[derive(Debug)]\nstruct S;\n\n}\n"
+        );
+        // The crate attribute moved above the wrapper but keeps its line,
+        // and columns on hidden lines account for the `# ` prefix
+        assert_eq!(block.markdown_position(2, 1), Some((2, 1)));
+        assert_eq!(block.markdown_position(4, 5), Some((3, 7)));
+    }
+
+    #[test]
+    fn test_indented_fence_columns() {
+        let block = &extract_rust_blocks("- Item\n\n  ```rust\n  let x = 1;\n  ```\n")[0];
+        assert_eq!(block.markdown_position(3, 5), Some((4, 7)));
+    }
+
+    #[test]
+    fn test_parse_diagnostics() {
+        let block = &extract_rust_blocks("Text\n\n```rust\nlet x: u32 = \"no\";\n```\n")[0];
+        let stderr = concat!(
+            r#"{"message":"mismatched types","code":{"code":"E0308"},"level":"error","spans":[{"file_name":"/tmp/x/block.rs","is_primary":true,"line_start":3,"column_start":14}]}"#,
+            "\n",
+            r#"{"message":"aborting due to 1 previous error","code":null,"level":"error","spans":[]}"#,
+            "\n",
+        );
+        let violations = parse_diagnostics(stderr, block, Path::new("/tmp/x/block.rs"));
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "mismatched types (E0308)");
+        assert_eq!((violations[0].line, violations[0].column), (4, 14));
+    }
+}
@@ -0,0 +1,387 @@
+//! Side-by-side comparison with markdownlint for `mdbook-lint compare`
+//!
+//! Both tools lint the same files. Their findings are matched by file, line,
+//! and rule ID, and [`Comparison`] reports per rule how many findings both
+//! tools made and how many only one of them did. Only `MD###` rules take
+//! part, since those are the ones the two tools share.
+
+use mdbook_lint_core::{MdBookLintError, Result, Violation};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tabled::{Table, Tabled, settings::Style};
+
+/// Files passed to one markdownlint invocation, keeping command lines short
+const FILES_PER_RUN: usize = 200;
+
+/// The tool on each side of a comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Tool {
+    MdbookLint,
+    Markdownlint,
+}
+
+impl std::fmt::Display for Tool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Tool::MdbookLint => write!(f, "mdbook-lint"),
+            Tool::Markdownlint => write!(f, "markdownlint"),
+        }
+    }
+}
+
+/// One finding, reduced to what both tools report
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Finding {
+    pub file: String,
+    pub line: usize,
+    pub rule_id: String,
+}
+
+impl Finding {
+    /// The finding for a violation, if its rule is a standard `MD###` rule
+    pub fn from_violation(file: &str, violation: &Violation) -> Option<Self> {
+        is_standard_rule(&violation.rule_id).then(|| Self {
+            file: normalize(file),
+            line: violation.line,
+            rule_id: violation.rule_id.clone(),
+        })
+    }
+}
+
+/// A finding only one tool made
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Difference {
+    #[serde(flatten)]
+    pub finding: Finding,
+    pub only_in: Tool,
+}
+
+/// How the two tools agree on one rule
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RuleComparison {
+    pub rule_id: String,
+    pub both: usize,
+    pub only_mdbook_lint: usize,
+    pub only_markdownlint: usize,
+}
+
+impl RuleComparison {
+    /// Share of the rule's findings that both tools made, as a percentage
+    pub fn agreement(&self) -> f64 {
+        let total = self.both + self.only_mdbook_lint + self.only_markdownlint;
+        if total == 0 {
+            100.0
+        } else {
+            self.both as f64 * 100.0 / total as f64
+        }
+    }
+
+    /// Whether the tools made exactly the same findings
+    pub fn matches(&self) -> bool {
+        self.only_mdbook_lint == 0 && self.only_markdownlint == 0
+    }
+}
+
+/// Compatibility report for a set of files
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Comparison {
+    pub files: usize,
+    /// Rules whose findings match exactly
+    pub matching_rules: usize,
+    /// Share of all findings both tools made, as a percentage
+    pub agreement: f64,
+    /// Sorted by rule ID
+    pub rules: Vec<RuleComparison>,
+    /// Sorted by file, line, and rule
+    pub differences: Vec<Difference>,
+}
+
+impl Comparison {
+    /// Match the findings of both tools
+    ///
+    /// A line can hold several findings for the same rule, so findings are
+    /// matched as counts rather than sets.
+    pub fn new(files: usize, ours: Vec<Finding>, theirs: Vec<Finding>) -> Self {
+        let mut counts: BTreeMap<Finding, (usize, usize)> = BTreeMap::new();
+        for finding in ours {
+            counts.entry(finding).or_default().0 += 1;
+        }
+        for finding in theirs {
+            counts.entry(finding).or_default().1 += 1;
+        }
+
+        let mut rules: BTreeMap<String, RuleComparison> = BTreeMap::new();
+        let mut differences = Vec::new();
+        for (finding, (ours, theirs)) in counts {
+            let rule = rules
+                .entry(finding.rule_id.clone())
+                .or_insert_with(|| RuleComparison {
+                    rule_id: finding.rule_id.clone(),
+                    ..RuleComparison::default()
+                });
+            rule.both += ours.min(theirs);
+            let (extra, only_in) = if ours > theirs {
+                rule.only_mdbook_lint += ours - theirs;
+                (ours - theirs, Tool::MdbookLint)
+            } else {
+                rule.only_markdownlint += theirs - ours;
+                (theirs - ours, Tool::Markdownlint)
+            };
+            for _ in 0..extra {
+                differences.push(Difference {
+                    finding: finding.clone(),
+                    only_in,
+                });
+            }
+        }
+
+        let rules: Vec<RuleComparison> = rules.into_values().collect();
+        let both: usize = rules.iter().map(|rule| rule.both).sum();
+        let total = both + differences.len();
+        Self {
+            files,
+            matching_rules: rules.iter().filter(|rule| rule.matches()).count(),
+            agreement: if total == 0 {
+                100.0
+            } else {
+                both as f64 * 100.0 / total as f64
+            },
+            rules,
+            differences,
+        }
+    }
+
+    /// Render as a plain-text table, listing each difference if asked
+    pub fn to_text(&self, show_differences: bool) -> String {
+        let mut out = format!(
+            "Compared {} files: {} of {} rules match, {:.1}% of findings agree\n",
+            self.files,
+            self.matching_rules,
+            self.rules.len(),
+            self.agreement,
+        );
+        if !self.rules.is_empty() {
+            out.push_str(&format!("\n{}\n", self.rule_table().with(Style::rounded())));
+        }
+        if show_differences && !self.differences.is_empty() {
+            out.push_str("\nDifferences:\n");
+            for difference in &self.differences {
+                let finding = &difference.finding;
+                out.push_str(&format!(
+                    "  {}:{} {} only in {}\n",
+                    finding.file, finding.line, finding.rule_id, difference.only_in
+                ));
+            }
+        }
+        out
+    }
+
+    /// Render as a Markdown report
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "## mdbook-lint vs markdownlint\n\n\
+             - **Files**: {}\n\
+             - **Matching rules**: {} of {}\n\
+             - **Agreement**: {:.1}%\n",
+            self.files,
+            self.matching_rules,
+            self.rules.len(),
+            self.agreement,
+        );
+        if !self.rules.is_empty() {
+            out.push_str(&format!(
+                "\n{}\n",
+                self.rule_table().with(Style::markdown())
+            ));
+        }
+        out
+    }
+
+    fn rule_table(&self) -> Table {
+        let rows = self.rules.iter().map(|rule| RuleRow {
+            rule: rule.rule_id.clone(),
+            both: rule.both,
+            only_mdbook_lint: rule.only_mdbook_lint,
+            only_markdownlint: rule.only_markdownlint,
+            agreement: format!("{:.1}%", rule.agreement()),
+        });
+        Table::new(rows)
+    }
+}
+
+#[derive(Tabled)]
+struct RuleRow {
+    #[tabled(rename = "Rule")]
+    rule: String,
+    #[tabled(rename = "Both")]
+    both: usize,
+    #[tabled(rename = "Only mdbook-lint")]
+    only_mdbook_lint: usize,
+    #[tabled(rename = "Only markdownlint")]
+    only_markdownlint: usize,
+    #[tabled(rename = "Agreement")]
+    agreement: String,
+}
+
+/// One result in markdownlint's `--json` output
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MarkdownlintResult {
+    file_name: String,
+    line_number: usize,
+    rule_names: Vec<String>,
+}
+
+/// Run markdownlint-cli on `files` and collect its findings
+///
+/// markdownlint exits non-zero when it finds anything, so its exit status
+/// only matters when the output cannot be read.
+pub fn run_markdownlint(program: &str, files: &[PathBuf]) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    for chunk in files.chunks(FILES_PER_RUN) {
+        let output = Command::new(program)
+            .arg("--json")
+            .args(chunk)
+            .output()
+            .map_err(|e| {
+                let hint = if e.kind() == io::ErrorKind::NotFound {
+                    "; install it with `npm install -g markdownlint-cli` or pass --markdownlint"
+                } else {
+                    ""
+                };
+                MdBookLintError::Io(io::Error::new(
+                    e.kind(),
+                    format!("failed to run {program}: {e}{hint}"),
+                ))
+            })?;
+
+        // markdownlint-cli writes its JSON report to stderr
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let report = [stderr.trim(), stdout.trim()]
+            .into_iter()
+            .find(|text| text.starts_with('['));
+        match report {
+            Some(report) => findings.extend(parse_markdownlint(report)?),
+            None if output.status.success() => {}
+            None => {
+                return Err(MdBookLintError::config_error(format!(
+                    "{program} failed: {}",
+                    stderr.trim()
+                )));
+            }
+        }
+    }
+    Ok(findings)
+}
+
+/// Parse markdownlint-cli's `--json` report
+pub fn parse_markdownlint(report: &str) -> Result<Vec<Finding>> {
+    let results: Vec<MarkdownlintResult> = serde_json::from_str(report).map_err(|e| {
+        MdBookLintError::config_error(format!("Failed to parse markdownlint output: {e}"))
+    })?;
+    Ok(results
+        .into_iter()
+        .filter_map(|result| {
+            let rule_id = result
+                .rule_names
+                .into_iter()
+                .find(|name| is_standard_rule(name))?;
+            Some(Finding {
+                file: normalize(&result.file_name),
+                line: result.line_number,
+                rule_id,
+            })
+        })
+        .collect())
+}
+
+fn is_standard_rule(rule_id: &str) -> bool {
+    rule_id
+        .strip_prefix("MD")
+        .is_some_and(|digits| digits.len() == 3 && digits.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Path with `/` separators and no leading `./`, so both tools' paths match
+fn normalize(file: &str) -> String {
+    let file = file.replace('\\', "/");
+    let mut path = Path::new(&file);
+    while let Ok(rest) = path.strip_prefix(".") {
+        path = rest;
+    }
+    path.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(file: &str, line: usize, rule_id: &str) -> Finding {
+        Finding {
+            file: file.to_string(),
+            line,
+            rule_id: rule_id.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_parse_markdownlint_report() {
+        let report = r#"[
+            {"fileName": "./docs/a.md", "lineNumber": 3, "ruleNames": ["MD009", "no-trailing-spaces"],
+             "ruleDescription": "Trailing spaces", "errorDetail": null, "errorContext": null, "errorRange": null},
+            {"fileName": "docs/a.md", "lineNumber": 1, "ruleNames": ["custom-rule"]}
+        ]"#;
+        assert_eq!(
+            parse_markdownlint(report).unwrap(),
+            vec![finding("docs/a.md", 3, "MD009")]
+        );
+        assert!(parse_markdownlint("not json").is_err());
+    }
+
+    #[test]
+    fn test_comparison_counts_each_side() {
+        let ours = vec![
+            finding("a.md", 1, "MD013"),
+            finding("a.md", 1, "MD013"),
+            finding("a.md", 4, "MD022"),
+        ];
+        let theirs = vec![
+            finding("a.md", 1, "MD013"),
+            finding("a.md", 4, "MD022"),
+            finding("a.md", 9, "MD047"),
+        ];
+        let comparison = Comparison::new(1, ours, theirs);
+
+        let md013 = &comparison.rules[0];
+        assert_eq!((md013.both, md013.only_mdbook_lint), (1, 1));
+        assert!(comparison.rules[1].matches());
+        assert_eq!(comparison.rules[2].only_markdownlint, 1);
+        assert_eq!(comparison.matching_rules, 1);
+        assert_eq!(comparison.agreement, 50.0);
+        assert_eq!(
+            comparison.differences,
+            vec![
+                Difference {
+                    finding: finding("a.md", 1, "MD013"),
+                    only_in: Tool::MdbookLint,
+                },
+                Difference {
+                    finding: finding("a.md", 9, "MD047"),
+                    only_in: Tool::Markdownlint,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_standard_rules_only() {
+        assert!(is_standard_rule("MD001"));
+        assert!(!is_standard_rule("MDBOOK001"));
+        assert!(!is_standard_rule("MD01"));
+        assert_eq!(normalize("./docs\\a.md"), "docs/a.md");
+    }
+}
@@ -1,13 +1,96 @@
-use mdbook_lint_core::{MdBookLintError, Result};
+use mdbook_lint_core::{
+    DedupePolicy, MarkdownExtensions, MdBookLintError, Result, Severity, Violation,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::env;
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
+/// markdownlint rule IDs and their aliases
+const MARKDOWNLINT_RULES: &[(&str, &[&str])] = &[
+    ("MD001", &["heading-increment", "header-increment"]),
+    ("MD002", &["first-heading-h1", "first-header-h1"]),
+    ("MD003", &["heading-style", "header-style"]),
+    ("MD004", &["ul-style"]),
+    ("MD005", &["list-indent"]),
+    ("MD006", &["ul-start-left"]),
+    ("MD007", &["ul-indent"]),
+    ("MD009", &["no-trailing-spaces"]),
+    ("MD010", &["no-hard-tabs"]),
+    ("MD011", &["no-reversed-links"]),
+    ("MD012", &["no-multiple-blanks"]),
+    ("MD013", &["line-length"]),
+    ("MD014", &["commands-show-output"]),
+    ("MD018", &["no-missing-space-atx"]),
+    ("MD019", &["no-multiple-space-atx"]),
+    ("MD020", &["no-missing-space-closed-atx"]),
+    ("MD021", &["no-multiple-space-closed-atx"]),
+    (
+        "MD022",
+        &["blanks-around-headings", "blanks-around-headers"],
+    ),
+    ("MD023", &["heading-start-left", "header-start-left"]),
+    ("MD024", &["no-duplicate-heading", "no-duplicate-header"]),
+    ("MD025", &["single-title", "single-h1"]),
+    ("MD026", &["no-trailing-punctuation"]),
+    ("MD027", &["no-multiple-space-blockquote"]),
+    ("MD028", &["no-blanks-blockquote"]),
+    ("MD029", &["ol-prefix"]),
+    ("MD030", &["list-marker-space"]),
+    ("MD031", &["blanks-around-fences"]),
+    ("MD032", &["blanks-around-lists"]),
+    ("MD033", &["no-inline-html"]),
+    ("MD034", &["no-bare-urls"]),
+    ("MD035", &["hr-style"]),
+    (
+        "MD036",
+        &["no-emphasis-as-heading", "no-emphasis-as-header"],
+    ),
+    ("MD037", &["no-space-in-emphasis"]),
+    ("MD038", &["no-space-in-code"]),
+    ("MD039", &["no-space-in-links"]),
+    ("MD040", &["fenced-code-language"]),
+    ("MD041", &["first-line-heading", "first-line-h1"]),
+    ("MD042", &["no-empty-links"]),
+    ("MD043", &["required-headings", "required-headers"]),
+    ("MD044", &["proper-names"]),
+    ("MD045", &["no-alt-text"]),
+    ("MD046", &["code-block-style"]),
+    ("MD047", &["single-trailing-newline"]),
+    ("MD048", &["code-fence-style"]),
+    ("MD049", &["emphasis-style"]),
+    ("MD050", &["strong-style"]),
+    ("MD051", &["link-fragments"]),
+    ("MD052", &["reference-links-images"]),
+    ("MD053", &["link-image-reference-definitions"]),
+    ("MD054", &["link-image-style"]),
+    ("MD055", &["table-pipe-style"]),
+    ("MD056", &["table-column-count"]),
+    ("MD058", &["blanks-around-tables"]),
+    ("MD059", &["descriptive-link-text"]),
+];
+
 /// Configuration for mdbook-lint CLI
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Presets, config files, or URLs this config builds on, applied in order
+    ///
+    /// Accepts a single string or a list. Resolved by [`Config::from_file`];
+    /// see [`crate::extends`].
+    #[serde(
+        default,
+        deserialize_with = "string_or_list",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub extends: Vec<String>,
+
+    /// Built-in rule profile applied before `extends`, such as `recommended`
+    ///
+    /// See [`mdbook_lint_rulesets::profiles`]; `--profile` overrides it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
     /// Core linting configuration
     #[serde(flatten)]
     pub core: mdbook_lint_core::Config,
@@ -20,9 +103,203 @@ pub struct Config {
     #[serde(rename = "fail-on-errors", default = "default_fail_on_errors")]
     pub fail_on_errors: bool,
 
+    /// Lowest severity that fails the build: `error`, `warning`, or `never`
+    /// (preprocessor-specific)
+    ///
+    /// Takes precedence over `fail-on-warnings` and `fail-on-errors`.
+    #[serde(
+        rename = "fail-on",
+        alias = "fail_on",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fail_on: Option<FailOn>,
+
+    /// Lowest severity that is printed: `info`, `warning`, or `error`
+    ///
+    /// Violations below it still count toward `fail-level`.
+    #[serde(
+        rename = "report-level",
+        alias = "report_level",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub report_level: Option<Severity>,
+
+    /// Lowest severity that fails `lint` or an mdBook build: `info`,
+    /// `warning`, or `error`
+    ///
+    /// Takes precedence over `fail-on`, `fail-on-warnings`, and
+    /// `fail-on-errors`.
+    #[serde(
+        rename = "fail-level",
+        alias = "fail_level",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub fail_level: Option<Severity>,
+
+    /// Print a compact per-book summary at the end of preprocessing instead
+    /// of one line per violation (preprocessor-specific)
+    #[serde(default)]
+    pub summary: bool,
+
+    /// Which version of each chapter the preprocessor lints: `preprocessed`
+    /// (the default) or `source` (preprocessor-specific)
+    #[serde(
+        rename = "chapter-content",
+        alias = "chapter_content",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub chapter_content: Option<ChapterContent>,
+
     /// How to handle malformed markdown (CLI-specific)
     #[serde(rename = "malformed-markdown", default)]
     pub malformed_markdown: MalformedMarkdownAction,
+
+    /// Inject a warning block into chapters with violations during draft
+    /// builds (preprocessor-specific)
+    #[serde(rename = "render-warnings", alias = "render_warnings", default)]
+    pub render_warnings: bool,
+
+    /// Treat the build as a draft, enabling `render-warnings`
+    /// (preprocessor-specific; the `MDBOOK_LINT_DRAFT` environment variable
+    /// has the same effect)
+    #[serde(default)]
+    pub draft: bool,
+
+    /// Run the ADR rules (ADR001-ADR018) on every linted file
+    ///
+    /// `--adr` has the same effect. Without it, the ADR rules only run when a
+    /// linted path is inside an ADR directory such as `docs/adr/`.
+    #[serde(default)]
+    pub adr: bool,
+
+    /// Chapters to lint, as glob patterns like the `chapters` keys
+    /// (preprocessor-specific); empty lints every chapter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub include: Vec<String>,
+
+    /// Chapters to skip, as glob patterns like the `chapters` keys
+    /// (preprocessor-specific), such as chapters other preprocessors generate
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Per-chapter overrides keyed by glob pattern (preprocessor-specific)
+    ///
+    /// Declared as `[preprocessor.mdbook-lint.chapters."src/appendix/*.md"]`
+    /// in `book.toml`. Every pattern matching a chapter is applied, in
+    /// pattern order, on top of the book-wide configuration.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub chapters: BTreeMap<String, ChapterConfig>,
+}
+
+/// Rule overrides for chapters matching a glob pattern
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChapterConfig {
+    /// Rules to enable for matching chapters
+    #[serde(rename = "enabled-rules", alias = "enabled_rules", default)]
+    pub enabled_rules: Vec<String>,
+
+    /// Rules to disable for matching chapters
+    #[serde(rename = "disabled-rules", alias = "disabled_rules", default)]
+    pub disabled_rules: Vec<String>,
+
+    /// Severity overrides for matching chapters
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severity: HashMap<String, Severity>,
+
+    /// Rule-specific configuration, merged key by key into the book-wide
+    /// settings for each rule
+    #[serde(flatten)]
+    pub rule_configs: HashMap<String, toml::Value>,
+}
+
+impl ChapterConfig {
+    /// Apply this override on top of `core`
+    pub fn apply_to(&self, core: &mut mdbook_lint_core::Config) {
+        for rule in &self.enabled_rules {
+            core.disabled_rules.retain(|r| r != rule);
+            if !core.enabled_rules.contains(rule) {
+                core.enabled_rules.push(rule.clone());
+            }
+        }
+        for rule in &self.disabled_rules {
+            core.enabled_rules.retain(|r| r != rule);
+            if !core.disabled_rules.contains(rule) {
+                core.disabled_rules.push(rule.clone());
+            }
+        }
+        core.severity
+            .extend(self.severity.iter().map(|(k, v)| (k.clone(), *v)));
+
+        for (rule_id, value) in &self.rule_configs {
+            match (core.rule_configs.get_mut(rule_id), value) {
+                (Some(toml::Value::Table(existing)), toml::Value::Table(overrides)) => {
+                    existing.extend(overrides.iter().map(|(k, v)| (k.clone(), v.clone())));
+                }
+                _ => {
+                    core.rule_configs.insert(rule_id.clone(), value.clone());
+                }
+            }
+        }
+    }
+}
+
+/// Lowest violation severity that fails an mdBook build
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    /// Fail on errors only
+    Error,
+    /// Fail on warnings and errors
+    Warning,
+    /// Never fail the build
+    Never,
+}
+
+impl FromStr for FailOn {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "warning" => Ok(Self::Warning),
+            "never" => Ok(Self::Never),
+            _ => Err(format!(
+                "fail-on must be \"error\", \"warning\", or \"never\", not \"{s}\""
+            )),
+        }
+    }
+}
+
+/// Which version of a chapter the preprocessor lints
+///
+/// Preprocessors that run before mdbook-lint can rewrite chapters, for
+/// example by expanding `{{#include}}` links, so violations in the content
+/// mdBook hands over may not line up with the file on disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChapterContent {
+    /// The content as received from mdBook, after earlier preprocessors
+    Preprocessed,
+    /// The chapter's source file, as it is on disk
+    Source,
+}
+
+impl FromStr for ChapterContent {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "preprocessed" => Ok(Self::Preprocessed),
+            "source" => Ok(Self::Source),
+            _ => Err(format!(
+                "chapter-content must be \"preprocessed\" or \"source\", not \"{s}\""
+            )),
+        }
+    }
 }
 
 /// How to handle malformed markdown
@@ -61,23 +338,98 @@ pub enum RuleCategory {
 impl Default for Config {
     fn default() -> Self {
         Self {
+            extends: Vec::new(),
+            profile: None,
             core: mdbook_lint_core::Config::default(),
             fail_on_warnings: false,
             fail_on_errors: true,
+            fail_on: None,
+            report_level: None,
+            fail_level: None,
+            summary: false,
+            chapter_content: None,
             malformed_markdown: MalformedMarkdownAction::Warn,
+            render_warnings: false,
+            draft: false,
+            adr: false,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            chapters: BTreeMap::new(),
         }
     }
 }
 
+/// Whether a chapter glob matches `path`, relative to the book root or to
+/// `src_dir`
+fn chapter_pattern_matches(pattern: &str, path: &Path, src_dir: &Path) -> bool {
+    use glob::{MatchOptions, Pattern};
+
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+    let normalize = |p: &Path| {
+        p.to_string_lossy()
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .to_string()
+    };
+    let full = normalize(path);
+    let relative = path
+        .strip_prefix(src_dir)
+        .map(normalize)
+        .unwrap_or_else(|_| full.clone());
+
+    let pattern = pattern.replace('\\', "/");
+    Pattern::new(pattern.trim_start_matches("./"))
+        .is_ok_and(|p| p.matches_with(&full, options) || p.matches_with(&relative, options))
+}
+
 fn default_fail_on_errors() -> bool {
     true
 }
 
+/// Deserialize either a single string or a list of strings
+fn string_or_list<'de, D>(deserializer: D) -> std::result::Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrList {
+        One(String),
+        Many(Vec<String>),
+    }
+
+    Ok(match StringOrList::deserialize(deserializer)? {
+        StringOrList::One(value) => vec![value],
+        StringOrList::Many(values) => values,
+    })
+}
+
 #[allow(dead_code)]
 impl Config {
     /// Load configuration from a file, auto-detecting format by extension
+    ///
+    /// Any presets, files, or URLs listed in `extends` are applied first.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::from_file_with_profile(path, None)
+    }
+
+    /// Load configuration from a file, using `profile` instead of the
+    /// profile the file names, if any
+    pub fn from_file_with_profile<P: AsRef<Path>>(path: P, profile: Option<&str>) -> Result<Self> {
         let path = path.as_ref();
+        let mut config = Self::from_file_unresolved(path)?;
+        if let Some(profile) = profile {
+            config.profile = Some(profile.to_string());
+        }
+        crate::extends::resolve(config, path)
+    }
+
+    /// Load configuration from a file without applying `extends`
+    pub(crate) fn from_file_unresolved(path: &Path) -> Result<Self> {
         let content = std::fs::read_to_string(path).map_err(|e| {
             MdBookLintError::config_error(format!(
                 "Failed to read config file {}: {}",
@@ -169,7 +521,11 @@ impl Config {
 
     /// Serialize configuration to TOML string
     pub fn to_toml_string(&self) -> Result<String> {
-        toml::to_string_pretty(self)
+        // Rule tables flattened in from the core config come before the
+        // CLI's own settings, which TOML cannot express directly; going
+        // through a `toml::Value` emits plain values before tables
+        toml::Value::try_from(self)
+            .and_then(|value| toml::to_string_pretty(&value))
             .map_err(|e| MdBookLintError::config_error(format!("Failed to serialize to TOML: {e}")))
     }
 
@@ -198,146 +554,59 @@ impl Config {
     }
 
     /// Convert markdownlint configuration format to our internal format
+    ///
+    /// Rules may be keyed by ID (`MD013`) or alias (`line-length`). `false`
+    /// disables a rule, and an object configures it with markdownlint's
+    /// option names, which the rules accept directly.
     pub fn from_markdownlint_config(config: &serde_json::Value) -> Result<Self> {
         let mut config_result = Config::default();
         let mut rule_configs = HashMap::new();
+        let mut default_enabled = true;
+        let mut turned_on = Vec::new();
 
         if let Some(obj) = config.as_object() {
             for (key, value) in obj {
-                // Skip comment fields
-                if key.starts_with("//") {
+                // Skip comment fields and the schema reference
+                if key.starts_with("//") || key == "$schema" {
                     continue;
                 }
 
-                match key.as_str() {
-                    // Global settings
-                    "default" => {
-                        if let Some(default_val) = value.as_bool()
-                            && !default_val
-                        {
-                            // If default is false, start with empty enabled categories
-                            config_result.core.enabled_categories.clear();
-                        }
-                    }
+                if key == "default" {
+                    default_enabled = value.as_bool() != Some(false);
+                    continue;
+                }
 
-                    // Map markdownlint rule names to MD### rule IDs
-                    "heading-style" => {
-                        if let Some(style_config) = Self::convert_markdownlint_rule(value, "MD003")
-                        {
-                            rule_configs.insert("MD003".to_string(), style_config);
-                        }
-                    }
-                    "heading-start-left" => {
-                        if value.as_bool() == Some(true) {
-                            // This is enforced by default in MD018
-                        }
-                    }
-                    "heading-increment" => {
-                        if value.as_bool() == Some(true) {
-                            // This is enforced by MD001
-                        }
-                    }
-                    "no-duplicate-heading" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD024") {
-                            rule_configs.insert("MD024".to_string(), config);
-                        }
-                    }
-                    "first-heading-h1" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD041") {
-                            rule_configs.insert("MD041".to_string(), config);
-                        }
-                    }
-                    "blanks-around-headings" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD022") {
-                            rule_configs.insert("MD022".to_string(), config);
-                        }
-                    }
-                    "ul-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD004") {
-                            rule_configs.insert("MD004".to_string(), config);
-                        }
-                    }
-                    "ul-indent" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD007") {
-                            rule_configs.insert("MD007".to_string(), config);
-                        }
-                    }
-                    "ol-prefix" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD029") {
-                            rule_configs.insert("MD029".to_string(), config);
-                        }
-                    }
-                    "line-length" => {
-                        if let Some(config) = Self::convert_line_length_config(value) {
-                            rule_configs.insert("MD013".to_string(), config);
-                        }
-                    }
-                    "no-hard-tabs" => {
-                        if value.as_bool() == Some(true) {
-                            // This is enforced by MD010
-                        }
-                    }
-                    "no-trailing-spaces" => {
-                        if value.as_bool() == Some(false) {
-                            config_result.core.disabled_rules.push("MD009".to_string());
-                        } else if let Some(config) = Self::convert_markdownlint_rule(value, "MD009")
-                        {
-                            rule_configs.insert("MD009".to_string(), config);
-                        }
-                    }
-                    "no-multiple-blanks" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD012") {
-                            rule_configs.insert("MD012".to_string(), config);
-                        }
-                    }
-                    "code-block-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD046") {
-                            rule_configs.insert("MD046".to_string(), config);
-                        }
-                    }
-                    "code-fence-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD048") {
-                            rule_configs.insert("MD048".to_string(), config);
-                        }
-                    }
-                    "fenced-code-language" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD040") {
-                            rule_configs.insert("MD040".to_string(), config);
-                        }
-                    }
-                    "no-inline-html" => {
-                        if let Some(config) = Self::convert_inline_html_config(value) {
-                            rule_configs.insert("MD033".to_string(), config);
-                        }
-                    }
-                    "emphasis-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD049") {
-                            rule_configs.insert("MD049".to_string(), config);
-                        }
-                    }
-                    "strong-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD050") {
-                            rule_configs.insert("MD050".to_string(), config);
-                        }
-                    }
-                    "hr-style" => {
-                        if let Some(config) = Self::convert_markdownlint_rule(value, "MD035") {
-                            rule_configs.insert("MD035".to_string(), config);
-                        }
+                // Tags and unknown keys are not mapped
+                let Some(rule_id) = Self::markdownlint_to_md_rule(key) else {
+                    continue;
+                };
+
+                match value {
+                    serde_json::Value::Bool(false) => {
+                        config_result.core.disabled_rules.push(rule_id);
                     }
+                    serde_json::Value::Bool(true) => turned_on.push(rule_id),
                     _ => {
-                        // For unknown rules, check if it's a boolean disable
-                        if value.as_bool() == Some(false) {
-                            // Try to map the rule name to an MD### rule and disable it
-                            if let Some(md_rule) = Self::markdownlint_to_md_rule(key) {
-                                config_result.core.disabled_rules.push(md_rule);
-                            }
+                        let converted = match rule_id.as_str() {
+                            "MD013" => Self::convert_line_length_config(value),
+                            "MD029" => Self::convert_ol_prefix_config(value),
+                            "MD033" => Self::convert_inline_html_config(value),
+                            _ => Self::convert_markdownlint_rule(value, &rule_id),
+                        };
+                        if let Some(converted) = converted {
+                            rule_configs.insert(rule_id.clone(), converted);
                         }
+                        turned_on.push(rule_id);
                     }
                 }
             }
         }
 
+        // With `"default": false` only the rules turned on explicitly run
+        if !default_enabled {
+            config_result.core.enabled_rules = turned_on;
+        }
+
         config_result.core.rule_configs = rule_configs;
         Ok(config_result)
     }
@@ -368,12 +637,33 @@ impl Config {
                 config.insert("ignore-tables".to_string(), toml::Value::Boolean(!tables));
             }
 
+            if let Some(headings) = obj.get("headings").and_then(|v| v.as_bool()) {
+                config.insert(
+                    "ignore-headings".to_string(),
+                    toml::Value::Boolean(!headings),
+                );
+            }
+
             Some(toml::Value::Table(config))
         } else {
             None
         }
     }
 
+    /// Special handling for ordered list prefix configuration, whose style
+    /// names differ from markdownlint's
+    fn convert_ol_prefix_config(value: &serde_json::Value) -> Option<toml::Value> {
+        let style = match value.get("style")?.as_str()? {
+            "one" => "all_ones",
+            "ordered" => "sequential",
+            "one_or_ordered" => "consistent",
+            _ => return None,
+        };
+        let mut config = toml::value::Table::new();
+        config.insert("style".to_string(), toml::Value::String(style.to_string()));
+        Some(toml::Value::Table(config))
+    }
+
     /// Special handling for inline HTML configuration
     fn convert_inline_html_config(value: &serde_json::Value) -> Option<toml::Value> {
         if let Some(obj) = value.as_object() {
@@ -391,40 +681,14 @@ impl Config {
         }
     }
 
-    /// Map markdownlint rule names to MD### rule IDs
-    fn markdownlint_to_md_rule(markdownlint_name: &str) -> Option<String> {
-        match markdownlint_name {
-            "heading-style" => Some("MD003".to_string()),
-            "heading-start-left" => Some("MD018".to_string()),
-            "heading-increment" => Some("MD001".to_string()),
-            "no-duplicate-heading" => Some("MD024".to_string()),
-            "first-heading-h1" => Some("MD041".to_string()),
-            "blanks-around-headings" => Some("MD022".to_string()),
-            "ul-style" => Some("MD004".to_string()),
-            "ul-indent" => Some("MD007".to_string()),
-            "ol-prefix" => Some("MD029".to_string()),
-            "line-length" => Some("MD013".to_string()),
-            "no-hard-tabs" => Some("MD010".to_string()),
-            "no-trailing-spaces" => Some("MD009".to_string()),
-            "no-multiple-blanks" => Some("MD012".to_string()),
-            "code-block-style" => Some("MD046".to_string()),
-            "code-fence-style" => Some("MD048".to_string()),
-            "fenced-code-language" => Some("MD040".to_string()),
-            "no-inline-html" => Some("MD033".to_string()),
-            "emphasis-style" => Some("MD049".to_string()),
-            "strong-style" => Some("MD050".to_string()),
-            "hr-style" => Some("MD035".to_string()),
-            "no-bare-urls" => Some("MD034".to_string()),
-            "no-alt-text" => Some("MD045".to_string()),
-            "no-space-in-code" => Some("MD038".to_string()),
-            "no-space-in-emphasis" => Some("MD037".to_string()),
-            "no-space-in-links" => Some("MD039".to_string()),
-            "no-empty-links" => Some("MD042".to_string()),
-            "reference-links-images" => Some("MD052".to_string()),
-            "link-image-style" => Some("MD051".to_string()),
-            "single-trailing-newline" => Some("MD047".to_string()),
-            _ => None,
-        }
+    /// Map markdownlint rule names or IDs to MD### rule IDs
+    pub(crate) fn markdownlint_to_md_rule(markdownlint_name: &str) -> Option<String> {
+        MARKDOWNLINT_RULES
+            .iter()
+            .find(|(id, aliases)| {
+                id.eq_ignore_ascii_case(markdownlint_name) || aliases.contains(&markdownlint_name)
+            })
+            .map(|(id, _)| id.to_string())
     }
 
     /// Attempt to detect configuration format from content and parse
@@ -455,7 +719,7 @@ impl Config {
     pub fn from_preprocessor_config(config: &serde_json::Value) -> Result<Self> {
         let preprocessor_config = config
             .get("preprocessor")
-            .and_then(|p| p.get("lint"))
+            .and_then(|p| p.get("mdbook-lint").or_else(|| p.get("lint")))
             .unwrap_or(&serde_json::Value::Null);
 
         if preprocessor_config.is_null() {
@@ -551,6 +815,35 @@ impl Config {
         }
     }
 
+    /// Chapter overrides whose pattern matches `path`, in pattern order
+    ///
+    /// `path` is the chapter's path relative to the book root (for example
+    /// `src/appendix/a.md`). Patterns written relative to the source
+    /// directory (`appendix/*.md`) match too. `*` does not cross directory
+    /// separators; use `**` for nested chapters.
+    pub fn chapter_overrides(&self, path: &Path, src_dir: &Path) -> Vec<&ChapterConfig> {
+        self.chapters
+            .iter()
+            .filter(|(pattern, _)| chapter_pattern_matches(pattern, path, src_dir))
+            .map(|(_, chapter)| chapter)
+            .collect()
+    }
+
+    /// Whether the preprocessor lints the chapter at `path`, according to
+    /// `include` and `exclude`
+    ///
+    /// Patterns match as in [`Config::chapter_overrides`]. A chapter is linted
+    /// when `include` is empty or one of its patterns matches, and no
+    /// `exclude` pattern does.
+    pub fn includes_chapter(&self, path: &Path, src_dir: &Path) -> bool {
+        let matches = |patterns: &[String]| {
+            patterns
+                .iter()
+                .any(|pattern| chapter_pattern_matches(pattern, path, src_dir))
+        };
+        (self.include.is_empty() || matches(&self.include)) && !matches(&self.exclude)
+    }
+
     /// Get rule-specific configuration
     pub fn get_rule_config(&self, rule_id: &str) -> Option<&toml::Value> {
         self.core.rule_configs.get(rule_id)
@@ -595,7 +888,9 @@ impl Config {
             // Check for config files in current directory
             for config_name in CONFIG_NAMES {
                 let config_path = current.join(config_name);
+                tracing::trace!(path = %config_path.display(), "looking for config file");
                 if config_path.exists() && config_path.is_file() {
+                    tracing::debug!(path = %config_path.display(), "discovered config file");
                     return Some(config_path);
                 }
             }
@@ -608,20 +903,116 @@ impl Config {
             }
         }
 
+        tracing::debug!(start = %start.display(), "no config file found");
         None
     }
 
+    /// Whether a violation of `severity` fails an mdBook build
+    ///
+    /// `fail-level` decides when it is set, then `fail-on`; otherwise
+    /// `fail-on-errors` and `fail-on-warnings` do.
+    pub fn fails_build(&self, severity: Severity) -> bool {
+        if let Some(level) = self.fail_level {
+            return severity >= level;
+        }
+        match (self.fail_on, severity) {
+            (Some(FailOn::Never), _) | (_, Severity::Info) => false,
+            (Some(FailOn::Warning), _) => true,
+            (Some(FailOn::Error), severity) => severity == Severity::Error,
+            (None, Severity::Error) => self.fail_on_errors,
+            (None, Severity::Warning) => self.fail_on_warnings,
+        }
+    }
+
+    /// Whether violations whose most severe is `worst` fail `mdbook-lint lint`
+    ///
+    /// `fail-level` decides when it is set; otherwise any error fails, and
+    /// with `fail-on-warnings` any violation at all does.
+    pub fn fails_lint(&self, worst: Option<Severity>) -> bool {
+        match (self.fail_level, worst) {
+            (_, None) => false,
+            (Some(level), Some(worst)) => worst >= level,
+            (None, Some(worst)) => worst == Severity::Error || self.fail_on_warnings,
+        }
+    }
+
+    /// Drop violations below `report-level`, and files left without any
+    pub fn retain_reported<T>(&self, files: &mut Vec<(T, Vec<Violation>)>) {
+        let Some(level) = self.report_level else {
+            return;
+        };
+        for (_, violations) in files.iter_mut() {
+            violations.retain(|violation| violation.severity >= level);
+        }
+        files.retain(|(_, violations)| !violations.is_empty());
+    }
+
     /// Merge this config with another, with the other taking precedence
     pub fn merge(&mut self, other: Config) {
+        if other.profile.is_some() {
+            self.profile = other.profile;
+        }
         if other.fail_on_warnings {
             self.fail_on_warnings = other.fail_on_warnings;
         }
         if !other.fail_on_errors {
             self.fail_on_errors = other.fail_on_errors;
         }
+        if other.fail_on.is_some() {
+            self.fail_on = other.fail_on;
+        }
+        if other.report_level.is_some() {
+            self.report_level = other.report_level;
+        }
+        if other.fail_level.is_some() {
+            self.fail_level = other.fail_level;
+        }
+        if other.summary {
+            self.summary = other.summary;
+        }
+        if other.chapter_content.is_some() {
+            self.chapter_content = other.chapter_content;
+        }
         if other.core.markdownlint_compatible {
             self.core.markdownlint_compatible = other.core.markdownlint_compatible;
         }
+        if other.render_warnings {
+            self.render_warnings = other.render_warnings;
+        }
+        if other.draft {
+            self.draft = other.draft;
+        }
+        if other.adr {
+            self.adr = other.adr;
+        }
+
+        if !other.include.is_empty() {
+            self.include = other.include;
+        }
+        if !other.exclude.is_empty() {
+            self.exclude = other.exclude;
+        }
+        if !other.core.ignore_paths.is_empty() {
+            self.core.ignore_paths = other.core.ignore_paths;
+        }
+        if !other.core.fragment_paths.is_empty() {
+            self.core.fragment_paths = other.core.fragment_paths;
+        }
+        if other.core.require_suppression_reason {
+            self.core.require_suppression_reason = true;
+        }
+        if other.core.dedupe != DedupePolicy::default() {
+            self.core.dedupe = other.core.dedupe;
+        }
+        if other.core.ignore_blockquotes.is_some() {
+            self.core.ignore_blockquotes = other.core.ignore_blockquotes;
+        }
+        if other.core.ruleset_version.is_some() {
+            self.core.ruleset_version = other.core.ruleset_version;
+        }
+        if other.core.markdown != MarkdownExtensions::default() {
+            self.core.markdown = other.core.markdown;
+        }
 
         // Merge rule lists
         if !other.core.enabled_rules.is_empty() {
@@ -637,8 +1028,21 @@ impl Config {
             self.core.disabled_categories = other.core.disabled_categories;
         }
 
-        // Merge rule-specific configs
-        self.core.rule_configs.extend(other.core.rule_configs);
+        self.core.severity.extend(other.core.severity);
+        self.core.custom_rules.extend(other.core.custom_rules);
+
+        // Merge rule-specific configs option by option
+        for (rule_id, value) in other.core.rule_configs {
+            match (self.core.rule_configs.get_mut(&rule_id), value) {
+                (Some(toml::Value::Table(existing)), toml::Value::Table(options)) => {
+                    existing.extend(options);
+                }
+                (_, value) => {
+                    self.core.rule_configs.insert(rule_id, value);
+                }
+            }
+        }
+        self.chapters.extend(other.chapters);
     }
 }
 
@@ -673,6 +1077,7 @@ impl FromStr for Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::IgnoreBlockquotes;
 
     #[test]
     fn test_default_config() {
@@ -824,6 +1229,99 @@ ignore-code-blocks = true
         );
     }
 
+    #[test]
+    fn test_config_from_preprocessor_with_chapters() {
+        let json_config = serde_json::json!({
+            "preprocessor": {
+                "mdbook-lint": {
+                    "disabled-rules": ["MD033"],
+                    "MD013": { "line-length": 80, "code-blocks": false },
+                    "chapters": {
+                        "src/appendix/*.md": {
+                            "disabled-rules": ["MD013"],
+                            "enabled-rules": ["MD033"]
+                        },
+                        "src/reference/**/*.md": {
+                            "severity": { "MD040": "info" },
+                            "MD013": { "line-length": 120 }
+                        }
+                    }
+                }
+            }
+        });
+
+        let config = Config::from_preprocessor_config(&json_config).unwrap();
+        assert_eq!(config.chapters.len(), 2);
+
+        let src = Path::new("src");
+        assert!(
+            config
+                .chapter_overrides(Path::new("src/intro.md"), src)
+                .is_empty()
+        );
+        assert!(
+            config
+                .chapter_overrides(Path::new("src/appendix/nested/a.md"), src)
+                .is_empty()
+        );
+
+        let overrides = config.chapter_overrides(Path::new("src/appendix/a.md"), src);
+        assert_eq!(overrides.len(), 1);
+        let mut core = config.core.clone();
+        overrides[0].apply_to(&mut core);
+        assert_eq!(core.disabled_rules, vec!["MD013"]);
+        assert_eq!(core.enabled_rules, vec!["MD033"]);
+
+        let overrides = config.chapter_overrides(Path::new("src/reference/cli/flags.md"), src);
+        assert_eq!(overrides.len(), 1);
+        let mut core = config.core.clone();
+        overrides[0].apply_to(&mut core);
+        assert_eq!(core.severity_override("MD040"), Some(Severity::Info));
+        let md013 = core.rule_configs.get("MD013").unwrap();
+        assert_eq!(md013.get("line-length").unwrap().as_integer(), Some(120));
+        assert_eq!(md013.get("code-blocks").unwrap().as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_chapter_patterns_relative_to_src() {
+        let config =
+            Config::from_toml_str("[chapters.\"appendix/*.md\"]\ndisabled-rules = [\"MD013\"]\n")
+                .unwrap();
+
+        let src = Path::new("book/src");
+        assert_eq!(
+            config
+                .chapter_overrides(Path::new("book/src/appendix/a.md"), src)
+                .len(),
+            1
+        );
+        assert!(
+            config
+                .chapter_overrides(Path::new("book/src/guide/a.md"), src)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_includes_chapter() {
+        let src = Path::new("src");
+        let config = Config::default();
+        assert!(config.includes_chapter(Path::new("src/intro.md"), src));
+
+        let config = Config::from_toml_str(
+            "include = [\"src/guide/**\", \"intro.md\"]\nexclude = [\"guide/generated/**\"]\n",
+        )
+        .unwrap();
+        assert!(config.includes_chapter(Path::new("src/intro.md"), src));
+        assert!(config.includes_chapter(Path::new("src/guide/setup.md"), src));
+        assert!(!config.includes_chapter(Path::new("src/reference/cli.md"), src));
+        assert!(!config.includes_chapter(Path::new("src/guide/generated/api.md"), src));
+
+        let config = Config::from_toml_str("exclude = [\"API *\"]\n").unwrap();
+        assert!(config.includes_chapter(Path::new("src/intro.md"), src));
+        assert!(!config.includes_chapter(Path::new("API Reference"), src));
+    }
+
     #[test]
     fn test_config_merge() {
         let mut base_config = Config {
@@ -1116,7 +1614,71 @@ deprecated-warning: info
             Config::markdownlint_to_md_rule("line-length"),
             Some("MD013".to_string())
         );
+        assert_eq!(
+            Config::markdownlint_to_md_rule("md013"),
+            Some("MD013".to_string())
+        );
+        assert_eq!(
+            Config::markdownlint_to_md_rule("heading-start-left"),
+            Some("MD023".to_string())
+        );
         assert_eq!(Config::markdownlint_to_md_rule("unknown-rule"), None);
+        assert_eq!(Config::markdownlint_to_md_rule("headings"), None);
+    }
+
+    #[test]
+    fn test_to_toml_string_with_rule_configs() {
+        let config =
+            Config::from_toml_str("fail-on-warnings = true\n\n[MD013]\nline-length = 100\n")
+                .unwrap();
+        let toml = config.to_toml_string().unwrap();
+        let reparsed = Config::from_toml_str(&toml).unwrap();
+        assert!(reparsed.fail_on_warnings);
+        assert_eq!(
+            reparsed
+                .get_rule_config("MD013")
+                .unwrap()
+                .get("line-length")
+                .unwrap()
+                .as_integer(),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn test_markdownlint_booleans_ids_and_default() {
+        let config = Config::from_markdownlint_json(
+            r#"{
+    "default": false,
+    "MD001": true,
+    "heading-style": false,
+    "ol-prefix": { "style": "one" },
+    "line-length": { "headings": false }
+}"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.core.disabled_rules, vec!["MD003"]);
+        assert!(config.get_rule_config("MD003").is_none());
+        assert_eq!(config.core.enabled_rules, vec!["MD001", "MD013", "MD029"]);
+        assert_eq!(
+            config
+                .get_rule_config("MD029")
+                .unwrap()
+                .get("style")
+                .unwrap()
+                .as_str(),
+            Some("all_ones")
+        );
+        assert_eq!(
+            config
+                .get_rule_config("MD013")
+                .unwrap()
+                .get("ignore-headings")
+                .unwrap()
+                .as_bool(),
+            Some(true)
+        );
     }
 
     #[test]
@@ -1328,6 +1890,43 @@ enabled-rules:
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_report_and_fail_levels() {
+        let config =
+            Config::from_toml_str("report-level = \"warning\"\nfail-level = \"info\"\n").unwrap();
+        assert_eq!(config.report_level, Some(Severity::Warning));
+        assert!(config.fails_lint(Some(Severity::Info)));
+        assert!(!config.fails_lint(None));
+
+        // Without fail-level, errors fail and warnings need fail-on-warnings
+        let default = Config::default();
+        assert!(default.fails_lint(Some(Severity::Error)));
+        assert!(!default.fails_lint(Some(Severity::Warning)));
+
+        let violation = |severity| Violation {
+            rule_id: "MD001".to_string(),
+            rule_name: "heading-increment".to_string(),
+            message: String::new(),
+            line: 1,
+            column: 1,
+            severity,
+            fix: None,
+            url: None,
+            path: None,
+        };
+        let mut files = vec![
+            (
+                "a.md",
+                vec![violation(Severity::Info), violation(Severity::Error)],
+            ),
+            ("b.md", vec![violation(Severity::Info)]),
+        ];
+        config.retain_reported(&mut files);
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].1.len(), 1);
+        assert_eq!(files[0].1[0].severity, Severity::Error);
+    }
+
     #[test]
     fn test_markdownlint_compatible_mode() {
         let config = Config {
@@ -1520,6 +2119,110 @@ auto-fix = true
         assert!(!config.should_auto_fix_rule("MD024")); // duplicate headings
     }
 
+    #[test]
+    fn test_severity_overrides_from_toml() {
+        use mdbook_lint_core::Severity;
+
+        let toml_config = r#"
+[severity]
+MD013 = "info"
+MDBOOK001 = "error"
+
+[MD013]
+line-length = 100
+"#;
+
+        let config = Config::from_toml_str(toml_config).unwrap();
+
+        assert_eq!(config.core.severity_override("MD013"), Some(Severity::Info));
+        assert_eq!(
+            config.core.severity_override("MDBOOK001"),
+            Some(Severity::Error)
+        );
+        assert_eq!(config.core.severity_override("MD001"), None);
+
+        // The severity table must not be mistaken for a rule config
+        assert!(!config.core.rule_configs.contains_key("severity"));
+        assert!(config.get_rule_config("MD013").is_some());
+    }
+
+    #[test]
+    fn test_severity_overrides_invalid_level() {
+        let toml_config = r#"
+[severity]
+MD013 = "critical"
+"#;
+
+        assert!(Config::from_toml_str(toml_config).is_err());
+    }
+
+    #[test]
+    fn test_adr_toggle_from_toml() {
+        let config = Config::from_toml_str("adr = true\n\n[ADR]\nformat = \"madr\"\n").unwrap();
+        assert!(config.adr);
+        assert!(!config.core.rule_configs.contains_key("adr"));
+        assert_eq!(
+            config.core.rule_configs["ADR"].get("format"),
+            Some(&toml::Value::String("madr".to_string()))
+        );
+
+        assert!(!Config::from_toml_str("").unwrap().adr);
+    }
+
+    #[test]
+    fn test_dedupe_policy_from_toml() {
+        let config = Config::from_toml_str("dedupe = \"loose\"").unwrap();
+        assert_eq!(config.core.dedupe, DedupePolicy::Loose);
+        assert!(!config.core.rule_configs.contains_key("dedupe"));
+
+        assert_eq!(
+            Config::from_toml_str("").unwrap().core.dedupe,
+            DedupePolicy::Strict
+        );
+        assert!(Config::from_toml_str("dedupe = \"sometimes\"").is_err());
+    }
+
+    #[test]
+    fn test_ignore_blockquotes_from_toml() {
+        let config = Config::from_toml_str("ignore-blockquotes = true").unwrap();
+        assert_eq!(
+            config.core.ignore_blockquotes,
+            Some(IgnoreBlockquotes::All(true))
+        );
+        assert!(!config.core.rule_configs.contains_key("ignore-blockquotes"));
+
+        let mut config = Config::from_toml_str("ignore-blockquotes = 2").unwrap();
+        assert_eq!(config.core.ignored_blockquote_depth("MD013"), Some(2));
+        config.merge(Config::from_toml_str("[MD013]\nignore-blockquotes = false\n").unwrap());
+        assert_eq!(config.core.ignored_blockquote_depth("MD013"), None);
+        assert_eq!(config.core.ignored_blockquote_depth("MD009"), Some(2));
+
+        assert_eq!(
+            Config::from_toml_str("").unwrap().core.ignore_blockquotes,
+            None
+        );
+    }
+
+    #[test]
+    fn test_markdown_extensions_from_toml() {
+        let config =
+            Config::from_toml_str("[markdown]\ndescription-lists = false\nsuperscript = true\n")
+                .unwrap();
+        assert_eq!(
+            config.core.markdown,
+            MarkdownExtensions {
+                description_lists: false,
+                superscript: true,
+                ..MarkdownExtensions::default()
+            }
+        );
+        assert!(!config.core.rule_configs.contains_key("markdown"));
+
+        let mut merged = Config::default();
+        merged.merge(config);
+        assert!(merged.core.markdown.superscript);
+    }
+
     #[test]
     fn test_auto_fix_aggressive_config() {
         // Example: fix everything except structural changes
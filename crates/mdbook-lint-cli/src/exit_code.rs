@@ -0,0 +1,60 @@
+//! Process exit codes shared by every subcommand
+//!
+//! Scripts can tell a run that found problems in the book (`VIOLATIONS`)
+//! apart from one where mdbook-lint itself could not do its job.
+
+use mdbook_lint_core::MdBookLintError;
+
+/// Violations were found that fail the run
+pub const VIOLATIONS: i32 = 1;
+/// The configuration or command-line arguments are invalid
+///
+/// Usage errors that clap reports while parsing the arguments exit with the
+/// same code.
+pub const CONFIG_ERROR: i32 = 2;
+/// A file or stream could not be read, decoded, or written
+pub const IO_ERROR: i32 = 3;
+/// mdbook-lint failed unexpectedly, including panics
+pub const INTERNAL_ERROR: i32 = 4;
+
+/// Exit code for an error that stopped the run
+pub fn for_error(error: &MdBookLintError) -> i32 {
+    match error {
+        MdBookLintError::Config(_)
+        | MdBookLintError::Json(_)
+        | MdBookLintError::Yaml(_)
+        | MdBookLintError::Toml(_) => CONFIG_ERROR,
+        MdBookLintError::Io(_) | MdBookLintError::WalkDir(_) | MdBookLintError::Document(_) => {
+            IO_ERROR
+        }
+        MdBookLintError::Parse { .. }
+        | MdBookLintError::Rule { .. }
+        | MdBookLintError::Plugin(_)
+        | MdBookLintError::Registry(_)
+        | MdBookLintError::Cancelled(_) => INTERNAL_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_error() {
+        assert_eq!(
+            for_error(&MdBookLintError::config_error("bad")),
+            CONFIG_ERROR
+        );
+        let toml_error = toml::from_str::<toml::Value>("= 1").unwrap_err();
+        assert_eq!(for_error(&toml_error.into()), CONFIG_ERROR);
+        assert_eq!(for_error(&std::io::Error::other("disk").into()), IO_ERROR);
+        assert_eq!(
+            for_error(&MdBookLintError::document_error("unsupported encoding")),
+            IO_ERROR
+        );
+        assert_eq!(
+            for_error(&MdBookLintError::rule_error("MD001", "boom")),
+            INTERNAL_ERROR
+        );
+    }
+}
@@ -0,0 +1,460 @@
+//! Resolution of `extends` in configuration files
+//!
+//! A config can build on built-in presets, other local config files, and
+//! (with the `remote-config` feature) config files fetched over HTTP:
+//!
+//! ```toml
+//! extends = ["strict", "./shared/base.toml", "https://example.com/org-lint.toml"]
+//! ```
+//!
+//! Bases are applied in order with [`Config::merge`], so later entries
+//! override earlier ones and the extending file overrides all of them.
+//!
+//! A `profile` names one of the rule profiles from
+//! [`mdbook_lint_rulesets::profiles`]. It is applied before every `extends`
+//! entry. The profiles can also be listed in `extends` like any other preset.
+
+use crate::config::Config;
+use mdbook_lint_core::{MdBookLintError, Result};
+use mdbook_lint_rulesets::profiles;
+use std::path::{Path, PathBuf};
+
+/// Built-in presets available to `extends` besides the rule profiles, with a
+/// one-line description
+const EXTRA_PRESETS: &[(&str, &str)] = &[
+    (
+        "relaxed",
+        "Disables line length, inline HTML, and prose style rules",
+    ),
+    (
+        "markdownlint-parity",
+        "Only the rules markdownlint runs by default, with its defaults",
+    ),
+];
+
+const RELAXED: &str = r#"
+disabled-rules = [
+    "MD013",
+    "MD033",
+    "MD036",
+    "MD041",
+    "CONTENT003",
+    "CONTENT004",
+    "CONTENT005",
+    "SPELL001",
+]
+"#;
+
+const MARKDOWNLINT_PARITY: &str = r#"
+markdownlint-compatible = true
+enabled-rules = [
+    "MD001", "MD003", "MD004", "MD005", "MD007", "MD009", "MD010", "MD011",
+    "MD012", "MD013", "MD014", "MD018", "MD019", "MD020", "MD021", "MD022",
+    "MD023", "MD024", "MD025", "MD026", "MD027", "MD028", "MD029", "MD030",
+    "MD031", "MD032", "MD033", "MD034", "MD035", "MD036", "MD037", "MD038",
+    "MD039", "MD040", "MD041", "MD042", "MD043", "MD045", "MD046", "MD047",
+    "MD048", "MD049", "MD050", "MD051", "MD052", "MD053", "MD054", "MD055",
+    "MD056", "MD058", "MD059", "MD060",
+]
+"#;
+
+/// Every built-in preset, rule profiles first, with a one-line description
+pub fn presets() -> Vec<(&'static str, &'static str)> {
+    profiles::PROFILES
+        .iter()
+        .map(|profile| (profile.name, profile.description))
+        .chain(EXTRA_PRESETS.iter().copied())
+        .collect()
+}
+
+/// TOML source of a built-in preset
+fn preset_source(name: &str) -> Option<&'static str> {
+    if let Some(profile) = profiles::profile(name) {
+        return Some(profile.source);
+    }
+    match name {
+        "relaxed" => Some(RELAXED),
+        "markdownlint-parity" => Some(MARKDOWNLINT_PARITY),
+        _ => None,
+    }
+}
+
+/// Whether an `extends` entry is a remote URL
+fn is_remote(entry: &str) -> bool {
+    entry.starts_with("https://") || entry.starts_with("http://")
+}
+
+/// Whether an `extends` entry is a path rather than a preset name
+fn is_path(entry: &str) -> bool {
+    entry.contains('/') || entry.contains('\\') || entry.contains('.')
+}
+
+/// Where an `extends` entry was loaded from, used to resolve nested entries
+/// and to detect cycles
+#[derive(Debug, Clone, PartialEq)]
+enum Source {
+    Preset(String),
+    File(PathBuf),
+    Remote(String),
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Preset(name) => write!(f, "preset '{name}'"),
+            Source::File(path) => write!(f, "{}", path.display()),
+            Source::Remote(url) => write!(f, "{url}"),
+        }
+    }
+}
+
+/// Apply the bases listed in `config.extends`
+///
+/// `config_path` is the file `config` was read from; relative paths are
+/// resolved against its directory. The returned config has an empty
+/// `extends` list.
+pub fn resolve(config: Config, config_path: &Path) -> Result<Config> {
+    let source = Source::File(
+        config_path
+            .canonicalize()
+            .unwrap_or_else(|_| config_path.to_path_buf()),
+    );
+    resolve_from(config, &source, &mut vec![source.clone()])
+}
+
+fn resolve_from(mut config: Config, source: &Source, stack: &mut Vec<Source>) -> Result<Config> {
+    let mut extends = std::mem::take(&mut config.extends);
+    if let Some(profile) = &config.profile {
+        if profiles::profile(profile).is_none() {
+            return Err(MdBookLintError::config_error(format!(
+                "Unknown profile '{profile}' (available: {})",
+                profiles::profile_names().join(", ")
+            )));
+        }
+        extends.insert(0, profile.clone());
+    }
+    if extends.is_empty() {
+        return Ok(config);
+    }
+
+    let mut resolved = Config::default();
+    for entry in &extends {
+        let base_source = locate(entry, source)?;
+        if stack.contains(&base_source) {
+            let chain: Vec<String> = stack.iter().map(ToString::to_string).collect();
+            return Err(MdBookLintError::config_error(format!(
+                "Config extends cycle: {} -> {base_source}",
+                chain.join(" -> ")
+            )));
+        }
+
+        tracing::debug!(base = %base_source, "extending config");
+        let base = load(&base_source)?;
+        stack.push(base_source.clone());
+        let base = resolve_from(base, &base_source, stack)?;
+        stack.pop();
+        resolved.merge(base);
+    }
+
+    resolved.merge(config);
+    Ok(resolved)
+}
+
+/// Work out what an `extends` entry refers to, relative to `parent`
+fn locate(entry: &str, parent: &Source) -> Result<Source> {
+    if is_remote(entry) {
+        return Ok(Source::Remote(entry.to_string()));
+    }
+    if !is_path(entry) {
+        return if preset_source(entry).is_some() {
+            Ok(Source::Preset(entry.to_string()))
+        } else {
+            let names: Vec<&str> = presets().into_iter().map(|(name, _)| name).collect();
+            Err(MdBookLintError::config_error(format!(
+                "Unknown preset '{entry}' in extends (available: {})",
+                names.join(", ")
+            )))
+        };
+    }
+
+    match parent {
+        Source::File(parent_path) => {
+            let dir = parent_path.parent().unwrap_or(Path::new("."));
+            let path = dir.join(entry);
+            // Canonicalize so the same file reached by two routes is one node
+            Ok(Source::File(path.canonicalize().unwrap_or(path)))
+        }
+        Source::Preset(_) | Source::Remote(_) => Err(MdBookLintError::config_error(format!(
+            "{parent} cannot extend the local path '{entry}'"
+        ))),
+    }
+}
+
+/// Read and parse the config an `extends` entry refers to, without resolving
+/// its own `extends`
+fn load(source: &Source) -> Result<Config> {
+    match source {
+        Source::Preset(name) => Config::from_toml_str(preset_source(name).unwrap_or_default())
+            .map_err(|e| {
+                MdBookLintError::config_error(format!("Invalid built-in preset '{name}': {e}"))
+            }),
+        Source::File(path) => {
+            if !path.is_file() {
+                return Err(MdBookLintError::config_error(format!(
+                    "Extended config file not found: {}",
+                    path.display()
+                )));
+            }
+            Config::from_file_unresolved(path)
+        }
+        Source::Remote(url) => {
+            let content = remote::fetch(url)?;
+            if url.ends_with(".toml") {
+                Config::from_toml_str(&content)
+            } else if url.ends_with(".yaml") || url.ends_with(".yml") {
+                Config::from_yaml_str(&content)
+            } else if url.ends_with(".json") {
+                Config::from_json_str(&content)
+            } else {
+                content.parse()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "remote-config")]
+mod remote {
+    use mdbook_lint_core::{MdBookLintError, Result};
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    /// How long a downloaded config is used before it is fetched again
+    const CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+    /// Directory for downloaded configs
+    ///
+    /// `MDBOOK_LINT_CACHE_DIR` overrides the default of
+    /// `$XDG_CACHE_HOME/mdbook-lint` or `~/.cache/mdbook-lint`.
+    fn cache_dir() -> Option<PathBuf> {
+        if let Some(dir) = std::env::var_os("MDBOOK_LINT_CACHE_DIR") {
+            return Some(PathBuf::from(dir));
+        }
+        let base = std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))?;
+        Some(base.join("mdbook-lint"))
+    }
+
+    /// Cache file name for a URL
+    fn cache_file(url: &str) -> Option<PathBuf> {
+        let name: String = url
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        Some(cache_dir()?.join("extends").join(name))
+    }
+
+    /// Fetch a remote config, using a cached copy while it is fresh
+    ///
+    /// If the download fails, a stale cached copy is used instead.
+    pub fn fetch(url: &str) -> Result<String> {
+        let cached = cache_file(url);
+        if let Some(path) = &cached
+            && let Ok(modified) = std::fs::metadata(path).and_then(|m| m.modified())
+            && modified.elapsed().is_ok_and(|age| age < CACHE_TTL)
+            && let Ok(content) = std::fs::read_to_string(path)
+        {
+            tracing::debug!(url, path = %path.display(), "using cached remote config");
+            return Ok(content);
+        }
+
+        match download(url) {
+            Ok(content) => {
+                if let Some(path) = &cached {
+                    let written = path
+                        .parent()
+                        .map_or(Ok(()), std::fs::create_dir_all)
+                        .and_then(|()| std::fs::write(path, &content));
+                    if let Err(e) = written {
+                        tracing::warn!(url, error = %e, "could not cache remote config");
+                    }
+                }
+                Ok(content)
+            }
+            Err(e) => match cached.and_then(|path| std::fs::read_to_string(path).ok()) {
+                Some(content) => {
+                    tracing::warn!(url, error = %e, "using stale cached remote config");
+                    Ok(content)
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    fn download(url: &str) -> Result<String> {
+        tracing::info!(url, "fetching remote config");
+        let agent: ureq::Agent = ureq::Agent::config_builder()
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        agent
+            .get(url)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| {
+                MdBookLintError::config_error(format!("Failed to fetch remote config {url}: {e}"))
+            })
+    }
+}
+
+#[cfg(not(feature = "remote-config"))]
+mod remote {
+    use mdbook_lint_core::{MdBookLintError, Result};
+
+    pub fn fetch(url: &str) -> Result<String> {
+        Err(MdBookLintError::config_error(format!(
+            "Cannot extend remote config {url}: mdbook-lint was built without the \
+             `remote-config` feature"
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::Severity;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn write(dir: &Path, name: &str, content: &str) -> PathBuf {
+        let path = dir.join(name);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).unwrap();
+        }
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_presets_parse() {
+        for (name, _) in presets() {
+            let config = Config {
+                extends: vec![name.to_string()],
+                ..Config::default()
+            };
+            resolve(config, Path::new(".mdbook-lint.toml")).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_extending_file_overrides_preset() {
+        let config = Config::from_toml_str(
+            r#"
+extends = "strict"
+
+[severity]
+MD001 = "warning"
+"#,
+        )
+        .unwrap();
+        let config = resolve(config, Path::new(".mdbook-lint.toml")).unwrap();
+
+        assert!(config.extends.is_empty());
+        assert_eq!(config.core.severity.get("MD001"), Some(&Severity::Warning));
+        assert_eq!(config.core.severity.get("MD051"), Some(&Severity::Error));
+    }
+
+    #[test]
+    fn test_profile_applies_before_extends() {
+        let config = Config::from_toml_str(
+            r#"
+profile = "recommended"
+extends = "relaxed"
+
+[severity]
+MD052 = "warning"
+"#,
+        )
+        .unwrap();
+        let config = resolve(config, Path::new(".mdbook-lint.toml")).unwrap();
+
+        assert_eq!(config.profile.as_deref(), Some("recommended"));
+        // `relaxed` is applied after the profile, so its rule list wins
+        assert!(config.core.disabled_rules.contains(&"MD041".to_string()));
+        assert_eq!(config.core.severity.get("MD051"), Some(&Severity::Error));
+        assert_eq!(config.core.severity.get("MD052"), Some(&Severity::Warning));
+        assert!(config.core.rule_configs.contains_key("MD024"));
+
+        let config = Config {
+            profile: Some("everything".to_string()),
+            ..Config::default()
+        };
+        let err = resolve(config, Path::new(".mdbook-lint.toml")).unwrap_err();
+        assert!(err.to_string().contains("Unknown profile 'everything'"));
+    }
+
+    #[test]
+    fn test_local_files_resolve_relative_to_extending_file() {
+        let temp = TempDir::new().unwrap();
+        write(
+            temp.path(),
+            "shared/base.toml",
+            "extends = [\"relaxed\", \"./links.toml\"]\n\n[MD007]\nindent = 4\n",
+        );
+        write(
+            temp.path(),
+            "shared/links.toml",
+            "[severity]\nMD051 = \"error\"\n",
+        );
+        let path = write(
+            temp.path(),
+            ".mdbook-lint.toml",
+            "extends = [\"./shared/base.toml\"]\n\n[MD013]\nline_length = 100\n",
+        );
+
+        let config = Config::from_file(&path).unwrap();
+
+        assert!(config.core.disabled_rules.contains(&"MD033".to_string()));
+        assert_eq!(config.core.severity.get("MD051"), Some(&Severity::Error));
+        assert!(config.core.rule_configs.contains_key("MD007"));
+        assert!(config.core.rule_configs.contains_key("MD013"));
+    }
+
+    #[test]
+    fn test_cycle_is_an_error() {
+        let temp = TempDir::new().unwrap();
+        write(temp.path(), "a.toml", "extends = [\"./b.toml\"]\n");
+        write(temp.path(), "b.toml", "extends = [\"./a.toml\"]\n");
+
+        let err = Config::from_file(temp.path().join("a.toml")).unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{err}");
+    }
+
+    #[test]
+    fn test_unknown_preset_and_missing_file() {
+        let config = Config {
+            extends: vec!["strictest".to_string()],
+            ..Config::default()
+        };
+        let err = resolve(config, Path::new(".mdbook-lint.toml")).unwrap_err();
+        assert!(err.to_string().contains("Unknown preset 'strictest'"));
+
+        let config = Config {
+            extends: vec!["./missing.toml".to_string()],
+            ..Config::default()
+        };
+        let err = resolve(config, Path::new(".mdbook-lint.toml")).unwrap_err();
+        assert!(err.to_string().contains("not found"));
+    }
+
+    #[cfg(not(feature = "remote-config"))]
+    #[test]
+    fn test_remote_requires_feature() {
+        let config = Config {
+            extends: vec!["https://example.com/lint.toml".to_string()],
+            ..Config::default()
+        };
+        let err = resolve(config, Path::new(".mdbook-lint.toml")).unwrap_err();
+        assert!(err.to_string().contains("remote-config"));
+    }
+}
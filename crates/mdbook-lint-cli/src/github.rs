@@ -0,0 +1,278 @@
+//! GitHub Actions output for `--output github` and `--output github-summary`
+//!
+//! Annotations are printed as workflow commands, one `::group::` per file.
+//! GitHub only shows the first [`ANNOTATION_LIMIT`] annotations of a step, so
+//! errors claim those slots before warnings and notices; the rest are still
+//! printed as plain log lines inside their group.
+//!
+//! The job summary is a Markdown report appended to the file named by
+//! `$GITHUB_STEP_SUMMARY`.
+
+use mdbook_lint_core::{Severity, Violation};
+use std::collections::HashSet;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+/// Annotations GitHub displays per step
+pub const ANNOTATION_LIMIT: usize = 10;
+
+/// Rows listed in the job summary before the rest are elided
+const SUMMARY_ROW_LIMIT: usize = 500;
+
+/// Print violations as GitHub Actions annotations
+pub fn print_annotations(violations_by_file: &[(String, Vec<Violation>)]) {
+    print!("{}", render_annotations(violations_by_file));
+}
+
+/// Render violations as workflow commands grouped by file
+pub fn render_annotations(violations_by_file: &[(String, Vec<Violation>)]) -> String {
+    // Pick the violations that get annotations, most severe first
+    let mut ranked: Vec<(usize, usize, &Violation)> = violations_by_file
+        .iter()
+        .enumerate()
+        .flat_map(|(file, (_, violations))| {
+            violations
+                .iter()
+                .enumerate()
+                .map(move |(index, v)| (file, index, v))
+        })
+        .collect();
+    ranked.sort_by_key(|&(file, index, v)| (std::cmp::Reverse(v.severity), file, index));
+    let annotated: HashSet<(usize, usize)> = ranked
+        .iter()
+        .take(ANNOTATION_LIMIT)
+        .map(|&(file, index, _)| (file, index))
+        .collect();
+
+    let mut out = String::new();
+    for (file, (file_path, violations)) in violations_by_file.iter().enumerate() {
+        if violations.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("::group::{}\n", escape_data(file_path)));
+        let mut sorted: Vec<(usize, &Violation)> = violations.iter().enumerate().collect();
+        sorted.sort_by_key(|(_, v)| (v.line, v.column));
+        for (index, violation) in sorted {
+            if annotated.contains(&(file, index)) {
+                out.push_str(&annotation(file_path, violation));
+            } else {
+                out.push_str(&format!(
+                    "{file_path}:{}:{}: {}[{}]: {}\n",
+                    violation.line,
+                    violation.column,
+                    severity_label(violation.severity),
+                    violation.rule_id,
+                    violation.message
+                ));
+            }
+        }
+        out.push_str("::endgroup::\n");
+    }
+
+    let skipped = ranked.len().saturating_sub(ANNOTATION_LIMIT);
+    if skipped > 0 {
+        out.push_str(&format!(
+            "{skipped} more violation(s) were not annotated; GitHub shows only {ANNOTATION_LIMIT} per step\n"
+        ));
+    }
+    out
+}
+
+/// One `::error`, `::warning`, or `::notice` workflow command
+fn annotation(file_path: &str, violation: &Violation) -> String {
+    let command = match violation.severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "notice",
+    };
+    // A fix that spans several lines marks the extent of the problem
+    let end_line = violation
+        .fix
+        .as_ref()
+        .map(|fix| fix.end.line)
+        .filter(|&end| end >= violation.line)
+        .unwrap_or(violation.line);
+    format!(
+        "::{command} file={},line={},endLine={end_line},col={},title={}::{}\n",
+        escape_property(file_path),
+        violation.line,
+        violation.column,
+        escape_property(&format!("{} {}", violation.rule_id, violation.rule_name)),
+        escape_data(&format!("{}: {}", violation.rule_id, violation.message)),
+    )
+}
+
+/// Append a Markdown job summary to `$GITHUB_STEP_SUMMARY`
+///
+/// Outside GitHub Actions, where the variable is unset, the summary is
+/// printed to stdout instead.
+pub fn write_step_summary(violations_by_file: &[(String, Vec<Violation>)]) -> io::Result<()> {
+    let summary = render_summary(violations_by_file);
+    match std::env::var_os("GITHUB_STEP_SUMMARY") {
+        Some(path) if !path.is_empty() => {
+            let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+            file.write_all(summary.as_bytes())
+        }
+        _ => {
+            print!("{summary}");
+            Ok(())
+        }
+    }
+}
+
+/// Render the Markdown job summary
+pub fn render_summary(violations_by_file: &[(String, Vec<Violation>)]) -> String {
+    let count = |severity: Severity| {
+        violations_by_file
+            .iter()
+            .flat_map(|(_, v)| v)
+            .filter(|v| v.severity == severity)
+            .count()
+    };
+    let total: usize = violations_by_file.iter().map(|(_, v)| v.len()).sum();
+
+    let mut out = String::from("## mdbook-lint\n\n");
+    if total == 0 {
+        out.push_str("No issues found.\n\n");
+        return out;
+    }
+
+    let files = violations_by_file
+        .iter()
+        .filter(|(_, v)| !v.is_empty())
+        .count();
+    out.push_str(&format!(
+        "Found {total} violation(s) in {files} file(s): {} error(s), {} warning(s), {} info.\n\n",
+        count(Severity::Error),
+        count(Severity::Warning),
+        count(Severity::Info)
+    ));
+
+    out.push_str("| Severity | File | Line | Rule | Message |\n");
+    out.push_str("|----------|------|------|------|---------|\n");
+    let mut rows: Vec<(&String, &Violation)> = violations_by_file
+        .iter()
+        .flat_map(|(file, violations)| violations.iter().map(move |v| (file, v)))
+        .collect();
+    rows.sort_by_key(|(file, v)| (*file, v.line, v.column));
+    for (file, violation) in rows.into_iter().take(SUMMARY_ROW_LIMIT) {
+        out.push_str(&format!(
+            "| {} | `{}` | {} | {} | {} |\n",
+            severity_label(violation.severity),
+            file,
+            violation.line,
+            violation.rule_id,
+            escape_cell(&violation.message)
+        ));
+    }
+    if total > SUMMARY_ROW_LIMIT {
+        out.push_str(&format!(
+            "\n{} more violation(s) not listed.\n",
+            total - SUMMARY_ROW_LIMIT
+        ));
+    }
+    out.push('\n');
+    out
+}
+
+fn severity_label(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+/// Escape the message of a workflow command
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a `key=value` property of a workflow command
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Keep a message on one Markdown table row
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule_id: &str, line: usize, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_id.to_lowercase(),
+            message: format!("problem on line {line}"),
+            line,
+            column: 3,
+            severity,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_annotations_are_grouped_by_file() {
+        let out = render_annotations(&[(
+            "src/a,b.md".to_string(),
+            vec![violation("MD001", 4, Severity::Warning)],
+        )]);
+        assert_eq!(
+            out,
+            "::group::src/a,b.md\n\
+             ::warning file=src/a%2Cb.md,line=4,endLine=4,col=3,title=MD001 md001::MD001: problem on line 4\n\
+             ::endgroup::\n"
+        );
+    }
+
+    #[test]
+    fn test_errors_take_the_annotation_slots() {
+        let warnings = (1..=ANNOTATION_LIMIT)
+            .map(|line| violation("MD013", line, Severity::Warning))
+            .collect();
+        let out = render_annotations(&[
+            ("src/a.md".to_string(), warnings),
+            (
+                "src/b.md".to_string(),
+                vec![violation("MD001", 7, Severity::Error)],
+            ),
+        ]);
+
+        assert_eq!(out.matches("::warning ").count(), ANNOTATION_LIMIT - 1);
+        assert!(out.contains("::error file=src/b.md,line=7"));
+        assert!(out.contains(&format!(
+            "src/a.md:{ANNOTATION_LIMIT}:3: warning[MD013]: problem on line {ANNOTATION_LIMIT}\n"
+        )));
+        assert!(
+            out.ends_with(
+                "1 more violation(s) were not annotated; GitHub shows only 10 per step\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let mut error = violation("MD001", 2, Severity::Error);
+        error.message = "a | b".to_string();
+        let out = render_summary(&[
+            ("src/a.md".to_string(), vec![error]),
+            ("src/b.md".to_string(), vec![]),
+        ]);
+        assert!(
+            out.contains("Found 1 violation(s) in 1 file(s): 1 error(s), 0 warning(s), 0 info.")
+        );
+        assert!(out.contains("| error | `src/a.md` | 2 | MD001 | a \\| b |\n"));
+
+        assert_eq!(
+            render_summary(&[]),
+            "## mdbook-lint\n\nNo issues found.\n\n"
+        );
+    }
+}
@@ -21,8 +21,12 @@
 //! ```
 
 pub mod config;
+pub mod extends;
+pub mod migrate;
 pub mod preprocessor;
+pub mod renderer;
 pub mod rustdoc;
+pub mod source_map;
 
 #[cfg(test)]
 mod batch1_rule_config_test;
@@ -35,7 +39,7 @@ mod rule_config_test;
 pub use mdbook_lint_core::*;
 
 // Re-export CLI-specific types
-pub use config::Config;
+pub use config::{ChapterConfig, ChapterContent, Config, FailOn};
 pub use preprocessor::MdBookLint;
 
 /// Current version of mdbook-lint CLI
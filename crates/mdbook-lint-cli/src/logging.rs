@@ -0,0 +1,69 @@
+//! Diagnostic logging for the CLI and preprocessor
+//!
+//! Log events go to stderr so they never mix with lint output or the
+//! preprocessor's JSON on stdout. `RUST_LOG` takes precedence over the
+//! `-v`/`-q` flags when it is set, for example
+//! `RUST_LOG=mdbook_lint=debug mdbook build`.
+
+use tracing_subscriber::EnvFilter;
+
+/// Crates whose log events are shown by the `-v` flags
+const CRATES: &[&str] = &["mdbook_lint", "mdbook_lint_core", "mdbook_lint_rulesets"];
+
+/// Log level for a `-v` count: warnings by default, then info, debug, trace
+fn level(verbosity: u8, quiet: bool) -> &'static str {
+    match (quiet, verbosity) {
+        (true, _) => "error",
+        (false, 0) => "warn",
+        (false, 1) => "info",
+        (false, 2) => "debug",
+        (false, _) => "trace",
+    }
+}
+
+/// Filter directives for the given flags, ignoring `RUST_LOG`
+fn directives(verbosity: u8, quiet: bool) -> String {
+    let level = level(verbosity, quiet);
+    let crates: Vec<String> = CRATES
+        .iter()
+        .map(|krate| format!("{krate}={level}"))
+        .collect();
+    format!("warn,{}", crates.join(","))
+}
+
+/// Install the global subscriber
+///
+/// Calling this more than once is harmless; later calls are ignored.
+pub fn init(verbosity: u8, quiet: bool) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(directives(verbosity, quiet)));
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .with_ansi(anstream::AutoStream::choice(&std::io::stderr()) != anstream::ColorChoice::Never)
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert_eq!(level(0, false), "warn");
+        assert_eq!(level(1, false), "info");
+        assert_eq!(level(2, false), "debug");
+        assert_eq!(level(5, false), "trace");
+        assert_eq!(level(3, true), "error");
+    }
+
+    #[test]
+    fn test_filter_scopes_to_our_crates() {
+        assert_eq!(
+            directives(2, false),
+            "warn,mdbook_lint=debug,mdbook_lint_core=debug,mdbook_lint_rulesets=debug"
+        );
+    }
+}
@@ -0,0 +1,330 @@
+//! Completion support for the LSP server
+//!
+//! Completes three kinds of paths while writing a chapter:
+//!
+//! - link destinations, `[text](` or `[label]: `, with the chapters listed in
+//!   `SUMMARY.md`, relative to the current file
+//! - anchors after `#`, with the headings of the current file or of the
+//!   linked chapter
+//! - `{{#include}}`, `{{#rustdoc_include}}`, and `{{#playground}}` paths,
+//!   with the files and directories next to the current file
+
+use crate::lsp_rename::byte_offset;
+use mdbook::book::{SummaryItem, parse_summary};
+use mdbook_lint_core::Document;
+use std::path::{Component, Path, PathBuf};
+use tower_lsp::lsp_types::{
+    CompletionItem, CompletionItemKind, CompletionTextEdit, Position, Range, TextEdit,
+};
+
+/// Characters that open a completion context
+pub const TRIGGER_CHARACTERS: &[&str] = &["(", "#", "/"];
+
+/// mdBook directives whose argument is a file path
+const PATH_DIRECTIVES: &[&str] = &["include", "rustdoc_include", "playground"];
+
+/// What is being typed at the cursor
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Context<'a> {
+    /// A link destination without a `#` yet
+    Link { partial: &'a str },
+    /// An anchor, after the `#` of a link to `file` (empty for this file)
+    Anchor { file: &'a str, partial: &'a str },
+    /// The path argument of a directive
+    Include { partial: &'a str },
+}
+
+/// Completion items for `position` in `text`, the contents of `path`
+///
+/// `read` returns the contents of other files, so callers can prefer the
+/// editor's unsaved copy over the file on disk.
+pub fn completions(
+    text: &str,
+    path: &Path,
+    position: Position,
+    read: impl Fn(&Path) -> Option<String>,
+) -> Vec<CompletionItem> {
+    let Some(line) = text.lines().nth(position.line as usize) else {
+        return Vec::new();
+    };
+    let prefix = &line[..byte_offset(line, position.character)];
+    let Some(context) = context(prefix) else {
+        return Vec::new();
+    };
+    let dir = path.parent().unwrap_or(Path::new(""));
+    let replace = |partial: &str| Range {
+        start: Position::new(
+            position.line,
+            position.character - partial.encode_utf16().count() as u32,
+        ),
+        end: position,
+    };
+
+    match context {
+        Context::Link { partial } => {
+            let Ok(document) = Document::new(text.to_string(), path.to_path_buf()) else {
+                return Vec::new();
+            };
+            let Some(src_dir) = document.src_dir() else {
+                return Vec::new();
+            };
+            let Some(summary) = read(&src_dir.join("SUMMARY.md")) else {
+                return Vec::new();
+            };
+            chapters(&summary)
+                .into_iter()
+                .filter(|(_, location)| src_dir.join(location) != path)
+                .map(|(title, location)| {
+                    let relative = relative_path(dir, &src_dir.join(&location));
+                    item(relative, title, CompletionItemKind::FILE, replace(partial))
+                })
+                .collect()
+        }
+        Context::Anchor { file, partial } => {
+            let content = if file.is_empty() {
+                Some(text.to_string())
+            } else {
+                let file = match file.strip_suffix(".html") {
+                    Some(stem) => format!("{stem}.md"),
+                    None => file.to_string(),
+                };
+                read(&dir.join(file))
+            };
+            let Some(document) =
+                content.and_then(|content| Document::new(content, path.to_path_buf()).ok())
+            else {
+                return Vec::new();
+            };
+            document
+                .outline()
+                .iter()
+                .map(|heading| {
+                    item(
+                        heading.slug.clone(),
+                        heading.title.clone(),
+                        CompletionItemKind::REFERENCE,
+                        replace(partial),
+                    )
+                })
+                .collect()
+        }
+        Context::Include { partial } => {
+            let (parent, name) = partial.rsplit_once('/').unwrap_or(("", partial));
+            let Ok(entries) = std::fs::read_dir(dir.join(parent)) else {
+                return Vec::new();
+            };
+            let mut items: Vec<CompletionItem> = entries
+                .flatten()
+                .filter_map(|entry| {
+                    let file_name = entry.file_name().to_string_lossy().into_owned();
+                    if file_name.starts_with('.') || entry.path() == path {
+                        return None;
+                    }
+                    let is_dir = entry.file_type().ok()?.is_dir();
+                    let (label, kind) = if is_dir {
+                        (format!("{file_name}/"), CompletionItemKind::FOLDER)
+                    } else {
+                        (file_name, CompletionItemKind::FILE)
+                    };
+                    Some(item(label, String::new(), kind, replace(name)))
+                })
+                .collect();
+            items.sort_by(|a, b| a.label.cmp(&b.label));
+            items
+        }
+    }
+}
+
+/// Find the completion context from the text before the cursor
+fn context(prefix: &str) -> Option<Context<'_>> {
+    if let Some(start) = prefix.rfind("{{#") {
+        let directive = &prefix[start + 3..];
+        if !directive.contains("}}")
+            && let Some((name, argument)) = directive.split_once(char::is_whitespace)
+            && PATH_DIRECTIVES.contains(&name)
+        {
+            let partial = argument.trim_start();
+            if !partial.contains(char::is_whitespace) {
+                return Some(Context::Include { partial });
+            }
+        }
+    }
+
+    let partial = link_destination(prefix)?;
+    Some(match partial.split_once('#') {
+        Some((file, partial)) => Context::Anchor { file, partial },
+        None => Context::Link { partial },
+    })
+}
+
+/// The destination typed so far in an unfinished inline link or reference
+/// definition
+fn link_destination(prefix: &str) -> Option<&str> {
+    if let Some(start) = prefix.rfind("](") {
+        let partial = &prefix[start + 2..];
+        if !partial.contains([')', ' ', '\t']) {
+            return Some(partial.trim_start_matches('<'));
+        }
+    }
+
+    // `[label]: destination`
+    let trimmed = prefix.trim_start_matches([' ', '>']);
+    if trimmed.starts_with('[')
+        && let Some(end) = trimmed.find("]:")
+    {
+        let partial = trimmed[end + 2..].trim_start();
+        if !partial.contains(char::is_whitespace) {
+            return Some(partial.trim_start_matches('<'));
+        }
+    }
+    None
+}
+
+/// Titles and locations of the chapters in a `SUMMARY.md`, in order
+fn chapters(summary: &str) -> Vec<(String, PathBuf)> {
+    fn collect(item: &SummaryItem, out: &mut Vec<(String, PathBuf)>) {
+        let SummaryItem::Link(link) = item else {
+            return;
+        };
+        if let Some(location) = &link.location {
+            out.push((link.name.clone(), location.clone()));
+        }
+        for nested in &link.nested_items {
+            collect(nested, out);
+        }
+    }
+
+    let Ok(summary) = parse_summary(summary) else {
+        return Vec::new();
+    };
+    let mut out = Vec::new();
+    for item in summary
+        .prefix_chapters
+        .iter()
+        .chain(&summary.numbered_chapters)
+        .chain(&summary.suffix_chapters)
+    {
+        collect(item, &mut out);
+    }
+    out
+}
+
+fn item(label: String, detail: String, kind: CompletionItemKind, range: Range) -> CompletionItem {
+    CompletionItem {
+        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+            range,
+            new_text: label.clone(),
+        })),
+        label,
+        detail: (!detail.is_empty()).then_some(detail),
+        kind: Some(kind),
+        ..Default::default()
+    }
+}
+
+/// Path to `to` from the directory `from`, with `/` separators
+fn relative_path(from: &Path, to: &Path) -> String {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(
+        to[common..]
+            .iter()
+            .map(|c| c.as_os_str().to_string_lossy().into_owned()),
+    );
+    parts.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn book() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("guide")).unwrap();
+        fs::create_dir_all(src.join("listings")).unwrap();
+        fs::write(
+            src.join("SUMMARY.md"),
+            "# Summary\n\n[Intro](intro.md)\n\n- [Guide](guide/README.md)\n  - [Setup](guide/setup.md)\n",
+        )
+        .unwrap();
+        fs::write(src.join("intro.md"), "# Intro\n").unwrap();
+        fs::write(src.join("guide/README.md"), "# Guide\n\n## First Steps\n").unwrap();
+        fs::write(src.join("listings/main.rs"), "fn main() {}\n").unwrap();
+        dir
+    }
+
+    fn labels(dir: &TempDir, file: &str, text: &str, character: u32) -> Vec<String> {
+        let path = dir.path().join("src").join(file);
+        let line = text.lines().count().saturating_sub(1) as u32;
+        completions(text, &path, Position::new(line, character), |p| {
+            fs::read_to_string(p).ok()
+        })
+        .into_iter()
+        .map(|item| item.label)
+        .collect()
+    }
+
+    #[test]
+    fn test_chapter_links() {
+        let dir = book();
+        let text = "# Setup\n\nSee [the guide](";
+        assert_eq!(
+            labels(&dir, "guide/setup.md", text, 16),
+            vec!["../intro.md", "README.md"]
+        );
+
+        let definition = "# Setup\n\n[guide]: gu";
+        assert_eq!(labels(&dir, "guide/setup.md", definition, 11).len(), 2);
+    }
+
+    #[test]
+    fn test_anchors() {
+        let dir = book();
+        let text = "# Setup\n\n## Install\n\n[a](#";
+        assert_eq!(
+            labels(&dir, "guide/setup.md", text, 5),
+            vec!["setup", "install"]
+        );
+
+        let other = "# Setup\n\n[a](README.html#fi";
+        let items = completions(
+            other,
+            &dir.path().join("src/guide/setup.md"),
+            Position::new(2, 24),
+            |p| fs::read_to_string(p).ok(),
+        );
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].label, "first-steps");
+        assert_eq!(items[1].detail.as_deref(), Some("First Steps"));
+        let Some(CompletionTextEdit::Edit(edit)) = &items[1].text_edit else {
+            panic!("expected a text edit");
+        };
+        assert_eq!(edit.range.start, Position::new(2, 22));
+    }
+
+    #[test]
+    fn test_include_paths() {
+        let dir = book();
+        let text = "# Intro\n\n{{#include ";
+        assert_eq!(
+            labels(&dir, "intro.md", text, 20),
+            vec!["SUMMARY.md", "guide/", "listings/"]
+        );
+
+        let nested = "# Intro\n\n{{#rustdoc_include listings/";
+        assert_eq!(labels(&dir, "intro.md", nested, 37), vec!["main.rs"]);
+    }
+
+    #[test]
+    fn test_no_context() {
+        let dir = book();
+        assert!(labels(&dir, "intro.md", "# Intro\n\nPlain text (", 21).is_empty());
+        assert!(labels(&dir, "intro.md", "# Intro\n\n[a](b.md) and", 22).is_empty());
+    }
+}
@@ -0,0 +1,173 @@
+//! Workspace configuration for the LSP server, reloaded when it changes
+//!
+//! The server reads the configuration the way the preprocessor does: the
+//! discovered `.mdbook-lint.toml` (or YAML/JSON), then the
+//! `[preprocessor.lint]` section of `book.toml`. A [`ConfigStamp`] records
+//! the modification time and a content hash of each of those files, so the
+//! server can notice an edit and rebuild its engine without a restart.
+
+use crate::config::Config;
+use mdbook_lint_core::Result;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Load the configuration for the workspace at `root`
+///
+/// Returns the configuration and the files it is read from, including ones
+/// that don't exist yet but would be picked up if created.
+pub fn load(root: &Path) -> Result<(Config, Vec<PathBuf>)> {
+    let config_file = Config::discover_config(Some(root));
+    let book_toml = root.join("book.toml");
+
+    let mut config = match &config_file {
+        Some(path) => Config::from_file(path)?,
+        None => Config::default(),
+    };
+    if let Some(book_config) = crate::workspace::book_toml_config(&book_toml)? {
+        config.merge(book_config);
+    }
+
+    let watched = vec![
+        config_file.unwrap_or_else(|| root.join(".mdbook-lint.toml")),
+        book_toml,
+    ];
+    Ok((config, watched))
+}
+
+/// Modification time and content hash of a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FileStamp {
+    modified: Option<SystemTime>,
+    hash: u64,
+}
+
+impl FileStamp {
+    fn read(path: &Path) -> Option<Self> {
+        let content = std::fs::read(path).ok()?;
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        Some(Self {
+            modified: modified(path),
+            hash: hasher.finish(),
+        })
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+/// Fingerprints of the files a configuration was loaded from
+#[derive(Debug, Default)]
+pub struct ConfigStamp {
+    files: Vec<(PathBuf, Option<FileStamp>)>,
+}
+
+impl ConfigStamp {
+    /// Record the current state of `paths`
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let files = paths
+            .into_iter()
+            .map(|path| {
+                let stamp = FileStamp::read(&path);
+                (path, stamp)
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Whether any file was created, deleted, or edited since it was stamped
+    ///
+    /// Files are only read when their modification time moved, and a file
+    /// saved without changes doesn't count.
+    pub fn changed(&mut self) -> bool {
+        let mut changed = false;
+        for (path, stamp) in &mut self.files {
+            match stamp {
+                Some(previous)
+                    if previous.modified.is_some() && modified(path) == previous.modified =>
+                {
+                    continue;
+                }
+                None if !path.exists() => continue,
+                _ => {}
+            }
+            let current = FileStamp::read(path);
+            if current.as_ref().map(|s| s.hash) != stamp.as_ref().map(|s| s.hash) {
+                changed = true;
+            }
+            *stamp = current;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_load_merges_book_toml() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join(".mdbook-lint.toml"),
+            "disabled-rules = [\"MD013\"]\n",
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("book.toml"),
+            "[book]\ntitle = \"Test\"\n\n[preprocessor.lint]\nfail-on-warnings = true\n",
+        )
+        .unwrap();
+
+        let (config, watched) = load(dir.path()).unwrap();
+        assert!(config.fail_on_warnings);
+        assert!(config.core.disabled_rules.contains(&"MD013".to_string()));
+        assert_eq!(
+            watched,
+            vec![
+                dir.path().join(".mdbook-lint.toml"),
+                dir.path().join("book.toml")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stamp_detects_changes() {
+        let dir = TempDir::new().unwrap();
+        let config = dir.path().join(".mdbook-lint.toml");
+        let mut stamp = ConfigStamp::new(vec![config.clone()]);
+        assert!(!stamp.changed());
+
+        // Created
+        fs::write(&config, "fail-on-warnings = true\n").unwrap();
+        assert!(stamp.changed());
+        assert!(!stamp.changed());
+
+        // Saved again with the same contents
+        let touched = SystemTime::now() + std::time::Duration::from_secs(5);
+        fs::File::options()
+            .write(true)
+            .open(&config)
+            .unwrap()
+            .set_modified(touched)
+            .unwrap();
+        assert!(!stamp.changed());
+
+        // Edited, then deleted
+        fs::write(&config, "fail-on-warnings = false\n").unwrap();
+        fs::File::options()
+            .write(true)
+            .open(&config)
+            .unwrap()
+            .set_modified(touched + std::time::Duration::from_secs(5))
+            .unwrap();
+        assert!(stamp.changed());
+        fs::remove_file(&config).unwrap();
+        assert!(stamp.changed());
+        assert!(!stamp.changed());
+    }
+}
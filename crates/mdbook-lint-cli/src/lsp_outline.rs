@@ -0,0 +1,150 @@
+//! Document symbols and folding ranges for the LSP server
+//!
+//! Symbols are the heading outline, nested by level, so editors can show and
+//! navigate the structure of a chapter. Folding ranges cover each heading's
+//! section and each fenced code block.
+
+use comrak::Arena;
+use comrak::nodes::NodeValue;
+use mdbook_lint_core::Document;
+use std::path::Path;
+use tower_lsp::lsp_types::{DocumentSymbol, FoldingRange, Position, Range, SymbolKind};
+
+/// The heading outline of `text` as nested symbols
+pub fn document_symbols(text: &str, path: &Path) -> Vec<DocumentSymbol> {
+    let Ok(document) = Document::new(text.to_string(), path.to_path_buf()) else {
+        return Vec::new();
+    };
+    nested_symbols(&document, None)
+}
+
+/// Symbols for the headings whose parent is `parent`, with their children
+fn nested_symbols(document: &Document, parent: Option<usize>) -> Vec<DocumentSymbol> {
+    document
+        .outline()
+        .iter()
+        .enumerate()
+        .filter(|(_, heading)| heading.parent == parent)
+        .map(|(index, heading)| {
+            let line = heading.line - 1;
+            let end_line = last_content_line(document, line, heading.end_line - 1);
+            let children = nested_symbols(document, Some(index));
+            #[allow(deprecated)]
+            DocumentSymbol {
+                name: heading.title.clone(),
+                detail: Some(format!("H{}", heading.level)),
+                kind: SymbolKind::STRING,
+                tags: None,
+                deprecated: None,
+                range: Range {
+                    start: Position::new(line as u32, 0),
+                    end: Position::new(end_line as u32, line_width(document, end_line)),
+                },
+                selection_range: Range {
+                    start: Position::new(line as u32, 0),
+                    end: Position::new(line as u32, line_width(document, line)),
+                },
+                children: (!children.is_empty()).then_some(children),
+            }
+        })
+        .collect()
+}
+
+/// Folding ranges for the sections and fenced code blocks of `text`
+pub fn folding_ranges(text: &str, path: &Path) -> Vec<FoldingRange> {
+    let Ok(document) = Document::new(text.to_string(), path.to_path_buf()) else {
+        return Vec::new();
+    };
+    let mut ranges = Vec::new();
+
+    for heading in document.outline().iter() {
+        let start = heading.line - 1;
+        let end = last_content_line(&document, start, heading.end_line - 1);
+        if end > start {
+            ranges.push(fold(start, end));
+        }
+    }
+
+    let arena = Arena::new();
+    let ast = document.parse_ast(&arena);
+    let offset = document.frontmatter_ast_offset(ast);
+    for node in ast.descendants() {
+        let data = node.data.borrow();
+        if let NodeValue::CodeBlock(block) = &data.value
+            && block.fenced
+        {
+            let start = data.sourcepos.start.line + offset - 1;
+            let end = data.sourcepos.end.line + offset - 1;
+            if end > start {
+                ranges.push(fold(start, end));
+            }
+        }
+    }
+
+    ranges.sort_by_key(|range| (range.start_line, range.end_line));
+    ranges
+}
+
+fn fold(start: usize, end: usize) -> FoldingRange {
+    FoldingRange {
+        start_line: start as u32,
+        end_line: end as u32,
+        ..Default::default()
+    }
+}
+
+/// The last non-blank 0-based line from `end` back to `start`
+fn last_content_line(document: &Document, start: usize, end: usize) -> usize {
+    (start..=end)
+        .rev()
+        .find(|&line| {
+            document
+                .lines
+                .get(line)
+                .is_some_and(|l| !l.trim().is_empty())
+        })
+        .unwrap_or(start)
+}
+
+/// Length of a 0-based line in UTF-16 code units
+fn line_width(document: &Document, line: usize) -> u32 {
+    document
+        .lines
+        .get(line)
+        .map_or(0, |l| l.encode_utf16().count() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "---\ntitle: Guide\n---\n\n# Guide\n\nIntro.\n\n## Install\n\n```sh\ncargo install mdbook\n```\n\n### From source\n\nBuild it.\n\n## Usage\n\nRun it.\n";
+
+    #[test]
+    fn test_document_symbols() {
+        let symbols = document_symbols(TEXT, Path::new("guide.md"));
+        assert_eq!(symbols.len(), 1);
+        let guide = &symbols[0];
+        assert_eq!(guide.name, "Guide");
+        assert_eq!(guide.range.start.line, 4);
+        assert_eq!(guide.range.end, Position::new(20, 7));
+
+        let sections = guide.children.as_ref().unwrap();
+        let names: Vec<&str> = sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Install", "Usage"]);
+        assert_eq!(sections[0].range.end.line, 16);
+        let nested = sections[0].children.as_ref().unwrap();
+        assert_eq!(nested[0].name, "From source");
+        assert_eq!(nested[0].detail.as_deref(), Some("H3"));
+        assert!(sections[1].children.is_none());
+    }
+
+    #[test]
+    fn test_folding_ranges() {
+        let ranges: Vec<(u32, u32)> = folding_ranges(TEXT, Path::new("guide.md"))
+            .iter()
+            .map(|r| (r.start_line, r.end_line))
+            .collect();
+        assert_eq!(ranges, vec![(4, 20), (8, 16), (10, 12), (14, 16), (18, 20)]);
+    }
+}
@@ -0,0 +1,548 @@
+//! Rename support for the LSP server
+//!
+//! Two things can be renamed: reference-link labels, whose definitions and
+//! uses are rewritten within the file, and headings, whose `#anchor` links
+//! are rewritten in the file and in any other file that links to it. Text
+//! in code blocks, code spans, and frontmatter is left alone.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::outline::mdbook_slug;
+use std::ops::Range as ByteRange;
+use std::path::{Component, Path, PathBuf};
+use tower_lsp::lsp_types::{Position, Range, TextEdit};
+
+/// What a rename at a position changes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameTarget {
+    /// A reference-link label
+    Label {
+        /// Label as written at the position
+        text: String,
+        /// Normalized label used for matching
+        label: String,
+        range: Range,
+    },
+    /// A heading and the anchor mdBook gives it
+    Heading {
+        /// Heading text as written, without the `#` markers
+        text: String,
+        /// Current anchor
+        slug: String,
+        /// Numbering mdBook appends to a repeated heading's anchor, like `-1`
+        suffix: String,
+        range: Range,
+    },
+}
+
+impl RenameTarget {
+    /// Range of the text the rename replaces at the requested position
+    pub fn range(&self) -> Range {
+        match self {
+            Self::Label { range, .. } | Self::Heading { range, .. } => *range,
+        }
+    }
+
+    /// Current text at [`Self::range`]
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Label { text, .. } | Self::Heading { text, .. } => text,
+        }
+    }
+
+    /// The anchor a heading gets when renamed to `new_name`, or `None` for
+    /// a label
+    pub fn renamed_slug(&self, new_name: &str) -> Option<String> {
+        match self {
+            Self::Heading { suffix, .. } => Some(format!("{}{suffix}", mdbook_slug(new_name))),
+            Self::Label { .. } => None,
+        }
+    }
+
+    /// Check that `new_name` can replace the target
+    pub fn validate(&self, new_name: &str) -> Result<(), String> {
+        if new_name.trim().is_empty() {
+            return Err("New name must not be empty".to_string());
+        }
+        if new_name.contains('\n') {
+            return Err("New name must be a single line".to_string());
+        }
+        if matches!(self, Self::Label { .. }) && new_name.contains(['[', ']']) {
+            return Err("Reference labels cannot contain brackets".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// How a bracketed label is used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefKind {
+    /// `[label]: url`
+    Definition,
+    /// `[text][label]`
+    Full,
+    /// `[label][]`, where `insert` is the offset inside the empty brackets
+    Collapsed { insert: usize },
+    /// `[label]`, where `insert` is the offset after the closing bracket
+    Shortcut { insert: usize },
+}
+
+/// A reference label on a line
+#[derive(Debug, Clone)]
+struct LabelRef {
+    kind: RefKind,
+    /// Byte range of the label within the line
+    label: ByteRange<usize>,
+}
+
+/// The lines of a document with the facts needed to find renameable text
+struct Source<'a> {
+    document: Document,
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Source<'a> {
+    fn new(text: &'a str, path: &Path) -> Option<Self> {
+        let document = Document::new(text.to_string(), path.to_path_buf()).ok()?;
+        Some(Self {
+            document,
+            lines: text.lines().collect(),
+        })
+    }
+
+    /// Whether a line holds prose, rather than code or frontmatter
+    fn is_prose(&self, line: usize) -> bool {
+        !self.document.index().is_code_block_line(line + 1)
+            && !self.document.is_frontmatter_line(line + 1)
+    }
+
+    /// Brackets on a 0-based line that are not in code spans
+    fn brackets(&self, line: usize) -> Vec<ByteRange<usize>> {
+        let text = self.lines[line];
+        let bytes = text.as_bytes();
+        let mut brackets = Vec::new();
+        let mut pos = 0;
+        while let Some(offset) = text[pos..].find('[') {
+            let start = pos + offset;
+            pos = start + 1;
+            if start > 0 && bytes[start - 1] == b'\\' {
+                continue;
+            }
+            let Some(close) = text[start + 1..].find([']', '[']) else {
+                break;
+            };
+            let end = start + 1 + close;
+            if bytes[end] == b'[' {
+                continue;
+            }
+            if !self.document.index().is_in_code(line + 1, start) {
+                brackets.push(start..end + 1);
+            }
+            pos = end + 1;
+        }
+        brackets
+    }
+
+    /// Reference labels and the byte ranges of link destinations on a
+    /// 0-based line
+    fn scan(&self, line: usize) -> (Vec<LabelRef>, Vec<ByteRange<usize>>) {
+        let mut labels = Vec::new();
+        let mut destinations = Vec::new();
+        if !self.is_prose(line) {
+            return (labels, destinations);
+        }
+
+        let text = self.lines[line];
+        let brackets = self.brackets(line);
+        let inner = |b: &ByteRange<usize>| b.start + 1..b.end - 1;
+        let mut i = 0;
+        while i < brackets.len() {
+            let bracket = &brackets[i];
+            let rest = &text[bracket.end..];
+            if rest.starts_with('(') {
+                if let Some(range) = destination(text, bracket.end + 1) {
+                    destinations.push(range);
+                }
+                i += 1;
+            } else if let Some(next) = brackets.get(i + 1)
+                && next.start == bracket.end
+            {
+                let (kind, label) = if next.len() == 2 {
+                    let insert = next.start + 1;
+                    (RefKind::Collapsed { insert }, inner(bracket))
+                } else {
+                    (RefKind::Full, inner(next))
+                };
+                labels.push(LabelRef { kind, label });
+                i += 2;
+            } else if rest.starts_with(':') && is_definition_prefix(&text[..bracket.start]) {
+                labels.push(LabelRef {
+                    kind: RefKind::Definition,
+                    label: inner(bracket),
+                });
+                if let Some(range) = destination(text, bracket.end + 1) {
+                    destinations.push(range);
+                }
+                i += 1;
+            } else {
+                labels.push(LabelRef {
+                    kind: RefKind::Shortcut {
+                        insert: bracket.end,
+                    },
+                    label: inner(bracket),
+                });
+                i += 1;
+            }
+        }
+        labels.retain(|r| !r.label.is_empty() && !text[r.label.clone()].trim().is_empty());
+        (labels, destinations)
+    }
+
+    /// Normalized labels that have a definition
+    fn defined_labels(&self) -> Vec<String> {
+        (0..self.lines.len())
+            .flat_map(|line| {
+                self.scan(line)
+                    .0
+                    .into_iter()
+                    .filter(|r| r.kind == RefKind::Definition)
+                    .map(move |r| normalize(&self.lines[line][r.label]))
+            })
+            .collect()
+    }
+
+    fn range(&self, line: usize, bytes: ByteRange<usize>) -> Range {
+        let text = self.lines[line];
+        Range {
+            start: Position::new(line as u32, utf16_len(&text[..bytes.start])),
+            end: Position::new(line as u32, utf16_len(&text[..bytes.end])),
+        }
+    }
+
+    fn edit(&self, line: usize, bytes: ByteRange<usize>, new_text: String) -> TextEdit {
+        TextEdit {
+            range: self.range(line, bytes),
+            new_text,
+        }
+    }
+}
+
+/// Find what a rename at `position` would change
+pub fn target_at(text: &str, path: &Path, position: Position) -> Option<RenameTarget> {
+    let source = Source::new(text, path)?;
+    let line = position.line as usize;
+    let line_text = *source.lines.get(line)?;
+    let offset = byte_offset(line_text, position.character);
+
+    if let Some(heading) = source
+        .document
+        .outline()
+        .iter()
+        .find(|h| h.line == line + 1)
+    {
+        let bytes = heading_text_range(line_text);
+        let suffix = heading
+            .slug
+            .strip_prefix(&mdbook_slug(&heading.title))
+            .unwrap_or("")
+            .to_string();
+        return Some(RenameTarget::Heading {
+            text: line_text[bytes.clone()].to_string(),
+            slug: heading.slug.clone(),
+            suffix,
+            range: source.range(line, bytes),
+        });
+    }
+
+    let found = source
+        .scan(line)
+        .0
+        .into_iter()
+        .find(|r| r.label.start <= offset && offset <= r.label.end)?;
+    let label = normalize(&line_text[found.label.clone()]);
+    let is_reference = match found.kind {
+        RefKind::Definition | RefKind::Full => true,
+        RefKind::Collapsed { .. } | RefKind::Shortcut { .. } => {
+            source.defined_labels().contains(&label)
+        }
+    };
+    is_reference.then(|| RenameTarget::Label {
+        text: line_text[found.label.clone()].to_string(),
+        label,
+        range: source.range(line, found.label),
+    })
+}
+
+/// Edits to `text` that rename `target` to `new_name`
+///
+/// For a heading this covers the heading and the links to its anchor in the
+/// same file; use [`anchor_edits`] for other files.
+pub fn rename_edits(
+    text: &str,
+    path: &Path,
+    target: &RenameTarget,
+    new_name: &str,
+) -> Vec<TextEdit> {
+    let Some(source) = Source::new(text, path) else {
+        return Vec::new();
+    };
+
+    match target {
+        RenameTarget::Label { label, .. } => {
+            let mut edits = Vec::new();
+            for line in 0..source.lines.len() {
+                for found in source.scan(line).0 {
+                    if normalize(&source.lines[line][found.label.clone()]) != *label {
+                        continue;
+                    }
+                    edits.push(match found.kind {
+                        RefKind::Definition | RefKind::Full => {
+                            source.edit(line, found.label, new_name.to_string())
+                        }
+                        RefKind::Collapsed { insert } => {
+                            source.edit(line, insert..insert, new_name.to_string())
+                        }
+                        RefKind::Shortcut { insert } => {
+                            source.edit(line, insert..insert, format!("[{new_name}]"))
+                        }
+                    });
+                }
+            }
+            edits
+        }
+        RenameTarget::Heading { slug, range, .. } => {
+            let mut edits = vec![TextEdit {
+                range: *range,
+                new_text: new_name.to_string(),
+            }];
+            if let Some(new_slug) = target.renamed_slug(new_name) {
+                edits.extend(source_anchor_edits(&source, path, path, slug, &new_slug));
+            }
+            edits
+        }
+    }
+}
+
+/// Edits to `text`, the contents of `path`, that point its links to
+/// `target#old_slug` at `target#new_slug` instead
+pub fn anchor_edits(
+    text: &str,
+    path: &Path,
+    target: &Path,
+    old_slug: &str,
+    new_slug: &str,
+) -> Vec<TextEdit> {
+    Source::new(text, path)
+        .map(|source| source_anchor_edits(&source, path, target, old_slug, new_slug))
+        .unwrap_or_default()
+}
+
+fn source_anchor_edits(
+    source: &Source,
+    path: &Path,
+    target: &Path,
+    old_slug: &str,
+    new_slug: &str,
+) -> Vec<TextEdit> {
+    let mut edits = Vec::new();
+    for line in 0..source.lines.len() {
+        for range in source.scan(line).1 {
+            let dest = &source.lines[line][range.clone()];
+            let Some((file, fragment)) = dest.split_once('#') else {
+                continue;
+            };
+            if fragment == old_slug && links_to(path, file, target) {
+                let start = range.start + file.len() + 1;
+                edits.push(source.edit(line, start..range.end, new_slug.to_string()));
+            }
+        }
+    }
+    edits
+}
+
+/// Whether a link to `file` written in `from` points at `target`
+///
+/// An empty `file` is the linking document itself. mdBook's `.html` output
+/// names are mapped back to their `.md` sources.
+fn links_to(from: &Path, file: &str, target: &Path) -> bool {
+    if file.is_empty() {
+        return normalize_path(from) == normalize_path(target);
+    }
+    if file.contains("://") || file.starts_with("mailto:") {
+        return false;
+    }
+    let file = match file.strip_suffix(".html") {
+        Some(stem) => format!("{stem}.md"),
+        None => file.to_string(),
+    };
+    let base = from.parent().unwrap_or(Path::new(""));
+    normalize_path(&base.join(file)) == normalize_path(target)
+}
+
+/// Resolve `.` and `..` components without touching the filesystem
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
+
+/// Byte range of a link destination starting at or after `start`
+fn destination(text: &str, start: usize) -> Option<ByteRange<usize>> {
+    let rest = &text[start..];
+    let mut begin = start + (rest.len() - rest.trim_start().len());
+    let angle = text[begin..].starts_with('<');
+    if angle {
+        begin += 1;
+    }
+    let end = text[begin..]
+        .find(|c: char| {
+            if angle {
+                c == '>'
+            } else {
+                c.is_whitespace() || c == ')'
+            }
+        })
+        .map_or(text.len(), |i| begin + i);
+    (end > begin).then_some(begin..end)
+}
+
+/// Whether the text before a bracket allows a reference definition: up to
+/// three spaces, possibly inside blockquotes
+fn is_definition_prefix(prefix: &str) -> bool {
+    let rest = prefix.trim_start_matches(['>', ' ']);
+    rest.is_empty() && prefix.len() - prefix.trim_start_matches(' ').len() <= 3
+}
+
+/// Byte range of a heading's text, without ATX markers
+fn heading_text_range(line: &str) -> ByteRange<usize> {
+    let trimmed = line.trim_start();
+    let mut start = line.len() - trimmed.len();
+    if trimmed.starts_with('#') {
+        start += trimmed.len() - trimmed.trim_start_matches('#').len();
+        start += line[start..].len() - line[start..].trim_start().len();
+    }
+
+    let mut end = line.trim_end().len().max(start);
+    let closing = line[start..end].trim_end_matches('#');
+    if closing.len() < end - start && (closing.is_empty() || closing.ends_with([' ', '\t'])) {
+        end = start + closing.trim_end().len();
+    }
+    start..end
+}
+
+/// Labels match case-insensitively, with runs of whitespace collapsed
+fn normalize(label: &str) -> String {
+    label
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+fn utf16_len(text: &str) -> u32 {
+    text.encode_utf16().count() as u32
+}
+
+/// Byte offset of a UTF-16 column within a line
+pub(crate) fn byte_offset(line: &str, character: u32) -> usize {
+    let mut units = 0;
+    for (offset, ch) in line.char_indices() {
+        if units >= character {
+            return offset;
+        }
+        units += ch.len_utf16() as u32;
+    }
+    line.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(text: &str, mut edits: Vec<TextEdit>) -> String {
+        let mut lines: Vec<String> = text.lines().map(str::to_string).collect();
+        edits.sort_by_key(|e| std::cmp::Reverse((e.range.start.line, e.range.start.character)));
+        for edit in edits {
+            let line = &mut lines[edit.range.start.line as usize];
+            let start = byte_offset(line, edit.range.start.character);
+            let end = byte_offset(line, edit.range.end.character);
+            line.replace_range(start..end, &edit.new_text);
+        }
+        lines.join("\n") + "\n"
+    }
+
+    fn rename(text: &str, line: u32, character: u32, new_name: &str) -> String {
+        let path = Path::new("/book/src/guide.md");
+        let target = target_at(text, path, Position::new(line, character)).unwrap();
+        apply(text, rename_edits(text, path, &target, new_name))
+    }
+
+    #[test]
+    fn test_rename_label() {
+        let text = "# Guide\n\nSee [the docs][Docs], [docs][], and [docs].\n\n`[docs]` and [docs](x.md).\n\n[docs]: https://example.com\n";
+        let renamed = "# Guide\n\nSee [the docs][manual], [docs][manual], and [docs][manual].\n\n`[docs]` and [docs](x.md).\n\n[manual]: https://example.com\n";
+        assert_eq!(rename(text, 6, 2, "manual"), renamed);
+        assert_eq!(rename(text, 2, 17, "manual"), renamed);
+        assert_eq!(rename(text, 2, 38, "manual"), renamed);
+    }
+
+    #[test]
+    fn test_undefined_shortcut_is_not_a_target() {
+        let path = Path::new("guide.md");
+        let text = "# Guide\n\n- [x] done\n";
+        assert_eq!(target_at(text, path, Position::new(2, 3)), None);
+    }
+
+    #[test]
+    fn test_rename_heading_and_anchors() {
+        let text = "---\ntitle: Guide\n---\n\n# Guide\n\n## Set up `mdbook` ##\n\nSee [setup](#set-up-mdbook) and [x](./guide.md#set-up-mdbook).\n\n[ref]: #set-up-mdbook\n";
+        let target = target_at(text, Path::new("guide.md"), Position::new(6, 4)).unwrap();
+        assert_eq!(target.text(), "Set up `mdbook`");
+        assert_eq!(
+            rename(text, 6, 4, "Installation"),
+            "---\ntitle: Guide\n---\n\n# Guide\n\n## Installation ##\n\nSee [setup](#installation) and [x](./guide.md#installation).\n\n[ref]: #installation\n"
+        );
+    }
+
+    #[test]
+    fn test_repeated_heading_keeps_number() {
+        let text = "# Example\n\n# Example\n\n[second](#example-1)\n";
+        assert_eq!(
+            rename(text, 2, 3, "Sample"),
+            "# Example\n\n# Sample\n\n[second](#sample-1)\n"
+        );
+    }
+
+    #[test]
+    fn test_anchor_edits_in_other_files() {
+        let target = Path::new("/book/src/guide/setup.md");
+        let text = "[a](guide/setup.md#install) [b](guide/setup.html#install) [c](other.md#install) [d](#install)\n";
+        let edits = anchor_edits(
+            text,
+            Path::new("/book/src/intro.md"),
+            target,
+            "install",
+            "setup",
+        );
+        assert_eq!(
+            apply(text, edits),
+            "[a](guide/setup.md#setup) [b](guide/setup.html#setup) [c](other.md#install) [d](#install)\n"
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let text = "[a]\n\n[a]: x\n";
+        let target = target_at(text, Path::new("a.md"), Position::new(0, 1)).unwrap();
+        assert!(target.validate("b").is_ok());
+        assert!(target.validate("b]").is_err());
+        assert!(target.validate(" ").is_err());
+    }
+}
@@ -2,31 +2,71 @@
 //!
 //! This module provides a Language Server Protocol server for real-time markdown
 //! linting in editors. It supports both general markdown linting and mdBook-specific
-//! enhancements.
+//! enhancements. It also provides the heading outline and folding ranges,
+//! renames reference-link labels and headings, and completes chapter links,
+//! heading anchors, and include paths. Edits to the workspace configuration
+//! take effect without restarting the server.
 //!
 //! This module is only available when the `lsp` feature is enabled.
 
 use crate::config::Config;
-use mdbook_lint_core::{Document, LintEngine, PluginRegistry, Severity, Violation};
+use crate::lsp_completion;
+use crate::lsp_config::{self, ConfigStamp};
+use crate::lsp_outline;
+use crate::lsp_rename::{self, RenameTarget};
+use crate::lsp_transport::{self, Transport};
+use mdbook_lint_core::{
+    CancellationToken, Document, LintEngine, PluginRegistry, Severity, Violation,
+};
 #[cfg(feature = "adr")]
 use mdbook_lint_rulesets::AdrRuleProvider;
+#[cfg(feature = "changelog")]
+use mdbook_lint_rulesets::ChangelogRuleProvider;
+#[cfg(feature = "frontmatter")]
+use mdbook_lint_rulesets::FrontMatterRuleProvider;
+#[cfg(feature = "readme")]
+use mdbook_lint_rulesets::ReadmeRuleProvider;
+#[cfg(feature = "rfc")]
+use mdbook_lint_rulesets::RfcRuleProvider;
+#[cfg(feature = "spelling")]
+use mdbook_lint_rulesets::SpellingRuleProvider;
 use mdbook_lint_rulesets::{MdBookRuleProvider, StandardRuleProvider};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tower_lsp::jsonrpc::Result;
 use tower_lsp::lsp_types::*;
-use tower_lsp::{Client, LanguageServer, LspService, Server};
+use tower_lsp::{Client, LanguageServer};
 
 /// The main LSP server implementation
 pub struct MdBookLintServer {
     client: Client,
-    engine: LintEngine,
+    engine: tokio::sync::RwLock<LintEngine>,
     document_map: tokio::sync::RwLock<HashMap<Url, String>>,
     config: tokio::sync::RwLock<Config>,
+    /// Files the configuration came from, once loaded from a workspace
+    config_stamp: tokio::sync::Mutex<Option<ConfigStamp>>,
+    /// Workspace root, searched for links to a renamed heading
+    root: tokio::sync::RwLock<Option<PathBuf>>,
+    /// Cancels the lint running for each document when newer text arrives
+    in_flight: std::sync::Mutex<HashMap<Url, CancellationToken>>,
 }
 
 impl MdBookLintServer {
     pub fn new(client: Client) -> Self {
+        let config = Config::default();
+        Self {
+            client,
+            engine: tokio::sync::RwLock::new(Self::create_engine(&config)),
+            document_map: tokio::sync::RwLock::new(HashMap::new()),
+            config: tokio::sync::RwLock::new(config),
+            config_stamp: tokio::sync::Mutex::new(None),
+            root: tokio::sync::RwLock::new(None),
+            in_flight: std::sync::Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Create an engine with rules built from `config`
+    fn create_engine(config: &Config) -> LintEngine {
         let mut registry = PluginRegistry::new();
         registry
             .register_provider(Box::new(StandardRuleProvider))
@@ -38,40 +78,186 @@ impl MdBookLintServer {
         registry
             .register_provider(Box::new(AdrRuleProvider))
             .expect("Failed to register ADR rules");
-        let engine = registry.create_engine().expect("Failed to create engine");
+        #[cfg(feature = "rfc")]
+        registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .expect("Failed to register RFC rules");
+        #[cfg(feature = "changelog")]
+        registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .expect("Failed to register changelog rules");
+        #[cfg(feature = "readme")]
+        registry
+            .register_provider(Box::new(ReadmeRuleProvider))
+            .expect("Failed to register README rules");
+        #[cfg(feature = "frontmatter")]
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .expect("Failed to register frontmatter rules");
+        #[cfg(feature = "spelling")]
+        registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .expect("Failed to register spelling rules");
+        registry
+            .create_engine_with_config(Some(&config.core))
+            .expect("Failed to create engine")
+    }
 
-        Self {
-            client,
-            engine,
-            document_map: tokio::sync::RwLock::new(HashMap::new()),
-            config: tokio::sync::RwLock::new(Config::default()),
+    /// Use `config`, rebuilding the engine so rule options take effect
+    async fn apply_config(&self, config: Config) {
+        *self.engine.write().await = Self::create_engine(&config);
+        *self.config.write().await = config;
+    }
+
+    /// Reload the configuration if one of its files changed
+    ///
+    /// Open documents are linted again with the new configuration. A
+    /// configuration that fails to load is reported and the previous one
+    /// kept.
+    async fn reload_config_if_changed(&self) {
+        let mut stamp = self.config_stamp.lock().await;
+        if !stamp.as_mut().is_some_and(ConfigStamp::changed) {
+            return;
+        }
+        let Some(root) = self.root.read().await.clone() else {
+            return;
+        };
+
+        match lsp_config::load(&root) {
+            Ok((config, watched)) => {
+                *stamp = Some(ConfigStamp::new(watched));
+                drop(stamp);
+                self.apply_config(config).await;
+                self.client
+                    .log_message(MessageType::INFO, "Reloaded mdbook-lint configuration")
+                    .await;
+            }
+            Err(e) => {
+                drop(stamp);
+                self.client
+                    .log_message(
+                        MessageType::WARNING,
+                        format!("Keeping the previous mdbook-lint configuration: {e}"),
+                    )
+                    .await;
+                return;
+            }
+        }
+
+        let documents: Vec<(Url, String)> = self
+            .document_map
+            .read()
+            .await
+            .iter()
+            .map(|(uri, text)| (uri.clone(), text.clone()))
+            .collect();
+        for (uri, text) in documents {
+            if let Some(diagnostics) = self.lint_document(&uri, &text).await {
+                self.client
+                    .publish_diagnostics(uri, diagnostics, None)
+                    .await;
+            }
         }
     }
 
     /// Lint a document and convert violations to LSP diagnostics
-    async fn lint_document(&self, uri: &Url, text: &str) -> Vec<Diagnostic> {
+    ///
+    /// Starting a lint cancels the previous one for the same document.
+    /// Returns `None` if this lint is itself cancelled by a newer one, so
+    /// stale diagnostics are never published.
+    async fn lint_document(&self, uri: &Url, text: &str) -> Option<Vec<Diagnostic>> {
         let path = uri
             .to_file_path()
             .unwrap_or_else(|_| PathBuf::from("untitled.md"));
 
+        let token = CancellationToken::new();
+        self.cancel_lint(uri, Some(token.clone()));
+
+        let engine = self.engine.read().await;
+        let config = self.config.read().await;
         let document = match Document::new(text.to_string(), path) {
-            Ok(doc) => doc,
-            Err(_) => return Vec::new(),
+            Ok(doc) => doc.with_extensions(config.core.markdown),
+            Err(_) => return Some(Vec::new()),
         };
+        let result = engine.lint_document_cancellable(&document, &config.core, &token);
+        if token.is_cancelled() {
+            return None;
+        }
+        let violations = result.unwrap_or_default();
+
+        Some(
+            violations
+                .into_iter()
+                .map(|violation| self.violation_to_diagnostic(violation))
+                .collect(),
+        )
+    }
 
-        let config = self.config.read().await;
-        let violations = match self
-            .engine
-            .lint_document_with_config(&document, &config.core)
-        {
-            Ok(violations) => violations,
-            Err(_) => return Vec::new(),
+    /// The rename target at a position in an open document, with its text
+    /// and path
+    async fn rename_target(
+        &self,
+        uri: &Url,
+        position: Position,
+    ) -> Option<(RenameTarget, String, PathBuf)> {
+        let text = self.document_map.read().await.get(uri)?.clone();
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from("untitled.md"));
+        let target = lsp_rename::target_at(&text, &path, position)?;
+        Some((target, text, path))
+    }
+
+    /// Edits to other workspace files that link to a renamed heading
+    ///
+    /// Open documents are read from the editor's copy, others from disk.
+    async fn workspace_anchor_edits(
+        &self,
+        path: &Path,
+        old_slug: &str,
+        new_slug: &str,
+    ) -> HashMap<Url, Vec<TextEdit>> {
+        let mut changes = HashMap::new();
+        let Some(root) = self.root.read().await.clone() else {
+            return changes;
         };
+        let mut files = Vec::new();
+        if crate::collect_markdown_files(&root, &mut files).is_err() {
+            return changes;
+        }
 
-        violations
-            .into_iter()
-            .map(|violation| self.violation_to_diagnostic(violation))
-            .collect()
+        let documents = self.document_map.read().await;
+        for file in files.into_iter().filter(|file| file != path) {
+            let Ok(uri) = Url::from_file_path(&file) else {
+                continue;
+            };
+            let text = match documents.get(&uri) {
+                Some(text) => text.clone(),
+                None => match std::fs::read_to_string(&file) {
+                    Ok(text) => text,
+                    Err(_) => continue,
+                },
+            };
+            let edits = lsp_rename::anchor_edits(&text, &file, path, old_slug, new_slug);
+            if !edits.is_empty() {
+                changes.insert(uri, edits);
+            }
+        }
+        changes
+    }
+
+    /// Cancel the lint in progress for `uri`, registering `next` in its place
+    fn cancel_lint(&self, uri: &Url, next: Option<CancellationToken>) {
+        let Ok(mut in_flight) = self.in_flight.lock() else {
+            return;
+        };
+        let previous = match next {
+            Some(token) => in_flight.insert(uri.clone(), token),
+            None => in_flight.remove(uri),
+        };
+        if let Some(previous) = previous {
+            previous.cancel();
+        }
     }
 
     /// Convert a mdbook-lint violation to an LSP diagnostic
@@ -113,18 +299,15 @@ impl LanguageServer for MdBookLintServer {
         // Detect if we're in an mdBook project and load config
         let (is_mdbook_project, config_loaded) = if let Some(root_uri) = &params.root_uri {
             if let Ok(root_path) = root_uri.to_file_path() {
+                *self.root.write().await = Some(root_path.clone());
                 let is_mdbook =
                     root_path.join("book.toml").exists() || root_path.join("SUMMARY.md").exists();
 
-                // Try to load .mdbook-lint.toml config if we're in an mdBook project
+                // Load the config if we're in an mdBook project, and watch
+                // its files for changes
                 let mut config_loaded = false;
-                if is_mdbook {
-                    let config_path = root_path.join(".mdbook-lint.toml");
-                    if config_path.exists()
-                        && let Ok(config_content) = std::fs::read_to_string(&config_path)
-                        && let Ok(config) = Config::from_toml_str(&config_content)
-                    {
-                        *self.config.write().await = config;
+                if is_mdbook && let Ok((config, watched)) = lsp_config::load(&root_path) {
+                    if let Some(config_path) = watched.first().filter(|path| path.exists()) {
                         config_loaded = true;
                         self.client
                             .log_message(
@@ -133,6 +316,8 @@ impl LanguageServer for MdBookLintServer {
                             )
                             .await;
                     }
+                    *self.config_stamp.lock().await = Some(ConfigStamp::new(watched));
+                    self.apply_config(config).await;
                 }
                 (is_mdbook, config_loaded)
             } else {
@@ -163,6 +348,21 @@ impl LanguageServer for MdBookLintServer {
                         work_done_progress_options: WorkDoneProgressOptions::default(),
                     },
                 )),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(
+                        lsp_completion::TRIGGER_CHARACTERS
+                            .iter()
+                            .map(|c| c.to_string())
+                            .collect(),
+                    ),
+                    ..Default::default()
+                }),
+                document_symbol_provider: Some(OneOf::Left(true)),
+                folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+                rename_provider: Some(OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })),
                 ..Default::default()
             },
             server_info: Some(ServerInfo {
@@ -185,6 +385,7 @@ impl LanguageServer for MdBookLintServer {
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         let uri = params.text_document.uri;
         let text = params.text_document.text;
+        self.reload_config_if_changed().await;
 
         // Store document content
         self.document_map
@@ -193,16 +394,18 @@ impl LanguageServer for MdBookLintServer {
             .insert(uri.clone(), text.clone());
 
         // Lint and publish diagnostics
-        let diagnostics = self.lint_document(&uri, &text).await;
-
-        self.client
-            .publish_diagnostics(uri, diagnostics, None)
-            .await;
+        if let Some(diagnostics) = self.lint_document(&uri, &text).await {
+            self.client
+                .publish_diagnostics(uri, diagnostics, None)
+                .await;
+        }
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = params.text_document.uri;
 
+        self.reload_config_if_changed().await;
+
         // Get the full new text (we use FULL sync mode)
         if let Some(change) = params.content_changes.into_iter().next() {
             let text = change.text;
@@ -213,22 +416,24 @@ impl LanguageServer for MdBookLintServer {
                 .await
                 .insert(uri.clone(), text.clone());
 
-            // Lint and publish diagnostics
-            let diagnostics = self.lint_document(&uri, &text).await;
-
-            self.client
-                .publish_diagnostics(uri, diagnostics, None)
-                .await;
+            // Lint and publish diagnostics, unless newer text has arrived
+            if let Some(diagnostics) = self.lint_document(&uri, &text).await {
+                self.client
+                    .publish_diagnostics(uri, diagnostics, None)
+                    .await;
+            }
         }
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
-        // Re-lint on save to ensure consistency
+        // Re-lint on save to ensure consistency; saving the config file
+        // from the editor reloads it here
         let uri = params.text_document.uri;
+        self.reload_config_if_changed().await;
 
-        if let Some(text) = self.document_map.read().await.get(&uri) {
-            let diagnostics = self.lint_document(&uri, text).await;
-
+        if let Some(text) = self.document_map.read().await.get(&uri)
+            && let Some(diagnostics) = self.lint_document(&uri, text).await
+        {
             self.client
                 .publish_diagnostics(uri, diagnostics, None)
                 .await;
@@ -236,7 +441,9 @@ impl LanguageServer for MdBookLintServer {
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
-        // Remove from document map and clear diagnostics
+        // Stop any lint in progress, remove from document map, and clear
+        // diagnostics
+        self.cancel_lint(&params.text_document.uri, None);
         self.document_map
             .write()
             .await
@@ -247,6 +454,100 @@ impl LanguageServer for MdBookLintServer {
             .await;
     }
 
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let uri = params.text_document.uri;
+        let Some(text) = self.document_map.read().await.get(&uri).cloned() else {
+            return Ok(None);
+        };
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from("untitled.md"));
+        Ok(Some(DocumentSymbolResponse::Nested(
+            lsp_outline::document_symbols(&text, &path),
+        )))
+    }
+
+    async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        let uri = params.text_document.uri;
+        let Some(text) = self.document_map.read().await.get(&uri).cloned() else {
+            return Ok(None);
+        };
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from("untitled.md"));
+        Ok(Some(lsp_outline::folding_ranges(&text, &path)))
+    }
+
+    async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        let uri = params.text_document_position.text_document.uri;
+        let documents = self.document_map.read().await;
+        let Some(text) = documents.get(&uri) else {
+            return Ok(None);
+        };
+        let path = uri
+            .to_file_path()
+            .unwrap_or_else(|_| PathBuf::from("untitled.md"));
+
+        // Prefer the editor's copy of other open files
+        let read = |file: &Path| {
+            Url::from_file_path(file)
+                .ok()
+                .and_then(|uri| documents.get(&uri).cloned())
+                .or_else(|| std::fs::read_to_string(file).ok())
+        };
+        let items =
+            lsp_completion::completions(text, &path, params.text_document_position.position, read);
+        Ok((!items.is_empty()).then_some(CompletionResponse::Array(items)))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let target = self
+            .rename_target(&params.text_document.uri, params.position)
+            .await;
+        Ok(target.map(
+            |(target, _, _)| PrepareRenameResponse::RangeWithPlaceholder {
+                range: target.range(),
+                placeholder: target.text().to_string(),
+            },
+        ))
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let uri = params.text_document_position.text_document.uri;
+        let Some((target, text, path)) = self
+            .rename_target(&uri, params.text_document_position.position)
+            .await
+        else {
+            return Ok(None);
+        };
+        target
+            .validate(&params.new_name)
+            .map_err(tower_lsp::jsonrpc::Error::invalid_params)?;
+
+        let mut changes = HashMap::new();
+        if let RenameTarget::Heading { slug, .. } = &target
+            && let Some(new_slug) = target.renamed_slug(&params.new_name)
+            && new_slug != *slug
+        {
+            changes = self.workspace_anchor_edits(&path, slug, &new_slug).await;
+        }
+        changes.insert(
+            uri,
+            lsp_rename::rename_edits(&text, &path, &target, &params.new_name),
+        );
+
+        Ok(Some(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        }))
+    }
+
     async fn diagnostic(
         &self,
         params: DocumentDiagnosticParams,
@@ -254,7 +555,7 @@ impl LanguageServer for MdBookLintServer {
         let uri = params.text_document.uri;
 
         if let Some(text) = self.document_map.read().await.get(&uri) {
-            let diagnostics = self.lint_document(&uri, text).await;
+            let diagnostics = self.lint_document(&uri, text).await.unwrap_or_default();
 
             Ok(DocumentDiagnosticReportResult::Report(
                 DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
@@ -280,24 +581,26 @@ impl LanguageServer for MdBookLintServer {
 }
 
 /// Run the LSP server
-pub async fn run_lsp_server(_stdio: bool, port: Option<u16>) -> mdbook_lint_core::Result<()> {
+///
+/// Serves one client over stdio, or any number of clients on a local TCP
+/// `port` until interrupted; see [`crate::lsp_transport`].
+pub async fn run_lsp_server(
+    _stdio: bool,
+    port: Option<u16>,
+    transport: Transport,
+) -> mdbook_lint_core::Result<()> {
     if let Some(port) = port {
-        // TCP mode
         let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{port}")).await?;
-        eprintln!("mdbook-lint LSP server listening on port {port}");
-
-        let (stream, _) = listener.accept().await?;
-        let (read, write) = tokio::io::split(stream);
-
-        let (service, socket) = LspService::new(MdBookLintServer::new);
-        Server::new(read, write, socket).serve(service).await;
+        let scheme = match transport {
+            Transport::Tcp => "tcp",
+            #[cfg(feature = "lsp-websocket")]
+            Transport::WebSocket => "ws",
+        };
+        eprintln!("mdbook-lint LSP server listening on {scheme}://127.0.0.1:{port}");
+        lsp_transport::serve(listener, transport, lsp_transport::ctrl_c()).await?;
     } else {
         // stdio mode (default)
-        let stdin = tokio::io::stdin();
-        let stdout = tokio::io::stdout();
-
-        let (service, socket) = LspService::new(MdBookLintServer::new);
-        Server::new(stdin, stdout, socket).serve(service).await;
+        lsp_transport::serve_stream(tokio::io::stdin(), tokio::io::stdout()).await;
     }
 
     Ok(())
@@ -0,0 +1,258 @@
+//! Network transports for the LSP server
+//!
+//! Over TCP, every accepted connection gets its own server session, so
+//! several editors can share one `mdbook-lint lsp --port` process. With the
+//! `lsp-websocket` feature the same listener can speak WebSocket instead,
+//! for browser-based editors: each text or binary message carries one
+//! JSON-RPC message, without the `Content-Length` header used on streams.
+//!
+//! The listener stops accepting connections on Ctrl-C, gives open sessions a
+//! moment to finish, and then closes them.
+
+use crate::lsp_server::MdBookLintServer;
+use std::future::Future;
+use std::time::Duration;
+use tokio::io::{AsyncRead, AsyncWrite};
+use tokio::net::TcpListener;
+use tokio::task::JoinSet;
+use tower_lsp::{LspService, Server};
+
+/// How long open sessions get to finish after shutdown is requested
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(5);
+
+/// Which protocol connections on the listener speak
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transport {
+    /// LSP base protocol: `Content-Length` framed messages
+    Tcp,
+    /// One JSON-RPC message per WebSocket message
+    #[cfg(feature = "lsp-websocket")]
+    WebSocket,
+}
+
+/// Accept clients on `listener` until `shutdown` completes
+pub async fn serve(
+    listener: TcpListener,
+    transport: Transport,
+    shutdown: impl Future<Output = ()>,
+) -> std::io::Result<()> {
+    let mut sessions = JoinSet::new();
+    tokio::pin!(shutdown);
+
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let (stream, peer) = match accepted {
+                    Ok(accepted) => accepted,
+                    Err(e) => {
+                        // Usually out of file descriptors; back off instead of spinning
+                        eprintln!("mdbook-lint LSP server failed to accept a connection: {e}");
+                        tokio::time::sleep(Duration::from_millis(100)).await;
+                        continue;
+                    }
+                };
+                eprintln!("mdbook-lint LSP client connected from {peer}");
+                sessions.spawn(async move {
+                    let result: std::io::Result<()> = match transport {
+                        Transport::Tcp => {
+                            let (read, write) = tokio::io::split(stream);
+                            serve_stream(read, write).await;
+                            Ok(())
+                        }
+                        #[cfg(feature = "lsp-websocket")]
+                        Transport::WebSocket => websocket::serve(stream).await,
+                    };
+                    match result {
+                        Ok(()) => eprintln!("mdbook-lint LSP client {peer} disconnected"),
+                        Err(e) => eprintln!("mdbook-lint LSP client {peer} failed: {e}"),
+                    }
+                });
+            }
+            // Reap finished sessions so the set doesn't grow without bound
+            Some(_) = sessions.join_next(), if !sessions.is_empty() => {}
+            () = &mut shutdown => break,
+        }
+    }
+
+    drop(listener);
+    if !sessions.is_empty() {
+        eprintln!(
+            "mdbook-lint LSP server shutting down, waiting for {} client(s)",
+            sessions.len()
+        );
+        let drain = async { while sessions.join_next().await.is_some() {} };
+        if tokio::time::timeout(SHUTDOWN_GRACE, drain).await.is_err() {
+            sessions.abort_all();
+        }
+    }
+    Ok(())
+}
+
+/// Run one server session over a byte stream
+pub async fn serve_stream(read: impl AsyncRead + Unpin, write: impl AsyncWrite) {
+    let (service, socket) = LspService::new(MdBookLintServer::new);
+    Server::new(read, write, socket).serve(service).await;
+}
+
+/// Resolves when the process receives Ctrl-C
+pub async fn ctrl_c() {
+    if tokio::signal::ctrl_c().await.is_err() {
+        // No signal handler available; run until the process is killed
+        std::future::pending::<()>().await;
+    }
+}
+
+#[cfg(feature = "lsp-websocket")]
+mod websocket {
+    //! Bridge between WebSocket messages and the LSP base protocol
+
+    use futures_util::{SinkExt, StreamExt};
+    use std::io;
+    use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::TcpStream;
+    use tokio_tungstenite::tungstenite::Message;
+
+    /// Buffer between the WebSocket and the server session
+    const BUFFER: usize = 64 * 1024;
+
+    /// Run one server session over a WebSocket connection
+    pub async fn serve(stream: TcpStream) -> io::Result<()> {
+        let socket = tokio_tungstenite::accept_async(stream)
+            .await
+            .map_err(io::Error::other)?;
+        let (mut sink, mut messages) = socket.split();
+
+        let (bridge, session) = tokio::io::duplex(BUFFER);
+        let (session_read, session_write) = tokio::io::split(session);
+        let (bridge_read, mut bridge_write) = tokio::io::split(bridge);
+
+        let incoming = async move {
+            while let Some(message) = messages.next().await {
+                let body = match message.map_err(io::Error::other)? {
+                    Message::Text(text) => text.as_bytes().to_vec(),
+                    Message::Binary(bytes) => bytes.to_vec(),
+                    Message::Close(_) => break,
+                    _ => continue,
+                };
+                let header = format!("Content-Length: {}\r\n\r\n", body.len());
+                bridge_write.write_all(header.as_bytes()).await?;
+                bridge_write.write_all(&body).await?;
+            }
+            bridge_write.shutdown().await
+        };
+
+        let outgoing = async move {
+            let mut reader = BufReader::new(bridge_read);
+            while let Some(body) = read_message(&mut reader).await? {
+                let text = String::from_utf8(body)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                sink.send(Message::text(text))
+                    .await
+                    .map_err(io::Error::other)?;
+            }
+            // The session ended; a closed socket is not an error here
+            let _ = sink.close().await;
+            Ok::<_, io::Error>(())
+        };
+
+        let (incoming, (), outgoing) = tokio::join!(
+            incoming,
+            super::serve_stream(session_read, session_write),
+            outgoing
+        );
+        incoming.and(outgoing)
+    }
+
+    /// Read one `Content-Length` framed message, or `None` at end of stream
+    pub async fn read_message(
+        reader: &mut (impl AsyncBufRead + Unpin),
+    ) -> io::Result<Option<Vec<u8>>> {
+        let mut length = None;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            if reader.read_line(&mut line).await? == 0 {
+                return Ok(None);
+            }
+            let header = line.trim_end();
+            if header.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = header.split_once(':')
+                && name.eq_ignore_ascii_case("content-length")
+            {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+
+        let length = length.ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "message without Content-Length")
+        })?;
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).await?;
+        Ok(Some(body))
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[tokio::test]
+        async fn test_read_message() {
+            let input = b"Content-Length: 2\r\nContent-Type: application/json\r\n\r\n{}Content-Length: 4\r\n\r\nnull";
+            let mut reader = BufReader::new(&input[..]);
+            assert_eq!(read_message(&mut reader).await.unwrap().unwrap(), b"{}");
+            assert_eq!(read_message(&mut reader).await.unwrap().unwrap(), b"null");
+            assert!(read_message(&mut reader).await.unwrap().is_none());
+
+            let mut missing = BufReader::new(&b"X: 1\r\n\r\n{}"[..]);
+            assert!(read_message(&mut missing).await.is_err());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpStream;
+
+    /// Send `initialize` and return the raw response
+    async fn initialize(port: u16) -> String {
+        let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+        let body = r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{"capabilities":{}}}"#;
+        let request = format!("Content-Length: {}\r\n\r\n{body}", body.len());
+        stream.write_all(request.as_bytes()).await.unwrap();
+
+        let mut response = Vec::new();
+        let mut buf = [0; 4096];
+        while !String::from_utf8_lossy(&response).contains("\"serverInfo\"") {
+            let n = stream.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before the response");
+            response.extend_from_slice(&buf[..n]);
+        }
+        String::from_utf8(response).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serves_several_clients_and_shuts_down() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(serve(listener, Transport::Tcp, async {
+            let _ = stopped.await;
+        }));
+
+        let (first, second) = tokio::join!(initialize(port), initialize(port));
+        assert!(first.contains("mdbook-lint"));
+        assert!(second.contains("mdbook-lint"));
+
+        stop.send(()).unwrap();
+        tokio::time::timeout(SHUTDOWN_GRACE * 2, server)
+            .await
+            .expect("server should stop after shutdown")
+            .unwrap()
+            .unwrap();
+        assert!(TcpStream::connect(("127.0.0.1", port)).await.is_err());
+    }
+}
@@ -1,30 +1,79 @@
+mod batch;
+mod check_code;
+mod compare;
 mod config;
+mod exit_code;
+mod extends;
+mod github;
+mod logging;
+#[cfg(feature = "lsp")]
+mod lsp_completion;
+#[cfg(feature = "lsp")]
+mod lsp_config;
+#[cfg(feature = "lsp")]
+mod lsp_outline;
+#[cfg(feature = "lsp")]
+mod lsp_rename;
 #[cfg(feature = "lsp")]
 mod lsp_server;
+#[cfg(feature = "lsp")]
+mod lsp_transport;
+mod memory;
+mod migrate;
 mod output;
 mod preprocessor;
+mod renderer;
 mod rustdoc;
+mod schema;
+#[cfg(unix)]
+mod serve;
+mod source_map;
+mod stats;
+mod suppressions;
+mod timing;
+#[cfg(feature = "content")]
+mod todos;
+mod undo;
+mod upgrade;
+mod wizard;
+mod workspace;
 
-use config::Config;
+use config::{Config, MalformedMarkdownAction};
+use timing::TimingOptions;
 
 use clap::{Parser, Subcommand, ValueEnum, builder::styling};
 use mdbook_lint_core::{
-    Document, PluginRegistry, Severity,
+    CancellationToken, CustomRule, Document, FixEngine, LintEngine, MdBookLintError,
+    PluginRegistry, Severity, SourceEncoding, Suppressions, Violation,
+    edit_distance::levenshtein,
     error::Result,
-    rule::{RuleCategory, RuleStability},
+    rule::{RuleCategory, RuleOption, RuleStability},
 };
 #[cfg(feature = "adr")]
 use mdbook_lint_rulesets::AdrRuleProvider;
+#[cfg(feature = "changelog")]
+use mdbook_lint_rulesets::ChangelogRuleProvider;
 #[cfg(feature = "content")]
 use mdbook_lint_rulesets::ContentRuleProvider;
+#[cfg(feature = "frontmatter")]
+use mdbook_lint_rulesets::FrontMatterRuleProvider;
+#[cfg(feature = "readme")]
+use mdbook_lint_rulesets::ReadmeRuleProvider;
+#[cfg(feature = "rfc")]
+use mdbook_lint_rulesets::RfcRuleProvider;
+#[cfg(feature = "spelling")]
+use mdbook_lint_rulesets::SpellingRuleProvider;
+#[cfg(feature = "adr")]
+use mdbook_lint_rulesets::adr::format::path_in_adr_dir;
 use mdbook_lint_rulesets::{MdBookRuleProvider, StandardRuleProvider};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 use std::process;
-use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, mpsc};
+use std::time::Duration;
 use tabled::{Table, Tabled, settings::Style};
 
 // Cargo-style help coloring
@@ -41,12 +90,12 @@ const STYLES: styling::Styles = styling::Styles::styled()
 #[command(about = "A markdown linter for mdBook projects")]
 #[command(styles = STYLES)]
 struct Cli {
-    /// Use verbose output
-    #[arg(short, long, global = true)]
-    verbose: bool,
+    /// Use verbose output (-v for status messages and info logs, -vv for debug logs)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
 
-    /// Do not print informational messages
-    #[arg(short, long, global = true)]
+    /// Only report errors; hide warnings, the summary, and informational messages
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
     quiet: bool,
 
     #[command(subcommand)]
@@ -58,19 +107,36 @@ enum Commands {
     /// Run as mdBook preprocessor (reads from stdin, writes to stdout)
     Preprocessor,
 
+    /// Run as mdBook renderer for `[output.lint]`, failing the build after
+    /// rendering with one report for the whole book
+    Render,
+
     /// Lint markdown files directly
     Lint {
         /// Markdown files or directories to lint
         files: Vec<String>,
+        /// Lint every book (each `book.toml`) under the given paths with its
+        /// own nearest configuration
+        #[arg(long)]
+        workspace: bool,
         /// Path to configuration file (TOML, YAML, or JSON)
         #[arg(short, long)]
         config: Option<String>,
+        /// Start from a rule profile: minimal, recommended, or strict
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
         /// Use only standard rules (MD001-MD059), exclude mdBook rules
         #[arg(long)]
         standard_only: bool,
         /// Use only mdBook rules (MDBOOK001-004), exclude standard rules
         #[arg(long)]
         mdbook_only: bool,
+        /// Also run the ADR rules (ADR001-ADR018) on every file
+        #[arg(long)]
+        adr: bool,
+        /// Use only the ADR rules, for a directory of decision records
+        #[arg(long)]
+        adr_only: bool,
         /// Fail on warnings (in addition to errors)
         #[arg(long)]
         fail_on_warnings: bool,
@@ -89,9 +155,15 @@ enum Commands {
         /// Preview fixes without applying them (can be used with --fix or --fix-unsafe)
         #[arg(long)]
         dry_run: bool,
+        /// Apply fixes only from these rules (comma-separated, e.g., MD009,MD047); implies --fix
+        #[arg(long, value_delimiter = ',', value_name = "RULES")]
+        fix_rules: Option<Vec<String>>,
         /// Disable backup file creation when fixing
         #[arg(long)]
         no_backup: bool,
+        /// Re-lint fixed files and refuse to write any whose fixes did not settle
+        #[arg(long)]
+        verify_fixes: bool,
         /// Disable specific rules (comma-separated list, e.g., MD001,MD002)
         #[arg(long, value_delimiter = ',')]
         disable: Option<Vec<String>>,
@@ -101,30 +173,74 @@ enum Commands {
         /// Control colored output (auto, always, never)
         #[arg(long, value_enum, default_value = "auto")]
         color: ColorChoice,
+        /// Report the time spent per rule and per file on stderr
+        #[arg(long)]
+        timing: bool,
+        /// Write per-rule and per-file timing as JSON to this file
+        #[arg(long, value_name = "FILE")]
+        timing_json: Option<PathBuf>,
+        /// Number of slowest rules and files to show with --timing
+        #[arg(long, default_value_t = 10)]
+        timing_top: usize,
+        /// Give up on a file whose rules take longer than this many seconds
+        #[arg(long, value_name = "SECONDS")]
+        timeout_per_file: Option<u64>,
+        /// Lint stdin (`-`) as if it were this file, for path-dependent rules
+        #[arg(long, value_name = "PATH")]
+        stdin_filepath: Option<PathBuf>,
+        /// Lint documents sent as newline-delimited JSON on stdin, answering
+        /// each with a line of JSON on stdout
+        #[arg(
+            long,
+            conflicts_with_all = ["files", "workspace", "stdin_filepath", "fix", "fix_unsafe", "fix_rules", "dry_run", "verify_fixes", "output", "timing", "timing_json"]
+        )]
+        stdin_batch: bool,
     },
 
     /// Automatically fix issues in markdown files (shorthand for `lint --fix`)
     Fix {
         /// Markdown files or directories to fix
         files: Vec<String>,
+        /// Fix every book (each `book.toml`) under the given paths with its
+        /// own nearest configuration
+        #[arg(long)]
+        workspace: bool,
         /// Path to configuration file (TOML, YAML, or JSON)
         #[arg(short, long)]
         config: Option<String>,
+        /// Start from a rule profile: minimal, recommended, or strict
+        #[arg(long, value_name = "NAME")]
+        profile: Option<String>,
         /// Use only standard rules (MD001-MD059), exclude mdBook rules
         #[arg(long)]
         standard_only: bool,
         /// Use only mdBook rules (MDBOOK001-004), exclude standard rules
         #[arg(long)]
         mdbook_only: bool,
+        /// Also run the ADR rules (ADR001-ADR018) on every file
+        #[arg(long)]
+        adr: bool,
+        /// Use only the ADR rules, for a directory of decision records
+        #[arg(long)]
+        adr_only: bool,
         /// Apply all fixes including potentially unsafe ones
         #[arg(long, name = "unsafe")]
         fix_unsafe: bool,
         /// Preview fixes without applying them
         #[arg(long)]
         dry_run: bool,
+        /// Apply fixes only from these rules (comma-separated, e.g., MD009,MD047)
+        #[arg(long = "rules", value_delimiter = ',', value_name = "RULES")]
+        fix_rules: Option<Vec<String>>,
+        /// Restore the files changed by the most recent fix run
+        #[arg(long, conflicts_with_all = ["workspace", "unsafe", "dry_run", "fix_rules", "verify_fixes"])]
+        undo: bool,
         /// Disable backup file creation when fixing
         #[arg(long)]
         no_backup: bool,
+        /// Re-lint fixed files and refuse to write any whose fixes did not settle
+        #[arg(long)]
+        verify_fixes: bool,
         /// Disable specific rules (comma-separated list, e.g., MD001,MD002)
         #[arg(long, value_delimiter = ',')]
         disable: Option<Vec<String>>,
@@ -167,6 +283,12 @@ enum Commands {
         config: PathBuf,
     },
 
+    /// Work with configuration files
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+
     /// Generate default configuration file
     Init {
         /// Output format for configuration
@@ -178,6 +300,123 @@ enum Commands {
         /// Include all available rules in generated config
         #[arg(long)]
         include_all: bool,
+        /// Scan the book and choose a preset and rules to disable interactively
+        #[arg(short, long, conflicts_with = "include_all")]
+        interactive: bool,
+    },
+
+    /// Convert a markdownlint config file into an mdbook-lint config
+    Migrate {
+        /// markdownlint config to convert (.markdownlint.json, .jsonc, .yaml)
+        input: PathBuf,
+        /// Output file path (defaults to .mdbook-lint.toml)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+        /// Overwrite the output file if it exists
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Move the config's ruleset-version to this release, enabling newer rules
+    UpgradeConfig {
+        /// Configuration file to update (discovered when omitted)
+        #[arg(short, long)]
+        config: Option<PathBuf>,
+    },
+
+    /// Lint with another tool too and report where the results differ
+    Compare {
+        /// Markdown files or directories to compare on
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Tool to compare with
+        #[arg(long, value_enum, default_value = "markdownlint")]
+        against: CompareTool,
+        /// The markdownlint-cli executable to run
+        #[arg(long, value_name = "PROGRAM", default_value = "markdownlint")]
+        markdownlint: String,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Output format for the report
+        #[arg(short, long, value_enum, default_value = "default")]
+        format: StatsFormat,
+        /// List every finding only one tool made
+        #[arg(long)]
+        differences: bool,
+    },
+
+    /// Summarize violations by rule, directory, and severity
+    Stats {
+        /// Markdown files or directories to summarize
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Output format for the report
+        #[arg(short, long, value_enum, default_value = "default")]
+        format: StatsFormat,
+    },
+
+    /// List TODO, FIXME, and placeholder markers, grouped by file
+    #[cfg(feature = "content")]
+    Todos {
+        /// Markdown files or directories to scan
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Output format for the list
+        #[arg(short, long, value_enum, default_value = "default")]
+        format: TodosFormat,
+        /// Exit with status 1 when any marker is found
+        #[arg(long)]
+        deny_todos: bool,
+    },
+
+    /// List inline suppression comments and their reasons, grouped by file
+    Suppressions {
+        /// Markdown files or directories to scan
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Output format for the list
+        #[arg(short, long, value_enum, default_value = "default")]
+        format: SuppressionsFormat,
+    },
+
+    /// Run a persistent lint daemon answering JSON-RPC requests on a Unix
+    /// socket (not available on Windows)
+    Serve {
+        /// Unix socket to listen on
+        #[arg(long, value_name = "PATH")]
+        socket: PathBuf,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+    },
+
+    /// Type-check Rust code blocks with rustc
+    CheckCode {
+        /// Markdown files or directories to check
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Edition for blocks without an `editionXXXX` attribute
+        #[arg(long, default_value = "2021")]
+        edition: String,
+        /// The rustc executable to run [default: $RUSTC, then rustc]
+        #[arg(long)]
+        rustc: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value = "default")]
+        output: OutputFormat,
     },
 
     /// Check if this preprocessor supports a renderer
@@ -192,9 +431,14 @@ enum Commands {
         /// Use stdio for communication (default)
         #[arg(long)]
         stdio: bool,
-        /// TCP port to listen on (alternative to stdio)
+        /// TCP port to listen on (alternative to stdio); accepts any number
+        /// of clients until interrupted
         #[arg(long, conflicts_with = "stdio")]
         port: Option<u16>,
+        /// Speak WebSocket on `--port`, for browser-based editors (requires
+        /// the `lsp-websocket` feature)
+        #[arg(long, requires = "port")]
+        websocket: bool,
     },
 
     /// Lint markdown in Rust documentation comments (//!)
@@ -220,6 +464,36 @@ enum Commands {
         #[arg(long, value_enum, default_value = "auto")]
         color: ColorChoice,
     },
+
+    /// Print per-rule timing for a book to find slow rules
+    #[command(hide = true)]
+    Bench {
+        /// Markdown files or directories to benchmark
+        #[arg(default_value = ".")]
+        paths: Vec<String>,
+        /// Path to configuration file (TOML, YAML, or JSON)
+        #[arg(short, long)]
+        config: Option<String>,
+        /// Number of times to lint every file
+        #[arg(short = 'n', long, default_value_t = 3)]
+        iterations: usize,
+        /// Only show the N slowest rules
+        #[arg(long)]
+        top: Option<usize>,
+        /// Fail if any rule takes longer than this many milliseconds per pass
+        #[arg(long)]
+        max_rule_ms: Option<f64>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print a JSON Schema for configuration files, for editor validation
+    Schema {
+        /// Output file path (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
 }
 
 #[derive(ValueEnum, Clone, PartialEq, Debug)]
@@ -238,8 +512,10 @@ enum OutputFormat {
     Default,
     /// JSON format for machine processing
     Json,
-    /// GitHub Actions format
+    /// GitHub Actions annotations, grouped by file
     Github,
+    /// Markdown job summary appended to $GITHUB_STEP_SUMMARY
+    GithubSummary,
 }
 
 #[derive(ValueEnum, Clone, PartialEq, Debug)]
@@ -260,6 +536,39 @@ enum RulesFormat {
     Json,
 }
 
+#[derive(ValueEnum, Clone, PartialEq, Debug)]
+enum StatsFormat {
+    /// Tables for the terminal
+    Default,
+    /// JSON format for machine processing
+    Json,
+    /// Markdown for pasting into issues
+    Markdown,
+}
+
+#[derive(ValueEnum, Clone, Copy, PartialEq, Debug)]
+enum CompareTool {
+    /// markdownlint-cli (`markdownlint --json`)
+    Markdownlint,
+}
+
+#[cfg(feature = "content")]
+#[derive(Clone, Debug, ValueEnum)]
+enum TodosFormat {
+    /// Markers grouped by file
+    Default,
+    /// JSON format for machine processing
+    Json,
+}
+
+#[derive(Clone, Debug, ValueEnum)]
+enum SuppressionsFormat {
+    /// Comments grouped by file
+    Default,
+    /// JSON format for machine processing
+    Json,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct JsonRuleProvider {
     provider_id: String,
@@ -280,6 +589,20 @@ struct JsonRule {
     replacement: Option<String>,
     introduced_in: Option<String>,
     can_fix: bool,
+    url: Option<String>,
+    options: Vec<JsonRuleOption>,
+}
+
+/// A configuration option in the JSON rules output
+#[derive(Serialize, Deserialize, Debug)]
+struct JsonRuleOption {
+    name: String,
+    #[serde(rename = "type")]
+    kind: String,
+    default: Option<serde_json::Value>,
+    description: String,
+    /// JSON Schema for the value, with any allowed values or bounds
+    schema: serde_json::Value,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -319,6 +642,20 @@ impl From<&RuleCategory> for JsonRuleCategory {
     }
 }
 
+impl From<&RuleOption> for JsonRuleOption {
+    fn from(option: &RuleOption) -> Self {
+        JsonRuleOption {
+            name: option.name.to_string(),
+            kind: option.kind.type_name().to_string(),
+            default: option
+                .default_value()
+                .and_then(|default| serde_json::to_value(default).ok()),
+            description: option.description.to_string(),
+            schema: option.kind.json_schema(),
+        }
+    }
+}
+
 impl From<&RuleStability> for JsonRuleStability {
     fn from(stability: &RuleStability) -> Self {
         match stability {
@@ -359,14 +696,25 @@ fn truncate_string(s: &str, max_len: usize) -> String {
 /// Known subcommands that should not trigger smart detection
 const KNOWN_SUBCOMMANDS: &[&str] = &[
     "preprocessor",
+    "render",
     "lint",
     "fix",
     "rules",
     "check",
+    "config",
     "init",
+    "migrate",
+    "upgrade-config",
+    "compare",
+    "stats",
+    "todos",
+    "suppressions",
+    "serve",
+    "check-code",
     "supports",
     "lsp",
     "rustdoc",
+    "bench",
     "help",
     "--help",
     "-h",
@@ -378,19 +726,36 @@ const KNOWN_SUBCOMMANDS: &[&str] = &[
 const LINT_FLAGS: &[&str] = &[
     "--fix",
     "--fix-unsafe",
+    "--fix-rules",
+    "--verify-fixes",
+    "--workspace",
     "--dry-run",
     "--no-backup",
     "--config",
     "-c",
+    "--profile",
     "--standard-only",
     "--mdbook-only",
+    "--adr",
+    "--adr-only",
     "--fail-on-warnings",
     "--markdownlint-compatible",
     "--output",
     "--disable",
     "--enable",
+    "--timing",
+    "--timing-json",
+    "--timeout-per-file",
 ];
 
+/// Check if an argument is one of the global `-v`/`-q` flags
+fn is_global_flag(arg: &str) -> bool {
+    matches!(arg, "--verbose" | "--quiet")
+        || arg
+            .strip_prefix('-')
+            .is_some_and(|flags| !flags.is_empty() && flags.chars().all(|c| matches!(c, 'v' | 'q')))
+}
+
 /// Check if an argument looks like a lint target (file path, directory, or glob pattern)
 fn looks_like_lint_target(arg: &str) -> bool {
     // Skip if it starts with a dash (it's a flag)
@@ -428,10 +793,15 @@ fn should_infer_lint_subcommand(args: &[String]) -> bool {
     let args: Vec<&str> = args.iter().skip(1).map(|s| s.as_str()).collect();
 
     if args.is_empty() {
-        return false; // No args = preprocessor mode
+        return false; // No args = preprocessor or renderer mode
     }
 
-    let first_arg = args[0];
+    // Global flags such as `-vv` or `--quiet` may come before the subcommand
+    let first_arg = args
+        .iter()
+        .find(|arg| !is_global_flag(arg))
+        .copied()
+        .unwrap_or(args[0]);
 
     // If first arg is a known subcommand, don't infer
     if KNOWN_SUBCOMMANDS.contains(&first_arg.to_lowercase().as_str()) {
@@ -472,24 +842,50 @@ fn main() {
     let args = maybe_insert_lint_subcommand(args);
 
     let cli = Cli::parse_from(args);
+    logging::init(cli.verbose, cli.quiet);
+
+    // The panic hook has already printed the panic message
+    let result = std::panic::catch_unwind(|| run(cli))
+        .unwrap_or_else(|_| process::exit(exit_code::INTERNAL_ERROR));
 
-    let result = match cli.command {
+    if let Err(e) = result {
+        eprintln!("Error: {e}");
+        process::exit(exit_code::for_error(&e));
+    }
+}
+
+/// Run the subcommand selected on the command line
+fn run(cli: Cli) -> Result<()> {
+    match cli.command {
         Some(Commands::Preprocessor) => run_preprocessor_mode(),
+        Some(Commands::Render) => run_renderer_mode(),
         Some(Commands::Lint {
             files,
+            workspace,
             config,
+            profile,
             standard_only,
             mdbook_only,
+            adr,
+            adr_only,
             fail_on_warnings,
             markdownlint_compatible,
             output,
             fix,
             fix_unsafe,
             dry_run,
+            fix_rules,
             no_backup,
+            verify_fixes,
             disable,
             enable,
             color,
+            timing,
+            timing_json,
+            timing_top,
+            timeout_per_file,
+            stdin_filepath,
+            stdin_batch,
         }) => {
             // Set up color choice before running
             match color {
@@ -497,32 +893,54 @@ fn main() {
                 ColorChoice::Never => anstream::ColorChoice::Never.write_global(),
                 ColorChoice::Auto => anstream::ColorChoice::Auto.write_global(),
             }
+            let timing = (timing || timing_json.is_some()).then_some(TimingOptions {
+                print: timing,
+                json: timing_json,
+                top: timing_top,
+            });
             run_cli_mode(
                 &files,
+                workspace,
                 config.as_deref(),
+                profile.as_deref(),
                 standard_only,
                 mdbook_only,
+                adr,
+                adr_only,
                 fail_on_warnings,
                 markdownlint_compatible,
                 output,
                 fix,
                 fix_unsafe,
                 dry_run,
+                fix_rules.as_ref(),
                 !no_backup,
+                verify_fixes,
                 disable.as_ref(),
                 enable.as_ref(),
-                cli.verbose,
+                stdin_filepath.as_deref(),
+                stdin_batch,
+                timing.as_ref(),
+                timeout_per_file.map(Duration::from_secs),
+                cli.verbose > 0,
                 cli.quiet,
             )
         }
         Some(Commands::Fix {
             files,
+            workspace,
             config,
+            profile,
             standard_only,
             mdbook_only,
+            adr,
+            adr_only,
             fix_unsafe,
             dry_run,
+            fix_rules,
+            undo,
             no_backup,
+            verify_fixes,
             disable,
             enable,
             color,
@@ -533,22 +951,35 @@ fn main() {
                 ColorChoice::Never => anstream::ColorChoice::Never.write_global(),
                 ColorChoice::Auto => anstream::ColorChoice::Auto.write_global(),
             }
+            if undo {
+                return run_undo(files.first().map(String::as_str), cli.quiet);
+            }
             // Fix subcommand is equivalent to lint --fix
             run_cli_mode(
                 &files,
+                workspace,
                 config.as_deref(),
+                profile.as_deref(),
                 standard_only,
                 mdbook_only,
+                adr,
+                adr_only,
                 false,                 // fail_on_warnings
                 false,                 // markdownlint_compatible
                 OutputFormat::Default, // output format
                 true,                  // fix is always true for this subcommand
                 fix_unsafe,
                 dry_run,
+                fix_rules.as_ref(),
                 !no_backup,
+                verify_fixes,
                 disable.as_ref(),
                 enable.as_ref(),
-                cli.verbose,
+                None,  // stdin_filepath
+                false, // stdin_batch
+                None,  // timing
+                None,  // timeout_per_file
+                cli.verbose > 0,
                 cli.quiet,
             )
         }
@@ -573,14 +1004,72 @@ fn main() {
             )
         }
         Some(Commands::Check { config }) => run_check_command(&config),
+        Some(Commands::Config {
+            command: ConfigCommand::Schema { output },
+        }) => run_config_schema_command(output.as_deref()),
         Some(Commands::Init {
             format,
             output,
             include_all,
-        }) => run_init_command(format, output, include_all),
+            interactive,
+        }) => run_init_command(format, output, include_all, interactive),
+        Some(Commands::Migrate {
+            input,
+            output,
+            force,
+        }) => run_migrate_command(&input, output, force),
+        Some(Commands::UpgradeConfig { config }) => run_upgrade_config_command(config),
+        Some(Commands::Compare {
+            paths,
+            against: CompareTool::Markdownlint,
+            markdownlint,
+            config,
+            format,
+            differences,
+        }) => run_compare_command(
+            &paths,
+            &markdownlint,
+            config.as_deref(),
+            format,
+            differences,
+        ),
+        Some(Commands::Stats {
+            paths,
+            config,
+            format,
+        }) => run_stats_command(&paths, config.as_deref(), format),
+        #[cfg(feature = "content")]
+        Some(Commands::Todos {
+            paths,
+            config,
+            format,
+            deny_todos,
+        }) => run_todos_command(&paths, config.as_deref(), format, deny_todos),
+        Some(Commands::Suppressions {
+            paths,
+            config,
+            format,
+        }) => run_suppressions_command(&paths, config.as_deref(), format),
+        Some(Commands::Serve { socket, config }) => run_serve_command(&socket, config.as_deref()),
+        Some(Commands::CheckCode {
+            paths,
+            config,
+            edition,
+            rustc,
+            output,
+        }) => {
+            let rustc = rustc
+                .or_else(|| std::env::var("RUSTC").ok())
+                .unwrap_or_else(|| "rustc".to_string());
+            run_check_code_command(&paths, config.as_deref(), &edition, &rustc, output)
+        }
         Some(Commands::Supports { renderer }) => run_supports_check(&renderer),
         #[cfg(feature = "lsp")]
-        Some(Commands::Lsp { stdio, port }) => run_lsp_server(stdio, port),
+        Some(Commands::Lsp {
+            stdio,
+            port,
+            websocket,
+        }) => run_lsp_server(stdio, port, websocket),
         Some(Commands::Rustdoc {
             paths,
             config,
@@ -602,20 +1091,62 @@ fn main() {
                 output,
                 disable.as_ref(),
                 enable.as_ref(),
-                cli.verbose,
+                cli.verbose > 0,
                 cli.quiet,
             )
         }
+        Some(Commands::Bench {
+            paths,
+            config,
+            iterations,
+            top,
+            max_rule_ms,
+        }) => run_bench_command(&paths, config.as_deref(), iterations, top, max_rule_ms),
         None => {
-            // No subcommand provided - default to preprocessor mode
-            // This matches mdBook's expectation for preprocessors
-            run_preprocessor_mode()
+            // No subcommand provided - mdBook runs both preprocessors and
+            // renderers this way, so the input decides which mode to use
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            let passed = if renderer::is_render_context(&input) {
+                renderer::render(&input)?
+            } else {
+                preprocessor::preprocess(&input)?
+            };
+            if !passed {
+                process::exit(exit_code::VIOLATIONS);
+            }
+            Ok(())
         }
-    };
+    }
+}
 
-    if let Err(e) = result {
-        eprintln!("Error: {e}");
-        process::exit(1);
+/// Load the configuration file at `config_path`, or discover one
+///
+/// `profile`, from `--profile`, replaces the profile named in the file.
+/// Returns the configuration and the path it was loaded from, if any.
+fn load_config(
+    config_path: Option<&str>,
+    profile: Option<&str>,
+) -> Result<(Config, Option<String>)> {
+    if let Some(path) = config_path {
+        // Explicit config path provided; the format is detected from the
+        // extension, falling back to the content
+        let cfg = Config::from_file_with_profile(path, profile)?;
+        tracing::info!(path, "loaded config");
+        Ok((cfg, Some(path.to_string())))
+    } else if let Some(discovered_path) = Config::discover_config(None) {
+        let path_str = discovered_path.display().to_string();
+        let cfg = Config::from_file_with_profile(&discovered_path, profile)?;
+        tracing::info!(path = %path_str, "loaded discovered config");
+        Ok((cfg, Some(path_str)))
+    } else {
+        // No config found, use defaults
+        tracing::info!("no config file found, using defaults");
+        let cfg = Config {
+            profile: profile.map(str::to_string),
+            ..Config::default()
+        };
+        Ok((extends::resolve(cfg, Path::new(".mdbook-lint.toml"))?, None))
     }
 }
 
@@ -719,106 +1250,23 @@ fn filter_ignored_paths(files: &mut Vec<PathBuf>, patterns: &[String]) {
     files.retain(|path| !path_is_ignored(path, patterns));
 }
 
-/// Apply fixes to file content, returning the fixed content if any fixes were applied
-fn apply_fixes_to_content(
-    content: &str,
-    violations: &[&mdbook_lint_core::violation::Violation],
-) -> Result<Option<String>> {
-    use mdbook_lint_core::violation::Position;
-
-    if violations.is_empty() {
-        return Ok(None);
-    }
-
-    // Collect all fixes from violations that have them
-    let mut fixes_with_violations: Vec<(&mdbook_lint_core::violation::Fix, &str)> = violations
-        .iter()
-        .filter_map(|v| v.fix.as_ref().map(|f| (f, v.rule_id.as_str())))
-        .collect();
-
-    if fixes_with_violations.is_empty() {
-        return Ok(None);
-    }
-
-    // Sort fixes by position (descending) to avoid offset issues when applying
-    // Sort by line first (descending), then by column (descending)
-    fixes_with_violations.sort_by(|a, b| {
-        let line_cmp = b.0.start.line.cmp(&a.0.start.line);
-        if line_cmp == std::cmp::Ordering::Equal {
-            b.0.start.column.cmp(&a.0.start.column)
-        } else {
-            line_cmp
-        }
-    });
-
-    // Convert content to a mutable string for applying fixes
-    let mut result = content.to_string();
-    let mut fixes_applied = 0;
-
-    // Helper function to convert line/column position to byte offset
-    let position_to_offset = |text: &str, pos: &Position| -> Option<usize> {
-        let mut current_line = 1;
-        let mut current_col = 1;
-
-        for (offset, ch) in text.char_indices() {
-            if current_line == pos.line && current_col == pos.column {
-                return Some(offset);
-            }
-
-            if ch == '\n' {
-                current_line += 1;
-                current_col = 1;
-            } else {
-                current_col += 1;
-            }
-        }
-
-        // Handle position at end of content
-        if current_line == pos.line && current_col == pos.column {
-            Some(text.len())
-        } else {
-            None
-        }
-    };
-
-    // Apply each fix
-    for (fix, _rule_id) in fixes_with_violations {
-        // Convert positions to byte offsets
-        let start_offset = match position_to_offset(&result, &fix.start) {
-            Some(offset) => offset,
-            None => {
-                eprintln!(
-                    "Warning: Could not find start position for fix at {}:{}",
-                    fix.start.line, fix.start.column
-                );
-                continue;
-            }
-        };
-
-        let end_offset = match position_to_offset(&result, &fix.end) {
-            Some(offset) => offset,
-            None => {
-                eprintln!(
-                    "Warning: Could not find end position for fix at {}:{}",
-                    fix.end.line, fix.end.column
-                );
-                continue;
+/// Restore the files changed by the most recent fix session
+///
+/// The journal is looked up from `path` (or the current directory) upward.
+fn run_undo(path: Option<&str>, quiet: bool) -> Result<()> {
+    let start = PathBuf::from(path.unwrap_or("."));
+    match undo::undo_last(&start)? {
+        Some(restored) => {
+            if !quiet {
+                for path in &restored {
+                    println!("Restored {}", path.display());
+                }
+                println!("Undid fixes in {} file(s)", restored.len());
             }
-        };
-
-        // Apply the fix based on the operation type
-        if start_offset <= end_offset && end_offset <= result.len() {
-            let replacement = fix.replacement.as_deref().unwrap_or("");
-            result.replace_range(start_offset..end_offset, replacement);
-            fixes_applied += 1;
         }
+        None => eprintln!("No fix session to undo"),
     }
-
-    if fixes_applied > 0 && result != content {
-        Ok(Some(result))
-    } else {
-        Ok(None)
-    }
+    Ok(())
 }
 
 /// Check if a file is tracked by git
@@ -857,18 +1305,28 @@ fn create_backup_file(path: &PathBuf) -> Result<()> {
 #[allow(clippy::too_many_arguments)]
 fn run_cli_mode(
     files: &[String],
+    workspace: bool,
     config_path: Option<&str>,
+    profile: Option<&str>,
     standard_only: bool,
     mdbook_only: bool,
+    adr: bool,
+    adr_only: bool,
     fail_on_warnings: bool,
     markdownlint_compatible: bool,
     output_format: OutputFormat,
     fix: bool,
     fix_unsafe: bool,
     dry_run: bool,
+    fix_rules: Option<&Vec<String>>,
     backup: bool,
+    verify_fixes: bool,
     disable: Option<&Vec<String>>,
     enable: Option<&Vec<String>>,
+    stdin_filepath: Option<&Path>,
+    stdin_batch: bool,
+    timing: Option<&TimingOptions>,
+    timeout_per_file: Option<Duration>,
     verbose: bool,
     quiet: bool,
 ) -> Result<()> {
@@ -878,16 +1336,44 @@ fn run_cli_mode(
             "Cannot specify both --standard-only and --mdbook-only",
         ));
     }
+    if adr_only && (standard_only || mdbook_only) {
+        return Err(mdbook_lint::error::MdBookLintError::config_error(
+            "Cannot combine --adr-only with --standard-only or --mdbook-only",
+        ));
+    }
 
     // Validate fix flags
-    if dry_run && !fix && !fix_unsafe {
+    if dry_run && !fix && !fix_unsafe && fix_rules.is_none() {
         return Err(mdbook_lint::error::MdBookLintError::config_error(
             "--dry-run requires either --fix or --fix-unsafe",
         ));
     }
+    if verify_fixes && !fix && !fix_unsafe && fix_rules.is_none() {
+        return Err(mdbook_lint::error::MdBookLintError::config_error(
+            "--verify-fixes requires either --fix or --fix-unsafe",
+        ));
+    }
 
-    // fix_unsafe implies fix
-    let apply_fixes = fix || fix_unsafe;
+    // fix_unsafe and fix_rules imply fix
+    let apply_fixes = fix || fix_unsafe || fix_rules.is_some();
+
+    // Only these rules' fixes are applied; everything is still reported
+    let fix_rules: Option<Vec<String>> = match fix_rules {
+        Some(rules) => {
+            let known = get_all_available_rule_ids();
+            let rules: Vec<String> = rules
+                .iter()
+                .map(|rule| rule.trim().to_uppercase())
+                .collect();
+            if let Some(unknown) = rules.iter().find(|rule| !known.contains(rule)) {
+                return Err(mdbook_lint::error::MdBookLintError::config_error(format!(
+                    "Unknown rule in the rules to fix: '{unknown}'"
+                )));
+            }
+            Some(rules)
+        }
+        None => None,
+    };
 
     // Validate disable/enable flags
     if disable.is_some() && enable.is_some() {
@@ -902,346 +1388,686 @@ fn run_cli_mode(
         ));
     }
 
-    // Load configuration - try discovery if no explicit path
-    let (mut config, config_source) = if let Some(path) = config_path {
-        // Explicit config path provided
-        let config_content = std::fs::read_to_string(path).map_err(|e| {
-            mdbook_lint::error::MdBookLintError::config_error(format!(
-                "Failed to read config file {path}: {e}"
-            ))
-        })?;
-
-        // Detect format from extension and content
-        let cfg = if path.ends_with(".toml") {
-            Config::from_toml_str(&config_content)?
-        } else if path.ends_with(".yaml") || path.ends_with(".yml") {
-            Config::from_yaml_str(&config_content)?
-        } else if path.ends_with(".json") {
-            Config::from_json_str(&config_content)?
-        } else {
-            // Try to auto-detect format
-            config_content.parse()?
-        };
-        (cfg, Some(path.to_string()))
-    } else if let Some(discovered_path) = Config::discover_config(None) {
-        // Try to discover config file
-        let path_str = discovered_path.display().to_string();
-        let cfg = Config::from_file(&discovered_path)?;
-        (cfg, Some(path_str))
+    // With --workspace every book is linted with its own configuration;
+    // otherwise the paths are linted together
+    let books: Vec<Option<workspace::Book>> = if workspace {
+        if files.iter().any(|f| f == "-") {
+            return Err(mdbook_lint::error::MdBookLintError::config_error(
+                "Cannot use --workspace with stdin (-)",
+            ));
+        }
+        let books = workspace::discover(files)?;
+        if books.is_empty() {
+            return Err(mdbook_lint::error::MdBookLintError::config_error(
+                "--workspace found no book.toml under the given paths",
+            ));
+        }
+        books.into_iter().map(Some).collect()
     } else {
-        // No config found, use defaults
-        (Config::default(), None)
+        vec![None]
     };
 
-    // Print config path in verbose mode
-    if verbose && let Some(ref path) = config_source {
-        output::print_status("Config", path);
-    }
+    let memory = timing.is_some().then(memory::MemorySampler::new);
+    let mut journal = undo::Journal::new();
+    let mut fixes_applied = 0;
+    let mut files_modified = 0;
+    let mut unsettled_fixes = false;
+    let mut all_violations = Vec::new();
+    let mut all_timings = Vec::new();
+    let mut book_totals = Vec::new();
+    let mut failing = false;
+    let mut incomplete = false;
+    let mut any_timed_out = false;
+    let mut any_lint_failed = false;
+
+    for book in &books {
+        let book_files;
+        let files = match book {
+            Some(book) if !book.src.is_dir() => {
+                tracing::warn!(
+                    "Skipping book {}: {} is not a directory",
+                    book.name(),
+                    book.src.display()
+                );
+                continue;
+            }
+            Some(book) => {
+                book_files = vec![book.src.to_string_lossy().into_owned()];
+                &book_files[..]
+            }
+            None => files,
+        };
 
-    // Override config with CLI flags
-    if fail_on_warnings {
-        config.fail_on_warnings = true;
-    }
-    if markdownlint_compatible {
-        config.core.markdownlint_compatible = true;
-    }
+        // Load configuration - try discovery if no explicit path
+        let (mut config, config_source) = match book {
+            Some(book) => workspace::load_config(book, config_path, profile)?,
+            None => load_config(config_path, profile)?,
+        };
 
-    // Apply disable/enable flags
-    if let Some(disabled_rules) = disable {
-        // Add to existing disabled rules
-        config
-            .core
-            .disabled_rules
-            .extend(disabled_rules.iter().cloned());
-    }
+        // Print book and config path in verbose mode
+        if verbose && let Some(book) = book {
+            output::print_status("Book", &book.name());
+        }
+        if verbose && let Some(ref path) = config_source {
+            output::print_status("Config", path);
+        }
+        if verbose && let Some(ref profile) = config.profile {
+            output::print_status("Profile", profile);
+        }
 
-    if let Some(enabled_rules) = enable {
-        // Clear existing disabled rules and only enable specified rules
-        config.core.disabled_rules.clear();
+        // Override config with CLI flags
+        if fail_on_warnings {
+            config.fail_on_warnings = true;
+        }
+        if markdownlint_compatible {
+            config.core.markdownlint_compatible = true;
+        }
 
-        // Get all available rule IDs and disable everything except enabled ones
-        let all_rule_ids = get_all_available_rule_ids();
-        for rule_id in all_rule_ids {
-            if !enabled_rules.contains(&rule_id) {
-                config.core.disabled_rules.push(rule_id);
+        // Apply disable/enable flags
+        if let Some(disabled_rules) = disable {
+            // Add to existing disabled rules
+            config
+                .core
+                .disabled_rules
+                .extend(disabled_rules.iter().cloned());
+        }
+
+        if let Some(enabled_rules) = enable {
+            // Clear existing disabled rules and only enable specified rules
+            config.core.disabled_rules.clear();
+
+            // Get all available rule IDs and disable everything except enabled ones
+            let all_rule_ids = get_all_available_rule_ids();
+            for rule_id in all_rule_ids {
+                if !enabled_rules.contains(&rule_id) {
+                    config.core.disabled_rules.push(rule_id);
+                }
             }
         }
-    }
 
-    // Create appropriate engine based on flags
-    let mut registry = PluginRegistry::new();
+        // Create appropriate engine based on flags
+        let mut registry = PluginRegistry::new();
+
+        if adr_only {
+            // Only the ADR rules, registered below
+        } else if standard_only {
+            registry.register_provider(Box::new(StandardRuleProvider))?;
+            #[cfg(feature = "content")]
+            registry.register_provider(Box::new(ContentRuleProvider))?;
+            #[cfg(feature = "rfc")]
+            registry.register_provider(Box::new(RfcRuleProvider))?;
+            #[cfg(feature = "changelog")]
+            registry.register_provider(Box::new(ChangelogRuleProvider))?;
+            #[cfg(feature = "readme")]
+            registry.register_provider(Box::new(ReadmeRuleProvider))?;
+            #[cfg(feature = "frontmatter")]
+            registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+            #[cfg(feature = "spelling")]
+            registry.register_provider(Box::new(SpellingRuleProvider))?;
+        } else if mdbook_only {
+            registry.register_provider(Box::new(MdBookRuleProvider))?;
+            #[cfg(feature = "content")]
+            registry.register_provider(Box::new(ContentRuleProvider))?;
+            #[cfg(feature = "rfc")]
+            registry.register_provider(Box::new(RfcRuleProvider))?;
+            #[cfg(feature = "changelog")]
+            registry.register_provider(Box::new(ChangelogRuleProvider))?;
+            #[cfg(feature = "readme")]
+            registry.register_provider(Box::new(ReadmeRuleProvider))?;
+            #[cfg(feature = "frontmatter")]
+            registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+            #[cfg(feature = "spelling")]
+            registry.register_provider(Box::new(SpellingRuleProvider))?;
+        } else {
+            // Default: use all rules (standard + mdBook + content if enabled)
+            registry.register_provider(Box::new(StandardRuleProvider))?;
+            registry.register_provider(Box::new(MdBookRuleProvider))?;
+            #[cfg(feature = "content")]
+            registry.register_provider(Box::new(ContentRuleProvider))?;
+            #[cfg(feature = "rfc")]
+            registry.register_provider(Box::new(RfcRuleProvider))?;
+            #[cfg(feature = "changelog")]
+            registry.register_provider(Box::new(ChangelogRuleProvider))?;
+            #[cfg(feature = "readme")]
+            registry.register_provider(Box::new(ReadmeRuleProvider))?;
+            #[cfg(feature = "frontmatter")]
+            registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+            #[cfg(feature = "spelling")]
+            registry.register_provider(Box::new(SpellingRuleProvider))?;
+        }
 
-    if standard_only {
-        registry.register_provider(Box::new(StandardRuleProvider))?;
-        #[cfg(feature = "content")]
-        registry.register_provider(Box::new(ContentRuleProvider))?;
+        // The ADR rules run when asked for, or when a target is an ADR directory
+        // such as docs/adr/; a provider-level [ADR] table reaches them through
+        // the core config
         #[cfg(feature = "adr")]
-        registry.register_provider(Box::new(AdrRuleProvider))?;
-    } else if mdbook_only {
-        registry.register_provider(Box::new(MdBookRuleProvider))?;
-        #[cfg(feature = "content")]
-        registry.register_provider(Box::new(ContentRuleProvider))?;
-        #[cfg(feature = "adr")]
-        registry.register_provider(Box::new(AdrRuleProvider))?;
-    } else {
-        // Default: use all rules (standard + mdBook + content if enabled)
-        registry.register_provider(Box::new(StandardRuleProvider))?;
-        registry.register_provider(Box::new(MdBookRuleProvider))?;
-        #[cfg(feature = "content")]
-        registry.register_provider(Box::new(ContentRuleProvider))?;
+        let adr_rules = adr
+            || adr_only
+            || config.adr
+            || files.iter().any(|file| path_in_adr_dir(Path::new(file)))
+            || stdin_filepath.is_some_and(path_in_adr_dir);
         #[cfg(feature = "adr")]
-        registry.register_provider(Box::new(AdrRuleProvider))?;
-    }
-
-    let engine = registry.create_engine_with_config(Some(&config.core))?;
-
-    let mut total_violations = 0;
-    let mut has_errors = false;
-    let mut violations_by_file = Vec::new();
-
-    // Check if stdin is requested (file argument is "-")
-    let has_stdin = files.iter().any(|f| f == "-");
-
-    // Validate stdin usage
-    if has_stdin {
-        if files.len() > 1 {
-            return Err(mdbook_lint::error::MdBookLintError::config_error(
-                "Cannot mix stdin (-) with other file arguments",
-            ));
+        if adr_rules {
+            tracing::info!("ADR rules enabled");
+            registry.register_provider(Box::new(AdrRuleProvider))?;
         }
-        if apply_fixes && !dry_run {
+        #[cfg(not(feature = "adr"))]
+        if adr || adr_only || config.adr {
             return Err(mdbook_lint::error::MdBookLintError::config_error(
-                "Cannot use --fix with stdin input. Use --fix --dry-run to preview fixes.",
+                "ADR rules are not available: mdbook-lint was built without the `adr` feature",
             ));
         }
-    }
-
-    // Process stdin if requested
-    if has_stdin {
-        let mut content = String::new();
-        io::stdin().read_to_string(&mut content).map_err(|e| {
-            mdbook_lint::error::MdBookLintError::document_error(format!(
-                "Failed to read from stdin: {e}"
-            ))
-        })?;
-
-        // Create document with synthetic path
-        let stdin_path = PathBuf::from("<stdin>");
-        let document = Document::new(content, stdin_path.clone())?;
-
-        // Lint with configuration
-        let violations = engine.lint_document_with_config(&document, &config.core)?;
 
-        if !violations.is_empty() {
-            violations_by_file.push(("<stdin>".to_string(), violations.clone()));
-            total_violations += violations.len();
-
-            for violation in &violations {
-                if violation.severity == Severity::Error {
-                    has_errors = true;
-                }
-            }
+        // Shared with the worker threads that enforce --timeout-per-file
+        let engine = Arc::new(registry.create_engine_with_config(Some(&config.core))?);
+        for problem in engine.registry().validate_rule_configs(&config.core) {
+            tracing::warn!("{problem}");
         }
-    } else {
-        // Process files
-        // Collect all markdown files from the provided paths
-        let mut markdown_files = Vec::new();
-        for file_path in files {
-            let path = PathBuf::from(file_path);
-
-            if path.is_dir() {
-                // Recursively find all markdown files in directory
-                collect_markdown_files(&path, &mut markdown_files)?;
+        engine.registry().report_deprecations(&config.core);
+        if let Some(notice) = engine.registry().ruleset_notice(&config.core) {
+            tracing::warn!("{notice}");
+        }
+
+        if stdin_batch {
+            // Batch documents bring their own paths, so the ADR rules are picked
+            // per document, the way a file in docs/adr/ turns them on
+            #[cfg(feature = "adr")]
+            let adr_engine = if adr_rules {
+                None
             } else {
-                // Skip non-markdown files
-                if let Some(ext) = path.extension()
-                    && !matches!(ext.to_str(), Some("md") | Some("markdown"))
-                {
-                    continue;
+                registry.register_provider(Box::new(AdrRuleProvider))?;
+                Some(Arc::new(
+                    registry.create_engine_with_config(Some(&config.core))?,
+                ))
+            };
+            let summary = batch::run(io::stdin().lock(), io::stdout().lock(), |request| {
+                if path_is_ignored(&request.path, &config.core.ignore_paths) {
+                    return Ok(Vec::new());
                 }
-                markdown_files.push(path);
+                let engine = &engine;
+                #[cfg(feature = "adr")]
+                let engine = match &adr_engine {
+                    Some(adr_engine) if path_in_adr_dir(&request.path) => adr_engine,
+                    _ => engine,
+                };
+                let document = Document::new(request.content.clone(), request.path.clone())
+                    .map_err(|e| e.to_string())?
+                    .with_extensions(config.core.markdown);
+                lint_within(
+                    engine,
+                    document,
+                    &config.core,
+                    timeout_per_file,
+                    LintEngine::lint_document_cancellable,
+                )
+                .map_err(|e| e.to_string())
+            })?;
+            if config.fails_lint(summary.worst) {
+                process::exit(exit_code::VIOLATIONS);
             }
+            return Ok(());
         }
 
-        // Drop any files matching the configured ignore-paths patterns
-        filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
-
-        // Process markdown files in parallel
-        let violations_mutex = Mutex::new(Vec::new());
-        let total_count = AtomicUsize::new(0);
-        let errors_found = AtomicBool::new(false);
-
-        markdown_files.par_iter().for_each(|path| {
-            let file_path = path.to_string_lossy().to_string();
+        let mut total_violations = 0;
+        let mut violations_by_file = Vec::new();
+        // Every linted document, kept for the collection rules
+        let mut documents = Vec::new();
+        let mut timings = Vec::new();
+        let mut read_failed = false;
+        let mut timed_out = false;
+        let mut lint_failed = false;
+
+        // Check if stdin is requested (file argument is "-")
+        let has_stdin = files.iter().any(|f| f == "-");
+
+        // Validate stdin usage
+        if has_stdin {
+            if files.len() > 1 {
+                return Err(mdbook_lint::error::MdBookLintError::config_error(
+                    "Cannot mix stdin (-) with other file arguments",
+                ));
+            }
+            if apply_fixes && !dry_run {
+                return Err(mdbook_lint::error::MdBookLintError::config_error(
+                    "Cannot use --fix with stdin input. Use --fix --dry-run to preview fixes.",
+                ));
+            }
+        } else if stdin_filepath.is_some() {
+            return Err(mdbook_lint::error::MdBookLintError::config_error(
+                "--stdin-filepath requires reading from stdin (-)",
+            ));
+        }
 
-            // Read file content
-            let content = match std::fs::read_to_string(path) {
-                Ok(c) => c,
-                Err(e) => {
-                    eprintln!("Failed to read file {}: {e}", path.display());
-                    return;
+        // Process stdin if requested
+        if has_stdin {
+            let mut content = Vec::new();
+            io::stdin().read_to_end(&mut content).map_err(|e| {
+                mdbook_lint::error::MdBookLintError::document_error(format!(
+                    "Failed to read from stdin: {e}"
+                ))
+            })?;
+
+            // Lint as the file the editor named, or under a synthetic path
+            let stdin_path =
+                stdin_filepath.map_or_else(|| PathBuf::from("<stdin>"), Path::to_path_buf);
+            let stdin_label = stdin_path.to_string_lossy().into_owned();
+            let ignored = path_is_ignored(&stdin_path, &config.core.ignore_paths);
+            let violations = match decode_markdown(&content, &stdin_path, &config)? {
+                // Editors pipe every buffer; honor ignore-paths for the named file
+                _ if ignored => Vec::new(),
+                Decoded::Document(document) if timing.is_some() => {
+                    let (violations, document_timing) = lint_within(
+                        &engine,
+                        document,
+                        &config.core,
+                        timeout_per_file,
+                        |engine, document, config, _| {
+                            engine.lint_document_timed_with_config(document, config)
+                        },
+                    )?;
+                    timings.push((stdin_label.clone(), document_timing));
+                    memory.iter().for_each(memory::MemorySampler::sample);
+                    violations
                 }
+                Decoded::Document(document) => lint_within(
+                    &engine,
+                    document,
+                    &config.core,
+                    timeout_per_file,
+                    LintEngine::lint_document_cancellable,
+                )?,
+                Decoded::Malformed(violation) => vec![violation],
+                Decoded::Skipped => Vec::new(),
             };
 
-            // Create document
-            let document = match Document::new(content, path.clone()) {
-                Ok(d) => d,
-                Err(e) => {
-                    eprintln!("Failed to parse document {}: {e}", path.display());
-                    return;
-                }
-            };
+            if !violations.is_empty() {
+                total_violations += violations.len();
+                violations_by_file.push((stdin_label, violations));
+            }
+        } else {
+            // Process files
+            // Collect all markdown files from the provided paths
+            let mut markdown_files = Vec::new();
+            for file_path in files {
+                let path = PathBuf::from(file_path);
 
-            // Lint with configuration
-            let violations = match engine.lint_document_with_config(&document, &config.core) {
-                Ok(v) => v,
-                Err(e) => {
-                    eprintln!("Failed to lint {}: {e}", path.display());
-                    return;
+                if path.is_dir() {
+                    // Recursively find all markdown files in directory
+                    collect_markdown_files(&path, &mut markdown_files)?;
+                } else {
+                    // Skip non-markdown files
+                    if let Some(ext) = path.extension()
+                        && !matches!(ext.to_str(), Some("md") | Some("markdown"))
+                    {
+                        continue;
+                    }
+                    markdown_files.push(path);
                 }
-            };
+            }
 
-            if !violations.is_empty() {
-                let violation_count = violations.len();
-                let has_error = violations.iter().any(|v| v.severity == Severity::Error);
+            // Drop any files matching the configured ignore-paths patterns
+            let collected = markdown_files.len();
+            filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+            tracing::debug!(
+                files = markdown_files.len(),
+                ignored = collected - markdown_files.len(),
+                "collected markdown files"
+            );
 
-                // Update atomics
-                total_count.fetch_add(violation_count, Ordering::Relaxed);
-                if has_error {
-                    errors_found.store(true, Ordering::Relaxed);
+            // Process markdown files in parallel
+            let violations_mutex = Mutex::new(Vec::new());
+            let documents_mutex = Mutex::new(Vec::new());
+            let timings_mutex = Mutex::new(Vec::new());
+            let total_count = AtomicUsize::new(0);
+            let malformed_error = Mutex::new(None);
+            let unreadable = AtomicBool::new(false);
+            let slow = AtomicBool::new(false);
+            let broken = AtomicBool::new(false);
+
+            markdown_files.par_iter().for_each(|path| {
+                let file_path = path.to_string_lossy().to_string();
+
+                // Read file content
+                let content = match std::fs::read(path) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        eprintln!("Failed to read file {}: {e}", path.display());
+                        unreadable.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                };
+
+                // Create document
+                let document = match decode_markdown(&content, path, &config) {
+                    Ok(Decoded::Document(document)) => document,
+                    Ok(Decoded::Malformed(violation)) => {
+                        total_count.fetch_add(1, Ordering::Relaxed);
+                        if let Ok(mut guard) = violations_mutex.lock() {
+                            guard.push((file_path, vec![violation]));
+                        }
+                        return;
+                    }
+                    Ok(Decoded::Skipped) => return,
+                    Err(e) => {
+                        if let Ok(mut guard) = malformed_error.lock() {
+                            guard.get_or_insert(e);
+                        }
+                        return;
+                    }
+                };
+                if engine.has_collection_rules()
+                    && let Ok(mut guard) = documents_mutex.lock()
+                {
+                    guard.push(document.clone());
                 }
 
-                // Add to results
-                if let Ok(mut guard) = violations_mutex.lock() {
-                    guard.push((file_path, violations));
+                // Lint with configuration, timing each rule if requested
+                let result = if timing.is_some() {
+                    lint_within(
+                        &engine,
+                        document,
+                        &config.core,
+                        timeout_per_file,
+                        |engine, document, config, _| {
+                            engine.lint_document_timed_with_config(document, config)
+                        },
+                    )
+                    .map(|(violations, document_timing)| {
+                        if let Ok(mut guard) = timings_mutex.lock() {
+                            guard.push((file_path.clone(), document_timing));
+                        }
+                        memory.iter().for_each(memory::MemorySampler::sample);
+                        violations
+                    })
+                } else {
+                    lint_within(
+                        &engine,
+                        document,
+                        &config.core,
+                        timeout_per_file,
+                        LintEngine::lint_document_cancellable,
+                    )
+                };
+                let violations = match result {
+                    Ok(v) => v,
+                    Err(MdBookLintError::Cancelled(_)) => {
+                        let seconds = timeout_per_file.unwrap_or_default().as_secs();
+                        eprintln!("Timed out linting {} after {seconds}s", path.display());
+                        slow.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to lint {}: {e}", path.display());
+                        broken.store(true, Ordering::Relaxed);
+                        return;
+                    }
+                };
+                tracing::debug!(file = %file_path, violations = violations.len(), "linted file");
+
+                if !violations.is_empty() {
+                    total_count.fetch_add(violations.len(), Ordering::Relaxed);
+
+                    // Add to results
+                    if let Ok(mut guard) = violations_mutex.lock() {
+                        guard.push((file_path, violations));
+                    }
                 }
+            });
+
+            if let Some(error) = malformed_error.into_inner().unwrap_or_default() {
+                return Err(error);
             }
-        });
 
-        // Collect results and sort by file path for deterministic output
-        violations_by_file = violations_mutex.into_inner().unwrap_or_default();
-        violations_by_file.sort_by(|a, b| a.0.cmp(&b.0));
-        total_violations = total_count.load(Ordering::Relaxed);
-        has_errors = errors_found.load(Ordering::Relaxed);
-    }
+            // Collect results and sort by file path for deterministic output
+            violations_by_file = violations_mutex.into_inner().unwrap_or_default();
+            violations_by_file.sort_by(|a, b| a.0.cmp(&b.0));
+            timings = timings_mutex.into_inner().unwrap_or_default();
+            total_violations = total_count.load(Ordering::Relaxed);
+            read_failed = unreadable.load(Ordering::Relaxed);
+            timed_out = slow.load(Ordering::Relaxed);
+            lint_failed = broken.load(Ordering::Relaxed);
+
+            documents = documents_mutex.into_inner().unwrap_or_default();
+            documents.sort_by(|a: &Document, b| a.path.cmp(&b.path));
+            total_violations += add_collection_violations(
+                &engine,
+                &documents,
+                &config.core,
+                files.first().map_or(Path::new("."), Path::new),
+                &mut violations_by_file,
+            )?;
+        }
 
-    // Apply fixes if requested
-    let mut fixes_applied = 0;
-    let mut files_modified = 0;
+        // Apply fixes if requested
+        let fixes_before = fixes_applied;
+        if apply_fixes {
+            // Each fix's safety decides whether --fix or only --fix-unsafe
+            // applies it; suggestions are never applied
+            let fix_engine = FixEngine::new().with_unsafe_fixes(fix_unsafe);
+            let is_fixable = |v: &mdbook_lint_core::violation::Violation| {
+                v.fix
+                    .as_ref()
+                    .is_some_and(|fix| fix_engine.allows(fix.safety))
+                    && config.should_auto_fix_rule(&v.rule_id)
+                    && fix_rules
+                        .as_ref()
+                        .is_none_or(|rules| rules.contains(&v.rule_id))
+            };
 
-    if apply_fixes {
-        for (file_path, violations) in &violations_by_file {
-            let fixable_violations: Vec<_> = violations
-                .iter()
-                .filter(|v| v.fix.is_some() && config.should_auto_fix_rule(&v.rule_id))
-                .collect();
+            for (file_path, violations) in &violations_by_file {
+                if !violations.iter().any(is_fixable) {
+                    continue;
+                }
+                // Collection rules only re-run across the book for a file
+                // they have fixes for
+                let collection_fixes = violations.iter().any(|v| v.path.is_some() && is_fixable(v));
 
-            if !fixable_violations.is_empty() {
                 let path = PathBuf::from(file_path);
 
-                // Read original content
-                let original_content = std::fs::read_to_string(&path).map_err(|e| {
+                // Read original content, keeping its encoding for the write
+                let bytes = std::fs::read(&path).map_err(|e| {
                     mdbook_lint::error::MdBookLintError::document_error(format!(
                         "Failed to read file {}: {e}",
                         path.display()
                     ))
                 })?;
-
-                if let Some(fixed_content) =
-                    apply_fixes_to_content(&original_content, &fixable_violations)?
-                {
-                    if dry_run {
-                        println!(
-                            "Would fix {} issue(s) in {}",
-                            fixable_violations.len(),
-                            file_path
-                        );
-                        // TODO: Show diff preview
+                let (original_content, encoding) = SourceEncoding::decode(&bytes)?;
+
+                // Re-lint between passes so fixes that conflicted with another
+                // fix are retried against the updated content
+                let report = fix_engine.fix_until_stable(&original_content, |content| {
+                    let document = Document::new(content.to_string(), path.clone())?
+                        .with_extensions(config.core.markdown);
+                    let mut violations = if collection_fixes {
+                        lint_in_collection(&engine, document, &mut documents, &config.core)?
                     } else {
-                        // Create backup if requested and not using git
-                        if backup && !is_git_tracked(&path)? {
-                            create_backup_file(&path)?;
-                        }
+                        engine.lint_document_with_config(&document, &config.core)?
+                    };
+                    violations.retain(is_fixable);
+                    Ok(violations)
+                })?;
 
-                        // Write fixed content
-                        std::fs::write(&path, fixed_content).map_err(|e| {
-                            mdbook_lint::error::MdBookLintError::document_error(format!(
-                                "Failed to write fixed file {}: {e}",
-                                path.display()
-                            ))
-                        })?;
+                for skipped in &report.skipped {
+                    eprintln!(
+                        "Warning: Skipped {} fix at {}:{}:{}: {}",
+                        skipped.violation.rule_id,
+                        file_path,
+                        skipped.violation.line,
+                        skipped.violation.column,
+                        skipped.reason
+                    );
+                }
 
-                        println!(
-                            "Fixed {} issue(s) in {}",
-                            fixable_violations.len(),
-                            file_path
-                        );
-                        fixes_applied += fixable_violations.len();
-                        files_modified += 1;
-                    }
+                if !report.is_modified(&original_content) {
+                    continue;
                 }
-            }
-        }
 
-        if !dry_run && fixes_applied > 0 {
-            println!(
-                "Applied {} fix(es) across {} file(s)",
-                fixes_applied, files_modified
-            );
-        }
-    }
+                // A fix that keeps firing or creates new violations is a bug in
+                // the rule; leave the file alone rather than churn it
+                if verify_fixes {
+                    let problems = fix_engine.verify(&original_content, &report, |content| {
+                        let document = Document::new(content.to_string(), path.clone())?
+                            .with_extensions(config.core.markdown);
+                        if collection_fixes {
+                            lint_in_collection(&engine, document, &mut documents, &config.core)
+                        } else {
+                            Ok(engine.lint_document_with_config(&document, &config.core)?)
+                        }
+                    })?;
+                    if !problems.is_empty() {
+                        for problem in &problems {
+                            eprintln!("Internal error: {file_path}: {problem}");
+                        }
+                        eprintln!("Not writing fixes to {file_path}; please report this as a bug");
+                        unsettled_fixes = true;
+                        continue;
+                    }
+                }
 
-    // Re-lint files after fixes to get accurate violations for display and exit code
-    if apply_fixes && !dry_run && fixes_applied > 0 {
-        violations_by_file.clear();
-        total_violations = 0;
-        has_errors = false;
+                if dry_run {
+                    println!(
+                        "Would fix {} issue(s) in {}",
+                        report.applied.len(),
+                        file_path
+                    );
+                    // TODO: Show diff preview
+                } else {
+                    // Create backup if requested and not using git
+                    if backup && !is_git_tracked(&path)? {
+                        create_backup_file(&path)?;
+                    }
 
-        // Process each file again to get post-fix violations
-        for file_path in files {
-            let path = PathBuf::from(file_path);
+                    // Journal the change so `fix --undo` can restore it, then
+                    // write fixed content in the file's original encoding
+                    let fixed_bytes = encoding.encode(&report.content);
+                    journal.record(
+                        &path,
+                        &bytes,
+                        &original_content,
+                        &fixed_bytes,
+                        &report.content,
+                    )?;
+                    std::fs::write(&path, fixed_bytes).map_err(|e| {
+                        mdbook_lint::error::MdBookLintError::document_error(format!(
+                            "Failed to write fixed file {}: {e}",
+                            path.display()
+                        ))
+                    })?;
+
+                    tracing::debug!(file = %file_path, fixes = report.applied.len(), "wrote fixes");
+                    if !quiet {
+                        println!("Fixed {} issue(s) in {}", report.applied.len(), file_path);
+                    }
+                    fixes_applied += report.applied.len();
+                    files_modified += 1;
 
-            // Handle directories by re-collecting markdown files
-            let mut current_markdown_files = Vec::new();
-            if path.is_dir() {
-                collect_markdown_files(&path, &mut current_markdown_files)?;
-            } else if let Some(ext) = path.extension()
-                && matches!(ext.to_str(), Some("md") | Some("markdown"))
-            {
-                current_markdown_files.push(path);
+                    // Collection rules fixing later files see the fixed text
+                    if let Some(document) = documents.iter_mut().find(|d| d.path == path) {
+                        *document = Document::new(report.content, path)?
+                            .with_extensions(config.core.markdown);
+                    }
+                }
             }
+        }
 
-            filter_ignored_paths(&mut current_markdown_files, &config.core.ignore_paths);
-
-            for md_path in current_markdown_files {
-                let file_path = md_path.to_string_lossy().to_string();
-
-                // Read file content (now potentially fixed)
-                let content = std::fs::read_to_string(&md_path).map_err(|e| {
-                    mdbook_lint::error::MdBookLintError::document_error(format!(
-                        "Failed to read file {}: {e}",
-                        md_path.display()
-                    ))
-                })?;
+        // Re-lint files after fixes to get accurate violations for display and exit code
+        if apply_fixes && !dry_run && fixes_applied > fixes_before {
+            violations_by_file.clear();
+            documents.clear();
+            total_violations = 0;
 
-                // Create document and lint
-                let document = Document::new(content, md_path.clone())?;
-                let violations = engine.lint_document_with_config(&document, &config.core)?;
+            // Process each file again to get post-fix violations
+            for file_path in files {
+                let path = PathBuf::from(file_path);
 
-                if !violations.is_empty() {
-                    violations_by_file.push((file_path, violations.clone()));
-                    total_violations += violations.len();
+                // Handle directories by re-collecting markdown files
+                let mut current_markdown_files = Vec::new();
+                if path.is_dir() {
+                    collect_markdown_files(&path, &mut current_markdown_files)?;
+                } else if let Some(ext) = path.extension()
+                    && matches!(ext.to_str(), Some("md") | Some("markdown"))
+                {
+                    current_markdown_files.push(path);
+                }
 
-                    for violation in &violations {
-                        if violation.severity == Severity::Error {
-                            has_errors = true;
+                filter_ignored_paths(&mut current_markdown_files, &config.core.ignore_paths);
+
+                for md_path in current_markdown_files {
+                    let file_path = md_path.to_string_lossy().to_string();
+
+                    // Read file content (now potentially fixed)
+                    let content = std::fs::read(&md_path).map_err(|e| {
+                        mdbook_lint::error::MdBookLintError::document_error(format!(
+                            "Failed to read file {}: {e}",
+                            md_path.display()
+                        ))
+                    })?;
+
+                    // Create document and lint
+                    let violations = match decode_markdown(&content, &md_path, &config)? {
+                        Decoded::Document(document) => {
+                            let violations =
+                                engine.lint_document_with_config(&document, &config.core)?;
+                            if engine.has_collection_rules() {
+                                documents.push(document);
+                            }
+                            violations
                         }
+                        Decoded::Malformed(violation) => vec![violation],
+                        Decoded::Skipped => continue,
+                    };
+
+                    if !violations.is_empty() {
+                        total_violations += violations.len();
+                        violations_by_file.push((file_path, violations));
                     }
                 }
             }
+
+            total_violations += add_collection_violations(
+                &engine,
+                &documents,
+                &config.core,
+                files.first().map_or(Path::new("."), Path::new),
+                &mut violations_by_file,
+            )?;
+        }
+
+        let worst = violations_by_file
+            .iter()
+            .flat_map(|(_, v)| v)
+            .map(|v| v.severity)
+            .max();
+        failing |= config.fails_lint(worst);
+        incomplete |= read_failed;
+        any_timed_out |= timed_out;
+        any_lint_failed |= lint_failed;
+        if let Some(book) = book {
+            book_totals.push((book.name(), total_violations));
         }
+        // Violations below report-level still count toward the exit code
+        config.retain_reported(&mut violations_by_file);
+        all_violations.extend(violations_by_file);
+        all_timings.extend(timings);
     }
 
+    if !dry_run && !quiet && fixes_applied > 0 {
+        println!(
+            "Applied {} fix(es) across {} file(s)",
+            fixes_applied, files_modified
+        );
+    }
+    if let Some(file) = journal.file() {
+        tracing::debug!(journal = %file.display(), "recorded fix session");
+    }
+
+    let violations_by_file = all_violations;
+    let timings = all_timings;
+    let total_violations: usize = violations_by_file.iter().map(|(_, v)| v.len()).sum();
+    let has_errors = violations_by_file
+        .iter()
+        .flat_map(|(_, v)| v)
+        .any(|v| v.severity == Severity::Error);
+
     // Count errors and warnings for summary
     let error_count = violations_by_file
         .iter()
@@ -1255,13 +2081,27 @@ fn run_cli_mode(
         .count();
 
     // Output results
+    // In quiet mode only errors are shown; JSON output stays complete
+    let quiet_violations;
+    let displayed = if quiet {
+        quiet_violations = output::errors_only(&violations_by_file);
+        &quiet_violations
+    } else {
+        &violations_by_file
+    };
+
     match output_format {
         OutputFormat::Default => {
-            output::print_cargo_style(&violations_by_file);
+            output::print_cargo_style(displayed);
+            if !quiet {
+                for (book, violations) in &book_totals {
+                    output::print_status("Book", &format!("{book}: {violations} violation(s)"));
+                }
+            }
             output::print_summary(total_violations, error_count, warning_count, quiet);
         }
         OutputFormat::Json => {
-            let output = serde_json::json!({
+            let mut output = serde_json::json!({
                 "total_violations": total_violations,
                 "has_errors": has_errors,
                 "files": violations_by_file.iter().map(|(file, violations)| {
@@ -1271,35 +2111,205 @@ fn run_cli_mode(
                     })
                 }).collect::<Vec<_>>()
             });
-            println!("{}", serde_json::to_string_pretty(&output).unwrap());
-        }
-        OutputFormat::Github => {
-            for (file_path, violations) in &violations_by_file {
-                for violation in violations {
-                    let level = match violation.severity {
-                        Severity::Error => "error",
-                        Severity::Warning => "warning",
-                        Severity::Info => "notice",
-                    };
-                    println!(
-                        "::{level} file={file_path},line={}::{}: {}",
-                        violation.line, violation.rule_id, violation.message
-                    );
-                }
+            if workspace {
+                output["books"] = book_totals
+                    .iter()
+                    .map(|(book, violations)| {
+                        serde_json::json!({ "book": book, "total_violations": violations })
+                    })
+                    .collect();
             }
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
         }
+        OutputFormat::Github => github::print_annotations(displayed),
+        OutputFormat::GithubSummary => github::write_step_summary(displayed)?,
+    }
+
+    if let Some(options) = timing {
+        let memory = memory.as_ref().and_then(memory::MemorySampler::finish);
+        timing::report(&timings, memory, options)?;
+    }
+
+    // Fixes that did not settle point to a bug in a rule
+    if unsettled_fixes {
+        process::exit(exit_code::INTERNAL_ERROR);
+    }
+
+    // Files that could not be read leave the results incomplete
+    if incomplete {
+        process::exit(exit_code::IO_ERROR);
+    }
+
+    // So do files that took longer than --timeout-per-file, and files the
+    // engine failed on
+    if any_timed_out || any_lint_failed {
+        process::exit(exit_code::INTERNAL_ERROR);
     }
 
     // Determine exit code
-    // For fix mode, we already re-linted and updated has_errors/total_violations
-    // For non-fix mode, use original values
-    if has_errors || (total_violations > 0 && config.fail_on_warnings) {
-        process::exit(1);
+    // For fix mode, each book was re-linted after its fixes, so `failing`
+    // reflects the fixed files and each book's own fail-on-warnings
+    if failing {
+        process::exit(exit_code::VIOLATIONS);
     }
 
     Ok(())
 }
 
+/// Run the collection rules across `documents` and add their violations to
+/// `violations_by_file`, returning how many were added
+///
+/// Book-wide violations that name no file are reported against `unowned`.
+fn add_collection_violations(
+    engine: &LintEngine,
+    documents: &[Document],
+    config: &mdbook_lint_core::Config,
+    unowned: &Path,
+    violations_by_file: &mut Vec<(String, Vec<Violation>)>,
+) -> Result<usize> {
+    if documents.is_empty() {
+        return Ok(0);
+    }
+
+    let mut added = 0;
+    for (path, violations) in engine.lint_collection_by_file(documents, config, unowned)? {
+        let file_path = path.to_string_lossy().into_owned();
+        added += violations.len();
+        match violations_by_file
+            .iter_mut()
+            .find(|(file, _)| *file == file_path)
+        {
+            Some((_, existing)) => {
+                existing.extend(violations);
+                existing.sort_by_key(|v| (v.line, v.column));
+            }
+            None => violations_by_file.push((file_path, violations)),
+        }
+    }
+    violations_by_file.sort_by(|a, b| a.0.cmp(&b.0));
+    Ok(added)
+}
+
+/// Lint `document` with its own rules, plus the collection rules run across
+/// `documents` with `document` in place of its copy there
+///
+/// Of the collection violations only those in `document` are returned, so
+/// its fixes are checked against every rule that reports in the file.
+fn lint_in_collection(
+    engine: &LintEngine,
+    document: Document,
+    documents: &mut [Document],
+    config: &mdbook_lint_core::Config,
+) -> Result<Vec<Violation>> {
+    let mut violations = engine.lint_document_with_config(&document, config)?;
+    let Some(index) = documents.iter().position(|d| d.path == document.path) else {
+        return Ok(violations);
+    };
+
+    let path = document.path.clone();
+    let original = std::mem::replace(&mut documents[index], document);
+    let collection = engine.lint_collection_with_config(documents, config);
+    documents[index] = original;
+    violations.extend(
+        collection?
+            .into_iter()
+            .filter(|v| v.path.as_ref() == Some(&path)),
+    );
+    Ok(violations)
+}
+
+/// Run `lint` on `document`, giving up once `timeout` has passed
+///
+/// Without a timeout the lint runs on the calling thread. With one it runs on
+/// its own thread under a deadline: the engine stops between rules when the
+/// deadline passes, and a rule that never returns is abandoned (its thread
+/// ends with the process).
+fn lint_within<T: Send + 'static>(
+    engine: &Arc<LintEngine>,
+    document: Document,
+    config: &mdbook_lint_core::Config,
+    timeout: Option<Duration>,
+    lint: fn(&LintEngine, &Document, &mdbook_lint_core::Config, &CancellationToken) -> Result<T>,
+) -> Result<T> {
+    let Some(timeout) = timeout else {
+        return lint(engine, &document, config, &CancellationToken::new());
+    };
+
+    let token = CancellationToken::with_timeout(timeout);
+    let (sender, receiver) = mpsc::channel();
+    let (worker_engine, worker_config, worker_token) =
+        (Arc::clone(engine), config.clone(), token.clone());
+    std::thread::spawn(move || {
+        let result = lint(&worker_engine, &document, &worker_config, &worker_token);
+        // The receiver is gone if the lint already timed out
+        let _ = sender.send(result);
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(mpsc::RecvTimeoutError::Timeout) => {
+            token.cancel();
+            Err(MdBookLintError::Cancelled(format!(
+                "no result after {}s",
+                timeout.as_secs()
+            )))
+        }
+        // The worker panicked; its panic message has already been printed
+        Err(mpsc::RecvTimeoutError::Disconnected) => Err(MdBookLintError::rule_error(
+            "engine",
+            "lint thread panicked",
+        )),
+    }
+}
+
+/// Outcome of decoding a markdown file under the `malformed-markdown` policy
+enum Decoded {
+    Document(Document),
+    /// The file could not be decoded and the policy is `warn`
+    Malformed(Violation),
+    /// The file could not be decoded and the policy is `skip`
+    Skipped,
+}
+
+/// Decode a markdown file, applying the `malformed-markdown` policy to files
+/// in an unsupported encoding
+///
+/// UTF-8 (with or without a byte order mark) and UTF-16 are decoded. Other
+/// files fail under `error`, become a single `ENCODING` warning under `warn`,
+/// and are left out under `skip`.
+fn decode_markdown(bytes: &[u8], path: &Path, config: &Config) -> Result<Decoded> {
+    let message = match Document::from_bytes(bytes, path.to_path_buf()) {
+        Ok(document) => {
+            return Ok(Decoded::Document(
+                document.with_extensions(config.core.markdown),
+            ));
+        }
+        Err(mdbook_lint::error::MdBookLintError::Document(message)) => message,
+        Err(error) => return Err(error),
+    };
+
+    match config.malformed_markdown {
+        MalformedMarkdownAction::Error => Err(mdbook_lint::error::MdBookLintError::document_error(
+            format!("{}: {message}", path.display()),
+        )),
+        MalformedMarkdownAction::Warn => Ok(Decoded::Malformed(Violation {
+            rule_id: "ENCODING".to_string(),
+            rule_name: "unsupported-encoding".to_string(),
+            message: format!("{message}; convert the file to UTF-8 to lint it"),
+            line: 1,
+            column: 1,
+            severity: Severity::Warning,
+            fix: None,
+            url: None,
+            path: None,
+        })),
+        MalformedMarkdownAction::Skip => {
+            tracing::debug!(file = %path.display(), %message, "skipped malformed file");
+            Ok(Decoded::Skipped)
+        }
+    }
+}
+
 fn run_rules_command(
     detailed: bool,
     category_filter: Option<&str>,
@@ -1324,12 +2334,32 @@ fn run_rules_command(
         registry.register_provider(Box::new(ContentRuleProvider))?;
         #[cfg(feature = "adr")]
         registry.register_provider(Box::new(AdrRuleProvider))?;
+        #[cfg(feature = "rfc")]
+        registry.register_provider(Box::new(RfcRuleProvider))?;
+        #[cfg(feature = "changelog")]
+        registry.register_provider(Box::new(ChangelogRuleProvider))?;
+        #[cfg(feature = "readme")]
+        registry.register_provider(Box::new(ReadmeRuleProvider))?;
+        #[cfg(feature = "frontmatter")]
+        registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+        #[cfg(feature = "spelling")]
+        registry.register_provider(Box::new(SpellingRuleProvider))?;
     } else if mdbook_only {
         registry.register_provider(Box::new(MdBookRuleProvider))?;
         #[cfg(feature = "content")]
         registry.register_provider(Box::new(ContentRuleProvider))?;
         #[cfg(feature = "adr")]
         registry.register_provider(Box::new(AdrRuleProvider))?;
+        #[cfg(feature = "rfc")]
+        registry.register_provider(Box::new(RfcRuleProvider))?;
+        #[cfg(feature = "changelog")]
+        registry.register_provider(Box::new(ChangelogRuleProvider))?;
+        #[cfg(feature = "readme")]
+        registry.register_provider(Box::new(ReadmeRuleProvider))?;
+        #[cfg(feature = "frontmatter")]
+        registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+        #[cfg(feature = "spelling")]
+        registry.register_provider(Box::new(SpellingRuleProvider))?;
     } else {
         // Default: show all rules (standard + mdBook + content if enabled)
         registry.register_provider(Box::new(StandardRuleProvider))?;
@@ -1338,6 +2368,16 @@ fn run_rules_command(
         registry.register_provider(Box::new(ContentRuleProvider))?;
         #[cfg(feature = "adr")]
         registry.register_provider(Box::new(AdrRuleProvider))?;
+        #[cfg(feature = "rfc")]
+        registry.register_provider(Box::new(RfcRuleProvider))?;
+        #[cfg(feature = "changelog")]
+        registry.register_provider(Box::new(ChangelogRuleProvider))?;
+        #[cfg(feature = "readme")]
+        registry.register_provider(Box::new(ReadmeRuleProvider))?;
+        #[cfg(feature = "frontmatter")]
+        registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+        #[cfg(feature = "spelling")]
+        registry.register_provider(Box::new(SpellingRuleProvider))?;
     }
 
     let engine = registry.create_engine()?;
@@ -1382,6 +2422,8 @@ fn run_rules_command(
                             replacement: metadata.replacement.map(String::from),
                             introduced_in: metadata.introduced_in.map(String::from),
                             can_fix: rule.can_fix(),
+                            url: rule.doc_url(),
+                            options: metadata.options.iter().map(JsonRuleOption::from).collect(),
                         };
 
                         json_rules.push(json_rule);
@@ -1411,6 +2453,7 @@ fn run_rules_command(
         RulesFormat::Default => {
             // Collect rules into table rows
             let mut rows: Vec<_> = Vec::new();
+            let mut options = Vec::new();
             let mut total_rules = 0;
 
             for rule_id in engine.available_rules() {
@@ -1443,6 +2486,9 @@ fn run_rules_command(
                             status,
                             can_fix: if rule.can_fix() { "Yes" } else { "-" }.to_string(),
                         });
+                        if !metadata.options.is_empty() {
+                            options.push((rule.id(), metadata.options));
+                        }
                     }
                 }
             }
@@ -1454,6 +2500,25 @@ fn run_rules_command(
                 let table = Table::new(&rows).with(Style::rounded()).to_string();
                 println!("{table}");
 
+                if !options.is_empty() {
+                    println!("\nOptions:");
+                    for (id, rule_options) in &options {
+                        println!("\n  {id}");
+                        for option in rule_options {
+                            let default = option
+                                .default
+                                .map(|default| format!(", default {default}"))
+                                .unwrap_or_default();
+                            println!(
+                                "    {} ({}{default}): {}",
+                                option.name,
+                                option.kind.expected(),
+                                option.description
+                            );
+                        }
+                    }
+                }
+
                 println!("\nTotal: {total_rules} rules available");
             } else {
                 // Simple list mode with rule name
@@ -1493,6 +2558,26 @@ fn run_rules_command(
     Ok(())
 }
 
+fn run_config_schema_command(output: Option<&Path>) -> Result<()> {
+    let registry = plugin_registry()?;
+    let engine = registry.create_engine()?;
+    let schema =
+        serde_json::to_string_pretty(&schema::config_schema(&registry, &engine)).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::config_error(format!(
+                "Failed to serialize schema: {e}"
+            ))
+        })?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{schema}\n"))?;
+            eprintln!("Wrote configuration schema to {}", path.display());
+        }
+        None => println!("{schema}"),
+    }
+    Ok(())
+}
+
 fn run_check_command(config_path: &PathBuf) -> Result<()> {
     let config_content = std::fs::read_to_string(config_path).map_err(|e| {
         mdbook_lint::error::MdBookLintError::config_error(format!(
@@ -1524,11 +2609,23 @@ fn run_check_command(config_path: &PathBuf) -> Result<()> {
     registry.register_provider(Box::new(ContentRuleProvider))?;
     #[cfg(feature = "adr")]
     registry.register_provider(Box::new(AdrRuleProvider))?;
+    #[cfg(feature = "rfc")]
+    registry.register_provider(Box::new(RfcRuleProvider))?;
+    #[cfg(feature = "changelog")]
+    registry.register_provider(Box::new(ChangelogRuleProvider))?;
+    #[cfg(feature = "readme")]
+    registry.register_provider(Box::new(ReadmeRuleProvider))?;
+    #[cfg(feature = "frontmatter")]
+    registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+    #[cfg(feature = "spelling")]
+    registry.register_provider(Box::new(SpellingRuleProvider))?;
     let engine = registry.create_engine()?;
 
-    let available_rules: std::collections::HashSet<String> = engine
+    // Placeholders count as known; they get a deprecation notice below
+    let mut available_rules: std::collections::HashSet<String> = engine
         .available_rules()
         .into_iter()
+        .chain(engine.registry().placeholder_ids())
         .map(|s| s.to_string())
         .collect();
 
@@ -1548,6 +2645,20 @@ fn run_check_command(config_path: &PathBuf) -> Result<()> {
     let mut warnings = Vec::new();
     let mut errors = Vec::new();
 
+    // Validate custom rules, then treat their IDs as known for the checks below
+    let mut custom_ids: Vec<&String> = config.core.custom_rules.keys().collect();
+    custom_ids.sort();
+    for rule_id in custom_ids {
+        if available_rules.contains(rule_id) {
+            errors.push(format!(
+                "Custom rule '{rule_id}' conflicts with a built-in rule of the same ID"
+            ));
+        } else if let Err(e) = CustomRule::new(rule_id, &config.core.custom_rules[rule_id]) {
+            errors.push(e.to_string());
+        }
+        available_rules.insert(rule_id.clone());
+    }
+
     // Validate enabled-rules
     for rule_id in &config.core.enabled_rules {
         if !available_rules.contains(rule_id) {
@@ -1603,9 +2714,34 @@ fn run_check_command(config_path: &PathBuf) -> Result<()> {
         }
     }
 
-    // Validate rule-specific configs reference valid rules
-    for rule_id in config.core.rule_configs.keys() {
+    // Validate severity overrides reference valid rules
+    for rule_id in config.core.severity.keys() {
         if !available_rules.contains(rule_id) {
+            warnings.push(format!(
+                "Severity override for unknown rule: '{rule_id}' (will be ignored)"
+            ));
+            if let Some(suggestion) = find_similar_rule(rule_id, &available_rules) {
+                warnings.push(format!("  Did you mean '{suggestion}'?"));
+            }
+        }
+    }
+
+    // Validate rule-specific configs reference valid rules. `[vocabulary]`
+    // and `[MDBOOK-html]` are shared tables read by CONTENT012 and MDBOOK037,
+    // and `[ADR]`, `[RFC]`, and `[CHANGELOG]` hold settings for every rule of
+    // their provider, rather than a rule ID. `$schema` points editors at
+    // `mdbook-lint config schema`.
+    for rule_id in config.core.rule_configs.keys() {
+        let shared = [
+            "vocabulary",
+            "MDBOOK-html",
+            "ADR",
+            "RFC",
+            "CHANGELOG",
+            "$schema",
+        ]
+        .contains(&rule_id.as_str());
+        if !shared && !available_rules.contains(rule_id) {
             warnings.push(format!(
                 "Configuration for unknown rule: '{rule_id}' (will be ignored)"
             ));
@@ -1615,6 +2751,39 @@ fn run_check_command(config_path: &PathBuf) -> Result<()> {
         }
     }
 
+    // Deprecated and reserved rules the config uses, unless silenced
+    if !matches!(
+        config.core.deprecated_warning,
+        mdbook_lint_core::config::DeprecatedWarningLevel::Silent
+    ) {
+        warnings.extend(engine.registry().deprecation_notices(&config.core));
+    }
+
+    // Validate the pinned rule set version
+    if let Some(version) = &config.core.ruleset_version {
+        if mdbook_lint_core::config::parse_version(version).is_none() {
+            errors.push(format!(
+                "ruleset-version must be a version such as \"{}\", not \"{version}\"",
+                env!("CARGO_PKG_VERSION")
+            ));
+        } else if let Some(notice) = engine.registry().ruleset_notice(&config.core) {
+            warnings.push(notice);
+        }
+    }
+
+    // Validate rule options against the options each rule declares
+    errors.extend(engine.registry().validate_rule_configs(&config.core));
+    if let Some(vocabulary) = config.core.rule_configs.get("vocabulary")
+        && let Some(metadata) = engine.registry().rule_metadata("CONTENT012")
+    {
+        errors.extend(metadata.validate_config("vocabulary", vocabulary));
+    }
+    if let Some(html) = config.core.rule_configs.get("MDBOOK-html")
+        && let Some(metadata) = engine.registry().rule_metadata("MDBOOK037")
+    {
+        errors.extend(metadata.validate_config("MDBOOK-html", html));
+    }
+
     // Print warnings
     for warning in &warnings {
         eprintln!("Warning: {warning}");
@@ -1632,137 +2801,684 @@ fn run_check_command(config_path: &PathBuf) -> Result<()> {
         )));
     }
 
-    if warnings.is_empty() {
-        println!("Configuration file {} is valid", config_path.display());
-    } else {
-        println!(
-            "Configuration file {} is valid (with {} warning(s))",
-            config_path.display(),
-            warnings.len()
-        );
+    if warnings.is_empty() {
+        println!("Configuration file {} is valid", config_path.display());
+    } else {
+        println!(
+            "Configuration file {} is valid (with {} warning(s))",
+            config_path.display(),
+            warnings.len()
+        );
+    }
+
+    Ok(())
+}
+
+/// Find a similar rule name for typo suggestions
+fn find_similar_rule(input: &str, available: &std::collections::HashSet<String>) -> Option<String> {
+    let input_lower = input.to_lowercase();
+
+    // First pass: check for case-insensitive exact match
+    for rule in available {
+        if input_lower == rule.to_lowercase() {
+            return Some(rule.clone());
+        }
+    }
+
+    // Second pass: find closest match by Levenshtein distance
+    let mut best_match: Option<(String, usize)> = None;
+
+    for rule in available {
+        let rule_lower = rule.to_lowercase();
+        let distance = levenshtein(&input_lower, &rule_lower);
+
+        // Only consider matches with distance <= 2
+        if distance <= 2 && (best_match.is_none() || distance < best_match.as_ref().unwrap().1) {
+            best_match = Some((rule.clone(), distance));
+        }
+    }
+
+    best_match.map(|(rule, _)| rule)
+}
+
+/// Comprehensive example configuration with all rules documented.
+/// This is embedded from example-mdbook-lint.toml at compile time.
+const EXAMPLE_CONFIG_TOML: &str = include_str!("../example-mdbook-lint.toml");
+
+fn run_init_command(
+    format: ConfigFormat,
+    output_path: Option<PathBuf>,
+    include_all: bool,
+    interactive: bool,
+) -> Result<()> {
+    if interactive {
+        return run_init_wizard(format, output_path);
+    }
+
+    let (content, extension) = if include_all {
+        // Use the comprehensive example config with all rules documented
+        match format {
+            ConfigFormat::Toml => (EXAMPLE_CONFIG_TOML.to_string(), "toml"),
+            ConfigFormat::Yaml => {
+                // For YAML/JSON, we still generate programmatically since the
+                // example config is TOML-specific with its comments
+                let config = Config::default();
+                (config.to_yaml_string()?, "yaml")
+            }
+            ConfigFormat::Json => {
+                let config = Config::default();
+                (config.to_json_string()?, "json")
+            }
+        }
+    } else {
+        // Generate minimal config programmatically
+        let config = Config::default();
+        match format {
+            ConfigFormat::Toml => (config.to_toml_string()?, "toml"),
+            ConfigFormat::Yaml => (config.to_yaml_string()?, "yaml"),
+            ConfigFormat::Json => (config.to_json_string()?, "json"),
+        }
+    };
+
+    let output_file =
+        output_path.unwrap_or_else(|| PathBuf::from(format!(".mdbook-lint.{extension}")));
+
+    std::fs::write(&output_file, &content).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::Io(io::Error::new(
+            e.kind(),
+            format!(
+                "Failed to write config file {}: {}",
+                output_file.display(),
+                e
+            ),
+        ))
+    })?;
+
+    println!("Configuration file created: {}", output_file.display());
+    if include_all {
+        println!("Includes documentation for all 78 available rules");
+        println!("Uncomment and modify settings as needed for your project");
+    } else {
+        println!("Run with --include-all for a comprehensive example with all rules documented");
+    }
+
+    Ok(())
+}
+
+fn run_migrate_command(input: &Path, output_path: Option<PathBuf>, force: bool) -> Result<()> {
+    use migrate::NoteKind;
+
+    let content = std::fs::read_to_string(input).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::config_error(format!(
+            "Failed to read {}: {e}",
+            input.display()
+        ))
+    })?;
+    let migration = migrate::migrate(&migrate::parse(&content, input)?)?;
+
+    let output_file = output_path.unwrap_or_else(|| PathBuf::from(".mdbook-lint.toml"));
+    if output_file.exists() && !force {
+        return Err(mdbook_lint::error::MdBookLintError::config_error(format!(
+            "{} already exists; use --force to overwrite it",
+            output_file.display()
+        )));
+    }
+
+    let content = match output_file.extension().and_then(|ext| ext.to_str()) {
+        Some("yaml") | Some("yml") => migration.config.to_yaml_string()?,
+        Some("json") => migration.config.to_json_string()?,
+        _ => migration.config.to_toml_string()?,
+    };
+    std::fs::write(&output_file, content).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::Io(io::Error::new(
+            e.kind(),
+            format!("Failed to write config file {}: {e}", output_file.display()),
+        ))
+    })?;
+
+    println!("Migrated {} to {}", input.display(), output_file.display());
+    for (kind, heading) in [
+        (NoteKind::Unmapped, "Not migrated:"),
+        (NoteKind::Differs, "Behaves differently:"),
+    ] {
+        let notes: Vec<_> = migration.notes(kind).collect();
+        if !notes.is_empty() {
+            println!("\n{heading}");
+            for note in notes {
+                println!("  {note}");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn run_upgrade_config_command(config_path: Option<PathBuf>) -> Result<()> {
+    let path = match config_path.or_else(|| Config::discover_config(None)) {
+        Some(path) => path,
+        None => {
+            return Err(mdbook_lint::error::MdBookLintError::config_error(
+                "No configuration file found; create one with `mdbook-lint init`".to_string(),
+            ));
+        }
+    };
+    let config = Config::from_file(&path)?;
+    let engine = create_engine(&config)?;
+    let registry = engine.registry();
+    let enabled = registry.held_back_rules(&config.core);
+
+    let current = config.core.ruleset_version.as_deref();
+    let version = upgrade::target_version(
+        registry.latest_rule_version(),
+        current.and_then(mdbook_lint_core::config::parse_version),
+    );
+    let content = std::fs::read_to_string(&path).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::config_error(format!(
+            "Failed to read {}: {e}",
+            path.display()
+        ))
+    })?;
+    let updated = upgrade::set_ruleset_version(&content, &path, &version)?;
+    std::fs::write(&path, updated).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::Io(io::Error::new(
+            e.kind(),
+            format!("Failed to write config file {}: {e}", path.display()),
+        ))
+    })?;
+
+    match current {
+        Some(old) => println!(
+            "Updated ruleset-version in {} from {old} to {version}",
+            path.display()
+        ),
+        None => println!("Set ruleset-version in {} to {version}", path.display()),
+    }
+    if enabled.is_empty() {
+        println!("No new rules were enabled");
+    } else {
+        println!("\nNow running:");
+        for id in enabled {
+            let description = registry
+                .get_rule(id)
+                .map(|rule| rule.description())
+                .unwrap_or_default();
+            println!("  {id}  {description}");
+        }
+    }
+
+    Ok(())
+}
+
+/// Directory the init wizard scans: the book's `src` when run from a book
+/// root, otherwise the current directory
+fn wizard_scan_dir() -> PathBuf {
+    let Ok(book_toml) = std::fs::read_to_string("book.toml") else {
+        return PathBuf::from(".");
+    };
+    let src = toml::from_str::<toml::Value>(&book_toml)
+        .ok()
+        .and_then(|value| value.get("book")?.get("src")?.as_str().map(str::to_string))
+        .unwrap_or_else(|| "src".to_string());
+    PathBuf::from(src)
+}
+
+fn run_init_wizard(format: ConfigFormat, output_path: Option<PathBuf>) -> Result<()> {
+    let extension = match format {
+        ConfigFormat::Toml => "toml",
+        ConfigFormat::Yaml => "yaml",
+        ConfigFormat::Json => "json",
+    };
+    let output_file =
+        output_path.unwrap_or_else(|| PathBuf::from(format!(".mdbook-lint.{extension}")));
+
+    let scan_dir = wizard_scan_dir();
+    let mut markdown_files = Vec::new();
+    collect_markdown_files(&scan_dir, &mut markdown_files)?;
+    markdown_files.sort();
+    let documents = markdown_files
+        .iter()
+        .filter_map(|path| {
+            let content = std::fs::read_to_string(path).ok()?;
+            Document::new(content, path.clone()).ok()
+        })
+        .collect::<Vec<_>>();
+    println!(
+        "Scanned {} markdown files in {}\n",
+        documents.len(),
+        scan_dir.display()
+    );
+
+    // Lint once with the defaults and once per preset so the menu can show
+    // how much each choice quiets
+    let presets = std::iter::once((None, "Default rules and severities")).chain(
+        extends::presets()
+            .into_iter()
+            .map(|(name, desc)| (Some(name), desc)),
+    );
+    let mut scans = Vec::new();
+    for (preset, description) in presets {
+        let config = extends::resolve(
+            Config {
+                extends: preset.map(str::to_string).into_iter().collect(),
+                ..Config::default()
+            },
+            &output_file,
+        )?;
+        let engine = create_engine(&config)?;
+        let results = documents
+            .iter()
+            .map(|document| {
+                let violations = engine.lint_document_with_config(document, &config.core)?;
+                Ok((document.path.display().to_string(), violations))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        scans.push(wizard::PresetScan {
+            preset,
+            description,
+            disabled_rules: config.core.disabled_rules.clone(),
+            counts: wizard::tally(&results),
+        });
+    }
+
+    let available_rules = get_all_available_rule_ids().into_iter().collect();
+    let stdin = io::stdin();
+    let mut prompter = wizard::Prompter::new(stdin.lock(), io::stdout());
+    let config = wizard::run(&mut prompter, &scans, &available_rules)?;
+
+    if output_file.exists()
+        && !prompter.confirm(
+            &format!("{} already exists. Overwrite?", output_file.display()),
+            false,
+        )?
+    {
+        println!("Left {} unchanged", output_file.display());
+        return Ok(());
+    }
+
+    let header = "Generated by `mdbook-lint init --interactive`";
+    let content = match format {
+        ConfigFormat::Toml => format!("# {header}\n\n{}", config.to_toml_string()?),
+        ConfigFormat::Yaml => format!("# {header}\n\n{}", config.to_yaml_string()?),
+        ConfigFormat::Json => config.to_json_string()?,
+    };
+    std::fs::write(&output_file, &content).map_err(|e| {
+        mdbook_lint::error::MdBookLintError::Io(io::Error::new(
+            e.kind(),
+            format!(
+                "Failed to write config file {}: {}",
+                output_file.display(),
+                e
+            ),
+        ))
+    })?;
+
+    println!("\nConfiguration file created: {}", output_file.display());
+    Ok(())
+}
+
+fn run_stats_command(
+    paths: &[String],
+    config_path: Option<&str>,
+    format: StatsFormat,
+) -> Result<()> {
+    let (config, _) = load_config(config_path, None)?;
+    let engine = create_engine(&config)?;
+
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
+        }
+    }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
+
+    let mut results = Vec::new();
+    for path in &markdown_files {
+        let bytes = std::fs::read(path).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::document_error(format!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let violations = match decode_markdown(&bytes, path, &config)? {
+            Decoded::Document(document) => {
+                engine.lint_document_with_config(&document, &config.core)?
+            }
+            Decoded::Malformed(violation) => vec![violation],
+            Decoded::Skipped => continue,
+        };
+        results.push((path.display().to_string(), violations));
+    }
+
+    let stats = stats::Stats::collect(&results);
+    match format {
+        StatsFormat::Default => print!("{}", stats.to_text()),
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&stats).unwrap()),
+        StatsFormat::Markdown => print!("{}", stats.to_markdown()),
+    }
+    Ok(())
+}
+
+/// Compare mdbook-lint's standard-rule findings with markdownlint's
+fn run_compare_command(
+    paths: &[String],
+    markdownlint: &str,
+    config_path: Option<&str>,
+    format: StatsFormat,
+    show_differences: bool,
+) -> Result<()> {
+    let (config, _) = load_config(config_path, None)?;
+    let engine = create_engine(&config)?;
+
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
+        }
+    }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
+
+    let mut ours = Vec::new();
+    for path in &markdown_files {
+        let bytes = std::fs::read(path).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::document_error(format!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        let Decoded::Document(document) = decode_markdown(&bytes, path, &config)? else {
+            continue;
+        };
+        let file = path.display().to_string();
+        ours.extend(
+            engine
+                .lint_document_with_config(&document, &config.core)?
+                .iter()
+                .filter_map(|violation| compare::Finding::from_violation(&file, violation)),
+        );
+    }
+    let theirs = compare::run_markdownlint(markdownlint, &markdown_files)?;
+
+    let comparison = compare::Comparison::new(markdown_files.len(), ours, theirs);
+    match format {
+        StatsFormat::Default => print!("{}", comparison.to_text(show_differences)),
+        StatsFormat::Json => println!("{}", serde_json::to_string_pretty(&comparison).unwrap()),
+        StatsFormat::Markdown => print!("{}", comparison.to_markdown()),
+    }
+    Ok(())
+}
+
+/// List the TODO and placeholder markers CONTENT001 and CONTENT002 find
+///
+/// The two rules run even if the configuration disables them; their
+/// settings, such as custom markers, still apply.
+#[cfg(feature = "content")]
+fn run_todos_command(
+    paths: &[String],
+    config_path: Option<&str>,
+    format: TodosFormat,
+    deny_todos: bool,
+) -> Result<()> {
+    let (mut config, _) = load_config(config_path, None)?;
+    config.core.enabled_rules = todos::TODO_RULES.iter().map(|id| id.to_string()).collect();
+    config.core.disabled_rules.clear();
+    config.core.enabled_categories.clear();
+    config.core.disabled_categories.clear();
+    let engine = create_engine(&config)?;
+
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
+        }
+    }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
+
+    let mut results = Vec::new();
+    for path in &markdown_files {
+        let bytes = std::fs::read(path).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::document_error(format!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        if let Decoded::Document(document) = decode_markdown(&bytes, path, &config)? {
+            let violations = engine.lint_document_with_config(&document, &config.core)?;
+            results.push((path.display().to_string(), violations));
+        }
+    }
+
+    let report = todos::TodoReport::collect(&results);
+    match format {
+        TodosFormat::Default => print!("{}", report.to_text()),
+        TodosFormat::Json => println!("{}", serde_json::to_string_pretty(&report).unwrap()),
     }
 
+    if deny_todos && report.total > 0 {
+        process::exit(exit_code::VIOLATIONS);
+    }
     Ok(())
 }
 
-/// Find a similar rule name for typo suggestions
-fn find_similar_rule(input: &str, available: &std::collections::HashSet<String>) -> Option<String> {
-    let input_lower = input.to_lowercase();
+/// List the inline suppression comments in the given paths, with the rules
+/// they turn off and the reasons given
+fn run_suppressions_command(
+    paths: &[String],
+    config_path: Option<&str>,
+    format: SuppressionsFormat,
+) -> Result<()> {
+    let (config, _) = load_config(config_path, None)?;
 
-    // First pass: check for case-insensitive exact match
-    for rule in available {
-        if input_lower == rule.to_lowercase() {
-            return Some(rule.clone());
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
         }
     }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
 
-    // Second pass: find closest match by Levenshtein distance
-    let mut best_match: Option<(String, usize)> = None;
-
-    for rule in available {
-        let rule_lower = rule.to_lowercase();
-        let distance = levenshtein_distance(&input_lower, &rule_lower);
-
-        // Only consider matches with distance <= 2
-        if distance <= 2 && (best_match.is_none() || distance < best_match.as_ref().unwrap().1) {
-            best_match = Some((rule.clone(), distance));
+    let mut results = Vec::new();
+    for path in &markdown_files {
+        let bytes = std::fs::read(path).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::document_error(format!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        if let Decoded::Document(document) = decode_markdown(&bytes, path, &config)? {
+            let suppressions = Suppressions::from_document(&document);
+            results.push((path.display().to_string(), suppressions));
         }
     }
 
-    best_match.map(|(rule, _)| rule)
+    let report = suppressions::SuppressionReport::collect(&results);
+    match format {
+        SuppressionsFormat::Default => print!("{}", report.to_text()),
+        SuppressionsFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&report).unwrap())
+        }
+    }
+    Ok(())
 }
 
-/// Simple Levenshtein distance implementation for typo detection
-fn levenshtein_distance(a: &str, b: &str) -> usize {
-    let a_chars: Vec<char> = a.chars().collect();
-    let b_chars: Vec<char> = b.chars().collect();
-    let a_len = a_chars.len();
-    let b_len = b_chars.len();
-
-    if a_len == 0 {
-        return b_len;
-    }
-    if b_len == 0 {
-        return a_len;
+/// Run the lint daemon until a client sends `shutdown`
+///
+/// The configuration is loaded and the engine built once, up front.
+#[cfg(unix)]
+fn run_serve_command(socket: &Path, config_path: Option<&str>) -> Result<()> {
+    let (config, _) = load_config(config_path, None)?;
+    let engine = create_engine(&config)?;
+    engine.registry().report_deprecations(&config.core);
+    if let Some(notice) = engine.registry().ruleset_notice(&config.core) {
+        tracing::warn!("{notice}");
     }
+    let daemon = serve::Daemon::new(Box::new(move |path, content| {
+        if path_is_ignored(path, &config.core.ignore_paths) {
+            return Ok(serve::Linted {
+                violations: Vec::new(),
+                cacheable: true,
+            });
+        }
+        let document = Document::new(content, path.to_path_buf())
+            .map_err(|e| e.to_string())?
+            .with_extensions(config.core.markdown);
+        // Rules that read other files can change their answer without the
+        // document changing
+        let cacheable = !engine
+            .registry()
+            .get_enabled_rules_with_overrides(&document, &config.core.for_document(&document))
+            .iter()
+            .any(|rule| rule.metadata().filesystem);
+        let violations = engine
+            .lint_document_with_config(&document, &config.core)
+            .map_err(|e| e.to_string())?;
+        Ok(serve::Linted {
+            violations,
+            cacheable,
+        })
+    }));
+
+    eprintln!("mdbook-lint daemon listening on {}", socket.display());
+    serve::serve(socket, Arc::new(daemon))?;
+    Ok(())
+}
 
-    let mut matrix = vec![vec![0; b_len + 1]; a_len + 1];
+#[cfg(not(unix))]
+fn run_serve_command(_socket: &Path, _config_path: Option<&str>) -> Result<()> {
+    Err(mdbook_lint::error::MdBookLintError::config_error(
+        "serve needs Unix domain sockets, which are not available on this platform",
+    ))
+}
 
-    for (i, row) in matrix.iter_mut().enumerate().take(a_len + 1) {
-        row[0] = i;
-    }
-    for (j, val) in matrix[0].iter_mut().enumerate().take(b_len + 1) {
-        *val = j;
-    }
+/// Type-check every Rust code block in the given files with rustc
+fn run_check_code_command(
+    paths: &[String],
+    config_path: Option<&str>,
+    edition: &str,
+    rustc: &str,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let (config, _) = load_config(config_path, None)?;
 
-    for (i, a_char) in a_chars.iter().enumerate() {
-        for (j, b_char) in b_chars.iter().enumerate() {
-            let cost = if a_char == b_char { 0 } else { 1 };
-            matrix[i + 1][j + 1] = (matrix[i][j + 1] + 1)
-                .min(matrix[i + 1][j] + 1)
-                .min(matrix[i][j] + cost);
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
         }
     }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
 
-    matrix[a_len][b_len]
-}
+    let mut blocks = Vec::new();
+    for path in &markdown_files {
+        let bytes = std::fs::read(path).map_err(|e| {
+            mdbook_lint::error::MdBookLintError::document_error(format!(
+                "Failed to read file {}: {e}",
+                path.display()
+            ))
+        })?;
+        if let Decoded::Document(document) = decode_markdown(&bytes, path, &config)? {
+            let file = path.display().to_string();
+            blocks.extend(
+                check_code::extract_rust_blocks(&document.content)
+                    .into_iter()
+                    .map(|block| (file.clone(), block)),
+            );
+        }
+    }
 
-/// Comprehensive example configuration with all rules documented.
-/// This is embedded from example-mdbook-lint.toml at compile time.
-const EXAMPLE_CONFIG_TOML: &str = include_str!("../example-mdbook-lint.toml");
+    // Blocks compile independently, so run rustc on all of them at once
+    let results = blocks
+        .par_iter()
+        .map(|(file, block)| {
+            check_code::check_block(block, rustc, edition).map(|violations| (file, violations))
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut violations_by_file: Vec<(String, Vec<Violation>)> = Vec::new();
+    for (file, violations) in results {
+        match violations_by_file.last_mut() {
+            Some((last, existing)) if last == file => existing.extend(violations),
+            _ => violations_by_file.push((file.clone(), violations)),
+        }
+    }
+    violations_by_file.retain(|(_, violations)| !violations.is_empty());
+    let total_violations: usize = violations_by_file.iter().map(|(_, v)| v.len()).sum();
 
-fn run_init_command(
-    format: ConfigFormat,
-    output_path: Option<PathBuf>,
-    include_all: bool,
-) -> Result<()> {
-    let (content, extension) = if include_all {
-        // Use the comprehensive example config with all rules documented
-        match format {
-            ConfigFormat::Toml => (EXAMPLE_CONFIG_TOML.to_string(), "toml"),
-            ConfigFormat::Yaml => {
-                // For YAML/JSON, we still generate programmatically since the
-                // example config is TOML-specific with its comments
-                let config = Config::default();
-                (config.to_yaml_string()?, "yaml")
-            }
-            ConfigFormat::Json => {
-                let config = Config::default();
-                (config.to_json_string()?, "json")
-            }
+    match output_format {
+        OutputFormat::Default => {
+            output::print_cargo_style(&violations_by_file);
+            output::print_summary(total_violations, total_violations, 0, false);
         }
-    } else {
-        // Generate minimal config programmatically
-        let config = Config::default();
-        match format {
-            ConfigFormat::Toml => (config.to_toml_string()?, "toml"),
-            ConfigFormat::Yaml => (config.to_yaml_string()?, "yaml"),
-            ConfigFormat::Json => (config.to_json_string()?, "json"),
+        OutputFormat::Json => {
+            let output = serde_json::json!({
+                "blocks": blocks.len(),
+                "total_violations": total_violations,
+                "files": violations_by_file.iter().map(|(file, violations)| {
+                    serde_json::json!({
+                        "file": file,
+                        "violations": violations
+                    })
+                }).collect::<Vec<_>>()
+            });
+            println!("{}", serde_json::to_string_pretty(&output).unwrap());
         }
-    };
-
-    let output_file =
-        output_path.unwrap_or_else(|| PathBuf::from(format!(".mdbook-lint.{extension}")));
-
-    std::fs::write(&output_file, &content).map_err(|e| {
-        mdbook_lint::error::MdBookLintError::config_error(format!(
-            "Failed to write config file {}: {}",
-            output_file.display(),
-            e
-        ))
-    })?;
-
-    println!("Configuration file created: {}", output_file.display());
-    if include_all {
-        println!("Includes documentation for all 78 available rules");
-        println!("Uncomment and modify settings as needed for your project");
-    } else {
-        println!("Run with --include-all for a comprehensive example with all rules documented");
+        OutputFormat::Github => github::print_annotations(&violations_by_file),
+        OutputFormat::GithubSummary => github::write_step_summary(&violations_by_file)?,
     }
 
+    if total_violations > 0 {
+        process::exit(exit_code::VIOLATIONS);
+    }
     Ok(())
 }
 
+/// Create an engine with every compiled-in rule provider
+fn create_engine(config: &Config) -> Result<mdbook_lint_core::LintEngine> {
+    plugin_registry()?.create_engine_with_config(Some(&config.core))
+}
+
+/// Registry with every rule provider enabled in this build
+fn plugin_registry() -> Result<PluginRegistry> {
+    let mut registry = PluginRegistry::new();
+    registry.register_provider(Box::new(StandardRuleProvider))?;
+    registry.register_provider(Box::new(MdBookRuleProvider))?;
+    #[cfg(feature = "content")]
+    registry.register_provider(Box::new(ContentRuleProvider))?;
+    #[cfg(feature = "adr")]
+    registry.register_provider(Box::new(AdrRuleProvider))?;
+    #[cfg(feature = "rfc")]
+    registry.register_provider(Box::new(RfcRuleProvider))?;
+    #[cfg(feature = "changelog")]
+    registry.register_provider(Box::new(ChangelogRuleProvider))?;
+    #[cfg(feature = "readme")]
+    registry.register_provider(Box::new(ReadmeRuleProvider))?;
+    #[cfg(feature = "frontmatter")]
+    registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+    #[cfg(feature = "spelling")]
+    registry.register_provider(Box::new(SpellingRuleProvider))?;
+    Ok(registry)
+}
+
 fn run_supports_check(renderer: &str) -> Result<()> {
     // mdBook preprocessors should support all renderers by default
     // unless they have specific renderer requirements
@@ -1797,30 +3513,7 @@ fn run_rustdoc_mode(
     }
 
     // Load configuration
-    let (mut config, config_source) = if let Some(path) = config_path {
-        let config_content = std::fs::read_to_string(path).map_err(|e| {
-            mdbook_lint::error::MdBookLintError::config_error(format!(
-                "Failed to read config file {path}: {e}"
-            ))
-        })?;
-
-        let cfg = if path.ends_with(".toml") {
-            Config::from_toml_str(&config_content)?
-        } else if path.ends_with(".yaml") || path.ends_with(".yml") {
-            Config::from_yaml_str(&config_content)?
-        } else if path.ends_with(".json") {
-            Config::from_json_str(&config_content)?
-        } else {
-            config_content.parse()?
-        };
-        (cfg, Some(path.to_string()))
-    } else if let Some(discovered_path) = Config::discover_config(None) {
-        let path_str = discovered_path.display().to_string();
-        let cfg = Config::from_file(&discovered_path)?;
-        (cfg, Some(path_str))
-    } else {
-        (Config::default(), None)
-    };
+    let (mut config, config_source) = load_config(config_path, None)?;
 
     if verbose && let Some(ref path) = config_source {
         output::print_status("Config", path);
@@ -1865,6 +3558,16 @@ fn run_rustdoc_mode(
     registry.register_provider(Box::new(ContentRuleProvider))?;
     #[cfg(feature = "adr")]
     registry.register_provider(Box::new(AdrRuleProvider))?;
+    #[cfg(feature = "rfc")]
+    registry.register_provider(Box::new(RfcRuleProvider))?;
+    #[cfg(feature = "changelog")]
+    registry.register_provider(Box::new(ChangelogRuleProvider))?;
+    #[cfg(feature = "readme")]
+    registry.register_provider(Box::new(ReadmeRuleProvider))?;
+    #[cfg(feature = "frontmatter")]
+    registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+    #[cfg(feature = "spelling")]
+    registry.register_provider(Box::new(SpellingRuleProvider))?;
 
     let engine = registry.create_engine_with_config(Some(&config.core))?;
 
@@ -1891,8 +3594,6 @@ fn run_rustdoc_mode(
         output::print_status("Checking", &format!("{} Rust file(s)", rust_files.len()));
     }
 
-    let mut total_violations = 0;
-    let mut has_errors = false;
     let mut violations_by_file: Vec<(String, Vec<mdbook_lint_core::violation::Violation>)> =
         Vec::new();
 
@@ -1912,7 +3613,7 @@ fn run_rustdoc_mode(
             // Use a synthetic path that indicates this is from rustdoc
             let doc_path = PathBuf::from(format!("{}#rustdoc", source_path));
             let document = match Document::new(extracted.content.clone(), doc_path) {
-                Ok(d) => d,
+                Ok(d) => d.with_extensions(config.core.markdown),
                 Err(e) => {
                     eprintln!("Failed to parse doc from {}: {}", source_path, e);
                     continue;
@@ -1933,18 +3634,26 @@ fn run_rustdoc_mode(
             }
 
             if !violations.is_empty() {
-                total_violations += violations.len();
-                for v in &violations {
-                    if v.severity == Severity::Error {
-                        has_errors = true;
-                    }
-                }
                 violations_by_file.push((source_path, violations));
             }
         }
     }
 
+    // Violations below report-level still count toward the exit code
+    let worst = violations_by_file
+        .iter()
+        .flat_map(|(_, v)| v)
+        .map(|v| v.severity)
+        .max();
+    let failing = config.fails_lint(worst);
+    config.retain_reported(&mut violations_by_file);
+
     // Count errors and warnings
+    let total_violations: usize = violations_by_file.iter().map(|(_, v)| v.len()).sum();
+    let has_errors = violations_by_file
+        .iter()
+        .flat_map(|(_, v)| v)
+        .any(|v| v.severity == Severity::Error);
     let error_count = violations_by_file
         .iter()
         .flat_map(|(_, v)| v)
@@ -1957,9 +3666,18 @@ fn run_rustdoc_mode(
         .count();
 
     // Output results
+    // In quiet mode only errors are shown; JSON output stays complete
+    let quiet_violations;
+    let displayed = if quiet {
+        quiet_violations = output::errors_only(&violations_by_file);
+        &quiet_violations
+    } else {
+        &violations_by_file
+    };
+
     match output_format {
         OutputFormat::Default => {
-            output::print_cargo_style(&violations_by_file);
+            output::print_cargo_style(displayed);
             output::print_summary(total_violations, error_count, warning_count, quiet);
         }
         OutputFormat::Json => {
@@ -1975,38 +3693,194 @@ fn run_rustdoc_mode(
             });
             println!("{}", serde_json::to_string_pretty(&output).unwrap());
         }
-        OutputFormat::Github => {
-            for (file_path, violations) in &violations_by_file {
-                for violation in violations {
-                    let level = match violation.severity {
-                        Severity::Error => "error",
-                        Severity::Warning => "warning",
-                        Severity::Info => "notice",
-                    };
-                    println!(
-                        "::{level} file={file_path},line={}::{}: {}",
-                        violation.line, violation.rule_id, violation.message
-                    );
-                }
-            }
+        OutputFormat::Github => github::print_annotations(displayed),
+        OutputFormat::GithubSummary => github::write_step_summary(displayed)?,
+    }
+
+    if failing {
+        process::exit(exit_code::VIOLATIONS);
+    }
+
+    Ok(())
+}
+
+/// Row for the per-rule timing table
+#[derive(Tabled)]
+struct BenchTableRow {
+    #[tabled(rename = "Rule")]
+    rule: String,
+    #[tabled(rename = "Per pass")]
+    per_pass: String,
+    #[tabled(rename = "Per file")]
+    per_file: String,
+    #[tabled(rename = "Slowest file")]
+    max: String,
+    #[tabled(rename = "Share")]
+    share: String,
+    #[tabled(rename = "Violations")]
+    violations: usize,
+}
+
+fn run_bench_command(
+    paths: &[String],
+    config_path: Option<&str>,
+    iterations: usize,
+    top: Option<usize>,
+    max_rule_ms: Option<f64>,
+) -> Result<()> {
+    use mdbook_lint_core::TimingReport;
+    use timing::format_ms;
+
+    if iterations == 0 {
+        return Err(mdbook_lint::error::MdBookLintError::config_error(
+            "--iterations must be at least 1",
+        ));
+    }
+
+    let (config, _) = load_config(config_path, None)?;
+
+    let mut registry = PluginRegistry::new();
+    registry.register_provider(Box::new(StandardRuleProvider))?;
+    registry.register_provider(Box::new(MdBookRuleProvider))?;
+    #[cfg(feature = "content")]
+    registry.register_provider(Box::new(ContentRuleProvider))?;
+    #[cfg(feature = "adr")]
+    registry.register_provider(Box::new(AdrRuleProvider))?;
+    #[cfg(feature = "rfc")]
+    registry.register_provider(Box::new(RfcRuleProvider))?;
+    #[cfg(feature = "changelog")]
+    registry.register_provider(Box::new(ChangelogRuleProvider))?;
+    #[cfg(feature = "readme")]
+    registry.register_provider(Box::new(ReadmeRuleProvider))?;
+    #[cfg(feature = "frontmatter")]
+    registry.register_provider(Box::new(FrontMatterRuleProvider))?;
+    #[cfg(feature = "spelling")]
+    registry.register_provider(Box::new(SpellingRuleProvider))?;
+    let engine = registry.create_engine_with_config(Some(&config.core))?;
+
+    let mut markdown_files = Vec::new();
+    for path in paths {
+        let path = PathBuf::from(path);
+        if path.is_dir() {
+            collect_markdown_files(&path, &mut markdown_files)?;
+        } else {
+            markdown_files.push(path);
+        }
+    }
+    filter_ignored_paths(&mut markdown_files, &config.core.ignore_paths);
+    markdown_files.sort();
+
+    let documents = markdown_files
+        .iter()
+        .map(|path| {
+            let content = std::fs::read_to_string(path).map_err(|e| {
+                mdbook_lint::error::MdBookLintError::document_error(format!(
+                    "Failed to read file {}: {e}",
+                    path.display()
+                ))
+            })?;
+            Ok(Document::new(content, path.clone())?.with_extensions(config.core.markdown))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if documents.is_empty() {
+        return Err(mdbook_lint::error::MdBookLintError::config_error(
+            "No markdown files found to benchmark",
+        ));
+    }
+
+    // Files are linted one at a time so rule timings are not skewed by
+    // contention between threads
+    let mut report = TimingReport::new();
+    let memory = memory::MemorySampler::new();
+    for _ in 0..iterations {
+        for document in &documents {
+            let (_, timing) = engine.lint_document_timed_with_config(document, &config.core)?;
+            report.record(&timing);
+            memory.sample();
         }
     }
 
-    if has_errors || (total_violations > 0 && config.fail_on_warnings) {
-        process::exit(1);
+    let passes = iterations as u32;
+    let total = report.total();
+    let slowest = report.slowest();
+    let rows: Vec<BenchTableRow> = slowest
+        .iter()
+        .take(top.unwrap_or(usize::MAX))
+        .map(|(rule_id, summary)| BenchTableRow {
+            rule: rule_id.to_string(),
+            per_pass: format_ms(summary.total / passes),
+            per_file: format_ms(summary.total / summary.documents.max(1) as u32),
+            max: format_ms(summary.max),
+            share: format!(
+                "{:.1}%",
+                100.0 * summary.total.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON)
+            ),
+            violations: summary.violations / iterations,
+        })
+        .collect();
+
+    println!(
+        "Linted {} files x {iterations} passes: {} per pass ({} parsing)",
+        documents.len(),
+        format_ms(total / passes),
+        format_ms(report.parse / passes),
+    );
+    if let Some(memory) = memory.finish() {
+        println!("Memory: {}", memory.summary());
+    }
+    println!("{}", Table::new(&rows).with(Style::rounded()));
+
+    if let Some(limit) = max_rule_ms {
+        let too_slow: Vec<String> = slowest
+            .iter()
+            .filter(|(_, summary)| (summary.total / passes).as_secs_f64() * 1000.0 > limit)
+            .map(|(rule_id, summary)| format!("{rule_id} ({})", format_ms(summary.total / passes)))
+            .collect();
+        if !too_slow.is_empty() {
+            eprintln!(
+                "Rules slower than {limit} ms per pass: {}",
+                too_slow.join(", ")
+            );
+            process::exit(exit_code::VIOLATIONS);
+        }
     }
 
     Ok(())
 }
 
 fn run_preprocessor_mode() -> Result<()> {
-    preprocessor::handle_preprocessing()
+    if !preprocessor::handle_preprocessing()? {
+        process::exit(exit_code::VIOLATIONS);
+    }
+    Ok(())
+}
+
+fn run_renderer_mode() -> Result<()> {
+    if !renderer::handle_rendering()? {
+        process::exit(exit_code::VIOLATIONS);
+    }
+    Ok(())
 }
 
 #[cfg(feature = "lsp")]
-fn run_lsp_server(stdio: bool, port: Option<u16>) -> Result<()> {
+fn run_lsp_server(stdio: bool, port: Option<u16>, websocket: bool) -> Result<()> {
+    let transport = if websocket {
+        #[cfg(feature = "lsp-websocket")]
+        {
+            lsp_transport::Transport::WebSocket
+        }
+        #[cfg(not(feature = "lsp-websocket"))]
+        {
+            return Err(mdbook_lint::error::MdBookLintError::config_error(
+                "--websocket requires mdbook-lint built with the `lsp-websocket` feature",
+            ));
+        }
+    } else {
+        lsp_transport::Transport::Tcp
+    };
     tokio::runtime::Runtime::new()?
-        .block_on(async { lsp_server::run_lsp_server(stdio, port).await })
+        .block_on(async { lsp_server::run_lsp_server(stdio, port, transport).await })
 }
 
 /// Get all available rule IDs from all providers
@@ -2028,6 +3902,26 @@ fn get_all_available_rule_ids() -> Vec<String> {
     registry
         .register_provider(Box::new(AdrRuleProvider))
         .unwrap();
+    #[cfg(feature = "rfc")]
+    registry
+        .register_provider(Box::new(RfcRuleProvider))
+        .unwrap();
+    #[cfg(feature = "changelog")]
+    registry
+        .register_provider(Box::new(ChangelogRuleProvider))
+        .unwrap();
+    #[cfg(feature = "readme")]
+    registry
+        .register_provider(Box::new(ReadmeRuleProvider))
+        .unwrap();
+    #[cfg(feature = "frontmatter")]
+    registry
+        .register_provider(Box::new(FrontMatterRuleProvider))
+        .unwrap();
+    #[cfg(feature = "spelling")]
+    registry
+        .register_provider(Box::new(SpellingRuleProvider))
+        .unwrap();
 
     // Create engine to get available rules
     let engine = registry.create_engine().unwrap();
@@ -2182,6 +4076,15 @@ mod tests {
             }
             _ => panic!("Expected Init command"),
         }
+
+        let cli = Cli::try_parse_from(["mdbook-lint", "init", "-i"]).unwrap();
+        assert!(matches!(
+            cli.command,
+            Some(Commands::Init {
+                interactive: true,
+                ..
+            })
+        ));
     }
 
     #[test]
@@ -2209,6 +4112,10 @@ mod tests {
             OutputFormat::from_str("github", true).unwrap(),
             OutputFormat::Github
         );
+        assert_eq!(
+            OutputFormat::from_str("github-summary", true).unwrap(),
+            OutputFormat::GithubSummary
+        );
     }
 
     #[test]
@@ -2277,6 +4184,26 @@ mod tests {
         all_registry
             .register_provider(Box::new(AdrRuleProvider))
             .unwrap();
+        #[cfg(feature = "rfc")]
+        all_registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .unwrap();
+        #[cfg(feature = "changelog")]
+        all_registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .unwrap();
+        #[cfg(feature = "readme")]
+        all_registry
+            .register_provider(Box::new(ReadmeRuleProvider))
+            .unwrap();
+        #[cfg(feature = "frontmatter")]
+        all_registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .unwrap();
+        #[cfg(feature = "spelling")]
+        all_registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .unwrap();
         let all_engine = all_registry.create_engine().unwrap();
         let all_rules = all_engine.available_rules().len();
 
@@ -2388,6 +4315,12 @@ mod tests {
             "--help"
         ])));
         assert!(!should_infer_lint_subcommand(&args(&["mdbook-lint", "-V"])));
+        assert!(!should_infer_lint_subcommand(&args(&[
+            "mdbook-lint",
+            "-vv",
+            "lint",
+            "README.md"
+        ])));
         assert!(!should_infer_lint_subcommand(&args(&[
             "mdbook-lint",
             "check",
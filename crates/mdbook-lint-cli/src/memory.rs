@@ -0,0 +1,176 @@
+//! Resident memory measurement for `lint --timing` and `bench`
+//!
+//! [`MemorySampler`] reads the process's resident set size (RSS) after each
+//! file is linted and reports the peak and the average. RSS comes from
+//! `/proc/self/status` on Linux and from `proc_pidinfo` on macOS; elsewhere
+//! nothing is measured and the reports leave memory out.
+
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Peak and average resident memory over a run
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct MemoryStats {
+    pub peak_bytes: u64,
+    pub average_bytes: u64,
+    pub samples: u64,
+}
+
+impl MemoryStats {
+    /// One-line summary such as `peak 41.2 MiB, average 38.0 MiB`
+    pub fn summary(&self) -> String {
+        format!(
+            "peak {}, average {}",
+            format_mib(self.peak_bytes),
+            format_mib(self.average_bytes)
+        )
+    }
+}
+
+fn format_mib(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// Collects RSS samples; safe to share between the threads linting files
+#[derive(Debug, Default)]
+pub struct MemorySampler {
+    total: AtomicU64,
+    samples: AtomicU64,
+    peak: AtomicU64,
+}
+
+impl MemorySampler {
+    pub fn new() -> Self {
+        let sampler = Self::default();
+        sampler.sample();
+        sampler
+    }
+
+    /// Record the current RSS
+    pub fn sample(&self) {
+        if let Some(bytes) = resident_bytes() {
+            self.total.fetch_add(bytes, Ordering::Relaxed);
+            self.samples.fetch_add(1, Ordering::Relaxed);
+            self.peak.fetch_max(bytes, Ordering::Relaxed);
+        }
+    }
+
+    /// Peak and average of the samples, or `None` if RSS can't be read here
+    ///
+    /// The peak also covers the time between samples when the platform
+    /// tracks the process's high-water mark.
+    pub fn finish(&self) -> Option<MemoryStats> {
+        self.sample();
+        let samples = self.samples.load(Ordering::Relaxed);
+        if samples == 0 {
+            return None;
+        }
+        let peak = self.peak.load(Ordering::Relaxed);
+        Some(MemoryStats {
+            peak_bytes: peak_resident_bytes().map_or(peak, |hwm| hwm.max(peak)),
+            average_bytes: self.total.load(Ordering::Relaxed) / samples,
+            samples,
+        })
+    }
+}
+
+/// Current RSS of this process in bytes
+#[cfg(target_os = "linux")]
+fn resident_bytes() -> Option<u64> {
+    proc_status_kib("VmRSS:").map(|kib| kib * 1024)
+}
+
+/// Highest RSS this process has reached, in bytes
+#[cfg(target_os = "linux")]
+fn peak_resident_bytes() -> Option<u64> {
+    proc_status_kib("VmHWM:").map(|kib| kib * 1024)
+}
+
+/// Value of a `kB` field in `/proc/self/status`
+#[cfg(target_os = "linux")]
+fn proc_status_kib(field: &str) -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    parse_status_kib(&status, field)
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn parse_status_kib(status: &str, field: &str) -> Option<u64> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix(field))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(target_os = "macos")]
+fn resident_bytes() -> Option<u64> {
+    let mut info: libc::proc_taskinfo = unsafe { std::mem::zeroed() };
+    let size = std::mem::size_of::<libc::proc_taskinfo>() as libc::c_int;
+    // SAFETY: `info` is a writable buffer of exactly `size` bytes
+    let written = unsafe {
+        libc::proc_pidinfo(
+            libc::getpid(),
+            libc::PROC_PIDTASKINFO,
+            0,
+            (&mut info as *mut libc::proc_taskinfo).cast(),
+            size,
+        )
+    };
+    (written == size).then_some(info.pti_resident_size)
+}
+
+#[cfg(target_os = "macos")]
+fn peak_resident_bytes() -> Option<u64> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    // SAFETY: `usage` is a valid `rusage` for getrusage to fill in
+    let result = unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) };
+    // ru_maxrss is in bytes on macOS
+    (result == 0).then_some(usage.ru_maxrss as u64)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn resident_bytes() -> Option<u64> {
+    None
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+fn peak_resident_bytes() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_status_kib() {
+        let status = "Name:\tmdbook-lint\nVmHWM:\t   10240 kB\nVmRSS:\t    8192 kB\n";
+        assert_eq!(parse_status_kib(status, "VmRSS:"), Some(8192));
+        assert_eq!(parse_status_kib(status, "VmHWM:"), Some(10240));
+        assert_eq!(parse_status_kib(status, "VmSwap:"), None);
+    }
+
+    #[test]
+    fn test_summary() {
+        let stats = MemoryStats {
+            peak_bytes: 3 * 1024 * 1024,
+            average_bytes: 1024 * 1024 + 512 * 1024,
+            samples: 4,
+        };
+        assert_eq!(stats.summary(), "peak 3.0 MiB, average 1.5 MiB");
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos"))]
+    #[test]
+    fn test_sampler_measures_this_process() {
+        let sampler = MemorySampler::new();
+        sampler.sample();
+        let stats = sampler.finish().unwrap();
+        assert_eq!(stats.samples, 3);
+        assert!(stats.peak_bytes >= stats.average_bytes);
+        assert!(stats.average_bytes > 0);
+    }
+}
@@ -0,0 +1,363 @@
+//! Conversion of markdownlint configuration files
+//!
+//! [`migrate`] converts a markdownlint config with
+//! [`Config::from_markdownlint_config`] and records everything that could not
+//! be carried over exactly: unknown rules and tags, options the rules do not
+//! read, and rules whose behavior differs from markdownlint's.
+
+use crate::config::Config;
+use mdbook_lint_core::{MdBookLintError, Result};
+use std::fmt;
+use std::path::Path;
+
+/// markdownlint option names each rule reads; options not listed are dropped
+const RULE_OPTIONS: &[(&str, &[&str])] = &[
+    ("MD002", &["level"]),
+    ("MD003", &["style"]),
+    ("MD004", &["style"]),
+    ("MD007", &["indent", "start_indent", "start_indented"]),
+    ("MD009", &["br_spaces", "list_item_empty_lines", "strict"]),
+    ("MD010", &["code_blocks", "spaces_per_tab"]),
+    ("MD012", &["maximum"]),
+    (
+        "MD013",
+        &["line_length", "code_blocks", "tables", "headings"],
+    ),
+    ("MD024", &["siblings_only"]),
+    ("MD025", &["level"]),
+    ("MD026", &["punctuation"]),
+    ("MD029", &["style"]),
+    ("MD030", &["ol_multi", "ol_single", "ul_multi", "ul_single"]),
+    ("MD035", &["style"]),
+    ("MD036", &["punctuation"]),
+    ("MD043", &["headings"]),
+    ("MD044", &["names", "code_blocks", "html_elements"]),
+    ("MD046", &["style"]),
+    ("MD048", &["style"]),
+    ("MD049", &["style"]),
+    ("MD050", &["style"]),
+    ("MD051", &["ignore_case", "ignored_pattern"]),
+    ("MD052", &["ignored_labels", "shortcut_syntax"]),
+    ("MD053", &["ignored_definitions"]),
+    ("MD054", &["autolink", "inline", "reference", "url_inline"]),
+    ("MD055", &["style"]),
+    ("MD059", &["prohibited_texts"]),
+];
+
+/// What kind of gap a [`Note`] describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteKind {
+    /// The setting was dropped
+    Unmapped,
+    /// The setting was converted but behaves differently
+    Differs,
+}
+
+/// A setting that did not carry over exactly
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Note {
+    /// The markdownlint key, with `rule.option` for options
+    pub key: String,
+    pub kind: NoteKind,
+    pub message: String,
+}
+
+impl fmt::Display for Note {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// The converted config and everything that did not carry over exactly
+#[derive(Debug)]
+pub struct Migration {
+    pub config: Config,
+    pub notes: Vec<Note>,
+}
+
+impl Migration {
+    /// Notes of one kind
+    pub fn notes(&self, kind: NoteKind) -> impl Iterator<Item = &Note> {
+        self.notes.iter().filter(move |note| note.kind == kind)
+    }
+}
+
+/// Parse a markdownlint config file (`.json`, `.jsonc`, `.yaml`, or `.yml`)
+pub fn parse(content: &str, path: &Path) -> Result<serde_json::Value> {
+    let extension = path.extension().and_then(|ext| ext.to_str());
+    let parsed = match extension {
+        Some("yaml") | Some("yml") => serde_yaml::from_str(content).map_err(|e| e.to_string()),
+        _ => serde_json::from_str(&strip_json_comments(content)).map_err(|e| e.to_string()),
+    };
+    parsed.map_err(|e| {
+        MdBookLintError::config_error(format!(
+            "Failed to parse markdownlint config {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Convert a parsed markdownlint config
+///
+/// The result runs in markdownlint-compatible mode unless MD044 is
+/// configured, since that mode skips MD044.
+pub fn migrate(markdownlint: &serde_json::Value) -> Result<Migration> {
+    let Some(obj) = markdownlint.as_object() else {
+        return Err(MdBookLintError::config_error(
+            "markdownlint config must be an object of rules",
+        ));
+    };
+
+    let mut config = Config::from_markdownlint_config(markdownlint)?;
+    config.core.markdownlint_compatible = true;
+    let mut notes = Vec::new();
+    let mut note = |key: &str, kind, message: String| {
+        notes.push(Note {
+            key: key.to_string(),
+            kind,
+            message,
+        })
+    };
+
+    for (key, value) in obj {
+        if key.starts_with("//") || key == "$schema" {
+            continue;
+        }
+
+        match key.as_str() {
+            "default" => {
+                if value.as_bool() == Some(false) {
+                    note(
+                        key,
+                        NoteKind::Differs,
+                        "only the listed rules are enabled, which also turns off the mdBook \
+                         rules; add them to `enabled-rules` to keep them"
+                            .to_string(),
+                    );
+                }
+                continue;
+            }
+            "extends" => {
+                note(
+                    key,
+                    NoteKind::Unmapped,
+                    "not followed; migrate the base file and add it to `extends`".to_string(),
+                );
+                continue;
+            }
+            _ => {}
+        }
+
+        let Some(rule_id) = Config::markdownlint_to_md_rule(key) else {
+            note(
+                key,
+                NoteKind::Unmapped,
+                "not a markdownlint rule; tags and custom rules are not migrated".to_string(),
+            );
+            continue;
+        };
+        if value.as_bool() == Some(false) {
+            continue;
+        }
+
+        match rule_id.as_str() {
+            "MD002" | "MD006" => note(
+                key,
+                NoteKind::Differs,
+                format!("{rule_id} is deprecated and only runs when listed in `enabled-rules`"),
+            ),
+            "MD044" => {
+                config.core.markdownlint_compatible = false;
+                note(
+                    key,
+                    NoteKind::Differs,
+                    "markdownlint-compatible mode skips MD044, so it is left off".to_string(),
+                );
+            }
+            _ => {}
+        }
+
+        let Some(options) = value.as_object() else {
+            continue;
+        };
+        let supported = RULE_OPTIONS
+            .iter()
+            .find(|(id, _)| *id == rule_id)
+            .map_or(&[][..], |(_, options)| *options);
+        for (option, option_value) in options {
+            let option_key = format!("{key}.{option}");
+            if !supported.contains(&option.as_str()) {
+                drop_option(&mut config, &rule_id, option);
+                note(
+                    &option_key,
+                    NoteKind::Unmapped,
+                    format!("{rule_id} does not support this option"),
+                );
+                continue;
+            }
+            if rule_id == "MD029" && option == "style" {
+                match option_value.as_str() {
+                    Some("one") | Some("ordered") => {}
+                    Some("one_or_ordered") => note(
+                        &option_key,
+                        NoteKind::Differs,
+                        "mapped to `consistent`, which makes every list follow the \
+                         first list's style instead of checking each list on its own"
+                            .to_string(),
+                    ),
+                    _ => note(
+                        &option_key,
+                        NoteKind::Unmapped,
+                        format!("style {option_value} is not supported"),
+                    ),
+                }
+            }
+        }
+    }
+
+    Ok(Migration { config, notes })
+}
+
+/// Remove an option from a rule's converted config, dropping the rule's
+/// table once it is empty
+fn drop_option(config: &mut Config, rule_id: &str, option: &str) {
+    let Some(toml::Value::Table(table)) = config.core.rule_configs.get_mut(rule_id) else {
+        return;
+    };
+    table.remove(option);
+    table.remove(&option.replace('_', "-"));
+    if table.is_empty() {
+        config.core.rule_configs.remove(rule_id);
+    }
+}
+
+/// Remove `//` and `/* */` comments outside strings, as allowed in
+/// `.markdownlint.jsonc`
+fn strip_json_comments(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            match c {
+                '\\' => out.extend(chars.next()),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match (c, chars.peek()) {
+            ('"', _) => {
+                in_string = true;
+                out.push(c);
+            }
+            ('/', Some('/')) => while chars.next_if(|&c| c != '\n').is_some() {},
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_reports_unmapped_and_differing() {
+        let migration = migrate(&json!({
+            "$schema": "https://example.com/schema.json",
+            "extends": "base.json",
+            "whitespace": false,
+            "MD013": { "line_length": 100, "heading_line_length": 80 },
+            "no-inline-html": { "allowed_elements": ["br"] },
+            "ol-prefix": { "style": "one_or_ordered" },
+            "MD033": true
+        }))
+        .unwrap();
+
+        let unmapped: Vec<&str> = migration
+            .notes(NoteKind::Unmapped)
+            .map(|n| n.key.as_str())
+            .collect();
+        assert_eq!(
+            unmapped,
+            vec![
+                "MD013.heading_line_length",
+                "extends",
+                "no-inline-html.allowed_elements",
+                "whitespace"
+            ]
+        );
+        let differs: Vec<&str> = migration
+            .notes(NoteKind::Differs)
+            .map(|n| n.key.as_str())
+            .collect();
+        assert_eq!(differs, vec!["ol-prefix.style"]);
+
+        let config = &migration.config;
+        assert!(config.core.markdownlint_compatible);
+        assert!(config.get_rule_config("MD033").is_none());
+        assert_eq!(
+            config
+                .get_rule_config("MD029")
+                .unwrap()
+                .get("style")
+                .unwrap()
+                .as_str(),
+            Some("consistent")
+        );
+        assert_eq!(
+            config
+                .get_rule_config("MD013")
+                .unwrap()
+                .get("line-length")
+                .unwrap()
+                .as_integer(),
+            Some(100)
+        );
+    }
+
+    #[test]
+    fn test_migrate_proper_names_leaves_compatible_mode_off() {
+        let migration = migrate(&json!({ "proper-names": { "names": ["mdBook"] } })).unwrap();
+        assert!(!migration.config.core.markdownlint_compatible);
+        assert_eq!(migration.notes(NoteKind::Differs).count(), 1);
+    }
+
+    #[test]
+    fn test_migrate_rejects_non_object() {
+        assert!(migrate(&json!(["MD013"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_jsonc_and_yaml() {
+        let jsonc = r#"{
+  // Line length
+  "MD013": { "line_length": 100 }, /* inline */
+  "MD044": { "names": ["http://example.com"] }
+}"#;
+        let value = parse(jsonc, Path::new(".markdownlint.jsonc")).unwrap();
+        assert_eq!(value["MD013"]["line_length"], 100);
+        assert_eq!(value["MD044"]["names"][0], "http://example.com");
+
+        let value = parse(
+            "MD013:\n  line_length: 90\n",
+            Path::new(".markdownlint.yaml"),
+        )
+        .unwrap();
+        assert_eq!(value["MD013"]["line_length"], 90);
+    }
+}
@@ -1,9 +1,12 @@
 //! Cargo-style colored output for mdbook-lint
 //!
-//! This module provides formatted, colored output similar to Cargo/rustc.
+//! This module provides formatted, colored output similar to Cargo/rustc:
+//! violations are grouped by file, each with a source frame, a caret under
+//! the offending column, and a link to the rule's documentation.
 
-use anstream::println;
+use anstream::{print, println};
 use anstyle::{AnsiColor, Style};
+use mdbook_lint_core::SourceEncoding;
 use mdbook_lint_core::violation::{Severity, Violation};
 use std::fs;
 
@@ -15,6 +18,8 @@ struct OutputStyles {
     success: Style,
     bold: Style,
     blue: Style,
+    dim: Style,
+    file: Style,
 }
 
 impl Default for OutputStyles {
@@ -26,85 +31,164 @@ impl Default for OutputStyles {
             success: Style::new().fg_color(Some(AnsiColor::Green.into())).bold(),
             bold: Style::new().bold(),
             blue: Style::new().fg_color(Some(AnsiColor::Blue.into())).bold(),
+            dim: Style::new().dimmed(),
+            file: Style::new().bold().underline(),
         }
     }
 }
 
-/// Formats and prints violations in cargo-style output
+/// Formats and prints violations grouped by file, with a source frame for each
 pub fn print_cargo_style(violations_by_file: &[(String, Vec<Violation>)]) {
     let styles = OutputStyles::default();
 
     for (file_path, violations) in violations_by_file {
-        // Try to read file content for context
-        let file_content = fs::read_to_string(file_path).ok();
+        // Try to read file content for context, decoded the same way it was
+        // when linted so BOMs and UTF-16 don't shift the frame
+        let file_content = fs::read(file_path)
+            .ok()
+            .and_then(|bytes| SourceEncoding::decode(&bytes).ok())
+            .map(|(content, _)| content);
         let lines: Vec<&str> = file_content
             .as_ref()
             .map(|c| c.lines().collect())
             .unwrap_or_default();
 
-        for violation in violations {
-            print_violation(&styles, file_path, violation, &lines);
+        print!("{}", render_file(&styles, file_path, violations, &lines));
+    }
+}
+
+/// Render one file's header followed by each of its violations
+fn render_file(
+    styles: &OutputStyles,
+    file_path: &str,
+    violations: &[Violation],
+    lines: &[&str],
+) -> String {
+    let count = |severity: Severity| violations.iter().filter(|v| v.severity == severity).count();
+    let mut counts = Vec::new();
+    for (severity, label) in [
+        (Severity::Error, "error"),
+        (Severity::Warning, "warning"),
+        (Severity::Info, "info"),
+    ] {
+        match count(severity) {
+            0 => {}
+            1 => counts.push(format!("1 {label}")),
+            n if severity == Severity::Info => counts.push(format!("{n} {label}")),
+            n => counts.push(format!("{n} {label}s")),
         }
     }
+
+    let mut out = format!(
+        "{underline}{file_path}{underline:#} {dim}({}){dim:#}\n\n",
+        counts.join(", "),
+        underline = styles.file,
+        dim = styles.dim,
+    );
+
+    let mut sorted: Vec<&Violation> = violations.iter().collect();
+    sorted.sort_by_key(|v| (v.line, v.column));
+    for violation in sorted {
+        out.push_str(&render_violation(styles, file_path, violation, lines));
+    }
+    out
 }
 
-/// Print a single violation with cargo-style formatting
-fn print_violation(styles: &OutputStyles, file_path: &str, violation: &Violation, lines: &[&str]) {
+/// Render a single violation with a source frame and a link to the rule docs
+fn render_violation(
+    styles: &OutputStyles,
+    file_path: &str,
+    violation: &Violation,
+    lines: &[&str],
+) -> String {
     let (severity_style, severity_label) = match violation.severity {
         Severity::Error => (styles.error, "error"),
         Severity::Warning => (styles.warning, "warning"),
         Severity::Info => (styles.info, "info"),
     };
+    let blue = styles.blue;
 
-    // Line 1: severity[RULE_ID]: message
-    println!(
-        "{severity_style}{severity_label}[{}]{severity_style:#}: {}",
-        violation.rule_id, violation.message
-    );
-
-    // Line 2: --> file:line:column
-    println!(
-        "  {blue}-->{blue:#} {file_path}:{line}:{col}",
-        blue = styles.blue,
-        line = violation.line,
-        col = violation.column
+    // severity[RULE_ID]: message
+    let mut out = format!(
+        "{severity_style}{severity_label}[{}]{severity_style:#}{bold}: {}{bold:#}\n",
+        violation.rule_id,
+        violation.message,
+        bold = styles.bold,
     );
 
-    // Show source context if available
-    if let Some(source_line) = lines.get(violation.line.saturating_sub(1)) {
-        let line_num = violation.line;
-        let line_num_width = line_num.to_string().len().max(2);
-
-        // Empty line with pipe
-        println!(
-            "  {blue}{:>width$} |{blue:#}",
-            "",
-            width = line_num_width,
-            blue = styles.blue
-        );
-
-        // Source line
-        println!(
-            "  {blue}{:>width$} |{blue:#} {source_line}",
-            line_num,
-            width = line_num_width,
-            blue = styles.blue
-        );
+    // --> file:line:column
+    let index = violation.line.saturating_sub(1);
+    let source_line = lines.get(index);
+    let last_line = if source_line.is_some() {
+        (violation.line + 1).min(lines.len())
+    } else {
+        violation.line
+    };
+    let width = last_line.to_string().len().max(2);
+    out.push_str(&format!(
+        "{:>width$}{blue}-->{blue:#} {file_path}:{}:{}\n",
+        "", violation.line, violation.column
+    ));
 
-        // Underline/caret line
+    // Source frame: the offending line with one line of context either side
+    if let Some(source_line) = source_line {
+        out.push_str(&frame_line(styles, width, "", "", Style::new()));
+        if index > 0 {
+            let number = (violation.line - 1).to_string();
+            out.push_str(&frame_line(
+                styles,
+                width,
+                &number,
+                lines[index - 1],
+                styles.dim,
+            ));
+        }
+        let number = violation.line.to_string();
+        out.push_str(&frame_line(
+            styles,
+            width,
+            &number,
+            source_line,
+            Style::new(),
+        ));
         let caret_pos = violation.column.saturating_sub(1);
         let underline = build_underline(source_line, caret_pos, &violation.rule_name);
-        println!(
-            "  {blue}{:>width$} |{blue:#} {severity_style}{underline}{severity_style:#}",
+        out.push_str(&frame_line(styles, width, "", &underline, severity_style));
+        if let Some(after) = lines.get(index + 1) {
+            let number = (violation.line + 1).to_string();
+            out.push_str(&frame_line(styles, width, &number, after, styles.dim));
+        }
+    }
+
+    // = docs: URL
+    if let Some(url) = &violation.url {
+        out.push_str(&format!(
+            " {blue}{:>width$} ={blue:#} {bold}docs{bold:#}: {url}\n",
             "",
-            width = line_num_width,
-            blue = styles.blue,
-            severity_style = severity_style
-        );
+            bold = styles.bold,
+        ));
     }
 
     // Empty line between violations
-    println!();
+    out.push('\n');
+    out
+}
+
+/// Render one gutter line of a source frame, expanding tabs so carets line up
+fn frame_line(
+    styles: &OutputStyles,
+    width: usize,
+    number: &str,
+    text: &str,
+    style: Style,
+) -> String {
+    let blue = styles.blue;
+    if text.is_empty() {
+        format!(" {blue}{number:>width$} |{blue:#}\n")
+    } else {
+        let text = text.replace('\t', "    ");
+        format!(" {blue}{number:>width$} |{blue:#} {style}{text}{style:#}\n")
+    }
 }
 
 /// Build the underline string with carets pointing to the issue
@@ -163,7 +247,24 @@ fn determine_underline_length(source_line: &str, start_pos: usize) -> usize {
     len.max(1)
 }
 
-/// Print summary line
+/// Keep only error-severity violations, dropping files left without any
+pub fn errors_only(
+    violations_by_file: &[(String, Vec<Violation>)],
+) -> Vec<(String, Vec<Violation>)> {
+    violations_by_file
+        .iter()
+        .filter_map(|(file, violations)| {
+            let errors: Vec<Violation> = violations
+                .iter()
+                .filter(|v| v.severity == Severity::Error)
+                .cloned()
+                .collect();
+            (!errors.is_empty()).then(|| (file.clone(), errors))
+        })
+        .collect()
+}
+
+/// Print summary line (nothing in quiet mode)
 pub fn print_summary(
     total_violations: usize,
     error_count: usize,
@@ -172,13 +273,15 @@ pub fn print_summary(
 ) {
     let styles = OutputStyles::default();
 
+    if quiet {
+        return;
+    }
+
     if total_violations == 0 {
-        if !quiet {
-            println!(
-                "{success}No issues found{success:#}",
-                success = styles.success
-            );
-        }
+        println!(
+            "{success}No issues found{success:#}",
+            success = styles.success
+        );
     } else {
         let mut parts = Vec::new();
 
@@ -214,6 +317,7 @@ pub fn print_summary(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::rule::builtin_doc_url;
 
     #[test]
     fn test_determine_underline_length() {
@@ -229,4 +333,141 @@ mod tests {
         assert!(underline.contains("^"));
         assert!(underline.contains("heading-increment"));
     }
+
+    fn plain_styles() -> OutputStyles {
+        OutputStyles {
+            error: Style::new(),
+            warning: Style::new(),
+            info: Style::new(),
+            success: Style::new(),
+            bold: Style::new(),
+            blue: Style::new(),
+            dim: Style::new(),
+            file: Style::new(),
+        }
+    }
+
+    fn violation(rule_id: &str, line: usize, column: usize, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: "rule-name".to_string(),
+            message: "Something is wrong".to_string(),
+            line,
+            column,
+            severity,
+            fix: None,
+            url: builtin_doc_url(rule_id),
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_render_file_groups_and_frames() {
+        let lines = ["# Title", "", "Some\ttext", "last"];
+        let violations = vec![
+            violation("MD010", 3, 6, Severity::Warning),
+            violation("MD001", 1, 1, Severity::Error),
+            violation("CONTENT003", 1, 1, Severity::Warning),
+        ];
+
+        let out = render_file(&plain_styles(), "a.md", &violations, &lines);
+        let expected = "\
+a.md (1 error, 2 warnings)
+
+error[MD001]: Something is wrong
+  --> a.md:1:1
+    |
+  1 | # Title
+    | ^ rule-name
+  2 |
+    = docs: https://joshrotenberg.github.io/mdbook-lint/rules/standard/md001.html
+
+warning[CONTENT003]: Something is wrong
+  --> a.md:1:1
+    |
+  1 | # Title
+    | ^ rule-name
+  2 |
+
+warning[MD010]: Something is wrong
+  --> a.md:3:6
+    |
+  2 |
+  3 | Some    text
+    |         ^^^^ rule-name
+  4 | last
+    = docs: https://joshrotenberg.github.io/mdbook-lint/rules/standard/md010.html
+
+";
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn test_render_violation_without_source() {
+        let out = render_violation(
+            &plain_styles(),
+            "<stdin>",
+            &violation("MDBOOK002", 7, 3, Severity::Error),
+            &[],
+        );
+        assert!(out.contains("  --> <stdin>:7:3\n"));
+        assert!(!out.contains('|'));
+        assert!(out.contains("rules/mdbook/mdbook002.html"));
+    }
+
+    #[test]
+    fn test_every_rule_doc_url_has_a_page() {
+        use mdbook_lint_core::PluginRegistry;
+        use mdbook_lint_core::rule::DOCS_BASE_URL;
+        use mdbook_lint_rulesets::{
+            AdrRuleProvider, ChangelogRuleProvider, ContentRuleProvider, FrontMatterRuleProvider,
+            MdBookRuleProvider, ReadmeRuleProvider, RfcRuleProvider, SpellingRuleProvider,
+            StandardRuleProvider,
+        };
+
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(StandardRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(MdBookRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(ContentRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(AdrRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(ReadmeRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine().unwrap();
+
+        let docs = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../../docs/src");
+        for rule in engine.registry().rules() {
+            if let Some(url) = rule.doc_url() {
+                let page = url
+                    .strip_prefix(&format!("{DOCS_BASE_URL}/"))
+                    .unwrap()
+                    .replace(".html", ".md");
+                assert!(
+                    docs.join(&page).exists(),
+                    "{} links to missing {page}",
+                    rule.id()
+                );
+            }
+        }
+    }
 }
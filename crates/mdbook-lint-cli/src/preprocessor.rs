@@ -1,20 +1,45 @@
 use mdbook::book::{Book, BookItem, Chapter};
 use mdbook::preprocess::{Preprocessor, PreprocessorContext};
+use mdbook::renderer::RenderContext;
 
-use crate::Config;
+use crate::config::{ChapterContent, Config};
+use crate::source_map::{SourceLocation, SourceMap, normalize};
 #[cfg(test)]
 use mdbook_lint_core::RuleCategory;
 use mdbook_lint_core::{
-    Document, LintEngine, MdBookLintError, PluginRegistry, Severity, Violation,
+    Document, DocumentKind, LintEngine, MdBookLintError, PluginRegistry, Severity, Violation,
 };
 #[cfg(feature = "adr")]
 use mdbook_lint_rulesets::AdrRuleProvider;
+#[cfg(feature = "changelog")]
+use mdbook_lint_rulesets::ChangelogRuleProvider;
 #[cfg(feature = "content")]
 use mdbook_lint_rulesets::ContentRuleProvider;
+#[cfg(feature = "frontmatter")]
+use mdbook_lint_rulesets::FrontMatterRuleProvider;
+#[cfg(feature = "readme")]
+use mdbook_lint_rulesets::ReadmeRuleProvider;
+#[cfg(feature = "rfc")]
+use mdbook_lint_rulesets::RfcRuleProvider;
+#[cfg(feature = "spelling")]
+use mdbook_lint_rulesets::SpellingRuleProvider;
 use mdbook_lint_rulesets::{MdBookRuleProvider, StandardRuleProvider};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::io::{self, Read};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Error returned by [`MdBookLint::run`] when violations fail the build
+#[derive(Debug)]
+pub struct BuildFailed(String);
+
+impl std::fmt::Display for BuildFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for BuildFailed {}
 
 /// mdbook-lint preprocessor
 pub struct MdBookLint {
@@ -24,6 +49,8 @@ pub struct MdBookLint {
     pub config: Config,
     /// Book source directory (set from PreprocessorContext in preprocessor mode)
     book_src_dir: Option<PathBuf>,
+    /// Source directory relative to the book root, used to match `chapters` patterns
+    src_dir_name: PathBuf,
 }
 
 impl MdBookLint {
@@ -44,12 +71,33 @@ impl MdBookLint {
         registry
             .register_provider(Box::new(AdrRuleProvider))
             .expect("Failed to register ADR rules");
+        #[cfg(feature = "rfc")]
+        registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .expect("Failed to register RFC rules");
+        #[cfg(feature = "changelog")]
+        registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .expect("Failed to register changelog rules");
+        #[cfg(feature = "readme")]
+        registry
+            .register_provider(Box::new(ReadmeRuleProvider))
+            .expect("Failed to register README rules");
+        #[cfg(feature = "frontmatter")]
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .expect("Failed to register frontmatter rules");
+        #[cfg(feature = "spelling")]
+        registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .expect("Failed to register spelling rules");
         let engine = registry.create_engine().expect("Failed to create engine");
 
         Self {
             config: Config::default(),
             engine,
             book_src_dir: None,
+            src_dir_name: PathBuf::from("src"),
         }
     }
 
@@ -71,12 +119,33 @@ impl MdBookLint {
         registry
             .register_provider(Box::new(AdrRuleProvider))
             .expect("Failed to register ADR rules");
+        #[cfg(feature = "rfc")]
+        registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .expect("Failed to register RFC rules");
+        #[cfg(feature = "changelog")]
+        registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .expect("Failed to register changelog rules");
+        #[cfg(feature = "readme")]
+        registry
+            .register_provider(Box::new(ReadmeRuleProvider))
+            .expect("Failed to register README rules");
+        #[cfg(feature = "frontmatter")]
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .expect("Failed to register frontmatter rules");
+        #[cfg(feature = "spelling")]
+        registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .expect("Failed to register spelling rules");
         let engine = registry.create_engine().expect("Failed to create engine");
 
         Self {
             config,
             engine,
             book_src_dir: None,
+            src_dir_name: PathBuf::from("src"),
         }
     }
 
@@ -91,46 +160,134 @@ impl MdBookLint {
         &mut self,
         ctx: &PreprocessorContext,
     ) -> mdbook_lint_core::Result<()> {
-        let book_root = &ctx.root;
+        let section = ctx
+            .config
+            .get_preprocessor("mdbook-lint")
+            .or_else(|| ctx.config.get_preprocessor("lint"));
+        self.load_config(&ctx.root, &ctx.config, section, "[preprocessor.lint]")
+    }
+
+    /// Load configuration from renderer context
+    ///
+    /// Like [`MdBookLint::load_config_from_context`], with settings from the
+    /// `[output.lint]` section of `book.toml` instead.
+    pub fn load_config_from_render_context(
+        &mut self,
+        ctx: &RenderContext,
+    ) -> mdbook_lint_core::Result<()> {
+        let section = ctx
+            .config
+            .get_renderer("mdbook-lint")
+            .or_else(|| ctx.config.get_renderer("lint"));
+        self.load_config(&ctx.root, &ctx.config, section, "[output.lint]")
+    }
 
+    /// Load the discovered config file, then `section` of `book.toml`
+    fn load_config(
+        &mut self,
+        book_root: &Path,
+        book_config: &mdbook::Config,
+        section: Option<&toml::value::Table>,
+        section_name: &str,
+    ) -> mdbook_lint_core::Result<()> {
         // Compute the book source directory from context
         // This is root + book.src (which defaults to "src")
-        let src_dir_name = ctx.config.book.src.to_str().unwrap_or("src").to_string();
+        let src_dir_name = book_config.book.src.to_str().unwrap_or("src").to_string();
         self.book_src_dir = Some(book_root.join(&src_dir_name));
+        self.src_dir_name = PathBuf::from(&src_dir_name);
 
         // First, try to discover and load .mdbook-lint.toml config file
         if let Some(discovered_path) = Config::discover_config(Some(book_root)) {
+            tracing::info!(path = %discovered_path.display(), "loaded config");
             self.config = Config::from_file(&discovered_path)?;
         }
 
-        // Then, merge with book.toml preprocessor config (takes precedence)
-        let preprocessor_config = ctx
-            .config
-            .get_preprocessor("mdbook-lint")
-            .or_else(|| ctx.config.get_preprocessor("lint"));
-
-        if let Some(config) = preprocessor_config {
+        // Then, merge with the book.toml section (takes precedence)
+        if let Some(config) = section {
+            tracing::info!("merging {section_name} settings from book.toml");
             let book_toml_config = parse_mdbook_config(config)?;
+            let book_toml_config =
+                crate::extends::resolve(book_toml_config, &book_root.join("book.toml"))?;
             self.config.merge(book_toml_config);
         }
 
         // Recreate the engine with the loaded configuration
-        let mut registry = PluginRegistry::new();
-        registry
-            .register_provider(Box::new(StandardRuleProvider))
-            .expect("Failed to register standard rules");
-        registry
-            .register_provider(Box::new(MdBookRuleProvider))
-            .expect("Failed to register mdbook rules");
-        self.engine = registry
-            .create_engine_with_config(Some(&self.config.core))
-            .expect("Failed to create configured engine");
+        self.engine = Self::configured_engine(&self.config.core)?;
+        for problem in self
+            .engine
+            .registry()
+            .validate_rule_configs(&self.config.core)
+        {
+            tracing::warn!("{problem}");
+        }
+        self.engine
+            .registry()
+            .report_deprecations(&self.config.core);
+        if let Some(notice) = self.engine.registry().ruleset_notice(&self.config.core) {
+            tracing::warn!("{notice}");
+        }
 
         Ok(())
     }
 
+    /// Create an engine with rules built from `config`
+    fn configured_engine(
+        config: &mdbook_lint_core::Config,
+    ) -> mdbook_lint_core::Result<LintEngine> {
+        let mut registry = PluginRegistry::new();
+        registry.register_provider(Box::new(StandardRuleProvider))?;
+        registry.register_provider(Box::new(MdBookRuleProvider))?;
+        registry.create_engine_with_config(Some(config))
+    }
+
+    /// Configuration for a chapter after applying matching `chapters` overrides
+    ///
+    /// Returns `None` when no override matches `source_path`.
+    fn chapter_config(&self, source_path: &Path) -> Option<mdbook_lint_core::Config> {
+        let overrides = self
+            .config
+            .chapter_overrides(&self.src_dir_name.join(source_path), &self.src_dir_name);
+        if overrides.is_empty() {
+            return None;
+        }
+
+        let mut config = self.config.core.clone();
+        for chapter in overrides {
+            chapter.apply_to(&mut config);
+        }
+        Some(config)
+    }
+
+    /// Content to lint for `chapter`: its source file with
+    /// `chapter-content = "source"`, otherwise the content mdBook passed in
+    ///
+    /// Chapters without a readable source file, such as ones generated by
+    /// other preprocessors, fall back to the passed-in content.
+    fn chapter_content(&self, chapter: &Chapter) -> String {
+        if self.config.chapter_content == Some(ChapterContent::Source)
+            && let (Some(book_src), Some(source_path)) = (&self.book_src_dir, &chapter.source_path)
+        {
+            let path = book_src.join(source_path);
+            match std::fs::read_to_string(&path) {
+                Ok(content) => return content,
+                Err(e) => tracing::debug!(
+                    path = %path.display(),
+                    "linting preprocessed content, source unreadable: {e}"
+                ),
+            }
+        }
+        chapter.content.clone()
+    }
+
     /// Process a chapter and return any violations found
+    #[cfg(test)]
     fn process_chapter(&self, chapter: &Chapter) -> mdbook_lint_core::Result<Vec<Violation>> {
+        let document = self.chapter_document(chapter)?;
+        self.lint_chapter(chapter, &document)
+    }
+
+    /// The document the rules see for `chapter`
+    fn chapter_document(&self, chapter: &Chapter) -> mdbook_lint_core::Result<Document> {
         // Create document from chapter content
         // When running in preprocessor mode, source_path is relative to the book source directory
         // We need to resolve it to an absolute path for rules that check file existence
@@ -147,20 +304,202 @@ impl MdBookLint {
             source_path
         };
 
+        // Every chapter mdBook hands over is listed in SUMMARY.md, so it is a
+        // chapter whatever its name, unless it is an ADR
+        let kind = match DocumentKind::detect(&resolved_path) {
+            DocumentKind::Adr => DocumentKind::Adr,
+            _ => DocumentKind::Chapter,
+        };
         let document = Document::with_book_src_dir(
-            chapter.content.clone(),
+            self.chapter_content(chapter),
             resolved_path,
             self.book_src_dir.clone(),
-        )?;
+        )?
+        .with_extensions(self.config.core.markdown)
+        .with_kind(kind);
+        Ok(document)
+    }
 
-        // Use optimized checking (single AST parse) with configuration
-        let violations = self
-            .engine
-            .lint_document_with_config(&document, &self.config.core)?;
+    /// Lint `document`, the content of `chapter`, with the chapter's
+    /// configuration
+    fn lint_chapter(
+        &self,
+        chapter: &Chapter,
+        document: &Document,
+    ) -> mdbook_lint_core::Result<Vec<Violation>> {
+        // Apply per-chapter overrides; rule options require rebuilding rules
+        let chapter_config = chapter
+            .source_path
+            .as_deref()
+            .and_then(|path| self.chapter_config(path));
+        if chapter_config.is_some() {
+            tracing::debug!(path = %document.path.display(), "applying chapter overrides");
+        }
+        let violations = match chapter_config {
+            Some(config) if config.rule_configs != self.config.core.rule_configs => {
+                Self::configured_engine(&config)?.lint_document_with_config(document, &config)?
+            }
+            Some(config) => self.engine.lint_document_with_config(document, &config)?,
+            None => self
+                .engine
+                .lint_document_with_config(document, &self.config.core)?,
+        };
 
+        tracing::debug!(
+            path = %document.path.display(),
+            violations = violations.len(),
+            "linted chapter"
+        );
         Ok(violations)
     }
 
+    /// Whether `include` and `exclude` let `chapter` be linted
+    ///
+    /// Chapters that other preprocessors generate have no source path, so
+    /// the patterns match their name instead.
+    fn includes_chapter(&self, chapter: &Chapter) -> bool {
+        if self.config.include.is_empty() && self.config.exclude.is_empty() {
+            return true;
+        }
+        match &chapter.source_path {
+            Some(source_path) => self
+                .config
+                .includes_chapter(&self.src_dir_name.join(source_path), &self.src_dir_name),
+            None => self
+                .config
+                .includes_chapter(Path::new(&chapter.name), &self.src_dir_name),
+        }
+    }
+
+    /// Lint every chapter of `book`, returning each file with violations
+    /// and its violations, skipping chapters that `include` and `exclude`
+    /// leave out
+    ///
+    /// Files are named by their path in the source directory. Violations in
+    /// text that an earlier preprocessor pulled in with `{{#include}}` are
+    /// reported against the included file where that can be traced (see
+    /// [`SourceMap`]). The collection rules run across the linted chapters;
+    /// book-wide violations that name no file are reported against the
+    /// source directory.
+    pub(crate) fn lint_book(
+        &self,
+        book: &Book,
+    ) -> mdbook_lint_core::Result<Vec<(PathBuf, Vec<Violation>)>> {
+        let mut chapters = Vec::new();
+        let mut documents = Vec::new();
+        for item in book.iter() {
+            if let BookItem::Chapter(chapter) = item {
+                if !self.includes_chapter(chapter) {
+                    tracing::debug!(chapter = %chapter.name, "chapter excluded from linting");
+                    continue;
+                }
+                let document = self.chapter_document(chapter)?;
+                chapters.push((chapter, self.lint_chapter(chapter, &document)?));
+                documents.push(document);
+            }
+        }
+
+        let mut elsewhere = Vec::new();
+        for (path, violations) in self.engine.lint_collection_by_file(
+            &documents,
+            &self.config.core,
+            &self.src_dir_name,
+        )? {
+            match documents.iter().position(|document| document.path == path) {
+                Some(index) => {
+                    let chapter_violations = &mut chapters[index].1;
+                    chapter_violations.extend(violations);
+                    chapter_violations.sort_by_key(|v| (v.line, v.column));
+                }
+                None => elsewhere.push((path, violations)),
+            }
+        }
+
+        let mut files: Vec<(PathBuf, Vec<Violation>)> = Vec::new();
+
+        for (chapter, violations) in chapters {
+            if violations.is_empty() {
+                continue;
+            }
+
+            let path = chapter
+                .source_path
+                .clone()
+                .unwrap_or_else(|| PathBuf::from("unknown"));
+            let source_map = self.source_map(chapter);
+            for mut violation in violations {
+                let file = match source_map
+                    .as_ref()
+                    .and_then(|map| map.locate(violation.line, violation.column))
+                {
+                    Some(location) => self.remap_violation(&mut violation, location, &path),
+                    None => path.clone(),
+                };
+
+                // A file included by several chapters is reported once
+                match files.iter_mut().find(|(existing, _)| *existing == file) {
+                    Some((_, existing)) if existing.contains(&violation) => {}
+                    Some((_, existing)) => existing.push(violation),
+                    None => files.push((file, vec![violation])),
+                }
+            }
+        }
+        files.extend(elsewhere);
+        Ok(files)
+    }
+
+    /// Map of where the lines of `chapter` came from, when earlier
+    /// preprocessors expanded includes in it
+    fn source_map(&self, chapter: &Chapter) -> Option<SourceMap> {
+        if self.config.chapter_content == Some(ChapterContent::Source) {
+            return None;
+        }
+        let book_src = self.book_src_dir.as_ref()?;
+        let path = book_src.join(chapter.source_path.as_ref()?);
+        let source = std::fs::read_to_string(&path).ok()?;
+        let source = source.strip_prefix('\u{feff}').unwrap_or(&source);
+        if source == chapter.content {
+            return None;
+        }
+
+        let map = SourceMap::new(source, &path, &chapter.content);
+        if map.is_none() {
+            tracing::debug!(
+                path = %path.display(),
+                "chapter content doesn't match its expanded source, reporting lines as received"
+            );
+        }
+        map
+    }
+
+    /// Move `violation` to `location`, returning the file to report it
+    /// against: its path in the source directory, or the full path for files
+    /// outside it
+    ///
+    /// Fixes are dropped from violations that move, since their positions
+    /// refer to the expanded chapter.
+    fn remap_violation(
+        &self,
+        violation: &mut Violation,
+        location: SourceLocation,
+        chapter_path: &Path,
+    ) -> PathBuf {
+        let file = self
+            .book_src_dir
+            .as_ref()
+            .and_then(|book_src| location.path.strip_prefix(normalize(book_src)).ok())
+            .map_or_else(|| location.path.clone(), Path::to_path_buf);
+        if file != chapter_path
+            || violation.line != location.line
+            || violation.column != location.column
+        {
+            violation.line = location.line;
+            violation.column = location.column;
+            violation.fix = None;
+        }
+        file
+    }
+
     /// Format violations for output
     fn format_violations(&self, violations: &[Violation], chapter_path: &str) -> String {
         if violations.is_empty() {
@@ -177,16 +516,23 @@ impl MdBookLint {
         output
     }
 
+    /// Determine if violations should be injected into chapters for `renderer`
+    ///
+    /// Requires `render-warnings = true`, a draft build (`draft = true` or the
+    /// `MDBOOK_LINT_DRAFT` environment variable set to `1` or `true`), and the
+    /// HTML renderer.
+    fn should_render_warnings(&self, renderer: &str) -> bool {
+        let draft = self.config.draft
+            || std::env::var("MDBOOK_LINT_DRAFT")
+                .is_ok_and(|v| v == "1" || v.eq_ignore_ascii_case("true"));
+        self.config.render_warnings && draft && renderer == "html"
+    }
+
     /// Determine if we should fail the build based on violations
-    fn should_fail_build(&self, violations: &[Violation]) -> bool {
-        for violation in violations {
-            match violation.severity {
-                Severity::Error if self.config.fail_on_errors => return true,
-                Severity::Warning if self.config.fail_on_warnings => return true,
-                _ => {}
-            }
-        }
-        false
+    pub(crate) fn should_fail_build(&self, violations: &[Violation]) -> bool {
+        violations
+            .iter()
+            .any(|violation| self.config.fails_build(violation.severity))
     }
 }
 
@@ -201,70 +547,90 @@ impl Preprocessor for MdBookLint {
         "lint"
     }
 
-    fn run(&self, _ctx: &PreprocessorContext, book: Book) -> mdbook::errors::Result<Book> {
-        let mut total_violations = Vec::new();
-        let mut should_fail = false;
-
-        // Process each chapter
-        for item in book.iter() {
-            if let BookItem::Chapter(chapter) = item {
-                let violations = self.process_chapter(chapter).map_err(|e| {
-                    mdbook::errors::Error::msg(format!("Failed to process chapter: {e}"))
-                })?;
-
-                if !violations.is_empty() {
-                    let chapter_path = chapter
-                        .source_path
-                        .as_ref()
-                        .map(|p| p.to_string_lossy())
-                        .unwrap_or("unknown".into());
-
-                    // Print violations to stderr
-                    eprint!("{}", self.format_violations(&violations, &chapter_path));
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> mdbook::errors::Result<Book> {
+        tracing::debug!(renderer = %ctx.renderer, "running preprocessor");
 
-                    if self.should_fail_build(&violations) {
-                        should_fail = true;
-                    }
+        let mut chapters = self
+            .lint_book(&book)
+            .map_err(|e| mdbook::errors::Error::msg(format!("Failed to process chapter: {e}")))?;
+        let should_fail = chapters
+            .iter()
+            .any(|(_, violations)| self.should_fail_build(violations));
+        // Violations below report-level still count toward failing the build
+        let [error_count, warning_count, _] = severity_counts(chapters.iter().flat_map(|(_, v)| v));
+        self.config.retain_reported(&mut chapters);
 
-                    total_violations.extend(violations);
-                }
+        if self.config.summary {
+            let chapter_count = book
+                .iter()
+                .filter(|item| matches!(item, BookItem::Chapter(_)))
+                .count();
+            eprint!("{}", format_summary(&chapters, chapter_count));
+        } else {
+            // Print violations to stderr
+            for (path, violations) in &chapters {
+                eprint!(
+                    "{}",
+                    self.format_violations(violations, &path.to_string_lossy())
+                );
             }
         }
 
         // Print summary
+        let total_violations: Vec<&Violation> = chapters
+            .iter()
+            .flat_map(|(_, violations)| violations)
+            .collect();
         if !total_violations.is_empty() {
-            let error_count = total_violations
-                .iter()
-                .filter(|v| v.severity == Severity::Error)
-                .count();
-            let warning_count = total_violations
-                .iter()
-                .filter(|v| v.severity == Severity::Warning)
-                .count();
-            let info_count = total_violations
-                .iter()
-                .filter(|v| v.severity == Severity::Info)
-                .count();
-
-            eprintln!(
-                "mdbook-lint: {error_count} error(s), {warning_count} warning(s), {info_count} info"
-            );
-
-            if should_fail {
-                return Err(mdbook::errors::Error::msg(format!(
-                    "mdbook-lint: Build failed due to {error_count} error(s) and {warning_count} warning(s)"
-                )));
+            let count = |severity| {
+                total_violations
+                    .iter()
+                    .filter(|v| v.severity == severity)
+                    .count()
+            };
+            if !self.config.summary {
+                eprintln!(
+                    "mdbook-lint: {} error(s), {} warning(s), {} info",
+                    count(Severity::Error),
+                    count(Severity::Warning),
+                    count(Severity::Info)
+                );
             }
-        } else {
+        } else if !self.config.summary {
             eprintln!("mdbook-lint: No issues found");
         }
 
-        // Return the book unchanged (we're just linting, not modifying)
+        if should_fail {
+            return Err(mdbook::errors::Error::new(BuildFailed(format!(
+                "mdbook-lint: Build failed due to {error_count} error(s) and {warning_count} warning(s)"
+            ))));
+        }
+
+        // In draft builds, surface the violations in the rendered chapters
+        let chapter_violations: HashMap<PathBuf, Vec<Violation>> = chapters.into_iter().collect();
+        if self.should_render_warnings(&ctx.renderer) && !chapter_violations.is_empty() {
+            tracing::debug!(
+                chapters = chapter_violations.len(),
+                "rendering warnings into chapters"
+            );
+            book.for_each_mut(|item| {
+                if let BookItem::Chapter(chapter) = item
+                    && let Some(violations) = chapter
+                        .source_path
+                        .as_ref()
+                        .and_then(|path| chapter_violations.get(path))
+                {
+                    chapter.content =
+                        insert_warning_block(&chapter.content, &render_warning_block(violations));
+                }
+            });
+        }
+
         Ok(book)
     }
 
     fn supports_renderer(&self, renderer: &str) -> bool {
-        // We support all renderers since we don't modify content
+        // Warning blocks are only injected for html; other renderers get the book unchanged
         match renderer {
             "html" | "markdown" | "epub" | "pdf" => true,
             _ => true, // Default to supporting unknown renderers
@@ -272,10 +638,153 @@ impl Preprocessor for MdBookLint {
     }
 }
 
+/// Compact summary of a book's violations: totals, one line per chapter,
+/// and the rules reported most often
+fn format_summary(chapters: &[(PathBuf, Vec<Violation>)], chapter_count: usize) -> String {
+    let describe = |[errors, warnings, info]: [usize; 3]| {
+        [
+            (errors, "error(s)"),
+            (warnings, "warning(s)"),
+            (info, "info"),
+        ]
+        .into_iter()
+        .filter(|(count, _)| *count > 0)
+        .map(|(count, label)| format!("{count} {label}"))
+        .collect::<Vec<_>>()
+        .join(", ")
+    };
+
+    if chapters.is_empty() {
+        return format!("mdbook-lint: No issues found in {chapter_count} chapter(s)\n");
+    }
+
+    let [errors, warnings, info] = severity_counts(chapters.iter().flat_map(|(_, v)| v));
+    let mut summary = format!(
+        "mdbook-lint: {errors} error(s), {warnings} warning(s), {info} info in {} of {chapter_count} chapter(s)\n",
+        chapters.len()
+    );
+    for (path, violations) in chapters {
+        summary.push_str(&format!(
+            "  {}: {}\n",
+            path.display(),
+            describe(severity_counts(violations))
+        ));
+    }
+
+    let mut by_rule: HashMap<&str, usize> = HashMap::new();
+    for (_, violations) in chapters {
+        for violation in violations {
+            *by_rule.entry(&violation.rule_id).or_default() += 1;
+        }
+    }
+    let mut by_rule: Vec<(&str, usize)> = by_rule.into_iter().collect();
+    by_rule.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+    let top: Vec<String> = by_rule
+        .iter()
+        .take(5)
+        .map(|(rule_id, count)| format!("{rule_id} ({count})"))
+        .collect();
+    summary.push_str(&format!("  Most reported: {}\n", top.join(", ")));
+    summary
+}
+
+/// Number of errors, warnings, and info violations
+fn severity_counts<'a>(violations: impl IntoIterator<Item = &'a Violation>) -> [usize; 3] {
+    let mut counts = [0; 3];
+    for violation in violations {
+        counts[match violation.severity {
+            Severity::Error => 0,
+            Severity::Warning => 1,
+            Severity::Info => 2,
+        }] += 1;
+    }
+    counts
+}
+
+/// Render violations as a styled HTML block for the top of a chapter
+fn render_warning_block(violations: &[Violation]) -> String {
+    let color = if violations.iter().any(|v| v.severity == Severity::Error) {
+        "#d9534f"
+    } else if violations.iter().any(|v| v.severity == Severity::Warning) {
+        "#f0ad4e"
+    } else {
+        "#5bc0de"
+    };
+    let plural = if violations.len() == 1 { "" } else { "s" };
+
+    let mut block = format!(
+        "<div class=\"mdbook-lint-warnings\" style=\"border-left: 4px solid {color}; \
+         padding: 0.5em 1em; margin-bottom: 1em; background: rgba(127, 127, 127, 0.1);\">\n\
+         <strong>mdbook-lint: {} issue{plural} in this chapter</strong>\n<ul>\n",
+        violations.len()
+    );
+    for violation in violations {
+        block.push_str(&format!(
+            "<li><code>{}</code> line {}: {}</li>\n",
+            violation.rule_id,
+            violation.line,
+            escape_html(&violation.message)
+        ));
+    }
+    block.push_str("</ul>\n</div>\n\n");
+    block
+}
+
+/// Insert `block` at the top of `content`, after any leading frontmatter
+fn insert_warning_block(content: &str, block: &str) -> String {
+    let mut lines = content.split_inclusive('\n');
+    let mut offset = 0;
+    if let Some(first) = lines.next()
+        && matches!(first.trim_end(), "---" | "+++")
+    {
+        let delimiter = first.trim_end();
+        let mut end = first.len();
+        for line in lines {
+            end += line.len();
+            if line.trim_end() == delimiter {
+                offset = end;
+                break;
+            }
+        }
+    }
+
+    let (frontmatter, body) = content.split_at(offset);
+    let separator = if !frontmatter.is_empty() && !frontmatter.ends_with('\n') {
+        "\n"
+    } else {
+        ""
+    };
+    format!("{frontmatter}{separator}{block}{body}")
+}
+
+/// Escape text for inclusion in HTML
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 /// Parse preprocessor configuration from mdbook config
-fn parse_mdbook_config(config: &toml::value::Table) -> mdbook_lint_core::Result<Config> {
+pub(crate) fn parse_mdbook_config(config: &toml::value::Table) -> mdbook_lint_core::Result<Config> {
     let mut preprocessor_config = Config::default();
 
+    if let Some(extends) = config.get("extends") {
+        preprocessor_config.extends = match extends {
+            toml::Value::String(entry) => vec![entry.clone()],
+            toml::Value::Array(entries) => entries
+                .iter()
+                .map(|entry| entry.as_str().map(str::to_string))
+                .collect::<Option<_>>()
+                .ok_or_else(|| MdBookLintError::config_error("extends must contain strings"))?,
+            _ => {
+                return Err(MdBookLintError::config_error(
+                    "extends must be a string or a list of strings",
+                ));
+            }
+        };
+    }
+
     if let Some(fail_on_warnings) = config.get("fail-on-warnings") {
         preprocessor_config.fail_on_warnings = fail_on_warnings
             .as_bool()
@@ -288,6 +797,83 @@ fn parse_mdbook_config(config: &toml::value::Table) -> mdbook_lint_core::Result<
             .ok_or_else(|| MdBookLintError::config_error("fail-on-errors must be a boolean"))?;
     }
 
+    if let Some(fail_on) = config.get("fail-on") {
+        preprocessor_config.fail_on = Some(
+            fail_on
+                .as_str()
+                .ok_or_else(|| MdBookLintError::config_error("fail-on must be a string"))?
+                .parse()
+                .map_err(MdBookLintError::config_error)?,
+        );
+    }
+
+    if let Some(report_level) = config.get("report-level") {
+        preprocessor_config.report_level =
+            Some(parse_severity_level(report_level, "report-level")?);
+    }
+
+    if let Some(fail_level) = config.get("fail-level") {
+        preprocessor_config.fail_level = Some(parse_severity_level(fail_level, "fail-level")?);
+    }
+
+    if let Some(summary) = config.get("summary") {
+        preprocessor_config.summary = summary
+            .as_bool()
+            .ok_or_else(|| MdBookLintError::config_error("summary must be a boolean"))?;
+    }
+
+    if let Some(chapter_content) = config.get("chapter-content") {
+        preprocessor_config.chapter_content = Some(
+            chapter_content
+                .as_str()
+                .ok_or_else(|| MdBookLintError::config_error("chapter-content must be a string"))?
+                .parse()
+                .map_err(MdBookLintError::config_error)?,
+        );
+    }
+
+    if let Some(render_warnings) = config.get("render-warnings") {
+        preprocessor_config.render_warnings = render_warnings
+            .as_bool()
+            .ok_or_else(|| MdBookLintError::config_error("render-warnings must be a boolean"))?;
+    }
+
+    if let Some(draft) = config.get("draft") {
+        preprocessor_config.draft = draft
+            .as_bool()
+            .ok_or_else(|| MdBookLintError::config_error("draft must be a boolean"))?;
+    }
+
+    if let Some(require_reason) = config.get("require-suppression-reason") {
+        preprocessor_config.core.require_suppression_reason =
+            require_reason.as_bool().ok_or_else(|| {
+                MdBookLintError::config_error("require-suppression-reason must be a boolean")
+            })?;
+    }
+
+    if let Some(ruleset_version) = config.get("ruleset-version") {
+        preprocessor_config.core.ruleset_version = Some(
+            ruleset_version
+                .as_str()
+                .ok_or_else(|| MdBookLintError::config_error("ruleset-version must be a string"))?
+                .to_string(),
+        );
+    }
+
+    if let Some(include) = config.get("include") {
+        preprocessor_config.include = parse_chapter_globs(include, "include")?;
+    }
+
+    if let Some(exclude) = config.get("exclude") {
+        preprocessor_config.exclude = parse_chapter_globs(exclude, "exclude")?;
+    }
+
+    if let Some(chapters) = config.get("chapters") {
+        preprocessor_config.chapters = chapters.clone().try_into().map_err(|e| {
+            MdBookLintError::config_error(format!("Invalid chapters configuration: {e}"))
+        })?;
+    }
+
     if let Some(enabled_categories) = config.get("enabled-categories")
         && let Some(categories_array) = enabled_categories.as_array()
     {
@@ -343,6 +929,38 @@ fn parse_mdbook_config(config: &toml::value::Table) -> mdbook_lint_core::Result<
     Ok(preprocessor_config)
 }
 
+/// Parse a `report-level` or `fail-level` value: a severity name
+fn parse_severity_level(value: &toml::Value, key: &str) -> mdbook_lint_core::Result<Severity> {
+    value.clone().try_into().map_err(|_| {
+        MdBookLintError::config_error(format!(
+            "{key} must be \"info\", \"warning\", or \"error\", not {value}"
+        ))
+    })
+}
+
+/// Parse an `include` or `exclude` value: one glob or a list of them
+fn parse_chapter_globs(value: &toml::Value, key: &str) -> mdbook_lint_core::Result<Vec<String>> {
+    let globs: Vec<String> = match value {
+        toml::Value::String(glob) => vec![glob.clone()],
+        toml::Value::Array(globs) => globs
+            .iter()
+            .map(|glob| glob.as_str().map(str::to_string))
+            .collect::<Option<_>>()
+            .ok_or_else(|| MdBookLintError::config_error(format!("{key} must contain strings")))?,
+        _ => {
+            return Err(MdBookLintError::config_error(format!(
+                "{key} must be a string or a list of strings"
+            )));
+        }
+    };
+    if let Some(invalid) = globs.iter().find(|glob| glob::Pattern::new(glob).is_err()) {
+        return Err(MdBookLintError::config_error(format!(
+            "{key} has an invalid glob pattern: {invalid}"
+        )));
+    }
+    Ok(globs)
+}
+
 /// Parse preprocessor configuration from serde_json Value (for tests)
 #[allow(dead_code)]
 fn parse_config(config: &Value) -> mdbook_lint_core::Result<Config> {
@@ -360,6 +978,24 @@ fn parse_config(config: &Value) -> mdbook_lint_core::Result<Config> {
             .ok_or_else(|| MdBookLintError::config_error("fail-on-errors must be a boolean"))?;
     }
 
+    if let Some(render_warnings) = config.get("render-warnings") {
+        preprocessor_config.render_warnings = render_warnings
+            .as_bool()
+            .ok_or_else(|| MdBookLintError::config_error("render-warnings must be a boolean"))?;
+    }
+
+    if let Some(draft) = config.get("draft") {
+        preprocessor_config.draft = draft
+            .as_bool()
+            .ok_or_else(|| MdBookLintError::config_error("draft must be a boolean"))?;
+    }
+
+    if let Some(chapters) = config.get("chapters") {
+        preprocessor_config.chapters = serde_json::from_value(chapters.clone()).map_err(|e| {
+            MdBookLintError::config_error(format!("Invalid chapters configuration: {e}"))
+        })?;
+    }
+
     if let Some(enabled_categories) = config.get("enabled-categories")
         && let Some(categories_array) = enabled_categories.as_array()
     {
@@ -424,7 +1060,7 @@ fn parse_config(config: &Value) -> mdbook_lint_core::Result<Config> {
 ///
 /// However, we preserve `__non_exhaustive` fields because mdbook's Book struct requires them
 /// for deserialization (they deserialize to `()` which accepts null).
-fn strip_null_values(value: Value) -> Value {
+pub(crate) fn strip_null_values(value: Value) -> Value {
     match value {
         Value::Array(arr) => Value::Array(arr.into_iter().map(strip_null_values).collect()),
         Value::Object(obj) => Value::Object(
@@ -448,7 +1084,7 @@ fn strip_null_values(value: Value) -> Value {
 ///
 /// The input is expected to be a JSON array: `[PreprocessorContext, Book]`
 /// Returns (normalized_value, is_mdbook_05) tuple.
-fn normalize_mdbook_json(value: Value) -> (Value, bool) {
+pub(crate) fn normalize_mdbook_json(value: Value) -> (Value, bool) {
     let is_05 = detect_mdbook_05_format(&value);
     let normalized = normalize_mdbook_json_inner(value);
     (normalized, is_05)
@@ -529,17 +1165,27 @@ fn denormalize_for_mdbook_05(value: Value) -> Value {
 }
 
 /// Handle the preprocessor protocol (stdin/stdout communication with mdbook)
-pub fn handle_preprocessing() -> mdbook_lint_core::Result<()> {
-    let mut stdin = io::stdin();
+///
+/// Returns `false` when violations fail the build, after reporting why on
+/// stderr; the book is not written back in that case.
+pub fn handle_preprocessing() -> mdbook_lint_core::Result<bool> {
     let mut input = String::new();
-    stdin
+    io::stdin()
         .read_to_string(&mut input)
         .map_err(MdBookLintError::Io)?;
+    preprocess(&input)
+}
 
+/// Run the preprocessor on `input`, the `[context, book]` JSON mdBook sends,
+/// and print the processed book
+///
+/// Returns `false` when violations fail the build, as
+/// [`handle_preprocessing`] does.
+pub fn preprocess(input: &str) -> mdbook_lint_core::Result<bool> {
     // Parse as generic JSON first, then normalize and clean before deserializing.
     // 1. Normalize: Convert mdbook 0.5.x format (items) to 0.4.x format (sections)
     // 2. Strip nulls: Remove null values that toml::Value can't handle
-    let json_value: Value = serde_json::from_str(&input).map_err(MdBookLintError::Json)?;
+    let json_value: Value = serde_json::from_str(input).map_err(MdBookLintError::Json)?;
     let (normalized, is_mdbook_05) = normalize_mdbook_json(json_value);
     let cleaned = strip_null_values(normalized);
 
@@ -549,9 +1195,18 @@ pub fn handle_preprocessing() -> mdbook_lint_core::Result<()> {
     let mut preprocessor = MdBookLint::new();
     preprocessor.load_config_from_context(&ctx)?;
 
-    let processed_book = preprocessor
-        .run(&ctx, book)
-        .map_err(|e| MdBookLintError::document_error(format!("Preprocessor failed: {e}")))?;
+    let processed_book = match preprocessor.run(&ctx, book) {
+        Ok(book) => book,
+        Err(e) if e.is::<BuildFailed>() => {
+            eprintln!("{e}");
+            return Ok(false);
+        }
+        Err(e) => {
+            return Err(MdBookLintError::document_error(format!(
+                "Preprocessor failed: {e}"
+            )));
+        }
+    };
 
     // Serialize the book back to JSON
     let output_value = serde_json::to_value(&processed_book).map_err(MdBookLintError::Json)?;
@@ -566,12 +1221,13 @@ pub fn handle_preprocessing() -> mdbook_lint_core::Result<()> {
     let output = serde_json::to_string(&final_output).map_err(MdBookLintError::Json)?;
 
     print!("{output}");
-    Ok(())
+    Ok(true)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::FailOn;
     use mdbook::book::Chapter;
 
     use serde_json::json;
@@ -618,6 +1274,40 @@ count threshold that is required by the linter for content validation.
         assert_eq!(violations.len(), 0);
     }
 
+    #[test]
+    fn test_process_chapter_with_chapter_overrides() {
+        let config = parse_config(&json!({
+            "chapters": {
+                "src/reference/*.md": {
+                    "MD013": { "line-length": 20 }
+                },
+                "appendix/*.md": {
+                    "disabled-rules": ["MD001"]
+                }
+            }
+        }))
+        .unwrap();
+        let preprocessor = MdBookLint::with_config(config);
+        let content = "# Level 1\n\n### Level 3\n\nThis prose line is over twenty characters.\n";
+        let lint = |path: &str| {
+            let chapter = Chapter::new("Chapter", content.to_string(), PathBuf::from(path), vec![]);
+            preprocessor.process_chapter(&chapter).unwrap()
+        };
+        let has =
+            |violations: &[Violation], rule: &str| violations.iter().any(|v| v.rule_id == rule);
+
+        let intro = lint("intro.md");
+        assert!(has(&intro, "MD001"));
+        assert!(!has(&intro, "MD013"));
+
+        let reference = lint("reference/cli.md");
+        assert!(has(&reference, "MD001"));
+        assert!(has(&reference, "MD013"));
+
+        let appendix = lint("appendix/a.md");
+        assert!(!has(&appendix, "MD001"));
+    }
+
     #[test]
     fn test_process_chapter_with_violations() {
         let preprocessor = MdBookLint::new();
@@ -930,6 +1620,8 @@ count threshold that is required by the linter for content validation.
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert!(!preprocessor.should_fail_build(&warning_violations));
 
@@ -942,6 +1634,8 @@ count threshold that is required by the linter for content validation.
             column: 1,
             severity: Severity::Error,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert!(preprocessor.should_fail_build(&error_violations));
     }
@@ -957,6 +1651,8 @@ count threshold that is required by the linter for content validation.
             column: 1,
             severity: Severity::Error,
             fix: None,
+            url: None,
+            path: None,
         }];
 
         let output = preprocessor.format_violations(&violations, "test.md");
@@ -1291,4 +1987,331 @@ count threshold that is required by the linter for content validation.
         assert!(denormalized_book.get("sections").is_none());
         assert!(denormalized_book.get("__non_exhaustive").is_none());
     }
+
+    fn violation(rule_id: &str, message: &str, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: "test".to_string(),
+            message: message.to_string(),
+            line: 3,
+            column: 1,
+            severity,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_fail_on_overrides_fail_flags() {
+        let fails = |fail_on: Option<FailOn>, severity| {
+            MdBookLint::with_config(Config {
+                fail_on_warnings: true,
+                fail_on_errors: true,
+                fail_on,
+                ..Default::default()
+            })
+            .should_fail_build(&[violation("MD001", "test", severity)])
+        };
+
+        assert!(fails(None, Severity::Warning));
+        assert!(!fails(Some(FailOn::Error), Severity::Warning));
+        assert!(fails(Some(FailOn::Error), Severity::Error));
+        assert!(fails(Some(FailOn::Warning), Severity::Warning));
+        assert!(!fails(Some(FailOn::Warning), Severity::Info));
+        assert!(!fails(Some(FailOn::Never), Severity::Error));
+    }
+
+    #[test]
+    fn test_parse_mdbook_config_fail_on_and_summary() {
+        let table: toml::value::Table =
+            toml::from_str("fail-on = \"never\"\nsummary = true\n").unwrap();
+        let config = parse_mdbook_config(&table).unwrap();
+        assert_eq!(config.fail_on, Some(FailOn::Never));
+        assert!(config.summary);
+
+        let table: toml::value::Table = toml::from_str("fail-on = \"sometimes\"\n").unwrap();
+        let err = parse_mdbook_config(&table).unwrap_err().to_string();
+        assert!(err.contains(r#"fail-on must be "error", "warning", or "never""#));
+    }
+
+    #[test]
+    fn test_parse_mdbook_config_report_and_fail_levels() {
+        let table: toml::value::Table =
+            toml::from_str("report-level = \"info\"\nfail-level = \"error\"\n").unwrap();
+        let config = parse_mdbook_config(&table).unwrap();
+        assert_eq!(config.report_level, Some(Severity::Info));
+        assert_eq!(config.fail_level, Some(Severity::Error));
+
+        // fail-level wins over fail-on and the fail flags
+        let lint = MdBookLint::with_config(Config {
+            fail_on: Some(FailOn::Never),
+            ..config
+        });
+        assert!(lint.should_fail_build(&[violation("MD001", "test", Severity::Error)]));
+        assert!(!lint.should_fail_build(&[violation("MD001", "test", Severity::Warning)]));
+
+        let table: toml::value::Table = toml::from_str("fail-level = \"fatal\"\n").unwrap();
+        let err = parse_mdbook_config(&table).unwrap_err().to_string();
+        assert!(err.contains(r#"fail-level must be "info", "warning", or "error""#));
+    }
+
+    #[test]
+    fn test_lint_book_skips_excluded_chapters() {
+        let table: toml::value::Table = toml::from_str(
+            "include = \"guide/**\"\nexclude = [\"guide/generated/*.md\", \"API*\"]\n",
+        )
+        .unwrap();
+        let preprocessor = MdBookLint::with_config(parse_mdbook_config(&table).unwrap());
+
+        let content = "# Title\n\n### Skipped level\n";
+        let mut book = Book::new();
+        for path in ["intro.md", "guide/setup.md", "guide/generated/api.md"] {
+            book.push_item(BookItem::Chapter(Chapter::new(
+                "Chapter",
+                content.to_string(),
+                path,
+                vec![],
+            )));
+        }
+        book.push_item(BookItem::Chapter(Chapter::new_draft(
+            "API Reference",
+            vec![],
+        )));
+        let mut generated = Chapter::new_draft("API Types", vec![]);
+        generated.content = content.to_string();
+        book.push_item(BookItem::Chapter(generated));
+
+        let chapters = preprocessor.lint_book(&book).unwrap();
+        let paths: Vec<_> = chapters.iter().map(|(path, _)| path.clone()).collect();
+        assert_eq!(paths, vec![PathBuf::from("guide/setup.md")]);
+
+        let table: toml::value::Table = toml::from_str("exclude = [\"[\"]\n").unwrap();
+        let err = parse_mdbook_config(&table).unwrap_err().to_string();
+        assert!(err.contains("exclude has an invalid glob pattern"));
+    }
+
+    #[test]
+    fn test_chapter_content_source() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("guide.md"),
+            "# Guide\n\n### Skipped level\n",
+        )
+        .unwrap();
+
+        let table: toml::value::Table = toml::from_str("chapter-content = \"source\"\n").unwrap();
+        let mut preprocessor = MdBookLint::with_config(parse_mdbook_config(&table).unwrap());
+        preprocessor.book_src_dir = Some(dir.path().to_path_buf());
+
+        // Earlier preprocessors replaced the skipped heading level
+        let expanded = "# Guide\n\nIncluded text.\n".to_string();
+        let lint = |preprocessor: &MdBookLint, path: &str| {
+            let chapter = Chapter::new("Guide", expanded.clone(), PathBuf::from(path), vec![]);
+            preprocessor.process_chapter(&chapter).unwrap()
+        };
+        let violations = lint(&preprocessor, "guide.md");
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.rule_id == "MD001" && v.line == 3)
+        );
+        // No source file to read, so the content mdBook passed in is linted
+        assert!(
+            lint(&preprocessor, "generated.md")
+                .iter()
+                .all(|v| v.rule_id != "MD001")
+        );
+
+        preprocessor.config.chapter_content = Some(ChapterContent::Preprocessed);
+        assert!(
+            lint(&preprocessor, "guide.md")
+                .iter()
+                .all(|v| v.rule_id != "MD001")
+        );
+
+        let table: toml::value::Table = toml::from_str("chapter-content = \"disk\"\n").unwrap();
+        let err = parse_mdbook_config(&table).unwrap_err().to_string();
+        assert!(err.contains(r#"chapter-content must be "preprocessed" or "source""#));
+    }
+
+    #[test]
+    fn test_lint_book_reports_included_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let chapter = "# Chapter\n\n{{#include shared.md}}\n\nSee [the guide][missing].\n";
+        std::fs::write(dir.path().join("chapter.md"), chapter).unwrap();
+        std::fs::write(
+            dir.path().join("other.md"),
+            "# Other\n\n{{#include shared.md}}\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("shared.md"), "Text\n\n### Skipped level\n").unwrap();
+
+        let mut preprocessor = MdBookLint::new();
+        preprocessor.book_src_dir = Some(dir.path().to_path_buf());
+
+        // Content as the links preprocessor hands it over
+        let mut book = Book::new();
+        book.push_item(BookItem::Chapter(Chapter::new(
+            "Chapter",
+            "# Chapter\n\nText\n\n### Skipped level\n\nSee [the guide][missing].\n".to_string(),
+            "chapter.md",
+            vec![],
+        )));
+        book.push_item(BookItem::Chapter(Chapter::new(
+            "Other",
+            "# Other\n\nText\n\n### Skipped level\n".to_string(),
+            "other.md",
+            vec![],
+        )));
+
+        let files = preprocessor.lint_book(&book).unwrap();
+        let violations = |path: &str| {
+            files
+                .iter()
+                .find(|(file, _)| file == Path::new(path))
+                .map(|(_, violations)| {
+                    violations
+                        .iter()
+                        .filter(|v| v.rule_id.starts_with("MD0"))
+                        .map(|v| (v.rule_id.as_str(), v.line))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+        };
+
+        assert_eq!(violations("chapter.md"), vec![("MD052", 5)]);
+        // Reported once, though both chapters include it
+        assert_eq!(violations("shared.md"), vec![("MD001", 3)]);
+        assert!(violations("other.md").is_empty());
+    }
+
+    #[test]
+    fn test_lint_book_runs_collection_rules() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("de")).unwrap();
+        for (path, content) in [
+            ("SUMMARY.md", "- [Intro](intro.md)\n- [Other](other.md)\n"),
+            ("intro.md", "# Intro\n"),
+            ("other.md", "# Other\n"),
+            ("de/SUMMARY.md", "- [Einführung](intro.md)\n"),
+            ("de/intro.md", "# Einführung\n"),
+        ] {
+            std::fs::write(dir.path().join(path), content).unwrap();
+        }
+
+        let mut preprocessor = MdBookLint::new();
+        preprocessor.book_src_dir = Some(dir.path().to_path_buf());
+
+        let mut book = Book::new();
+        for (name, path, content) in [
+            ("Intro", "intro.md", "# Intro\n"),
+            ("Other", "other.md", "# Other\n"),
+            ("Einführung", "de/intro.md", "# Einführung\n"),
+        ] {
+            book.push_item(BookItem::Chapter(Chapter::new(
+                name,
+                content.to_string(),
+                path,
+                vec![],
+            )));
+        }
+
+        let files = preprocessor.lint_book(&book).unwrap();
+        let missing: Vec<(&Path, &str)> = files
+            .iter()
+            .flat_map(|(file, violations)| violations.iter().map(move |v| (file.as_path(), v)))
+            .filter(|(_, v)| v.rule_id == "MDBOOK034")
+            .map(|(file, v)| (file, v.message.as_str()))
+            .collect();
+        assert_eq!(missing.len(), 1, "{missing:?}");
+        assert_eq!(missing[0].0, Path::new("other.md"));
+        assert!(missing[0].1.starts_with("No 'de' translation"));
+    }
+
+    #[test]
+    fn test_format_summary() {
+        let chapters = vec![
+            (
+                PathBuf::from("intro.md"),
+                vec![
+                    violation("MD013", "long", Severity::Warning),
+                    violation("MD013", "long", Severity::Warning),
+                    violation("MD052", "undefined", Severity::Error),
+                ],
+            ),
+            (
+                PathBuf::from("guide/setup.md"),
+                vec![violation("MD013", "long", Severity::Warning)],
+            ),
+        ];
+
+        assert_eq!(
+            format_summary(&chapters, 5),
+            "mdbook-lint: 1 error(s), 3 warning(s), 0 info in 2 of 5 chapter(s)\n  intro.md: 1 error(s), 2 warning(s)\n  guide/setup.md: 1 warning(s)\n  Most reported: MD013 (3), MD052 (1)\n"
+        );
+        assert_eq!(
+            format_summary(&[], 5),
+            "mdbook-lint: No issues found in 5 chapter(s)\n"
+        );
+    }
+
+    #[test]
+    fn test_render_warning_block() {
+        let block = render_warning_block(&[
+            violation("MD013", "Line <too> long", Severity::Warning),
+            violation("MD001", "Heading levels", Severity::Error),
+        ]);
+
+        assert!(block.starts_with("<div class=\"mdbook-lint-warnings\""));
+        assert!(block.contains("#d9534f"));
+        assert!(block.contains("2 issues in this chapter"));
+        assert!(block.contains("<li><code>MD013</code> line 3: Line &lt;too&gt; long</li>"));
+        assert!(block.ends_with("</div>\n\n"));
+    }
+
+    #[test]
+    fn test_insert_warning_block_after_frontmatter() {
+        assert_eq!(
+            insert_warning_block("# Title\n", "BLOCK\n"),
+            "BLOCK\n# Title\n"
+        );
+        assert_eq!(
+            insert_warning_block("---\ntitle: x\n---\n# Title\n", "BLOCK\n"),
+            "---\ntitle: x\n---\nBLOCK\n# Title\n"
+        );
+        // An unclosed delimiter is not frontmatter
+        assert_eq!(
+            insert_warning_block("---\n# Title\n", "BLOCK\n"),
+            "BLOCK\n---\n# Title\n"
+        );
+    }
+
+    #[test]
+    fn test_should_render_warnings() {
+        let config = Config {
+            render_warnings: true,
+            draft: true,
+            ..Default::default()
+        };
+        let preprocessor = MdBookLint::with_config(config);
+        assert!(preprocessor.should_render_warnings("html"));
+        assert!(!preprocessor.should_render_warnings("markdown"));
+
+        let preprocessor = MdBookLint::with_config(Config {
+            render_warnings: false,
+            draft: true,
+            ..Default::default()
+        });
+        assert!(!preprocessor.should_render_warnings("html"));
+    }
+
+    #[test]
+    fn test_parse_render_warnings_config() {
+        let config = parse_config(&json!({"render-warnings": true, "draft": true})).unwrap();
+        assert!(config.render_warnings);
+        assert!(config.draft);
+
+        assert!(parse_config(&json!({"render-warnings": "yes"})).is_err());
+    }
 }
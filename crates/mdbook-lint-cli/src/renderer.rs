@@ -0,0 +1,167 @@
+//! mdBook renderer backend
+//!
+//! With an `[output.lint]` table in `book.toml`, mdBook runs mdbook-lint as
+//! one of the book's renderers instead of (or as well as) a preprocessor. The
+//! renderer sees the whole book at once, prints one report covering every
+//! chapter, writes the same report to `report.txt` in its output directory,
+//! and exits non-zero when the violations fail the build, which makes
+//! `mdbook build` fail after the other renderers have run.
+//!
+//! mdBook starts renderers and preprocessors with the same command and no
+//! arguments, so [`is_render_context`] tells the two inputs apart.
+
+use crate::preprocessor::{MdBookLint, normalize_mdbook_json, strip_null_values};
+use mdbook::renderer::RenderContext;
+use mdbook_lint_core::{MdBookLintError, Result, Severity, Violation};
+use serde_json::Value;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Name of the report written to the renderer's output directory
+pub const REPORT_FILE: &str = "report.txt";
+
+/// Whether `input` is the render context mdBook sends a renderer, rather than
+/// the `[context, book]` pair it sends a preprocessor
+pub fn is_render_context(input: &str) -> bool {
+    serde_json::from_str::<Value>(input)
+        .is_ok_and(|value| value.get("destination").is_some() && value.get("book").is_some())
+}
+
+/// Handle the renderer protocol: read the render context from stdin, then
+/// lint and report as [`render`] does
+pub fn handle_rendering() -> Result<bool> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(MdBookLintError::Io)?;
+    render(&input)
+}
+
+/// Lint the book in `input`, the render context JSON mdBook sends, and
+/// report every violation
+///
+/// Returns `false` when violations fail the build, after reporting why on
+/// stderr.
+pub fn render(input: &str) -> Result<bool> {
+    // Same cleanup as the preprocessor: mdBook 0.5 books and null values
+    let json_value: Value = serde_json::from_str(input).map_err(MdBookLintError::Json)?;
+    let (normalized, _) = normalize_mdbook_json(json_value);
+    let ctx: RenderContext =
+        serde_json::from_value(strip_null_values(normalized)).map_err(MdBookLintError::Json)?;
+
+    let mut lint = MdBookLint::new();
+    lint.load_config_from_render_context(&ctx)?;
+    let mut chapters = lint.lint_book(&ctx.book)?;
+
+    // Violations below report-level still count toward failing the build
+    let should_fail = chapters
+        .iter()
+        .any(|(_, violations)| lint.should_fail_build(violations));
+    let count = |severity| {
+        chapters
+            .iter()
+            .flat_map(|(_, violations)| violations)
+            .filter(|v| v.severity == severity)
+            .count()
+    };
+    let (error_count, warning_count) = (count(Severity::Error), count(Severity::Warning));
+    lint.config.retain_reported(&mut chapters);
+
+    let report = format_report(&chapters);
+    eprint!("{report}");
+
+    std::fs::create_dir_all(&ctx.destination)?;
+    std::fs::write(ctx.destination.join(REPORT_FILE), &report)?;
+
+    if should_fail {
+        eprintln!(
+            "mdbook-lint: Build failed due to {error_count} error(s) and {warning_count} warning(s)"
+        );
+    }
+    Ok(!should_fail)
+}
+
+/// One report for the whole book: each chapter's violations, then totals
+fn format_report(chapters: &[(PathBuf, Vec<Violation>)]) -> String {
+    let mut report = String::new();
+    for (chapter_path, violations) in chapters {
+        for violation in violations {
+            report.push_str(&format!("{}:{violation}\n", chapter_path.display()));
+        }
+    }
+
+    let count = |severity| {
+        chapters
+            .iter()
+            .flat_map(|(_, violations)| violations)
+            .filter(|v| v.severity == severity)
+            .count()
+    };
+    if chapters.is_empty() {
+        report.push_str("mdbook-lint: No issues found\n");
+    } else {
+        report.push_str(&format!(
+            "mdbook-lint: {} error(s), {} warning(s), {} info in {} chapter(s)\n",
+            count(Severity::Error),
+            count(Severity::Warning),
+            count(Severity::Info),
+            chapters.len()
+        ));
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook::book::{Book, BookItem, Chapter};
+    use tempfile::TempDir;
+
+    fn render_context(root: &std::path::Path, content: &str, book_toml: &str) -> String {
+        let mut book = Book::new();
+        book.push_item(BookItem::Chapter(Chapter::new(
+            "Intro",
+            content.to_string(),
+            "intro.md",
+            Vec::new(),
+        )));
+        let config: mdbook::Config = book_toml.parse().unwrap();
+        let ctx = RenderContext::new(root, book, config, root.join("book"));
+        serde_json::to_string(&ctx).unwrap()
+    }
+
+    #[test]
+    fn test_is_render_context() {
+        let dir = TempDir::new().unwrap();
+        assert!(is_render_context(&render_context(
+            dir.path(),
+            "# Intro\n",
+            ""
+        )));
+        assert!(!is_render_context(r#"[{"root": "."}, {"sections": []}]"#));
+        assert!(!is_render_context("not json"));
+    }
+
+    #[test]
+    fn test_render_fails_on_errors_and_writes_report() {
+        let dir = TempDir::new().unwrap();
+        let input = render_context(dir.path(), "# Intro\n\nSee [the guide][missing].\n", "");
+
+        assert!(!render(&input).unwrap());
+        let report = std::fs::read_to_string(dir.path().join("book").join(REPORT_FILE)).unwrap();
+        assert!(report.contains("intro.md:3:5:error: MD052/reference-links-images"));
+        assert!(report.contains("in 1 chapter(s)"));
+    }
+
+    #[test]
+    fn test_render_reads_output_table() {
+        let dir = TempDir::new().unwrap();
+        let input = render_context(
+            dir.path(),
+            "# Intro\n\nSee [the guide][missing].\n",
+            "[output.lint]\nfail-on-errors = false\n",
+        );
+
+        assert!(render(&input).unwrap());
+    }
+}
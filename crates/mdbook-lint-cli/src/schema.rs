@@ -0,0 +1,400 @@
+//! JSON Schema for configuration files, printed by `mdbook-lint config schema`
+//!
+//! The schema covers the top-level settings and one table per rule, built
+//! from the options each rule declares in its [`RuleMetadata`]. Rule tables
+//! allow keys the schema doesn't list, since many rules accept both the
+//! `snake_case` and `kebab-case` spelling of an option.
+//!
+//! [`RuleMetadata`]: mdbook_lint_core::RuleMetadata
+
+use mdbook_lint_core::rule::DOCUMENT_KINDS_KEY;
+use mdbook_lint_core::{DocumentKind, LintEngine, PluginRegistry, Region, RuleMetadata};
+use serde_json::{Map, Value, json};
+
+/// Categories accepted by `enabled-categories` and `disabled-categories`
+const CATEGORIES: &[&str] = &[
+    "structure",
+    "style",
+    "whitespace",
+    "code",
+    "links",
+    "mdbook",
+    "accessibility",
+];
+
+/// Description of the top-level `ignore-blockquotes` setting
+const IGNORE_BLOCKQUOTES: &str =
+    "Drop every rule's violations in blockquotes, or in quotes nested at least this deep";
+
+const FRAGMENT_PATHS: &str =
+    "Glob patterns for files linted as fragments, which rules about whole documents skip";
+
+/// Build the schema for every rule and provider in `registry`
+pub fn config_schema(registry: &PluginRegistry, engine: &LintEngine) -> Value {
+    let string_list = json!({"type": "array", "items": {"type": "string"}});
+    let severity = json!({"type": "string", "enum": ["info", "warning", "error"]});
+    let severity_table = json!({
+        "type": "object",
+        "description": "Severity to report each rule's violations with, keyed by rule ID",
+        "additionalProperties": severity.clone(),
+    });
+
+    let ignore_blockquotes = ignore_blockquotes_schema();
+
+    let mut properties = json!({
+        "$schema": {
+            "type": "string",
+            "description": "Location of this schema, for editors that read it from the file",
+        },
+        "extends": {
+            "description": "Presets, config files, or URLs this config builds on, applied in order",
+            "anyOf": [{"type": "string"}, string_list],
+        },
+        "profile": {
+            "type": "string",
+            "description": "Built-in rule profile applied before `extends`",
+            "enum": mdbook_lint_rulesets::profiles::profile_names(),
+        },
+        "enabled-categories": {
+            "type": "array",
+            "description": "Rule categories to enable",
+            "items": {"type": "string", "enum": CATEGORIES},
+        },
+        "disabled-categories": {
+            "type": "array",
+            "description": "Rule categories to disable",
+            "items": {"type": "string", "enum": CATEGORIES},
+        },
+        "rules": {
+            "type": "object",
+            "description": "With `default = false`, run only the rules set to true in `[rules.enabled]` (TOML only)",
+            "properties": {
+                "default": {"type": "boolean"},
+                "enabled": {"type": "object", "additionalProperties": {"type": "boolean"}},
+            },
+        },
+        "enabled-rules": with_description(&string_list, "Rules to enable, by ID"),
+        "disabled-rules": with_description(&string_list, "Rules to disable, by ID"),
+        "deprecated-warning": {
+            "type": "string",
+            "description": "How to report deprecated rules",
+            "enum": ["warn", "info", "silent"],
+        },
+        "ruleset-version": {
+            "type": "string",
+            "description": "mdbook-lint version whose rules the project expects; newer rules wait for `mdbook-lint upgrade-config`",
+        },
+        "markdownlint-compatible": {
+            "type": "boolean",
+            "description": "Disable the rules markdownlint disables by default",
+        },
+        "auto-fix": {
+            "type": "boolean",
+            "description": "Apply fixes with --fix unless a rule's table sets `auto-fix = false`",
+        },
+        "ignore-paths": with_description(&string_list, "Glob patterns for paths to skip"),
+        "ignore_paths": with_description(&string_list, "Glob patterns for paths to skip"),
+        "ignore-blockquotes": with_description(&ignore_blockquotes, IGNORE_BLOCKQUOTES),
+        "ignore_blockquotes": with_description(&ignore_blockquotes, IGNORE_BLOCKQUOTES),
+        "dedupe": {
+            "type": "string",
+            "description": "How overlapping findings from related rules are merged",
+            "enum": ["strict", "loose", "off"],
+        },
+        "severity": severity_table.clone(),
+        "custom-rules": {
+            "type": "object",
+            "description": "Rules defined by a regular expression, keyed by rule ID",
+            "additionalProperties": {
+                "type": "object",
+                "required": ["pattern", "message"],
+                "properties": {
+                    "pattern": {"type": "string", "description": "Regular expression matched against each line"},
+                    "message": {"type": "string", "description": "Message reported for each match; `{match}` is replaced by the matched text"},
+                    "severity": severity,
+                    "name": {"type": "string", "description": "Rule name (defaults to the lowercased rule ID)"},
+                    "include-code": {"type": "boolean", "description": "Also check lines inside fenced code blocks"},
+                },
+            },
+        },
+        "fail-on-warnings": {"type": "boolean", "description": "Fail builds on warnings"},
+        "fail-on-errors": {"type": "boolean", "description": "Fail builds on errors (default true)"},
+        "fail-on": {
+            "type": "string",
+            "description": "Lowest severity that fails the build; overrides fail-on-warnings and fail-on-errors",
+            "enum": ["error", "warning", "never"],
+        },
+        "summary": {
+            "type": "boolean",
+            "description": "Print a compact summary after preprocessing instead of one line per violation",
+        },
+        "malformed-markdown": {
+            "type": "string",
+            "description": "How to handle malformed Markdown",
+            "enum": ["error", "warn", "skip"],
+        },
+        "render-warnings": {
+            "type": "boolean",
+            "description": "Add a warning block to chapters with violations in draft builds",
+        },
+        "draft": {"type": "boolean", "description": "Treat the build as a draft"},
+        "adr": {"type": "boolean", "description": "Run the ADR rules on every linted file"},
+        "markdown": {
+            "type": "object",
+            "description": "Markdown extensions documents are parsed with",
+            "properties": {
+                "footnotes": {"type": "boolean", "description": "Footnote references and definitions (default true)"},
+                "description-lists": {"type": "boolean", "description": "Description lists (default true)"},
+                "tasklists": {"type": "boolean", "description": "Task list items (default true)"},
+                "superscript": {"type": "boolean", "description": "^Superscript^ text (default false)"},
+                "wikilinks": {"type": "boolean", "description": "[[Page]] wiki links (default false)"},
+            },
+            "additionalProperties": false,
+        },
+    });
+    let properties = properties.as_object_mut().expect("properties is an object");
+    // Later keys, kept out of the literal above, which is at the `json!`
+    // recursion limit
+    let severity_level = json!({"type": "string", "enum": ["info", "warning", "error"]});
+    properties.extend([
+        (
+            "report-level".to_string(),
+            with_description(&severity_level, "Lowest severity that is printed"),
+        ),
+        (
+            "fail-level".to_string(),
+            with_description(
+                &severity_level,
+                "Lowest severity that fails lint or the build; overrides fail-on, fail-on-warnings, and fail-on-errors",
+            ),
+        ),
+        (
+            "fragment-paths".to_string(),
+            with_description(&string_list, FRAGMENT_PATHS),
+        ),
+        (
+            "fragment_paths".to_string(),
+            with_description(&string_list, FRAGMENT_PATHS),
+        ),
+        (
+            "require-suppression-reason".to_string(),
+            json!({
+                "type": "boolean",
+                "description": "Report suppression comments that give no reason",
+            }),
+        ),
+        (
+            "include".to_string(),
+            with_description(
+                &string_list,
+                "Glob patterns of the chapters the preprocessor lints; empty lints every chapter",
+            ),
+        ),
+        (
+            "exclude".to_string(),
+            with_description(&string_list, "Glob patterns of chapters the preprocessor skips"),
+        ),
+        (
+            "chapter-content".to_string(),
+            json!({
+                "type": "string",
+                "description": "Lint chapters as received from earlier preprocessors or as their source files on disk",
+                "enum": ["preprocessed", "source"],
+            }),
+        ),
+    ]);
+
+    let mut rule_tables = Map::new();
+    for rule in engine.registry().rules() {
+        rule_tables.insert(
+            rule.id().to_string(),
+            rule_table(rule.description(), &rule.metadata()),
+        );
+    }
+    for rule in engine.registry().collection_rules() {
+        rule_tables.insert(
+            rule.id().to_string(),
+            rule_table(rule.description(), &rule.metadata()),
+        );
+    }
+
+    properties.insert(
+        "chapters".to_string(),
+        json!({
+            "type": "object",
+            "description": "Overrides for chapters matching a glob pattern",
+            "additionalProperties": {
+                "type": "object",
+                "properties": Value::Object(rule_tables.clone()),
+            },
+        }),
+    );
+    if let Some(chapter) =
+        properties["chapters"]["additionalProperties"]["properties"].as_object_mut()
+    {
+        chapter.insert(
+            "enabled-rules".to_string(),
+            with_description(&string_list, "Rules to enable for matching chapters"),
+        );
+        chapter.insert(
+            "disabled-rules".to_string(),
+            with_description(&string_list, "Rules to disable for matching chapters"),
+        );
+        chapter.insert("severity".to_string(), severity_table);
+    }
+
+    for provider in registry.providers() {
+        if let Some(schema) = provider.config_schema()
+            && let Some(provided) = schema.get("properties").and_then(Value::as_object)
+        {
+            for (key, value) in provided {
+                properties.insert(key.clone(), value.clone());
+            }
+        }
+    }
+    properties.extend(rule_tables);
+
+    json!({
+        "$schema": "http://json-schema.org/draft-07/schema#",
+        "title": "mdbook-lint configuration",
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    })
+}
+
+/// Description of a rule table's `ignore-*` key for `region`
+fn region_description(region: Region) -> &'static str {
+    match region {
+        Region::CodeBlock => "Drop this rule's violations in code blocks",
+        Region::Table => "Drop this rule's violations in tables",
+        Region::Heading => "Drop this rule's violations in headings",
+        Region::Blockquote => {
+            "Drop this rule's violations in blockquotes, or in quotes nested at least this deep"
+        }
+    }
+}
+
+/// Schema for `ignore-blockquotes`: a boolean or a nesting depth
+fn ignore_blockquotes_schema() -> Value {
+    json!({"anyOf": [{"type": "boolean"}, {"type": "integer", "minimum": 0}]})
+}
+
+/// Schema for one rule's table
+fn rule_table(description: &str, metadata: &RuleMetadata) -> Value {
+    let mut properties = Map::new();
+    properties.insert(
+        "auto-fix".to_string(),
+        json!({"type": "boolean", "description": "Apply this rule's fixes with --fix"}),
+    );
+    properties.insert(
+        DOCUMENT_KINDS_KEY.to_string(),
+        json!({
+            "type": "array",
+            "items": {
+                "type": "string",
+                "enum": DocumentKind::ALL.map(DocumentKind::name),
+            },
+            "description": "Kinds of document this rule runs on, instead of the kinds it declares",
+        }),
+    );
+    for region in Region::ALL {
+        let schema = match region {
+            Region::Blockquote => ignore_blockquotes_schema(),
+            _ => json!({"type": "boolean"}),
+        };
+        properties.insert(
+            region.config_key().to_string(),
+            with_description(&schema, region_description(region)),
+        );
+    }
+    for option in &metadata.options {
+        let mut schema = with_description(&option.kind.json_schema(), option.description);
+        if let Some(default) = option.default_value()
+            && let Ok(default) = serde_json::to_value(default)
+        {
+            schema["default"] = default;
+        }
+        properties.insert(option.name.to_string(), schema);
+    }
+    json!({
+        "type": "object",
+        "description": description,
+        "properties": properties,
+    })
+}
+
+fn with_description(schema: &Value, description: &str) -> Value {
+    let mut schema = schema.clone();
+    if let Some(object) = schema.as_object_mut() {
+        object.insert("description".to_string(), json!(description));
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_rulesets::{MdBookRuleProvider, StandardRuleProvider};
+
+    fn schema() -> Value {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(StandardRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(MdBookRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine().unwrap();
+        config_schema(&registry, &engine)
+    }
+
+    #[test]
+    fn test_rule_options_come_from_metadata() {
+        let schema = schema();
+        let md013 = &schema["properties"]["MD013"];
+        assert_eq!(md013["properties"]["line-length"]["type"], json!("integer"));
+        assert_eq!(md013["properties"]["line-length"]["default"], json!(80));
+        assert_eq!(
+            md013["properties"]["length-mode"]["enum"],
+            json!(["strict", "visual"])
+        );
+        assert_eq!(md013["properties"]["auto-fix"]["type"], json!("boolean"));
+        assert_eq!(
+            md013["properties"]["ignore-tables"]["type"],
+            json!("boolean")
+        );
+        assert_eq!(
+            md013["properties"]["ignore-blockquotes"]["anyOf"][1]["type"],
+            json!("integer")
+        );
+        assert_eq!(
+            md013["properties"]["document-kinds"]["items"]["enum"],
+            json!(["chapter", "summary", "readme", "adr", "fragment"])
+        );
+
+        // Chapter overrides accept the same rule tables
+        assert_eq!(
+            schema["properties"]["chapters"]["additionalProperties"]["properties"]["MD013"],
+            *md013
+        );
+    }
+
+    #[test]
+    fn test_top_level_settings() {
+        let schema = schema();
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(
+            schema["properties"]["dedupe"]["enum"],
+            json!(["strict", "loose", "off"])
+        );
+        assert_eq!(
+            schema["properties"]["fail-level"]["enum"],
+            json!(["info", "warning", "error"])
+        );
+        assert_eq!(
+            schema["properties"]["custom-rules"]["additionalProperties"]["required"],
+            json!(["pattern", "message"])
+        );
+    }
+}
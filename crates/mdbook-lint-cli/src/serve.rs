@@ -0,0 +1,447 @@
+//! Persistent lint daemon for `mdbook-lint serve`
+//!
+//! The daemon loads the configuration and builds the engine once, then
+//! answers JSON-RPC 2.0 requests on a Unix socket, one JSON object per line.
+//! Editors and build scripts that lint often skip process startup and engine
+//! construction, and a document whose contents haven't changed since its
+//! last lint is answered from a cache. Documents checked by a rule that
+//! reads other files, such as link and include checks, are linted afresh
+//! every time, since a change elsewhere can change their result. The
+//! configuration is read once, at startup.
+//!
+//! Unix sockets are the only transport, so the daemon is not available on
+//! Windows.
+//!
+//! Methods:
+//!
+//! - `lint` with `{"path": ..., "content": ...}` returns
+//!   `{"path": ..., "violations": [...]}`. Without `content` the file is
+//!   read from disk.
+//! - `status` returns the version and request counts.
+//! - `shutdown` stops the daemon after answering.
+
+use mdbook_lint_core::Violation;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::io::{self, BufRead, BufReader, ErrorKind, Write};
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+/// A document that could not be read or linted
+const LINT_FAILED: i64 = -32000;
+
+/// Documents whose results are kept at once; the least recently used is
+/// dropped to make room
+const CACHE_CAPACITY: usize = 1024;
+
+/// Lints one document: its path and contents
+pub type LintFn = dyn Fn(&Path, String) -> Result<Linted, String> + Send + Sync;
+
+/// Result of linting one document
+pub struct Linted {
+    pub violations: Vec<Violation>,
+    /// Whether the result depends on the document's contents alone, so it
+    /// can be reused until they change
+    pub cacheable: bool,
+}
+
+/// A cached result
+#[derive(Clone)]
+struct CacheEntry {
+    /// Hash of the contents the result is for
+    hash: u64,
+    violations: Vec<Violation>,
+    /// When the entry was last used, for eviction
+    used: u64,
+}
+
+/// Last result for each path, up to [`CACHE_CAPACITY`] of them
+#[derive(Default)]
+struct Cache {
+    entries: HashMap<PathBuf, CacheEntry>,
+    clock: u64,
+}
+
+impl Cache {
+    /// The result for `path` if it was linted with these contents
+    fn get(&mut self, path: &Path, hash: u64) -> Option<Vec<Violation>> {
+        self.clock += 1;
+        let entry = self
+            .entries
+            .get_mut(path)
+            .filter(|entry| entry.hash == hash)?;
+        entry.used = self.clock;
+        Some(entry.violations.clone())
+    }
+
+    fn insert(&mut self, path: PathBuf, hash: u64, violations: Vec<Violation>) {
+        self.clock += 1;
+        if !self.entries.contains_key(&path)
+            && self.entries.len() >= CACHE_CAPACITY
+            && let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.used)
+                .map(|(path, _)| path.clone())
+        {
+            self.entries.remove(&oldest);
+        }
+        self.entries.insert(
+            path,
+            CacheEntry {
+                hash,
+                violations,
+                used: self.clock,
+            },
+        );
+    }
+}
+
+#[derive(Deserialize)]
+struct LintParams {
+    path: PathBuf,
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// Request handling shared by every connection
+pub struct Daemon {
+    lint: Box<LintFn>,
+    cache: Mutex<Cache>,
+    requests: AtomicUsize,
+    cache_hits: AtomicUsize,
+    stopping: AtomicBool,
+}
+
+impl Daemon {
+    pub fn new(lint: Box<LintFn>) -> Self {
+        Self {
+            lint,
+            cache: Mutex::new(Cache::default()),
+            requests: AtomicUsize::new(0),
+            cache_hits: AtomicUsize::new(0),
+            stopping: AtomicBool::new(false),
+        }
+    }
+
+    /// Whether a `shutdown` request has been answered
+    pub fn is_stopping(&self) -> bool {
+        self.stopping.load(Ordering::SeqCst)
+    }
+
+    /// Answer one line of input; notifications get no answer
+    pub fn handle_line(&self, line: &str) -> Option<String> {
+        let response = match serde_json::from_str::<Value>(line) {
+            Ok(request) => self.handle(&request)?,
+            Err(e) => error_response(&Value::Null, PARSE_ERROR, &e.to_string()),
+        };
+        Some(response.to_string())
+    }
+
+    fn handle(&self, request: &Value) -> Option<Value> {
+        let id = request.get("id").cloned();
+        let Some(method) = request.get("method").and_then(Value::as_str) else {
+            return Some(error_response(
+                &id.unwrap_or(Value::Null),
+                INVALID_REQUEST,
+                "request has no method",
+            ));
+        };
+        self.requests.fetch_add(1, Ordering::Relaxed);
+
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        let result = match method {
+            "lint" => self.lint(params),
+            "status" => Ok(json!({
+                "version": env!("CARGO_PKG_VERSION"),
+                "requests": self.requests.load(Ordering::Relaxed),
+                "cache_hits": self.cache_hits.load(Ordering::Relaxed),
+            })),
+            "shutdown" => {
+                self.stopping.store(true, Ordering::SeqCst);
+                Ok(Value::Null)
+            }
+            _ => Err((METHOD_NOT_FOUND, format!("unknown method '{method}'"))),
+        };
+
+        let id = id?;
+        Some(match result {
+            Ok(result) => json!({"jsonrpc": "2.0", "id": id, "result": result}),
+            Err((code, message)) => error_response(&id, code, &message),
+        })
+    }
+
+    fn lint(&self, params: Value) -> Result<Value, (i64, String)> {
+        let params: LintParams =
+            serde_json::from_value(params).map_err(|e| (INVALID_PARAMS, e.to_string()))?;
+        let content = match params.content {
+            Some(content) => content,
+            None => std::fs::read_to_string(&params.path).map_err(|e| {
+                (
+                    LINT_FAILED,
+                    format!("Failed to read file {}: {e}", params.path.display()),
+                )
+            })?,
+        };
+
+        let mut hasher = DefaultHasher::new();
+        content.hash(&mut hasher);
+        let hash = hasher.finish();
+        let cached = self
+            .cache
+            .lock()
+            .ok()
+            .and_then(|mut cache| cache.get(&params.path, hash));
+
+        let violations = match cached {
+            Some(violations) => {
+                self.cache_hits.fetch_add(1, Ordering::Relaxed);
+                violations
+            }
+            None => {
+                let linted = (self.lint)(&params.path, content).map_err(|e| (LINT_FAILED, e))?;
+                if let Ok(mut cache) = self.cache.lock() {
+                    if linted.cacheable {
+                        cache.insert(params.path.clone(), hash, linted.violations.clone());
+                    } else {
+                        cache.entries.remove(&params.path);
+                    }
+                }
+                linted.violations
+            }
+        };
+        Ok(json!({"path": params.path, "violations": violations}))
+    }
+}
+
+fn error_response(id: &Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {"code": code, "message": message},
+    })
+}
+
+/// Listen on a Unix socket at `path` until a `shutdown` request
+pub fn serve(path: &Path, daemon: Arc<Daemon>) -> io::Result<()> {
+    if path.exists() {
+        if UnixStream::connect(path).is_ok() {
+            return Err(io::Error::new(
+                ErrorKind::AddrInUse,
+                format!("a daemon is already listening on {}", path.display()),
+            ));
+        }
+        // Left behind by a daemon that was killed
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+
+    for stream in listener.incoming() {
+        if daemon.is_stopping() {
+            break;
+        }
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("mdbook-lint daemon failed to accept a connection: {e}");
+                continue;
+            }
+        };
+        let daemon = Arc::clone(&daemon);
+        let socket = path.to_path_buf();
+        std::thread::spawn(move || {
+            let Ok(reader) = stream.try_clone() else {
+                return;
+            };
+            let mut writer = stream;
+            for line in BufReader::new(reader).lines() {
+                let Ok(line) = line else { break };
+                if line.trim().is_empty() {
+                    continue;
+                }
+                if let Some(response) = daemon.handle_line(&line)
+                    && writeln!(writer, "{response}")
+                        .and_then(|()| writer.flush())
+                        .is_err()
+                {
+                    break;
+                }
+                if daemon.is_stopping() {
+                    // Wake the accept loop so it sees the request
+                    let _ = UnixStream::connect(&socket);
+                    break;
+                }
+            }
+        });
+    }
+
+    std::fs::remove_file(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::Severity;
+
+    fn daemon() -> Daemon {
+        Daemon::new(Box::new(|path, content| {
+            if content.is_empty() {
+                return Err(format!("{} is empty", path.display()));
+            }
+            let violations = vec![Violation {
+                rule_id: "MD041".to_string(),
+                rule_name: "first-line-heading".to_string(),
+                message: content.lines().next().unwrap_or_default().to_string(),
+                line: 1,
+                column: 1,
+                severity: Severity::Warning,
+                fix: None,
+                url: None,
+                path: None,
+            }];
+            Ok(Linted {
+                violations,
+                // Stands in for a document checked by a filesystem rule
+                cacheable: !content.contains("[link]"),
+            })
+        }))
+    }
+
+    fn call(daemon: &Daemon, request: Value) -> Value {
+        serde_json::from_str(&daemon.handle_line(&request.to_string()).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_lint_and_cache() {
+        let daemon = daemon();
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "lint",
+            "params": {"path": "src/a.md", "content": "text"},
+        });
+        let response = call(&daemon, request.clone());
+        assert_eq!(response["id"], 1);
+        assert_eq!(response["result"]["path"], "src/a.md");
+        assert_eq!(response["result"]["violations"][0]["message"], "text");
+
+        call(&daemon, request);
+        let status = call(
+            &daemon,
+            json!({"jsonrpc": "2.0", "id": 2, "method": "status"}),
+        );
+        assert_eq!(status["result"]["requests"], 3);
+        assert_eq!(status["result"]["cache_hits"], 1);
+    }
+
+    #[test]
+    fn test_filesystem_results_not_cached() {
+        let daemon = daemon();
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "lint",
+            "params": {"path": "src/a.md", "content": "[link](b.md)"},
+        });
+        call(&daemon, request.clone());
+        call(&daemon, request);
+        let status = call(
+            &daemon,
+            json!({"jsonrpc": "2.0", "id": 2, "method": "status"}),
+        );
+        assert_eq!(status["result"]["cache_hits"], 0);
+    }
+
+    #[test]
+    fn test_cache_evicts_least_recently_used() {
+        let mut cache = Cache::default();
+        for i in 0..CACHE_CAPACITY {
+            cache.insert(PathBuf::from(format!("{i}.md")), 0, Vec::new());
+        }
+        // Touch the oldest so the second oldest goes first
+        assert!(cache.get(Path::new("0.md"), 0).is_some());
+        cache.insert(PathBuf::from("new.md"), 0, Vec::new());
+
+        assert_eq!(cache.entries.len(), CACHE_CAPACITY);
+        assert!(cache.get(Path::new("0.md"), 0).is_some());
+        assert!(cache.get(Path::new("1.md"), 0).is_none());
+        assert!(cache.get(Path::new("new.md"), 0).is_some());
+        assert!(cache.get(Path::new("new.md"), 1).is_none());
+    }
+
+    #[test]
+    fn test_errors() {
+        let daemon = daemon();
+        let code = |response: Value| response["error"]["code"].as_i64().unwrap();
+
+        let response = daemon.handle_line("{not json").unwrap();
+        assert_eq!(code(serde_json::from_str(&response).unwrap()), PARSE_ERROR);
+        assert_eq!(
+            code(call(&daemon, json!({"id": 1, "method": "format"}))),
+            METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            code(call(
+                &daemon,
+                json!({"id": 2, "method": "lint", "params": {}})
+            )),
+            INVALID_PARAMS
+        );
+        let failed = call(
+            &daemon,
+            json!({"id": 3, "method": "lint", "params": {"path": "a.md", "content": ""}}),
+        );
+        assert_eq!(failed["error"]["message"], "a.md is empty");
+
+        // Notifications are not answered
+        assert!(
+            daemon
+                .handle_line(&json!({"method": "status"}).to_string())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_serve_over_socket() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let socket = dir.path().join("lint.sock");
+        let server = {
+            let socket = socket.clone();
+            std::thread::spawn(move || serve(&socket, Arc::new(daemon())))
+        };
+        let stream = (0..100)
+            .find_map(|_| {
+                UnixStream::connect(&socket)
+                    .inspect_err(|_| std::thread::sleep(std::time::Duration::from_millis(20)))
+                    .ok()
+            })
+            .expect("daemon should start listening");
+
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut line = String::new();
+        writeln!(
+            writer,
+            r#"{{"jsonrpc":"2.0","id":1,"method":"lint","params":{{"path":"a.md","content":"hi"}}}}"#
+        )
+        .unwrap();
+        reader.read_line(&mut line).unwrap();
+        assert!(line.contains("\"violations\""));
+
+        writeln!(writer, r#"{{"jsonrpc":"2.0","id":2,"method":"shutdown"}}"#).unwrap();
+        line.clear();
+        reader.read_line(&mut line).unwrap();
+        assert!(line.contains("\"result\":null"));
+
+        server.join().unwrap().unwrap();
+        assert!(!socket.exists());
+    }
+}
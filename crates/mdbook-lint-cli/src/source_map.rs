@@ -0,0 +1,388 @@
+//! Mapping expanded chapters back to their source files
+//!
+//! mdBook's `links` preprocessor usually runs before mdbook-lint and replaces
+//! each `{{#include}}` with the file it names, so the chapter the preprocessor
+//! lints is not the file on disk: line numbers drift past every include, and
+//! problems inside included files are reported against the chapter.
+//!
+//! [`SourceMap`] repeats that expansion from the chapter's source file,
+//! recording where each stretch of every expanded line came from, and checks
+//! the result against the content mdBook passed in. When the two match, a
+//! violation's line and column can be traced back to the file that holds
+//! the text. When they don't, because another preprocessor changed the
+//! chapter or it uses directives this module doesn't follow, there is no map
+//! and violations are reported as they are.
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Deepest nesting of includes that mdBook expands
+const MAX_INCLUDE_DEPTH: usize = 10;
+
+/// Where a violation in expanded chapter content actually is
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourceLocation {
+    /// File holding the text, with `..` components resolved
+    pub path: PathBuf,
+    /// Line in that file (1-based)
+    pub line: usize,
+    /// Column in that file (1-based)
+    pub column: usize,
+}
+
+/// The start of a stretch of an expanded line that was copied from one place
+#[derive(Debug, Clone)]
+struct Segment {
+    /// Character offset in the expanded line where the stretch starts
+    at: usize,
+    path: PathBuf,
+    line: usize,
+    column: usize,
+}
+
+/// A line of expanded content and the stretches it was built from
+#[derive(Debug, Clone, Default)]
+struct MappedLine {
+    text: String,
+    segments: Vec<Segment>,
+}
+
+impl MappedLine {
+    /// Start a stretch copied from `path` at `line` and `column`
+    fn mark(&mut self, path: &Path, line: usize, column: usize) {
+        self.segments.push(Segment {
+            at: self.text.chars().count(),
+            path: path.to_path_buf(),
+            line,
+            column,
+        });
+    }
+
+    /// Append `other`, keeping track of where its stretches came from
+    fn append(&mut self, other: MappedLine) {
+        let offset = self.text.chars().count();
+        self.segments
+            .extend(other.segments.into_iter().map(|segment| Segment {
+                at: segment.at + offset,
+                ..segment
+            }));
+        self.text.push_str(&other.text);
+    }
+}
+
+/// Origins of every line of a chapter after mdBook expanded its includes
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    lines: Vec<Vec<Segment>>,
+}
+
+impl SourceMap {
+    /// Map `expanded`, the content mdBook passed in for the chapter at
+    /// `path`, back to `source`, the chapter file as it is on disk
+    ///
+    /// Returns `None` when expanding the includes in `source` does not give
+    /// `expanded`.
+    pub fn new(source: &str, path: &Path, expanded: &str) -> Option<Self> {
+        let path = normalize(path);
+        let lines = source
+            .split('\n')
+            .enumerate()
+            .map(|(idx, line)| (idx + 1, line.to_string()))
+            .collect();
+        let mapped = expand(lines, &path, 0)?;
+
+        let matches = mapped.len() == expanded.split('\n').count()
+            && mapped
+                .iter()
+                .zip(expanded.split('\n'))
+                .all(|(line, text)| line.text == text);
+        matches.then(|| Self {
+            lines: mapped.into_iter().map(|line| line.segments).collect(),
+        })
+    }
+
+    /// Where the text at `line` and `column` of the expanded chapter
+    /// came from
+    pub fn locate(&self, line: usize, column: usize) -> Option<SourceLocation> {
+        let offset = column.saturating_sub(1);
+        let segment = self
+            .lines
+            .get(line.checked_sub(1)?)?
+            .iter()
+            .rev()
+            .find(|segment| segment.at <= offset)?;
+        Some(SourceLocation {
+            path: segment.path.clone(),
+            line: segment.line,
+            column: segment.column + offset - segment.at,
+        })
+    }
+}
+
+/// Expand the directives in `lines` of the file at `path`, as mdBook's
+/// `links` preprocessor does
+///
+/// `lines` pairs each line with its 1-based number in the file; they are
+/// joined with newlines. Returns `None` for directives whose output can't be
+/// traced line by line (`rustdoc_include`, `playground`) and includes
+/// nested too deeply.
+fn expand(lines: Vec<(usize, String)>, path: &Path, depth: usize) -> Option<Vec<MappedLine>> {
+    let base = path.parent().unwrap_or(Path::new(""));
+    let mut mapped = Vec::new();
+    let mut current = MappedLine::default();
+
+    for (idx, (number, line)) in lines.into_iter().enumerate() {
+        if idx > 0 {
+            mapped.push(std::mem::take(&mut current));
+        }
+        let column = |byte: usize| line[..byte].chars().count() + 1;
+        current.mark(path, number, 1);
+
+        let mut pos = 0;
+        while let Some(offset) = line[pos..].find("{{") {
+            let start = pos + offset;
+
+            // `\{{#...}}` drops the backslash and keeps everything up to the
+            // line's last `}}` as it is
+            if line[..start].ends_with('\\')
+                && line[start + 2..].starts_with('#')
+                && let Some(end) = line.rfind("}}").filter(|&end| end > start)
+            {
+                current.text.push_str(&line[pos..start - 1]);
+                current.mark(path, number, column(start));
+                current.text.push_str(&line[start..end + 2]);
+                pos = end + 2;
+                current.mark(path, number, column(pos));
+                continue;
+            }
+
+            let Some((name, args, end)) = parse_directive(&line[start..]) else {
+                current.text.push_str(&line[pos..start + 1]);
+                pos = start + 1;
+                continue;
+            };
+            let end = start + end;
+            current.text.push_str(&line[pos..start]);
+
+            match name {
+                "include" => {
+                    let spec = args.split_whitespace().next().unwrap_or("");
+                    let (file, range) = spec.split_once(':').unwrap_or((spec, ""));
+                    let target = normalize(&base.join(file));
+                    match fs::read_to_string(&target) {
+                        Ok(content) => {
+                            if depth >= MAX_INCLUDE_DEPTH {
+                                return None;
+                            }
+                            let included =
+                                expand(select_lines(&content, range), &target, depth + 1)?;
+                            let mut included = included.into_iter();
+                            if let Some(first) = included.next() {
+                                current.append(first);
+                            }
+                            for next in included {
+                                mapped.push(std::mem::replace(&mut current, next));
+                            }
+                        }
+                        // mdBook leaves a directive it can't expand in place
+                        Err(_) => current.text.push_str(&line[start..end]),
+                    }
+                }
+                "title" => {}
+                "rustdoc_include" | "playground" | "playpen" => return None,
+                _ => current.text.push_str(&line[start..end]),
+            }
+            pos = end;
+            current.mark(path, number, column(pos));
+        }
+        current.text.push_str(&line[pos..]);
+    }
+    mapped.push(current);
+    Some(mapped)
+}
+
+/// Parse the directive `text` starts with: `{{#name args}}`, with optional
+/// whitespace after the opening braces
+///
+/// Returns the name, the arguments, and the directive's length in bytes.
+fn parse_directive(text: &str) -> Option<(&str, &str, usize)> {
+    let inner = text.strip_prefix("{{")?;
+    let trimmed = inner.trim_start();
+    let rest = trimmed.strip_prefix('#')?;
+    let name_len = rest
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .unwrap_or(rest.len());
+    let (name, after) = rest.split_at(name_len);
+    let args_start = after.len() - after.trim_start().len();
+    if name.is_empty() || args_start == 0 {
+        return None;
+    }
+    let args_len = after[args_start..].find('}')?;
+    if args_len == 0 || !after[args_start + args_len..].starts_with("}}") {
+        return None;
+    }
+    let len = text.len() - after.len() + args_start + args_len + 2;
+    Some((name, &after[args_start..args_start + args_len], len))
+}
+
+/// The numbered lines of `content` an include's `range` selects: a line
+/// range such as `3:10`, `3`, `3:`, or `:10`, an anchor name, or nothing for
+/// the whole file
+fn select_lines(content: &str, range: &str) -> Vec<(usize, String)> {
+    let lines = content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| (idx + 1, line.to_string()));
+
+    let mut parts = range.splitn(3, ':');
+    let first = parts.next().unwrap_or("");
+    let start = match first.parse::<usize>() {
+        Ok(start) => Some(start.saturating_sub(1)),
+        Err(_) if first.is_empty() => None,
+        Err(_) => return anchored_lines(content, first),
+    };
+    let end = parts.next().map(str::parse::<usize>);
+    let (skip, take) = match (start, end) {
+        (Some(start), Some(Ok(end))) => (start, end.saturating_sub(start)),
+        (Some(start), Some(Err(_))) => (start, usize::MAX),
+        (Some(start), None) => (start, 1),
+        (None, Some(Ok(end))) => (0, end),
+        (None, _) => (0, usize::MAX),
+    };
+    lines.skip(skip).take(take).collect()
+}
+
+/// Lines between `ANCHOR: name` and `ANCHOR_END: name`, leaving out any
+/// other anchor markers
+fn anchored_lines(content: &str, anchor: &str) -> Vec<(usize, String)> {
+    let mut retained = Vec::new();
+    let mut found = false;
+    for (idx, line) in content.lines().enumerate() {
+        if found {
+            match anchor_name(line, "ANCHOR_END:") {
+                Some(name) if name == anchor => break,
+                Some(_) => {}
+                None if anchor_name(line, "ANCHOR:").is_none() => {
+                    retained.push((idx + 1, line.to_string()));
+                }
+                None => {}
+            }
+        } else if anchor_name(line, "ANCHOR:") == Some(anchor) {
+            found = true;
+        }
+    }
+    retained
+}
+
+/// Name of the anchor marked by `marker` on `line`, if there is one
+fn anchor_name<'a>(line: &'a str, marker: &str) -> Option<&'a str> {
+    let (_, rest) = line.split_once(marker)?;
+    let rest = rest.trim_start();
+    let len = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '-'))
+        .unwrap_or(rest.len());
+    (len > 0).then(|| &rest[..len])
+}
+
+/// `path` with `.` and `..` components resolved without touching the disk
+pub(crate) fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if normalized.file_name().is_some() => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn location(path: &Path, line: usize, column: usize) -> Option<SourceLocation> {
+        Some(SourceLocation {
+            path: path.to_path_buf(),
+            line,
+            column,
+        })
+    }
+
+    #[test]
+    fn test_maps_included_lines() {
+        let dir = TempDir::new().unwrap();
+        let chapter = dir.path().join("chapter.md");
+        let shared = dir.path().join("shared.md");
+        fs::write(&shared, "Shared one\nShared two\n").unwrap();
+
+        let source = "# Chapter\n\n{{#include shared.md}}\n\nAfter\n";
+        let expanded = "# Chapter\n\nShared one\nShared two\n\nAfter\n";
+        let map = SourceMap::new(source, &chapter, expanded).unwrap();
+
+        assert_eq!(map.locate(1, 3), location(&chapter, 1, 3));
+        assert_eq!(map.locate(3, 1), location(&shared, 1, 1));
+        assert_eq!(map.locate(4, 8), location(&shared, 2, 8));
+        assert_eq!(map.locate(6, 1), location(&chapter, 5, 1));
+    }
+
+    #[test]
+    fn test_maps_ranges_anchors_and_inline_includes() {
+        let dir = TempDir::new().unwrap();
+        let chapter = dir.path().join("guide").join("chapter.md");
+        let code = dir.path().join("listings").join("main.rs");
+        fs::create_dir_all(code.parent().unwrap()).unwrap();
+        fs::write(
+            &code,
+            "use std::io;\n// ANCHOR: main\nfn main() {\n    // ANCHOR: body\n    run();\n    // ANCHOR_END: body\n}\n// ANCHOR_END: main\n",
+        )
+        .unwrap();
+
+        let source = "```rust\n{{#include ../listings/main.rs:main}}\n```\n\nSee `{{#include ../listings/main.rs:1}}` and \\{{#include x.rs}}.\n";
+        let expanded = "```rust\nfn main() {\n    run();\n}\n```\n\nSee `use std::io;` and {{#include x.rs}}.\n";
+        let map = SourceMap::new(source, &chapter, expanded).unwrap();
+
+        assert_eq!(map.locate(2, 1), location(&code, 3, 1));
+        assert_eq!(map.locate(3, 5), location(&code, 5, 5));
+        assert_eq!(map.locate(4, 1), location(&code, 7, 1));
+        assert_eq!(map.locate(5, 1), location(&chapter, 3, 1));
+        // Inline include, then text after it and an escaped directive
+        assert_eq!(map.locate(7, 1), location(&chapter, 5, 1));
+        assert_eq!(map.locate(7, 6), location(&code, 1, 1));
+        assert_eq!(map.locate(7, 18), location(&chapter, 5, 40));
+        assert_eq!(map.locate(7, 24), location(&chapter, 5, 47));
+    }
+
+    #[test]
+    fn test_no_map_when_content_differs() {
+        let dir = TempDir::new().unwrap();
+        let chapter = dir.path().join("chapter.md");
+        fs::write(dir.path().join("shared.md"), "Shared\n").unwrap();
+
+        let source = "{{#include shared.md}}\n";
+        assert!(SourceMap::new(source, &chapter, "Shared\n").is_some());
+        assert!(SourceMap::new(source, &chapter, "Changed by another preprocessor\n").is_none());
+        assert!(SourceMap::new("{{#playground main.rs}}\n", &chapter, "fn main() {}\n").is_none());
+    }
+
+    #[test]
+    fn test_select_lines() {
+        let content = "one\ntwo\nthree\nfour\n";
+        let numbers = |range| {
+            select_lines(content, range)
+                .into_iter()
+                .map(|(number, _)| number)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(""), vec![1, 2, 3, 4]);
+        assert_eq!(numbers("2"), vec![2]);
+        assert_eq!(numbers("2:3"), vec![2, 3]);
+        assert_eq!(numbers("3:"), vec![3, 4]);
+        assert_eq!(numbers(":2"), vec![1, 2]);
+        assert_eq!(numbers("missing"), Vec::<usize>::new());
+    }
+}
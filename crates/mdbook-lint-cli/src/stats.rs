@@ -0,0 +1,367 @@
+//! Violation statistics for `mdbook-lint stats`
+//!
+//! [`Stats::collect`] aggregates lint results by rule, by directory, and by
+//! severity. The report renders as tables, JSON, or a Markdown summary that
+//! can be pasted into an issue.
+
+use mdbook_lint_core::{Severity, Violation};
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use tabled::{Table, Tabled, settings::Style};
+
+/// Violation counts at each severity
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SeverityCounts {
+    pub errors: usize,
+    pub warnings: usize,
+    pub info: usize,
+}
+
+impl SeverityCounts {
+    fn add(&mut self, severity: Severity) {
+        match severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+            Severity::Info => self.info += 1,
+        }
+    }
+
+    /// Total number of violations
+    pub fn total(&self) -> usize {
+        self.errors + self.warnings + self.info
+    }
+}
+
+/// Violations reported by one rule
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuleStats {
+    pub rule_id: String,
+    pub rule_name: String,
+    pub violations: usize,
+    /// Number of files the rule fired in
+    pub files: usize,
+    #[serde(flatten)]
+    pub severity: SeverityCounts,
+}
+
+/// Violations in the files directly inside one directory
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DirectoryStats {
+    pub directory: String,
+    pub files: usize,
+    pub violations: usize,
+    #[serde(flatten)]
+    pub severity: SeverityCounts,
+}
+
+/// Aggregated lint results
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    pub files: usize,
+    pub files_with_violations: usize,
+    pub total_violations: usize,
+    pub by_severity: SeverityCounts,
+    /// Most frequent rule first
+    pub by_rule: Vec<RuleStats>,
+    /// Most violations first
+    pub by_directory: Vec<DirectoryStats>,
+}
+
+impl Stats {
+    /// Aggregate per-file lint results
+    pub fn collect(results: &[(String, Vec<Violation>)]) -> Self {
+        let mut by_severity = SeverityCounts::default();
+        let mut rules: BTreeMap<&str, (RuleStats, BTreeSet<&str>)> = BTreeMap::new();
+        let mut directories: BTreeMap<String, DirectoryStats> = BTreeMap::new();
+
+        for (path, violations) in results {
+            let directory = directory_of(path);
+            let dir = directories
+                .entry(directory.clone())
+                .or_insert_with(|| DirectoryStats {
+                    directory,
+                    files: 0,
+                    violations: 0,
+                    severity: SeverityCounts::default(),
+                });
+            dir.files += 1;
+
+            for violation in violations {
+                by_severity.add(violation.severity);
+                dir.violations += 1;
+                dir.severity.add(violation.severity);
+
+                let (rule, files) = rules.entry(&violation.rule_id).or_insert_with(|| {
+                    let stats = RuleStats {
+                        rule_id: violation.rule_id.clone(),
+                        rule_name: violation.rule_name.clone(),
+                        violations: 0,
+                        files: 0,
+                        severity: SeverityCounts::default(),
+                    };
+                    (stats, BTreeSet::new())
+                });
+                rule.violations += 1;
+                rule.severity.add(violation.severity);
+                files.insert(path);
+            }
+        }
+
+        let mut by_rule: Vec<RuleStats> = rules
+            .into_values()
+            .map(|(mut rule, files)| {
+                rule.files = files.len();
+                rule
+            })
+            .collect();
+        by_rule.sort_by(|a, b| {
+            b.violations
+                .cmp(&a.violations)
+                .then(a.rule_id.cmp(&b.rule_id))
+        });
+
+        let mut by_directory: Vec<DirectoryStats> = directories
+            .into_values()
+            .filter(|dir| dir.violations > 0)
+            .collect();
+        by_directory.sort_by(|a, b| {
+            b.violations
+                .cmp(&a.violations)
+                .then(a.directory.cmp(&b.directory))
+        });
+
+        Self {
+            files: results.len(),
+            files_with_violations: results.iter().filter(|(_, v)| !v.is_empty()).count(),
+            total_violations: by_severity.total(),
+            by_severity,
+            by_rule,
+            by_directory,
+        }
+    }
+
+    /// Render as plain-text tables
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "{} violations in {} of {} files ({} errors, {} warnings, {} info)\n",
+            self.total_violations,
+            self.files_with_violations,
+            self.files,
+            self.by_severity.errors,
+            self.by_severity.warnings,
+            self.by_severity.info,
+        );
+        if self.total_violations > 0 {
+            out.push_str(&format!(
+                "\nBy rule:\n{}\n",
+                self.rule_table().with(Style::rounded())
+            ));
+            out.push_str(&format!(
+                "\nBy directory:\n{}\n",
+                self.directory_table().with(Style::rounded())
+            ));
+        }
+        out
+    }
+
+    /// Render as a Markdown report
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "## Lint statistics\n\n\
+             - **Files**: {} ({} with violations)\n\
+             - **Violations**: {}\n\
+             - **Errors**: {}\n\
+             - **Warnings**: {}\n\
+             - **Info**: {}\n",
+            self.files,
+            self.files_with_violations,
+            self.total_violations,
+            self.by_severity.errors,
+            self.by_severity.warnings,
+            self.by_severity.info,
+        );
+        if self.total_violations > 0 {
+            out.push_str(&format!(
+                "\n### By rule\n\n{}\n",
+                self.rule_table().with(Style::markdown())
+            ));
+            out.push_str(&format!(
+                "\n### By directory\n\n{}\n",
+                self.directory_table().with(Style::markdown())
+            ));
+        }
+        out
+    }
+
+    fn rule_table(&self) -> Table {
+        let rows = self.by_rule.iter().map(|rule| RuleRow {
+            rule: rule.rule_id.clone(),
+            name: rule.rule_name.clone(),
+            violations: rule.violations,
+            errors: rule.severity.errors,
+            warnings: rule.severity.warnings,
+            info: rule.severity.info,
+            files: rule.files,
+        });
+        Table::new(rows)
+    }
+
+    fn directory_table(&self) -> Table {
+        let rows = self.by_directory.iter().map(|dir| DirectoryRow {
+            directory: dir.directory.clone(),
+            violations: dir.violations,
+            errors: dir.severity.errors,
+            warnings: dir.severity.warnings,
+            info: dir.severity.info,
+            files: dir.files,
+        });
+        Table::new(rows)
+    }
+}
+
+#[derive(Tabled)]
+struct RuleRow {
+    #[tabled(rename = "Rule")]
+    rule: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Violations")]
+    violations: usize,
+    #[tabled(rename = "Errors")]
+    errors: usize,
+    #[tabled(rename = "Warnings")]
+    warnings: usize,
+    #[tabled(rename = "Info")]
+    info: usize,
+    #[tabled(rename = "Files")]
+    files: usize,
+}
+
+#[derive(Tabled)]
+struct DirectoryRow {
+    #[tabled(rename = "Directory")]
+    directory: String,
+    #[tabled(rename = "Violations")]
+    violations: usize,
+    #[tabled(rename = "Errors")]
+    errors: usize,
+    #[tabled(rename = "Warnings")]
+    warnings: usize,
+    #[tabled(rename = "Info")]
+    info: usize,
+    #[tabled(rename = "Files")]
+    files: usize,
+}
+
+/// Directory of a file path with `/` separators, or `.` for the current
+/// directory
+fn directory_of(path: &str) -> String {
+    let parent = Path::new(path)
+        .parent()
+        .map(|p| p.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default();
+    match parent.trim_start_matches("./") {
+        "" | "." => ".".to_string(),
+        dir => dir.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule_id: &str, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_id.to_lowercase(),
+            message: String::new(),
+            line: 1,
+            column: 1,
+            severity,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    fn stats() -> Stats {
+        Stats::collect(&[
+            (
+                "src/intro.md".to_string(),
+                vec![
+                    violation("MD013", Severity::Warning),
+                    violation("MD013", Severity::Warning),
+                    violation("MD001", Severity::Error),
+                ],
+            ),
+            (
+                "src/guide/setup.md".to_string(),
+                vec![violation("MD013", Severity::Info)],
+            ),
+            ("src/guide/clean.md".to_string(), vec![]),
+            ("README.md".to_string(), vec![]),
+        ])
+    }
+
+    #[test]
+    fn test_collect_totals() {
+        let stats = stats();
+        assert_eq!(stats.files, 4);
+        assert_eq!(stats.files_with_violations, 2);
+        assert_eq!(stats.total_violations, 4);
+        assert_eq!(
+            stats.by_severity,
+            SeverityCounts {
+                errors: 1,
+                warnings: 2,
+                info: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_collect_by_rule_and_directory() {
+        let stats = stats();
+        let md013 = &stats.by_rule[0];
+        assert_eq!(md013.rule_id, "MD013");
+        assert_eq!((md013.violations, md013.files), (3, 2));
+        assert_eq!((md013.severity.warnings, md013.severity.info), (2, 1));
+        assert_eq!(stats.by_rule[1].rule_id, "MD001");
+
+        let directories: Vec<(&str, usize, usize)> = stats
+            .by_directory
+            .iter()
+            .map(|d| (d.directory.as_str(), d.violations, d.files))
+            .collect();
+        assert_eq!(directories, vec![("src", 3, 1), ("src/guide", 1, 2)]);
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let markdown = stats().to_markdown();
+        assert!(markdown.starts_with("## Lint statistics\n"));
+        assert!(markdown.contains("- **Violations**: 4\n"));
+        assert!(markdown.contains("### By rule"));
+        assert!(markdown.contains("| MD013 | md013 |"));
+        assert!(markdown.contains("| src/guide "));
+    }
+
+    #[test]
+    fn test_clean_report_has_no_tables() {
+        let stats = Stats::collect(&[("a.md".to_string(), vec![])]);
+        assert_eq!(
+            stats.to_text(),
+            "0 violations in 0 of 1 files (0 errors, 0 warnings, 0 info)\n"
+        );
+        assert!(!stats.to_markdown().contains("###"));
+    }
+
+    #[test]
+    fn test_directory_of() {
+        assert_eq!(directory_of("a.md"), ".");
+        assert_eq!(directory_of("./a.md"), ".");
+        assert_eq!(directory_of("./src/a.md"), "src");
+        assert_eq!(directory_of("src/nested/a.md"), "src/nested");
+    }
+}
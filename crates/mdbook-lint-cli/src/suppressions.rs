@@ -0,0 +1,154 @@
+//! Suppression listing for `mdbook-lint suppressions`
+//!
+//! [`SuppressionReport::collect`] gathers the inline suppression comments
+//! (see [`mdbook_lint_core::suppression`]) in each scanned file, with the
+//! rules they turn off and the reasons given, so they can be audited. The
+//! report renders as text or JSON.
+
+use mdbook_lint_core::Suppressions;
+use mdbook_lint_core::suppression::{DIRECTIVE_PREFIX, Suppression};
+use serde::Serialize;
+
+/// Suppression comments in one file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileSuppressions {
+    pub path: String,
+    pub suppressions: Vec<Suppression>,
+}
+
+/// Suppression comments across all scanned files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SuppressionReport {
+    pub total: usize,
+    /// Comments that turn rules off without giving a reason
+    pub missing_reasons: usize,
+    /// Files with at least one comment, in the order they were scanned
+    pub files: Vec<FileSuppressions>,
+}
+
+impl SuppressionReport {
+    /// Group the suppression comments found in each file
+    pub fn collect(results: &[(String, Suppressions)]) -> Self {
+        let files: Vec<FileSuppressions> = results
+            .iter()
+            .filter(|(_, suppressions)| !suppressions.is_empty())
+            .map(|(path, suppressions)| FileSuppressions {
+                path: path.clone(),
+                suppressions: suppressions.directives().to_vec(),
+            })
+            .collect();
+        let all = || files.iter().flat_map(|file| &file.suppressions);
+
+        Self {
+            total: all().count(),
+            missing_reasons: all().filter(|s| s.needs_reason()).count(),
+            files,
+        }
+    }
+
+    /// Render the report for the terminal
+    pub fn to_text(&self) -> String {
+        if self.files.is_empty() {
+            return "No suppression comments found\n".to_string();
+        }
+
+        let mut out = String::new();
+        for file in &self.files {
+            out.push_str(&file.path);
+            out.push('\n');
+            let width = file
+                .suppressions
+                .iter()
+                .map(|s| format!("{}:{}", s.line, s.column).len())
+                .max()
+                .unwrap_or(0);
+            let directive_width = file
+                .suppressions
+                .iter()
+                .map(|s| DIRECTIVE_PREFIX.len() + s.directive.name().len())
+                .max()
+                .unwrap_or(0);
+            for suppression in &file.suppressions {
+                let position = format!("{}:{}", suppression.line, suppression.column);
+                let directive = format!("{DIRECTIVE_PREFIX}{}", suppression.directive.name());
+                let rules = if suppression.rules.is_empty() {
+                    "all rules".to_string()
+                } else {
+                    suppression.rules.join(", ")
+                };
+                let reason = match &suppression.reason {
+                    Some(reason) => format!(" -- {reason}"),
+                    None if suppression.needs_reason() => " (no reason)".to_string(),
+                    None => String::new(),
+                };
+                out.push_str(&format!(
+                    "  {position:<width$}  {directive:<directive_width$}  {rules}{reason}\n"
+                ));
+            }
+            out.push('\n');
+        }
+
+        let comments = if self.total == 1 {
+            "suppression comment"
+        } else {
+            "suppression comments"
+        };
+        let files = if self.files.len() == 1 {
+            "file"
+        } else {
+            "files"
+        };
+        out.push_str(&format!(
+            "{} {comments} in {} {files}, {} without a reason\n",
+            self.total,
+            self.files.len(),
+            self.missing_reasons
+        ));
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::Document;
+    use std::path::PathBuf;
+
+    fn suppressions(content: &str) -> Suppressions {
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        Suppressions::from_document(&document)
+    }
+
+    #[test]
+    fn test_collect_and_render() {
+        let results = vec![
+            (
+                "src/intro.md".to_string(),
+                suppressions(
+                    "# Intro\n\n<!-- mdbook-lint-disable-next-line MD013 -- reason: long URL -->\nText\n<!-- mdbook-lint-disable -->\nText\n<!-- mdbook-lint-enable -->\n",
+                ),
+            ),
+            ("src/clean.md".to_string(), suppressions("# Clean\n")),
+        ];
+        let report = SuppressionReport::collect(&results);
+        assert_eq!(report.total, 3);
+        assert_eq!(report.missing_reasons, 1);
+        assert_eq!(report.files.len(), 1);
+
+        assert_eq!(
+            report.to_text(),
+            "src/intro.md\n  \
+             3:1  mdbook-lint-disable-next-line  MD013 -- long URL\n  \
+             5:1  mdbook-lint-disable            all rules (no reason)\n  \
+             7:1  mdbook-lint-enable             all rules\n\
+             \n3 suppression comments in 1 file, 1 without a reason\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = SuppressionReport::collect(&[("a.md".to_string(), suppressions("# A\n"))]);
+        assert_eq!(report.total, 0);
+        assert_eq!(report.to_text(), "No suppression comments found\n");
+    }
+}
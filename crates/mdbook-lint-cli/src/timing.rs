@@ -0,0 +1,133 @@
+//! Per-rule and per-file timing reports for `lint --timing`
+
+use crate::memory::MemoryStats;
+use anstream::eprintln;
+use anstyle::{AnsiColor, Style};
+use mdbook_lint_core::{DocumentTiming, MdBookLintError, Result, TimingReport};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Where and how much timing information to report
+#[derive(Debug, Clone)]
+pub struct TimingOptions {
+    /// Print the slowest rules and files to stderr
+    pub print: bool,
+    /// Write the full report as JSON to this file
+    pub json: Option<PathBuf>,
+    /// Number of rules and files to print
+    pub top: usize,
+}
+
+/// Format a duration in milliseconds with a fixed precision
+pub fn format_ms(duration: Duration) -> String {
+    format!("{:.3} ms", duration.as_secs_f64() * 1000.0)
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Report timings collected while linting `files`, and the memory used
+pub fn report(
+    files: &[(String, DocumentTiming)],
+    memory: Option<MemoryStats>,
+    options: &TimingOptions,
+) -> Result<()> {
+    let mut report = TimingReport::new();
+    for (_, timing) in files {
+        report.record(timing);
+    }
+
+    let mut slowest_files: Vec<(&str, Duration, Duration)> = files
+        .iter()
+        .map(|(path, timing)| (path.as_str(), timing.total(), timing.parse))
+        .collect();
+    slowest_files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+
+    if options.print {
+        print_report(&report, &slowest_files, memory, options.top);
+    }
+
+    if let Some(path) = &options.json {
+        let json = serde_json::json!({
+            "files_linted": files.len(),
+            "total_ms": millis(report.total()),
+            "parse_ms": millis(report.parse),
+            "memory": memory,
+            "rules": report.slowest().iter().map(|(rule_id, summary)| {
+                serde_json::json!({
+                    "rule": rule_id,
+                    "total_ms": millis(summary.total),
+                    "max_ms": millis(summary.max),
+                    "files": summary.documents,
+                    "violations": summary.violations,
+                })
+            }).collect::<Vec<_>>(),
+            "files": slowest_files.iter().map(|(file, total, parse)| {
+                serde_json::json!({
+                    "file": file,
+                    "total_ms": millis(*total),
+                    "parse_ms": millis(*parse),
+                })
+            }).collect::<Vec<_>>(),
+        });
+        let content = serde_json::to_string_pretty(&json).map_err(|e| {
+            MdBookLintError::document_error(format!("Failed to serialize timing report: {e}"))
+        })?;
+        std::fs::write(path, content).map_err(|e| {
+            MdBookLintError::document_error(format!(
+                "Failed to write timing report {}: {e}",
+                path.display()
+            ))
+        })?;
+    }
+
+    Ok(())
+}
+
+fn print_report(
+    report: &TimingReport,
+    slowest_files: &[(&str, Duration, Duration)],
+    memory: Option<MemoryStats>,
+    top: usize,
+) {
+    let header = Style::new().fg_color(Some(AnsiColor::Green.into())).bold();
+    let total = report.total();
+
+    eprintln!(
+        "{header}Timing{header:#}: {} files, {} in rules and parsing ({} parsing)",
+        slowest_files.len(),
+        format_ms(total),
+        format_ms(report.parse)
+    );
+    if let Some(memory) = memory {
+        eprintln!("{header}Memory{header:#}: {}", memory.summary());
+    }
+
+    let rules = report.slowest();
+    let width = rules
+        .iter()
+        .take(top)
+        .map(|(id, _)| id.len())
+        .max()
+        .unwrap_or(0);
+    eprintln!("\n{header}Slowest rules{header:#}:");
+    for (rule_id, summary) in rules.iter().take(top) {
+        let share = 100.0 * summary.total.as_secs_f64() / total.as_secs_f64().max(f64::EPSILON);
+        eprintln!(
+            "  {rule_id:<width$}  {:>12}  {share:>5.1}%",
+            format_ms(summary.total)
+        );
+    }
+
+    let width = slowest_files
+        .iter()
+        .take(top)
+        .map(|(file, _, _)| file.len())
+        .max()
+        .unwrap_or(0);
+    eprintln!("\n{header}Slowest files{header:#}:");
+    for (file, file_total, _) in slowest_files.iter().take(top) {
+        eprintln!("  {file:<width$}  {:>12}", format_ms(*file_total));
+    }
+}
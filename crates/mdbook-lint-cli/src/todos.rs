@@ -0,0 +1,193 @@
+//! TODO and placeholder listing for `mdbook-lint todos`
+//!
+//! [`TodoReport::collect`] picks the markers found by CONTENT001 (TODO,
+//! FIXME, and friends) and CONTENT002 (placeholder text) out of lint
+//! results and groups them by file. The report renders as text or JSON.
+
+use mdbook_lint_core::Violation;
+use serde::Serialize;
+
+/// Rules whose violations are work-in-progress markers
+pub const TODO_RULES: &[&str] = &["CONTENT001", "CONTENT002"];
+
+/// One marker in a file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Todo {
+    pub line: usize,
+    pub column: usize,
+    /// The marker, such as `TODO` or `FIXME`, or `placeholder`
+    pub marker: String,
+    pub rule_id: String,
+    pub message: String,
+}
+
+/// Markers found in one file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FileTodos {
+    pub path: String,
+    pub todos: Vec<Todo>,
+}
+
+/// Markers across all scanned files
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TodoReport {
+    pub total: usize,
+    /// Files with at least one marker, in the order they were scanned
+    pub files: Vec<FileTodos>,
+}
+
+impl TodoReport {
+    /// Group the TODO markers in per-file lint results
+    pub fn collect(results: &[(String, Vec<Violation>)]) -> Self {
+        let files: Vec<FileTodos> = results
+            .iter()
+            .filter_map(|(path, violations)| {
+                let mut todos: Vec<Todo> = violations
+                    .iter()
+                    .filter(|v| TODO_RULES.contains(&v.rule_id.as_str()))
+                    .map(|v| Todo {
+                        line: v.line,
+                        column: v.column,
+                        marker: marker_of(v),
+                        rule_id: v.rule_id.clone(),
+                        message: v.message.clone(),
+                    })
+                    .collect();
+                if todos.is_empty() {
+                    return None;
+                }
+                todos.sort_by_key(|t| (t.line, t.column));
+                Some(FileTodos {
+                    path: path.clone(),
+                    todos,
+                })
+            })
+            .collect();
+
+        Self {
+            total: files.iter().map(|f| f.todos.len()).sum(),
+            files,
+        }
+    }
+
+    /// Render the report for the terminal
+    pub fn to_text(&self) -> String {
+        if self.files.is_empty() {
+            return "No TODO, FIXME, or placeholder markers found\n".to_string();
+        }
+
+        let mut out = String::new();
+        for file in &self.files {
+            out.push_str(&file.path);
+            out.push('\n');
+            let width = file
+                .todos
+                .iter()
+                .map(|t| format!("{}:{}", t.line, t.column).len())
+                .max()
+                .unwrap_or(0);
+            let marker_width = file.todos.iter().map(|t| t.marker.len()).max().unwrap_or(0);
+            for todo in &file.todos {
+                let position = format!("{}:{}", todo.line, todo.column);
+                out.push_str(&format!(
+                    "  {position:<width$}  {:<marker_width$}  {}\n",
+                    todo.marker, todo.message
+                ));
+            }
+            out.push('\n');
+        }
+
+        let markers = if self.total == 1 { "marker" } else { "markers" };
+        let files = if self.files.len() == 1 {
+            "file"
+        } else {
+            "files"
+        };
+        out.push_str(&format!(
+            "{} {markers} in {} {files}\n",
+            self.total,
+            self.files.len()
+        ));
+        out
+    }
+}
+
+/// The marker a violation reports: the leading word of a CONTENT001
+/// message, such as `TODO`, and `placeholder` for CONTENT002
+fn marker_of(violation: &Violation) -> String {
+    match violation.rule_id.as_str() {
+        "CONTENT001" => violation
+            .message
+            .split_whitespace()
+            .next()
+            .unwrap_or("TODO")
+            .to_string(),
+        _ => "placeholder".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::Severity;
+
+    fn violation(rule_id: &str, line: usize, message: &str) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: String::new(),
+            message: message.to_string(),
+            line,
+            column: 1,
+            severity: Severity::Warning,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_collect_and_render() {
+        let results = vec![
+            (
+                "src/intro.md".to_string(),
+                vec![
+                    violation(
+                        "CONTENT002",
+                        12,
+                        "TBD (To Be Determined) placeholder - replace with actual content",
+                    ),
+                    violation("MD013", 3, "Line too long"),
+                    violation(
+                        "CONTENT001",
+                        3,
+                        "FIXME comment found - resolve before publishing",
+                    ),
+                ],
+            ),
+            ("src/clean.md".to_string(), vec![]),
+        ];
+        let report = TodoReport::collect(&results);
+        assert_eq!(report.total, 2);
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(report.files[0].todos[0].marker, "FIXME");
+        assert_eq!(report.files[0].todos[1].marker, "placeholder");
+
+        assert_eq!(
+            report.to_text(),
+            "src/intro.md\n  \
+             3:1   FIXME        FIXME comment found - resolve before publishing\n  \
+             12:1  placeholder  TBD (To Be Determined) placeholder - replace with actual content\n\
+             \n2 markers in 1 file\n"
+        );
+    }
+
+    #[test]
+    fn test_empty_report() {
+        let report = TodoReport::collect(&[("a.md".to_string(), vec![])]);
+        assert_eq!(report.total, 0);
+        assert_eq!(
+            report.to_text(),
+            "No TODO, FIXME, or placeholder markers found\n"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Undo journal for fixes
+//!
+//! Every run that writes fixes records a session under `.mdbook-lint/undo/`
+//! in the project root: for each file, its path, hashes of the bytes before
+//! and after the fix, and the lines the fix changed. `fix --undo` restores the
+//! most recent session. It refuses to touch anything if one of the files has
+//! changed since it was fixed, so edits made after the fix are never lost.
+
+use mdbook_lint_core::{MdBookLintError, Result, SourceEncoding};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Journal directory, relative to the project root
+pub const UNDO_DIR: &str = ".mdbook-lint/undo";
+
+/// Number of sessions kept; older ones are removed when a new one is saved
+const KEEP_SESSIONS: usize = 10;
+
+/// Files that mark a project root
+const ROOT_MARKERS: &[&str] = &["book.toml", ".mdbook-lint.toml", ".git"];
+
+/// The files written by one fix run
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Session {
+    pub files: Vec<FileRecord>,
+}
+
+/// One fixed file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FileRecord {
+    /// Absolute path of the file
+    pub path: PathBuf,
+    /// Hash of the file's bytes before the fix
+    pub before_hash: String,
+    /// Hash of the file's bytes after the fix
+    pub after_hash: String,
+    /// The lines the fix changed
+    pub hunk: Hunk,
+}
+
+/// The lines that differ between two versions of a text
+///
+/// Lines shared at the start and end are left out, so a fix that touches a
+/// few lines records only those.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Hunk {
+    /// Index of the first differing line
+    pub line: usize,
+    /// The differing lines before the fix
+    pub before: String,
+    /// The differing lines after the fix
+    pub after: String,
+}
+
+impl Hunk {
+    /// The hunk that turns `before` into `after`
+    pub fn between(before: &str, after: &str) -> Self {
+        let old: Vec<&str> = before.split_inclusive('\n').collect();
+        let new: Vec<&str> = after.split_inclusive('\n').collect();
+
+        let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+        let suffix = old[prefix..]
+            .iter()
+            .rev()
+            .zip(new[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+
+        Self {
+            line: prefix,
+            before: old[prefix..old.len() - suffix].concat(),
+            after: new[prefix..new.len() - suffix].concat(),
+        }
+    }
+
+    /// Turn the fixed text back into the original, or `None` if `after` does
+    /// not contain this hunk's lines where it expects them
+    pub fn revert(&self, after: &str) -> Option<String> {
+        let lines: Vec<&str> = after.split_inclusive('\n').collect();
+        let start: usize = lines.get(..self.line)?.iter().map(|line| line.len()).sum();
+        let end = start + self.after.len();
+        (after.get(start..end)? == self.after)
+            .then(|| [&after[..start], &self.before, &after[end..]].concat())
+    }
+}
+
+/// Records the files written by a fix run
+pub struct Journal {
+    file: Option<PathBuf>,
+    session: Session,
+}
+
+impl Journal {
+    /// Start an empty session; nothing is written until a file is recorded
+    pub fn new() -> Self {
+        Self {
+            file: None,
+            session: Session::default(),
+        }
+    }
+
+    /// Record a file that is about to be overwritten with `after_bytes`
+    ///
+    /// The session is saved after every file, so files written before a
+    /// failure can still be undone.
+    pub fn record(
+        &mut self,
+        path: &Path,
+        before_bytes: &[u8],
+        before: &str,
+        after_bytes: &[u8],
+        after: &str,
+    ) -> Result<()> {
+        let path = std::path::absolute(path)?;
+        let file = match &self.file {
+            Some(file) => file.clone(),
+            None => {
+                let dir = project_root(&path).join(UNDO_DIR);
+                std::fs::create_dir_all(&dir)?;
+                prune(&dir)?;
+                let file = new_session_file(&dir);
+                self.file = Some(file.clone());
+                file
+            }
+        };
+
+        self.session.files.push(FileRecord {
+            path,
+            before_hash: hash(before_bytes),
+            after_hash: hash(after_bytes),
+            hunk: Hunk::between(before, after),
+        });
+        std::fs::write(&file, serde_json::to_string_pretty(&self.session)?)?;
+        Ok(())
+    }
+
+    /// The session file, once a file has been recorded
+    pub fn file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+}
+
+/// Restore the files of the most recent session found at or above `start`
+///
+/// Returns the restored paths, or `None` when there is nothing to undo. The
+/// session is removed once its files are restored, so repeated calls walk
+/// back through earlier sessions.
+pub fn undo_last(start: &Path) -> Result<Option<Vec<PathBuf>>> {
+    let start = std::path::absolute(start)?;
+    let Some(session_file) = start
+        .ancestors()
+        .find_map(|dir| sessions(&dir.join(UNDO_DIR)).pop())
+    else {
+        return Ok(None);
+    };
+
+    let session: Session = serde_json::from_str(&std::fs::read_to_string(&session_file)?)?;
+
+    // Work out every restored file before writing any of them
+    let mut restored = Vec::new();
+    for record in &session.files {
+        let changed = || {
+            MdBookLintError::document_error(format!(
+                "Cannot undo: {} has changed since it was fixed",
+                record.path.display()
+            ))
+        };
+        let bytes = std::fs::read(&record.path).map_err(|_| changed())?;
+        if hash(&bytes) != record.after_hash {
+            return Err(changed());
+        }
+        let (text, encoding) = SourceEncoding::decode(&bytes)?;
+        let original = record.hunk.revert(&text).ok_or_else(changed)?;
+        let original = encoding.encode(&original);
+        if hash(&original) != record.before_hash {
+            return Err(MdBookLintError::document_error(format!(
+                "Cannot undo: the recorded changes to {} do not restore the original bytes",
+                record.path.display()
+            )));
+        }
+        restored.push((&record.path, original));
+    }
+
+    for (path, bytes) in &restored {
+        std::fs::write(path, bytes)?;
+    }
+    std::fs::remove_file(&session_file)?;
+    Ok(Some(
+        restored.into_iter().map(|(path, _)| path.clone()).collect(),
+    ))
+}
+
+/// The nearest directory above `file` that holds a `book.toml`, config file,
+/// or `.git`, falling back to the file's own directory
+fn project_root(file: &Path) -> PathBuf {
+    let dir = file.parent().unwrap_or(Path::new("."));
+    dir.ancestors()
+        .find(|dir| ROOT_MARKERS.iter().any(|marker| dir.join(marker).exists()))
+        .unwrap_or(dir)
+        .to_path_buf()
+}
+
+/// Session files in `dir`, oldest first
+fn sessions(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    files.sort();
+    files
+}
+
+/// Remove the oldest sessions so a new one keeps the count at the limit
+fn prune(dir: &Path) -> Result<()> {
+    let files = sessions(dir);
+    let excess = (files.len() + 1).saturating_sub(KEEP_SESSIONS);
+    for file in &files[..excess] {
+        std::fs::remove_file(file)?;
+    }
+    Ok(())
+}
+
+/// A session file name that sorts after every existing one
+fn new_session_file(dir: &Path) -> PathBuf {
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    let latest = sessions(dir)
+        .last()
+        .and_then(|file| file.file_stem()?.to_str()?.parse::<u128>().ok())
+        .unwrap_or_default();
+    dir.join(format!("{:020}.json", millis.max(latest + 1)))
+}
+
+/// 64-bit FNV-1a hash of `bytes`, as hex
+fn hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    format!("{hash:016x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hunk_round_trip() {
+        let before = "# Title  \n\nsame\nold line\nsame\n";
+        let after = "# Title\n\nsame\nnew line\nsame\n";
+        let hunk = Hunk::between(before, after);
+
+        assert_eq!(hunk.line, 0);
+        assert_eq!(hunk.before, "# Title  \n\nsame\nold line\n");
+        assert_eq!(hunk.revert(after).as_deref(), Some(before));
+        assert_eq!(hunk.revert("# Other\n"), None);
+
+        let tail = Hunk::between("a\nb", "a\nb\n");
+        assert_eq!((tail.line, tail.before.as_str()), (1, "b"));
+        assert_eq!(tail.revert("a\nb\n").as_deref(), Some("a\nb"));
+    }
+
+    #[test]
+    fn test_undo_last_session() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("chapter.md");
+        std::fs::write(&file, "fixed\n").unwrap();
+
+        let mut journal = Journal::new();
+        journal
+            .record(&file, b"broken  \n", "broken  \n", b"fixed\n", "fixed\n")
+            .unwrap();
+        assert!(
+            journal
+                .file()
+                .unwrap()
+                .starts_with(dir.path().join(UNDO_DIR))
+        );
+
+        let restored = undo_last(dir.path()).unwrap().unwrap();
+        assert_eq!(restored, vec![std::path::absolute(&file).unwrap()]);
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "broken  \n");
+        assert!(undo_last(dir.path()).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_undo_refuses_changed_file() {
+        let dir = TempDir::new().unwrap();
+        let file = dir.path().join("chapter.md");
+        std::fs::write(&file, "edited since\n").unwrap();
+
+        let mut journal = Journal::new();
+        journal
+            .record(&file, b"broken  \n", "broken  \n", b"fixed\n", "fixed\n")
+            .unwrap();
+
+        let error = undo_last(dir.path()).unwrap_err();
+        assert!(error.to_string().contains("has changed since it was fixed"));
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "edited since\n");
+    }
+}
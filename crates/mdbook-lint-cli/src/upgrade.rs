@@ -0,0 +1,150 @@
+//! `upgrade-config`: move a configuration's `ruleset-version` forward
+//!
+//! The key is rewritten in place so the rest of the file, comments included,
+//! is left untouched. TOML and YAML files are edited line by line; JSON has no
+//! comments to lose and is re-serialized.
+
+use mdbook_lint_core::{MdBookLintError, Result};
+use std::path::Path;
+
+/// Spellings of the key accepted when reading a configuration
+const KEYS: &[&str] = &["ruleset-version", "ruleset_version"];
+
+/// Version to pin to: the newest of the installed crate, the rules it ships,
+/// and the current pin, so upgrading never moves the pin backwards
+pub fn target_version(
+    latest_rule: Option<(u64, u64, u64)>,
+    current: Option<(u64, u64, u64)>,
+) -> String {
+    let (major, minor, patch) = [
+        mdbook_lint_core::config::parse_version(env!("CARGO_PKG_VERSION")),
+        latest_rule,
+        current,
+    ]
+    .into_iter()
+    .flatten()
+    .max()
+    .unwrap_or_default();
+    format!("{major}.{minor}.{patch}")
+}
+
+/// Return `content` with its `ruleset-version` set to `version`
+///
+/// The format is taken from the extension of `path`, defaulting to TOML.
+pub fn set_ruleset_version(content: &str, path: &Path, version: &str) -> Result<String> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => set_json(content, version),
+        Some("yaml") | Some("yml") => Ok(set_line(content, version, ':', false)),
+        _ => Ok(set_line(content, version, '=', true)),
+    }
+}
+
+fn set_json(content: &str, version: &str) -> Result<String> {
+    let mut value: serde_json::Value = serde_json::from_str(content)
+        .map_err(|e| MdBookLintError::config_error(format!("Failed to parse JSON config: {e}")))?;
+    let object = value.as_object_mut().ok_or_else(|| {
+        MdBookLintError::config_error("JSON config must be an object".to_string())
+    })?;
+    object.remove("ruleset_version");
+    object.insert(
+        "ruleset-version".to_string(),
+        serde_json::Value::String(version.to_string()),
+    );
+    let mut json = serde_json::to_string_pretty(&value).map_err(|e| {
+        MdBookLintError::config_error(format!("Failed to serialize JSON config: {e}"))
+    })?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Replace the top-level key line, or insert one after the leading comments
+///
+/// In TOML only lines before the first table header are top level; in YAML
+/// they are the unindented ones.
+fn set_line(content: &str, version: &str, separator: char, toml: bool) -> String {
+    let new_line = if toml {
+        format!("ruleset-version = \"{version}\"")
+    } else {
+        format!("ruleset-version: \"{version}\"")
+    };
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+
+    let top_level = lines
+        .iter()
+        .take_while(|line| !toml || !line.trim_start().starts_with('['))
+        .count();
+    let existing = lines[..top_level].iter().position(|line| {
+        let line = if toml { line.trim_start() } else { line };
+        KEYS.iter().any(|key| {
+            line.strip_prefix(key)
+                .is_some_and(|rest| rest.trim_start().starts_with(separator))
+        })
+    });
+
+    if let Some(index) = existing {
+        lines[index] = new_line;
+    } else {
+        let index = lines
+            .iter()
+            .take_while(|line| line.trim_start().starts_with('#'))
+            .count();
+        let opens_section = lines
+            .get(index)
+            .is_some_and(|line| toml && line.trim_start().starts_with('['));
+        if opens_section {
+            lines.insert(index, String::new());
+        }
+        lines.insert(index, new_line);
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toml_replaces_existing_key() {
+        let content =
+            "# Lint settings\nruleset_version = \"0.1.0\"\n\n[MD013]\nline-length = 100\n";
+        let updated =
+            set_ruleset_version(content, Path::new(".mdbook-lint.toml"), "0.15.0").unwrap();
+        assert_eq!(
+            updated,
+            "# Lint settings\nruleset-version = \"0.15.0\"\n\n[MD013]\nline-length = 100\n"
+        );
+    }
+
+    #[test]
+    fn test_toml_inserts_key_before_first_table() {
+        let content = "# Lint settings\n[MD013]\nruleset-version = 1\n";
+        let updated = set_ruleset_version(content, Path::new("lint.toml"), "0.15.0").unwrap();
+        assert_eq!(
+            updated,
+            "# Lint settings\nruleset-version = \"0.15.0\"\n\n[MD013]\nruleset-version = 1\n"
+        );
+    }
+
+    #[test]
+    fn test_yaml_and_json() {
+        let yaml = "fail-on-warnings: true\nruleset-version: 0.1.0\n";
+        assert_eq!(
+            set_ruleset_version(yaml, Path::new("lint.yaml"), "0.15.0").unwrap(),
+            "fail-on-warnings: true\nruleset-version: \"0.15.0\"\n"
+        );
+
+        let json = "{\"ruleset_version\": \"0.1.0\"}";
+        let updated = set_ruleset_version(json, Path::new("lint.json"), "0.15.0").unwrap();
+        let value: serde_json::Value = serde_json::from_str(&updated).unwrap();
+        assert_eq!(value, serde_json::json!({"ruleset-version": "0.15.0"}));
+    }
+
+    #[test]
+    fn test_target_version_never_moves_backwards() {
+        assert_eq!(target_version(None, Some((99, 0, 0))), "99.0.0");
+        assert_eq!(target_version(Some((98, 1, 2)), Some((0, 1, 0))), "98.1.2");
+    }
+}
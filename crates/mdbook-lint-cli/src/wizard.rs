@@ -0,0 +1,376 @@
+//! Interactive `init --interactive` wizard
+//!
+//! The caller lints the book once per preset and passes the results in; the
+//! wizard shows which rules fire, asks for a preset, rules to disable, and
+//! whether warnings should fail the build, and returns the resulting config.
+//! Prompts read whole lines, so answers can also be piped in.
+
+use crate::config::Config;
+use mdbook_lint_core::{Result, Severity, Violation};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::{BufRead, Write};
+use tabled::{Table, Tabled, settings::Style};
+
+/// How often one rule fired while scanning
+#[derive(Debug, Clone, PartialEq, Eq, Tabled)]
+pub struct RuleCount {
+    #[tabled(rename = "Rule")]
+    pub rule_id: String,
+    #[tabled(rename = "Name")]
+    pub name: String,
+    #[tabled(rename = "Violations")]
+    pub violations: usize,
+    #[tabled(rename = "Errors")]
+    pub errors: usize,
+    #[tabled(rename = "Files")]
+    pub files: usize,
+}
+
+/// Scan results for one preset choice
+#[derive(Debug, Clone)]
+pub struct PresetScan {
+    /// Preset name, or `None` for the default rules
+    pub preset: Option<&'static str>,
+    /// One-line description shown in the menu
+    pub description: &'static str,
+    /// Rules the preset disables
+    pub disabled_rules: Vec<String>,
+    /// Rules that fire with this preset, most frequent first
+    pub counts: Vec<RuleCount>,
+}
+
+impl PresetScan {
+    fn label(&self) -> &'static str {
+        self.preset.unwrap_or("none")
+    }
+
+    fn totals(&self) -> (usize, usize) {
+        self.counts
+            .iter()
+            .fold((0, 0), |(v, e), c| (v + c.violations, e + c.errors))
+    }
+}
+
+/// Count violations per rule, most frequent first
+pub fn tally(results: &[(String, Vec<Violation>)]) -> Vec<RuleCount> {
+    let mut counts: BTreeMap<&str, RuleCount> = BTreeMap::new();
+    for (_, violations) in results {
+        let mut seen_in_file = BTreeSet::new();
+        for violation in violations {
+            let count = counts
+                .entry(&violation.rule_id)
+                .or_insert_with(|| RuleCount {
+                    rule_id: violation.rule_id.clone(),
+                    name: violation.rule_name.clone(),
+                    violations: 0,
+                    errors: 0,
+                    files: 0,
+                });
+            count.violations += 1;
+            if violation.severity == Severity::Error {
+                count.errors += 1;
+            }
+            if seen_in_file.insert(&violation.rule_id) {
+                count.files += 1;
+            }
+        }
+    }
+
+    let mut counts: Vec<RuleCount> = counts.into_values().collect();
+    counts.sort_by(|a, b| {
+        b.violations
+            .cmp(&a.violations)
+            .then(a.rule_id.cmp(&b.rule_id))
+    });
+    counts
+}
+
+/// Line-based prompts over any reader and writer
+pub struct Prompter<R, W> {
+    input: R,
+    output: W,
+}
+
+impl<R: BufRead, W: Write> Prompter<R, W> {
+    /// Create a prompter reading answers from `input`
+    pub fn new(input: R, output: W) -> Self {
+        Self { input, output }
+    }
+
+    /// Print a line of text
+    pub fn say(&mut self, text: &str) -> Result<()> {
+        writeln!(self.output, "{text}")?;
+        Ok(())
+    }
+
+    /// Ask a question, returning the trimmed answer or `default` when the
+    /// answer is empty or input has ended
+    pub fn ask(&mut self, question: &str, default: &str) -> Result<String> {
+        if default.is_empty() {
+            write!(self.output, "{question}: ")?;
+        } else {
+            write!(self.output, "{question} [{default}]: ")?;
+        }
+        self.output.flush()?;
+
+        let mut answer = String::new();
+        self.input.read_line(&mut answer)?;
+        let answer = answer.trim();
+        Ok(if answer.is_empty() {
+            default.to_string()
+        } else {
+            answer.to_string()
+        })
+    }
+
+    /// Ask a yes/no question
+    pub fn confirm(&mut self, question: &str, default: bool) -> Result<bool> {
+        let hint = if default { "Y/n" } else { "y/N" };
+        loop {
+            let answer = self.ask(&format!("{question} [{hint}]"), "")?;
+            match answer.to_lowercase().as_str() {
+                "" => return Ok(default),
+                "y" | "yes" => return Ok(true),
+                "n" | "no" => return Ok(false),
+                _ => self.say("Please answer y or n")?,
+            }
+        }
+    }
+}
+
+/// Walk the user through choosing a config
+///
+/// `scans` must start with the default (no preset) scan. `available_rules`
+/// is used to validate the rules the user asks to disable.
+pub fn run<R: BufRead, W: Write>(
+    prompter: &mut Prompter<R, W>,
+    scans: &[PresetScan],
+    available_rules: &BTreeSet<String>,
+) -> Result<Config> {
+    let default_scan = &scans[0];
+    if default_scan.counts.is_empty() {
+        prompter.say("No rules fire with the default configuration.\n")?;
+    } else {
+        prompter.say("Rules that fire with the default configuration:")?;
+        let table = Table::new(&default_scan.counts)
+            .with(Style::rounded())
+            .to_string();
+        prompter.say(&format!("{table}\n"))?;
+    }
+
+    // Preset
+    prompter.say("Presets:")?;
+    for (i, scan) in scans.iter().enumerate() {
+        let (violations, errors) = scan.totals();
+        prompter.say(&format!(
+            "  {}) {:<20} {:>5} violations ({errors} errors)  {}",
+            i + 1,
+            scan.label(),
+            violations,
+            scan.description
+        ))?;
+    }
+    let scan = loop {
+        let answer = prompter.ask("Choose a preset", "1")?;
+        let chosen = answer
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .and_then(|i| scans.get(i))
+            .or_else(|| scans.iter().find(|s| s.label() == answer));
+        match chosen {
+            Some(scan) => break scan,
+            None => prompter.say(&format!("Unknown preset '{answer}'"))?,
+        }
+    };
+
+    // Rules to disable
+    let firing: Vec<&str> = scan.counts.iter().map(|c| c.rule_id.as_str()).collect();
+    let disabled_rules = if firing.is_empty() {
+        Vec::new()
+    } else {
+        prompter.say(&format!(
+            "\nStill firing with '{}': {}",
+            scan.label(),
+            scan.counts
+                .iter()
+                .map(|c| format!("{} ({})", c.rule_id, c.violations))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))?;
+        loop {
+            let answer = prompter.ask(
+                "Rules to disable (comma-separated, 'all' for every rule above, blank for none)",
+                "",
+            )?;
+            match parse_rule_list(&answer, &firing, available_rules) {
+                Ok(rules) => break rules,
+                Err(unknown) => prompter.say(&format!("Unknown rule '{unknown}'"))?,
+            }
+        }
+    };
+
+    let fail_on_warnings = prompter.confirm("Fail the build on warnings?", false)?;
+
+    let mut config = Config {
+        extends: scan.preset.map(str::to_string).into_iter().collect(),
+        fail_on_warnings,
+        ..Config::default()
+    };
+    // A list in the generated file replaces the preset's list rather than
+    // adding to it, so the preset's disabled rules are carried over
+    if !disabled_rules.is_empty() {
+        config.core.disabled_rules = scan.disabled_rules.clone();
+        for rule in disabled_rules {
+            if !config.core.disabled_rules.contains(&rule) {
+                config.core.disabled_rules.push(rule);
+            }
+        }
+    }
+    Ok(config)
+}
+
+/// Parse a comma-separated list of rule IDs, returning the first unknown ID
+/// as the error
+fn parse_rule_list(
+    answer: &str,
+    firing: &[&str],
+    available_rules: &BTreeSet<String>,
+) -> std::result::Result<Vec<String>, String> {
+    if answer.trim().eq_ignore_ascii_case("all") {
+        return Ok(firing.iter().map(|id| id.to_string()).collect());
+    }
+
+    let mut rules = Vec::new();
+    for id in answer.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+        let id = id.to_uppercase();
+        if !available_rules.contains(&id) {
+            return Err(id);
+        }
+        if !rules.contains(&id) {
+            rules.push(id);
+        }
+    }
+    Ok(rules)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn violation(rule_id: &str, severity: Severity) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_id.to_lowercase(),
+            message: String::new(),
+            line: 1,
+            column: 1,
+            severity,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    fn scans() -> Vec<PresetScan> {
+        let results = vec![
+            (
+                "a.md".to_string(),
+                vec![
+                    violation("MD013", Severity::Warning),
+                    violation("MD013", Severity::Warning),
+                    violation("MD001", Severity::Error),
+                ],
+            ),
+            (
+                "b.md".to_string(),
+                vec![violation("MD013", Severity::Warning)],
+            ),
+        ];
+        vec![
+            PresetScan {
+                preset: None,
+                description: "Default rules",
+                disabled_rules: Vec::new(),
+                counts: tally(&results),
+            },
+            PresetScan {
+                preset: Some("relaxed"),
+                description: "Fewer rules",
+                disabled_rules: vec!["MD013".to_string()],
+                counts: tally(&results[..0]),
+            },
+            PresetScan {
+                preset: Some("strict"),
+                description: "More rules",
+                disabled_rules: vec!["MD033".to_string()],
+                counts: tally(&results),
+            },
+        ]
+    }
+
+    fn available() -> BTreeSet<String> {
+        ["MD001", "MD013", "MD033"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn run_with(input: &str) -> (Config, String) {
+        let mut output = Vec::new();
+        let mut prompter = Prompter::new(input.as_bytes(), &mut output);
+        let config = run(&mut prompter, &scans(), &available()).unwrap();
+        (config, String::from_utf8(output).unwrap())
+    }
+
+    #[test]
+    fn test_tally_counts_violations_errors_and_files() {
+        let counts = &scans()[0].counts;
+        assert_eq!(counts[0].rule_id, "MD013");
+        assert_eq!((counts[0].violations, counts[0].files), (3, 2));
+        assert_eq!((counts[1].violations, counts[1].errors), (1, 1));
+    }
+
+    #[test]
+    fn test_defaults_when_input_ends() {
+        let (config, output) = run_with("");
+        assert!(output.contains("MD013"));
+        assert!(config.extends.is_empty());
+        assert!(config.core.disabled_rules.is_empty());
+        assert!(!config.fail_on_warnings);
+    }
+
+    #[test]
+    fn test_choices_are_applied() {
+        let (config, _) = run_with("1\nmd013, MD033\ny\n");
+        assert_eq!(config.core.disabled_rules, vec!["MD013", "MD033"]);
+        assert!(config.fail_on_warnings);
+
+        let (config, _) = run_with("1\nall\nn\n");
+        assert_eq!(config.core.disabled_rules, vec!["MD013", "MD001"]);
+    }
+
+    #[test]
+    fn test_preset_by_name_skips_disable_prompt_when_clean() {
+        let (config, output) = run_with("relaxed\n\n");
+        assert_eq!(config.extends, vec!["relaxed"]);
+        assert!(config.core.disabled_rules.is_empty());
+        assert!(!output.contains("Rules to disable"));
+    }
+
+    #[test]
+    fn test_preset_disabled_rules_are_kept() {
+        let (config, _) = run_with("strict\nMD001\n\n");
+        assert_eq!(config.extends, vec!["strict"]);
+        assert_eq!(config.core.disabled_rules, vec!["MD033", "MD001"]);
+    }
+
+    #[test]
+    fn test_invalid_answers_are_asked_again() {
+        let (config, output) = run_with("9\n1\nMD999\nMD001\nmaybe\nn\n");
+        assert!(output.contains("Unknown preset '9'"));
+        assert!(output.contains("Unknown rule 'MD999'"));
+        assert!(output.contains("Please answer y or n"));
+        assert_eq!(config.core.disabled_rules, vec!["MD001"]);
+    }
+}
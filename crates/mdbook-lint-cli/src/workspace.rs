@@ -0,0 +1,228 @@
+//! `lint --workspace`: lint every book in a repository
+//!
+//! A repository can hold several books, such as `docs/user/book.toml` and
+//! `docs/dev/book.toml`. [`discover`] finds them, and each book is linted with
+//! the configuration nearest to it: the first `.mdbook-lint.toml` (or
+//! YAML/JSON) found from the book's directory upward, then the
+//! `[preprocessor.lint]` section of its `book.toml`, the way the preprocessor
+//! reads them.
+
+use crate::config::Config;
+use mdbook_lint_core::{MdBookLintError, Result};
+use std::path::{Path, PathBuf};
+
+/// Directories never searched for books
+const SKIPPED_DIRS: &[&str] = &["node_modules", "target"];
+
+/// A book found in the workspace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Book {
+    /// Directory holding `book.toml`
+    pub root: PathBuf,
+    /// Chapter directory, `book.src` in `book.toml` (default `src`)
+    pub src: PathBuf,
+}
+
+impl Book {
+    fn at(root: PathBuf) -> Result<Self> {
+        let book_toml = read_book_toml(&root.join("book.toml"))?;
+        let src = book_toml
+            .get("book")
+            .and_then(|book| book.get("src"))
+            .and_then(toml::Value::as_str)
+            .unwrap_or("src");
+        Ok(Self {
+            src: root.join(src),
+            root,
+        })
+    }
+
+    /// The book's directory as shown in reports, `.` for the current one
+    pub fn name(&self) -> String {
+        let name = self.root.to_string_lossy().replace('\\', "/");
+        match name.trim_start_matches("./").trim_end_matches('/') {
+            "" => ".".to_string(),
+            name => name.to_string(),
+        }
+    }
+}
+
+/// Find every book under `paths` (the current directory if empty), sorted by
+/// directory
+pub fn discover(paths: &[String]) -> Result<Vec<Book>> {
+    let mut books = Vec::new();
+    if paths.is_empty() {
+        find_books(Path::new("."), &mut books)?;
+    }
+    for path in paths {
+        find_books(Path::new(path), &mut books)?;
+    }
+    books.sort_by(|a, b| a.root.cmp(&b.root));
+    books.dedup();
+    Ok(books)
+}
+
+fn find_books(dir: &Path, books: &mut Vec<Book>) -> Result<()> {
+    if dir.join("book.toml").is_file() {
+        // Leading `./` would show up in every reported path
+        let root = dir.strip_prefix(".").unwrap_or(dir);
+        books.push(Book::at(root.to_path_buf())?);
+    }
+
+    let entries = std::fs::read_dir(dir).map_err(|e| {
+        MdBookLintError::document_error(format!("Failed to read directory {}: {e}", dir.display()))
+    })?;
+    for entry in entries {
+        let path = entry?.path();
+        let skipped = path.file_name().is_some_and(|name| {
+            let name = name.to_string_lossy();
+            name.starts_with('.') || SKIPPED_DIRS.contains(&name.as_ref())
+        });
+        if path.is_dir() && !skipped {
+            find_books(&path, books)?;
+        }
+    }
+    Ok(())
+}
+
+/// Load the configuration for `book`
+///
+/// An explicit `config_path` replaces the discovered file for every book; the
+/// book's own `[preprocessor.lint]` section still applies on top of it.
+/// Returns the configuration and the path of the file it came from, if any.
+pub fn load_config(
+    book: &Book,
+    config_path: Option<&str>,
+    profile: Option<&str>,
+) -> Result<(Config, Option<String>)> {
+    let file = config_path
+        .map(PathBuf::from)
+        .or_else(|| Config::discover_config(Some(&book.root)));
+    let mut config = match &file {
+        Some(path) => Config::from_file_with_profile(path, profile)?,
+        None => crate::extends::resolve(
+            Config {
+                profile: profile.map(str::to_string),
+                ..Config::default()
+            },
+            &book.root.join(".mdbook-lint.toml"),
+        )?,
+    };
+    if let Some(book_config) = book_toml_config(&book.root.join("book.toml"))? {
+        config.merge(book_config);
+    }
+    Ok((config, file.map(|path| path.display().to_string())))
+}
+
+/// The `[preprocessor.lint]` (or `[preprocessor.mdbook-lint]`) section of a
+/// `book.toml`, if the file and section exist
+pub fn book_toml_config(book_toml: &Path) -> Result<Option<Config>> {
+    if !book_toml.is_file() {
+        return Ok(None);
+    }
+    let book = read_book_toml(book_toml)?;
+    let section = book
+        .get("preprocessor")
+        .and_then(toml::Value::as_table)
+        .and_then(|p| p.get("mdbook-lint").or_else(|| p.get("lint")))
+        .and_then(toml::Value::as_table);
+    match section {
+        Some(section) => {
+            let config = crate::preprocessor::parse_mdbook_config(section)?;
+            Ok(Some(crate::extends::resolve(config, book_toml)?))
+        }
+        None => Ok(None),
+    }
+}
+
+fn read_book_toml(path: &Path) -> Result<toml::value::Table> {
+    let content = std::fs::read_to_string(path)?;
+    toml::from_str(&content).map_err(|e| {
+        MdBookLintError::config_error(format!("Failed to parse {}: {e}", path.display()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write(path: &Path, content: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+
+    #[test]
+    fn test_discover_finds_nested_books() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            &root.join("docs/user/book.toml"),
+            "[book]\ntitle = \"User\"\n",
+        );
+        write(
+            &root.join("docs/dev/book.toml"),
+            "[book]\nsrc = \"chapters\"\n",
+        );
+        write(&root.join("node_modules/pkg/book.toml"), "");
+        write(&root.join(".git/book.toml"), "");
+
+        let books = discover(&[root.display().to_string()]).unwrap();
+        assert_eq!(
+            books,
+            vec![
+                Book {
+                    root: root.join("docs/dev"),
+                    src: root.join("docs/dev/chapters"),
+                },
+                Book {
+                    root: root.join("docs/user"),
+                    src: root.join("docs/user/src"),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_book_name() {
+        let book = |root: &str| Book {
+            root: PathBuf::from(root),
+            src: PathBuf::from(root).join("src"),
+        };
+        assert_eq!(book("docs/user").name(), "docs/user");
+        assert_eq!(book("./docs/dev/").name(), "docs/dev");
+        assert_eq!(book("").name(), ".");
+    }
+
+    #[test]
+    fn test_load_config_uses_nearest_file_and_book_toml() {
+        let dir = TempDir::new().unwrap();
+        let root = dir.path();
+        write(
+            &root.join(".mdbook-lint.toml"),
+            "disabled-rules = [\"MD013\"]\n",
+        );
+        write(
+            &root.join("docs/dev/.mdbook-lint.toml"),
+            "disabled-rules = [\"MD041\"]\n",
+        );
+        write(&root.join("docs/dev/book.toml"), "[book]\n");
+        write(
+            &root.join("docs/user/book.toml"),
+            "[preprocessor.lint]\nfail-on-warnings = true\n",
+        );
+
+        let books = discover(&[root.display().to_string()]).unwrap();
+        let (dev, dev_source) = load_config(&books[0], None, None).unwrap();
+        assert_eq!(dev.core.disabled_rules, vec!["MD041"]);
+        assert!(dev_source.unwrap().ends_with("docs/dev/.mdbook-lint.toml"));
+
+        let (user, user_source) = load_config(&books[1], None, None).unwrap();
+        assert_eq!(user.core.disabled_rules, vec!["MD013"]);
+        assert!(user.fail_on_warnings);
+        assert_eq!(
+            user_source,
+            Some(root.join(".mdbook-lint.toml").display().to_string())
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! Integration tests for turning on the ADR rules (`--adr`, `--adr-only`,
+//! `adr = true`, and ADR directories)
+
+#![cfg(feature = "adr")]
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+/// A Nygard ADR without Status or Consequences sections and with trailing
+/// spaces (MD009)
+const ADR: &str = "# 1. Use Rust\n\nDate: 2024-01-01\n\n## Context\n\nWe need a language.   \n\n## Decision\n\nWe use Rust.\n";
+
+/// The ADR both at the top level and under `docs/adr/`
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("decision.md"), ADR).unwrap();
+    let adr_dir = temp_dir.path().join("docs/adr");
+    fs::create_dir_all(&adr_dir).unwrap();
+    fs::write(adr_dir.join("0001-use-rust.md"), ADR).unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_adr_rules_are_opt_in() {
+    let temp_dir = create_project();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "decision.md"])
+        .assert()
+        .stdout(contains("ADR002").not());
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--adr", "decision.md"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR002").and(contains("MD009")));
+
+    fs::write(temp_dir.path().join(".mdbook-lint.toml"), "adr = true\n").unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "decision.md"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR002"));
+}
+
+#[test]
+fn test_adr_directory_turns_on_adr_rules() {
+    let temp_dir = create_project();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "docs/adr"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR006").and(contains("MD009")));
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--adr-only", "docs/adr"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR006").and(contains("MD009").not()));
+}
+
+#[test]
+fn test_provider_format_reaches_adr_rules() {
+    let temp_dir = create_project();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "[ADR]\nformat = \"madr\"\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--adr-only", "docs/adr"])
+        .assert()
+        .code(1)
+        .stdout(contains("MADR format ADR is missing frontmatter").and(contains("ADR006").not()));
+}
+
+#[test]
+fn test_adr_only_conflicts_with_standard_only() {
+    cli_command()
+        .args(["lint", "--adr-only", "--standard-only", "README.md"])
+        .assert()
+        .code(2)
+        .stderr(contains("Cannot combine --adr-only"));
+}
+
+#[test]
+fn test_adr_collection_rules_run() {
+    let temp_dir = TempDir::new().unwrap();
+    let adr_dir = temp_dir.path().join("docs/adr");
+    fs::create_dir_all(&adr_dir).unwrap();
+    fs::write(adr_dir.join("0001-use-rust.md"), ADR).unwrap();
+    fs::write(
+        adr_dir.join("0003-use-tokio.md"),
+        ADR.replace("1. Use Rust", "3. Use Tokio"),
+    )
+    .unwrap();
+    fs::write(
+        adr_dir.join("0003-use-serde.md"),
+        ADR.replace("1. Use Rust", "3. Use Serde"),
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--adr-only", "docs/adr"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR011").and(contains("Missing ADR number 2")))
+        .stdout(contains("ADR012").and(contains("Duplicate ADR number 3")))
+        .stdout(contains("docs/adr/0003-use-tokio.md:1:1"));
+}
+
+#[test]
+fn test_adr_status_lifecycle() {
+    // The incorrect example from the ADR018 documentation
+    let temp_dir = TempDir::new().unwrap();
+    let adr_dir = temp_dir.path().join("adr");
+    fs::create_dir_all(&adr_dir).unwrap();
+    fs::write(
+        adr_dir.join("0001-use-postgresql.md"),
+        "# 1. Use PostgreSQL\n\n## Status\n\nSuperseded by [ADR-0002](0002-use-mysql.md)\n",
+    )
+    .unwrap();
+    fs::write(
+        adr_dir.join("0002-use-mysql.md"),
+        "# 2. Use MySQL\n\n## Status\n\nProposed\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--adr-only", "adr"])
+        .assert()
+        .code(1)
+        .stdout(contains("ADR018"))
+        .stdout(contains(
+            "Superseded by ADR 2, whose status 'proposed' cannot replace another ADR",
+        ))
+        .stdout(contains("adr/0001-use-postgresql.md:5:1"));
+}
@@ -0,0 +1,57 @@
+//! Integration tests for the hidden `bench` subcommand
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn create_book() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let src = temp_dir.path().join("src");
+    fs::create_dir(&src).unwrap();
+    fs::write(src.join("intro.md"), "# Intro\n\nSome text.\n").unwrap();
+    fs::write(
+        src.join("guide.md"),
+        "# Guide\n\n## Setup\n\n```rust\nfn main() {}\n```\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_bench_prints_rule_timings() {
+    let temp_dir = create_book();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["bench", "src", "--iterations", "2", "--top", "5"])
+        .assert()
+        .success()
+        .stdout(contains("Linted 2 files x 2 passes"))
+        .stdout(contains("Per pass"));
+}
+
+#[test]
+fn test_bench_fails_over_rule_limit() {
+    let temp_dir = create_book();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["bench", "src", "--iterations", "1", "--max-rule-ms", "0"])
+        .assert()
+        .failure()
+        .stderr(contains("Rules slower than 0 ms per pass"));
+}
+
+#[test]
+fn test_bench_is_hidden_from_help() {
+    cli_command()
+        .arg("--help")
+        .assert()
+        .success()
+        .stdout(contains("rustdoc"))
+        .stdout(contains("bench").not());
+}
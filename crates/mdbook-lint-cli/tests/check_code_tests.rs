@@ -0,0 +1,67 @@
+//! Integration tests for the `check-code` command
+//!
+//! These run the `rustc` found on `PATH`.
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_check_code_reports_compile_errors() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("chapter.md"),
+        "# Chapter\n\n```rust\n# let y = 2;\nlet x: u32 = \"no\";\n```\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["check-code", "chapter.md"])
+        .assert()
+        .code(1)
+        .stdout(contains("mismatched types (E0308)"))
+        .stdout(contains("chapter.md:5:14"));
+}
+
+#[test]
+fn test_check_code_passes_valid_blocks() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("chapter.md"),
+        "# Chapter\n\n\
+         ```rust\n# use std::collections::HashMap;\nlet mut map = HashMap::new();\nmap.insert(1, 2);\n```\n\n\
+         ```rust,edition2018\nfn main() {\n    println!(\"hi\");\n}\n```\n\n\
+         ```rust,ignore\nthis does not compile\n```\n\n\
+         ```toml\nnot = \"rust\"\n```\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["check-code", "--output", "json", "."])
+        .assert()
+        .success()
+        .stdout(contains("\"blocks\": 2"))
+        .stdout(contains("\"total_violations\": 0"));
+}
+
+#[test]
+fn test_check_code_missing_rustc() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("chapter.md"),
+        "```rust\nlet x = 1;\n```\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["check-code", "--rustc", "/nonexistent/rustc", "chapter.md"])
+        .assert()
+        .code(3)
+        .stderr(contains("failed to run /nonexistent/rustc"));
+}
@@ -0,0 +1,154 @@
+//! Integration tests for the collection rules, which look at every linted
+//! file together
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+/// A temporary project with `files` written under it
+fn project(files: &[(&str, &str)]) -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    for (path, content) in files {
+        let path = temp_dir.path().join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, content).unwrap();
+    }
+    temp_dir
+}
+
+#[cfg(feature = "rfc")]
+#[test]
+fn test_rfc_numbering_and_links() {
+    let temp_dir = project(&[
+        (
+            "rfcs/0001-a.md",
+            "# A\n\nBuilds on [RFC 9](0009-missing.md).\n",
+        ),
+        ("rfcs/0002-b.md", "# B\n"),
+        ("rfcs/0002-c.md", "# C\n"),
+        ("rfcs/0005-d.md", "# D\n\nSee [RFC 1](0001-a.md).\n"),
+    ]);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--enable", "RFC003,RFC004", "rfcs"])
+        .assert()
+        .success()
+        .stdout(contains("RFC003"))
+        .stdout(contains("RFC number 2 is already used by rfcs/0002-b.md"))
+        .stdout(contains("Missing RFC numbers 3-4"))
+        .stdout(contains("RFC004").and(contains("rfcs/0001-a.md:3:")))
+        .stdout(contains(
+            "Link to RFC '0009-missing.md' does not match any RFC",
+        ));
+}
+
+#[cfg(feature = "content")]
+#[test]
+fn test_glossary_consistency_reported_and_fixed() {
+    let temp_dir = project(&[
+        (
+            ".mdbook-lint.toml",
+            "[CONTENT013]\nglossary = \"src/glossary.md\"\n",
+        ),
+        (
+            "src/glossary.md",
+            "# Glossary\n\n## mdBook\n\nThe book tool.\n",
+        ),
+        ("src/intro.md", "# Intro\n\nThis Mdbook has chapters.\n"),
+    ]);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--enable", "CONTENT013", "src"])
+        .assert()
+        .success()
+        .stdout(contains("CONTENT013"))
+        .stdout(contains(
+            "'Mdbook' should be written as 'mdBook' (see glossary)",
+        ))
+        .stdout(contains("src/intro.md:3:6"));
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--enable", "CONTENT013", "--fix", "src"])
+        .assert()
+        .success()
+        .stdout(contains("Fixed 1 issue(s) in src/intro.md"))
+        .stdout(contains("CONTENT013").not());
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("src/intro.md")).unwrap(),
+        "# Intro\n\nThis mdBook has chapters.\n"
+    );
+}
+
+#[cfg(feature = "content")]
+#[test]
+fn test_duplicate_content_is_opt_in() {
+    const SETUP: &str = "Before you begin, install the toolchain with rustup and make sure \
+        cargo is on your path. Then clone the repository, change into its directory, and \
+        run the build once so every dependency is downloaded and compiled before the \
+        first chapter's examples are tried.";
+    let temp_dir = project(&[
+        ("src/intro.md", &format!("# Intro\n\n{SETUP}\n")),
+        (
+            "src/guide.md",
+            &format!(
+                "# Guide\n\n{}\n",
+                SETUP.replace("the repository", "the project repository")
+            ),
+        ),
+    ]);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .assert()
+        .stdout(contains("CONTENT014").not());
+
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "[CONTENT014]\nthreshold = 0.8\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .assert()
+        .stdout(contains("CONTENT014"))
+        .stdout(contains("Section 'Intro' is 83% similar to src/guide.md:1"))
+        .stdout(contains("src/intro.md:1:1"));
+}
+
+#[test]
+fn test_translation_rules_on_two_language_book() {
+    let temp_dir = project(&[
+        (
+            "src/SUMMARY.md",
+            "# Summary\n\n- [Intro](intro.md)\n- [Other](other.md)\n",
+        ),
+        ("src/intro.md", "# Intro\n\n## Setup\n"),
+        ("src/other.md", "# Other\n"),
+        (
+            "src/de/SUMMARY.md",
+            "# Zusammenfassung\n\n- [Einführung](intro.md)\n",
+        ),
+        (
+            "src/de/intro.md",
+            "# Einführung\n\n### Einrichtung\n\nSiehe [Intro](../intro.md).\n",
+        ),
+    ]);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .assert()
+        .stdout(contains("MDBOOK034").and(contains("No 'de' translation")))
+        .stdout(contains("src/other.md:1:1"))
+        .stdout(contains("MDBOOK035").and(contains("src/de/intro.md:3:1")))
+        .stdout(contains("MDBOOK036").and(contains("src/de/intro.md:5:")));
+}
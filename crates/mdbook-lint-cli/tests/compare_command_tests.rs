@@ -0,0 +1,83 @@
+//! Integration tests for the `compare` command
+//!
+//! A shell script stands in for markdownlint-cli, so these only run on Unix.
+
+#![cfg(unix)]
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+/// Write a fake markdownlint that prints `report` to stderr and exits 1
+fn fake_markdownlint(dir: &Path, report: &str) -> PathBuf {
+    let script = dir.join("markdownlint");
+    fs::write(&script, format!("#!/bin/sh\necho '{report}' >&2\nexit 1\n")).unwrap();
+    fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+    script
+}
+
+#[test]
+fn test_compare_reports_per_rule_agreement() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n\nline \n").unwrap();
+    let markdownlint = fake_markdownlint(
+        dir.path(),
+        r#"[{"fileName": "doc.md", "lineNumber": 3, "ruleNames": ["MD009", "no-trailing-spaces"]},
+            {"fileName": "doc.md", "lineNumber": 1, "ruleNames": ["MD041", "first-line-heading"]}]"#,
+    );
+
+    cli_command()
+        .current_dir(dir.path())
+        .args(["compare", "doc.md", "--differences", "--markdownlint"])
+        .arg(&markdownlint)
+        .assert()
+        .success()
+        .stdout(contains("Compared 1 files: 1 of 2 rules match"))
+        .stdout(contains("MD009"))
+        .stdout(contains("doc.md:1 MD041 only in markdownlint"))
+        .stdout(contains("MD009 only in").not());
+}
+
+#[test]
+fn test_compare_json_output() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n\nline \n").unwrap();
+    let markdownlint = fake_markdownlint(dir.path(), "[]");
+
+    let output = cli_command()
+        .current_dir(dir.path())
+        .args(["compare", "doc.md", "--format", "json", "--markdownlint"])
+        .arg(&markdownlint)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["files"], 1);
+    assert_eq!(report["rules"][0]["rule_id"], "MD009");
+    assert_eq!(report["differences"][0]["only_in"], "mdbook-lint");
+}
+
+#[test]
+fn test_compare_without_markdownlint() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    cli_command()
+        .current_dir(dir.path())
+        .args([
+            "compare",
+            "doc.md",
+            "--markdownlint",
+            "no-such-markdownlint",
+        ])
+        .assert()
+        .failure()
+        .stderr(contains("npm install -g markdownlint-cli"));
+}
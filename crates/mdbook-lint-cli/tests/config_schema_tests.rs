@@ -0,0 +1,43 @@
+//! Integration tests for the `config schema` command
+
+mod common;
+
+use common::cli_command;
+use tempfile::TempDir;
+
+#[test]
+fn test_config_schema_stdout() {
+    let output = cli_command()
+        .args(["config", "schema"])
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let schema: serde_json::Value = serde_json::from_slice(&output).unwrap();
+    assert_eq!(schema["title"], "mdbook-lint configuration");
+    assert_eq!(
+        schema["properties"]["MD013"]["properties"]["line-length"]["type"],
+        "integer"
+    );
+    assert_eq!(
+        schema["properties"]["MDBOOK026"]["properties"]["draft_severity"]["enum"],
+        serde_json::json!(["info", "warning", "error", "off", "none", "allow"])
+    );
+}
+
+#[test]
+fn test_config_schema_output_file() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("schema.json");
+    cli_command()
+        .args(["config", "schema", "--output"])
+        .arg(&path)
+        .assert()
+        .success();
+
+    let schema: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert_eq!(schema["additionalProperties"], false);
+}
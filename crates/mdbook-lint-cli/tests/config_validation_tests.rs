@@ -0,0 +1,67 @@
+//! Integration tests for rule option validation in `check` and at startup
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_check_reports_bad_rule_options() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join(".mdbook-lint.toml");
+    fs::write(
+        &config,
+        "[MD013]\nline-length = 10\nignore_tables = \"yes\"\n\n[MD022]\nlines_above = 1\n\n[vocabulary]\nterm = []\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .arg("check")
+        .arg(&config)
+        .assert()
+        .failure()
+        .stderr(contains("MD013.line-length must be an integer ≥ 20"))
+        .stderr(contains("MD013.ignore_tables must be a boolean"))
+        .stderr(contains(
+            "MD022.lines_above is not an option; MD022 takes no options",
+        ))
+        .stderr(contains("vocabulary.term is not an option"));
+}
+
+#[test]
+fn test_check_accepts_either_spelling() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join(".mdbook-lint.toml");
+    fs::write(
+        &config,
+        "[MD013]\nline_length = 100\nignore-code-blocks = true\nauto-fix = false\n\n[MD003]\nstyle = \"atx\"\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .arg("check")
+        .arg(&config)
+        .assert()
+        .success()
+        .stdout(contains("is valid"));
+}
+
+#[test]
+fn test_lint_warns_about_bad_rule_options() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".mdbook-lint.toml"),
+        "[MD013]\nline-length = \"long\"\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "doc.md"])
+        .assert()
+        .success()
+        .stderr(contains("MD013.line-length must be an integer ≥ 20"));
+}
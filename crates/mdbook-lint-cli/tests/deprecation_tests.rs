@@ -0,0 +1,72 @@
+//! Integration tests for notices about deprecated and reserved rules
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn create_project(config: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join(".mdbook-lint.toml"), config).unwrap();
+    fs::write(dir.path().join("a.md"), "# A\n\nText.\n").unwrap();
+    fs::write(dir.path().join("b.md"), "# B\n\nText.\n").unwrap();
+    dir
+}
+
+#[test]
+fn test_notices_once_per_run() {
+    let dir = create_project("enabled-rules = [\"MD001\", \"MD002\", \"MD008\"]\n");
+
+    let output = cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "a.md", "b.md"])
+        .assert()
+        .success()
+        .get_output()
+        .stderr
+        .clone();
+    let stderr = String::from_utf8(output).unwrap();
+    assert_eq!(
+        stderr.matches("Warning: Rule MD002 is deprecated").count(),
+        1,
+        "{stderr}"
+    );
+    assert!(stderr.contains("Consider using MD041 instead."));
+    assert!(stderr.contains("Warning: Rule MD008 is reserved"));
+}
+
+#[test]
+fn test_notice_levels() {
+    let dir = create_project("deprecated-warning = \"info\"\n\n[MD006]\n");
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "a.md"])
+        .assert()
+        .success()
+        .stderr(contains("Info: Rule MD006 is deprecated"));
+
+    let dir = create_project("deprecated-warning = \"silent\"\n\n[MD006]\n");
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "a.md"])
+        .assert()
+        .success()
+        .stderr(contains("MD006").not());
+}
+
+#[test]
+fn test_check_reports_placeholder_rules() {
+    let dir = create_project("disabled-rules = [\"MD015\"]\n\n[severity]\nMD015 = \"error\"\n");
+    cli_command()
+        .current_dir(dir.path())
+        .args(["check", ".mdbook-lint.toml"])
+        .assert()
+        .success()
+        .stderr(contains(
+            "Warning: Rule MD015 is deprecated - Functionality merged into MD013 (line-length). Consider using MD013 instead.",
+        ))
+        .stderr(contains("Unknown rule").not());
+}
@@ -0,0 +1,58 @@
+//! Integration tests for rule documentation URLs in JSON and default output
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+const MD001_URL: &str = "https://joshrotenberg.github.io/mdbook-lint/rules/standard/md001.html";
+
+#[test]
+fn test_json_violations_include_url() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n\n### Skipped\n").unwrap();
+
+    let assert = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "--output", "json", "doc.md"])
+        .assert();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let violations = parsed["files"][0]["violations"].as_array().unwrap();
+    let md001 = violations
+        .iter()
+        .find(|v| v["rule_id"] == "MD001")
+        .expect("MD001 should fire");
+    assert_eq!(md001["url"], MD001_URL);
+}
+
+#[test]
+fn test_default_output_links_to_docs() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n\n### Skipped\n").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .stdout(contains(format!("docs: {MD001_URL}")));
+}
+
+#[test]
+fn test_rules_json_includes_url() {
+    let assert = cli_command().args(["rules", "--json"]).assert().success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let rule = parsed["providers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|p| p["rules"].as_array().unwrap())
+        .find(|r| r["id"] == "MD001")
+        .unwrap();
+    assert_eq!(rule["url"], MD001_URL);
+}
@@ -0,0 +1,95 @@
+//! Integration tests for byte order marks, UTF-16 files, and the
+//! `malformed-markdown` policy for undecodable files
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn utf16le(text: &str) -> Vec<u8> {
+    [0xFF, 0xFE]
+        .into_iter()
+        .chain(text.encode_utf16().flat_map(u16::to_le_bytes))
+        .collect()
+}
+
+#[test]
+fn test_bom_does_not_affect_first_line() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("doc.md"),
+        b"\xEF\xBB\xBF# Title\n\nSome text.\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .success()
+        .stdout(contains("MD041").not())
+        .stdout(contains("MD018").not());
+}
+
+#[test]
+fn test_utf16_is_linted_and_fixed_in_place() {
+    let temp_dir = TempDir::new().unwrap();
+    let path = temp_dir.path().join("doc.md");
+    fs::write(&path, utf16le("# Title\n\nTrailing   \n")).unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .stdout(contains("MD009"));
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "--fix", "doc.md"])
+        .assert()
+        .success();
+    assert_eq!(fs::read(&path).unwrap(), utf16le("# Title\n\nTrailing\n"));
+}
+
+#[test]
+fn test_malformed_markdown_policy() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("latin1.md"), b"# Caf\xE9\n").unwrap();
+
+    // warn (default): one violation in place of linting the file
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "latin1.md"])
+        .assert()
+        .success()
+        .stdout(contains("warning[ENCODING]"))
+        .stdout(contains("not valid UTF-8"))
+        .stdout(contains("MD041").not());
+
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "malformed-markdown = \"error\"\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "latin1.md"])
+        .assert()
+        .failure()
+        .stderr(contains("latin1.md: unsupported encoding"));
+
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "malformed-markdown = \"skip\"\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "latin1.md"])
+        .assert()
+        .success()
+        .stdout(contains("ENCODING").not());
+}
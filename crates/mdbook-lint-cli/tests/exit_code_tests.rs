@@ -0,0 +1,114 @@
+//! Integration tests for the exit code taxonomy: 0 ok, 1 violations,
+//! 2 config or usage error, 3 IO error
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_clean_file_exits_zero() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n\nSome text.\n").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_violations_exit_one() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n\n### Skipped\n").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "--fail-on-warnings", "doc.md"])
+        .assert()
+        .code(1);
+}
+
+#[test]
+fn test_report_level_and_fail_level_are_separate() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n\n### Skipped\n").unwrap();
+
+    // Printed but not failing
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "report-level = \"info\"\nfail-level = \"error\"\nfail-on-warnings = true\n\n[severity]\nMD001 = \"info\"\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .code(0)
+        .stdout(contains("MD001"));
+
+    // Failing but not printed
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "report-level = \"error\"\nfail-level = \"info\"\n\n[severity]\nMD001 = \"info\"\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .code(1)
+        .stdout(contains("MD001").not());
+}
+
+#[test]
+fn test_config_errors_exit_two() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("doc.md"), "# Title\n").unwrap();
+    fs::write(temp_dir.path().join("bad.toml"), "disabled-rules = [\n").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--config", "bad.toml", "doc.md"])
+        .assert()
+        .code(2);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "--mdbook-only", "doc.md"])
+        .assert()
+        .code(2);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--no-such-flag", "doc.md"])
+        .assert()
+        .code(2);
+}
+
+#[test]
+fn test_io_errors_exit_three() {
+    let temp_dir = TempDir::new().unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "missing.md"])
+        .assert()
+        .code(3);
+
+    fs::write(temp_dir.path().join("doc.md"), b"\xC3\x28 invalid\n").unwrap();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "malformed-markdown = \"error\"\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "doc.md"])
+        .assert()
+        .code(3);
+}
@@ -6,6 +6,7 @@
 mod common;
 
 use common::*;
+use predicates::prelude::*;
 use predicates::str::contains;
 use std::fs;
 use tempfile::TempDir;
@@ -62,9 +63,11 @@ fn test_fix_with_remaining_violations() {
     let temp_dir = TempDir::new().unwrap();
     let test_file = temp_dir.path().join("test.md");
 
+    // MD013 reflow is only applied with --fix-unsafe, so the long line remains
+    let long_line = "word ".repeat(40);
     fs::write(
         &test_file,
-        "# Test Document  \n\n\n\nThis has trailing spaces.   \n\n\n",
+        format!("# Test Document  \n\n\n\nThis has trailing spaces.   \n\n{long_line}\n\n\n"),
     )
     .unwrap();
 
@@ -82,15 +85,19 @@ fn test_fix_with_remaining_violations() {
         .stdout(contains("Found"))
         .stdout(contains("warning(s)"));
 
-    // Verify trailing spaces were fixed but multiple blank lines remain
+    // Verify fixable issues were fixed but the long line remains
     let fixed_content = fs::read_to_string(&test_file).unwrap();
     assert!(
         !fixed_content.contains("spaces.   "),
         "Trailing spaces should be removed"
     );
     assert!(
-        fixed_content.contains("\n\n\n"),
-        "Multiple blank lines should remain (not fixable)"
+        !fixed_content.contains("\n\n\n"),
+        "Multiple blank lines should be collapsed"
+    );
+    assert!(
+        fixed_content.contains(long_line.trim_end()),
+        "Long line should remain (not fixable without --fix-unsafe)"
     );
 }
 
@@ -102,7 +109,10 @@ fn test_fix_with_fail_on_warnings() {
 
     fs::write(
         &test_file,
-        "# Test Document  \n\n\n\nThis has trailing spaces.   \n",
+        format!(
+            "# Test Document  \n\n\n\nThis has trailing spaces.   \n\n{}\n",
+            "word ".repeat(40)
+        ),
     )
     .unwrap();
 
@@ -187,6 +197,47 @@ fn test_fix_unsafe_flag() {
     );
 }
 
+#[test]
+fn test_md013_reflow_requires_fix_unsafe() {
+    // MD013 reflow rewrites whole paragraphs, so plain --fix must leave it alone
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("test.md");
+    let long_paragraph = format!("# Test\n\n{}\n", "word ".repeat(30).trim_end());
+    fs::write(&test_file, &long_paragraph).unwrap();
+
+    // Run from the temp dir so the repository's own config isn't discovered
+    cli_command()
+        .current_dir(temp_dir.path())
+        .arg("lint")
+        .arg("--fix")
+        .arg("--no-backup")
+        .arg(&test_file)
+        .assert();
+    assert_eq!(fs::read_to_string(&test_file).unwrap(), long_paragraph);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .arg("lint")
+        .arg("--fix-unsafe")
+        .arg("--no-backup")
+        .arg(&test_file)
+        .assert()
+        .success();
+
+    let fixed_content = fs::read_to_string(&test_file).unwrap();
+    assert!(
+        fixed_content.lines().all(|line| line.len() <= 80),
+        "Paragraph should be reflowed to 80 columns: {fixed_content:?}"
+    );
+    assert_eq!(
+        fixed_content
+            .split_whitespace()
+            .filter(|w| *w == "word")
+            .count(),
+        30
+    );
+}
+
 #[test]
 fn test_dry_run_with_fix_unsafe() {
     // Test --dry-run with --fix-unsafe
@@ -396,9 +447,9 @@ fn test_fix_error_validation() {
         .arg(&test_file)
         .assert();
 
-    // Should fail with validation error
+    // Should fail with a usage (configuration) error
     assert
-        .code(1)
+        .code(2)
         .stderr(contains("--dry-run requires either --fix or --fix-unsafe"));
 }
 
@@ -484,7 +535,14 @@ fn test_fix_exit_codes() {
 
     // Test 2: Some violations remain after fix - should exit 0 (warnings don't fail by default)
     let test_file2 = temp_dir.path().join("mixed.md");
-    fs::write(&test_file2, "# Test\n\n\n\nTrailing spaces.   \n").unwrap();
+    fs::write(
+        &test_file2,
+        format!(
+            "# Test\n\n\n\nTrailing spaces.   \n\n{}\n",
+            "word ".repeat(40)
+        ),
+    )
+    .unwrap();
 
     let assert = cli_command()
         .arg("lint")
@@ -497,7 +555,14 @@ fn test_fix_exit_codes() {
 
     // Test 3: Remaining violations with --fail-on-warnings - should exit 1
     let test_file3 = temp_dir.path().join("mixed2.md");
-    fs::write(&test_file3, "# Test\n\n\n\nTrailing spaces.   \n").unwrap();
+    fs::write(
+        &test_file3,
+        format!(
+            "# Test\n\n\n\nTrailing spaces.   \n\n{}\n",
+            "word ".repeat(40)
+        ),
+    )
+    .unwrap();
 
     let assert = cli_command()
         .arg("lint")
@@ -731,3 +796,139 @@ fn test_fix_subcommand_equivalent_to_lint_fix() {
         "`fix` and `lint --fix` should produce identical results"
     );
 }
+
+#[test]
+fn test_fix_rules_limits_fixes() {
+    // Only MD047 is fixed; the trailing spaces are left and still reported
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("test.md");
+    fs::write(
+        &test_file,
+        "# Test Document\n\nTrailing spaces.   \nNo final newline",
+    )
+    .unwrap();
+
+    cli_command()
+        .arg("lint")
+        .arg("--fix-rules")
+        .arg("md047")
+        .arg("--no-backup")
+        .arg(&test_file)
+        .assert()
+        .stdout(contains("MD009"));
+
+    let fixed_content = fs::read_to_string(&test_file).unwrap();
+    assert_eq!(
+        fixed_content,
+        "# Test Document\n\nTrailing spaces.   \nNo final newline\n"
+    );
+}
+
+#[test]
+fn test_fix_rules_unknown_rule() {
+    let temp_dir = TempDir::new().unwrap();
+    let test_file = temp_dir.path().join("test.md");
+    fs::write(&test_file, "# Test\n").unwrap();
+
+    cli_command()
+        .arg("fix")
+        .arg("--rules")
+        .arg("MD009,MD999")
+        .arg(&test_file)
+        .assert()
+        .code(2)
+        .stderr(contains("Unknown rule in the rules to fix: 'MD999'"));
+}
+
+#[test]
+fn test_fix_undo_restores_last_session() {
+    let temp_dir = TempDir::new().unwrap();
+    let first = temp_dir.path().join("first.md");
+    let second = temp_dir.path().join("second.md");
+    let original = "# Test Document  \n\nTrailing spaces.   \n";
+    fs::write(&first, original).unwrap();
+    fs::write(&second, original).unwrap();
+
+    cli_command()
+        .arg("fix")
+        .arg("--no-backup")
+        .arg(&first)
+        .arg(&second)
+        .assert()
+        .success();
+    assert_ne!(fs::read_to_string(&first).unwrap(), original);
+    assert!(temp_dir.path().join(".mdbook-lint/undo").is_dir());
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .arg("fix")
+        .arg("--undo")
+        .assert()
+        .success()
+        .stdout(contains("Undid fixes in 2 file(s)"));
+    assert_eq!(fs::read_to_string(&first).unwrap(), original);
+    assert_eq!(fs::read_to_string(&second).unwrap(), original);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .arg("fix")
+        .arg("--undo")
+        .assert()
+        .success()
+        .stderr(contains("No fix session to undo"));
+}
+
+#[test]
+fn test_verify_fixes_writes_settled_fixes() {
+    let temp_dir = TempDir::new().unwrap();
+    let file = temp_dir.path().join("test.md");
+    fs::write(&file, "# Title\n##  Section\nText   \n* item\n\n\n").unwrap();
+
+    cli_command()
+        .arg("lint")
+        .arg("--fix")
+        .arg("--verify-fixes")
+        .arg("--no-backup")
+        .arg(&file)
+        .assert()
+        .stdout(contains("Fixed"))
+        .stderr(contains("Internal error").not());
+    assert_eq!(
+        fs::read_to_string(&file).unwrap(),
+        "# Title\n\n## Section\n\nText\n\n* item\n"
+    );
+
+    cli_command()
+        .arg("lint")
+        .arg("--verify-fixes")
+        .arg(&file)
+        .assert()
+        .code(2)
+        .stderr(contains(
+            "--verify-fixes requires either --fix or --fix-unsafe",
+        ));
+}
+
+#[cfg(all(feature = "spelling", feature = "content"))]
+#[test]
+fn test_fix_leaves_suggested_wording_alone() {
+    let temp_dir = TempDir::new().unwrap();
+    let content = "# Intro\n\nWe recieve teh data and utilize it.\n";
+    fs::write(temp_dir.path().join("test.md"), content).unwrap();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "[vocabulary.replace]\nutilize = \"use\"\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--fix", "--no-backup", "test.md"])
+        .assert()
+        .stdout(contains("SPELL001"))
+        .stdout(contains("CONTENT012"));
+    assert_eq!(
+        fs::read_to_string(temp_dir.path().join("test.md")).unwrap(),
+        content
+    );
+}
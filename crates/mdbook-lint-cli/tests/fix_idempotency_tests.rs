@@ -0,0 +1,120 @@
+//! Property tests for fix idempotency
+//!
+//! Documents are assembled from fragments that trip the fixable rules. After
+//! fixing, [`FixEngine::verify`] must find nothing: no rule whose fix was
+//! applied still reports a fixable violation, and no other rule reports more
+//! of them than before.
+
+use mdbook_lint::{Config, Document, LintEngine, PluginRegistry};
+use mdbook_lint_core::{FixEngine, Violation};
+use mdbook_lint_rulesets::{MdBookRuleProvider, StandardRuleProvider};
+use proptest::prelude::*;
+use std::sync::OnceLock;
+
+fn engine() -> &'static LintEngine {
+    static ENGINE: OnceLock<LintEngine> = OnceLock::new();
+    ENGINE.get_or_init(|| {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(StandardRuleProvider))
+            .unwrap();
+        registry
+            .register_provider(Box::new(MdBookRuleProvider))
+            .unwrap();
+        registry.create_engine().unwrap()
+    })
+}
+
+fn lint(content: &str) -> mdbook_lint::error::Result<Vec<Violation>> {
+    let document = Document::new(content.to_string(), "chapter.md".into())?;
+    engine().lint_document_with_config(&document, &Config::default().core)
+}
+
+/// Lines that trip fixable rules, mixed with ordinary ones
+///
+/// Closed ATX headings are left out: MD018 wants a space after the opening
+/// hashes and MD020 wants none inside the closing ones, so their fixes undo
+/// each other.
+fn fragment() -> impl Strategy<Value = String> {
+    prop_oneof![
+        Just("# Heading".to_string()),
+        Just("## Héading ünicode".to_string()),
+        Just("Setext heading\n--------------".to_string()),
+        Just("#No space".to_string()),
+        Just("##  Two spaces".to_string()),
+        Just("  ### Indented heading".to_string()),
+        Just("Plain paragraph text.".to_string()),
+        Just("Trailing spaces   ".to_string()),
+        Just("Hard\ttab".to_string()),
+        Just(String::new()),
+        Just(String::new()),
+        Just("* star item".to_string()),
+        Just("- dash item".to_string()),
+        Just("+ plus item".to_string()),
+        Just("   * deep item".to_string()),
+        Just("-  wide marker".to_string()),
+        Just("1. first".to_string()),
+        Just("1.  wide ordered".to_string()),
+        Just(">  quoted".to_string()),
+        Just("> ## Quoted heading".to_string()),
+        Just("> - quoted item".to_string()),
+        Just("> ```\n> quoted code\n> ```".to_string()),
+        Just("** bold with spaces **".to_string()),
+        Just("` code span `".to_string()),
+        Just("Visit https://example.com today".to_string()),
+        Just("```\nfenced code\n```".to_string()),
+        Just("~~~rust\nlet x = 1;\n~~~".to_string()),
+        Just("***".to_string()),
+        Just("| a | b |\n|---|---|\n| 1 | 2 |".to_string()),
+    ]
+}
+
+fn document() -> impl Strategy<Value = String> {
+    (prop::collection::vec(fragment(), 1..12), any::<bool>()).prop_map(|(lines, newline)| {
+        let mut content = lines.join("\n");
+        if newline {
+            content.push('\n');
+        }
+        content
+    })
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    /// Property: fixing settles in one run
+    #[test]
+    fn fixes_settle(content in document()) {
+        let fix_engine = FixEngine::new();
+        let report = fix_engine.fix_until_stable(&content, lint).unwrap();
+        let problems = fix_engine.verify(&content, &report, lint).unwrap();
+        prop_assert!(
+            problems.is_empty(),
+            "fixing {:?} gave {:?}: {}",
+            content,
+            report.content,
+            problems.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+        );
+    }
+}
+
+/// Blank lines wanted by MD022, MD031, and MD032 at the same spot are
+/// inserted once, in one pass, and keep blockquote markers
+#[test]
+fn blank_line_fixes_settle_in_one_pass() {
+    let content = "# Héading\n```sh\nmake\n```\n- item\n\nText\n> Quoted\n> - item\n> ## Quoted heading\n>\n> More\n";
+    let violations = lint(content).unwrap();
+    let report = FixEngine::new().apply(content, &violations);
+
+    assert_eq!(
+        report.content,
+        "# Héading\n\n```sh\nmake\n```\n\n- item\n\nText\n> Quoted\n>\n> - item\n>\n> ## Quoted heading\n>\n> More\n"
+    );
+    let remaining = lint(&report.content).unwrap();
+    assert!(
+        !remaining
+            .iter()
+            .any(|v| matches!(v.rule_id.as_str(), "MD022" | "MD031" | "MD032")),
+        "{remaining:?}"
+    );
+}
@@ -0,0 +1,88 @@
+//! Integration tests for `init --interactive`
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+/// A book whose only chapter has an overlong line (MD013) and a bare URL (MD034)
+fn create_book() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    fs::write(
+        temp_dir.path().join("book.toml"),
+        "[book]\ntitle = \"Test\"\nsrc = \"content\"\n",
+    )
+    .unwrap();
+    let src = temp_dir.path().join("content");
+    fs::create_dir(&src).unwrap();
+    fs::write(
+        src.join("intro.md"),
+        format!(
+            "# Intro\n\n{}\n\nSee https://example.com\n",
+            "word ".repeat(40)
+        ),
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_wizard_reports_rules_and_writes_choices() {
+    let temp_dir = create_book();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["init", "--interactive"])
+        .write_stdin("relaxed\nall\ny\n")
+        .assert()
+        .success()
+        .stdout(contains("Scanned 1 markdown files in content"))
+        .stdout(contains("MD013"))
+        .stdout(contains("markdownlint-parity"))
+        .stdout(contains("Configuration file created: .mdbook-lint.toml"));
+
+    let config = fs::read_to_string(temp_dir.path().join(".mdbook-lint.toml")).unwrap();
+    assert!(config.starts_with("# Generated by `mdbook-lint init --interactive`"));
+    assert!(config.contains("extends = ['relaxed']"));
+    assert!(config.contains("'MD034'"));
+    // The preset's own disabled rules are carried over
+    assert!(config.contains("'MD013'"));
+    assert!(config.contains("fail-on-warnings = true"));
+
+    // The generated config lints cleanly
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "content"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_wizard_keeps_existing_file_unless_confirmed() {
+    let temp_dir = create_book();
+    let config_path = temp_dir.path().join("lint.yaml");
+    fs::write(&config_path, "fail-on-warnings: false\n").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["init", "-i", "--format", "yaml", "--output", "lint.yaml"])
+        .write_stdin("")
+        .assert()
+        .success()
+        .stdout(contains("Left lint.yaml unchanged"));
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "fail-on-warnings: false\n"
+    );
+}
+
+#[test]
+fn test_interactive_conflicts_with_include_all() {
+    cli_command()
+        .args(["init", "--interactive", "--include-all"])
+        .assert()
+        .failure()
+        .stderr(contains("cannot be used with"));
+}
@@ -0,0 +1,87 @@
+//! Integration tests for `lint --timing`, `--timing-json`, and
+//! `--timeout-per-file`
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn create_book() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    let src = temp_dir.path().join("src");
+    fs::create_dir(&src).unwrap();
+    fs::write(src.join("intro.md"), "# Intro\n\nSome text.\n").unwrap();
+    fs::write(
+        src.join("guide.md"),
+        "# Guide\n\n## Setup\n\n```rust\nfn main() {}\n```\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_lint_timing_on_stderr() {
+    let temp_dir = create_book();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src", "--timing", "--timing-top", "2"])
+        .assert()
+        .stderr(contains("Timing: 2 files"))
+        .stderr(contains("Slowest rules"))
+        .stderr(contains("Slowest files"));
+}
+
+#[test]
+fn test_lint_timing_json() {
+    let temp_dir = create_book();
+    let report = temp_dir.path().join("timing.json");
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src", "--timing-json"])
+        .arg(&report)
+        .assert()
+        .stderr(contains("Slowest rules").not());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&report).unwrap()).unwrap();
+    assert_eq!(json["files_linted"], 2);
+    assert_eq!(json["files"].as_array().unwrap().len(), 2);
+    let rules = json["rules"].as_array().unwrap();
+    assert!(rules.iter().any(|r| r["rule"] == "MD001"));
+    assert!(rules.iter().all(|r| r["files"] == 2));
+    if cfg!(any(target_os = "linux", target_os = "macos")) {
+        assert!(json["memory"]["peak_bytes"].as_u64().unwrap() > 0);
+        assert!(json["memory"]["samples"].as_u64().unwrap() >= 3);
+    }
+}
+
+#[test]
+fn test_timeout_per_file_within_budget() {
+    let temp_dir = create_book();
+
+    let plain = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .output()
+        .unwrap();
+    let bounded = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src", "--timeout-per-file", "60"])
+        .output()
+        .unwrap();
+
+    assert_eq!(bounded.status.code(), plain.status.code());
+    assert_eq!(bounded.stdout, plain.stdout);
+    assert!(!String::from_utf8_lossy(&bounded.stderr).contains("Timed out"));
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src", "--timing", "--timeout-per-file", "60"])
+        .assert()
+        .stderr(contains("Timing: 2 files"));
+}
@@ -0,0 +1,81 @@
+//! Integration tests for `--quiet`, `-v`/`-vv`, and `RUST_LOG` logging
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+/// A document with one error (MD018, raised by config) and one warning (MD009)
+fn create_project() -> TempDir {
+    let temp_dir = TempDir::new().expect("Failed to create temp directory");
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "[severity]\nMD018 = \"error\"\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("doc.md"),
+        "# Title\n\nTrailing spaces   \n\n#Missing space\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_quiet_shows_only_errors() {
+    let temp_dir = create_project();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--quiet", "doc.md"])
+        .assert()
+        .failure()
+        .stdout(contains("MD018"))
+        .stdout(contains("MD009").not())
+        .stdout(contains("Found").not());
+}
+
+#[test]
+fn test_quiet_conflicts_with_verbose() {
+    cli_command()
+        .args(["-q", "-v", "lint", "doc.md"])
+        .assert()
+        .failure()
+        .stderr(contains("cannot be used with"));
+}
+
+#[test]
+fn test_verbose_levels_log_to_stderr() {
+    let temp_dir = create_project();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .args(["-v", "lint", "doc.md"])
+        .assert()
+        .stderr(contains("loaded discovered config"))
+        .stderr(contains("linted file").not());
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .env_remove("RUST_LOG")
+        .args(["-vv", "lint", "doc.md"])
+        .assert()
+        .stderr(contains("linted file"))
+        .stdout(contains("linted file").not());
+}
+
+#[test]
+fn test_rust_log_overrides_flags() {
+    let temp_dir = create_project();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .env("RUST_LOG", "mdbook_lint=debug")
+        .args(["lint", "doc.md"])
+        .assert()
+        .stderr(contains("discovered config file"));
+}
@@ -198,6 +198,40 @@ This line is intentionally way too long and should normally trigger MD013 but it
     assert_eq!(count_violations(&stderr_output, "MD013"), 0);
 }
 
+#[test]
+fn test_preprocessor_extends_shared_config() {
+    // book.toml settings build on a shared file, which builds on a preset
+    let temp_book = TempMdBook::new();
+    fs::write(
+        temp_book.book_dir.join("lint-base.toml"),
+        "extends = \"relaxed\"\ndisabled-rules = [\"MD001\", \"MD013\"]\n",
+    )
+    .unwrap();
+
+    let config = json!({
+        "extends": "./lint-base.toml",
+        "fail-on-warnings": false
+    });
+
+    temp_book
+        .with_book_toml(Some(config.clone()))
+        .with_summary("# Summary\n\n[Introduction](./intro.md)\n")
+        .with_chapter(
+            "intro.md",
+            "# Introduction\n\n### Skipped level\n\n```\nno language\n```\n",
+        );
+
+    let input = temp_book.create_preprocessor_input_with_config(config);
+    let assert = cli_command().write_stdin(input).assert();
+    let stderr_output = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+
+    assert_eq!(count_violations(&stderr_output, "MD001"), 0);
+    verify_violations(
+        &stderr_output,
+        &[ViolationExpectation::at_least("MDBOOK001", 1)],
+    );
+}
+
 #[test]
 fn test_preprocessor_fail_on_warnings_false() {
     // Test that preprocessor doesn't fail build when fail-on-warnings is false
@@ -235,6 +269,105 @@ Code without language (warning level violation)
     assert.success();
 }
 
+#[test]
+fn test_preprocessor_render_warnings_in_draft() {
+    // Test that violations are injected into chapters in draft builds
+    let temp_book = TempMdBook::new();
+
+    let config = json!({
+        "render-warnings": true,
+        "fail-on-warnings": false,
+        "fail-on-errors": false
+    });
+
+    temp_book
+        .with_book_toml(Some(config.clone()))
+        .with_summary(
+            r#"
+# Summary
+
+[Draft Chapter](./draft.md)
+"#,
+        )
+        .with_chapter(
+            "draft.md",
+            r#"# Draft Chapter
+
+```
+Code without language
+```
+"#,
+        );
+
+    // Not a draft build: content is left untouched
+    let input = temp_book.create_preprocessor_input_with_config(config.clone());
+    let assert = cli_command()
+        .env_remove("MDBOOK_LINT_DRAFT")
+        .write_stdin(input)
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(!stdout.contains("mdbook-lint-warnings"));
+
+    // Draft build via the environment variable
+    let input = temp_book.create_preprocessor_input_with_config(config);
+    let assert = cli_command()
+        .env("MDBOOK_LINT_DRAFT", "1")
+        .write_stdin(input)
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    assert!(stdout.contains("mdbook-lint-warnings"));
+    assert!(stdout.contains("<code>MDBOOK001</code>"));
+}
+
+#[test]
+fn test_preprocessor_per_chapter_overrides() {
+    // Test that chapter glob overrides apply only to matching chapters.
+    // The config is passed in the preprocessor input, as mdBook does.
+    let temp_book = TempMdBook::new();
+
+    let config = json!({
+        "fail-on-warnings": false,
+        "fail-on-errors": false,
+        "chapters": {
+            "src/appendix/*.md": {
+                "disabled-rules": ["MDBOOK001"]
+            }
+        }
+    });
+
+    let chapter = r#"# Chapter
+
+```
+Code without language
+```
+"#;
+
+    temp_book
+        .with_summary(
+            r#"
+# Summary
+
+[Guide](./guide.md)
+[Appendix](./appendix/a.md)
+"#,
+        )
+        .with_chapter("guide.md", chapter)
+        .with_chapter("appendix/a.md", chapter);
+
+    let input = temp_book.create_preprocessor_input_with_config(config);
+    let assert = cli_command().write_stdin(input).assert().success();
+    let stderr_output = String::from_utf8(assert.get_output().stderr.clone()).unwrap();
+
+    let mdbook001: Vec<_> = stderr_output
+        .lines()
+        .filter(|line| line.contains("MDBOOK001"))
+        .collect();
+    assert_eq!(mdbook001.len(), 1, "{stderr_output}");
+    assert!(mdbook001[0].starts_with("guide.md"));
+}
+
 #[test]
 fn test_preprocessor_summary_validation() {
     // Test MDBOOK003 (SUMMARY.md validation) through preprocessor
@@ -0,0 +1,75 @@
+//! Integration tests for the `migrate` subcommand
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+const MARKDOWNLINT_JSONC: &str = r#"{
+  // Project-wide settings
+  "MD013": { "line_length": 100, "stern": true },
+  "no-trailing-spaces": false,
+  "whitespace": false
+}"#;
+
+#[test]
+fn test_migrate_writes_config_and_reports_gaps() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join(".markdownlint.jsonc"),
+        MARKDOWNLINT_JSONC,
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("doc.md"),
+        format!("# Title\n\nTrailing spaces   \n\n{}\n", "x".repeat(90)),
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["migrate", ".markdownlint.jsonc"])
+        .assert()
+        .success()
+        .stdout(contains(
+            "Migrated .markdownlint.jsonc to .mdbook-lint.toml",
+        ))
+        .stdout(contains("MD013.stern: MD013 does not support this option"))
+        .stdout(contains("whitespace: not a markdownlint rule"));
+
+    let config = fs::read_to_string(temp_dir.path().join(".mdbook-lint.toml")).unwrap();
+    assert!(config.contains("markdownlint-compatible = true"));
+    assert!(config.contains("disabled-rules = ['MD009']"));
+    assert!(!config.contains("stern"));
+
+    // The migrated config is picked up: no MD009 and a 100 column limit
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--standard-only", "doc.md"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_migrate_refuses_to_overwrite_without_force() {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(temp_dir.path().join("lint.yaml"), "MD013: false\n").unwrap();
+    fs::write(temp_dir.path().join(".mdbook-lint.toml"), "").unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["migrate", "lint.yaml"])
+        .assert()
+        .failure()
+        .stderr(contains("use --force to overwrite"));
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["migrate", "lint.yaml", "--force"])
+        .assert()
+        .success();
+    let config = fs::read_to_string(temp_dir.path().join(".mdbook-lint.toml")).unwrap();
+    assert!(config.contains("'MD013'"));
+}
@@ -0,0 +1,79 @@
+//! Integration tests for rule profiles (`profile = "..."` and `--profile`)
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+/// A document that skips a heading level (MD001) and has trailing spaces (MD009)
+fn create_doc() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    fs::write(
+        temp_dir.path().join("doc.md"),
+        "# Title\n\n### Skipped\n\nText   \n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_minimal_profile_skips_style_rules() {
+    let temp_dir = create_doc();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--fail-on-warnings", "doc.md"])
+        .assert()
+        .code(1);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args([
+            "lint",
+            "--profile",
+            "minimal",
+            "--fail-on-warnings",
+            "doc.md",
+        ])
+        .assert()
+        .code(0);
+}
+
+#[test]
+fn test_cli_profile_replaces_config_profile() {
+    let temp_dir = create_doc();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "profile = \"minimal\"\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--fail-on-warnings", "doc.md"])
+        .assert()
+        .code(0);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--profile", "strict", "doc.md"])
+        .assert()
+        .code(1)
+        .stdout(contains("error[MD001]"));
+}
+
+#[test]
+fn test_unknown_profile_is_config_error() {
+    let temp_dir = create_doc();
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "--profile", "everything", "doc.md"])
+        .assert()
+        .code(2)
+        .stderr(contains(
+            "Unknown profile 'everything' (available: minimal, recommended, strict)",
+        ));
+}
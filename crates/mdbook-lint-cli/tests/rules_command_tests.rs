@@ -180,3 +180,64 @@ fn test_rules_command_help() {
         .stdout(contains("--category"))
         .stdout(contains("--provider"));
 }
+
+#[test]
+fn test_rules_command_detailed_lists_options() {
+    cli_command()
+        .arg("rules")
+        .arg("--detailed")
+        .assert()
+        .success()
+        .stdout(contains("Options:"))
+        .stdout(contains(
+            "line-length (an integer ≥ 20, default 80): Maximum line length",
+        ));
+}
+
+#[test]
+fn test_rules_command_json_options() {
+    let assert = cli_command().arg("rules").arg("--json").assert();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let rules: Vec<&serde_json::Value> = parsed["providers"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|provider| provider["rules"].as_array().unwrap())
+        .collect();
+    let md013 = rules.iter().find(|rule| rule["id"] == "MD013").unwrap();
+    let line_length = &md013["options"][0];
+    assert_eq!(line_length["name"], "line-length");
+    assert_eq!(line_length["type"], "integer");
+    assert_eq!(line_length["default"], 80);
+    assert_eq!(line_length["schema"]["minimum"], 20);
+
+    // Every declared default is a value its option accepts
+    for rule in &rules {
+        for option in rule["options"].as_array().unwrap() {
+            let default = &option["default"];
+            if default.is_null() {
+                continue;
+            }
+            let schema = &option["schema"];
+            if let Some(values) = schema["enum"].as_array() {
+                assert!(
+                    values.contains(default),
+                    "{}.{}",
+                    rule["id"],
+                    option["name"]
+                );
+            }
+            let matches_type = match option["type"].as_str().unwrap() {
+                "boolean" => default.is_boolean(),
+                "integer" => default.is_i64(),
+                "number" => default.is_number(),
+                "string" => default.is_string(),
+                "array" => default.is_array(),
+                _ => true,
+            };
+            assert!(matches_type, "{}.{}", rule["id"], option["name"]);
+        }
+    }
+}
@@ -284,3 +284,68 @@ More content here.
         println!("  {}: {}", violation.rule_id, violation.message);
     }
 }
+
+#[test]
+fn test_custom_rules_registered_from_config() {
+    let config: Config = r#"
+[custom-rules.NOJIRA]
+pattern = "JIRA-\\d+"
+message = "Remove internal ticket reference {match}"
+severity = "error"
+
+[custom-rules.NOWIP]
+pattern = "(?i)\\bwip\\b"
+message = "Remove work-in-progress markers"
+"#
+    .parse()
+    .unwrap();
+
+    let mut registry = PluginRegistry::new();
+    registry
+        .register_provider(Box::new(StandardRuleProvider))
+        .unwrap();
+    let engine = registry
+        .create_engine_with_config(Some(&config.core))
+        .unwrap();
+    assert!(engine.available_rules().contains(&"NOJIRA"));
+
+    let document = Document::new(
+        "# Notes\n\nFixed in JIRA-42, still WIP.\n".to_string(),
+        PathBuf::from("notes.md"),
+    )
+    .unwrap();
+    let violations = engine
+        .lint_document_with_config(&document, &config.core)
+        .unwrap();
+
+    let jira = violations.iter().find(|v| v.rule_id == "NOJIRA").unwrap();
+    assert_eq!(jira.message, "Remove internal ticket reference JIRA-42");
+    assert_eq!(jira.severity, mdbook_lint::Severity::Error);
+    assert_eq!((jira.line, jira.column), (3, 10));
+    assert!(violations.iter().any(|v| v.rule_id == "NOWIP"));
+
+    // Custom rules honor disabled-rules like any other rule
+    let mut disabled = config.core.clone();
+    disabled.disabled_rules.push("NOWIP".to_string());
+    let violations = engine
+        .lint_document_with_config(&document, &disabled)
+        .unwrap();
+    assert!(!violations.iter().any(|v| v.rule_id == "NOWIP"));
+}
+
+#[test]
+fn test_custom_rule_with_invalid_pattern_fails_engine_creation() {
+    let config: Config = "[custom-rules.BROKEN]\npattern = \"(\"\nmessage = \"x\"\n"
+        .parse()
+        .unwrap();
+
+    let mut registry = PluginRegistry::new();
+    registry
+        .register_provider(Box::new(StandardRuleProvider))
+        .unwrap();
+    let err = registry
+        .create_engine_with_config(Some(&config.core))
+        .err()
+        .unwrap();
+    assert!(err.to_string().contains("custom rule 'BROKEN'"));
+}
@@ -0,0 +1,101 @@
+//! Integration tests for `ruleset-version` pinning and `upgrade-config`
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::*;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_pinned_config_holds_back_newer_rules() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join(".mdbook-lint.toml"),
+        "ruleset-version = \"0.1.0\"\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "doc.md"])
+        .assert()
+        .stderr(contains(
+            "added after ruleset-version 0.1.0 are not running",
+        ))
+        .stderr(contains("mdbook-lint upgrade-config"));
+}
+
+#[cfg(feature = "content")]
+#[test]
+fn test_notice_names_opt_in_rules_only_once_configured() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join(".mdbook-lint.toml");
+    fs::write(&config, "ruleset-version = \"0.14.0\"\n").unwrap();
+    fs::write(dir.path().join("doc.md"), "# Title\n").unwrap();
+
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "doc.md"])
+        .assert()
+        .stderr(contains("CONTENT013"))
+        .stderr(contains("CONTENT015").not());
+
+    fs::write(
+        &config,
+        "ruleset-version = \"0.14.0\"\n\n[CONTENT015]\nmax-words = 30\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "doc.md"])
+        .assert()
+        .stderr(contains("CONTENT015"));
+}
+
+#[test]
+fn test_upgrade_config_moves_the_pin_forward() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join(".mdbook-lint.toml");
+    fs::write(
+        &config,
+        "# Project lint settings\nruleset-version = \"0.1.0\"\n\n[MD013]\nline-length = 100\n",
+    )
+    .unwrap();
+
+    cli_command()
+        .current_dir(dir.path())
+        .arg("upgrade-config")
+        .assert()
+        .success()
+        .stdout(contains("from 0.1.0 to"))
+        .stdout(contains("Now running:"));
+
+    let updated = fs::read_to_string(&config).unwrap();
+    assert!(updated.starts_with("# Project lint settings\nruleset-version = \""));
+    assert!(updated.ends_with("[MD013]\nline-length = 100\n"));
+    assert!(!updated.contains("0.1.0"));
+
+    cli_command()
+        .arg("check")
+        .arg(&config)
+        .assert()
+        .success()
+        .stderr(contains("not running").not());
+}
+
+#[test]
+fn test_check_rejects_unparseable_ruleset_version() {
+    let dir = TempDir::new().unwrap();
+    let config = dir.path().join(".mdbook-lint.toml");
+    fs::write(&config, "ruleset-version = \"latest\"\n").unwrap();
+
+    cli_command()
+        .arg("check")
+        .arg(&config)
+        .assert()
+        .failure()
+        .stderr(contains("ruleset-version must be a version"));
+}
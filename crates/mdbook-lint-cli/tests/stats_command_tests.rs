@@ -0,0 +1,79 @@
+//! Integration tests for the `stats` command
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn book() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let guide = temp_dir.path().join("src/guide");
+    fs::create_dir_all(&guide).unwrap();
+    fs::write(
+        temp_dir.path().join("src/intro.md"),
+        "# Intro\n\n### Skipped\n",
+    )
+    .unwrap();
+    fs::write(guide.join("setup.md"), "# Setup\n\n#### Skipped\n").unwrap();
+    fs::write(guide.join("clean.md"), "# Clean\n\nAll good.\n").unwrap();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "enabled-rules = [\"MD001\"]\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_stats_default_output() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["stats", "src"])
+        .assert()
+        .success()
+        .stdout(contains("2 violations in 2 of 3 files"))
+        .stdout(contains("By rule:"))
+        .stdout(contains("By directory:"))
+        .stdout(contains("src/guide"));
+}
+
+#[test]
+fn test_stats_json() {
+    let temp_dir = book();
+    let assert = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["stats", "--format", "json", "src"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    assert_eq!(parsed["files"], 3);
+    assert_eq!(parsed["total_violations"], 2);
+    assert_eq!(parsed["by_severity"]["errors"], 2);
+    assert_eq!(parsed["by_rule"][0]["rule_id"], "MD001");
+    assert_eq!(parsed["by_rule"][0]["files"], 2);
+    let directories: Vec<&str> = parsed["by_directory"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["directory"].as_str().unwrap())
+        .collect();
+    assert_eq!(directories, vec!["src", "src/guide"]);
+}
+
+#[test]
+fn test_stats_markdown() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["stats", "-f", "markdown", "src"])
+        .assert()
+        .success()
+        .stdout(contains("## Lint statistics"))
+        .stdout(contains("- **Violations**: 2"))
+        .stdout(contains("| MD001 "));
+}
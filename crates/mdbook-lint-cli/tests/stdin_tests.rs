@@ -0,0 +1,104 @@
+//! Integration tests for linting stdin: `--stdin-filepath` and `--stdin-batch`
+
+#![cfg(feature = "adr")]
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use tempfile::TempDir;
+
+const ADR: &str = "# Use Rust\n\nSome text.\n";
+
+#[test]
+fn test_stdin_filepath_names_the_document() {
+    let dir = TempDir::new().unwrap();
+
+    // Without a path, stdin isn't known to be an ADR
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "--output", "json", "-"])
+        .write_stdin(ADR)
+        .assert()
+        .success()
+        .stdout(contains("<stdin>").and(contains("ADR002").not()));
+
+    cli_command()
+        .current_dir(dir.path())
+        .args([
+            "lint",
+            "--output",
+            "json",
+            "--stdin-filepath",
+            "docs/adr/0001-use-rust.md",
+            "-",
+        ])
+        .write_stdin(ADR)
+        .assert()
+        .code(1)
+        .stdout(contains("\"file\": \"docs/adr/0001-use-rust.md\"").and(contains("ADR002")));
+}
+
+#[test]
+fn test_stdin_filepath_requires_stdin() {
+    cli_command()
+        .args(["lint", "--stdin-filepath", "a.md", "b.md"])
+        .assert()
+        .code(2)
+        .stderr(contains("--stdin-filepath requires reading from stdin"));
+}
+
+#[test]
+fn test_stdin_batch() {
+    let dir = TempDir::new().unwrap();
+    let input = [
+        serde_json::json!({"id": 1, "path": "docs/adr/0001-use-rust.md", "content": ADR}),
+        serde_json::json!({"id": 2, "path": "src/intro.md", "content": ADR}),
+    ]
+    .map(|request| request.to_string())
+    .join("\n");
+
+    let output = cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "--stdin-batch"])
+        .write_stdin(input)
+        .assert()
+        .code(1)
+        .get_output()
+        .stdout
+        .clone();
+
+    let responses: Vec<serde_json::Value> = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(responses.len(), 2);
+    let rules = |response: &serde_json::Value| -> Vec<String> {
+        response["violations"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v["rule_id"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // ADR rules follow each document's path
+    assert_eq!(responses[0]["id"], 1);
+    assert!(rules(&responses[0]).contains(&"ADR002".to_string()));
+    assert_eq!(responses[1]["path"], "src/intro.md");
+    assert!(
+        !rules(&responses[1])
+            .iter()
+            .any(|rule| rule.starts_with("ADR"))
+    );
+}
+
+#[test]
+fn test_stdin_batch_conflicts_with_files() {
+    cli_command()
+        .args(["lint", "--stdin-batch", "README.md"])
+        .assert()
+        .code(2);
+}
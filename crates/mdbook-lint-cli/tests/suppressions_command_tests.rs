@@ -0,0 +1,104 @@
+//! Integration tests for inline suppression comments and the `suppressions`
+//! command
+
+mod common;
+
+use common::cli_command;
+use predicates::prelude::PredicateBooleanExt;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn book() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let src = temp_dir.path().join("src");
+    fs::create_dir_all(&src).unwrap();
+    fs::write(
+        src.join("intro.md"),
+        format!(
+            "# Intro\n\n<!-- mdbook-lint-disable-next-line MD013 -- reason: long URL -->\n{}\n",
+            "x".repeat(120)
+        ),
+    )
+    .unwrap();
+    fs::write(
+        src.join("table.md"),
+        format!(
+            "# Table\n\n<!-- mdbook-lint-disable MD013 -->\n{}\n<!-- mdbook-lint-enable MD013 -->\n",
+            "y".repeat(120)
+        ),
+    )
+    .unwrap();
+    fs::write(src.join("clean.md"), "# Clean\n").unwrap();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "enabled-rules = [\"MD013\"]\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_suppression_comments_drop_violations() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .assert()
+        .success()
+        .stdout(contains("MD013").not());
+}
+
+#[test]
+fn test_require_suppression_reason() {
+    let temp_dir = book();
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "enabled-rules = [\"MD013\"]\nrequire-suppression-reason = true\n",
+    )
+    .unwrap();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["lint", "src"])
+        .assert()
+        .code(1)
+        .stdout(contains("SUPPRESS001"))
+        .stdout(contains("src/table.md:3:1"));
+}
+
+#[test]
+fn test_suppressions_default_output() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["suppressions", "src"])
+        .assert()
+        .success()
+        .stdout(contains("MD013 -- long URL"))
+        .stdout(contains("MD013 (no reason)"))
+        .stdout(contains(
+            "3 suppression comments in 2 files, 1 without a reason",
+        ));
+}
+
+#[test]
+fn test_suppressions_json() {
+    let temp_dir = book();
+    let assert = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["suppressions", "--format", "json", "src"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    assert_eq!(parsed["total"], 3);
+    assert_eq!(parsed["missing_reasons"], 1);
+    assert_eq!(parsed["files"][0]["path"], "src/intro.md");
+    assert_eq!(
+        parsed["files"][0]["suppressions"][0]["directive"],
+        "disable-next-line"
+    );
+    assert_eq!(parsed["files"][0]["suppressions"][0]["reason"], "long URL");
+    assert!(parsed["files"][1]["suppressions"][0]["reason"].is_null());
+}
@@ -0,0 +1,83 @@
+//! Integration tests for the `todos` command
+
+#![cfg(feature = "content")]
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use std::fs;
+use tempfile::TempDir;
+
+fn book() -> TempDir {
+    let temp_dir = TempDir::new().unwrap();
+    let guide = temp_dir.path().join("src/guide");
+    fs::create_dir_all(&guide).unwrap();
+    fs::write(
+        temp_dir.path().join("src/intro.md"),
+        "# Intro\n\nTODO: write the introduction.\n",
+    )
+    .unwrap();
+    fs::write(
+        guide.join("setup.md"),
+        "# Setup\n\nFIXME: check the install steps.\n\nTBD\n",
+    )
+    .unwrap();
+    fs::write(guide.join("clean.md"), "# Clean\n\nAll good.\n").unwrap();
+    // The todos command runs CONTENT001 and CONTENT002 even when disabled
+    fs::write(
+        temp_dir.path().join(".mdbook-lint.toml"),
+        "disabled-rules = [\"CONTENT001\"]\n",
+    )
+    .unwrap();
+    temp_dir
+}
+
+#[test]
+fn test_todos_default_output() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["todos", "src"])
+        .assert()
+        .success()
+        .stdout(contains("src/guide/setup.md"))
+        .stdout(contains("FIXME"))
+        .stdout(contains("placeholder"))
+        .stdout(contains("3 markers in 2 files"));
+}
+
+#[test]
+fn test_todos_json() {
+    let temp_dir = book();
+    let assert = cli_command()
+        .current_dir(temp_dir.path())
+        .args(["todos", "--format", "json", "src"])
+        .assert()
+        .success();
+    let stdout = String::from_utf8(assert.get_output().stdout.clone()).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    assert_eq!(parsed["total"], 3);
+    assert_eq!(parsed["files"][0]["path"], "src/guide/setup.md");
+    assert_eq!(parsed["files"][0]["todos"][0]["marker"], "FIXME");
+    assert_eq!(parsed["files"][1]["todos"][0]["marker"], "TODO");
+    assert_eq!(parsed["files"][1]["todos"][0]["line"], 3);
+}
+
+#[test]
+fn test_deny_todos() {
+    let temp_dir = book();
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["todos", "--deny-todos", "src"])
+        .assert()
+        .code(1);
+
+    cli_command()
+        .current_dir(temp_dir.path())
+        .args(["todos", "--deny-todos", "src/guide/clean.md"])
+        .assert()
+        .success()
+        .stdout(contains("No TODO, FIXME, or placeholder markers found"));
+}
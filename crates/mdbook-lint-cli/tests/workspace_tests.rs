@@ -0,0 +1,97 @@
+//! Integration tests for `lint --workspace`
+
+mod common;
+
+use common::cli_command;
+use predicates::str::contains;
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn write(path: &Path, content: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Two books: `docs/user` with the default configuration and `docs/dev`,
+/// whose own config turns off MD009
+fn two_books() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    let chapter = "# Intro\n\nTrailing spaces here.   \n";
+    write(
+        &root.join("docs/user/book.toml"),
+        "[book]\ntitle = \"User\"\n",
+    );
+    write(&root.join("docs/user/src/intro.md"), chapter);
+    write(
+        &root.join("docs/dev/book.toml"),
+        "[book]\ntitle = \"Dev\"\nsrc = \"chapters\"\n",
+    );
+    write(
+        &root.join("docs/dev/.mdbook-lint.toml"),
+        "disabled-rules = [\"MD009\"]\n",
+    );
+    write(&root.join("docs/dev/chapters/intro.md"), chapter);
+    dir
+}
+
+fn rules_in(file: &Value) -> Vec<&str> {
+    file["violations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|v| v["rule_id"].as_str().unwrap())
+        .collect()
+}
+
+#[test]
+fn test_workspace_lints_each_book_with_its_config() {
+    let dir = two_books();
+    let output = cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "--workspace", "--output", "json"])
+        .output()
+        .unwrap();
+    let report: Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let files = report["files"].as_array().unwrap();
+    let file = |name: &str| files.iter().find(|f| f["file"] == name).unwrap();
+    assert!(!rules_in(file("docs/dev/chapters/intro.md")).contains(&"MD009"));
+    assert!(rules_in(file("docs/user/src/intro.md")).contains(&"MD009"));
+
+    let books = report["books"].as_array().unwrap();
+    let names: Vec<&str> = books.iter().map(|b| b["book"].as_str().unwrap()).collect();
+    assert_eq!(names, ["docs/dev", "docs/user"]);
+}
+
+#[test]
+fn test_workspace_fixes_each_book_and_reports_per_book() {
+    let dir = two_books();
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "--workspace", "--fix", "--no-backup", "docs"])
+        .assert()
+        .stdout(contains("Fixed 1 issue(s) in docs/user/src/intro.md"))
+        .stdout(contains("docs/dev: "))
+        .stdout(contains("docs/user: "));
+
+    // MD009 is off in the dev book, so its trailing spaces stay
+    let dev = fs::read_to_string(dir.path().join("docs/dev/chapters/intro.md")).unwrap();
+    let user = fs::read_to_string(dir.path().join("docs/user/src/intro.md")).unwrap();
+    assert!(dev.contains("here.   \n"));
+    assert!(user.contains("here.\n"));
+}
+
+#[test]
+fn test_workspace_without_books_is_a_config_error() {
+    let dir = TempDir::new().unwrap();
+    write(&dir.path().join("notes.md"), "# Notes\n");
+    cli_command()
+        .current_dir(dir.path())
+        .args(["lint", "--workspace"])
+        .assert()
+        .code(2)
+        .stderr(contains("--workspace found no book.toml"));
+}
@@ -0,0 +1,227 @@
+//! Local files referenced from a document, such as images
+//!
+//! Rules that look at the files a chapter points to resolve them through
+//! [`Document::asset`](crate::Document::asset) instead of joining paths
+//! themselves, so chapter-relative paths, `/`-rooted paths, and URL escapes
+//! are handled the same way by every rule.
+
+use std::fs::File;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+
+/// A local file referenced from a document
+///
+/// The file may not exist; [`Asset::path`] is only set when it does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    reference: String,
+    path: Option<PathBuf>,
+}
+
+impl Asset {
+    /// Resolve a decoded reference from the chapter at `chapter`
+    ///
+    /// Paths starting with `/` are looked up in `src_dir`. Other paths are
+    /// looked up next to the chapter first, then in `src_dir`.
+    pub fn resolve(reference: String, chapter: &Path, src_dir: Option<&Path>) -> Self {
+        let chapter_dir = chapter.parent().unwrap_or(Path::new("."));
+        let candidates = match (reference.strip_prefix('/'), src_dir) {
+            (Some(rooted), Some(src)) => vec![src.join(rooted)],
+            (Some(rooted), None) => vec![chapter_dir.join(rooted)],
+            (None, Some(src)) => vec![chapter_dir.join(&reference), src.join(&reference)],
+            (None, None) => vec![chapter_dir.join(&reference)],
+        };
+        let path = candidates
+            .into_iter()
+            .map(|candidate| normalize(&candidate))
+            .find(|candidate| candidate.is_file());
+        Self { reference, path }
+    }
+
+    /// The referenced path, with query, fragment, and `%XX` escapes removed
+    pub fn reference(&self) -> &str {
+        &self.reference
+    }
+
+    /// The file on disk, if it exists
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Whether the referenced file exists
+    pub fn exists(&self) -> bool {
+        self.path.is_some()
+    }
+
+    /// Size of the file in bytes
+    pub fn size(&self) -> Option<u64> {
+        std::fs::metadata(self.path.as_ref()?).ok().map(|m| m.len())
+    }
+
+    /// Up to `limit` bytes from the start of the file
+    ///
+    /// Enough to read an image header without loading a large file.
+    pub fn read_head(&self, limit: usize) -> Option<Vec<u8>> {
+        let file = File::open(self.path.as_ref()?).ok()?;
+        let mut bytes = Vec::new();
+        file.take(limit as u64).read_to_end(&mut bytes).ok()?;
+        Some(bytes)
+    }
+}
+
+/// The local path a link or image URL refers to, or `None` for remote URLs
+///
+/// URLs with a scheme (`https:`, `data:`, `mailto:`), protocol-relative URLs,
+/// and fragment-only links are not local. Query strings and fragments are
+/// dropped and `%XX` escapes are decoded.
+pub fn local_reference(url: &str) -> Option<String> {
+    let url = url.trim();
+    if url.is_empty() || url.starts_with("//") || url.starts_with('#') || url.contains(':') {
+        return None;
+    }
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    (!path.is_empty()).then(|| percent_decode(path))
+}
+
+/// Decode `%XX` escapes such as `%20` in a URL path
+fn percent_decode(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(byte) = path
+                .get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        {
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| path.to_string())
+}
+
+/// The file mdBook renders the page at `path` from, if it exists
+///
+/// Links between chapters can name the Markdown file, the `.html` page built
+/// from it, or a directory. A directory's page is its `index.md`, or else its
+/// `README.md`, which mdBook's index preprocessor renders as `index.html`;
+/// `guide/`, `guide/index.html`, and `guide/README.md` all name the same
+/// chapter.
+pub fn page_source(path: &Path) -> Option<PathBuf> {
+    if path.is_file() {
+        return Some(path.to_path_buf());
+    }
+    let index = |dir: &Path| {
+        ["index.md", "README.md"]
+            .into_iter()
+            .map(|name| dir.join(name))
+            .find(|candidate| candidate.is_file())
+    };
+    if path.is_dir() {
+        return index(path);
+    }
+    if path.extension().is_some_and(|ext| ext == "html") {
+        let markdown = path.with_extension("md");
+        if markdown.is_file() {
+            return Some(markdown);
+        }
+        if path.file_stem().is_some_and(|stem| stem == "index") {
+            return index(path.parent()?);
+        }
+    }
+    None
+}
+
+/// Resolve `.` and `..` without touching the filesystem
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut components = Vec::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                components.pop();
+            }
+            _ => components.push(component),
+        }
+    }
+    components.iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_local_reference() {
+        assert_eq!(local_reference("a%20b.png?v=2").as_deref(), Some("a b.png"));
+        assert_eq!(
+            local_reference("../img/x.svg#layer").as_deref(),
+            Some("../img/x.svg")
+        );
+        assert_eq!(local_reference("https://example.com/a.png"), None);
+        assert_eq!(local_reference("//cdn.example.com/a.png"), None);
+        assert_eq!(local_reference("data:image/png;base64,AA"), None);
+        assert_eq!(local_reference("#section"), None);
+    }
+
+    #[test]
+    fn test_resolve() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("guide")).unwrap();
+        fs::create_dir_all(src.join("images")).unwrap();
+        fs::write(src.join("images/logo.png"), b"12345").unwrap();
+        let chapter = src.join("guide/setup.md");
+
+        let asset = Asset::resolve("../images/logo.png".into(), &chapter, Some(&src));
+        assert_eq!(asset.path(), Some(src.join("images/logo.png").as_path()));
+        assert_eq!(asset.size(), Some(5));
+        assert_eq!(asset.read_head(2).as_deref(), Some(&b"12"[..]));
+
+        // Rooted at src/, and relative paths fall back to src/
+        assert!(Asset::resolve("/images/logo.png".into(), &chapter, Some(&src)).exists());
+        assert!(Asset::resolve("images/logo.png".into(), &chapter, Some(&src)).exists());
+        assert!(!Asset::resolve("images/logo.png".into(), &chapter, None).exists());
+
+        let missing = Asset::resolve("missing.png".into(), &chapter, Some(&src));
+        assert_eq!(missing.reference(), "missing.png");
+        assert_eq!((missing.path(), missing.size()), (None, None));
+    }
+
+    #[test]
+    fn test_page_source() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("guide")).unwrap();
+        fs::create_dir_all(src.join("empty")).unwrap();
+        fs::write(src.join("intro.md"), "# Intro\n").unwrap();
+        fs::write(src.join("guide/README.md"), "# Guide\n").unwrap();
+
+        let readme = Some(src.join("guide/README.md"));
+        assert_eq!(
+            page_source(&src.join("intro.md")),
+            Some(src.join("intro.md"))
+        );
+        assert_eq!(
+            page_source(&src.join("intro.html")),
+            Some(src.join("intro.md"))
+        );
+        assert_eq!(page_source(&src.join("guide")), readme);
+        assert_eq!(page_source(&src.join("guide/index.html")), readme);
+
+        fs::write(src.join("guide/index.md"), "# Index\n").unwrap();
+        assert_eq!(
+            page_source(&src.join("guide")),
+            Some(src.join("guide/index.md"))
+        );
+
+        assert_eq!(page_source(&src.join("empty")), None);
+        assert_eq!(page_source(&src.join("missing.html")), None);
+    }
+}
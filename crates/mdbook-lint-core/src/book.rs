@@ -0,0 +1,285 @@
+//! Whole-book linting
+//!
+//! A [`Book`] is the set of documents that are linted together: the chapters
+//! of an mdBook project, or every Markdown file under a directory.
+//! [`LintEngine::lint_book`](crate::LintEngine::lint_book) runs the
+//! per-document rules on each of them and the collection rules across all of
+//! them, and returns the violations grouped by file.
+
+use crate::Document;
+use crate::error::{MdBookLintError, Result};
+use mdbook::book::{SummaryItem, parse_summary};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// Where a book's documents come from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BookSource {
+    /// An mdBook project: `SUMMARY.md` and the chapters it links to
+    ///
+    /// The path is the project root (with `book.toml`) or the source
+    /// directory itself (with `SUMMARY.md`).
+    MdBook(PathBuf),
+    /// Every `.md` and `.markdown` file under a directory, hidden
+    /// directories excepted
+    Directory(PathBuf),
+}
+
+impl BookSource {
+    /// Pick the source for a directory: an mdBook project when it has a
+    /// `book.toml` or `SUMMARY.md`, otherwise a plain directory
+    pub fn detect(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if path.join("book.toml").is_file() || path.join("SUMMARY.md").is_file() {
+            Self::MdBook(path)
+        } else {
+            Self::Directory(path)
+        }
+    }
+
+    /// The directory the source was created from
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::MdBook(path) | Self::Directory(path) => path,
+        }
+    }
+}
+
+/// Documents that are linted together
+#[derive(Debug)]
+pub struct Book {
+    /// The book's source directory, when it is an mdBook project
+    pub src_dir: Option<PathBuf>,
+    /// The documents, in reading order for mdBook projects and path order
+    /// for directories
+    pub documents: Vec<Document>,
+}
+
+impl Book {
+    /// Build a book from documents that are already loaded
+    pub fn new(src_dir: Option<PathBuf>, documents: Vec<Document>) -> Self {
+        Self { src_dir, documents }
+    }
+
+    /// Load a book's documents from disk
+    ///
+    /// For mdBook projects the source directory comes from `book.src` in
+    /// `book.toml` (default `src`). Chapters that `SUMMARY.md` links to but
+    /// that don't exist are left out; MDBOOK002 reports them.
+    pub fn load(source: &BookSource) -> Result<Self> {
+        match source {
+            BookSource::MdBook(root) => {
+                let src_dir = mdbook_src_dir(root)?;
+                let summary_path = src_dir.join("SUMMARY.md");
+                let summary = std::fs::read_to_string(&summary_path).map_err(|e| {
+                    MdBookLintError::document_error(format!(
+                        "Failed to read {}: {e}",
+                        summary_path.display()
+                    ))
+                })?;
+                let chapters = parse_summary(&summary).map_err(|e| {
+                    MdBookLintError::document_error(format!(
+                        "Failed to parse {}: {e}",
+                        summary_path.display()
+                    ))
+                })?;
+
+                let mut paths = vec![summary_path];
+                let mut seen = BTreeSet::new();
+                for item in chapters
+                    .prefix_chapters
+                    .iter()
+                    .chain(&chapters.numbered_chapters)
+                    .chain(&chapters.suffix_chapters)
+                {
+                    collect_chapters(item, &src_dir, &mut seen, &mut paths);
+                }
+
+                let documents = paths
+                    .into_iter()
+                    .filter(|path| path.is_file())
+                    .map(|path| load_document(path, Some(&src_dir)))
+                    .collect::<Result<_>>()?;
+                Ok(Self::new(Some(src_dir), documents))
+            }
+            BookSource::Directory(dir) => {
+                let mut paths = Vec::new();
+                let walker = walkdir::WalkDir::new(dir)
+                    .sort_by_file_name()
+                    .into_iter()
+                    .filter_entry(|entry| {
+                        entry.depth() == 0 || !entry.file_name().to_string_lossy().starts_with('.')
+                    });
+                for entry in walker {
+                    let entry = entry?;
+                    let is_markdown = entry
+                        .path()
+                        .extension()
+                        .is_some_and(|ext| ext == "md" || ext == "markdown");
+                    if entry.file_type().is_file() && is_markdown {
+                        paths.push(entry.into_path());
+                    }
+                }
+
+                let documents = paths
+                    .into_iter()
+                    .map(|path| load_document(path, None))
+                    .collect::<Result<_>>()?;
+                Ok(Self::new(None, documents))
+            }
+        }
+    }
+}
+
+/// The source directory of the mdBook project at `root`
+fn mdbook_src_dir(root: &Path) -> Result<PathBuf> {
+    let book_toml = root.join("book.toml");
+    if !book_toml.is_file() {
+        return Ok(root.to_path_buf());
+    }
+    let config: toml::Value = toml::from_str(&std::fs::read_to_string(&book_toml)?)?;
+    Ok(root.join(configured_src(&config)))
+}
+
+/// The source directory named by the `book.toml` in `root`, if it has one
+/// that can be read
+pub(crate) fn book_toml_src_dir(root: &Path) -> Option<PathBuf> {
+    let content = std::fs::read_to_string(root.join("book.toml")).ok()?;
+    let config: toml::Value = toml::from_str(&content).ok()?;
+    Some(root.join(configured_src(&config)))
+}
+
+/// `book.src` in a parsed `book.toml`, default `src`
+fn configured_src(config: &toml::Value) -> &str {
+    config
+        .get("book")
+        .and_then(|book| book.get("src"))
+        .and_then(|src| src.as_str())
+        .unwrap_or("src")
+}
+
+/// Add the chapter files of a SUMMARY.md item and its sub-chapters
+fn collect_chapters(
+    item: &SummaryItem,
+    src_dir: &Path,
+    seen: &mut BTreeSet<PathBuf>,
+    paths: &mut Vec<PathBuf>,
+) {
+    let SummaryItem::Link(link) = item else {
+        return;
+    };
+    // Draft chapters have no file
+    if let Some(location) = &link.location {
+        let path = src_dir.join(location);
+        if seen.insert(path.clone()) {
+            paths.push(path);
+        }
+    }
+    for nested in &link.nested_items {
+        collect_chapters(nested, src_dir, seen, paths);
+    }
+}
+
+/// Read a document, recording the book it belongs to
+fn load_document(path: PathBuf, src_dir: Option<&Path>) -> Result<Document> {
+    let bytes = std::fs::read(&path)?;
+    let mut document = Document::from_bytes(&bytes, path)?;
+    document.book_src_dir = src_dir.map(Path::to_path_buf);
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn mdbook_project() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("docs");
+        fs::create_dir_all(src.join("guide")).unwrap();
+        fs::write(dir.path().join("book.toml"), "[book]\nsrc = \"docs\"\n").unwrap();
+        fs::write(
+            src.join("SUMMARY.md"),
+            "# Summary\n\n[Intro](intro.md)\n\n- [Guide](guide/README.md)\n  - [Setup](guide/setup.md)\n- [Draft]()\n- [Gone](gone.md)\n",
+        )
+        .unwrap();
+        fs::write(src.join("intro.md"), "# Intro\n").unwrap();
+        fs::write(src.join("guide/README.md"), "# Guide\n").unwrap();
+        fs::write(src.join("guide/setup.md"), "# Setup\n").unwrap();
+        fs::write(src.join("orphan.md"), "# Orphan\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_detect() {
+        let project = mdbook_project();
+        assert!(matches!(
+            BookSource::detect(project.path()),
+            BookSource::MdBook(_)
+        ));
+        assert!(matches!(
+            BookSource::detect(project.path().join("docs")),
+            BookSource::MdBook(_)
+        ));
+        assert!(matches!(
+            BookSource::detect(project.path().join("docs/guide")),
+            BookSource::Directory(_)
+        ));
+    }
+
+    #[test]
+    fn test_load_mdbook() {
+        let project = mdbook_project();
+        let book = Book::load(&BookSource::MdBook(project.path().to_path_buf())).unwrap();
+        let src = project.path().join("docs");
+
+        assert_eq!(book.src_dir.as_deref(), Some(src.as_path()));
+        let paths: Vec<&Path> = book.documents.iter().map(|d| d.path.as_path()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                src.join("SUMMARY.md").as_path(),
+                src.join("intro.md").as_path(),
+                src.join("guide/README.md").as_path(),
+                src.join("guide/setup.md").as_path(),
+            ]
+        );
+        assert!(
+            book.documents
+                .iter()
+                .all(|d| d.book_src_dir.as_deref() == Some(src.as_path()))
+        );
+    }
+
+    #[test]
+    fn test_load_directory() {
+        let project = mdbook_project();
+        fs::create_dir_all(project.path().join("docs/.hidden")).unwrap();
+        fs::write(project.path().join("docs/.hidden/skip.md"), "# Skip\n").unwrap();
+        let book = Book::load(&BookSource::Directory(project.path().join("docs"))).unwrap();
+
+        let names: Vec<String> = book
+            .documents
+            .iter()
+            .map(|d| {
+                d.path
+                    .strip_prefix(project.path())
+                    .unwrap()
+                    .display()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "docs/SUMMARY.md",
+                "docs/guide/README.md",
+                "docs/guide/setup.md",
+                "docs/intro.md",
+                "docs/orphan.md",
+            ]
+        );
+        assert_eq!(book.src_dir, None);
+    }
+}
@@ -0,0 +1,107 @@
+//! Stopping a lint that is no longer wanted or is taking too long
+//!
+//! A [`CancellationToken`] is checked by the registry before each rule runs.
+//! The LSP server cancels the token for a document when newer text arrives,
+//! and the CLI gives each file a deadline with `--timeout-per-file`. Rules
+//! with long loops can poll [`LintContext::is_cancelled`](crate::LintContext::is_cancelled)
+//! to stop sooner.
+
+use crate::error::{MdBookLintError, Result};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// A shared flag and optional deadline that stop a lint in progress
+///
+/// Clones share the flag, so cancelling any clone cancels them all.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// A token that is only cancelled by calling [`Self::cancel`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A token that is also cancelled once `deadline` passes
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..Self::default()
+        }
+    }
+
+    /// A token that is also cancelled `timeout` from now
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::with_deadline(Instant::now() + timeout)
+    }
+
+    /// Cancel this token and every clone of it
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// The deadline, if the token has one
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Whether the token was cancelled or its deadline has passed
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+            || self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Return [`MdBookLintError::Cancelled`] if the lint should stop
+    pub fn check(&self) -> Result<()> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(MdBookLintError::Cancelled("lint was cancelled".to_string()))
+        } else if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            Err(MdBookLintError::Cancelled("deadline exceeded".to_string()))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_is_shared() {
+        let token = CancellationToken::new();
+        let clone = token.clone();
+        assert!(!token.is_cancelled());
+        assert!(token.check().is_ok());
+
+        clone.cancel();
+        assert!(token.is_cancelled());
+        assert!(matches!(
+            token.check(),
+            Err(MdBookLintError::Cancelled(reason)) if reason == "lint was cancelled"
+        ));
+    }
+
+    #[test]
+    fn test_deadline() {
+        let expired = CancellationToken::with_deadline(Instant::now());
+        assert!(expired.is_cancelled());
+        assert!(matches!(
+            expired.check(),
+            Err(MdBookLintError::Cancelled(reason)) if reason == "deadline exceeded"
+        ));
+
+        let pending = CancellationToken::with_timeout(Duration::from_secs(3600));
+        assert!(!pending.is_cancelled());
+        assert!(pending.deadline().is_some());
+    }
+}
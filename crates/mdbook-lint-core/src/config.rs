@@ -3,9 +3,29 @@
 //! This module contains the minimal configuration types needed by the core
 //! linting engine. The full configuration is handled by the CLI crate.
 
+use crate::custom::CustomRuleConfig;
+use crate::deduplication::DedupePolicy;
+use crate::document::{Document, MarkdownExtensions};
+use crate::kind::DocumentKind;
+use crate::regions::{IgnoreBlockquotes, Region, RegionMap};
+use crate::suppression::Suppressions;
+use crate::violation::{Severity, Violation};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
 
+/// Front matter key that holds per-document overrides
+///
+/// ```yaml
+/// ---
+/// mdbook-lint:
+///   disable: [MD013, CONTENT001]
+///   severity:
+///     MD033: info
+/// ---
+/// ```
+pub const FRONT_MATTER_KEY: &str = "mdbook-lint";
+
 /// Core configuration for the linting engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -29,6 +49,19 @@ pub struct Config {
     #[serde(rename = "deprecated-warning", default)]
     pub deprecated_warning: DeprecatedWarningLevel,
 
+    /// Version of the rule set the project was last checked against
+    ///
+    /// Rules introduced after this mdbook-lint version don't run unless they
+    /// are listed in `enabled-rules`, so upgrading mdbook-lint doesn't turn
+    /// on new rules behind the project's back.
+    #[serde(
+        rename = "ruleset-version",
+        alias = "ruleset_version",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ruleset_version: Option<String>,
+
     /// Enable markdownlint compatibility mode (disables rules that are disabled by default in markdownlint)
     #[serde(rename = "markdownlint-compatible", default)]
     pub markdownlint_compatible: bool,
@@ -48,6 +81,66 @@ pub struct Config {
     #[serde(rename = "ignore-paths", alias = "ignore_paths", default)]
     pub ignore_paths: Vec<String>,
 
+    /// Glob patterns for files that are fragments, matched the way
+    /// `ignore-paths` is
+    ///
+    /// A matching file is linted as [`DocumentKind::Fragment`] whatever its
+    /// name, so rules about whole documents, such as a first-line heading,
+    /// skip it.
+    #[serde(rename = "fragment-paths", alias = "fragment_paths", default)]
+    pub fragment_paths: Vec<String>,
+
+    /// Report suppression comments that turn rules off without a reason
+    /// (see [`crate::suppression`])
+    #[serde(
+        rename = "require-suppression-reason",
+        alias = "require_suppression_reason",
+        default
+    )]
+    pub require_suppression_reason: bool,
+
+    /// How overlapping findings from rules in the same dedupe group are
+    /// merged: `strict` (same line and column), `loose` (same line), or `off`
+    #[serde(default)]
+    pub dedupe: DedupePolicy,
+
+    /// Drop every rule's violations in blockquotes: `true` for all quotes,
+    /// or a nesting depth. A rule's own `ignore-blockquotes` key takes
+    /// precedence.
+    #[serde(
+        rename = "ignore-blockquotes",
+        alias = "ignore_blockquotes",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ignore_blockquotes: Option<IgnoreBlockquotes>,
+
+    /// Markdown extensions documents are parsed with, from the `[markdown]`
+    /// table
+    #[serde(default)]
+    pub markdown: MarkdownExtensions,
+
+    /// Per-rule severity overrides from the `[severity]` table.
+    ///
+    /// Maps a rule ID to the severity its violations should be reported with
+    /// (e.g. `MD013 = "info"`, `MDBOOK001 = "error"`). The engine applies the
+    /// mapping to every violation it returns, so both output formatting and
+    /// exit-code evaluation see the remapped severity.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub severity: HashMap<String, Severity>,
+
+    /// User-defined regex rules from the `[custom-rules]` table.
+    ///
+    /// Each key is the rule ID and each value describes the pattern, message,
+    /// and severity. The rules are registered when an engine is created.
+    #[serde(
+        rename = "custom-rules",
+        alias = "custom_rules",
+        default,
+        skip_serializing_if = "HashMap::is_empty"
+    )]
+    pub custom_rules: HashMap<String, CustomRuleConfig>,
+
     /// Rule-specific configuration
     #[serde(flatten)]
     pub rule_configs: HashMap<String, toml::Value>,
@@ -65,14 +158,70 @@ impl Default for Config {
             enabled_rules: Vec::new(),
             disabled_rules: Vec::new(),
             deprecated_warning: DeprecatedWarningLevel::default(),
+            ruleset_version: None,
             markdownlint_compatible: false,
             auto_fix: true, // Default to true - fixes are applied when --fix is used
             ignore_paths: Vec::new(),
+            fragment_paths: Vec::new(),
+            require_suppression_reason: false,
+            dedupe: DedupePolicy::default(),
+            ignore_blockquotes: None,
+            markdown: MarkdownExtensions::default(),
+            severity: HashMap::new(),
+            custom_rules: HashMap::new(),
             rule_configs: HashMap::new(),
         }
     }
 }
 
+/// Match a path against a glob the way `ignore-paths` does: a trailing `/`
+/// matches everything under a directory, a pattern also matches deeper in
+/// the tree, and `*` does not cross path separators
+fn path_matches_glob(path: &std::path::Path, glob: &str) -> bool {
+    use glob::{MatchOptions, Pattern};
+
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+    let normalized = path
+        .to_string_lossy()
+        .replace('\\', "/")
+        .trim_start_matches("./")
+        .to_string();
+
+    let mut pattern = glob.replace('\\', "/").trim_start_matches("./").to_string();
+    if pattern.ends_with('/') {
+        pattern.push_str("**");
+    }
+    let mut candidates = vec![pattern.clone()];
+    if !pattern.starts_with("**/") && !pattern.starts_with('/') {
+        candidates.push(format!("**/{pattern}"));
+    }
+
+    candidates.iter().any(|candidate| {
+        Pattern::new(candidate).is_ok_and(|p| p.matches_with(&normalized, options))
+    })
+}
+
+/// Parse a version such as `0.14.0`, `v0.14`, or `0.14.0-beta.1`
+///
+/// Missing components count as zero and pre-release or build suffixes are
+/// ignored.
+pub fn parse_version(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let version = version.split(['-', '+']).next()?;
+    let mut parts = version.split('.').map(str::parse::<u64>);
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().unwrap_or(Ok(0)).ok()?;
+    let patch = parts.next().unwrap_or(Ok(0)).ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
 /// How to handle deprecated rule warnings
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
@@ -137,4 +286,184 @@ impl Config {
         // Fall back to global setting
         self.auto_fix
     }
+
+    /// Get the configured severity override for a rule, if any
+    pub fn severity_override(&self, rule_id: &str) -> Option<Severity> {
+        self.severity.get(rule_id).copied()
+    }
+
+    /// Remap violation severities according to the `[severity]` table
+    ///
+    /// Violations from rules without an override are left untouched.
+    pub fn apply_severity_overrides(&self, violations: &mut [Violation]) {
+        if self.severity.is_empty() {
+            return;
+        }
+
+        for violation in violations {
+            if let Some(severity) = self.severity_override(&violation.rule_id) {
+                violation.severity = severity;
+            }
+        }
+    }
+
+    /// Regions whose violations `rule_id` drops, from the boolean `ignore-*`
+    /// keys in its table
+    ///
+    /// Blockquotes can be ignored by depth and globally, so they are left
+    /// to [`Config::ignored_blockquote_depth`].
+    pub fn ignored_regions(&self, rule_id: &str) -> Vec<Region> {
+        let Some(rule_config) = self.rule_configs.get(rule_id) else {
+            return Vec::new();
+        };
+        Region::ALL
+            .into_iter()
+            .filter(|region| {
+                *region != Region::Blockquote
+                    && rule_config
+                        .get(region.config_key())
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false)
+            })
+            .collect()
+    }
+
+    /// Kinds of document `rule_id` runs on, from the `document-kinds` key in
+    /// its table
+    ///
+    /// `None` when the key is missing or invalid, which leaves the choice to
+    /// the rule's [`RuleMetadata::kinds`](crate::RuleMetadata::kinds).
+    pub fn rule_document_kinds(&self, rule_id: &str) -> Option<Vec<DocumentKind>> {
+        self.rule_configs
+            .get(rule_id)
+            .and_then(|rule_config| rule_config.get(crate::rule::DOCUMENT_KINDS_KEY))
+            .and_then(crate::rule::parse_document_kinds)
+    }
+
+    /// The kind `document` is linted as
+    ///
+    /// A path matching `fragment-paths` makes it a fragment; otherwise it
+    /// keeps [`Document::kind`].
+    pub fn document_kind(&self, document: &Document) -> DocumentKind {
+        if self
+            .fragment_paths
+            .iter()
+            .any(|pattern| path_matches_glob(&document.path, pattern))
+        {
+            DocumentKind::Fragment
+        } else {
+            document.kind()
+        }
+    }
+
+    /// The shallowest blockquote depth whose violations `rule_id` drops
+    ///
+    /// The rule's own `ignore-blockquotes` key wins over the top-level one,
+    /// so a rule can set it to `false` to keep checking quotes.
+    pub fn ignored_blockquote_depth(&self, rule_id: &str) -> Option<usize> {
+        self.rule_configs
+            .get(rule_id)
+            .and_then(|rule_config| rule_config.get(Region::Blockquote.config_key()))
+            .and_then(IgnoreBlockquotes::from_value)
+            .or(self.ignore_blockquotes)
+            .and_then(IgnoreBlockquotes::min_depth)
+    }
+
+    /// Whether any rule ignores violations in some region
+    pub fn has_region_filters(&self) -> bool {
+        self.ignore_blockquotes
+            .and_then(IgnoreBlockquotes::min_depth)
+            .is_some()
+            || self.rule_configs.keys().any(|rule_id| {
+                !self.ignored_regions(rule_id).is_empty()
+                    || self.ignored_blockquote_depth(rule_id).is_some()
+            })
+    }
+
+    /// Drop violations on lines in regions their rule ignores
+    ///
+    /// Only the line a violation is reported on counts, so a violation
+    /// reported on a table's first line is dropped with `ignore-tables` even
+    /// if its fix would reach past the table.
+    pub fn apply_region_filters(&self, regions: &RegionMap, violations: &mut Vec<Violation>) {
+        let mut ignored: HashMap<String, (Vec<Region>, Option<usize>)> = HashMap::new();
+        violations.retain(|violation| {
+            let (rule_regions, quote_depth) =
+                ignored.entry(violation.rule_id.clone()).or_insert_with(|| {
+                    (
+                        self.ignored_regions(&violation.rule_id),
+                        self.ignored_blockquote_depth(&violation.rule_id),
+                    )
+                });
+            !rule_regions
+                .iter()
+                .any(|region| regions.contains(violation.line, *region))
+                && !quote_depth
+                    .is_some_and(|depth| regions.blockquote_depth(violation.line) >= depth)
+        });
+    }
+
+    /// Drop violations turned off by suppression comments in `document`
+    ///
+    /// With `require-suppression-reason` set, also adds a violation for each
+    /// comment that gives no reason.
+    pub fn apply_suppressions(&self, document: &Document, violations: &mut Vec<Violation>) {
+        let suppressions = Suppressions::from_document(document);
+        if suppressions.is_empty() {
+            return;
+        }
+        violations.retain(|violation| !suppressions.suppresses(violation));
+        if self.require_suppression_reason {
+            violations.extend(suppressions.missing_reasons());
+        }
+    }
+
+    /// Configuration for `document` with the overrides from its front matter
+    ///
+    /// The [`FRONT_MATTER_KEY`] table accepts `disable`, a rule ID or list of
+    /// rule IDs that do not run on the document, and `severity`, a table of
+    /// severity overrides like the `[severity]` table. Returns `self`
+    /// unchanged when the document has no such key or its front matter does
+    /// not parse.
+    pub fn for_document(&self, document: &Document) -> Cow<'_, Config> {
+        let Some(overrides) = document
+            .front_matter()
+            .and_then(|front_matter| front_matter.parse().ok())
+            .and_then(|mut value| value.get_mut(FRONT_MATTER_KEY).map(|v| v.take()))
+        else {
+            return Cow::Borrowed(self);
+        };
+
+        let mut config = self.clone();
+        let disable = match overrides.get("disable") {
+            Some(serde_json::Value::String(rule)) => vec![rule.as_str()],
+            Some(serde_json::Value::Array(rules)) => {
+                rules.iter().filter_map(|rule| rule.as_str()).collect()
+            }
+            _ => Vec::new(),
+        };
+        for rule in disable {
+            config.enabled_rules.retain(|r| r != rule);
+            if !config.disabled_rules.iter().any(|r| r == rule) {
+                config.disabled_rules.push(rule.to_string());
+            }
+        }
+
+        if let Some(severity) = overrides.get("severity").and_then(|v| v.as_object()) {
+            for (rule, level) in severity {
+                match serde_json::from_value::<Severity>(level.clone()) {
+                    Ok(level) => {
+                        config.severity.insert(rule.clone(), level);
+                    }
+                    Err(_) => tracing::warn!(
+                        path = %document.path.display(),
+                        rule = %rule,
+                        "ignoring invalid front matter severity {level}"
+                    ),
+                }
+            }
+        }
+
+        Cow::Owned(config)
+    }
 }
@@ -0,0 +1,246 @@
+//! Project-level information for rules that look beyond one document
+//!
+//! A [`LintContext`] is passed to every rule alongside the [`Document`]. It
+//! locates the book (its `src/` directory and the project root holding
+//! `book.toml`) and loads `SUMMARY.md`, `book.toml`, and neighbouring files on
+//! first use, so rules don't each walk the filesystem on their own.
+//!
+//! Reading files is a capability: only rules whose metadata declares it with
+//! [`RuleMetadata::filesystem`](crate::rule::RuleMetadata::filesystem) get a
+//! context that touches the disk. Other rules see the same paths, but every
+//! file accessor returns `None`.
+//!
+//! The context also carries the lint's [`CancellationToken`], so every rule
+//! on a document sees the same cancellation state.
+
+use crate::{CancellationToken, Document};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Book paths and lazily loaded project files, shared by the rules run on a
+/// document
+#[derive(Debug, Default)]
+pub struct LintContext {
+    /// Directory containing `SUMMARY.md`
+    src_dir: Option<PathBuf>,
+    /// Directory containing `book.toml`
+    project_root: Option<PathBuf>,
+    /// Whether file accessors may read from disk
+    filesystem: bool,
+    /// Stops the lint when cancelled
+    cancellation: CancellationToken,
+    summary: OnceLock<Option<String>>,
+    book_config: OnceLock<Option<toml::Value>>,
+}
+
+impl LintContext {
+    /// A context with no book and no filesystem access
+    ///
+    /// Used for content that doesn't live in a book, such as stdin or an
+    /// editor buffer.
+    pub fn detached() -> Self {
+        Self::default()
+    }
+
+    /// A context for the book whose source directory is `src_dir`
+    ///
+    /// The project root is the nearest directory at or above `src_dir` that
+    /// contains `book.toml`, falling back to the parent of `src_dir`.
+    pub fn for_src_dir(src_dir: Option<PathBuf>) -> Self {
+        let project_root = src_dir.as_deref().and_then(|src| {
+            src.ancestors()
+                .find(|dir| dir.join("book.toml").is_file())
+                .or_else(|| src.parent())
+                .map(Path::to_path_buf)
+        });
+        Self {
+            src_dir,
+            project_root,
+            filesystem: true,
+            ..Self::default()
+        }
+    }
+
+    /// A context for the book containing `document`
+    pub fn for_document(document: &Document) -> Self {
+        Self::for_src_dir(document.src_dir().map(Path::to_path_buf))
+    }
+
+    /// The same book paths, with file access turned off
+    pub fn without_filesystem(&self) -> Self {
+        Self {
+            src_dir: self.src_dir.clone(),
+            project_root: self.project_root.clone(),
+            cancellation: self.cancellation.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// The same context, stopped by `token`
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// The token that stops this lint
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    /// Whether the lint has been cancelled or has run past its deadline
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation.is_cancelled()
+    }
+
+    /// Whether file accessors may read from disk
+    pub fn has_filesystem(&self) -> bool {
+        self.filesystem
+    }
+
+    /// The book's source directory, which contains `SUMMARY.md`
+    pub fn src_dir(&self) -> Option<&Path> {
+        self.src_dir.as_deref()
+    }
+
+    /// The project root, which contains `book.toml`
+    pub fn project_root(&self) -> Option<&Path> {
+        self.project_root.as_deref()
+    }
+
+    /// Contents of the book's `SUMMARY.md`
+    pub fn summary(&self) -> Option<&str> {
+        self.summary
+            .get_or_init(|| self.read_file(&self.src_dir.as_ref()?.join("SUMMARY.md")))
+            .as_deref()
+    }
+
+    /// The parsed `book.toml`
+    pub fn book_config(&self) -> Option<&toml::Value> {
+        self.book_config
+            .get_or_init(|| {
+                let text = self.read_file(&self.project_root.as_ref()?.join("book.toml"))?;
+                toml::from_str(&text).ok()
+            })
+            .as_ref()
+    }
+
+    /// A `book.toml` setting by dotted key, such as `output.html.site-url`
+    pub fn book_setting(&self, key: &str) -> Option<&toml::Value> {
+        key.split('.')
+            .try_fold(self.book_config()?, |value, part| value.get(part))
+    }
+
+    /// Other files in the same directory as `document`, sorted by path
+    pub fn sibling_files(&self, document: &Document) -> Vec<PathBuf> {
+        if !self.filesystem {
+            return Vec::new();
+        }
+        let Some(Ok(entries)) = document.path.parent().map(|dir| {
+            // `Path::new("a.md").parent()` is the empty path
+            let dir = if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir
+            };
+            std::fs::read_dir(dir)
+        }) else {
+            return Vec::new();
+        };
+
+        let name = document.path.file_name();
+        let mut files: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.file_type().is_ok_and(|t| t.is_file()))
+            .filter(|entry| Some(entry.file_name().as_os_str()) != name)
+            .map(|entry| entry.path())
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// Read a file as UTF-8 text
+    pub fn read_file(&self, path: &Path) -> Option<String> {
+        if !self.filesystem {
+            return None;
+        }
+        std::fs::read_to_string(path).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn book() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            dir.path().join("book.toml"),
+            "[book]\ntitle = \"Guide\"\n\n[output.html]\nsite-url = \"/guide/\"\n",
+        )
+        .unwrap();
+        fs::write(src.join("SUMMARY.md"), "# Summary\n\n- [Intro](intro.md)\n").unwrap();
+        fs::write(src.join("intro.md"), "# Intro\n").unwrap();
+        fs::write(src.join("setup.md"), "# Setup\n").unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_for_document() {
+        let book = book();
+        let src = book.path().join("src");
+        let document = Document::new("# Intro\n".into(), src.join("intro.md")).unwrap();
+        let context = LintContext::for_document(&document);
+
+        assert_eq!(context.src_dir(), Some(src.as_path()));
+        assert_eq!(context.project_root(), Some(book.path()));
+        assert!(context.summary().unwrap().contains("[Intro](intro.md)"));
+        assert_eq!(
+            context
+                .book_setting("output.html.site-url")
+                .and_then(|v| v.as_str()),
+            Some("/guide/")
+        );
+        assert_eq!(context.book_setting("book.missing"), None);
+        assert_eq!(
+            context.sibling_files(&document),
+            vec![src.join("SUMMARY.md"), src.join("setup.md")]
+        );
+    }
+
+    #[test]
+    fn test_without_filesystem() {
+        let book = book();
+        let src = book.path().join("src");
+        let document = Document::new("# Intro\n".into(), src.join("intro.md")).unwrap();
+        let context = LintContext::for_document(&document).without_filesystem();
+
+        assert!(!context.has_filesystem());
+        assert_eq!(context.src_dir(), Some(src.as_path()));
+        assert_eq!(context.summary(), None);
+        assert_eq!(context.book_config(), None);
+        assert!(context.sibling_files(&document).is_empty());
+    }
+
+    #[test]
+    fn test_cancellation_survives_restriction() {
+        let token = CancellationToken::new();
+        let context = LintContext::detached().with_cancellation(token.clone());
+        let restricted = context.without_filesystem();
+        assert!(!restricted.is_cancelled());
+
+        token.cancel();
+        assert!(context.is_cancelled());
+        assert!(restricted.is_cancelled());
+    }
+
+    #[test]
+    fn test_detached() {
+        let context = LintContext::detached();
+        assert_eq!((context.src_dir(), context.project_root()), (None, None));
+        assert_eq!(context.summary(), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! User-defined regex rules.
+//!
+//! Simple pattern checks can be declared in configuration instead of being
+//! written in Rust:
+//!
+//! ```toml
+//! [custom-rules.NOJIRA]
+//! pattern = "JIRA-\\d+"
+//! message = "Remove internal ticket references"
+//! severity = "warning"
+//! ```
+//!
+//! Each entry becomes a [`CustomRule`] that the [`PluginRegistry`] registers
+//! alongside provider rules when an engine is created, so custom rules can be
+//! enabled, disabled, and re-leveled like any other rule.
+//!
+//! [`PluginRegistry`]: crate::PluginRegistry
+
+use crate::error::{MdBookLintError, Result};
+use crate::rule::{Rule, RuleCategory, RuleMetadata};
+use crate::violation::{Severity, Violation};
+use crate::{Document, RuleRegistry};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Configuration for a single custom rule from the `[custom-rules]` table
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct CustomRuleConfig {
+    /// Regular expression matched against each line
+    pub pattern: String,
+    /// Message reported for each match; `{match}` is replaced by the matched text
+    pub message: String,
+    /// Severity of reported violations
+    #[serde(default = "default_severity")]
+    pub severity: Severity,
+    /// Human-readable rule name (defaults to the lowercased rule ID)
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Whether lines inside fenced code blocks are checked too
+    #[serde(default, alias = "include_code")]
+    pub include_code: bool,
+}
+
+fn default_severity() -> Severity {
+    Severity::Warning
+}
+
+/// A rule defined in configuration by a regular expression
+#[derive(Debug, Clone)]
+pub struct CustomRule {
+    id: &'static str,
+    name: &'static str,
+    description: &'static str,
+    pattern: Regex,
+    message: String,
+    severity: Severity,
+    include_code: bool,
+}
+
+impl CustomRule {
+    /// Build a rule from its configuration, compiling the pattern
+    ///
+    /// The [`Rule`] trait hands out `&'static str` identifiers, so the ID,
+    /// name, and description are leaked. Custom rules are created once per
+    /// engine, which keeps this bounded.
+    pub fn new(id: &str, config: &CustomRuleConfig) -> Result<Self> {
+        let pattern = Regex::new(&config.pattern).map_err(|e| {
+            MdBookLintError::config_error(format!("Invalid pattern for custom rule '{id}': {e}"))
+        })?;
+
+        let name = config.name.clone().unwrap_or_else(|| id.to_lowercase());
+        Ok(Self {
+            id: Box::leak(id.to_string().into_boxed_str()),
+            name: Box::leak(name.into_boxed_str()),
+            description: Box::leak(config.message.clone().into_boxed_str()),
+            pattern,
+            message: config.message.clone(),
+            severity: config.severity,
+            include_code: config.include_code,
+        })
+    }
+}
+
+/// Register every configured custom rule with `registry`
+///
+/// Rules are registered in ID order. Fails if a pattern does not compile or
+/// an ID is already taken by another rule.
+pub fn register_custom_rules(
+    registry: &mut RuleRegistry,
+    custom_rules: &HashMap<String, CustomRuleConfig>,
+) -> Result<()> {
+    let mut ids: Vec<&String> = custom_rules.keys().collect();
+    ids.sort();
+
+    for id in ids {
+        if registry.get_rule(id).is_some() {
+            return Err(MdBookLintError::config_error(format!(
+                "Custom rule '{id}' conflicts with an existing rule of the same ID"
+            )));
+        }
+        registry.register(Box::new(CustomRule::new(id, &custom_rules[id])?));
+    }
+
+    Ok(())
+}
+
+impl Rule for CustomRule {
+    fn id(&self) -> &'static str {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn description(&self) -> &'static str {
+        self.description
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        let mut fence: Option<&str> = None;
+
+        for (idx, line) in document.lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+
+            if !self.include_code {
+                let marker = if trimmed.starts_with("```") {
+                    Some("```")
+                } else if trimmed.starts_with("~~~") {
+                    Some("~~~")
+                } else {
+                    None
+                };
+                match (fence, marker) {
+                    (None, Some(marker)) => {
+                        fence = Some(marker);
+                        continue;
+                    }
+                    (Some(open), Some(marker)) if open == marker => {
+                        fence = None;
+                        continue;
+                    }
+                    (Some(_), _) => continue,
+                    _ => {}
+                }
+            }
+
+            for m in self.pattern.find_iter(line) {
+                if m.as_str().is_empty() {
+                    continue;
+                }
+                let column = line[..m.start()].chars().count() + 1;
+                violations.push(self.create_violation(
+                    self.message.replace("{match}", m.as_str()),
+                    idx + 1,
+                    column,
+                    self.severity,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn config(pattern: &str, message: &str) -> CustomRuleConfig {
+        CustomRuleConfig {
+            pattern: pattern.to_string(),
+            message: message.to_string(),
+            severity: Severity::Warning,
+            name: None,
+            include_code: false,
+        }
+    }
+
+    fn check(rule: &CustomRule, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_matches_report_line_and_column() {
+        let rule = CustomRule::new("NOJIRA", &config(r"JIRA-\d+", "Remove {match}")).unwrap();
+        let violations = check(&rule, "# Notes\n\nSee JIRA-12 and JIRA-345.\n");
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].rule_id, "NOJIRA");
+        assert_eq!(violations[0].rule_name, "nojira");
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(violations[0].column, 5);
+        assert_eq!(violations[0].message, "Remove JIRA-12");
+        assert_eq!(violations[1].column, 17);
+    }
+
+    #[test]
+    fn test_code_blocks_skipped_unless_included() {
+        let content = "Text\n\n```\nJIRA-1\n```\n";
+        let mut cfg = config(r"JIRA-\d+", "No tickets");
+
+        let rule = CustomRule::new("NOJIRA", &cfg).unwrap();
+        assert!(check(&rule, content).is_empty());
+
+        cfg.include_code = true;
+        let rule = CustomRule::new("NOJIRA", &cfg).unwrap();
+        assert_eq!(check(&rule, content).len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let err = CustomRule::new("BAD", &config("(unclosed", "x")).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid pattern for custom rule 'BAD'")
+        );
+    }
+
+    #[test]
+    fn test_register_rejects_duplicate_ids() {
+        let mut custom = HashMap::new();
+        custom.insert("NOJIRA".to_string(), config("JIRA", "x"));
+
+        let mut registry = RuleRegistry::new();
+        register_custom_rules(&mut registry, &custom).unwrap();
+        assert!(registry.get_rule("NOJIRA").is_some());
+
+        let err = register_custom_rules(&mut registry, &custom).unwrap_err();
+        assert!(err.to_string().contains("conflicts"));
+    }
+}
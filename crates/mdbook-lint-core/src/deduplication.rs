@@ -2,35 +2,91 @@
 //!
 //! This module handles deduplication of violations that are reported by multiple rules
 //! for the same issue (e.g., MD040 and MDBOOK001 both flagging missing code block languages).
+//!
+//! Rules that can report the same problem form a dedupe group ([`DEDUPE_GROUPS`]).
+//! When several rules of a group flag the same spot, only the finding from the rule
+//! listed first is kept. The `dedupe` config setting ([`DedupePolicy`]) decides what
+//! counts as the same spot.
 
 use crate::violation::Violation;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Groups of rules that report the same problem, each listed in order of precedence
+///
+/// - `code_block_language`: a fenced code block without a language
+/// - `atx_heading_spacing`: missing or extra spaces around the hashes of an ATX
+///   heading; the closed-heading rules are more specific, so they win
+/// - `image_alt_text`: an image with empty alternate text
+pub const DEDUPE_GROUPS: &[(&str, &[&str])] = &[
+    ("code_block_language", &["MDBOOK001", "MD040"]),
+    ("atx_heading_spacing", &["MD020", "MD021", "MD018", "MD019"]),
+    ("image_alt_text", &["MD045", "MD042"]),
+];
+
+/// How findings from rules in the same dedupe group are merged
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DedupePolicy {
+    /// Merge findings at the same line and column (default)
+    #[default]
+    Strict,
+    /// Merge findings anywhere on the same line
+    Loose,
+    /// Report every finding
+    Off,
+}
+
+impl std::fmt::Display for DedupePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DedupePolicy::Strict => write!(f, "strict"),
+            DedupePolicy::Loose => write!(f, "loose"),
+            DedupePolicy::Off => write!(f, "off"),
+        }
+    }
+}
+
 /// Configuration for violation deduplication
 #[derive(Debug, Clone)]
 pub struct DeduplicationConfig {
-    /// Whether deduplication is enabled (default: true)
-    pub enabled: bool,
+    /// How findings in the same group are merged (default: strict)
+    pub policy: DedupePolicy,
     /// Rule precedence mapping (higher precedence rules win)
     pub rule_precedence: HashMap<String, u32>,
 }
 
 impl Default for DeduplicationConfig {
     fn default() -> Self {
-        let mut rule_precedence = HashMap::new();
-
-        // Define default precedence: MDBOOK rules have higher precedence than standard MD rules
-        // for overlapping functionality since they provide more specific mdbook context
-        rule_precedence.insert("MDBOOK001".to_string(), 100); // Higher precedence
-        rule_precedence.insert("MD040".to_string(), 50); // Lower precedence
+        // Within each group, earlier rules take precedence; MDBOOK rules come
+        // before standard MD rules since they provide more specific mdbook context
+        let rule_precedence = DEDUPE_GROUPS
+            .iter()
+            .flat_map(|(_, rules)| {
+                rules
+                    .iter()
+                    .enumerate()
+                    .map(|(index, rule)| (rule.to_string(), 100 - 10 * index as u32))
+            })
+            .collect();
 
         Self {
-            enabled: true,
+            policy: DedupePolicy::default(),
             rule_precedence,
         }
     }
 }
 
+impl DeduplicationConfig {
+    /// The default precedence with the given policy
+    pub fn with_policy(policy: DedupePolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+}
+
 /// Rule overlap definitions - maps which rules check for the same issues
 pub struct RuleOverlaps {
     /// Maps rule pairs that check for the same violation type
@@ -40,13 +96,15 @@ pub struct RuleOverlaps {
 
 impl Default for RuleOverlaps {
     fn default() -> Self {
-        let mut overlaps = HashMap::new();
-
-        // MD040 ↔ MDBOOK001: Both check for missing fenced code block language
-        overlaps.insert(
-            "missing_code_block_language".to_string(),
-            vec!["MD040".to_string(), "MDBOOK001".to_string()],
-        );
+        let overlaps = DEDUPE_GROUPS
+            .iter()
+            .map(|(group, rules)| {
+                (
+                    group.to_string(),
+                    rules.iter().map(|rule| rule.to_string()).collect(),
+                )
+            })
+            .collect();
 
         Self { overlaps }
     }
@@ -81,10 +139,11 @@ impl RuleOverlaps {
 
     /// Generate a signature for a violation to identify overlap types
     fn get_violation_signature(&self, violation: &Violation) -> String {
-        match violation.rule_id.as_str() {
-            "MD040" | "MDBOOK001" => "missing_code_block_language".to_string(),
-            _ => format!("unique_{}", violation.rule_id),
-        }
+        self.overlaps
+            .iter()
+            .find(|(_, rules)| rules.contains(&violation.rule_id))
+            .map(|(group, _)| group.clone())
+            .unwrap_or_else(|| format!("unique_{}", violation.rule_id))
     }
 }
 
@@ -93,7 +152,7 @@ pub fn deduplicate_violations(
     violations: Vec<Violation>,
     config: &DeduplicationConfig,
 ) -> Vec<Violation> {
-    if !config.enabled {
+    if config.policy == DedupePolicy::Off {
         return violations;
     }
 
@@ -103,12 +162,11 @@ pub fn deduplicate_violations(
 
     // Group violations by location and type
     for violation in violations {
-        let group_key = format!(
-            "{}:{}:{}",
-            violation.line,
-            violation.column,
-            overlaps.get_violation_signature(&violation)
-        );
+        let signature = overlaps.get_violation_signature(&violation);
+        let group_key = match config.policy {
+            DedupePolicy::Loose => format!("{}:{}", violation.line, signature),
+            _ => format!("{}:{}:{}", violation.line, violation.column, signature),
+        };
 
         violation_groups
             .entry(group_key)
@@ -127,11 +185,14 @@ pub fn deduplicate_violations(
             // Check if this is a known overlap
             let signature = overlaps.get_violation_signature(&group[0]);
             if overlaps.overlaps.contains_key(&signature) {
-                // This is a known overlap - select based on precedence
+                // This is a known overlap - select based on precedence, then
+                // the leftmost finding on the line
                 group.sort_by(|a, b| {
                     let precedence_a = config.rule_precedence.get(&a.rule_id).unwrap_or(&0);
                     let precedence_b = config.rule_precedence.get(&b.rule_id).unwrap_or(&0);
-                    precedence_b.cmp(precedence_a) // Higher precedence first
+                    precedence_b
+                        .cmp(precedence_a) // Higher precedence first
+                        .then(a.column.cmp(&b.column))
                 });
 
                 // Take the highest precedence violation
@@ -168,6 +229,8 @@ mod tests {
             column,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }
     }
 
@@ -237,10 +300,7 @@ mod tests {
             create_test_violation("MDBOOK001", 5, 1, "Message 2"),
         ];
 
-        let config = DeduplicationConfig {
-            enabled: false,
-            ..Default::default()
-        };
+        let config = DeduplicationConfig::with_policy(DedupePolicy::Off);
 
         let result = deduplicate_violations(violations.clone(), &config);
 
@@ -249,6 +309,46 @@ mod tests {
         assert_eq!(result, violations);
     }
 
+    #[test]
+    fn test_heading_spacing_policies() {
+        // `##  Two spaces  ##` trips the open and closed heading rules
+        let violations = vec![
+            create_test_violation("MD020", 3, 1, "Whitespace inside hashes"),
+            create_test_violation("MD021", 3, 3, "Multiple spaces after hashes"),
+            create_test_violation("MD019", 3, 3, "Multiple spaces after hash"),
+            create_test_violation("MD021", 3, 15, "Multiple spaces before hashes"),
+            create_test_violation("MD013", 3, 1, "Line length"),
+        ];
+        let ids = |policy| {
+            let config = DeduplicationConfig::with_policy(policy);
+            deduplicate_violations(violations.clone(), &config)
+                .into_iter()
+                .map(|v| (v.rule_id, v.column))
+                .collect::<Vec<_>>()
+        };
+
+        let mut strict = ids(DedupePolicy::Strict);
+        strict.sort();
+        assert_eq!(
+            strict,
+            vec![
+                ("MD013".to_string(), 1),
+                ("MD020".to_string(), 1),
+                ("MD021".to_string(), 3),
+                ("MD021".to_string(), 15),
+            ]
+        );
+
+        let mut loose = ids(DedupePolicy::Loose);
+        loose.sort();
+        assert_eq!(
+            loose,
+            vec![("MD013".to_string(), 1), ("MD020".to_string(), 1)]
+        );
+
+        assert_eq!(ids(DedupePolicy::Off).len(), 5);
+    }
+
     #[test]
     fn test_custom_precedence() {
         let violations = vec![
@@ -1,10 +1,18 @@
+use crate::asset::{self, Asset};
 use crate::error::{MdBookLintError, Result};
+use crate::index::DocumentIndex;
+use crate::kind::DocumentKind;
+use crate::outline::Outline;
+use crate::regions::RegionMap;
 use comrak::nodes::{AstNode, NodeValue};
 use comrak::{Arena, ComrakOptions, parse_document};
-use std::path::PathBuf;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 /// Represents a parsed markdown document with position information
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Document {
     /// The original markdown content
     pub content: String,
@@ -14,6 +22,317 @@ pub struct Document {
     pub lines: Vec<String>,
     /// Optional book source directory (used by mdbook rules for path resolution)
     pub book_src_dir: Option<PathBuf>,
+    /// Encoding of the source file, used to write fixes back in kind
+    pub encoding: SourceEncoding,
+    /// Line and verbatim-region index, built on first use
+    index: OnceLock<DocumentIndex>,
+    /// Book source directory discovered from the path, on first use
+    discovered_src_dir: OnceLock<Option<PathBuf>>,
+    /// Heading outline, built on first use
+    outline: OnceLock<Outline>,
+    /// Block regions of each line, built on first use
+    regions: OnceLock<RegionMap>,
+    /// Markdown extensions the content is parsed with
+    extensions: MarkdownExtensions,
+    /// What the document is for, detected from the path unless set
+    kind: DocumentKind,
+}
+
+/// Markdown extensions enabled when parsing a document
+///
+/// Read from the `[markdown]` configuration table so the AST matches what
+/// the book's renderer supports. Rules can check [`Document::extensions`] to
+/// skip syntax the book doesn't use. The defaults are the extensions
+/// mdbook-lint has always parsed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct MarkdownExtensions {
+    /// `[^label]` footnote references and definitions
+    pub footnotes: bool,
+    /// Description lists: a term followed by `: definition` lines
+    #[serde(alias = "description_lists")]
+    pub description_lists: bool,
+    /// `- [ ]` and `- [x]` task list items
+    pub tasklists: bool,
+    /// `^superscript^` text
+    pub superscript: bool,
+    /// `[[Page]]` wiki links
+    ///
+    /// comrak has no wiki link parser, so this doesn't change the AST; it
+    /// tells rules whether `[[...]]` is meant as a link.
+    pub wikilinks: bool,
+}
+
+impl Default for MarkdownExtensions {
+    fn default() -> Self {
+        Self {
+            footnotes: true,
+            description_lists: true,
+            tasklists: true,
+            superscript: false,
+            wikilinks: false,
+        }
+    }
+}
+
+/// Text encoding of a document's source bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SourceEncoding {
+    /// UTF-8 without a byte order mark
+    #[default]
+    Utf8,
+    /// UTF-8 with a leading byte order mark
+    Utf8Bom,
+    /// UTF-16, little endian
+    Utf16Le,
+    /// UTF-16, big endian
+    Utf16Be,
+}
+
+impl SourceEncoding {
+    const UTF8_BOM: &'static [u8] = &[0xEF, 0xBB, 0xBF];
+
+    /// Detect the encoding of `bytes` and decode them, dropping any byte
+    /// order mark
+    ///
+    /// UTF-16 is recognized by its byte order mark, or without one when the
+    /// text starts with an ASCII character. Anything that is not valid in the
+    /// detected encoding is an error.
+    pub fn decode(bytes: &[u8]) -> Result<(String, Self)> {
+        if let Some(rest) = bytes.strip_prefix(Self::UTF8_BOM) {
+            return Self::decode_utf8(rest).map(|text| (text, Self::Utf8Bom));
+        }
+
+        let utf16 = match bytes {
+            [0xFF, 0xFE, rest @ ..] => Some((Self::Utf16Le, rest)),
+            [0xFE, 0xFF, rest @ ..] => Some((Self::Utf16Be, rest)),
+            [lo, 0, ..] if lo.is_ascii() && *lo != 0 => Some((Self::Utf16Le, bytes)),
+            [0, hi, ..] if hi.is_ascii() && *hi != 0 => Some((Self::Utf16Be, bytes)),
+            _ => None,
+        };
+        match utf16 {
+            Some((encoding, rest)) => {
+                Self::decode_utf16(rest, encoding).map(|text| (text, encoding))
+            }
+            None => Self::decode_utf8(bytes).map(|text| (text, Self::Utf8)),
+        }
+    }
+
+    /// Encode `text` in this encoding, restoring the byte order mark
+    pub fn encode(self, text: &str) -> Vec<u8> {
+        match self {
+            Self::Utf8 => text.as_bytes().to_vec(),
+            Self::Utf8Bom => [Self::UTF8_BOM, text.as_bytes()].concat(),
+            Self::Utf16Le => std::iter::once(0xFEFF)
+                .chain(text.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+            Self::Utf16Be => std::iter::once(0xFEFF)
+                .chain(text.encode_utf16())
+                .flat_map(u16::to_be_bytes)
+                .collect(),
+        }
+    }
+
+    fn decode_utf8(bytes: &[u8]) -> Result<String> {
+        String::from_utf8(bytes.to_vec()).map_err(|e| {
+            MdBookLintError::document_error(format!(
+                "unsupported encoding: not valid UTF-8 (invalid byte at offset {})",
+                e.utf8_error().valid_up_to()
+            ))
+        })
+    }
+
+    fn decode_utf16(bytes: &[u8], encoding: Self) -> Result<String> {
+        if !bytes.len().is_multiple_of(2) {
+            return Err(MdBookLintError::document_error(format!(
+                "unsupported encoding: {encoding} text has an odd number of bytes"
+            )));
+        }
+        let units = bytes.chunks_exact(2).map(|pair| {
+            let pair = [pair[0], pair[1]];
+            match encoding {
+                Self::Utf16Be => u16::from_be_bytes(pair),
+                _ => u16::from_le_bytes(pair),
+            }
+        });
+        char::decode_utf16(units)
+            .collect::<std::result::Result<String, _>>()
+            .map_err(|e| {
+                MdBookLintError::document_error(format!(
+                    "unsupported encoding: invalid {encoding} ({e})"
+                ))
+            })
+    }
+}
+
+impl std::fmt::Display for SourceEncoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SourceEncoding::Utf8 => write!(f, "UTF-8"),
+            SourceEncoding::Utf8Bom => write!(f, "UTF-8 with BOM"),
+            SourceEncoding::Utf16Le => write!(f, "UTF-16LE"),
+            SourceEncoding::Utf16Be => write!(f, "UTF-16BE"),
+        }
+    }
+}
+
+/// Line terminator used by a text
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    /// The ending of the first line of `text`, or `Lf` when it has no line
+    /// breaks
+    pub fn detect(text: &str) -> Self {
+        match text.find('\n') {
+            Some(at) if text[..at].ends_with('\r') => Self::CrLf,
+            _ => Self::Lf,
+        }
+    }
+
+    /// The terminator itself
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+
+    /// `text` with every bare `\n` written in this style
+    ///
+    /// `\r\n` already in the text is left alone, so normalizing twice is
+    /// harmless. `Lf` never changes the text.
+    pub fn normalize(self, text: &str) -> Cow<'_, str> {
+        if self == Self::Lf || !text.contains('\n') {
+            return Cow::Borrowed(text);
+        }
+        let mut normalized = String::with_capacity(text.len() + text.len() / 16);
+        for line in text.split_inclusive('\n') {
+            match line.strip_suffix('\n') {
+                Some(body) => {
+                    normalized.push_str(body.strip_suffix('\r').unwrap_or(body));
+                    normalized.push_str("\r\n");
+                }
+                None => normalized.push_str(line),
+            }
+        }
+        Cow::Owned(normalized)
+    }
+}
+
+impl std::fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineEnding::Lf => write!(f, "LF"),
+            LineEnding::CrLf => write!(f, "CRLF"),
+        }
+    }
+}
+
+/// Syntax of a frontmatter block
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    /// YAML between `---` delimiters
+    Yaml,
+    /// TOML between `+++` delimiters
+    Toml,
+}
+
+impl std::fmt::Display for FrontMatterFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrontMatterFormat::Yaml => write!(f, "YAML"),
+            FrontMatterFormat::Toml => write!(f, "TOML"),
+        }
+    }
+}
+
+/// A frontmatter block at the start of a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrontMatter {
+    /// Syntax of the block
+    pub format: FrontMatterFormat,
+    /// Line of the opening delimiter (1-based)
+    pub start_line: usize,
+    /// Line of the closing delimiter (1-based)
+    pub end_line: usize,
+    /// Text between the delimiters
+    pub raw: String,
+}
+
+impl FrontMatter {
+    /// Parse the block into a JSON value tree
+    ///
+    /// Both formats are normalized to `serde_json::Value` so rules can inspect
+    /// keys without caring about the syntax. An empty block parses to an empty
+    /// object, and TOML datetimes become strings in their original form.
+    pub fn parse(&self) -> Result<serde_json::Value> {
+        match self.format {
+            FrontMatterFormat::Yaml => {
+                let value: serde_json::Value = serde_yaml::from_str(&self.raw)?;
+                Ok(if value.is_null() {
+                    serde_json::Value::Object(serde_json::Map::new())
+                } else {
+                    value
+                })
+            }
+            FrontMatterFormat::Toml => {
+                let table: toml::value::Table = toml::from_str(&self.raw)?;
+                Ok(toml_to_json(toml::Value::Table(table)))
+            }
+        }
+    }
+
+    /// Find the document line (1-based) where a top-level key is defined
+    ///
+    /// Used to point violations at the offending key rather than the opening
+    /// delimiter. Only unindented `key:` (YAML) or `key =` (TOML) lines match.
+    pub fn key_line(&self, key: &str) -> Option<usize> {
+        let separator = match self.format {
+            FrontMatterFormat::Yaml => ':',
+            FrontMatterFormat::Toml => '=',
+        };
+
+        self.raw
+            .lines()
+            .position(|line| {
+                if line.starts_with(char::is_whitespace) {
+                    return false;
+                }
+                let Some((name, _)) = line.split_once(separator) else {
+                    return false;
+                };
+                name.trim().trim_matches(|c| c == '"' || c == '\'') == key
+            })
+            .map(|idx| self.start_line + idx + 1)
+    }
+}
+
+/// Convert a TOML value into the equivalent JSON value
+fn toml_to_json(value: toml::Value) -> serde_json::Value {
+    match value {
+        toml::Value::String(s) => serde_json::Value::String(s),
+        toml::Value::Integer(i) => serde_json::Value::from(i),
+        toml::Value::Float(f) => serde_json::Value::from(f),
+        toml::Value::Boolean(b) => serde_json::Value::Bool(b),
+        toml::Value::Datetime(dt) => serde_json::Value::String(dt.to_string()),
+        toml::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(toml_to_json).collect())
+        }
+        toml::Value::Table(table) => serde_json::Value::Object(
+            table
+                .into_iter()
+                .map(|(k, v)| (k, toml_to_json(v)))
+                .collect(),
+        ),
+    }
 }
 
 impl Document {
@@ -22,6 +341,20 @@ impl Document {
         Self::with_book_src_dir(content, path, None)
     }
 
+    /// Parse a markdown document from raw file bytes
+    ///
+    /// The encoding is detected with [`SourceEncoding::decode`]; files that
+    /// cannot be decoded return a [`MdBookLintError::Document`] error whose
+    /// message starts with `unsupported encoding`.
+    pub fn from_bytes(bytes: &[u8], path: PathBuf) -> Result<Self> {
+        let (content, encoding) = SourceEncoding::decode(bytes)?;
+        let mut document = Self::new(content, path)?;
+        if encoding != SourceEncoding::Utf8 {
+            document.encoding = encoding;
+        }
+        Ok(document)
+    }
+
     /// Parse a markdown document with an optional book source directory
     ///
     /// The book_src_dir is used by mdbook rules (e.g., MDBOOK002) for resolving
@@ -29,24 +362,69 @@ impl Document {
     /// directly from the filesystem, this can be None and the rules will discover
     /// the book source directory by looking for SUMMARY.md.
     pub fn with_book_src_dir(
-        content: String,
+        mut content: String,
         path: PathBuf,
         book_src_dir: Option<PathBuf>,
     ) -> Result<Self> {
         // Allow empty documents for edge case handling
         // Some rules need to handle empty files correctly
 
+        // A byte order mark is not content; leaving it in would put an
+        // invisible character before the first heading
+        let encoding = if content.starts_with('\u{FEFF}') {
+            content.drain(..'\u{FEFF}'.len_utf8());
+            SourceEncoding::Utf8Bom
+        } else {
+            SourceEncoding::Utf8
+        };
+
         // Split content into lines for line-based rules
         let lines: Vec<String> = content.lines().map(|s| s.to_owned()).collect();
 
+        let kind = DocumentKind::detect(&path);
         Ok(Document {
             content,
             path,
             lines,
             book_src_dir,
+            encoding,
+            index: OnceLock::new(),
+            discovered_src_dir: OnceLock::new(),
+            outline: OnceLock::new(),
+            regions: OnceLock::new(),
+            extensions: MarkdownExtensions::default(),
+            kind,
         })
     }
 
+    /// The same document, parsed with `extensions`
+    pub fn with_extensions(mut self, extensions: MarkdownExtensions) -> Self {
+        if extensions != self.extensions {
+            self.extensions = extensions;
+            self.index = OnceLock::new();
+            self.outline = OnceLock::new();
+            self.regions = OnceLock::new();
+        }
+        self
+    }
+
+    /// Markdown extensions the document is parsed with
+    pub fn extensions(&self) -> &MarkdownExtensions {
+        &self.extensions
+    }
+
+    /// The same document, treated as `kind` rather than the kind its path
+    /// suggests
+    pub fn with_kind(mut self, kind: DocumentKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// What the document is for, which decides the rules that apply to it
+    pub fn kind(&self) -> DocumentKind {
+        self.kind
+    }
+
     /// Parse the content into a comrak AST
     pub fn parse_ast<'a>(&self, arena: &'a Arena<AstNode<'a>>) -> &'a AstNode<'a> {
         // Configure comrak options for position tracking and compatibility
@@ -55,11 +433,11 @@ impl Document {
         options.extension.tagfilter = false;
         options.extension.table = true;
         options.extension.autolink = true;
-        options.extension.tasklist = true;
-        options.extension.superscript = false;
+        options.extension.tasklist = self.extensions.tasklists;
+        options.extension.superscript = self.extensions.superscript;
         options.extension.header_ids = None;
-        options.extension.footnotes = true;
-        options.extension.description_lists = true;
+        options.extension.footnotes = self.extensions.footnotes;
+        options.extension.description_lists = self.extensions.description_lists;
         options.extension.front_matter_delimiter = Some("---".to_owned());
         options.parse.smart = false;
         options.parse.default_info_string = None;
@@ -87,28 +465,56 @@ impl Document {
         Ok(ast)
     }
 
+    /// The line ending of the document's first line
+    pub fn line_ending(&self) -> LineEnding {
+        LineEnding::detect(&self.content)
+    }
+
+    /// Precomputed line offsets and verbatim regions (code blocks, HTML
+    /// blocks, code spans, and math), shared by all rules
+    ///
+    /// Built from a parse of the document on first use and cached for the
+    /// lifetime of the document.
+    pub fn index(&self) -> &DocumentIndex {
+        self.index.get_or_init(|| {
+            let arena = Arena::new();
+            let ast = self.parse_ast(&arena);
+            DocumentIndex::from_ast(&self.content, &self.lines, ast)
+        })
+    }
+
+    /// Headings with their levels, titles, anchors, and section line ranges
+    ///
+    /// Built from a parse of the document on first use and cached for the
+    /// lifetime of the document.
+    pub fn outline(&self) -> &Outline {
+        self.outline.get_or_init(|| {
+            let arena = Arena::new();
+            let ast = self.parse_ast(&arena);
+            Outline::from_ast(ast, self.lines.len())
+        })
+    }
+
+    /// Which lines are in code blocks, tables, headings, and blockquotes
+    ///
+    /// Built from a parse of the document on first use and cached for the
+    /// lifetime of the document.
+    pub fn regions(&self) -> &RegionMap {
+        self.regions.get_or_init(|| {
+            let arena = Arena::new();
+            let ast = self.parse_ast(&arena);
+            RegionMap::from_ast(ast, self.lines.len())
+        })
+    }
+
     /// Get the line number (1-based) for a given byte offset
     pub fn line_number_at_offset(&self, offset: usize) -> usize {
-        let mut current_offset = 0;
-        for (line_idx, line) in self.lines.iter().enumerate() {
-            if current_offset + line.len() >= offset {
-                return line_idx + 1; // 1-based line numbers
-            }
-            current_offset += line.len() + 1; // +1 for newline
-        }
-        self.lines.len() // Return last line if offset is at end
+        self.index().line_number_at_offset(offset)
     }
 
     /// Get the column number (1-based) for a given byte offset
     pub fn column_number_at_offset(&self, offset: usize) -> usize {
-        let mut current_offset = 0;
-        for line in &self.lines {
-            if current_offset + line.len() >= offset {
-                return offset - current_offset + 1; // 1-based column numbers
-            }
-            current_offset += line.len() + 1; // +1 for newline
-        }
-        1 // Default to column 1
+        self.index().column_number_at_offset(offset)
     }
 
     /// Get all heading nodes from the AST
@@ -195,6 +601,44 @@ impl Document {
         }
     }
 
+    /// The book's source directory
+    ///
+    /// This is [`Self::book_src_dir`] when it was given. Otherwise it is the
+    /// `book.src` directory (default `src`) of the nearest `book.toml` above
+    /// the document whose source directory contains it, as mdBook reads it,
+    /// and failing that the nearest directory above the document that
+    /// contains `SUMMARY.md`. A `SUMMARY.md` in a subdirectory of a book's
+    /// source directory does not start a new book.
+    pub fn src_dir(&self) -> Option<&Path> {
+        if let Some(dir) = &self.book_src_dir {
+            return Some(dir);
+        }
+        self.discovered_src_dir
+            .get_or_init(|| {
+                let mut ancestors = self.path.ancestors().skip(1);
+                ancestors
+                    .clone()
+                    .filter_map(crate::book::book_toml_src_dir)
+                    .find(|src| self.path.starts_with(src))
+                    .or_else(|| {
+                        ancestors
+                            .find(|dir| dir.join("SUMMARY.md").is_file())
+                            .map(Path::to_path_buf)
+                    })
+            })
+            .as_deref()
+    }
+
+    /// Resolve a link or image URL to a local file referenced by this document
+    ///
+    /// Returns `None` for remote URLs. The returned [`Asset`] gives rules
+    /// read access to the file, whether or not it exists; see
+    /// [`Asset::resolve`] for how paths are looked up.
+    pub fn asset(&self, url: &str) -> Option<Asset> {
+        let reference = asset::local_reference(url)?;
+        Some(Asset::resolve(reference, &self.path, self.src_dir()))
+    }
+
     /// Get the source position of a node
     pub fn node_position<'a>(&self, node: &'a AstNode<'a>) -> Option<(usize, usize)> {
         let sourcepos = node.data.borrow().sourcepos;
@@ -205,34 +649,63 @@ impl Document {
         }
     }
 
-    /// Detect a leading YAML frontmatter block.
+    /// Detect a leading YAML or TOML frontmatter block.
     ///
-    /// Recognition matches comrak's front-matter extension (configured in
+    /// YAML recognition matches comrak's front-matter extension (configured in
     /// [`Self::parse_ast`] with a `---` delimiter): the block must begin on the
     /// very first line of the document, its opening and closing delimiter lines
     /// must each be exactly `---` (no indentation, no trailing whitespace, no
-    /// extra dashes), and a closing delimiter must be present. Returns the
-    /// 1-based, inclusive `(start_line, end_line)` of the block with the
-    /// delimiters included, or `None` when the document has no frontmatter.
+    /// extra dashes), and a closing delimiter must be present. TOML frontmatter
+    /// follows the same rules with `+++` delimiters. Returns the 1-based,
+    /// inclusive `(start_line, end_line)` of the block with the delimiters
+    /// included, or `None` when the document has no frontmatter.
     ///
     /// Line-based rules use this to avoid treating frontmatter delimiters and
     /// YAML keys as Markdown headings or lists (see MD041, MD007).
     pub fn frontmatter_line_range(&self) -> Option<(usize, usize)> {
         // comrak only recognizes frontmatter that begins on the first line; a
         // leading blank line disables it, so check line 1 directly.
-        if self.lines.first().map(String::as_str) != Some("---") {
-            return None;
-        }
+        let delimiter = match self.lines.first().map(String::as_str) {
+            Some(line @ ("---" | "+++")) => line,
+            _ => return None,
+        };
 
-        // Find the closing delimiter. comrak requires an exact `---` line.
+        // Find the closing delimiter, which must match the opening one exactly.
         self.lines
             .iter()
             .enumerate()
             .skip(1)
-            .find(|(_, line)| line.as_str() == "---")
+            .find(|(_, line)| line.as_str() == delimiter)
             .map(|(idx, _)| (1, idx + 1))
     }
 
+    /// Whether a 1-based line number falls inside the frontmatter block,
+    /// delimiters included
+    pub fn is_frontmatter_line(&self, line: usize) -> bool {
+        self.frontmatter_line_range()
+            .is_some_and(|(start, end)| (start..=end).contains(&line))
+    }
+
+    /// Extract the leading frontmatter block, if any
+    ///
+    /// The block is located with [`Self::frontmatter_line_range`]; use
+    /// [`FrontMatter::parse`] to inspect its keys.
+    pub fn front_matter(&self) -> Option<FrontMatter> {
+        let (start_line, end_line) = self.frontmatter_line_range()?;
+        let format = if self.lines[0] == "+++" {
+            FrontMatterFormat::Toml
+        } else {
+            FrontMatterFormat::Yaml
+        };
+
+        Some(FrontMatter {
+            format,
+            start_line,
+            end_line,
+            raw: self.lines[start_line..end_line - 1].join("\n"),
+        })
+    }
+
     /// Number of leading source lines that comrak folds into a front-matter node.
     ///
     /// When frontmatter is present, comrak renumbers every following node as if
@@ -363,6 +836,17 @@ mod tests {
         assert_eq!(doc.lines[3], "Line 4");
     }
 
+    #[test]
+    fn test_line_ending() {
+        assert_eq!(LineEnding::detect("a\r\nb\nc"), LineEnding::CrLf);
+        assert_eq!(LineEnding::detect("a\nb\r\n"), LineEnding::Lf);
+        assert_eq!(LineEnding::detect("no breaks"), LineEnding::Lf);
+
+        assert_eq!(LineEnding::CrLf.normalize("a\nb\r\nc"), "a\r\nb\r\nc");
+        assert_eq!(LineEnding::CrLf.normalize("a\r\n"), "a\r\n");
+        assert_eq!(LineEnding::Lf.normalize("a\r\nb\n"), "a\r\nb\n");
+    }
+
     #[test]
     fn test_document_with_only_newlines() {
         let content = "\n\n\n\n".to_string();
@@ -524,6 +1008,37 @@ title: Test
         );
     }
 
+    #[test]
+    fn test_extensions_change_the_ast() {
+        let content = "Term\n\n: Definition\n\nA note[^1] and ^super^.\n\n[^1]: Note.\n";
+        let count = |doc: &Document, matches: fn(&NodeValue) -> bool| {
+            let arena = Arena::new();
+            doc.parse_ast(&arena)
+                .descendants()
+                .filter(|node| matches(&node.data.borrow().value))
+                .count()
+        };
+        let description = |value: &NodeValue| matches!(value, NodeValue::DescriptionList);
+        let footnote = |value: &NodeValue| matches!(value, NodeValue::FootnoteReference(_));
+        let superscript = |value: &NodeValue| matches!(value, NodeValue::Superscript);
+
+        let doc = Document::new(content.to_string(), PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.extensions(), &MarkdownExtensions::default());
+        assert_eq!(count(&doc, description), 1);
+        assert_eq!(count(&doc, footnote), 1);
+        assert_eq!(count(&doc, superscript), 0);
+
+        let doc = doc.with_extensions(MarkdownExtensions {
+            footnotes: false,
+            description_lists: false,
+            superscript: true,
+            ..MarkdownExtensions::default()
+        });
+        assert_eq!(count(&doc, description), 0);
+        assert_eq!(count(&doc, footnote), 0);
+        assert_eq!(count(&doc, superscript), 1);
+    }
+
     #[test]
     fn test_frontmatter_line_range_detected() {
         let content = "---\ntitle: My Document\nstatus: accepted\n---\n\n# My Document\n";
@@ -531,6 +1046,44 @@ title: Test
         assert_eq!(doc.frontmatter_line_range(), Some((1, 4)));
     }
 
+    #[test]
+    fn test_bom_is_stripped() {
+        let doc = Document::new("\u{FEFF}# Title\n".to_string(), PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.lines[0], "# Title");
+        assert_eq!(doc.encoding, SourceEncoding::Utf8Bom);
+
+        let doc = Document::from_bytes(b"\xEF\xBB\xBF# Title\n", PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.content, "# Title\n");
+        assert_eq!(doc.encoding, SourceEncoding::Utf8Bom);
+    }
+
+    #[test]
+    fn test_utf16_is_transcoded_and_round_trips() {
+        for encoding in [SourceEncoding::Utf16Le, SourceEncoding::Utf16Be] {
+            let bytes = encoding.encode("# Café\n");
+            let doc = Document::from_bytes(&bytes, PathBuf::from("t.md")).unwrap();
+            assert_eq!(doc.content, "# Café\n");
+            assert_eq!(doc.encoding, encoding);
+            assert_eq!(doc.encoding.encode(&doc.content), bytes);
+        }
+
+        // Without a byte order mark
+        let bytes: Vec<u8> = "# Hi\n".encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let doc = Document::from_bytes(&bytes, PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.content, "# Hi\n");
+        assert_eq!(doc.encoding, SourceEncoding::Utf16Le);
+    }
+
+    #[test]
+    fn test_undecodable_bytes_are_an_encoding_error() {
+        let err = Document::from_bytes(b"# Caf\xE9\n", PathBuf::from("t.md")).unwrap_err();
+        assert!(err.to_string().contains("unsupported encoding"));
+        assert!(err.to_string().contains("offset 5"));
+
+        let err = Document::from_bytes(b"\xFF\xFE#\x00\x00", PathBuf::from("t.md")).unwrap_err();
+        assert!(err.to_string().contains("odd number of bytes"));
+    }
+
     #[test]
     fn test_frontmatter_line_range_none_without_frontmatter() {
         let doc = Document::new("# Heading\n\nBody.\n".to_string(), PathBuf::from("t.md")).unwrap();
@@ -559,6 +1112,63 @@ title: Test
         assert_eq!(doc.frontmatter_line_range(), None);
     }
 
+    #[test]
+    fn test_frontmatter_line_range_toml() {
+        let content = "+++\ntitle = \"x\"\n+++\n\n# H\n";
+        let doc = Document::new(content.to_string(), PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.frontmatter_line_range(), Some((1, 3)));
+        assert!(doc.is_frontmatter_line(2));
+        assert!(!doc.is_frontmatter_line(5));
+
+        // Delimiters must match
+        let doc =
+            Document::new("+++\ntitle = 1\n---\n".to_string(), PathBuf::from("t.md")).unwrap();
+        assert_eq!(doc.frontmatter_line_range(), None);
+    }
+
+    #[test]
+    fn test_front_matter_yaml_parse() {
+        let content = "---\ntitle: My Document\ntags:\n  - a\n  - b\n---\n\n# H\n";
+        let doc = Document::new(content.to_string(), PathBuf::from("t.md")).unwrap();
+        let fm = doc.front_matter().unwrap();
+
+        assert_eq!(fm.format, FrontMatterFormat::Yaml);
+        assert_eq!(fm.raw, "title: My Document\ntags:\n  - a\n  - b");
+        let value = fm.parse().unwrap();
+        assert_eq!(value["title"], "My Document");
+        assert_eq!(value["tags"][1], "b");
+        assert_eq!(fm.key_line("title"), Some(2));
+        assert_eq!(fm.key_line("tags"), Some(3));
+        assert_eq!(fm.key_line("a"), None);
+    }
+
+    #[test]
+    fn test_front_matter_toml_parse() {
+        let content = "+++\ntitle = \"Doc\"\ndate = 2024-01-15\ndraft = true\n+++\n";
+        let doc = Document::new(content.to_string(), PathBuf::from("t.md")).unwrap();
+        let fm = doc.front_matter().unwrap();
+
+        assert_eq!(fm.format, FrontMatterFormat::Toml);
+        let value = fm.parse().unwrap();
+        assert_eq!(value["title"], "Doc");
+        assert_eq!(value["date"], "2024-01-15");
+        assert_eq!(value["draft"], true);
+        assert_eq!(fm.key_line("date"), Some(3));
+    }
+
+    #[test]
+    fn test_front_matter_empty_and_invalid() {
+        let doc = Document::new("---\n---\n# H\n".to_string(), PathBuf::from("t.md")).unwrap();
+        let value = doc.front_matter().unwrap().parse().unwrap();
+        assert!(value.as_object().unwrap().is_empty());
+
+        let doc = Document::new("---\ntitle: [\n---\n".to_string(), PathBuf::from("t.md")).unwrap();
+        assert!(doc.front_matter().unwrap().parse().is_err());
+
+        let doc = Document::new("# H\n".to_string(), PathBuf::from("t.md")).unwrap();
+        assert!(doc.front_matter().is_none());
+    }
+
     #[test]
     fn test_frontmatter_ast_offset_matches_comrak() {
         // The heading below sits on source line 6 but comrak reports it at line 1
@@ -647,4 +1257,25 @@ Final paragraph.
             "Expected multiple lines in complex document"
         );
     }
+
+    #[test]
+    fn test_src_dir_follows_book_toml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        let src = root.join("docs");
+        std::fs::create_dir_all(src.join("guide")).unwrap();
+        std::fs::write(root.join("book.toml"), "[book]\nsrc = \"docs\"\n").unwrap();
+        std::fs::write(src.join("SUMMARY.md"), "- [Guide](guide/README.md)\n").unwrap();
+        // A sub-page SUMMARY.md is a chapter, not another book
+        std::fs::write(src.join("guide/SUMMARY.md"), "# Contents\n").unwrap();
+
+        let chapter = Document::new("# Setup\n".to_string(), src.join("guide/setup.md")).unwrap();
+        assert_eq!(chapter.src_dir(), Some(src.as_path()));
+
+        // Files outside `book.src` fall back to the nearest SUMMARY.md
+        std::fs::create_dir_all(root.join("notes")).unwrap();
+        std::fs::write(root.join("notes/SUMMARY.md"), "").unwrap();
+        let note = Document::new("# Note\n".to_string(), root.join("notes/a.md")).unwrap();
+        assert_eq!(note.src_dir(), Some(root.join("notes").as_path()));
+    }
 }
@@ -0,0 +1,68 @@
+//! Edit distance between short strings
+//!
+//! Used to suggest the name a user probably meant, such as a rule ID in the
+//! configuration or a directive in a document.
+
+/// Levenshtein distance: the number of single-character insertions,
+/// deletions and substitutions that turn `a` into `b`
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    distance(a, b, false)
+}
+
+/// Edit distance that also counts swapping two adjacent characters as one
+/// edit, so `rsut` is one typo away from `rust`
+///
+/// This is the optimal string alignment distance: no substring is edited
+/// twice.
+pub fn typo_distance(a: &str, b: &str) -> usize {
+    distance(a, b, true)
+}
+
+fn distance(a: &str, b: &str, transpositions: bool) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // Rows `i - 2` and `i - 1` of the table, then row `i`
+    let mut before: Vec<usize> = Vec::new();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for i in 1..=a.len() {
+        let mut current = vec![i];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut cell = (previous[j - 1] + cost)
+                .min(previous[j] + 1)
+                .min(current[j - 1] + 1);
+            if transpositions && i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                cell = cell.min(before[j - 2] + 1);
+            }
+            current.push(cell);
+        }
+        before = std::mem::replace(&mut previous, current);
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("include", "include"), 0);
+        assert_eq!(levenshtein("includ", "include"), 1);
+        assert_eq!(levenshtein("inlcude", "include"), 2);
+        assert_eq!(levenshtein("each", "title"), 5);
+        assert_eq!(levenshtein("", "md013"), 5);
+        assert_eq!(levenshtein("md013", ""), 5);
+        assert_eq!(levenshtein("méd", "med"), 1);
+    }
+
+    #[test]
+    fn test_typo_distance() {
+        assert_eq!(typo_distance("rust", "rust"), 0);
+        assert_eq!(typo_distance("rsut", "rust"), 1);
+        assert_eq!(typo_distance("pyhton", "python"), 1);
+        assert_eq!(typo_distance("shell-session", "shellsession"), 1);
+        assert_eq!(typo_distance("mermaid", "rust"), 6);
+        assert_eq!(levenshtein("rsut", "rust"), 2);
+    }
+}
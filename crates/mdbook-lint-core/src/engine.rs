@@ -2,6 +2,7 @@
 
 use crate::config::Config;
 use crate::error::Result;
+use crate::fix::{FixEngine, resolve_range};
 use crate::registry::RuleRegistry;
 use serde_json::Value;
 
@@ -107,6 +108,10 @@ impl PluginRegistry {
             provider.register_rules_with_config(&mut registry, config);
         }
 
+        if let Some(config) = config {
+            crate::custom::register_custom_rules(&mut registry, &config.custom_rules)?;
+        }
+
         Ok(registry)
     }
 
@@ -202,6 +207,90 @@ impl LintEngine {
             .check_document_optimized_with_config(document, config)
     }
 
+    /// Lint a document with specific configuration and project context
+    ///
+    /// Use this to share one [`crate::LintContext`] between the documents of
+    /// a book, or to pass [`crate::LintContext::detached`] for content that
+    /// should not cause any file reads.
+    pub fn lint_document_with_context(
+        &self,
+        document: &crate::Document,
+        config: &crate::Config,
+        context: &crate::LintContext,
+    ) -> Result<Vec<crate::Violation>> {
+        self.registry
+            .check_document_timed_with_context(document, config, context)
+            .map(|(violations, _)| violations)
+    }
+
+    /// Lint a document, stopping early once `token` is cancelled
+    ///
+    /// The token is checked before each rule runs; a cancelled or timed out
+    /// lint returns [`crate::MdBookLintError::Cancelled`] instead of partial
+    /// results.
+    pub fn lint_document_cancellable(
+        &self,
+        document: &crate::Document,
+        config: &crate::Config,
+        token: &crate::CancellationToken,
+    ) -> Result<Vec<crate::Violation>> {
+        let context = crate::LintContext::for_document(document).with_cancellation(token.clone());
+        self.lint_document_with_context(document, config, &context)
+    }
+
+    /// Lint a document with specific configuration, timing each rule
+    ///
+    /// Returns the same violations as [`Self::lint_document_with_config`]
+    /// along with the parse time and the time spent in every rule that ran.
+    pub fn lint_document_timed_with_config(
+        &self,
+        document: &crate::Document,
+        config: &crate::Config,
+    ) -> Result<(Vec<crate::Violation>, crate::DocumentTiming)> {
+        self.registry
+            .check_document_timed_with_config(document, config)
+    }
+
+    /// Lint several documents in parallel
+    ///
+    /// Each document is parsed once and its AST and [`crate::DocumentIndex`]
+    /// are shared by every rule that runs on it. Results are returned in the
+    /// same order as `documents`.
+    pub fn lint_documents(
+        &self,
+        documents: &[crate::Document],
+    ) -> Result<Vec<Vec<crate::Violation>>> {
+        self.lint_documents_with_config(documents, &Config::default())
+    }
+
+    /// Lint several documents in parallel with specific configuration
+    pub fn lint_documents_with_config(
+        &self,
+        documents: &[crate::Document],
+        config: &crate::Config,
+    ) -> Result<Vec<Vec<crate::Violation>>> {
+        use rayon::prelude::*;
+        use std::collections::HashMap;
+
+        // Documents of the same book share one context, so SUMMARY.md and
+        // book.toml are read once per book
+        let mut contexts: HashMap<Option<&std::path::Path>, crate::LintContext> = HashMap::new();
+        for document in documents {
+            let src_dir = document.src_dir();
+            contexts
+                .entry(src_dir)
+                .or_insert_with(|| crate::LintContext::for_src_dir(src_dir.map(Into::into)));
+        }
+
+        documents
+            .par_iter()
+            .map(|document| {
+                let context = &contexts[&document.src_dir()];
+                self.lint_document_with_context(document, config, context)
+            })
+            .collect()
+    }
+
     /// Lint content string directly (convenience method)
     ///
     /// # Arguments
@@ -231,33 +320,22 @@ impl LintEngine {
     /// ```
     pub fn apply_fix(&self, content: &str, violation: &crate::Violation) -> Option<String> {
         let fix = violation.fix.as_ref()?;
-
-        let start_offset = position_to_offset(content, &fix.start)?;
-        let mut end_offset = position_to_offset(content, &fix.end)?;
-
-        // Handle newline duplication: if the replacement ends with a newline and
-        // the original content has a newline at the end position, skip it to avoid
-        // double newlines. This is a common pattern when rules create fixes that
-        // replace entire lines including their trailing newline.
         let replacement = fix.replacement.as_deref().unwrap_or("");
-        if replacement.ends_with('\n') && content.as_bytes().get(end_offset) == Some(&b'\n') {
-            end_offset += 1;
-        }
+        let (start, end) = resolve_range(content, &fix.start, &fix.end, replacement)?;
 
-        if start_offset <= end_offset && end_offset <= content.len() {
-            let mut result = content.to_string();
-            result.replace_range(start_offset..end_offset, replacement);
-            Some(result)
-        } else {
-            None
-        }
+        let mut result = content.to_string();
+        result.replace_range(start..end, replacement);
+        Some(result)
     }
 
     /// Apply all available fixes to content
     ///
-    /// Applies fixes from violations that have them, processing in reverse position
-    /// order to avoid offset issues. Returns the fixed content and a list of
-    /// violations that could not be fixed.
+    /// This is a single pass of [`FixEngine::apply`] with unsafe fixes
+    /// enabled: overlapping fixes are resolved deterministically and the
+    /// losers are left unfixed, as are suggestions. Returns the
+    /// fixed content and a list of violations that could not be fixed. Use
+    /// [`FixEngine`] directly to find out why a fix was skipped or to fix
+    /// until a fixpoint is reached.
     ///
     /// # Example
     ///
@@ -273,69 +351,17 @@ impl LintEngine {
         content: &str,
         violations: &[crate::Violation],
     ) -> (String, Vec<crate::Violation>) {
-        use std::cmp::Ordering;
-
-        if violations.is_empty() {
-            return (content.to_string(), Vec::new());
-        }
-
-        // Collect violations with fixes, along with their index for tracking unfixed ones
-        let mut fixable: Vec<(usize, &crate::Violation)> = violations
-            .iter()
-            .enumerate()
-            .filter(|(_, v)| v.fix.is_some())
-            .collect();
-
-        if fixable.is_empty() {
-            return (content.to_string(), violations.to_vec());
-        }
-
-        // Sort by position (descending) to avoid offset issues when applying
-        fixable.sort_by(|a, b| {
-            let fix_a = a.1.fix.as_ref().unwrap();
-            let fix_b = b.1.fix.as_ref().unwrap();
-            match fix_b.start.line.cmp(&fix_a.start.line) {
-                Ordering::Equal => fix_b.start.column.cmp(&fix_a.start.column),
-                other => other,
-            }
-        });
-
-        let mut result = content.to_string();
-        let mut applied_indices = std::collections::HashSet::new();
-
-        for (idx, violation) in &fixable {
-            let fix = violation.fix.as_ref().unwrap();
-
-            let start = position_to_offset(&result, &fix.start);
-            let mut end = position_to_offset(&result, &fix.end);
+        let report = FixEngine::new()
+            .with_unsafe_fixes(true)
+            .apply(content, violations);
 
-            // Handle newline duplication (see apply_fix for details)
-            let replacement = fix.replacement.as_deref().unwrap_or("");
-            if let Some(end_offset) = end
-                && replacement.ends_with('\n')
-                && result.as_bytes().get(end_offset) == Some(&b'\n')
-            {
-                end = Some(end_offset + 1);
-            }
-
-            if let (Some(start), Some(end)) = (start, end)
-                && start <= end
-                && end <= result.len()
-            {
-                result.replace_range(start..end, replacement);
-                applied_indices.insert(*idx);
-            }
-        }
-
-        // Collect violations that weren't fixed
         let unfixed: Vec<crate::Violation> = violations
             .iter()
-            .enumerate()
-            .filter(|(idx, v)| v.fix.is_none() || !applied_indices.contains(idx))
-            .map(|(_, v)| v.clone())
+            .filter(|v| v.fix.is_none() || !report.applied.contains(v))
+            .cloned()
             .collect();
 
-        (result, unfixed)
+        (report.content, unfixed)
     }
 
     /// Get all available rule IDs
@@ -375,31 +401,65 @@ impl LintEngine {
     pub fn has_collection_rules(&self) -> bool {
         self.registry.has_collection_rules()
     }
-}
-
-/// Convert a line/column position to a byte offset in text
-fn position_to_offset(text: &str, pos: &crate::violation::Position) -> Option<usize> {
-    let mut current_line = 1;
-    let mut current_col = 1;
 
-    for (offset, ch) in text.char_indices() {
-        if current_line == pos.line && current_col == pos.column {
-            return Some(offset);
+    /// Run the collection rules across `documents`, grouping their
+    /// violations by the file each belongs to
+    ///
+    /// Violations are filed under their [`path`](crate::Violation::path);
+    /// book-wide ones that name no file are filed under `unowned`.
+    pub fn lint_collection_by_file(
+        &self,
+        documents: &[crate::Document],
+        config: &crate::Config,
+        unowned: &std::path::Path,
+    ) -> Result<std::collections::BTreeMap<std::path::PathBuf, Vec<crate::Violation>>> {
+        let mut results = std::collections::BTreeMap::<_, Vec<_>>::new();
+        for violation in self.lint_collection_with_config(documents, config)? {
+            let path = violation
+                .path
+                .clone()
+                .unwrap_or_else(|| unowned.to_path_buf());
+            results.entry(path).or_default().push(violation);
         }
+        Ok(results)
+    }
+
+    /// Lint every document of a book and run the collection rules across them
+    ///
+    /// Returns the violations of each document, keyed by its path; documents
+    /// without violations map to an empty list. Collection violations are
+    /// filed as [`Self::lint_collection_by_file`] does, with book-wide ones
+    /// under the book's source directory (or an empty path for plain
+    /// directories).
+    ///
+    /// ```rust,ignore
+    /// let book = Book::load(&BookSource::detect("my-book"))?;
+    /// for (path, violations) in engine.lint_book(&book, &config)? {
+    ///     println!("{}: {} violations", path.display(), violations.len());
+    /// }
+    /// ```
+    pub fn lint_book(
+        &self,
+        book: &crate::Book,
+        config: &crate::Config,
+    ) -> Result<std::collections::BTreeMap<std::path::PathBuf, Vec<crate::Violation>>> {
+        let documents = &book.documents;
+        let per_document = self.lint_documents_with_config(documents, config)?;
+        let mut results: std::collections::BTreeMap<_, _> = documents
+            .iter()
+            .map(|document| document.path.clone())
+            .zip(per_document)
+            .collect();
 
-        if ch == '\n' {
-            current_line += 1;
-            current_col = 1;
-        } else {
-            current_col += 1;
+        let unowned = book.src_dir.clone().unwrap_or_default();
+        for (path, violations) in self.lint_collection_by_file(documents, config, &unowned)? {
+            results.entry(path).or_default().extend(violations);
         }
-    }
 
-    // Handle position at end of content
-    if current_line == pos.line && current_col == pos.column {
-        Some(text.len())
-    } else {
-        None
+        for violations in results.values_mut() {
+            violations.sort_by_key(|v| (v.line, v.column));
+        }
+        Ok(results)
     }
 }
 
@@ -530,6 +590,235 @@ mod tests {
         assert!(registry.get_provider("nonexistent").is_none());
     }
 
+    #[test]
+    fn test_lint_book() {
+        use crate::rule::CollectionRule;
+        use std::fs;
+
+        /// Flags documents whose first line isn't a heading
+        struct NoHeading;
+
+        impl Rule for NoHeading {
+            fn id(&self) -> &'static str {
+                "TEST002"
+            }
+            fn name(&self) -> &'static str {
+                "no-heading"
+            }
+            fn description(&self) -> &'static str {
+                "Documents start with a heading"
+            }
+            fn metadata(&self) -> RuleMetadata {
+                RuleMetadata::stable(RuleCategory::Structure)
+            }
+            fn check_with_ast<'a>(
+                &self,
+                document: &crate::Document,
+                _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+            ) -> Result<Vec<crate::Violation>> {
+                if document.content.starts_with('#') {
+                    return Ok(vec![]);
+                }
+                Ok(vec![self.create_violation(
+                    "No heading".to_string(),
+                    1,
+                    1,
+                    crate::Severity::Warning,
+                )])
+            }
+        }
+
+        /// Reports the last document, and one book-wide finding
+        struct LastDocument;
+
+        impl CollectionRule for LastDocument {
+            fn id(&self) -> &'static str {
+                "COLL001"
+            }
+            fn name(&self) -> &'static str {
+                "last-document"
+            }
+            fn description(&self) -> &'static str {
+                "Reports the last document"
+            }
+            fn metadata(&self) -> RuleMetadata {
+                RuleMetadata::stable(RuleCategory::Structure)
+            }
+            fn check_collection(
+                &self,
+                documents: &[crate::Document],
+            ) -> Result<Vec<crate::Violation>> {
+                let last = documents.last().unwrap();
+                Ok(vec![
+                    self.create_violation_for_file(
+                        &last.path,
+                        "Last".to_string(),
+                        2,
+                        1,
+                        crate::Severity::Info,
+                    ),
+                    self.create_violation("Book-wide".to_string(), 1, 1, crate::Severity::Info),
+                ])
+            }
+        }
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(
+            src.join("SUMMARY.md"),
+            "# Summary\n\n- [One](one.md)\n- [Two](two.md)\n",
+        )
+        .unwrap();
+        fs::write(src.join("one.md"), "# One\n").unwrap();
+        fs::write(src.join("two.md"), "Two\n").unwrap();
+
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(NoHeading));
+        registry.register_collection_rule(Box::new(LastDocument));
+        let engine = LintEngine::with_registry(registry);
+
+        let book = crate::Book::load(&crate::BookSource::detect(&src)).unwrap();
+        let results = engine.lint_book(&book, &Config::default()).unwrap();
+
+        let summary: Vec<(String, Vec<String>)> = results
+            .iter()
+            .map(|(path, violations)| {
+                let name = path.strip_prefix(dir.path()).unwrap().display().to_string();
+                let messages = violations
+                    .iter()
+                    .map(|v| format!("{} {}", v.rule_id, v.message))
+                    .collect();
+                (name, messages)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("src".to_string(), vec!["COLL001 Book-wide".to_string()]),
+                ("src/SUMMARY.md".to_string(), vec![]),
+                ("src/one.md".to_string(), vec![]),
+                (
+                    "src/two.md".to_string(),
+                    vec!["TEST002 No heading".to_string(), "COLL001 Last".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_documents_preserves_order() {
+        // Reports every line inside a fenced code block, using the shared index
+        struct CodeLineRule;
+
+        impl Rule for CodeLineRule {
+            fn id(&self) -> &'static str {
+                "TEST002"
+            }
+            fn name(&self) -> &'static str {
+                "code-lines"
+            }
+            fn description(&self) -> &'static str {
+                "Reports code block lines"
+            }
+            fn metadata(&self) -> RuleMetadata {
+                RuleMetadata::stable(RuleCategory::Content)
+            }
+            fn check_with_ast<'a>(
+                &self,
+                document: &crate::Document,
+                ast: Option<&'a comrak::nodes::AstNode<'a>>,
+            ) -> Result<Vec<crate::Violation>> {
+                assert!(ast.is_some(), "engine should share its parsed AST");
+                let index = document.index();
+                Ok((1..=index.line_count())
+                    .filter(|&line| index.is_code_block_line(line))
+                    .map(|line| {
+                        self.create_violation("code".to_string(), line, 1, crate::Severity::Info)
+                    })
+                    .collect())
+            }
+        }
+
+        let mut engine = LintEngine::new();
+        engine.registry_mut().register(Box::new(CodeLineRule));
+
+        let documents: Vec<crate::Document> = (0..8)
+            .map(|n| {
+                let content = format!("# Doc {n}\n\n```\n{}```\n", "code\n".repeat(n));
+                crate::Document::new(content, PathBuf::from(format!("doc{n}.md"))).unwrap()
+            })
+            .collect();
+
+        let results = engine.lint_documents(&documents).unwrap();
+        assert_eq!(results.len(), 8);
+        for (n, violations) in results.iter().enumerate() {
+            assert_eq!(violations.len(), n + 2);
+        }
+    }
+
+    #[test]
+    fn test_lint_document_cancellable() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+        // Cancels the lint it is part of, so later rules never run
+        struct CancellingRule(&'static str);
+
+        impl Rule for CancellingRule {
+            fn id(&self) -> &'static str {
+                self.0
+            }
+            fn name(&self) -> &'static str {
+                "cancelling"
+            }
+            fn description(&self) -> &'static str {
+                "Cancels the lint"
+            }
+            fn metadata(&self) -> RuleMetadata {
+                RuleMetadata::stable(RuleCategory::Content)
+            }
+            fn check_with_ast<'a>(
+                &self,
+                _document: &crate::Document,
+                _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+            ) -> Result<Vec<crate::Violation>> {
+                Ok(Vec::new())
+            }
+            fn check_with_context<'a>(
+                &self,
+                _document: &crate::Document,
+                _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+                context: &crate::LintContext,
+            ) -> Result<Vec<crate::Violation>> {
+                RUNS.fetch_add(1, Ordering::Relaxed);
+                context.cancellation().cancel();
+                Ok(Vec::new())
+            }
+        }
+
+        let mut engine = LintEngine::new();
+        engine
+            .registry_mut()
+            .register(Box::new(CancellingRule("TEST001")));
+        engine
+            .registry_mut()
+            .register(Box::new(CancellingRule("TEST002")));
+        let document = crate::Document::new("# Title\n".into(), PathBuf::from("a.md")).unwrap();
+        let config = Config::default();
+
+        let token = crate::CancellationToken::new();
+        let result = engine.lint_document_cancellable(&document, &config, &token);
+        assert!(matches!(result, Err(crate::MdBookLintError::Cancelled(_))));
+        assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+
+        let expired = crate::CancellationToken::with_deadline(std::time::Instant::now());
+        let result = engine.lint_document_cancellable(&document, &config, &expired);
+        assert!(matches!(result, Err(crate::MdBookLintError::Cancelled(_))));
+        assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+    }
+
     #[test]
     fn test_create_rule_registry() {
         let mut registry = PluginRegistry::new();
@@ -637,31 +926,43 @@ mod tests {
 
         // Line 1, column 1 = offset 0
         assert_eq!(
-            super::position_to_offset(text, &crate::violation::Position { line: 1, column: 1 }),
+            crate::fix::position_to_offset(
+                text,
+                &crate::violation::Position { line: 1, column: 1 }
+            ),
             Some(0)
         );
 
         // Line 1, column 3 = offset 2 ('n' in 'line1')
         assert_eq!(
-            super::position_to_offset(text, &crate::violation::Position { line: 1, column: 3 }),
+            crate::fix::position_to_offset(
+                text,
+                &crate::violation::Position { line: 1, column: 3 }
+            ),
             Some(2)
         );
 
         // Line 2, column 1 = offset 6 (after 'line1\n')
         assert_eq!(
-            super::position_to_offset(text, &crate::violation::Position { line: 2, column: 1 }),
+            crate::fix::position_to_offset(
+                text,
+                &crate::violation::Position { line: 2, column: 1 }
+            ),
             Some(6)
         );
 
         // Line 3, column 1 = offset 12
         assert_eq!(
-            super::position_to_offset(text, &crate::violation::Position { line: 3, column: 1 }),
+            crate::fix::position_to_offset(
+                text,
+                &crate::violation::Position { line: 3, column: 1 }
+            ),
             Some(12)
         );
 
         // Invalid position
         assert_eq!(
-            super::position_to_offset(
+            crate::fix::position_to_offset(
                 text,
                 &crate::violation::Position {
                     line: 10,
@@ -693,7 +994,10 @@ mod tests {
                     line: 1,
                     column: 12,
                 },
+                safety: crate::violation::FixSafety::Safe,
             }),
+            url: None,
+            path: None,
         };
 
         let result = engine.apply_fix(content, &violation);
@@ -713,6 +1017,8 @@ mod tests {
             column: 1,
             severity: crate::Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let result = engine.apply_fix(content, &violation);
@@ -737,7 +1043,10 @@ mod tests {
                     replacement: Some("AAA".to_string()),
                     start: crate::violation::Position { line: 1, column: 1 },
                     end: crate::violation::Position { line: 1, column: 4 },
+                    safety: crate::violation::FixSafety::Safe,
                 }),
+                url: None,
+                path: None,
             },
             crate::Violation {
                 rule_id: "TEST".to_string(),
@@ -754,7 +1063,10 @@ mod tests {
                         line: 1,
                         column: 12,
                     },
+                    safety: crate::violation::FixSafety::Safe,
                 }),
+                url: None,
+                path: None,
             },
         ];
 
@@ -784,7 +1096,10 @@ mod tests {
                         line: 1,
                         column: 12,
                     },
+                    safety: crate::violation::FixSafety::Safe,
                 }),
+                url: None,
+                path: None,
             },
             crate::Violation {
                 rule_id: "TEST2".to_string(),
@@ -794,6 +1109,8 @@ mod tests {
                 column: 1,
                 severity: crate::Severity::Warning,
                 fix: None,
+                url: None,
+                path: None,
             },
         ];
 
@@ -827,7 +1144,10 @@ mod tests {
                     column: 14,
                 }, // Points to the newline position
                 replacement: Some("# New Heading\n".to_string()),
+                safety: crate::violation::FixSafety::Safe,
             }),
+            url: None,
+            path: None,
         };
 
         let result = engine.apply_fix(content, &violation);
@@ -860,7 +1180,10 @@ mod tests {
                     column: 12,
                 },
                 replacement: Some("rust".to_string()),
+                safety: crate::violation::FixSafety::Safe,
             }),
+            url: None,
+            path: None,
         };
 
         let result = engine.apply_fix(content, &violation);
@@ -145,6 +145,10 @@ pub enum MdBookLintError {
     #[error("Registry error: {0}")]
     Registry(String),
 
+    /// The lint was cancelled or ran past its deadline
+    #[error("Lint cancelled: {0}")]
+    Cancelled(String),
+
     /// JSON serialization/deserialization errors
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
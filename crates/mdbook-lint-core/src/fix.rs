@@ -0,0 +1,766 @@
+//! Fix application engine.
+//!
+//! This module applies the [`Fix`]es attached to violations. Fixes are
+//! resolved against the original content up front, so conflicting edits are
+//! detected instead of being applied on top of each other:
+//!
+//! - Fixes are ordered by start offset; when two fixes touch overlapping text
+//!   the one that starts first (or, on a tie, covers more text) wins and the
+//!   other is reported as skipped
+//! - Identical fixes reported by more than one rule are applied once
+//! - Fixes whose positions do not exist in the content are skipped
+//! - Replacement text is written with the content's own line endings, so
+//!   fixes to a CRLF file keep it CRLF
+//! - A whole-line replacement of a last line that has no newline does not
+//!   add one, so it cannot stack with the end-of-file newline fix
+//! - Only [`FixSafety::Safe`] fixes are applied unless the engine is built
+//!   [`with_unsafe_fixes`](FixEngine::with_unsafe_fixes); suggestions are
+//!   never applied
+//!
+//! [`FixEngine::fix_until_stable`] re-lints after each pass, so fixes skipped
+//! because of a conflict get another chance against the updated content.
+//! [`FixEngine::verify`] lints the fixed content once more and reports rules
+//! whose fixes did not settle: a rule that still fires after its fix was
+//! applied, or fixes that left new violations behind.
+//!
+//! # Example
+//!
+//! ```rust
+//! use mdbook_lint_core::fix::FixEngine;
+//! use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+//!
+//! let violation = Violation {
+//!     rule_id: "MD009".to_string(),
+//!     rule_name: "no-trailing-spaces".to_string(),
+//!     message: "Trailing spaces".to_string(),
+//!     line: 1,
+//!     column: 6,
+//!     severity: Severity::Warning,
+//!     fix: Some(Fix {
+//!         description: "Remove trailing spaces".to_string(),
+//!         replacement: None,
+//!         start: Position { line: 1, column: 6 },
+//!         end: Position { line: 1, column: 8 },
+//!         safety: FixSafety::Safe,
+//!     }),
+//!     url: None,
+//!     path: None,
+//! };
+//!
+//! let report = FixEngine::new().apply("Hello  \n", &[violation]);
+//! assert_eq!(report.content, "Hello\n");
+//! assert_eq!(report.applied.len(), 1);
+//! assert!(report.skipped.is_empty());
+//! ```
+
+use crate::document::LineEnding;
+use crate::error::Result;
+use crate::violation::{FixSafety, Position, Violation};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+
+/// Default upper bound on the number of lint-and-fix passes
+pub const DEFAULT_MAX_PASSES: usize = 10;
+
+/// Why a fix was not applied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SkipReason {
+    /// The fix overlaps a fix that was applied in the same pass
+    Overlap {
+        /// Rule whose fix took precedence
+        rule_id: String,
+        /// Line of the violation whose fix took precedence
+        line: usize,
+    },
+    /// The same edit was already applied on behalf of another rule
+    Duplicate {
+        /// Rule whose identical fix was applied
+        rule_id: String,
+    },
+    /// The fix's start or end position does not exist in the content
+    InvalidPosition,
+}
+
+impl fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkipReason::Overlap { rule_id, line } => {
+                write!(f, "overlaps fix from {rule_id} at line {line}")
+            }
+            SkipReason::Duplicate { rule_id } => {
+                write!(f, "identical to fix from {rule_id}")
+            }
+            SkipReason::InvalidPosition => write!(f, "fix position is outside the document"),
+        }
+    }
+}
+
+/// A fix that the engine declined to apply
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkippedFix {
+    /// The violation carrying the skipped fix
+    pub violation: Violation,
+    /// Why the fix was skipped
+    pub reason: SkipReason,
+}
+
+/// A problem [`FixEngine::verify`] found with the fixes applied to a file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FixProblem {
+    /// A rule whose fixes were applied still reports a fixable violation, so
+    /// every further fix run would rewrite the file again
+    StillFires {
+        /// The violation reported on the fixed content
+        violation: Violation,
+    },
+    /// The fixed content has more fixable violations of a rule whose fixes
+    /// were not applied than the original
+    Introduced {
+        /// Rule with new violations
+        rule_id: String,
+        /// Fixable violations of the rule in the original content
+        before: usize,
+        /// Fixable violations of the rule in the fixed content
+        after: usize,
+    },
+}
+
+impl fmt::Display for FixProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixProblem::StillFires { violation } => write!(
+                f,
+                "{} still reports a fixable violation at line {} after its fix was applied",
+                violation.rule_id, violation.line
+            ),
+            FixProblem::Introduced {
+                rule_id,
+                before,
+                after,
+            } => write!(
+                f,
+                "fixes introduced {} new fixable {rule_id} violation(s) ({before} before, {after} after)",
+                after - before
+            ),
+        }
+    }
+}
+
+/// Outcome of applying fixes to a piece of content
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixReport {
+    /// Content after all applied fixes
+    pub content: String,
+    /// Violations whose fixes were applied, across all passes
+    pub applied: Vec<Violation>,
+    /// Fixes that were not applied in the final pass
+    pub skipped: Vec<SkippedFix>,
+    /// Number of passes that applied at least one fix
+    pub passes: usize,
+}
+
+impl FixReport {
+    /// Whether any fix changed the content
+    pub fn is_modified(&self, original: &str) -> bool {
+        self.content != original
+    }
+}
+
+/// A fix resolved to byte offsets in the content it applies to
+struct ResolvedFix<'a> {
+    start: usize,
+    end: usize,
+    replacement: Cow<'a, str>,
+    violation: &'a Violation,
+}
+
+/// Applies violation fixes with conflict detection
+#[derive(Debug, Clone)]
+pub struct FixEngine {
+    max_passes: usize,
+    unsafe_fixes: bool,
+}
+
+impl Default for FixEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FixEngine {
+    /// Create a fix engine with the default pass limit
+    pub fn new() -> Self {
+        Self {
+            max_passes: DEFAULT_MAX_PASSES,
+            unsafe_fixes: false,
+        }
+    }
+
+    /// Set the maximum number of passes used by [`Self::fix_until_stable`]
+    pub fn with_max_passes(mut self, max_passes: usize) -> Self {
+        self.max_passes = max_passes.max(1);
+        self
+    }
+
+    /// Also apply [`FixSafety::Unsafe`] fixes, as `--fix-unsafe` does
+    pub fn with_unsafe_fixes(mut self, unsafe_fixes: bool) -> Self {
+        self.unsafe_fixes = unsafe_fixes;
+        self
+    }
+
+    /// Whether fixes with the given safety are applied
+    pub fn allows(&self, safety: FixSafety) -> bool {
+        match safety {
+            FixSafety::Safe => true,
+            FixSafety::Unsafe => self.unsafe_fixes,
+            FixSafety::Suggestion => false,
+        }
+    }
+
+    /// Apply the fixes from `violations` to `content` in a single pass
+    ///
+    /// Violations without a fix, or whose fix is not [allowed](Self::allows),
+    /// are ignored. All positions are interpreted
+    /// against `content` as given, so the violations must come from linting
+    /// exactly this text.
+    pub fn apply(&self, content: &str, violations: &[Violation]) -> FixReport {
+        let mut skipped = Vec::new();
+        let mut resolved = Vec::new();
+        let line_ending = LineEnding::detect(content);
+
+        for violation in violations {
+            let Some(fix) = violation.fix.as_ref().filter(|fix| self.allows(fix.safety)) else {
+                continue;
+            };
+            let replacement = line_ending.normalize(fix.replacement.as_deref().unwrap_or(""));
+            match resolve_range(content, &fix.start, &fix.end, &replacement) {
+                Some((start, end)) => {
+                    let replacement =
+                        if start < end && end == content.len() && !content.ends_with('\n') {
+                            strip_line_ending(replacement)
+                        } else {
+                            replacement
+                        };
+                    resolved.push(ResolvedFix {
+                        start,
+                        end,
+                        replacement,
+                        violation,
+                    })
+                }
+                None => skipped.push(SkippedFix {
+                    violation: violation.clone(),
+                    reason: SkipReason::InvalidPosition,
+                }),
+            }
+        }
+
+        // Earliest start wins; on a tie prefer the wider edit, then order by
+        // rule ID so the result does not depend on rule execution order.
+        resolved.sort_by(|a, b| {
+            a.start
+                .cmp(&b.start)
+                .then(b.end.cmp(&a.end))
+                .then_with(|| a.violation.rule_id.cmp(&b.violation.rule_id))
+        });
+
+        let mut accepted: Vec<ResolvedFix> = Vec::new();
+        for fix in resolved {
+            if let Some(previous) = accepted.last() {
+                if previous.start == fix.start
+                    && previous.end == fix.end
+                    && previous.replacement == fix.replacement
+                {
+                    skipped.push(SkippedFix {
+                        violation: fix.violation.clone(),
+                        reason: SkipReason::Duplicate {
+                            rule_id: previous.violation.rule_id.clone(),
+                        },
+                    });
+                    continue;
+                }
+
+                // Two insertions at the same point have no well-defined order
+                let same_insertion_point = previous.start == previous.end
+                    && fix.start == fix.end
+                    && previous.start == fix.start;
+                if fix.start < previous.end || same_insertion_point {
+                    skipped.push(SkippedFix {
+                        violation: fix.violation.clone(),
+                        reason: SkipReason::Overlap {
+                            rule_id: previous.violation.rule_id.clone(),
+                            line: previous.violation.line,
+                        },
+                    });
+                    continue;
+                }
+            }
+            accepted.push(fix);
+        }
+
+        // Apply back to front so earlier offsets stay valid
+        let mut result = content.to_string();
+        for fix in accepted.iter().rev() {
+            result.replace_range(fix.start..fix.end, &fix.replacement);
+        }
+
+        FixReport {
+            content: result,
+            applied: accepted.iter().map(|fix| fix.violation.clone()).collect(),
+            skipped,
+            passes: usize::from(!accepted.is_empty()),
+        }
+    }
+
+    /// Repeatedly lint and fix `content` until no more fixes apply
+    ///
+    /// `lint` is called with the current content and should return the
+    /// violations whose fixes may be applied. Passes stop when a pass applies
+    /// nothing, the content stops changing, or the pass limit is reached. The
+    /// report's `skipped` list reflects the final pass only, since fixes
+    /// skipped earlier are regenerated and retried by later passes.
+    pub fn fix_until_stable<F>(&self, content: &str, mut lint: F) -> Result<FixReport>
+    where
+        F: FnMut(&str) -> Result<Vec<Violation>>,
+    {
+        let mut report = FixReport {
+            content: content.to_string(),
+            ..FixReport::default()
+        };
+
+        for _ in 0..self.max_passes {
+            let violations = lint(&report.content)?;
+            let pass = self.apply(&report.content, &violations);
+            report.skipped = pass.skipped;
+
+            if pass.applied.is_empty() || pass.content == report.content {
+                break;
+            }
+
+            report.content = pass.content;
+            report.applied.extend(pass.applied);
+            report.passes += 1;
+        }
+
+        Ok(report)
+    }
+}
+
+impl FixEngine {
+    /// Check that the fixes in `report` settled
+    ///
+    /// `lint` is called with `original` and with the fixed content and should
+    /// return every violation, fixable or not. Only violations carrying a fix
+    /// the engine would apply are checked, leaving out fixes skipped in the
+    /// final pass because they conflict with another one. A rule whose fix
+    /// was applied must not report another such violation, and no other rule
+    /// may report more of them than before. An empty result means running the
+    /// fixes again would change nothing.
+    ///
+    /// Violations without a fix are not counted: a fix that turns text into a
+    /// heading can rightly make a structural rule fire, and that violation is
+    /// reported like any other.
+    pub fn verify<F>(
+        &self,
+        original: &str,
+        report: &FixReport,
+        mut lint: F,
+    ) -> Result<Vec<FixProblem>>
+    where
+        F: FnMut(&str) -> Result<Vec<Violation>>,
+    {
+        let before = lint(original)?;
+        let after = lint(&report.content)?;
+        let fixed_rules: HashSet<&str> = report
+            .applied
+            .iter()
+            .map(|violation| violation.rule_id.as_str())
+            .collect();
+
+        let fixable = |violation: &&Violation| {
+            violation
+                .fix
+                .as_ref()
+                .is_some_and(|fix| self.allows(fix.safety))
+                && !report
+                    .skipped
+                    .iter()
+                    .any(|skipped| &skipped.violation == *violation)
+        };
+
+        let mut problems: Vec<FixProblem> = after
+            .iter()
+            .filter(fixable)
+            .filter(|violation| fixed_rules.contains(violation.rule_id.as_str()))
+            .map(|violation| FixProblem::StillFires {
+                violation: violation.clone(),
+            })
+            .collect();
+
+        let mut counts: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+        for violation in before.iter().filter(fixable) {
+            counts.entry(&violation.rule_id).or_default().0 += 1;
+        }
+        for violation in after.iter().filter(fixable) {
+            counts.entry(&violation.rule_id).or_default().1 += 1;
+        }
+        problems.extend(
+            counts
+                .into_iter()
+                .filter(|(rule_id, (before, after))| {
+                    after > before && !fixed_rules.contains(rule_id)
+                })
+                .map(|(rule_id, (before, after))| FixProblem::Introduced {
+                    rule_id: rule_id.to_string(),
+                    before,
+                    after,
+                }),
+        );
+
+        Ok(problems)
+    }
+}
+
+/// Drop one trailing `\n` or `\r\n` from a replacement
+fn strip_line_ending(replacement: Cow<'_, str>) -> Cow<'_, str> {
+    let trimmed = replacement
+        .strip_suffix('\n')
+        .map(|text| text.strip_suffix('\r').unwrap_or(text));
+    match trimmed {
+        Some(text) => Cow::Owned(text.to_string()),
+        None => replacement,
+    }
+}
+
+/// Resolve a fix's positions to a byte range in `content`
+///
+/// When the replacement ends with a newline and the range ends right before
+/// one (`\n` or `\r\n`), the range is extended over it so whole-line
+/// replacements don't leave a doubled newline behind. An empty range in the
+/// middle of a line is an insertion and is never extended, so inserting a
+/// newline at the end of a line adds a line break instead of doing nothing.
+pub(crate) fn resolve_range(
+    content: &str,
+    start: &Position,
+    end: &Position,
+    replacement: &str,
+) -> Option<(usize, usize)> {
+    let start = position_to_offset(content, start)?;
+    let mut end = position_to_offset(content, end)?;
+
+    let at_line_start = start == 0 || content[..start].ends_with('\n');
+    if replacement.ends_with('\n') && (start < end || at_line_start) {
+        if content[end..].starts_with("\r\n") {
+            end += 2;
+        } else if content[end..].starts_with('\n') {
+            end += 1;
+        }
+    }
+
+    (start <= end && end <= content.len()).then_some((start, end))
+}
+
+/// Convert a line/column position to a byte offset in text
+pub(crate) fn position_to_offset(text: &str, pos: &Position) -> Option<usize> {
+    let mut current_line = 1;
+    let mut current_col = 1;
+
+    for (offset, ch) in text.char_indices() {
+        if current_line == pos.line && current_col == pos.column {
+            return Some(offset);
+        }
+
+        if ch == '\n' {
+            current_line += 1;
+            current_col = 1;
+        } else {
+            current_col += 1;
+        }
+    }
+
+    // Handle position at end of content
+    if current_line == pos.line && current_col == pos.column {
+        Some(text.len())
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::violation::{Fix, FixSafety, Severity};
+
+    fn violation(
+        rule_id: &str,
+        start: (usize, usize),
+        end: (usize, usize),
+        text: &str,
+    ) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: "test".to_string(),
+            message: "test".to_string(),
+            line: start.0,
+            column: start.1,
+            severity: Severity::Warning,
+            fix: Some(Fix {
+                description: "test".to_string(),
+                replacement: Some(text.to_string()),
+                start: Position {
+                    line: start.0,
+                    column: start.1,
+                },
+                end: Position {
+                    line: end.0,
+                    column: end.1,
+                },
+                safety: FixSafety::Safe,
+            }),
+            url: None,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_apply_non_overlapping() {
+        let violations = vec![
+            violation("B", (1, 9), (1, 12), "CCC"),
+            violation("A", (1, 1), (1, 4), "AAA"),
+        ];
+        let report = FixEngine::new().apply("aaa bbb ccc", &violations);
+
+        assert_eq!(report.content, "AAA bbb CCC");
+        assert_eq!(report.applied.len(), 2);
+        assert!(report.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_apply_overlap_earliest_wins() {
+        let violations = vec![
+            violation("LATE", (1, 3), (1, 8), "X"),
+            violation("EARLY", (1, 1), (1, 5), "Y"),
+        ];
+        let report = FixEngine::new().apply("abcdefghij", &violations);
+
+        assert_eq!(report.content, "Yefghij");
+        assert_eq!(report.applied[0].rule_id, "EARLY");
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(report.skipped[0].violation.rule_id, "LATE");
+        assert_eq!(
+            report.skipped[0].reason,
+            SkipReason::Overlap {
+                rule_id: "EARLY".to_string(),
+                line: 1
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_overlap_is_order_independent() {
+        let a = violation("MD001", (1, 1), (1, 4), "A");
+        let b = violation("MD002", (1, 1), (1, 4), "B");
+        let forward = FixEngine::new().apply("abcdef", &[a.clone(), b.clone()]);
+        let backward = FixEngine::new().apply("abcdef", &[b, a]);
+
+        assert_eq!(forward.content, "Adef");
+        assert_eq!(forward.content, backward.content);
+    }
+
+    #[test]
+    fn test_apply_duplicate_fix() {
+        let violations = vec![
+            violation("MD001", (1, 1), (1, 2), "X"),
+            violation("MD002", (1, 1), (1, 2), "X"),
+        ];
+        let report = FixEngine::new().apply("abc", &violations);
+
+        assert_eq!(report.content, "Xbc");
+        assert_eq!(
+            report.skipped[0].reason,
+            SkipReason::Duplicate {
+                rule_id: "MD001".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_apply_adjacent_fixes() {
+        let violations = vec![
+            violation("A", (1, 1), (1, 3), "X"),
+            violation("B", (1, 3), (1, 5), "Y"),
+        ];
+        let report = FixEngine::new().apply("abcd", &violations);
+
+        assert_eq!(report.content, "XY");
+        assert!(report.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_apply_insertions_at_same_point_conflict() {
+        let violations = vec![
+            violation("A", (1, 2), (1, 2), "X"),
+            violation("B", (1, 2), (1, 2), "Y"),
+        ];
+        let report = FixEngine::new().apply("ab", &violations);
+
+        assert_eq!(report.content, "aXb");
+        assert_eq!(report.skipped.len(), 1);
+    }
+
+    #[test]
+    fn test_apply_invalid_position() {
+        let violations = vec![violation("A", (5, 1), (5, 2), "X")];
+        let report = FixEngine::new().apply("abc", &violations);
+
+        assert_eq!(report.content, "abc");
+        assert!(report.applied.is_empty());
+        assert_eq!(report.skipped[0].reason, SkipReason::InvalidPosition);
+    }
+
+    #[test]
+    fn test_apply_filters_by_safety() {
+        let mut violations = vec![
+            violation("A", (1, 1), (1, 4), "AAA"),
+            violation("B", (1, 5), (1, 8), "BBB"),
+            violation("C", (1, 9), (1, 12), "CCC"),
+        ];
+        violations[1].fix.as_mut().unwrap().safety = FixSafety::Unsafe;
+        violations[2].fix.as_mut().unwrap().safety = FixSafety::Suggestion;
+
+        let safe = FixEngine::new().apply("aaa bbb ccc", &violations);
+        assert_eq!(safe.content, "AAA bbb ccc");
+        assert!(safe.skipped.is_empty());
+
+        let with_unsafe = FixEngine::new()
+            .with_unsafe_fixes(true)
+            .apply("aaa bbb ccc", &violations);
+        assert_eq!(with_unsafe.content, "AAA BBB ccc");
+    }
+
+    #[test]
+    fn test_apply_newline_handling() {
+        let violations = vec![violation("A", (1, 1), (1, 4), "new\n")];
+        let report = FixEngine::new().apply("old\nnext\n", &violations);
+
+        assert_eq!(report.content, "new\nnext\n");
+    }
+
+    #[test]
+    fn test_apply_inserts_newline_at_end_of_line() {
+        let violations = vec![violation("A", (1, 4), (1, 4), "\n")];
+        let report = FixEngine::new().apply("old\nnext\n", &violations);
+
+        assert_eq!(report.content, "old\n\nnext\n");
+    }
+
+    #[test]
+    fn test_apply_keeps_missing_final_newline() {
+        // The line fix leaves the final newline to the insertion at the end
+        let violations = vec![
+            violation("LINE", (1, 1), (1, 6), "fixed\n"),
+            violation("EOF", (1, 6), (1, 6), "\n"),
+        ];
+        let report = FixEngine::new().apply("broke", &violations);
+        assert_eq!(report.content, "fixed\n");
+        assert_eq!(report.applied.len(), 2);
+    }
+
+    #[test]
+    fn test_apply_preserves_crlf() {
+        let violations = vec![
+            violation("A", (1, 1), (1, 4), "new\n"),
+            violation("B", (3, 1), (3, 5), "two\nlines"),
+        ];
+        let report = FixEngine::new().apply("old\r\nnext\r\nlast\r\n", &violations);
+
+        assert_eq!(report.content, "new\r\nnext\r\ntwo\r\nlines\r\n");
+    }
+
+    #[test]
+    fn test_fix_until_stable_retries_skipped_fixes() {
+        // Upper-cases the first lowercase letter each time it is linted; two
+        // overlapping fixes are reported per pass so only one applies.
+        let lint = |content: &str| -> Result<Vec<Violation>> {
+            let mut violations = Vec::new();
+            if let Some(idx) = content.find(|c: char| c.is_ascii_lowercase()) {
+                let col = idx + 1;
+                let upper = content[idx..idx + 1].to_ascii_uppercase();
+                violations.push(violation("A", (1, col), (1, col + 1), &upper));
+                violations.push(violation("B", (1, col), (1, col + 1), "_"));
+            }
+            Ok(violations)
+        };
+
+        let report = FixEngine::new().fix_until_stable("abc", lint).unwrap();
+        assert_eq!(report.content, "ABC");
+        assert_eq!(report.passes, 3);
+        assert_eq!(report.applied.len(), 3);
+        assert!(report.skipped.is_empty());
+    }
+
+    #[test]
+    fn test_verify_reports_unsettled_fixes() {
+        // A fixes "a" to "b"; B flags every "b" and would delete it, but
+        // only A's fixes are applied
+        let lint = |content: &str| -> Result<Vec<Violation>> {
+            let mut violations = Vec::new();
+            for (idx, c) in content.char_indices() {
+                let col = idx + 1;
+                if c == 'a' {
+                    violations.push(violation("A", (1, col), (1, col + 1), "b"));
+                } else if c == 'b' {
+                    violations.push(violation("B", (1, col), (1, col + 1), ""));
+                }
+            }
+            Ok(violations)
+        };
+        let only_a = |content: &str| -> Result<Vec<Violation>> {
+            Ok(lint(content)?
+                .into_iter()
+                .filter(|violation| violation.rule_id == "A")
+                .collect())
+        };
+        let engine = FixEngine::new();
+        let report = engine.fix_until_stable("ab", only_a).unwrap();
+        assert_eq!(report.content, "bb");
+        assert_eq!(
+            engine.verify("ab", &report, lint).unwrap(),
+            vec![FixProblem::Introduced {
+                rule_id: "B".to_string(),
+                before: 1,
+                after: 2,
+            }]
+        );
+
+        // A fix that never converges keeps its rule firing
+        let churn =
+            |_: &str| -> Result<Vec<Violation>> { Ok(vec![violation("A", (1, 1), (1, 1), "x")]) };
+        let engine = engine.with_max_passes(2);
+        let report = engine.fix_until_stable("", churn).unwrap();
+        let problems = engine.verify("", &report, churn).unwrap();
+        assert!(matches!(
+            problems.as_slice(),
+            [FixProblem::StillFires { violation }] if violation.rule_id == "A"
+        ));
+        assert_eq!(
+            problems[0].to_string(),
+            "A still reports a fixable violation at line 1 after its fix was applied"
+        );
+    }
+
+    #[test]
+    fn test_fix_until_stable_respects_pass_limit() {
+        // A fix that never converges stops at the pass limit
+        let lint =
+            |_: &str| -> Result<Vec<Violation>> { Ok(vec![violation("A", (1, 1), (1, 1), "x")]) };
+
+        let report = FixEngine::new()
+            .with_max_passes(2)
+            .fix_until_stable("", lint)
+            .unwrap();
+        assert_eq!(report.content, "xx");
+        assert_eq!(report.passes, 2);
+    }
+}
@@ -0,0 +1,480 @@
+//! Precomputed line and verbatim-region lookups for a document
+//!
+//! Many rules need to know where lines start and whether a position is
+//! inside text that Markdown leaves alone: fenced and indented code blocks,
+//! HTML blocks, inline code spans, and TeX math, which mdBook passes through
+//! to MathJax. The [`DocumentIndex`] computes this once per document, on first
+//! use, so rules share one definitive answer instead of each rescanning the
+//! content with its own approximation.
+
+use comrak::nodes::{AstNode, NodeValue};
+
+/// The kind of block a line belongs to
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum LineBlock {
+    #[default]
+    Text,
+    FencedCode,
+    IndentedCode,
+    Html,
+}
+
+/// Line offsets and verbatim regions of a document
+///
+/// Line numbers are 1-based, matching [`crate::Violation`] positions. Code
+/// span and math ranges are byte ranges into the line, delimiters included.
+#[derive(Debug, Clone, Default)]
+pub struct DocumentIndex {
+    /// Byte offset in the content where each line starts
+    line_starts: Vec<usize>,
+    /// The block each line belongs to
+    blocks: Vec<LineBlock>,
+    /// Whether each line is part of a code block, fences included
+    code_block_lines: Vec<bool>,
+    /// Inline code spans on each line outside code and HTML blocks
+    code_spans: Vec<Vec<(usize, usize)>>,
+    /// TeX math on each line outside code and HTML blocks
+    math_spans: Vec<Vec<(usize, usize)>>,
+}
+
+impl DocumentIndex {
+    /// Build the index for a document's content and its split lines
+    ///
+    /// Without an AST only fenced code blocks can be found, by scanning the
+    /// lines. [`crate::Document::index`] uses [`DocumentIndex::from_ast`].
+    pub fn new(content: &str, lines: &[String]) -> Self {
+        let blocks = fenced_code_lines(lines)
+            .into_iter()
+            .map(|fenced| {
+                if fenced {
+                    LineBlock::FencedCode
+                } else {
+                    LineBlock::Text
+                }
+            })
+            .collect();
+        Self::build(content, lines, blocks)
+    }
+
+    /// Build the index with code and HTML blocks taken from a parse of the
+    /// content
+    pub fn from_ast<'a>(content: &str, lines: &[String], ast: &'a AstNode<'a>) -> Self {
+        let mut blocks = vec![LineBlock::Text; lines.len()];
+        // comrak numbers lines from after the frontmatter
+        let frontmatter_lines = ast
+            .children()
+            .find_map(|child| match &child.data.borrow().value {
+                NodeValue::FrontMatter(text) => Some(text.matches('\n').count()),
+                _ => None,
+            })
+            .unwrap_or(0);
+
+        for node in ast.descendants() {
+            let data = node.data.borrow();
+            let kind = match &data.value {
+                NodeValue::CodeBlock(block) if block.fenced => LineBlock::FencedCode,
+                NodeValue::CodeBlock(_) => LineBlock::IndentedCode,
+                NodeValue::HtmlBlock(_) => LineBlock::Html,
+                _ => continue,
+            };
+
+            let start = data.sourcepos.start.line + frontmatter_lines;
+            let end = (data.sourcepos.end.line + frontmatter_lines).min(lines.len());
+            if start == frontmatter_lines || start > end {
+                continue;
+            }
+            for block in &mut blocks[start - 1..end] {
+                *block = kind;
+            }
+        }
+
+        Self::build(content, lines, blocks)
+    }
+
+    fn build(content: &str, lines: &[String], blocks: Vec<LineBlock>) -> Self {
+        let mut line_starts = Vec::with_capacity(lines.len());
+        let mut offset = 0;
+        for line in lines {
+            line_starts.push(offset);
+            offset += line.len();
+            // Step over the line terminator, which `str::lines` strips
+            if content[offset..].starts_with("\r\n") {
+                offset += 2;
+            } else if offset < content.len() {
+                offset += 1;
+            }
+        }
+
+        let code_block_lines = blocks
+            .iter()
+            .map(|block| matches!(block, LineBlock::FencedCode | LineBlock::IndentedCode))
+            .collect();
+        let code_spans: Vec<_> = lines
+            .iter()
+            .zip(&blocks)
+            .map(|(line, block)| {
+                if *block == LineBlock::Text {
+                    inline_code_spans(line)
+                } else {
+                    Vec::new()
+                }
+            })
+            .collect();
+        let math_spans = math_spans(lines, &blocks, &code_spans);
+
+        Self {
+            line_starts,
+            blocks,
+            code_block_lines,
+            code_spans,
+            math_spans,
+        }
+    }
+
+    /// Number of indexed lines
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Byte offset where a 1-based line starts
+    pub fn line_start(&self, line: usize) -> Option<usize> {
+        self.line_starts.get(line.checked_sub(1)?).copied()
+    }
+
+    /// The 1-based line containing a byte offset
+    ///
+    /// Offsets past the end of the content map to the last line.
+    pub fn line_number_at_offset(&self, offset: usize) -> usize {
+        self.line_starts
+            .partition_point(|&start| start <= offset)
+            .max(1)
+            .min(self.line_starts.len())
+    }
+
+    /// The 1-based byte column of an offset within its line
+    pub fn column_number_at_offset(&self, offset: usize) -> usize {
+        match self.line_start(self.line_number_at_offset(offset)) {
+            Some(start) => offset.saturating_sub(start) + 1,
+            None => 1,
+        }
+    }
+
+    fn block(&self, line: usize) -> LineBlock {
+        line.checked_sub(1)
+            .and_then(|idx| self.blocks.get(idx))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Whether a 1-based line is inside a fenced or indented code block,
+    /// fences included
+    pub fn is_code_block_line(&self, line: usize) -> bool {
+        matches!(
+            self.block(line),
+            LineBlock::FencedCode | LineBlock::IndentedCode
+        )
+    }
+
+    /// Whether a 1-based line is inside a fenced code block, fences included
+    pub fn is_fenced_code_line(&self, line: usize) -> bool {
+        self.block(line) == LineBlock::FencedCode
+    }
+
+    /// Whether a 1-based line is inside an indented code block
+    pub fn is_indented_code_line(&self, line: usize) -> bool {
+        self.block(line) == LineBlock::IndentedCode
+    }
+
+    /// Whether a 1-based line is inside an HTML block
+    pub fn is_html_block_line(&self, line: usize) -> bool {
+        self.block(line) == LineBlock::Html
+    }
+
+    /// Per-line code block flags, indexed from 0
+    pub fn code_block_lines(&self) -> &[bool] {
+        &self.code_block_lines
+    }
+
+    /// Byte ranges of inline code spans on a 1-based line
+    pub fn code_spans(&self, line: usize) -> &[(usize, usize)] {
+        line.checked_sub(1)
+            .and_then(|idx| self.code_spans.get(idx))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Byte ranges of TeX math on a 1-based line
+    ///
+    /// Covers `\\( \\)`, `\\[ \\]`, and `$$ $$`, which may span lines, and
+    /// `$...$` spans that start and end next to text.
+    pub fn math_spans(&self, line: usize) -> &[(usize, usize)] {
+        line.checked_sub(1)
+            .and_then(|idx| self.math_spans.get(idx))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Whether a byte offset within a 1-based line is in a code block or code span
+    pub fn is_in_code(&self, line: usize, byte_offset: usize) -> bool {
+        self.is_code_block_line(line) || in_spans(self.code_spans(line), byte_offset)
+    }
+
+    /// Whether a byte offset within a 1-based line is in TeX math
+    pub fn is_in_math(&self, line: usize, byte_offset: usize) -> bool {
+        in_spans(self.math_spans(line), byte_offset)
+    }
+
+    /// Whether a byte offset within a 1-based line is text that Markdown
+    /// leaves alone: a code block, HTML block, code span, or math
+    pub fn is_verbatim(&self, line: usize, byte_offset: usize) -> bool {
+        self.block(line) != LineBlock::Text
+            || self.is_in_code(line, byte_offset)
+            || self.is_in_math(line, byte_offset)
+    }
+}
+
+fn in_spans(spans: &[(usize, usize)], byte_offset: usize) -> bool {
+    spans
+        .iter()
+        .any(|(start, end)| (*start..*end).contains(&byte_offset))
+}
+
+/// Mark the lines of fenced code blocks
+///
+/// A block opens with three or more backticks or tildes and closes with a run
+/// of the same character that is at least as long. An unclosed block runs to
+/// the end of the document.
+fn fenced_code_lines(lines: &[String]) -> Vec<bool> {
+    let mut in_block = vec![false; lines.len()];
+    let mut fence: Option<(char, usize)> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        let marker = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+        let run = marker.map_or(0, |c| trimmed.chars().take_while(|&ch| ch == c).count());
+
+        match (fence, marker) {
+            (None, Some(c)) if run >= 3 => {
+                // Backticks are not allowed in a backtick fence's info string
+                if c == '`' && trimmed[run..].contains('`') {
+                    continue;
+                }
+                fence = Some((c, run));
+                in_block[idx] = true;
+            }
+            (None, _) => {}
+            (Some((open, open_run)), Some(c))
+                if c == open && run >= open_run && trimmed[run..].trim().is_empty() =>
+            {
+                fence = None;
+                in_block[idx] = true;
+            }
+            (Some(_), _) => in_block[idx] = true,
+        }
+    }
+
+    in_block
+}
+
+/// Byte ranges of inline code spans on a single line
+///
+/// A span opens with a run of backticks and closes with a run of exactly the
+/// same length. Unmatched runs are literal text.
+fn inline_code_spans(line: &str) -> Vec<(usize, usize)> {
+    let bytes = line.as_bytes();
+    let run_at = |i: usize| bytes[i..].iter().take_while(|&&b| b == b'`').count();
+
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'`' {
+            i += 1;
+            continue;
+        }
+        let run = run_at(i);
+        let mut j = i + run;
+        let mut close = None;
+        while j < bytes.len() {
+            if bytes[j] == b'`' {
+                let candidate = run_at(j);
+                if candidate == run {
+                    close = Some(j + candidate);
+                    break;
+                }
+                j += candidate;
+            } else {
+                j += 1;
+            }
+        }
+        match close {
+            Some(end) => {
+                spans.push((i, end));
+                i = end;
+            }
+            None => i += run,
+        }
+    }
+    spans
+}
+
+/// Byte ranges of TeX math on each line
+///
+/// Code spans are stepped over, so a `$` inside one never opens math. Display
+/// math left open at the end of a line continues onto the following text
+/// lines.
+fn math_spans(
+    lines: &[String],
+    blocks: &[LineBlock],
+    code_spans: &[Vec<(usize, usize)>],
+) -> Vec<Vec<(usize, usize)>> {
+    const PAIRS: [(&str, &str); 3] = [("\\\\(", "\\\\)"), ("\\\\[", "\\\\]"), ("$$", "$$")];
+
+    let mut spans = vec![Vec::new(); lines.len()];
+    // The closing delimiter of display math that continues onto later lines
+    let mut open_display: Option<&str> = None;
+
+    for (idx, line) in lines.iter().enumerate() {
+        if blocks[idx] != LineBlock::Text {
+            open_display = None;
+            continue;
+        }
+
+        let line_spans = &mut spans[idx];
+        let mut pos = 0;
+        if let Some(close) = open_display {
+            match line.find(close) {
+                Some(end) => {
+                    line_spans.push((0, end + close.len()));
+                    pos = end + close.len();
+                    open_display = None;
+                }
+                None => {
+                    line_spans.push((0, line.len()));
+                    continue;
+                }
+            }
+        }
+
+        while pos < line.len() {
+            if let Some(&(_, end)) = code_spans[idx].iter().find(|(start, _)| *start == pos) {
+                pos = end;
+                continue;
+            }
+
+            let rest = &line[pos..];
+            if let Some((open, close)) = PAIRS.iter().find(|(open, _)| rest.starts_with(open)) {
+                let body = pos + open.len();
+                match line[body..].find(close) {
+                    Some(end) => {
+                        let end = body + end + close.len();
+                        line_spans.push((pos, end));
+                        pos = end;
+                    }
+                    None => {
+                        line_spans.push((pos, line.len()));
+                        open_display = Some(close);
+                        break;
+                    }
+                }
+            } else if rest.starts_with('$') && !line[..pos].ends_with('\\') {
+                let inline = rest[1..].find('$').filter(|&len| {
+                    let body = &rest[1..1 + len];
+                    !body.is_empty()
+                        && !body.starts_with(char::is_whitespace)
+                        && !body.ends_with(char::is_whitespace)
+                        && !rest[len + 2..].starts_with(|c: char| c.is_ascii_digit())
+                });
+                match inline {
+                    Some(len) => {
+                        line_spans.push((pos, pos + len + 2));
+                        pos += len + 2;
+                    }
+                    None => pos += 1,
+                }
+            } else {
+                pos += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+    }
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(content: &str) -> DocumentIndex {
+        let lines: Vec<String> = content.lines().map(String::from).collect();
+        DocumentIndex::new(content, &lines)
+    }
+
+    #[test]
+    fn test_line_offsets() {
+        let idx = index("# Title\r\n\r\nText here\nLast");
+        assert_eq!(idx.line_count(), 4);
+        assert_eq!(idx.line_start(3), Some(11));
+        assert_eq!(idx.line_start(5), None);
+        assert_eq!(idx.line_number_at_offset(0), 1);
+        assert_eq!(idx.line_number_at_offset(13), 3);
+        assert_eq!(idx.column_number_at_offset(13), 3);
+        assert_eq!(idx.line_number_at_offset(100), 4);
+    }
+
+    #[test]
+    fn test_fenced_code_blocks() {
+        let content = "Text\n\n````markdown\n```rust\n```\n````\n\n~~~\ncode\n~~~\nAfter\n";
+        let idx = index(content);
+        let flags: Vec<usize> = (1..=idx.line_count())
+            .filter(|&l| idx.is_code_block_line(l))
+            .collect();
+        assert_eq!(flags, vec![3, 4, 5, 6, 8, 9, 10]);
+    }
+
+    #[test]
+    fn test_inline_code_spans() {
+        let idx = index("Use `a` and ``b ` c`` but not `open\n```\n`in block`\n");
+        assert_eq!(idx.code_spans(1), &[(4, 7), (12, 21)]);
+        assert!(idx.is_in_code(1, 5));
+        assert!(!idx.is_in_code(1, 8));
+        assert!(!idx.is_in_code(1, 30));
+        assert!(idx.code_spans(3).is_empty());
+        assert!(idx.is_in_code(3, 0));
+    }
+
+    fn ast_index(content: &str) -> DocumentIndex {
+        let document =
+            crate::Document::new(content.to_string(), std::path::PathBuf::from("test.md")).unwrap();
+        document.index().clone()
+    }
+
+    #[test]
+    fn test_blocks_from_ast() {
+        let idx = ast_index(
+            "---\ntitle: Test\n---\n\nText\n\n    indented\n\n<div>\n# not a heading\n</div>\n\n~~~\ncode\n~~~\n",
+        );
+        assert!(!idx.is_code_block_line(5));
+        assert!(idx.is_indented_code_line(7));
+        assert!(idx.is_code_block_line(7));
+        assert!(!idx.is_fenced_code_line(7));
+        assert!((9..=11).all(|line| idx.is_html_block_line(line)));
+        assert!(!idx.is_code_block_line(10));
+        assert!((13..=15).all(|line| idx.is_fenced_code_line(line)));
+        assert!(idx.is_verbatim(10, 0));
+        assert!(!idx.is_verbatim(5, 0));
+    }
+
+    #[test]
+    fn test_math_spans() {
+        let idx = index(
+            "Inline $a_b$ and `$x` then $5 and $6\n$$\nx_1\n$$ after\nSee \\\\(y_2\\\\) too\n",
+        );
+        assert_eq!(idx.math_spans(1), &[(7, 12)]);
+        assert!(idx.is_in_math(1, 9));
+        assert!(!idx.is_in_math(1, 18));
+        assert_eq!(idx.math_spans(2), &[(0, 2)]);
+        assert_eq!(idx.math_spans(3), &[(0, 3)]);
+        assert_eq!(idx.math_spans(4), &[(0, 2)]);
+        assert!(!idx.is_in_math(4, 4));
+        assert_eq!(idx.math_spans(5), &[(4, 13)]);
+        assert!(idx.is_verbatim(5, 6));
+    }
+}
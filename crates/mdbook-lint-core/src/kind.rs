@@ -0,0 +1,129 @@
+//! Kinds of document
+//!
+//! Not every Markdown file in a book is a chapter. `SUMMARY.md` is a table
+//! of contents, a README introduces a repository, an ADR records a decision,
+//! and a fragment pulled into chapters with `{{#include}}` often starts
+//! mid-document. A rule can declare which kinds it applies to with
+//! [`crate::RuleMetadata::kinds`], and a rule's table can narrow or widen
+//! that with `document-kinds` (see [`crate::Config::rule_document_kinds`]).
+//!
+//! [`DocumentKind::detect`] decides the kind from the path. Callers that
+//! know more, such as the mdBook preprocessor, which only sees chapters
+//! listed in `SUMMARY.md`, can set it with [`crate::Document::with_kind`].
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Directory names that hold architecture decision records
+pub const ADR_DIRECTORY_NAMES: &[&str] = &["adr", "adrs", "decisions", "architecture-decisions"];
+
+/// Directory names that hold fragments included into other documents
+pub const FRAGMENT_DIRECTORY_NAMES: &[&str] =
+    &["includes", "_includes", "partials", "fragments", "snippets"];
+
+/// What a Markdown document is for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DocumentKind {
+    /// A page of a book, or any document that is none of the others
+    Chapter,
+    /// An mdBook `SUMMARY.md`
+    Summary,
+    /// A `README.md`
+    Readme,
+    /// An architecture decision record
+    Adr,
+    /// Text meant to be included into other documents
+    Fragment,
+}
+
+impl DocumentKind {
+    /// Every kind, in the order they are documented
+    pub const ALL: [DocumentKind; 5] = [
+        DocumentKind::Chapter,
+        DocumentKind::Summary,
+        DocumentKind::Readme,
+        DocumentKind::Adr,
+        DocumentKind::Fragment,
+    ];
+
+    /// Name of the kind in configuration
+    pub fn name(self) -> &'static str {
+        match self {
+            DocumentKind::Chapter => "chapter",
+            DocumentKind::Summary => "summary",
+            DocumentKind::Readme => "readme",
+            DocumentKind::Adr => "adr",
+            DocumentKind::Fragment => "fragment",
+        }
+    }
+
+    /// The kind with configuration name `name`
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.name() == name)
+    }
+
+    /// The kind of the document at `path`
+    ///
+    /// `SUMMARY.md` is a summary. Files whose name starts with `_` or that
+    /// sit in a directory from [`FRAGMENT_DIRECTORY_NAMES`] are fragments.
+    /// `README.md` in any case is a readme, and files in a directory from
+    /// [`ADR_DIRECTORY_NAMES`] are ADRs. Everything else is a chapter.
+    pub fn detect(path: &Path) -> Self {
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy())
+            .unwrap_or_default();
+        let normalized = path.to_string_lossy().replace('\\', "/").to_lowercase();
+        let dirs: Vec<&str> = normalized.rsplit('/').skip(1).collect();
+        let in_dir = |names: &[&str]| dirs.iter().any(|dir| names.contains(dir));
+
+        if file_name == "SUMMARY.md" {
+            DocumentKind::Summary
+        } else if file_name.starts_with('_') || in_dir(FRAGMENT_DIRECTORY_NAMES) {
+            DocumentKind::Fragment
+        } else if file_name.eq_ignore_ascii_case("readme.md") {
+            DocumentKind::Readme
+        } else if in_dir(ADR_DIRECTORY_NAMES) {
+            DocumentKind::Adr
+        } else {
+            DocumentKind::Chapter
+        }
+    }
+}
+
+impl std::fmt::Display for DocumentKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect() {
+        let kind = |path: &str| DocumentKind::detect(Path::new(path));
+
+        assert_eq!(kind("src/intro.md"), DocumentKind::Chapter);
+        assert_eq!(kind("src/SUMMARY.md"), DocumentKind::Summary);
+        assert_eq!(kind("README.md"), DocumentKind::Readme);
+        assert_eq!(kind("src/guide/readme.md"), DocumentKind::Readme);
+        assert_eq!(kind("docs/adr/0001-use-rust.md"), DocumentKind::Adr);
+        assert_eq!(kind("docs\\Decisions\\0002.md"), DocumentKind::Adr);
+        assert_eq!(kind("src/_shared.md"), DocumentKind::Fragment);
+        assert_eq!(kind("src/snippets/install.md"), DocumentKind::Fragment);
+        assert_eq!(kind("docs/adr/_template.md"), DocumentKind::Fragment);
+        // Only directories count, not the file name
+        assert_eq!(kind("src/snippets.md"), DocumentKind::Chapter);
+    }
+
+    #[test]
+    fn test_names() {
+        for kind in DocumentKind::ALL {
+            assert_eq!(DocumentKind::from_name(kind.name()), Some(kind));
+        }
+        assert_eq!(DocumentKind::from_name("page"), None);
+    }
+}
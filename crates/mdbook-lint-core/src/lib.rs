@@ -116,7 +116,7 @@
 //! Represents a linting violation with location and optional fix:
 //!
 //! ```rust
-//! use mdbook_lint_core::violation::{Violation, Severity, Fix, Position};
+//! use mdbook_lint_core::violation::{Violation, Severity, Fix, FixSafety, Position};
 //!
 //! let violation = Violation {
 //!     rule_id: "MD001".to_string(),
@@ -130,7 +130,10 @@
 //!         replacement: Some("## Correct Level".to_string()),
 //!         start: Position { line: 5, column: 1 },
 //!         end: Position { line: 5, column: 20 },
+//!         safety: FixSafety::Safe,
 //!     }),
+//!     url: None,
+//!     path: None,
 //! };
 //! ```
 //!
@@ -166,27 +169,58 @@
 //!
 //! This crate has no optional features. All functionality is included by default.
 
+pub mod asset;
+pub mod book;
+pub mod cancel;
 pub mod config;
+pub mod context;
+pub mod custom;
 pub mod deduplication;
 pub mod document;
+pub mod edit_distance;
 pub mod engine;
 pub mod error;
+pub mod fix;
+pub mod index;
+pub mod kind;
+pub mod outline;
+pub mod regions;
 pub mod registry;
 pub mod rule;
+pub mod suppression;
 pub mod test_helpers;
+pub mod timing;
 pub mod violation;
 
 // Re-export core types for convenience
+pub use asset::Asset;
+pub use book::{Book, BookSource};
+pub use cancel::CancellationToken;
 pub use config::Config;
-pub use document::Document;
+pub use context::LintContext;
+pub use custom::{CustomRule, CustomRuleConfig};
+pub use deduplication::DedupePolicy;
+pub use document::{
+    Document, FrontMatter, FrontMatterFormat, LineEnding, MarkdownExtensions, SourceEncoding,
+};
 pub use engine::{LintEngine, PluginRegistry, RuleProvider};
 pub use error::{
     ConfigError, DocumentError, ErrorContext, IntoMdBookLintError, MdBookLintError, MdlntError,
     PluginError, Result, RuleError,
 };
+pub use fix::{FixEngine, FixProblem, FixReport, SkipReason, SkippedFix};
+pub use index::DocumentIndex;
+pub use kind::DocumentKind;
+pub use outline::{Outline, OutlineHeading};
+pub use regions::{IgnoreBlockquotes, Region, RegionMap};
 pub use registry::RuleRegistry;
-pub use rule::{AstRule, CollectionRule, Rule, RuleCategory, RuleMetadata, RuleStability};
-pub use violation::{Severity, Violation};
+pub use rule::{
+    AstRule, CollectionRule, OptionKind, Rule, RuleCategory, RuleMetadata, RuleOption,
+    RuleStability, builtin_doc_url,
+};
+pub use suppression::{Suppression, Suppressions};
+pub use timing::{DocumentTiming, RuleTiming, RuleTimingSummary, TimingReport};
+pub use violation::{FixSafety, Severity, Violation};
 
 /// Current version of mdbook-lint-core
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
@@ -224,11 +258,14 @@ pub fn create_mdbook_engine() -> LintEngine {
 /// Common imports
 pub mod prelude {
     pub use crate::{
-        Document,
+        Document, LintContext,
         engine::{LintEngine, PluginRegistry, RuleProvider},
         error::{ErrorContext, IntoMdBookLintError, MdBookLintError, MdlntError, Result},
         registry::RuleRegistry,
-        rule::{AstRule, CollectionRule, Rule, RuleCategory, RuleMetadata, RuleStability},
+        rule::{
+            AstRule, CollectionRule, OptionKind, Rule, RuleCategory, RuleMetadata, RuleOption,
+            RuleStability,
+        },
         violation::{Severity, Violation},
     };
 }
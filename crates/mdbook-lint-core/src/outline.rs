@@ -0,0 +1,277 @@
+//! Heading outline of a document
+//!
+//! Rules about document structure keep needing the same facts about each
+//! heading: its level, its text, the anchor mdBook gives it, and the lines
+//! its section covers. The [`Outline`] collects these once per document, on
+//! first use, from [`crate::Document::outline`].
+
+use comrak::nodes::{AstNode, NodeValue};
+use std::collections::HashMap;
+
+/// A heading and the section it starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineHeading {
+    /// Heading level, 1 to 6
+    pub level: u32,
+    /// Rendered text of the heading, without markup
+    pub title: String,
+    /// Anchor mdBook generates for the heading, numbered when repeated
+    pub slug: String,
+    /// 1-based line of the heading
+    pub line: usize,
+    /// 1-based last line of the section, just before the next heading at
+    /// the same or a higher level, or the last line of the document
+    pub end_line: usize,
+    /// Index of the enclosing heading, the nearest earlier one with a lower
+    /// level
+    pub parent: Option<usize>,
+}
+
+/// Headings of a document in source order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Outline {
+    headings: Vec<OutlineHeading>,
+}
+
+impl Outline {
+    /// Build the outline from a document's AST
+    ///
+    /// `line_count` is the number of lines in the document, where the last
+    /// section ends. Lines are document lines even when the document starts
+    /// with frontmatter, which comrak leaves out of its numbering.
+    pub fn from_ast<'a>(ast: &'a AstNode<'a>, line_count: usize) -> Self {
+        let mut headings: Vec<OutlineHeading> = Vec::new();
+        let mut slug_counts: HashMap<String, usize> = HashMap::new();
+        let frontmatter_lines = ast
+            .children()
+            .find_map(|child| match &child.data.borrow().value {
+                NodeValue::FrontMatter(text) => Some(text.matches('\n').count()),
+                _ => None,
+            })
+            .unwrap_or(0);
+
+        for node in ast.descendants() {
+            let level = match &node.data.borrow().value {
+                NodeValue::Heading(heading) => u32::from(heading.level),
+                _ => continue,
+            };
+            let line = node.data.borrow().sourcepos.start.line + frontmatter_lines;
+            let text = heading_text(node);
+            let title = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+            let base = mdbook_slug(&text);
+            let count = slug_counts.entry(base.clone()).or_insert(0);
+            let slug = if *count == 0 {
+                base
+            } else {
+                format!("{base}-{count}")
+            };
+            *count += 1;
+
+            // This heading ends every open section at its level or deeper
+            for open in headings.iter_mut().rev() {
+                if open.end_line != usize::MAX {
+                    continue;
+                }
+                if open.level >= level {
+                    open.end_line = line.saturating_sub(1).max(open.line);
+                }
+            }
+            let parent = headings.iter().rposition(|h| h.level < level);
+
+            headings.push(OutlineHeading {
+                level,
+                title,
+                slug,
+                line,
+                end_line: usize::MAX,
+                parent,
+            });
+        }
+
+        for heading in &mut headings {
+            if heading.end_line == usize::MAX {
+                heading.end_line = line_count.max(heading.line);
+            }
+        }
+
+        Self { headings }
+    }
+
+    /// All headings in source order
+    pub fn headings(&self) -> &[OutlineHeading] {
+        &self.headings
+    }
+
+    /// Iterate over the headings in source order
+    pub fn iter(&self) -> std::slice::Iter<'_, OutlineHeading> {
+        self.headings.iter()
+    }
+
+    /// Number of headings
+    pub fn len(&self) -> usize {
+        self.headings.len()
+    }
+
+    /// Whether the document has no headings
+    pub fn is_empty(&self) -> bool {
+        self.headings.is_empty()
+    }
+
+    /// The heading enclosing the heading at `index`
+    pub fn parent(&self, index: usize) -> Option<&OutlineHeading> {
+        self.headings
+            .get(index)
+            .and_then(|heading| heading.parent)
+            .map(|parent| &self.headings[parent])
+    }
+
+    /// Headings directly under the heading at `index`
+    pub fn children(&self, index: usize) -> impl Iterator<Item = &OutlineHeading> {
+        self.headings
+            .iter()
+            .filter(move |heading| heading.parent == Some(index))
+    }
+
+    /// Headings enclosing the heading at `index`, innermost first
+    pub fn ancestors(&self, index: usize) -> impl Iterator<Item = &OutlineHeading> {
+        std::iter::successors(self.parent(index), |heading| {
+            heading.parent.map(|parent| &self.headings[parent])
+        })
+    }
+
+    /// The heading with the given anchor
+    pub fn find_slug(&self, slug: &str) -> Option<&OutlineHeading> {
+        self.headings.iter().find(|heading| heading.slug == slug)
+    }
+
+    /// The innermost section containing `line`
+    pub fn section_at(&self, line: usize) -> Option<&OutlineHeading> {
+        self.headings
+            .iter()
+            .rev()
+            .find(|heading| heading.line <= line && line <= heading.end_line)
+    }
+}
+
+impl<'a> IntoIterator for &'a Outline {
+    type Item = &'a OutlineHeading;
+    type IntoIter = std::slice::Iter<'a, OutlineHeading>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// mdBook's `normalize_id`, applied to heading text with any leading `#`
+/// removed
+///
+/// Only ASCII letters are lowercased, each whitespace character becomes a
+/// hyphen, and everything other than letters, digits, `-`, and `_` is
+/// dropped. Leading and trailing hyphens are kept. Repeated headings are
+/// numbered separately; see [`OutlineHeading::slug`].
+pub fn mdbook_slug(text: &str) -> String {
+    let text = text.trim().trim_start_matches('#').trim();
+    text.chars()
+        .filter_map(|ch| {
+            if ch.is_alphanumeric() || ch == '_' || ch == '-' {
+                Some(ch.to_ascii_lowercase())
+            } else if ch.is_whitespace() {
+                Some('-')
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Rendered text of a heading node
+///
+/// Images and inline HTML contribute no text, matching how mdBook strips
+/// tags before generating the anchor.
+pub fn heading_text<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+    for child in node.children() {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(code) => text.push_str(&code.literal),
+            NodeValue::SoftBreak | NodeValue::LineBreak => text.push('\n'),
+            NodeValue::Image(_) | NodeValue::HtmlInline(_) => {}
+            _ => text.push_str(&heading_text(child)),
+        }
+    }
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Document;
+    use std::path::PathBuf;
+
+    fn outline(content: &str) -> crate::outline::Outline {
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        document.outline().clone()
+    }
+
+    #[test]
+    fn test_levels_titles_and_ranges() {
+        let outline = outline(
+            "# Guide\n\nIntro.\n\n## Install `cargo`\n\nText.\n\n### From *source*\n\nMore.\n\n## Usage\n\nEnd.\n",
+        );
+        let titles: Vec<(u32, &str, &str)> = outline
+            .iter()
+            .map(|h| (h.level, h.title.as_str(), h.slug.as_str()))
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                (1, "Guide", "guide"),
+                (2, "Install cargo", "install-cargo"),
+                (3, "From source", "from-source"),
+                (2, "Usage", "usage"),
+            ]
+        );
+        let ranges: Vec<(usize, usize, Option<usize>)> = outline
+            .iter()
+            .map(|h| (h.line, h.end_line, h.parent))
+            .collect();
+        assert_eq!(
+            ranges,
+            vec![
+                (1, 15, None),
+                (5, 12, Some(0)),
+                (9, 12, Some(1)),
+                (13, 15, Some(0))
+            ]
+        );
+
+        assert_eq!(outline.section_at(10).unwrap().title, "From source");
+        assert_eq!(outline.section_at(3).unwrap().title, "Guide");
+        assert_eq!(outline.children(0).count(), 2);
+        let ancestors: Vec<&str> = outline.ancestors(2).map(|h| h.title.as_str()).collect();
+        assert_eq!(ancestors, vec!["Install cargo", "Guide"]);
+    }
+
+    #[test]
+    fn test_repeated_and_setext_headings() {
+        let outline =
+            outline("Title\n=====\n\n## Example\n\n## Example\n\n```\n# not a heading\n```\n");
+        let slugs: Vec<&str> = outline.iter().map(|h| h.slug.as_str()).collect();
+        assert_eq!(slugs, vec!["title", "example", "example-1"]);
+        assert_eq!(outline.find_slug("example-1").unwrap().line, 6);
+        assert_eq!(outline.headings()[1].end_line, 5);
+    }
+
+    #[test]
+    fn test_lines_after_frontmatter() {
+        let outline = outline("---\ntitle: Guide\n---\n\n# Guide\n\n# Usage\n");
+        let lines: Vec<usize> = outline.iter().map(|h| h.line).collect();
+        assert_eq!(lines, vec![5, 7]);
+        assert_eq!(outline.headings()[0].end_line, 6);
+    }
+
+    #[test]
+    fn test_no_headings() {
+        assert!(outline("Just text.\n").is_empty());
+    }
+}
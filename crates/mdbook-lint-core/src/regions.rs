@@ -0,0 +1,240 @@
+//! Block regions of a document
+//!
+//! Many rules have no reason to look inside some kinds of block: a long
+//! line in a table or a spelling slip in a code sample is often fine. Rather
+//! than every rule hard-coding its own exclusions, a rule's table can set
+//! `ignore-code-blocks`, `ignore-tables`, `ignore-headings`, or
+//! `ignore-blockquotes`, and the registry drops the rule's violations on
+//! lines in those regions (see [`crate::Config::apply_region_filters`]). The
+//! [`RegionMap`] records which lines each region covers, and how deeply
+//! each line is quoted.
+//!
+//! Quoted text is often someone else's: a cited email, a pasted changelog.
+//! `ignore-blockquotes` can also be set at the top level of the
+//! configuration to cover every rule, and takes a nesting depth as well as
+//! `true` (see [`IgnoreBlockquotes`]).
+
+use comrak::nodes::{AstNode, NodeValue};
+use serde::{Deserialize, Serialize};
+
+/// A kind of block whose violations a rule can be configured to ignore
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Region {
+    /// Fenced and indented code blocks
+    CodeBlock,
+    /// Tables
+    Table,
+    /// ATX and setext headings
+    Heading,
+    /// Blockquotes, including nested ones
+    Blockquote,
+}
+
+impl Region {
+    /// Every region, in the order their keys are documented
+    pub const ALL: [Region; 4] = [
+        Region::CodeBlock,
+        Region::Table,
+        Region::Heading,
+        Region::Blockquote,
+    ];
+
+    /// Key in a rule's table that ignores violations in this region
+    pub fn config_key(self) -> &'static str {
+        match self {
+            Region::CodeBlock => "ignore-code-blocks",
+            Region::Table => "ignore-tables",
+            Region::Heading => "ignore-headings",
+            Region::Blockquote => "ignore-blockquotes",
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// Value of an `ignore-blockquotes` key
+///
+/// `true` ignores every blockquote. A number ignores quotes nested at least
+/// that deep, so `2` keeps top-level quotes but drops quotes inside them;
+/// `1` is the same as `true`, and `0` the same as `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum IgnoreBlockquotes {
+    /// Ignore every blockquote, or none
+    All(bool),
+    /// Ignore blockquotes nested at least this deep
+    Depth(usize),
+}
+
+impl IgnoreBlockquotes {
+    /// Read the setting from a configuration value, if it has the right type
+    pub fn from_value(value: &toml::Value) -> Option<Self> {
+        match value {
+            toml::Value::Boolean(all) => Some(Self::All(*all)),
+            toml::Value::Integer(depth) => usize::try_from(*depth).ok().map(Self::Depth),
+            _ => None,
+        }
+    }
+
+    /// The shallowest quote depth that is ignored, or `None` when nothing is
+    pub fn min_depth(self) -> Option<usize> {
+        match self {
+            Self::All(true) => Some(1),
+            Self::All(false) | Self::Depth(0) => None,
+            Self::Depth(depth) => Some(depth),
+        }
+    }
+}
+
+/// The regions each line of a document belongs to
+///
+/// Line numbers are 1-based document lines, even when the document starts
+/// with frontmatter, which comrak leaves out of its numbering.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RegionMap {
+    lines: Vec<u8>,
+    /// Number of blockquotes each line is inside
+    quote_depths: Vec<u8>,
+}
+
+impl RegionMap {
+    /// Build the map from a document's AST
+    ///
+    /// `line_count` is the number of lines in the document.
+    pub fn from_ast<'a>(ast: &'a AstNode<'a>, line_count: usize) -> Self {
+        let mut lines = vec![0; line_count];
+        let mut quote_depths = vec![0u8; line_count];
+        let frontmatter_lines = ast
+            .children()
+            .find_map(|child| match &child.data.borrow().value {
+                NodeValue::FrontMatter(text) => Some(text.matches('\n').count()),
+                _ => None,
+            })
+            .unwrap_or(0);
+
+        for node in ast.descendants() {
+            let data = node.data.borrow();
+            let (region, extra) = match &data.value {
+                NodeValue::CodeBlock(_) => (Region::CodeBlock, 0),
+                NodeValue::Table(_) => (Region::Table, 0),
+                // comrak ends a setext heading on its text line, so add the
+                // underline
+                NodeValue::Heading(heading) if heading.setext => (Region::Heading, 1),
+                NodeValue::Heading(_) => (Region::Heading, 0),
+                NodeValue::BlockQuote => (Region::Blockquote, 0),
+                _ => continue,
+            };
+
+            let start = data.sourcepos.start.line + frontmatter_lines;
+            let end = (data.sourcepos.end.line + frontmatter_lines)
+                .max(data.sourcepos.start.line + frontmatter_lines + extra)
+                .min(line_count);
+            if start == frontmatter_lines || start > end {
+                continue;
+            }
+            for flags in &mut lines[start - 1..end] {
+                *flags |= region.bit();
+            }
+            if region == Region::Blockquote {
+                for depth in &mut quote_depths[start - 1..end] {
+                    *depth = depth.saturating_add(1);
+                }
+            }
+        }
+
+        Self {
+            lines,
+            quote_depths,
+        }
+    }
+
+    /// Whether a 1-based line is inside `region`
+    pub fn contains(&self, line: usize, region: Region) -> bool {
+        line.checked_sub(1)
+            .and_then(|idx| self.lines.get(idx))
+            .is_some_and(|flags| flags & region.bit() != 0)
+    }
+
+    /// How many blockquotes a 1-based line is inside, 0 outside any
+    pub fn blockquote_depth(&self, line: usize) -> usize {
+        line.checked_sub(1)
+            .and_then(|idx| self.quote_depths.get(idx))
+            .map_or(0, |depth| usize::from(*depth))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Document;
+    use comrak::Arena;
+    use std::path::PathBuf;
+
+    fn regions(content: &str) -> RegionMap {
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let arena = Arena::new();
+        let ast = document.parse_ast(&arena);
+        RegionMap::from_ast(ast, document.lines.len())
+    }
+
+    #[test]
+    fn test_regions_by_line() {
+        let map = regions(
+            "# Title\n\nText\n\n```\ncode\n```\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n> Quote\n> > Nested\n\nSetext\n------\n",
+        );
+
+        assert!(map.contains(1, Region::Heading));
+        assert!(!map.contains(3, Region::Heading));
+        assert!((5..=7).all(|line| map.contains(line, Region::CodeBlock)));
+        assert!((9..=11).all(|line| map.contains(line, Region::Table)));
+        assert!(map.contains(13, Region::Blockquote));
+        assert!(map.contains(14, Region::Blockquote));
+        assert!(map.contains(16, Region::Heading));
+        assert!(map.contains(17, Region::Heading));
+        assert!(!map.contains(18, Region::Heading));
+        assert!(!map.contains(3, Region::CodeBlock));
+    }
+
+    #[test]
+    fn test_regions_after_frontmatter() {
+        let map = regions("---\ntitle: Test\n---\n\n# Heading\n\n    indented\n");
+
+        assert!(!map.contains(1, Region::Heading));
+        assert!(map.contains(5, Region::Heading));
+        assert!(map.contains(7, Region::CodeBlock));
+    }
+
+    #[test]
+    fn test_blockquote_depth() {
+        let map = regions("Text\n\n> Quote\n> > Nested\n> > > Deeper\n>\n> Back\n\nAfter\n");
+
+        assert_eq!(map.blockquote_depth(1), 0);
+        assert_eq!(map.blockquote_depth(3), 1);
+        assert_eq!(map.blockquote_depth(4), 2);
+        assert_eq!(map.blockquote_depth(5), 3);
+        assert_eq!(map.blockquote_depth(7), 1);
+        assert_eq!(map.blockquote_depth(9), 0);
+    }
+
+    #[test]
+    fn test_ignore_blockquotes_setting() {
+        let min_depth = |value: toml::Value| {
+            IgnoreBlockquotes::from_value(&value).and_then(IgnoreBlockquotes::min_depth)
+        };
+
+        assert_eq!(min_depth(toml::Value::Boolean(true)), Some(1));
+        assert_eq!(min_depth(toml::Value::Boolean(false)), None);
+        assert_eq!(min_depth(toml::Value::Integer(2)), Some(2));
+        assert_eq!(min_depth(toml::Value::Integer(0)), None);
+        assert_eq!(
+            IgnoreBlockquotes::from_value(&toml::Value::Integer(-1)),
+            None
+        );
+        assert_eq!(
+            IgnoreBlockquotes::from_value(&toml::Value::String("yes".to_string())),
+            None
+        );
+    }
+}
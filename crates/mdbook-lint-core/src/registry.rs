@@ -1,11 +1,26 @@
 use crate::{
-    Document, config::Config, error::Result, rule::CollectionRule, rule::Rule, violation::Violation,
+    Document,
+    config::Config,
+    context::LintContext,
+    error::Result,
+    kind::DocumentKind,
+    regions::RegionMap,
+    rule::CollectionRule,
+    rule::Rule,
+    rule::RuleMetadata,
+    suppression::Suppressions,
+    timing::{DocumentTiming, RuleTiming},
+    violation::{Severity, Violation},
 };
+use std::any::Any;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::time::Instant;
 
 /// Registry for managing linting rules
 pub struct RuleRegistry {
     rules: Vec<Box<dyn Rule>>,
     collection_rules: Vec<Box<dyn CollectionRule>>,
+    placeholders: Vec<Box<dyn Rule>>,
 }
 
 impl RuleRegistry {
@@ -14,6 +29,7 @@ impl RuleRegistry {
         Self {
             rules: Vec::new(),
             collection_rules: Vec::new(),
+            placeholders: Vec::new(),
         }
     }
 
@@ -35,6 +51,20 @@ impl RuleRegistry {
         self.collection_rules.push(rule);
     }
 
+    /// Register a reserved or retired rule number
+    ///
+    /// Placeholders never run and aren't listed with the other rules. They
+    /// are kept so a configuration that still refers to one gets a notice
+    /// from [`Self::deprecation_notices`] instead of being ignored.
+    pub fn register_placeholder(&mut self, rule: Box<dyn Rule>) {
+        self.placeholders.push(rule);
+    }
+
+    /// IDs of the registered placeholders
+    pub fn placeholder_ids(&self) -> Vec<&'static str> {
+        self.placeholders.iter().map(|r| r.id()).collect()
+    }
+
     /// Get all registered collection rules
     pub fn collection_rules(&self) -> &[Box<dyn CollectionRule>] {
         &self.collection_rules
@@ -64,6 +94,37 @@ impl RuleRegistry {
         self.rules.iter().map(|r| r.id()).collect()
     }
 
+    /// Metadata of the rule, placeholder, or collection rule with the given ID
+    pub fn rule_metadata(&self, id: &str) -> Option<RuleMetadata> {
+        self.get_rule(id)
+            .or_else(|| {
+                self.placeholders
+                    .iter()
+                    .find(|r| r.id() == id)
+                    .map(|r| r.as_ref())
+            })
+            .map(|r| r.metadata())
+            .or_else(|| {
+                self.collection_rules
+                    .iter()
+                    .find(|r| r.id() == id)
+                    .map(|r| r.metadata())
+            })
+    }
+
+    /// Check each rule's configuration table against its declared options
+    ///
+    /// Tables that aren't named after a registered rule are skipped; they
+    /// belong to providers or aren't rule settings at all.
+    pub fn validate_rule_configs(&self, config: &Config) -> Vec<String> {
+        let mut ids: Vec<&String> = config.rule_configs.keys().collect();
+        ids.sort();
+        ids.into_iter()
+            .filter_map(|id| Some((id, self.rule_metadata(id)?)))
+            .flat_map(|(id, metadata)| metadata.validate_config(id, &config.rule_configs[id]))
+            .collect()
+    }
+
     /// Get rules that should be enabled based on configuration
     ///
     /// This method applies configuration filters to determine which rules
@@ -93,6 +154,7 @@ impl RuleRegistry {
             .rules
             .iter()
             .filter(|rule| self.should_run_rule(rule.as_ref(), config))
+            .filter(|rule| Self::applies_to_document(rule.as_ref(), document, config))
             .map(|rule| rule.as_ref())
             .collect();
 
@@ -116,6 +178,16 @@ impl RuleRegistry {
         enabled_rules
     }
 
+    /// Whether a rule runs on documents of `document`'s kind: the kinds in
+    /// its table's `document-kinds` if set, otherwise the kinds it declares
+    fn applies_to_document(rule: &dyn Rule, document: &Document, config: &Config) -> bool {
+        let kind = config.document_kind(document);
+        match config.rule_document_kinds(rule.id()) {
+            Some(kinds) => kinds.contains(&kind),
+            None => rule.metadata().applies_to_kind(kind),
+        }
+    }
+
     /// Check if a rule override is applicable for a specific document
     /// This is used for rules like MDBOOK025 that should override based on file name/context
     /// rather than just violation presence
@@ -123,12 +195,7 @@ impl RuleRegistry {
         match rule_id {
             "MDBOOK025" => {
                 // MDBOOK025 overrides MD025 for SUMMARY.md files
-                document
-                    .path
-                    .file_name()
-                    .and_then(|name| name.to_str())
-                    .map(|name| name == "SUMMARY.md")
-                    .unwrap_or(false)
+                document.kind() == DocumentKind::Summary
             }
             _ => false,
         }
@@ -138,13 +205,16 @@ impl RuleRegistry {
     ///
     /// This implements the rule filtering logic that considers:
     /// 1. Explicitly disabled rules (always excluded)
-    /// 2. Explicitly enabled rules (always included, with deprecation warnings)
+    /// 2. Explicitly enabled rules (always included)
     /// 3. Category-based filtering (enabled/disabled categories)
     /// 4. Default behavior (exclude deprecated rules unless explicitly enabled)
     pub fn should_run_rule(&self, rule: &dyn Rule, config: &Config) -> bool {
-        let rule_id = rule.id();
-        let metadata = rule.metadata();
+        self.should_run(rule.id(), &rule.metadata(), config)
+    }
 
+    /// [`Self::should_run_rule`] by ID and metadata, so it covers collection
+    /// rules too
+    fn should_run(&self, rule_id: &str, metadata: &RuleMetadata, config: &Config) -> bool {
         // Check explicit disabled rules first
         if config.disabled_rules.contains(&rule_id.to_string()) {
             return false;
@@ -152,10 +222,6 @@ impl RuleRegistry {
 
         // Check explicit enabled rules
         if config.enabled_rules.contains(&rule_id.to_string()) {
-            // Show deprecation warning if needed
-            if metadata.deprecated {
-                self.show_deprecation_warning(rule, config);
-            }
             return true;
         }
 
@@ -184,10 +250,90 @@ impl RuleRegistry {
             return false;
         }
 
+        // Rules newer than the pinned ruleset-version wait for an upgrade
+        if Self::is_newer_than_pin(metadata, config) {
+            return false;
+        }
+
         // For rules not explicitly configured, only enable non-deprecated rules by default
         !metadata.deprecated
     }
 
+    /// Whether a rule was added after the configuration's `ruleset-version`
+    fn is_newer_than_pin(metadata: &RuleMetadata, config: &Config) -> bool {
+        let pin = config
+            .ruleset_version
+            .as_deref()
+            .and_then(crate::config::parse_version);
+        matches!((pin, metadata.mdbook_lint_version()), (Some(pin), Some(added)) if added > pin)
+    }
+
+    /// Rules held back by the configuration's `ruleset-version`
+    ///
+    /// These would run without the pin. Opt-in rules are only counted once
+    /// they are configured, since they would do nothing otherwise. They are
+    /// returned in registration order, collection rules last.
+    pub fn held_back_rules(&self, config: &Config) -> Vec<&'static str> {
+        if config.ruleset_version.is_none() {
+            return Vec::new();
+        }
+        let unpinned = Config {
+            ruleset_version: None,
+            ..config.clone()
+        };
+        let wanted = |id: &str, metadata: &RuleMetadata| {
+            !metadata.opt_in || config.rule_configs.contains_key(id)
+        };
+        let rules = self
+            .rules
+            .iter()
+            .filter(|rule| {
+                !self.should_run_rule(rule.as_ref(), config)
+                    && self.should_run_rule(rule.as_ref(), &unpinned)
+                    && wanted(rule.id(), &rule.metadata())
+            })
+            .map(|rule| rule.id());
+        let collection_rules = self
+            .collection_rules
+            .iter()
+            .filter(|rule| {
+                let metadata = rule.metadata();
+                !self.should_run(rule.id(), &metadata, config)
+                    && self.should_run(rule.id(), &metadata, &unpinned)
+                    && wanted(rule.id(), &metadata)
+            })
+            .map(|rule| rule.id());
+        rules.chain(collection_rules).collect()
+    }
+
+    /// Notice listing the rules held back by `ruleset-version`, if any
+    pub fn ruleset_notice(&self, config: &Config) -> Option<String> {
+        let held_back = self.held_back_rules(config);
+        if held_back.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "{} rule(s) added after ruleset-version {} are not running: {}. \
+             Run `mdbook-lint upgrade-config` to enable them.",
+            held_back.len(),
+            config.ruleset_version.as_deref().unwrap_or_default(),
+            held_back.join(", ")
+        ))
+    }
+
+    /// Newest mdbook-lint version a registered rule was added in
+    pub fn latest_rule_version(&self) -> Option<(u64, u64, u64)> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.metadata().mdbook_lint_version())
+            .chain(
+                self.collection_rules
+                    .iter()
+                    .filter_map(|rule| rule.metadata().mdbook_lint_version()),
+            )
+            .max()
+    }
+
     /// Convert RuleCategory to string for configuration matching
     fn category_to_string(&self, category: &crate::rule::RuleCategory) -> String {
         match category {
@@ -200,45 +346,64 @@ impl RuleRegistry {
         }
     }
 
-    /// Show deprecation warning based on configuration
+    /// Notices for the deprecated and reserved rules a configuration uses
     ///
-    /// Displays deprecation warnings according to the configured warning level.
-    fn show_deprecation_warning(&self, rule: &dyn Rule, config: &Config) {
-        let metadata = rule.metadata();
-
-        if !metadata.deprecated {
-            return;
-        }
+    /// A rule is used when it is listed in `enabled-rules`, has an options
+    /// table, or has a severity override. Each rule gets one notice, in ID
+    /// order, naming its replacement when it has one.
+    pub fn deprecation_notices(&self, config: &Config) -> Vec<String> {
+        let mut ids: Vec<&str> = config
+            .enabled_rules
+            .iter()
+            .chain(config.rule_configs.keys())
+            .chain(config.severity.keys())
+            .map(String::as_str)
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+
+        ids.into_iter()
+            .filter_map(|id| {
+                let metadata = self.rule_metadata(id)?;
+                if metadata.stability == crate::rule::RuleStability::Reserved {
+                    return Some(format!(
+                        "Rule {id} is reserved and never reports anything - {}.",
+                        metadata.deprecated_reason.unwrap_or("never implemented")
+                    ));
+                }
+                if !metadata.deprecated {
+                    return None;
+                }
+                Some(match metadata.replacement {
+                    Some(replacement) => format!(
+                        "Rule {id} is deprecated - {}. Consider using {replacement} instead.",
+                        metadata
+                            .deprecated_reason
+                            .unwrap_or("superseded by newer implementation"),
+                    ),
+                    None => format!(
+                        "Rule {id} is deprecated - {}.",
+                        metadata
+                            .deprecated_reason
+                            .unwrap_or("no longer recommended")
+                    ),
+                })
+            })
+            .collect()
+    }
 
-        let message = if let Some(replacement) = metadata.replacement {
-            format!(
-                "Rule {} is deprecated - {}. Consider using {} instead.",
-                rule.id(),
-                metadata
-                    .deprecated_reason
-                    .unwrap_or("superseded by newer implementation"),
-                replacement
-            )
-        } else {
-            format!(
-                "Rule {} is deprecated - {}.",
-                rule.id(),
-                metadata
-                    .deprecated_reason
-                    .unwrap_or("no longer recommended")
-            )
+    /// Print [`Self::deprecation_notices`] at the configured level
+    ///
+    /// Call this once per run, after the configuration is loaded, rather
+    /// than once per linted file.
+    pub fn report_deprecations(&self, config: &Config) {
+        let prefix = match config.deprecated_warning {
+            crate::config::DeprecatedWarningLevel::Warn => "Warning",
+            crate::config::DeprecatedWarningLevel::Info => "Info",
+            crate::config::DeprecatedWarningLevel::Silent => return,
         };
-
-        match config.deprecated_warning {
-            crate::config::DeprecatedWarningLevel::Warn => {
-                eprintln!("Warning: {message}");
-            }
-            crate::config::DeprecatedWarningLevel::Info => {
-                eprintln!("Info: {message}");
-            }
-            crate::config::DeprecatedWarningLevel::Silent => {
-                // No output
-            }
+        for notice in self.deprecation_notices(config) {
+            eprintln!("{prefix}: {notice}");
         }
     }
 
@@ -248,27 +413,101 @@ impl RuleRegistry {
         document: &Document,
         config: &Config,
     ) -> Result<Vec<Violation>> {
+        self.check_document_timed_with_config(document, config)
+            .map(|(violations, _)| violations)
+    }
+
+    /// Check a document like [`Self::check_document_optimized_with_config`],
+    /// also measuring the parse and the time spent in each rule
+    pub fn check_document_timed_with_config(
+        &self,
+        document: &Document,
+        config: &Config,
+    ) -> Result<(Vec<Violation>, DocumentTiming)> {
+        let context = LintContext::for_document(document);
+        self.check_document_timed_with_context(document, config, &context)
+    }
+
+    /// Check a document like [`Self::check_document_timed_with_config`] with
+    /// a given project context
+    ///
+    /// Rules whose metadata declares
+    /// [`filesystem`](crate::rule::RuleMetadata::filesystem) get `context`;
+    /// the others get a copy without file access. Returns
+    /// [`MdBookLintError::Cancelled`](crate::MdBookLintError::Cancelled) if the
+    /// context's cancellation token fires before every rule has run.
+    pub fn check_document_timed_with_context(
+        &self,
+        document: &Document,
+        config: &Config,
+        context: &LintContext,
+    ) -> Result<(Vec<Violation>, DocumentTiming)> {
         use comrak::Arena;
 
+        let restricted = context.without_filesystem();
+
+        let mut timing = DocumentTiming::default();
+
         // Parse AST once
+        let started = Instant::now();
         let arena = Arena::new();
         let ast = document.parse_ast(&arena);
+        timing.parse = started.elapsed();
 
+        let config = config.for_document(document);
+        let config = config.as_ref();
         let mut all_violations = Vec::new();
+        let mut crashes = Vec::new();
         let enabled_rules = self.get_enabled_rules_with_overrides(document, config);
 
-        // Run enabled rules with the pre-parsed AST
+        // Run enabled rules with the pre-parsed AST, stopping between rules
+        // if the lint is cancelled
         for rule in enabled_rules {
-            let violations = rule.check_with_ast(document, Some(ast))?;
+            context.cancellation().check()?;
+            let started = Instant::now();
+            let rule_context = if rule.metadata().filesystem {
+                context
+            } else {
+                &restricted
+            };
+            let violations = run_sandboxed(rule.id(), rule.name(), &mut crashes, || {
+                rule.check_with_context(document, Some(ast), rule_context)
+            })?;
+            tracing::trace!(
+                rule = rule.id(),
+                path = %document.path.display(),
+                violations = violations.len(),
+                elapsed = ?started.elapsed(),
+                "rule finished"
+            );
+            timing.rules.push(RuleTiming {
+                rule_id: rule.id(),
+                duration: started.elapsed(),
+                violations: violations.len(),
+            });
             all_violations.extend(violations);
         }
 
+        // Drop violations in regions their rule is configured to ignore
+        if config.has_region_filters() {
+            let regions = RegionMap::from_ast(ast, document.lines.len());
+            config.apply_region_filters(&regions, &mut all_violations);
+        }
+        config.apply_suppressions(document, &mut all_violations);
+
         // Apply deduplication to eliminate duplicate violations
-        let dedup_config = crate::deduplication::DeduplicationConfig::default();
-        let deduplicated_violations =
+        let dedup_config = crate::deduplication::DeduplicationConfig::with_policy(config.dedupe);
+        let mut deduplicated_violations =
             crate::deduplication::deduplicate_violations(all_violations, &dedup_config);
 
-        Ok(deduplicated_violations)
+        // Remap severities so callers see the configured levels
+        config.apply_severity_overrides(&mut deduplicated_violations);
+
+        // Internal rule errors are reported as they are
+        deduplicated_violations.extend(crashes);
+        self.attach_doc_urls(&mut deduplicated_violations);
+
+        Ok((deduplicated_violations, timing))
     }
 
     /// Check a document with enabled rules
@@ -277,19 +516,33 @@ impl RuleRegistry {
         document: &Document,
         config: &Config,
     ) -> Result<Vec<Violation>> {
+        let config = config.for_document(document);
+        let config = config.as_ref();
         let mut all_violations = Vec::new();
+        let mut crashes = Vec::new();
         let enabled_rules = self.get_enabled_rules_with_overrides(document, config);
 
         for rule in enabled_rules {
-            let violations = rule.check(document)?;
+            let violations = run_sandboxed(rule.id(), rule.name(), &mut crashes, || {
+                rule.check(document)
+            })?;
             all_violations.extend(violations);
         }
 
+        if config.has_region_filters() {
+            config.apply_region_filters(document.regions(), &mut all_violations);
+        }
+        config.apply_suppressions(document, &mut all_violations);
+
         // Apply deduplication to eliminate duplicate violations
-        let dedup_config = crate::deduplication::DeduplicationConfig::default();
-        let deduplicated_violations =
+        let dedup_config = crate::deduplication::DeduplicationConfig::with_policy(config.dedupe);
+        let mut deduplicated_violations =
             crate::deduplication::deduplicate_violations(all_violations, &dedup_config);
 
+        config.apply_severity_overrides(&mut deduplicated_violations);
+        deduplicated_violations.extend(crashes);
+        self.attach_doc_urls(&mut deduplicated_violations);
+
         Ok(deduplicated_violations)
     }
 
@@ -303,16 +556,22 @@ impl RuleRegistry {
     /// Check a document with all rules
     pub fn check_document(&self, document: &Document) -> Result<Vec<Violation>> {
         let mut all_violations = Vec::new();
+        let mut crashes = Vec::new();
 
         for rule in &self.rules {
-            let violations = rule.check(document)?;
+            let violations = run_sandboxed(rule.id(), rule.name(), &mut crashes, || {
+                rule.check(document)
+            })?;
             all_violations.extend(violations);
         }
+        Config::default().apply_suppressions(document, &mut all_violations);
 
         // Apply deduplication to eliminate duplicate violations
         let dedup_config = crate::deduplication::DeduplicationConfig::default();
-        let deduplicated_violations =
+        let mut deduplicated_violations =
             crate::deduplication::deduplicate_violations(all_violations, &dedup_config);
+        deduplicated_violations.extend(crashes);
+        self.attach_doc_urls(&mut deduplicated_violations);
 
         Ok(deduplicated_violations)
     }
@@ -323,12 +582,17 @@ impl RuleRegistry {
     /// Collection rules can see all documents at once, allowing for cross-document validation.
     pub fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
         let mut all_violations = Vec::new();
+        let mut crashes = Vec::new();
 
         for rule in &self.collection_rules {
-            let violations = rule.check_collection(documents)?;
+            let violations = run_sandboxed(rule.id(), rule.name(), &mut crashes, || {
+                rule.check_collection(documents)
+            })?;
             all_violations.extend(violations);
         }
 
+        all_violations.extend(crashes);
+        self.attach_doc_urls(&mut all_violations);
         Ok(all_violations)
     }
 
@@ -339,29 +603,89 @@ impl RuleRegistry {
         config: &Config,
     ) -> Result<Vec<Violation>> {
         let mut all_violations = Vec::new();
+        let mut crashes = Vec::new();
 
         for rule in &self.collection_rules {
-            let rule_id = rule.id();
-
-            // Check if rule is disabled
-            if config.disabled_rules.contains(&rule_id.to_string()) {
-                continue;
-            }
-
-            // If enabled_rules is specified, only run rules in that list
-            if !config.enabled_rules.is_empty()
-                && !config.enabled_rules.contains(&rule_id.to_string())
-            {
+            if !self.should_run(rule.id(), &rule.metadata(), config) {
                 continue;
             }
 
-            let violations = rule.check_collection(documents)?;
+            let violations = run_sandboxed(rule.id(), rule.name(), &mut crashes, || {
+                rule.check_collection(documents)
+            })?;
             all_violations.extend(violations);
         }
 
+        let mut all_violations =
+            self.filter_collection_violations(documents, config, all_violations);
+        all_violations.extend(crashes);
+        self.attach_doc_urls(&mut all_violations);
+
         Ok(all_violations)
     }
 
+    /// Filter collection violations the way the document each one points at
+    /// filters its own: front-matter overrides, ignored regions and inline
+    /// suppressions, then severity overrides
+    fn filter_collection_violations(
+        &self,
+        documents: &[Document],
+        config: &Config,
+        violations: Vec<Violation>,
+    ) -> Vec<Violation> {
+        let mut by_document: Vec<Vec<Violation>> = vec![Vec::new(); documents.len()];
+        let mut unowned = Vec::new();
+        for violation in violations {
+            let index = violation
+                .path
+                .as_ref()
+                .and_then(|path| documents.iter().position(|doc| &doc.path == path));
+            match index {
+                Some(index) => by_document[index].push(violation),
+                None => unowned.push(violation),
+            }
+        }
+
+        let mut filtered = Vec::new();
+        for (document, mut violations) in documents.iter().zip(by_document) {
+            if violations.is_empty() {
+                continue;
+            }
+            let config = config.for_document(document);
+            violations.retain(|violation| {
+                self.collection_rules
+                    .iter()
+                    .find(|rule| rule.id() == violation.rule_id)
+                    .is_none_or(|rule| self.should_run(rule.id(), &rule.metadata(), &config))
+            });
+            if config.has_region_filters() {
+                config.apply_region_filters(document.regions(), &mut violations);
+            }
+            let suppressions = Suppressions::from_document(document);
+            violations.retain(|violation| !suppressions.suppresses(violation));
+            config.apply_severity_overrides(&mut violations);
+            filtered.extend(violations);
+        }
+
+        config.apply_severity_overrides(&mut unowned);
+        filtered.extend(unowned);
+        filtered
+    }
+
+    /// Fill in each violation's documentation URL from the rule that raised it
+    fn attach_doc_urls(&self, violations: &mut [Violation]) {
+        for violation in violations.iter_mut().filter(|v| v.url.is_none()) {
+            violation.url = match self.get_rule(&violation.rule_id) {
+                Some(rule) => rule.doc_url(),
+                None => self
+                    .collection_rules
+                    .iter()
+                    .find(|rule| rule.id() == violation.rule_id)
+                    .and_then(|rule| rule.doc_url()),
+            };
+        }
+    }
+
     /// Get the number of registered rules
     pub fn len(&self) -> usize {
         self.rules.len()
@@ -383,6 +707,46 @@ impl RuleRegistry {
     }
 }
 
+/// Run a rule, turning a panic into an internal rule error violation
+///
+/// One broken rule must not take down the preprocessor mid-build, so the panic
+/// is reported against the rule and the remaining rules still run. The
+/// report goes to `crashes` rather than the returned violations: callers add
+/// it after region filters, suppressions, and severity overrides, none of
+/// which may hide or downgrade it.
+fn run_sandboxed(
+    rule_id: &str,
+    rule_name: &str,
+    crashes: &mut Vec<Violation>,
+    check: impl FnOnce() -> Result<Vec<Violation>>,
+) -> Result<Vec<Violation>> {
+    catch_unwind(AssertUnwindSafe(check)).unwrap_or_else(|payload| {
+        let message = panic_message(payload.as_ref());
+        tracing::error!(rule = rule_id, %message, "rule panicked");
+        crashes.push(Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_name.to_string(),
+            message: format!("Internal rule error: {rule_id} panicked: {message}"),
+            line: 1,
+            column: 1,
+            severity: Severity::Error,
+            fix: None,
+            url: None,
+            path: None,
+        });
+        Ok(Vec::new())
+    })
+}
+
+/// The message passed to `panic!`, when it is a string
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
 impl Default for RuleRegistry {
     /// Create a new empty registry
     ///
@@ -404,11 +768,28 @@ mod tests {
     struct TestRule {
         id: &'static str,
         name: &'static str,
+        url: Option<&'static str>,
+        metadata: Option<RuleMetadata>,
     }
 
     impl TestRule {
         fn new(id: &'static str, name: &'static str) -> Self {
-            Self { id, name }
+            Self {
+                id,
+                name,
+                url: None,
+                metadata: None,
+            }
+        }
+
+        fn with_metadata(mut self, metadata: RuleMetadata) -> Self {
+            self.metadata = Some(metadata);
+            self
+        }
+
+        fn with_url(mut self, url: &'static str) -> Self {
+            self.url = Some(url);
+            self
         }
     }
 
@@ -426,7 +807,14 @@ mod tests {
         }
 
         fn metadata(&self) -> RuleMetadata {
-            RuleMetadata::stable(RuleCategory::Structure)
+            let metadata = self
+                .metadata
+                .clone()
+                .unwrap_or_else(|| RuleMetadata::stable(RuleCategory::Structure));
+            match self.url {
+                Some(url) => metadata.url(url),
+                None => metadata,
+            }
         }
 
         fn check_with_ast<'a>(
@@ -443,6 +831,75 @@ mod tests {
         }
     }
 
+    // Collection rule that reports once per run
+    struct TestCollectionRule {
+        id: &'static str,
+        metadata: RuleMetadata,
+    }
+
+    impl crate::rule::CollectionRule for TestCollectionRule {
+        fn id(&self) -> &'static str {
+            self.id
+        }
+
+        fn name(&self) -> &'static str {
+            "test-collection-rule"
+        }
+
+        fn description(&self) -> &'static str {
+            "A test collection rule"
+        }
+
+        fn metadata(&self) -> RuleMetadata {
+            self.metadata.clone()
+        }
+
+        fn check_collection(&self, _documents: &[Document]) -> Result<Vec<Violation>> {
+            Ok(vec![self.create_violation(
+                format!("Test violation from {}", self.id),
+                1,
+                1,
+                crate::violation::Severity::Warning,
+            )])
+        }
+    }
+
+    /// Collection rule flagging the last line of every document
+    struct LastLineRule;
+
+    impl crate::rule::CollectionRule for LastLineRule {
+        fn id(&self) -> &'static str {
+            "TEST005"
+        }
+
+        fn name(&self) -> &'static str {
+            "last-line"
+        }
+
+        fn description(&self) -> &'static str {
+            "Flags the last line of every document"
+        }
+
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata::stable(RuleCategory::Structure)
+        }
+
+        fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+            Ok(documents
+                .iter()
+                .map(|doc| {
+                    self.create_violation_for_file(
+                        &doc.path,
+                        "Last line".to_string(),
+                        doc.lines.len(),
+                        1,
+                        crate::violation::Severity::Warning,
+                    )
+                })
+                .collect())
+        }
+    }
+
     #[test]
     fn test_empty_registry() {
         let registry = RuleRegistry::new();
@@ -504,6 +961,207 @@ mod tests {
         assert_eq!(enabled[0].id(), "TEST001");
     }
 
+    #[test]
+    fn test_deprecation_notices() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "current")));
+        registry.register(Box::new(TestRule::new("TEST002", "old").with_metadata(
+            RuleMetadata::deprecated(
+                RuleCategory::Structure,
+                "Superseded by TEST001",
+                Some("TEST001"),
+            ),
+        )));
+        registry.register_placeholder(Box::new(
+            TestRule::new("TEST003", "placeholder")
+                .with_metadata(RuleMetadata::reserved("Never implemented upstream")),
+        ));
+        assert_eq!(registry.rule_ids(), vec!["TEST001", "TEST002"]);
+        assert_eq!(registry.placeholder_ids(), vec!["TEST003"]);
+
+        // Rules the configuration doesn't mention get no notice
+        assert!(registry.deprecation_notices(&Config::default()).is_empty());
+
+        let mut config = Config {
+            enabled_rules: vec!["TEST002".to_string(), "TEST001".to_string()],
+            ..Default::default()
+        };
+        config.rule_configs.insert(
+            "TEST002".to_string(),
+            toml::Value::Table(Default::default()),
+        );
+        config
+            .severity
+            .insert("TEST003".to_string(), crate::violation::Severity::Error);
+        assert_eq!(
+            registry.deprecation_notices(&config),
+            vec![
+                "Rule TEST002 is deprecated - Superseded by TEST001. Consider using TEST001 instead.",
+                "Rule TEST003 is reserved and never reports anything - Never implemented upstream.",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ruleset_version_pin() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "old").with_metadata(
+            RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.1.0"),
+        )));
+        registry.register(Box::new(TestRule::new("TEST002", "new").with_metadata(
+            RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.15.0"),
+        )));
+        registry.register(Box::new(
+            TestRule::new("TEST003", "upstream").with_metadata(
+                RuleMetadata::stable(RuleCategory::Structure).introduced_in("markdownlint v0.1.0"),
+            ),
+        ));
+        assert_eq!(registry.latest_rule_version(), Some((0, 15, 0)));
+
+        let ids = |config: &Config| -> Vec<&str> {
+            registry
+                .get_enabled_rules(config)
+                .iter()
+                .map(|rule| rule.id())
+                .collect()
+        };
+        assert_eq!(
+            ids(&Config::default()),
+            vec!["TEST001", "TEST002", "TEST003"]
+        );
+        assert!(registry.ruleset_notice(&Config::default()).is_none());
+
+        let mut config = Config {
+            ruleset_version: Some("0.14".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(ids(&config), vec!["TEST001", "TEST003"]);
+        assert_eq!(registry.held_back_rules(&config), vec!["TEST002"]);
+        assert!(
+            registry.ruleset_notice(&config).unwrap().starts_with(
+                "1 rule(s) added after ruleset-version 0.14 are not running: TEST002."
+            )
+        );
+
+        // Listing a rule runs it regardless of the pin
+        config.enabled_rules = vec!["TEST001".to_string(), "TEST002".to_string()];
+        assert_eq!(ids(&config), vec!["TEST001", "TEST002"]);
+        assert!(registry.held_back_rules(&config).is_empty());
+
+        config.enabled_rules.clear();
+        config.ruleset_version = Some("v0.15.0".to_string());
+        assert_eq!(ids(&config), vec!["TEST001", "TEST002", "TEST003"]);
+    }
+
+    #[test]
+    fn test_ruleset_version_pin_opt_in_and_collection_rules() {
+        let new =
+            || RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.15.0");
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(
+            TestRule::new("TEST001", "new").with_metadata(new()),
+        ));
+        registry.register(Box::new(
+            TestRule::new("TEST002", "opt-in").with_metadata(new().opt_in()),
+        ));
+        registry.register_collection_rule(Box::new(TestCollectionRule {
+            id: "TEST003",
+            metadata: new(),
+        }));
+        registry.register_collection_rule(Box::new(TestCollectionRule {
+            id: "TEST004",
+            metadata: new().opt_in(),
+        }));
+
+        let mut config = Config {
+            ruleset_version: Some("0.14.0".to_string()),
+            ..Default::default()
+        };
+        // Opt-in rules are only held back once configured
+        assert_eq!(
+            registry.held_back_rules(&config),
+            vec!["TEST001", "TEST003"]
+        );
+        config.rule_configs.insert(
+            "TEST004".to_string(),
+            toml::Value::Table(Default::default()),
+        );
+        assert_eq!(
+            registry.held_back_rules(&config),
+            vec!["TEST001", "TEST003", "TEST004"]
+        );
+
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+        let collection_ids = |config: &Config| -> Vec<String> {
+            registry
+                .check_collection_with_config(std::slice::from_ref(&document), config)
+                .unwrap()
+                .into_iter()
+                .map(|v| v.rule_id)
+                .collect()
+        };
+        assert!(collection_ids(&config).is_empty());
+
+        config.enabled_rules = vec!["TEST003".to_string()];
+        assert_eq!(collection_ids(&config), vec!["TEST003"]);
+        assert!(registry.held_back_rules(&config).is_empty());
+
+        config.enabled_rules.clear();
+        config.ruleset_version = None;
+        assert_eq!(collection_ids(&config), vec!["TEST003", "TEST004"]);
+    }
+
+    #[test]
+    fn test_collection_violations_filtered_per_document() {
+        let mut registry = RuleRegistry::new();
+        registry.register_collection_rule(Box::new(LastLineRule));
+        let documents: Vec<Document> = [
+            ("plain.md", "# Plain\n"),
+            (
+                "inline.md",
+                "<!-- mdbook-lint-disable TEST005 -->\n# Inline\n",
+            ),
+            (
+                "front.md",
+                "---\nmdbook-lint:\n  disable: TEST005\n---\n# Front\n",
+            ),
+            (
+                "severity.md",
+                "---\nmdbook-lint:\n  severity:\n    TEST005: error\n---\n# Severity\n",
+            ),
+        ]
+        .into_iter()
+        .map(|(path, content)| Document::new(content.to_string(), PathBuf::from(path)).unwrap())
+        .collect();
+
+        let violations = registry
+            .check_collection_with_config(&documents, &Config::default())
+            .unwrap();
+        let found: Vec<_> = violations
+            .iter()
+            .map(|v| (v.path.clone().unwrap(), v.severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (PathBuf::from("plain.md"), Severity::Warning),
+                (PathBuf::from("severity.md"), Severity::Error),
+            ]
+        );
+
+        // Category filters apply to collection rules too
+        let config = Config {
+            disabled_categories: vec!["structure".to_string()],
+            ..Default::default()
+        };
+        assert!(
+            registry
+                .check_collection_with_config(&documents, &config)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn test_document_checking() {
         let mut registry = RuleRegistry::new();
@@ -530,6 +1188,427 @@ mod tests {
         assert_eq!(violations[0].rule_id, "TEST001");
     }
 
+    #[test]
+    fn test_severity_overrides_applied() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "test-rule-1")));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+
+        let mut config = Config::default();
+        config
+            .severity
+            .insert("TEST001".to_string(), crate::violation::Severity::Error);
+
+        let violations = registry
+            .check_document_optimized_with_config(&document, &config)
+            .unwrap();
+        assert_eq!(violations.len(), 2);
+
+        let test001 = violations.iter().find(|v| v.rule_id == "TEST001").unwrap();
+        assert_eq!(test001.severity, crate::violation::Severity::Error);
+
+        // Rules without an override keep their original severity
+        let test002 = violations.iter().find(|v| v.rule_id == "TEST002").unwrap();
+        assert_eq!(test002.severity, crate::violation::Severity::Warning);
+    }
+
+    #[test]
+    fn test_region_filters_applied() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "test-rule-1")));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+        registry.register(Box::new(TestRule::new("TEST003", "test-rule-3")));
+
+        let mut config = Config::default();
+        let table = |key: &str| toml::from_str::<toml::Value>(&format!("{key} = true")).unwrap();
+        config
+            .rule_configs
+            .insert("TEST001".to_string(), table("ignore-headings"));
+        config
+            .rule_configs
+            .insert("TEST002".to_string(), table("ignore-tables"));
+
+        // Every test rule reports line 1, which is a heading here
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+        for violations in [
+            registry.check_document_optimized_with_config(&document, &config),
+            registry.check_document_with_config(&document, &config),
+        ] {
+            let mut ids: Vec<String> = violations.unwrap().into_iter().map(|v| v.rule_id).collect();
+            ids.sort();
+            assert_eq!(ids, vec!["TEST002", "TEST003"]);
+        }
+    }
+
+    #[test]
+    fn test_suppression_comments_applied() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "test-rule-1")));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+        registry.register(Box::new(TestRule::new("TEST003", "test-rule-3")));
+
+        // Every test rule reports line 1, where the comment turns two off
+        let document = Document::new(
+            "<!-- mdbook-lint-disable TEST001 test-rule-2 -->\n".to_string(),
+            PathBuf::from("test.md"),
+        )
+        .unwrap();
+        let rule_ids = |config: &Config| {
+            let mut ids: Vec<String> = registry
+                .check_document_optimized_with_config(&document, config)
+                .unwrap()
+                .into_iter()
+                .map(|v| v.rule_id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(rule_ids(&Config::default()), vec!["TEST003"]);
+
+        let config = Config {
+            require_suppression_reason: true,
+            ..Config::default()
+        };
+        assert_eq!(rule_ids(&config), vec!["SUPPRESS001", "TEST003"]);
+    }
+
+    #[test]
+    fn test_blockquote_depth_filters_applied() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "test-rule-1")));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+        registry.register(Box::new(TestRule::new("TEST003", "test-rule-3")));
+
+        let mut config = Config {
+            ignore_blockquotes: Some(crate::IgnoreBlockquotes::Depth(2)),
+            ..Config::default()
+        };
+        let table = |text: &str| toml::from_str::<toml::Value>(text).unwrap();
+        config
+            .rule_configs
+            .insert("TEST002".to_string(), table("ignore-blockquotes = false"));
+        config
+            .rule_configs
+            .insert("TEST003".to_string(), table("ignore-blockquotes = 3"));
+
+        // Every test rule reports line 1, which is quoted twice here
+        let document = Document::new("> > Test".to_string(), PathBuf::from("test.md")).unwrap();
+        for violations in [
+            registry.check_document_optimized_with_config(&document, &config),
+            registry.check_document_with_config(&document, &config),
+        ] {
+            let mut ids: Vec<String> = violations.unwrap().into_iter().map(|v| v.rule_id).collect();
+            ids.sort();
+            assert_eq!(ids, vec!["TEST002", "TEST003"]);
+        }
+    }
+
+    #[test]
+    fn test_front_matter_overrides() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "test-rule-1")));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+        registry.register(Box::new(TestRule::new("TEST003", "test-rule-3")));
+
+        let check = |content: &str| {
+            let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+            let mut violations: Vec<(String, crate::violation::Severity)> = registry
+                .check_document_optimized_with_config(&document, &Config::default())
+                .unwrap()
+                .into_iter()
+                .map(|v| (v.rule_id, v.severity))
+                .collect();
+            violations.sort_by(|a, b| a.0.cmp(&b.0));
+            violations
+        };
+
+        let yaml = "---\ntitle: Setup\nmdbook-lint:\n  disable: [TEST001]\n  severity:\n    TEST002: info\n---\n\n# Setup\n";
+        assert_eq!(
+            check(yaml),
+            vec![
+                ("TEST002".to_string(), crate::violation::Severity::Info),
+                ("TEST003".to_string(), crate::violation::Severity::Warning),
+            ]
+        );
+
+        let toml = "+++\n[mdbook-lint]\ndisable = \"TEST003\"\n+++\n\n# Setup\n";
+        assert_eq!(check(toml).len(), 2);
+
+        // Other keys and unparseable front matter leave the configuration alone
+        assert_eq!(check("---\ntitle: Setup\n---\n\n# Setup\n").len(), 3);
+        assert_eq!(check("---\n: [\n---\n\n# Setup\n").len(), 3);
+    }
+
+    /// Reports whether it was given file access
+    struct ContextRule {
+        id: &'static str,
+        filesystem: bool,
+    }
+
+    impl Rule for ContextRule {
+        fn id(&self) -> &'static str {
+            self.id
+        }
+
+        fn name(&self) -> &'static str {
+            "context-rule"
+        }
+
+        fn description(&self) -> &'static str {
+            "Reports its context"
+        }
+
+        fn metadata(&self) -> RuleMetadata {
+            let metadata = RuleMetadata::stable(RuleCategory::MdBook);
+            if self.filesystem {
+                metadata.filesystem()
+            } else {
+                metadata
+            }
+        }
+
+        fn check_with_ast<'a>(
+            &self,
+            document: &Document,
+            ast: Option<&'a comrak::nodes::AstNode<'a>>,
+        ) -> Result<Vec<Violation>> {
+            self.check_with_context(document, ast, &LintContext::detached())
+        }
+
+        fn check_with_context<'a>(
+            &self,
+            _document: &Document,
+            _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+            context: &LintContext,
+        ) -> Result<Vec<Violation>> {
+            Ok(vec![self.create_violation(
+                format!("filesystem: {}", context.has_filesystem()),
+                1,
+                1,
+                crate::violation::Severity::Info,
+            )])
+        }
+    }
+
+    #[test]
+    fn test_rules_scoped_by_document_kind() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("TEST001", "any-kind")));
+        registry.register(Box::new(
+            TestRule::new("TEST002", "chapters-only").with_metadata(
+                RuleMetadata::stable(RuleCategory::Structure).applies_to(&[DocumentKind::Chapter]),
+            ),
+        ));
+
+        let rule_ids = |path: &str, config: &Config| {
+            let document = Document::new("# Title\n".to_string(), PathBuf::from(path)).unwrap();
+            registry
+                .get_enabled_rules_with_overrides(&document, config)
+                .iter()
+                .map(|rule| rule.id())
+                .collect::<Vec<_>>()
+        };
+
+        let config = Config::default();
+        assert_eq!(
+            rule_ids("src/intro.md", &config),
+            vec!["TEST001", "TEST002"]
+        );
+        assert_eq!(rule_ids("src/_partial.md", &config), vec!["TEST001"]);
+        assert_eq!(rule_ids("src/SUMMARY.md", &config), vec!["TEST001"]);
+
+        // `document-kinds` narrows a rule or widens one past its declaration
+        let mut config = Config::default();
+        let table = |text: &str| toml::from_str::<toml::Value>(text).unwrap();
+        config.rule_configs.insert(
+            "TEST001".to_string(),
+            table("document-kinds = [\"chapter\"]"),
+        );
+        config.rule_configs.insert(
+            "TEST002".to_string(),
+            table("document-kinds = [\"chapter\", \"fragment\"]"),
+        );
+        assert_eq!(rule_ids("src/_partial.md", &config), vec!["TEST002"]);
+        assert_eq!(rule_ids("README.md", &config), Vec::<&str>::new());
+
+        // `fragment-paths` makes any matching file a fragment
+        let config = Config {
+            fragment_paths: vec!["src/reuse/**".to_string(), "shared/".to_string()],
+            ..Config::default()
+        };
+        assert_eq!(rule_ids("src/reuse/install.md", &config), vec!["TEST001"]);
+        assert_eq!(rule_ids("book/shared/notice.md", &config), vec!["TEST001"]);
+        assert_eq!(
+            rule_ids("src/intro.md", &config),
+            vec!["TEST001", "TEST002"]
+        );
+
+        // A kind set by the caller wins over the path
+        let document = Document::new("# Title\n".to_string(), PathBuf::from("src/_partial.md"))
+            .unwrap()
+            .with_kind(DocumentKind::Chapter);
+        assert_eq!(
+            registry
+                .get_enabled_rules_with_overrides(&document, &Config::default())
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_context_filesystem_capability() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(ContextRule {
+            id: "CTX001",
+            filesystem: true,
+        }));
+        registry.register(Box::new(ContextRule {
+            id: "CTX002",
+            filesystem: false,
+        }));
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+        let config = Config::default();
+
+        let messages = |context: &LintContext| {
+            let (violations, _) = registry
+                .check_document_timed_with_context(&document, &config, context)
+                .unwrap();
+            let mut messages: Vec<String> = violations
+                .into_iter()
+                .map(|v| format!("{} {}", v.rule_id, v.message))
+                .collect();
+            messages.sort();
+            messages
+        };
+
+        assert_eq!(
+            messages(&LintContext::for_document(&document)),
+            vec!["CTX001 filesystem: true", "CTX002 filesystem: false"]
+        );
+        assert_eq!(
+            messages(&LintContext::detached()),
+            vec!["CTX001 filesystem: false", "CTX002 filesystem: false"]
+        );
+    }
+
+    /// Panics instead of checking
+    struct PanickingRule;
+
+    impl Rule for PanickingRule {
+        fn id(&self) -> &'static str {
+            "PANIC001"
+        }
+
+        fn name(&self) -> &'static str {
+            "panicking-rule"
+        }
+
+        fn description(&self) -> &'static str {
+            "Always panics"
+        }
+
+        fn metadata(&self) -> RuleMetadata {
+            RuleMetadata::stable(RuleCategory::Structure)
+        }
+
+        fn check_with_ast<'a>(
+            &self,
+            _document: &Document,
+            _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+        ) -> Result<Vec<Violation>> {
+            panic!("index {} out of range", 7)
+        }
+    }
+
+    #[test]
+    fn test_panicking_rule_is_sandboxed() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(PanickingRule));
+        registry.register(Box::new(ContextRule {
+            id: "CTX001",
+            filesystem: false,
+        }));
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+
+        let mut violations = registry
+            .check_document_optimized_with_config(&document, &Config::default())
+            .unwrap();
+        violations.sort_by(|a, b| a.rule_id.cmp(&b.rule_id));
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].rule_id, "CTX001");
+        assert_eq!(violations[1].rule_id, "PANIC001");
+        assert_eq!(violations[1].severity, crate::violation::Severity::Error);
+        assert_eq!(
+            violations[1].message,
+            "Internal rule error: PANIC001 panicked: index 7 out of range"
+        );
+        assert_eq!(registry.check_document(&document).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_panicking_rule_survives_suppressions_and_overrides() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(PanickingRule));
+        let document = Document::new(
+            "<!-- mdbook-lint-disable PANIC001 -->\n# Test".to_string(),
+            PathBuf::from("test.md"),
+        )
+        .unwrap();
+        let mut config = Config::default();
+        config
+            .severity
+            .insert("PANIC001".to_string(), crate::violation::Severity::Info);
+
+        let violations = registry
+            .check_document_optimized_with_config(&document, &config)
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, crate::violation::Severity::Error);
+
+        let violations = registry
+            .check_document_with_config(&document, &config)
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, crate::violation::Severity::Error);
+    }
+
+    #[test]
+    fn test_doc_urls_attached() {
+        let mut registry = RuleRegistry::new();
+        registry.register(Box::new(TestRule::new("MD001", "heading-increment")));
+        registry.register(Box::new(
+            TestRule::new("TEST001", "test-rule").with_url("https://example.com/test001"),
+        ));
+        registry.register(Box::new(TestRule::new("TEST002", "test-rule-2")));
+
+        let document = Document::new("# Test".to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = registry
+            .check_document_optimized_with_config(&document, &Config::default())
+            .unwrap();
+        let url = |id: &str| {
+            violations
+                .iter()
+                .find(|v| v.rule_id == id)
+                .unwrap()
+                .url
+                .clone()
+        };
+
+        assert_eq!(
+            url("MD001").as_deref(),
+            Some("https://joshrotenberg.github.io/mdbook-lint/rules/standard/md001.html")
+        );
+        assert_eq!(
+            url("TEST001").as_deref(),
+            Some("https://example.com/test001")
+        );
+        assert_eq!(url("TEST002"), None);
+    }
+
     #[test]
     fn test_default_registry_is_empty() {
         let registry = RuleRegistry::default();
@@ -1,4 +1,11 @@
-use crate::{Document, error::Result, violation::Violation};
+use crate::{
+    Document,
+    context::LintContext,
+    error::Result,
+    kind::DocumentKind,
+    regions::{IgnoreBlockquotes, Region},
+    violation::Violation,
+};
 use comrak::{Arena, nodes::AstNode};
 
 /// Rule stability levels
@@ -48,6 +55,145 @@ pub struct RuleMetadata {
     pub stability: RuleStability,
     /// Rules that this rule overrides (for context-specific rules)
     pub overrides: Option<&'static str>,
+    /// Documentation page, when it isn't the built-in one derived from the
+    /// rule ID (see [`builtin_doc_url`])
+    pub url: Option<&'static str>,
+    /// Whether the rule reads files beyond the document through its
+    /// [`LintContext`]
+    pub filesystem: bool,
+    /// Settings the rule reads from its configuration table
+    pub options: Vec<RuleOption>,
+    /// Whether option names may be spelled with `-` or `_` interchangeably
+    pub either_case: bool,
+    /// Kinds of document the rule applies to, or `None` for every kind
+    pub kinds: Option<&'static [DocumentKind]>,
+    /// Whether the rule does nothing until it is listed in `enabled-rules`
+    /// or given a configuration table
+    pub opt_in: bool,
+}
+
+/// Type of value a rule option accepts
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptionKind {
+    Boolean,
+    Integer,
+    /// Integer no smaller than the bound
+    IntegerAtLeast(i64),
+    /// Integer within the inclusive bounds
+    IntegerBetween(i64, i64),
+    Number,
+    /// Number within the inclusive bounds
+    NumberBetween(f64, f64),
+    String,
+    /// One of a fixed set of strings
+    Choice(&'static [&'static str]),
+    /// Array of strings
+    StringList,
+    /// Table, or a value with several accepted shapes
+    Any,
+}
+
+impl OptionKind {
+    /// JSON Schema describing values of this kind
+    pub fn json_schema(&self) -> serde_json::Value {
+        use serde_json::json;
+        match self {
+            OptionKind::Boolean => json!({"type": "boolean"}),
+            OptionKind::Integer => json!({"type": "integer"}),
+            OptionKind::IntegerAtLeast(min) => json!({"type": "integer", "minimum": min}),
+            OptionKind::IntegerBetween(min, max) => {
+                json!({"type": "integer", "minimum": min, "maximum": max})
+            }
+            OptionKind::Number => json!({"type": "number"}),
+            OptionKind::NumberBetween(min, max) => {
+                json!({"type": "number", "minimum": min, "maximum": max})
+            }
+            OptionKind::String => json!({"type": "string"}),
+            OptionKind::Choice(values) => json!({"type": "string", "enum": values}),
+            OptionKind::StringList => json!({"type": "array", "items": {"type": "string"}}),
+            OptionKind::Any => json!({}),
+        }
+    }
+
+    /// JSON Schema type name of values of this kind
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            OptionKind::Boolean => "boolean",
+            OptionKind::Integer
+            | OptionKind::IntegerAtLeast(_)
+            | OptionKind::IntegerBetween(..) => "integer",
+            OptionKind::Number | OptionKind::NumberBetween(..) => "number",
+            OptionKind::String | OptionKind::Choice(_) => "string",
+            OptionKind::StringList => "array",
+            OptionKind::Any => "any",
+        }
+    }
+
+    /// Whether `value` is valid for this kind
+    pub fn accepts(&self, value: &toml::Value) -> bool {
+        let number = value
+            .as_float()
+            .or_else(|| value.as_integer().map(|n| n as f64));
+        match self {
+            OptionKind::Boolean => value.is_bool(),
+            OptionKind::Integer => value.is_integer(),
+            OptionKind::IntegerAtLeast(min) => value.as_integer().is_some_and(|n| n >= *min),
+            OptionKind::IntegerBetween(min, max) => value
+                .as_integer()
+                .is_some_and(|n| (*min..=*max).contains(&n)),
+            OptionKind::Number => number.is_some(),
+            OptionKind::NumberBetween(min, max) => {
+                number.is_some_and(|n| (*min..=*max).contains(&n))
+            }
+            OptionKind::String => value.is_str(),
+            OptionKind::Choice(values) => value.as_str().is_some_and(|s| values.contains(&s)),
+            OptionKind::StringList => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(toml::Value::is_str)),
+            OptionKind::Any => true,
+        }
+    }
+
+    /// What a valid value looks like, for error messages
+    pub fn expected(&self) -> String {
+        match self {
+            OptionKind::Boolean => "a boolean".to_string(),
+            OptionKind::Integer => "an integer".to_string(),
+            OptionKind::IntegerAtLeast(min) => format!("an integer ≥ {min}"),
+            OptionKind::IntegerBetween(min, max) => {
+                format!("an integer between {min} and {max}")
+            }
+            OptionKind::Number => "a number".to_string(),
+            OptionKind::NumberBetween(min, max) => format!("a number between {min} and {max}"),
+            OptionKind::String => "a string".to_string(),
+            OptionKind::Choice(values) => {
+                let values: Vec<String> = values.iter().map(|v| format!("\"{v}\"")).collect();
+                format!("one of {}", values.join(", "))
+            }
+            OptionKind::StringList => "an array of strings".to_string(),
+            OptionKind::Any => "any value".to_string(),
+        }
+    }
+}
+
+/// A setting a rule reads from its configuration table
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleOption {
+    /// Key in the rule's table, such as `line-length`
+    pub name: &'static str,
+    pub kind: OptionKind,
+    /// Value used when the key is unset, written as TOML
+    pub default: Option<&'static str>,
+    pub description: &'static str,
+}
+
+impl RuleOption {
+    /// The default as a TOML value
+    pub fn default_value(&self) -> Option<toml::Value> {
+        let table: toml::value::Table =
+            toml::from_str(&format!("value = {}", self.default?)).ok()?;
+        table.get("value").cloned()
+    }
 }
 
 impl RuleMetadata {
@@ -61,6 +207,12 @@ impl RuleMetadata {
             introduced_in: None,
             stability: RuleStability::Stable,
             overrides: None,
+            url: None,
+            filesystem: false,
+            options: Vec::new(),
+            either_case: false,
+            kinds: None,
+            opt_in: false,
         }
     }
 
@@ -78,6 +230,12 @@ impl RuleMetadata {
             introduced_in: None,
             stability: RuleStability::Deprecated,
             overrides: None,
+            url: None,
+            filesystem: false,
+            options: Vec::new(),
+            either_case: false,
+            kinds: None,
+            opt_in: false,
         }
     }
 
@@ -91,6 +249,12 @@ impl RuleMetadata {
             introduced_in: None,
             stability: RuleStability::Experimental,
             overrides: None,
+            url: None,
+            filesystem: false,
+            options: Vec::new(),
+            either_case: false,
+            kinds: None,
+            opt_in: false,
         }
     }
 
@@ -104,6 +268,12 @@ impl RuleMetadata {
             introduced_in: None,
             stability: RuleStability::Reserved,
             overrides: None,
+            url: None,
+            filesystem: false,
+            options: Vec::new(),
+            either_case: false,
+            kinds: None,
+            opt_in: false,
         }
     }
 
@@ -113,11 +283,197 @@ impl RuleMetadata {
         self
     }
 
+    /// The mdbook-lint version this rule was added in
+    ///
+    /// `None` for rules without an `introduced_in` or whose version refers
+    /// to another tool, such as `markdownlint v0.1.0`.
+    pub fn mdbook_lint_version(&self) -> Option<(u64, u64, u64)> {
+        self.introduced_in?
+            .strip_prefix("mdbook-lint ")
+            .and_then(crate::config::parse_version)
+    }
+
     /// Set which rule this rule overrides
     pub fn overrides(mut self, rule_id: &'static str) -> Self {
         self.overrides = Some(rule_id);
         self
     }
+
+    /// Set the rule's documentation page
+    pub fn url(mut self, url: &'static str) -> Self {
+        self.url = Some(url);
+        self
+    }
+
+    /// Declare that the rule reads project files through [`LintContext`]
+    ///
+    /// Rules without this flag are given a context whose file accessors
+    /// return nothing.
+    pub fn filesystem(mut self) -> Self {
+        self.filesystem = true;
+        self
+    }
+
+    /// Declare that the rule stays quiet until it is enabled explicitly
+    pub fn opt_in(mut self) -> Self {
+        self.opt_in = true;
+        self
+    }
+
+    /// Describe a setting the rule reads from its configuration table
+    pub fn option(
+        mut self,
+        name: &'static str,
+        kind: OptionKind,
+        description: &'static str,
+    ) -> Self {
+        self.options.push(RuleOption {
+            name,
+            kind,
+            default: None,
+            description,
+        });
+        self
+    }
+
+    /// Set the default of the option declared last, written as TOML
+    pub fn defaults_to(mut self, default: &'static str) -> Self {
+        if let Some(option) = self.options.last_mut() {
+            option.default = Some(default);
+        }
+        self
+    }
+
+    /// Declare the kinds of document the rule applies to
+    ///
+    /// The rule doesn't run on other kinds unless its table's
+    /// `document-kinds` says so.
+    pub fn applies_to(mut self, kinds: &'static [DocumentKind]) -> Self {
+        self.kinds = Some(kinds);
+        self
+    }
+
+    /// Whether the rule applies to documents of `kind`
+    pub fn applies_to_kind(&self, kind: DocumentKind) -> bool {
+        self.kinds.is_none_or(|kinds| kinds.contains(&kind))
+    }
+
+    /// Declare that option names accept both `snake_case` and `kebab-case`
+    pub fn either_case(mut self) -> Self {
+        self.either_case = true;
+        self
+    }
+
+    /// Check a rule's configuration table against its declared options
+    ///
+    /// Returns one message per problem: a key the rule doesn't read, or a
+    /// value of the wrong type or out of range. Every table may also set
+    /// `auto-fix` and the [`Region`] keys, such as `ignore-tables`.
+    pub fn validate_config(&self, rule_id: &str, config: &toml::Value) -> Vec<String> {
+        let Some(table) = config.as_table() else {
+            return vec![format!("{rule_id} must be a table")];
+        };
+
+        let mut problems = Vec::new();
+        for (key, value) in table {
+            if key == Region::Blockquote.config_key() {
+                if IgnoreBlockquotes::from_value(value).is_none() {
+                    problems.push(format!(
+                        "{rule_id}.{key} must be a boolean or a nesting depth"
+                    ));
+                }
+                continue;
+            }
+            if key == DOCUMENT_KINDS_KEY {
+                if parse_document_kinds(value).is_none() {
+                    let names: Vec<&str> = DocumentKind::ALL.iter().map(|k| k.name()).collect();
+                    problems.push(format!(
+                        "{rule_id}.{key} must be a list of document kinds ({})",
+                        names.join(", ")
+                    ));
+                }
+                continue;
+            }
+            if key == "auto-fix" || Region::ALL.iter().any(|r| r.config_key() == key) {
+                if !value.is_bool() {
+                    problems.push(format!("{rule_id}.{key} must be a boolean"));
+                }
+                continue;
+            }
+            let option = self.options.iter().find(|option| {
+                option.name == key
+                    || (self.either_case && option.name.replace('_', "-") == key.replace('_', "-"))
+            });
+            match option {
+                Some(option) if !option.kind.accepts(value) => problems.push(format!(
+                    "{rule_id}.{key} must be {}",
+                    option.kind.expected()
+                )),
+                Some(_) => {}
+                None if self.options.is_empty() => problems.push(format!(
+                    "{rule_id}.{key} is not an option; {rule_id} takes no options"
+                )),
+                None => {
+                    let names: Vec<&str> = self.options.iter().map(|o| o.name).collect();
+                    problems.push(format!(
+                        "{rule_id}.{key} is not an option; {rule_id} takes {}",
+                        names.join(", ")
+                    ));
+                }
+            }
+        }
+        problems
+    }
+}
+
+/// Key in a rule's table that sets the kinds of document it runs on
+pub const DOCUMENT_KINDS_KEY: &str = "document-kinds";
+
+/// Read a `document-kinds` value: a list of kind names
+///
+/// Returns `None` when the value isn't a list or names an unknown kind.
+pub fn parse_document_kinds(value: &toml::Value) -> Option<Vec<DocumentKind>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|kind| kind.as_str().and_then(DocumentKind::from_name))
+        .collect()
+}
+
+/// Base URL of the published mdbook-lint documentation
+pub const DOCS_BASE_URL: &str = "https://joshrotenberg.github.io/mdbook-lint";
+
+/// Documentation page for a built-in rule, derived from its ID
+///
+/// Standard, mdBook, and ADR rules link to their own page; front matter and
+/// spelling rules link to their ruleset page. Other rules (content and custom
+/// rules) have no page and return `None`.
+pub fn builtin_doc_url(rule_id: &str) -> Option<String> {
+    let numbered = |prefix: &str| {
+        rule_id
+            .strip_prefix(prefix)
+            .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+    };
+    let page = if numbered("MDBOOK") {
+        format!("mdbook/{}", rule_id.to_lowercase())
+    } else if numbered("MD") {
+        format!("standard/{}", rule_id.to_lowercase())
+    } else if numbered("ADR") {
+        format!("adr/{}", rule_id.to_lowercase())
+    } else if numbered("RFC") {
+        "rfc/index".to_string()
+    } else if numbered("CHANGELOG") {
+        "changelog/index".to_string()
+    } else if numbered("README") {
+        "readme/index".to_string()
+    } else if numbered("FRONTMATTER") {
+        "frontmatter/index".to_string()
+    } else if numbered("SPELL") {
+        "spelling/index".to_string()
+    } else {
+        return None;
+    };
+    Some(format!("{DOCS_BASE_URL}/rules/{page}.html"))
 }
 
 /// Trait that all linting rules must implement
@@ -134,6 +490,14 @@ pub trait Rule: Send + Sync {
     /// Metadata about this rule's status and properties
     fn metadata(&self) -> RuleMetadata;
 
+    /// Documentation page for this rule
+    fn doc_url(&self) -> Option<String> {
+        self.metadata()
+            .url
+            .map(str::to_string)
+            .or_else(|| builtin_doc_url(self.id()))
+    }
+
     /// Check a document for violations of this rule with optional pre-parsed AST
     fn check_with_ast<'a>(
         &self,
@@ -141,9 +505,27 @@ pub trait Rule: Send + Sync {
         ast: Option<&'a AstNode<'a>>,
     ) -> Result<Vec<Violation>>;
 
+    /// Check a document with the project context it belongs to
+    ///
+    /// Rules that need project files, such as `SUMMARY.md` or `book.toml`,
+    /// override this; by default the context is ignored.
+    fn check_with_context<'a>(
+        &self,
+        document: &Document,
+        ast: Option<&'a AstNode<'a>>,
+        _context: &LintContext,
+    ) -> Result<Vec<Violation>> {
+        self.check_with_ast(document, ast)
+    }
+
     /// Check a document for violations of this rule (backward compatibility)
     fn check(&self, document: &Document) -> Result<Vec<Violation>> {
-        self.check_with_ast(document, None)
+        let context = LintContext::for_document(document);
+        if self.metadata().filesystem {
+            self.check_with_context(document, None, &context)
+        } else {
+            self.check_with_context(document, None, &context.without_filesystem())
+        }
     }
 
     /// Whether this rule can automatically fix violations
@@ -172,6 +554,8 @@ pub trait Rule: Send + Sync {
             column,
             severity,
             fix: None,
+            url: None,
+            path: None,
         }
     }
 
@@ -192,6 +576,8 @@ pub trait Rule: Send + Sync {
             column,
             severity,
             fix: Some(fix),
+            url: None,
+            path: None,
         }
     }
 }
@@ -299,6 +685,16 @@ pub trait AstRule: Send + Sync {
     /// Check a document using its AST
     fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>>;
 
+    /// Check the AST with the project context; see [`Rule::check_with_context`]
+    fn check_ast_with_context<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+        _context: &LintContext,
+    ) -> Result<Vec<Violation>> {
+        self.check_ast(document, ast)
+    }
+
     /// Whether this rule can automatically fix violations
     fn can_fix(&self) -> bool {
         false
@@ -325,6 +721,8 @@ pub trait AstRule: Send + Sync {
             column,
             severity,
             fix: None,
+            url: None,
+            path: None,
         }
     }
 
@@ -345,6 +743,8 @@ pub trait AstRule: Send + Sync {
             column,
             severity,
             fix: Some(fix),
+            url: None,
+            path: None,
         }
     }
 }
@@ -397,11 +797,20 @@ pub trait CollectionRule: Send + Sync {
     /// Metadata about this rule's status and properties
     fn metadata(&self) -> RuleMetadata;
 
+    /// Documentation page for this rule
+    fn doc_url(&self) -> Option<String> {
+        self.metadata()
+            .url
+            .map(str::to_string)
+            .or_else(|| builtin_doc_url(self.id()))
+    }
+
     /// Check a collection of documents for violations
     ///
     /// This method receives all documents that should be analyzed together.
     /// Implementations should filter the documents as needed (e.g., only ADR files)
-    /// and return violations that reference specific documents by path.
+    /// and create violations with [`Self::create_violation_for_file`] so each
+    /// names the document it belongs to.
     fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>>;
 
     /// Create a violation for this rule
@@ -420,10 +829,12 @@ pub trait CollectionRule: Send + Sync {
             column,
             severity,
             fix: None,
+            url: None,
+            path: None,
         }
     }
 
-    /// Create a violation with a file path prefix in the message
+    /// Create a violation in the file at `path`
     fn create_violation_for_file(
         &self,
         path: &std::path::Path,
@@ -435,11 +846,13 @@ pub trait CollectionRule: Send + Sync {
         Violation {
             rule_id: self.id().to_string(),
             rule_name: self.name().to_string(),
-            message: format!("{}: {}", path.display(), message),
+            message,
             line,
             column,
             severity,
             fix: None,
+            url: None,
+            path: Some(path.to_path_buf()),
         }
     }
 }
@@ -476,8 +889,19 @@ impl<T: AstRule> Rule for T {
         }
     }
 
-    fn check(&self, document: &Document) -> Result<Vec<Violation>> {
-        self.check_with_ast(document, None)
+    fn check_with_context<'a>(
+        &self,
+        document: &Document,
+        ast: Option<&'a AstNode<'a>>,
+        context: &LintContext,
+    ) -> Result<Vec<Violation>> {
+        if let Some(ast) = ast {
+            self.check_ast_with_context(document, ast, context)
+        } else {
+            let arena = Arena::new();
+            let ast = document.parse_ast(&arena);
+            self.check_ast_with_context(document, ast, context)
+        }
     }
 
     fn can_fix(&self) -> bool {
@@ -498,3 +922,157 @@ impl<T: AstRule> Rule for T {
         T::create_violation(self, message, line, column, severity)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_doc_url() {
+        assert_eq!(
+            builtin_doc_url("MDBOOK010").as_deref(),
+            Some("https://joshrotenberg.github.io/mdbook-lint/rules/mdbook/mdbook010.html")
+        );
+        assert!(
+            builtin_doc_url("ADR003")
+                .unwrap()
+                .ends_with("/rules/adr/adr003.html")
+        );
+        assert!(
+            builtin_doc_url("SPELL001")
+                .unwrap()
+                .ends_with("/rules/spelling/index.html")
+        );
+        assert!(
+            builtin_doc_url("RFC002")
+                .unwrap()
+                .ends_with("/rules/rfc/index.html")
+        );
+        assert!(
+            builtin_doc_url("CHANGELOG004")
+                .unwrap()
+                .ends_with("/rules/changelog/index.html")
+        );
+        assert!(
+            builtin_doc_url("README003")
+                .unwrap()
+                .ends_with("/rules/readme/index.html")
+        );
+        assert_eq!(builtin_doc_url("CONTENT001"), None);
+        assert_eq!(builtin_doc_url("MDX"), None);
+    }
+
+    #[test]
+    fn test_option_defaults() {
+        let metadata = RuleMetadata::stable(RuleCategory::Formatting)
+            .option(
+                "line-length",
+                OptionKind::IntegerAtLeast(20),
+                "Maximum line length",
+            )
+            .defaults_to("80")
+            .option("files", OptionKind::StringList, "Files to check")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+            .option("pattern", OptionKind::String, "Pattern to skip");
+
+        let defaults: Vec<Option<toml::Value>> = metadata
+            .options
+            .iter()
+            .map(RuleOption::default_value)
+            .collect();
+        assert_eq!(
+            defaults,
+            vec![
+                Some(toml::Value::Integer(80)),
+                Some(toml::Value::Array(vec!["CHANGELOG.md".into()])),
+                None,
+            ]
+        );
+        assert_eq!(metadata.options[1].kind.type_name(), "array");
+    }
+
+    #[test]
+    fn test_validate_config() {
+        let metadata = RuleMetadata::stable(RuleCategory::Formatting)
+            .option(
+                "line-length",
+                OptionKind::IntegerAtLeast(20),
+                "Maximum line length",
+            )
+            .option(
+                "length-mode",
+                OptionKind::Choice(&["strict", "visual"]),
+                "How length is measured",
+            );
+        let config: toml::Value = toml::from_str(
+            "line-length = 10\nlength-mode = \"visual\"\nauto-fix = false\nline_length = 100\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            metadata.validate_config("MD013", &config),
+            vec![
+                "MD013.line-length must be an integer ≥ 20",
+                "MD013.line_length is not an option; MD013 takes line-length, length-mode",
+            ]
+        );
+        assert_eq!(
+            metadata.either_case().validate_config("MD013", &config),
+            vec!["MD013.line-length must be an integer ≥ 20",]
+        );
+
+        let config: toml::Value = toml::from_str("length-mode = \"wide\"\n").unwrap();
+        assert_eq!(
+            RuleMetadata::stable(RuleCategory::Formatting)
+                .option(
+                    "length-mode",
+                    OptionKind::Choice(&["strict", "visual"]),
+                    "How length is measured",
+                )
+                .validate_config("MD013", &config),
+            vec![r#"MD013.length-mode must be one of "strict", "visual""#]
+        );
+        assert_eq!(
+            RuleMetadata::stable(RuleCategory::Formatting)
+                .validate_config("MD001", &toml::Value::Boolean(true)),
+            vec!["MD001 must be a table"]
+        );
+
+        let config: toml::Value = toml::from_str(
+            "ignore-tables = true\nignore-headings = \"yes\"\nignore-blockquotes = 2\n",
+        )
+        .unwrap();
+        assert_eq!(
+            RuleMetadata::stable(RuleCategory::Formatting).validate_config("MD001", &config),
+            vec!["MD001.ignore-headings must be a boolean"]
+        );
+
+        let config: toml::Value = toml::from_str("ignore-blockquotes = -1\n").unwrap();
+        assert_eq!(
+            RuleMetadata::stable(RuleCategory::Formatting).validate_config("MD001", &config),
+            vec!["MD001.ignore-blockquotes must be a boolean or a nesting depth"]
+        );
+    }
+
+    #[test]
+    fn test_validate_document_kinds() {
+        let metadata = RuleMetadata::stable(RuleCategory::Structure);
+        let config: toml::Value =
+            toml::from_str("document-kinds = [\"chapter\", \"fragment\"]\n").unwrap();
+        assert!(metadata.validate_config("MD041", &config).is_empty());
+
+        let config: toml::Value = toml::from_str("document-kinds = [\"page\"]\n").unwrap();
+        assert_eq!(
+            metadata.validate_config("MD041", &config),
+            vec![
+                "MD041.document-kinds must be a list of document kinds (chapter, summary, readme, adr, fragment)"
+            ]
+        );
+
+        let applies = RuleMetadata::stable(RuleCategory::Structure)
+            .applies_to(&[DocumentKind::Chapter, DocumentKind::Readme]);
+        assert!(applies.applies_to_kind(DocumentKind::Readme));
+        assert!(!applies.applies_to_kind(DocumentKind::Fragment));
+        assert!(metadata.applies_to_kind(DocumentKind::Fragment));
+    }
+}
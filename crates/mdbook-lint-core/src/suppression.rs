@@ -0,0 +1,315 @@
+//! Inline suppression comments
+//!
+//! An HTML comment can turn rules off for part of a document:
+//!
+//! ```markdown
+//! <!-- mdbook-lint-disable-next-line MD013 -- reason: long URL -->
+//! <!-- mdbook-lint-disable MD033 MD041 -->
+//! <!-- mdbook-lint-enable MD033 -->
+//! ```
+//!
+//! `disable` turns the listed rules off until a matching `enable`, and
+//! `disable-next-line` turns them off for the following line only. With no
+//! rules listed a directive covers every rule. Rules are named by ID or by
+//! name, in any case. Anything after ` -- ` is the reason, with or without a
+//! leading `reason:`. With `require-suppression-reason` set, each directive
+//! that disables rules without giving a reason is reported as
+//! [`MISSING_REASON_ID`].
+//!
+//! Directives inside code blocks are examples, not directives, and are
+//! skipped.
+
+use crate::document::Document;
+use crate::regions::Region;
+use crate::violation::{Severity, Violation};
+use serde::Serialize;
+
+/// Prefix of every directive, after the `<!--` that opens the comment
+pub const DIRECTIVE_PREFIX: &str = "mdbook-lint-";
+
+/// Rule ID reported for a directive without a reason
+pub const MISSING_REASON_ID: &str = "SUPPRESS001";
+
+/// Rule name reported for a directive without a reason
+pub const MISSING_REASON_NAME: &str = "suppression-reason";
+
+/// What a suppression comment does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Directive {
+    /// Turn rules off until they are enabled again
+    Disable,
+    /// Turn rules back on
+    Enable,
+    /// Turn rules off for the following line
+    DisableNextLine,
+}
+
+impl Directive {
+    /// The directive's name in a comment, after [`DIRECTIVE_PREFIX`]
+    pub fn name(self) -> &'static str {
+        match self {
+            Directive::Disable => "disable",
+            Directive::Enable => "enable",
+            Directive::DisableNextLine => "disable-next-line",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        [
+            Directive::Disable,
+            Directive::Enable,
+            Directive::DisableNextLine,
+        ]
+        .into_iter()
+        .find(|directive| directive.name() == name)
+    }
+}
+
+/// One suppression comment in a document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Suppression {
+    /// 1-based line of the comment
+    pub line: usize,
+    /// 1-based column of the comment's `<!--`
+    pub column: usize,
+    pub directive: Directive,
+    /// Rules the comment names; empty means every rule
+    pub rules: Vec<String>,
+    /// Why the rules are turned off, if the comment says
+    pub reason: Option<String>,
+}
+
+impl Suppression {
+    /// Whether the comment turns rules off without saying why
+    pub fn needs_reason(&self) -> bool {
+        self.directive != Directive::Enable && self.reason.is_none()
+    }
+
+    /// Whether the comment names `violation`'s rule, by ID or name
+    fn covers(&self, violation: &Violation) -> bool {
+        self.rules.is_empty()
+            || self.rules.iter().any(|rule| {
+                rule.eq_ignore_ascii_case(&violation.rule_id)
+                    || rule.eq_ignore_ascii_case(&violation.rule_name)
+            })
+    }
+}
+
+/// The suppression comments in a document, in order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Suppressions {
+    directives: Vec<Suppression>,
+}
+
+impl Suppressions {
+    /// Find the suppression comments in `document`
+    pub fn from_document(document: &Document) -> Self {
+        if !document.content.contains(DIRECTIVE_PREFIX) {
+            return Self::default();
+        }
+
+        let mut directives = Vec::new();
+        for (idx, line) in document.lines.iter().enumerate() {
+            if !line.contains(DIRECTIVE_PREFIX)
+                || document.regions().contains(idx + 1, Region::CodeBlock)
+            {
+                continue;
+            }
+            let mut offset = 0;
+            while let Some(start) = line[offset..].find("<!--") {
+                let start = offset + start;
+                let Some(end) = line[start..].find("-->") else {
+                    break;
+                };
+                let end = start + end;
+                if let Some((directive, rules, reason)) = parse_comment(&line[start + 4..end]) {
+                    directives.push(Suppression {
+                        line: idx + 1,
+                        column: line[..start].chars().count() + 1,
+                        directive,
+                        rules,
+                        reason,
+                    });
+                }
+                offset = end + 3;
+            }
+        }
+        Self { directives }
+    }
+
+    /// Every suppression comment, in document order
+    pub fn directives(&self) -> &[Suppression] {
+        &self.directives
+    }
+
+    /// Whether the document has no suppression comments
+    pub fn is_empty(&self) -> bool {
+        self.directives.is_empty()
+    }
+
+    /// Whether a comment turns `violation`'s rule off on its line
+    pub fn suppresses(&self, violation: &Violation) -> bool {
+        // Whether every rule is off, whether this rule was turned off by
+        // name, and whether it was turned back on by name since
+        let mut all = false;
+        let mut named = false;
+        let mut reenabled = false;
+
+        for suppression in &self.directives {
+            if suppression.line > violation.line {
+                break;
+            }
+            match suppression.directive {
+                Directive::DisableNextLine => {
+                    if suppression.line + 1 == violation.line && suppression.covers(violation) {
+                        return true;
+                    }
+                }
+                Directive::Disable if suppression.rules.is_empty() => {
+                    all = true;
+                    reenabled = false;
+                }
+                Directive::Enable if suppression.rules.is_empty() => {
+                    (all, named, reenabled) = (false, false, false);
+                }
+                Directive::Disable if suppression.covers(violation) => {
+                    (named, reenabled) = (true, false);
+                }
+                Directive::Enable if suppression.covers(violation) => {
+                    (named, reenabled) = (false, true);
+                }
+                Directive::Disable | Directive::Enable => {}
+            }
+        }
+        named || (all && !reenabled)
+    }
+
+    /// A violation for each comment that turns rules off without a reason
+    pub fn missing_reasons(&self) -> Vec<Violation> {
+        self.directives
+            .iter()
+            .filter(|s| s.needs_reason())
+            .map(|s| Violation {
+                rule_id: MISSING_REASON_ID.to_string(),
+                rule_name: MISSING_REASON_NAME.to_string(),
+                message: format!(
+                    "Suppression comment `{DIRECTIVE_PREFIX}{}` needs a reason, such as `-- reason: why`",
+                    s.directive.name()
+                ),
+                line: s.line,
+                column: s.column,
+                severity: Severity::Error,
+                fix: None,
+                url: None,
+                path: None,
+            })
+            .collect()
+    }
+}
+
+/// Split the text of an HTML comment into a directive, its rules, and its
+/// reason, or `None` if the comment is not a directive
+fn parse_comment(comment: &str) -> Option<(Directive, Vec<String>, Option<String>)> {
+    let body = comment.trim().strip_prefix(DIRECTIVE_PREFIX)?;
+    let (body, reason) = match body.split_once("--") {
+        Some((body, reason)) => (body, Some(reason)),
+        None => (body, None),
+    };
+    let mut words = body.split_whitespace();
+    let directive = Directive::from_name(words.next()?)?;
+    let rules = words
+        .flat_map(|word| word.split(','))
+        .filter(|rule| !rule.is_empty())
+        .map(str::to_string)
+        .collect();
+    let reason = reason
+        .map(|reason| {
+            let reason = reason.trim();
+            reason
+                .get(..7)
+                .filter(|prefix| prefix.eq_ignore_ascii_case("reason:"))
+                .map_or(reason, |_| reason[7..].trim_start())
+                .to_string()
+        })
+        .filter(|reason| !reason.is_empty());
+    Some((directive, rules, reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn suppressions(content: &str) -> Suppressions {
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        Suppressions::from_document(&document)
+    }
+
+    fn violation(rule_id: &str, rule_name: &str, line: usize) -> Violation {
+        Violation {
+            rule_id: rule_id.to_string(),
+            rule_name: rule_name.to_string(),
+            message: String::new(),
+            line,
+            column: 1,
+            severity: Severity::Warning,
+            fix: None,
+            url: None,
+            path: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_directives() {
+        let found = suppressions(
+            "# Title\n\n<!-- mdbook-lint-disable-next-line MD013 -- reason: long URL -->\nText\n<!-- mdbook-lint-disable MD033,line-length -- generated table -->\n<!--mdbook-lint-enable-->\n<!-- mdbook-lint-unknown MD001 -->\n<!-- just a comment -->\n",
+        );
+        let directives = found.directives();
+
+        assert_eq!(directives.len(), 3);
+        assert_eq!(directives[0].line, 3);
+        assert_eq!(directives[0].directive, Directive::DisableNextLine);
+        assert_eq!(directives[0].rules, vec!["MD013"]);
+        assert_eq!(directives[0].reason.as_deref(), Some("long URL"));
+        assert_eq!(directives[1].rules, vec!["MD033", "line-length"]);
+        assert_eq!(directives[1].reason.as_deref(), Some("generated table"));
+        assert_eq!(directives[2].directive, Directive::Enable);
+        assert!(directives[2].rules.is_empty());
+        assert_eq!(directives[2].reason, None);
+    }
+
+    #[test]
+    fn test_directives_in_code_blocks_are_skipped() {
+        let found = suppressions("```markdown\n<!-- mdbook-lint-disable -->\n```\n");
+        assert!(found.is_empty());
+    }
+
+    #[test]
+    fn test_suppresses() {
+        let found = suppressions(
+            "<!-- mdbook-lint-disable-next-line md013 -->\nLong\nLong\n<!-- mdbook-lint-disable no-inline-html -->\nHTML\n<!-- mdbook-lint-enable MD033 -->\nHTML\n<!-- mdbook-lint-disable -->\nAnything\n<!-- mdbook-lint-enable MD001 -->\nHeading\n<!-- mdbook-lint-enable -->\nAll back\n",
+        );
+
+        assert!(found.suppresses(&violation("MD013", "line-length", 2)));
+        assert!(!found.suppresses(&violation("MD013", "line-length", 3)));
+        assert!(!found.suppresses(&violation("MD009", "no-trailing-spaces", 2)));
+        assert!(found.suppresses(&violation("MD033", "no-inline-html", 5)));
+        assert!(!found.suppresses(&violation("MD033", "no-inline-html", 7)));
+        assert!(found.suppresses(&violation("MD009", "no-trailing-spaces", 9)));
+        assert!(!found.suppresses(&violation("MD001", "heading-increment", 11)));
+        assert!(found.suppresses(&violation("MD009", "no-trailing-spaces", 11)));
+        assert!(!found.suppresses(&violation("MD009", "no-trailing-spaces", 13)));
+    }
+
+    #[test]
+    fn test_missing_reasons() {
+        let found = suppressions(
+            "<!-- mdbook-lint-disable MD033 -->\n<!-- mdbook-lint-enable MD033 -->\n<!-- mdbook-lint-disable-next-line MD013 -- reason: -->\nText\n<!-- mdbook-lint-disable-next-line MD013 -- Reason: long URL -->\nText\n",
+        );
+        let lines: Vec<usize> = found.missing_reasons().iter().map(|v| v.line).collect();
+
+        assert_eq!(lines, vec![1, 3]);
+        assert_eq!(found.directives()[3].reason.as_deref(), Some("long URL"));
+    }
+}
@@ -358,6 +358,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
 
         assert_violation_contains_message(&violations, "test violation");
@@ -463,6 +465,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Warning,
                 fix: None,
+                url: None,
+                path: None,
             },
             Violation {
                 rule_id: "TEST002".to_string(),
@@ -472,6 +476,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Error,
                 fix: None,
+                url: None,
+                path: None,
             },
         ];
 
@@ -490,6 +496,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Warning,
                 fix: None,
+                url: None,
+                path: None,
             },
             Violation {
                 rule_id: "MD013".to_string(),
@@ -499,6 +507,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Error,
                 fix: None,
+                url: None,
+                path: None,
             },
         ];
 
@@ -517,6 +527,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Warning,
                 fix: None,
+                url: None,
+                path: None,
             },
             Violation {
                 rule_id: "TEST002".to_string(),
@@ -526,6 +538,8 @@ mod tests {
                 column: 1,
                 severity: Severity::Error,
                 fix: None,
+                url: None,
+                path: None,
             },
         ];
 
@@ -739,6 +753,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert_violation_contains_message(&violations, "nonexistent message");
     }
@@ -754,6 +770,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert_violation_at_line(&violations, 999);
     }
@@ -769,6 +787,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert_violation_rule_id(&violations, "NONEXISTENT");
     }
@@ -784,6 +804,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         }];
         assert_violation_severity(&violations, Severity::Error);
     }
@@ -808,6 +830,8 @@ mod tests {
             column: 1,
             severity: Severity::Error,
             fix: None,
+            url: None,
+            path: None,
         }];
 
         assert_violation_contains_message(&test_violations, "specific text");
@@ -0,0 +1,120 @@
+//! Per-rule timing for profiling lint runs
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+/// Time spent by one rule on one document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuleTiming {
+    /// Rule identifier
+    pub rule_id: &'static str,
+    /// Wall-clock time spent in the rule's check
+    pub duration: Duration,
+    /// Violations the rule reported, before deduplication
+    pub violations: usize,
+}
+
+/// Timing breakdown for linting a single document
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocumentTiming {
+    /// Time spent parsing the document into an AST
+    pub parse: Duration,
+    /// Time spent in each rule that ran, in execution order
+    pub rules: Vec<RuleTiming>,
+}
+
+impl DocumentTiming {
+    /// Total time for the document, parsing included
+    pub fn total(&self) -> Duration {
+        self.parse + self.rules.iter().map(|r| r.duration).sum::<Duration>()
+    }
+}
+
+/// Timing for one rule accumulated over many documents
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RuleTimingSummary {
+    /// Total wall-clock time across all documents
+    pub total: Duration,
+    /// Slowest single document
+    pub max: Duration,
+    /// Number of documents the rule ran on
+    pub documents: usize,
+    /// Violations reported across all documents
+    pub violations: usize,
+}
+
+/// Accumulates [`DocumentTiming`]s into per-rule totals
+#[derive(Debug, Clone, Default)]
+pub struct TimingReport {
+    /// Total parse time across all documents
+    pub parse: Duration,
+    /// Per-rule totals, keyed by rule ID
+    pub rules: BTreeMap<&'static str, RuleTimingSummary>,
+}
+
+impl TimingReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add the timing of one document
+    pub fn record(&mut self, timing: &DocumentTiming) {
+        self.parse += timing.parse;
+        for rule in &timing.rules {
+            let summary = self.rules.entry(rule.rule_id).or_default();
+            summary.total += rule.duration;
+            summary.max = summary.max.max(rule.duration);
+            summary.documents += 1;
+            summary.violations += rule.violations;
+        }
+    }
+
+    /// Total time across all rules and parsing
+    pub fn total(&self) -> Duration {
+        self.parse + self.rules.values().map(|s| s.total).sum::<Duration>()
+    }
+
+    /// Rules ordered from slowest to fastest total time
+    pub fn slowest(&self) -> Vec<(&'static str, &RuleTimingSummary)> {
+        let mut rules: Vec<_> = self.rules.iter().map(|(id, s)| (*id, s)).collect();
+        rules.sort_by(|a, b| b.1.total.cmp(&a.1.total).then(a.0.cmp(b.0)));
+        rules
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(parse_ms: u64, rules: &[(&'static str, u64, usize)]) -> DocumentTiming {
+        DocumentTiming {
+            parse: Duration::from_millis(parse_ms),
+            rules: rules
+                .iter()
+                .map(|(id, ms, violations)| RuleTiming {
+                    rule_id: id,
+                    duration: Duration::from_millis(*ms),
+                    violations: *violations,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_report_accumulates_and_sorts() {
+        let mut report = TimingReport::new();
+        report.record(&timing(2, &[("MD001", 1, 0), ("MD013", 5, 2)]));
+        report.record(&timing(3, &[("MD001", 4, 1), ("MD013", 1, 0)]));
+
+        assert_eq!(report.parse, Duration::from_millis(5));
+        assert_eq!(report.total(), Duration::from_millis(16));
+
+        let slowest = report.slowest();
+        assert_eq!(slowest[0].0, "MD013");
+        assert_eq!(slowest[0].1.total, Duration::from_millis(6));
+        assert_eq!(slowest[0].1.max, Duration::from_millis(5));
+        assert_eq!(slowest[1].1.documents, 2);
+        assert_eq!(slowest[1].1.violations, 1);
+    }
+}
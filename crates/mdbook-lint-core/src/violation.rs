@@ -13,6 +13,47 @@ pub struct Fix {
     pub start: Position,
     /// End position of the text to replace  
     pub end: Position,
+    /// Whether the fix may be applied without review
+    pub safety: FixSafety,
+}
+
+/// How confident a rule is that its fix preserves the author's intent
+///
+/// `--fix` applies only safe fixes, `--fix-unsafe` applies unsafe fixes too,
+/// and suggestions are never applied automatically; editors can still offer
+/// them.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FixSafety {
+    /// Changes formatting only; the rendered meaning is unchanged
+    #[default]
+    Safe,
+    /// Usually right, but may change meaning or layout, such as reflowing a
+    /// paragraph
+    Unsafe,
+    /// A best guess, such as a spelling correction, to be reviewed by a person
+    Suggestion,
+}
+
+impl std::fmt::Display for FixSafety {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FixSafety::Safe => write!(f, "safe"),
+            FixSafety::Unsafe => write!(f, "unsafe"),
+            FixSafety::Suggestion => write!(f, "suggestion"),
+        }
+    }
 }
 
 /// Position in a document
@@ -41,6 +82,13 @@ pub struct Violation {
     pub severity: Severity,
     /// Optional fix for this violation
     pub fix: Option<Fix>,
+    /// Documentation page for the rule, filled in by the rule registry
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// File the violation belongs to, set by collection rules that report
+    /// across documents; per-document violations leave it unset
+    #[serde(skip)]
+    pub path: Option<std::path::PathBuf>,
 }
 
 /// Severity levels for violations
@@ -49,10 +97,13 @@ pub struct Violation {
 )]
 pub enum Severity {
     /// Informational message
+    #[serde(alias = "info")]
     Info,
     /// Warning that should be addressed
+    #[serde(alias = "warning")]
     Warning,
     /// Error that must be fixed
+    #[serde(alias = "error")]
     Error,
 }
 
@@ -104,6 +155,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         };
 
         assert_eq!(violation.rule_id, "MD001");
@@ -124,6 +177,8 @@ mod tests {
             column: 81,
             severity: Severity::Error,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let expected = "10:81:error: MD013/line-length: Line too long";
@@ -140,6 +195,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let violation2 = Violation {
@@ -150,6 +207,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let violation3 = Violation {
@@ -160,6 +219,8 @@ mod tests {
             column: 1,
             severity: Severity::Error,
             fix: None,
+            url: None,
+            path: None,
         };
 
         assert_eq!(violation1, violation2);
@@ -176,6 +237,8 @@ mod tests {
             column: 3,
             severity: Severity::Info,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let cloned = original.clone();
@@ -192,6 +255,8 @@ mod tests {
             column: 1,
             severity: Severity::Warning,
             fix: None,
+            url: None,
+            path: None,
         };
 
         let debug_str = format!("{violation:?}");
@@ -216,6 +281,8 @@ mod tests {
                 column: 1,
                 severity: *severity,
                 fix: None,
+                url: None,
+                path: None,
             };
 
             // Test that display format includes severity
@@ -237,6 +304,7 @@ mod tests {
                 line: 5,
                 column: 11,
             },
+            safety: FixSafety::Safe,
         };
 
         let violation = Violation {
@@ -247,6 +315,8 @@ mod tests {
             column: 10,
             severity: Severity::Warning,
             fix: Some(fix.clone()),
+            url: None,
+            path: None,
         };
 
         assert_eq!(violation.fix, Some(fix));
@@ -274,6 +344,7 @@ mod tests {
                 line: 12,
                 column: 1,
             },
+            safety: FixSafety::Safe,
         };
 
         assert_eq!(fix.replacement, None);
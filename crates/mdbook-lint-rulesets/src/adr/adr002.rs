@@ -9,7 +9,7 @@ use crate::adr::format::{AdrFormat, detect_format, is_adr_document};
 use crate::adr::frontmatter::parse_frontmatter;
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -82,7 +82,14 @@ impl Rule for Adr002 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.14.0")
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.14.0")
+            .option(
+                "format",
+                OptionKind::Choice(&["auto", "nygard", "madr"]),
+                "ADR format to check against, instead of detecting it per document",
+            )
+            .defaults_to(r#""auto""#)
     }
 
     fn check_with_ast<'a>(
@@ -4,17 +4,14 @@
 
 use crate::adr::format::{AdrFormat, detect_format, is_adr_document};
 use crate::adr::frontmatter::parse_frontmatter;
+use crate::util::is_iso_date;
 use comrak::nodes::AstNode;
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
 
-/// Regex for ISO 8601 date format (YYYY-MM-DD)
-static ISO_DATE_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"^\d{4}-\d{2}-\d{2}$").expect("Invalid regex"));
-
 /// Regex to extract date from "Date:" line
 static DATE_LINE_REGEX: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"(?i)^date\s*:\s*(.+)$").expect("Invalid regex"));
@@ -58,31 +55,6 @@ impl Adr008 {
             other => other,
         }
     }
-
-    /// Check if a date string is in ISO 8601 format
-    fn is_iso_date(date: &str) -> bool {
-        let date = date.trim();
-        if !ISO_DATE_REGEX.is_match(date) {
-            return false;
-        }
-
-        // Also validate that it's a reasonable date
-        let parts: Vec<&str> = date.split('-').collect();
-        if parts.len() != 3 {
-            return false;
-        }
-
-        if let (Ok(year), Ok(month), Ok(day)) = (
-            parts[0].parse::<u32>(),
-            parts[1].parse::<u32>(),
-            parts[2].parse::<u32>(),
-        ) {
-            // Basic validation
-            (1900..=2100).contains(&year) && (1..=12).contains(&month) && (1..=31).contains(&day)
-        } else {
-            false
-        }
-    }
 }
 
 impl Rule for Adr008 {
@@ -99,7 +71,14 @@ impl Rule for Adr008 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.14.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.14.0")
+            .option(
+                "format",
+                OptionKind::Choice(&["auto", "nygard", "madr"]),
+                "ADR format to check against, instead of detecting it per document",
+            )
+            .defaults_to(r#""auto""#)
     }
 
     fn check_with_ast<'a>(
@@ -121,7 +100,7 @@ impl Rule for Adr008 {
                 if let Some(result) = parse_frontmatter(&document.content)
                     && let Some(ref fm) = result.frontmatter
                     && let Some(ref date) = fm.date
-                    && !Self::is_iso_date(date)
+                    && !is_iso_date(date.trim())
                 {
                     violations.push(self.create_violation(
                         format!("Date '{}' is not in ISO 8601 format (YYYY-MM-DD)", date),
@@ -142,7 +121,7 @@ impl Rule for Adr008 {
                     if let Some(caps) = DATE_LINE_REGEX.captures(line) {
                         if let Some(date_match) = caps.get(1) {
                             let date = date_match.as_str().trim();
-                            if !Self::is_iso_date(date) {
+                            if !is_iso_date(date) {
                                 violations.push(self.create_violation(
                                     format!(
                                         "Date '{}' is not in ISO 8601 format (YYYY-MM-DD)",
@@ -262,20 +241,6 @@ We need a database.
         assert!(violations[0].message.contains("not in ISO 8601 format"));
     }
 
-    #[test]
-    fn test_is_iso_date() {
-        assert!(Adr008::is_iso_date("2024-01-15"));
-        assert!(Adr008::is_iso_date("2024-12-31"));
-        assert!(Adr008::is_iso_date("1999-01-01"));
-        assert!(!Adr008::is_iso_date("01-15-2024"));
-        assert!(!Adr008::is_iso_date("2024/01/15"));
-        assert!(!Adr008::is_iso_date("January 15, 2024"));
-        assert!(!Adr008::is_iso_date("2024-13-01")); // Invalid month
-        assert!(!Adr008::is_iso_date("2024-00-01")); // Invalid month
-        assert!(!Adr008::is_iso_date("2024-01-32")); // Invalid day
-        assert!(!Adr008::is_iso_date("2024-01-00")); // Invalid day
-    }
-
     #[test]
     fn test_date_with_whitespace() {
         let content = r#"# 1. Use Rust
@@ -24,7 +24,7 @@ impl Default for Adr011 {
 
 impl Adr011 {
     /// Extract ADR number from a document
-    fn extract_adr_number(document: &Document) -> Option<u32> {
+    pub(crate) fn extract_adr_number(document: &Document) -> Option<u32> {
         let format = detect_format(&document.content);
 
         match format {
@@ -97,7 +97,8 @@ impl CollectionRule for Adr011 {
         // Check if first ADR is 1 (or 0)
         let first_num = *numbers.first().unwrap();
         if first_num != 0 && first_num != 1 {
-            violations.push(self.create_violation(
+            violations.push(self.create_violation_for_file(
+                &adr_numbers[&first_num].path,
                 format!(
                     "ADR numbering should start at 1 (or 0), but first ADR is {}",
                     first_num
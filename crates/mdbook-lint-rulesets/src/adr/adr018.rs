@@ -0,0 +1,591 @@
+//! ADR018: ADR status lifecycle
+//!
+//! Builds the graph of "supersedes" and "amends" relations between ADRs and
+//! checks it against each ADR's status. This rule analyzes all ADR documents
+//! in a collection.
+
+use crate::adr::Adr011;
+use crate::adr::format::{AdrFormat, detect_format, is_adr_document};
+use crate::adr::frontmatter::parse_frontmatter;
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::LazyLock;
+
+/// Regex for a relation to another ADR, by number or by link
+///
+/// Matches "Superseded by ADR-0002", "Supersedes ADR 1", "Amended by
+/// [3. Use MySQL](0003-use-mysql.md)", and "Amends [ADR-1](adr/0001-x.md)".
+static RELATION_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(superseded\s+by|supersedes|amended\s+by|amends)\b\s*:?\s*(?:\[[^\]]*\]\(\s*)?(?:[^\s)]*/)?(?:ADR[-\s]?)?(\d+)",
+    )
+    .expect("Invalid regex")
+});
+
+/// Default lifecycle: `draft → proposed → accepted → superseded/deprecated`,
+/// where a proposal may also be rejected
+const DEFAULT_TRANSITIONS: &[(&str, &[&str])] = &[
+    ("draft", &["proposed"]),
+    ("proposed", &["accepted", "rejected"]),
+    ("accepted", &["superseded", "deprecated"]),
+];
+
+/// How one ADR refers to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    SupersededBy,
+    Supersedes,
+    AmendedBy,
+    Amends,
+}
+
+impl Relation {
+    fn parse(text: &str) -> Self {
+        let text = text.to_lowercase();
+        if text.starts_with("superseded") {
+            Relation::SupersededBy
+        } else if text.starts_with("supersedes") {
+            Relation::Supersedes
+        } else if text.starts_with("amended") {
+            Relation::AmendedBy
+        } else {
+            Relation::Amends
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Relation::SupersededBy => "Superseded by",
+            Relation::Supersedes => "Supersedes",
+            Relation::AmendedBy => "Amended by",
+            Relation::Amends => "Amends",
+        }
+    }
+}
+
+/// What ADR018 reads from one ADR
+struct AdrNode<'a> {
+    doc: &'a Document,
+    number: u32,
+    /// Lifecycle statuses in the order they appear, with their line numbers
+    history: Vec<(String, usize)>,
+    /// Relations to other ADRs: kind, target number, and line number
+    relations: Vec<(Relation, u32, usize)>,
+}
+
+impl AdrNode<'_> {
+    /// The most recent lifecycle status
+    fn status(&self) -> Option<&(String, usize)> {
+        self.history.last()
+    }
+}
+
+/// ADR018: Validates the supersede/amend graph and status lifecycle of ADRs
+///
+/// This collection rule reports:
+/// - relations that form a cycle, such as two ADRs superseding each other
+/// - relations to an ADR number that doesn't exist
+/// - "Superseded by" references to an ADR that cannot replace another, such
+///   as one that is still proposed or was rejected
+/// - superseded ADRs whose status was not changed to "superseded"
+/// - status changes, listed in order in a Nygard `## Status` section, that
+///   the lifecycle doesn't allow
+pub struct Adr018 {
+    /// Allowed status changes, keyed by the earlier status (lowercase)
+    transitions: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl Default for Adr018 {
+    fn default() -> Self {
+        Self {
+            transitions: DEFAULT_TRANSITIONS
+                .iter()
+                .map(|(from, to)| {
+                    (
+                        from.to_string(),
+                        to.iter().map(|status| status.to_string()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+impl Adr018 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `transitions`: a table from each status to the statuses it may
+    ///   change to, replacing the default lifecycle, e.g.
+    ///   `transitions = { proposed = ["accepted", "rejected"], accepted = ["superseded"] }`
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+        if let Some(table) = config.get("transitions").and_then(|v| v.as_table()) {
+            rule.transitions = table
+                .iter()
+                .map(|(from, to)| {
+                    let to = to
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(|status| status.as_str())
+                        .map(str::to_lowercase)
+                        .collect();
+                    (from.to_lowercase(), to)
+                })
+                .collect();
+        }
+        rule
+    }
+
+    /// Every status the lifecycle mentions
+    fn statuses(&self) -> BTreeSet<&str> {
+        self.transitions
+            .iter()
+            .flat_map(|(from, to)| std::iter::once(from).chain(to))
+            .map(String::as_str)
+            .collect()
+    }
+
+    fn allows(&self, from: &str, to: &str) -> bool {
+        from == to
+            || self
+                .transitions
+                .get(from)
+                .is_some_and(|to_set| to_set.contains(to))
+    }
+
+    /// Whether an ADR with this status can supersede another: it has reached
+    /// "accepted" or a status after it. Any status qualifies if the lifecycle
+    /// has no "accepted".
+    fn can_supersede(&self, status: &str) -> bool {
+        if !self.statuses().contains("accepted") {
+            return true;
+        }
+        let mut reached = BTreeSet::from(["accepted"]);
+        let mut pending = vec!["accepted"];
+        while let Some(from) = pending.pop() {
+            for to in self.transitions.get(from).into_iter().flatten() {
+                if reached.insert(to.as_str()) {
+                    pending.push(to);
+                }
+            }
+        }
+        reached.contains(status)
+    }
+
+    /// Lifecycle statuses of a document in the order they appear
+    fn extract_history(&self, document: &Document) -> Vec<(String, usize)> {
+        let statuses = self.statuses();
+        let known = |text: &str| {
+            let word = first_word(text)?;
+            statuses.contains(word.as_str()).then_some(word)
+        };
+
+        match detect_format(&document.content) {
+            AdrFormat::Madr4 => {
+                let Some(status) = parse_frontmatter(&document.content)
+                    .and_then(|r| r.frontmatter)
+                    .and_then(|fm| fm.status)
+                    .and_then(|status| known(&status))
+                else {
+                    return Vec::new();
+                };
+                let line = document
+                    .lines
+                    .iter()
+                    .position(|line| line.trim_start().starts_with("status"))
+                    .map_or(1, |index| index + 1);
+                vec![(status, line)]
+            }
+            AdrFormat::Nygard | AdrFormat::Auto => {
+                let Some(heading) = document
+                    .lines
+                    .iter()
+                    .position(|line| line.trim().eq_ignore_ascii_case("## status"))
+                else {
+                    return Vec::new();
+                };
+                document.lines[heading + 1..]
+                    .iter()
+                    .enumerate()
+                    .take_while(|(_, line)| !line.trim_start().starts_with('#'))
+                    .filter_map(|(offset, line)| Some((known(line)?, heading + offset + 2)))
+                    .collect()
+            }
+        }
+    }
+
+    /// Relations to other ADRs, outside code blocks
+    fn extract_relations(document: &Document) -> Vec<(Relation, u32, usize)> {
+        let mut relations = Vec::new();
+        let mut in_code_block = false;
+
+        for (index, line) in document.lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+            for caps in RELATION_REGEX.captures_iter(line) {
+                if let Ok(target) = caps[2].parse() {
+                    relations.push((Relation::parse(&caps[1]), target, index + 1));
+                }
+            }
+        }
+
+        relations
+    }
+}
+
+/// The first word of a status line, lowercased, without markdown emphasis
+fn first_word(text: &str) -> Option<String> {
+    let word = text
+        .split_whitespace()
+        .next()?
+        .trim_matches(|c: char| !c.is_alphanumeric());
+    (!word.is_empty()).then(|| word.to_lowercase())
+}
+
+/// Cycles in a graph, each starting at its smallest node
+fn find_cycles(graph: &BTreeMap<u32, BTreeSet<u32>>) -> BTreeSet<Vec<u32>> {
+    fn visit(
+        node: u32,
+        graph: &BTreeMap<u32, BTreeSet<u32>>,
+        stack: &mut Vec<u32>,
+        done: &mut BTreeSet<u32>,
+        cycles: &mut BTreeSet<Vec<u32>>,
+    ) {
+        if done.contains(&node) {
+            return;
+        }
+        if let Some(start) = stack.iter().position(|&n| n == node) {
+            let mut cycle = stack[start..].to_vec();
+            let smallest = (0..cycle.len()).min_by_key(|&i| cycle[i]).unwrap_or(0);
+            cycle.rotate_left(smallest);
+            cycles.insert(cycle);
+            return;
+        }
+        stack.push(node);
+        for &next in graph.get(&node).into_iter().flatten() {
+            visit(next, graph, stack, done, cycles);
+        }
+        stack.pop();
+        done.insert(node);
+    }
+
+    let mut cycles = BTreeSet::new();
+    let mut done = BTreeSet::new();
+    for &node in graph.keys() {
+        visit(node, graph, &mut Vec::new(), &mut done, &mut cycles);
+    }
+    cycles
+}
+
+impl CollectionRule for Adr018 {
+    fn id(&self) -> &'static str {
+        "ADR018"
+    }
+
+    fn name(&self) -> &'static str {
+        "adr-status-lifecycle"
+    }
+
+    fn description(&self) -> &'static str {
+        "ADR supersede/amend relations and status changes should follow the lifecycle"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "transitions",
+                OptionKind::Any,
+                "Table from each status to the statuses it may change to",
+            )
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        let adrs: Vec<AdrNode> = documents
+            .iter()
+            .filter(|doc| is_adr_document(&doc.content, Some(&doc.path)))
+            .filter_map(|doc| {
+                Some(AdrNode {
+                    doc,
+                    number: Adr011::extract_adr_number(doc)?,
+                    history: self.extract_history(doc),
+                    relations: Self::extract_relations(doc),
+                })
+            })
+            .collect();
+
+        // ADR012 reports duplicate numbers; the first ADR with a number wins
+        let mut by_number: BTreeMap<u32, &AdrNode> = BTreeMap::new();
+        for adr in &adrs {
+            by_number.entry(adr.number).or_insert(adr);
+        }
+
+        // Edges point from the newer ADR to the one it supersedes or amends
+        let mut graph: BTreeMap<u32, BTreeSet<u32>> = BTreeMap::new();
+        let mut superseded_by: BTreeMap<u32, u32> = BTreeMap::new();
+
+        for adr in &adrs {
+            for pair in adr.history.windows(2) {
+                let ((from, _), (to, line)) = (&pair[0], &pair[1]);
+                if !self.allows(from, to) {
+                    let allowed = self
+                        .transitions
+                        .get(from)
+                        .map(|to| to.iter().cloned().collect::<Vec<_>>().join(", "))
+                        .filter(|allowed| !allowed.is_empty())
+                        .unwrap_or_else(|| "none".to_string());
+                    violations.push(self.create_violation_for_file(
+                        &adr.doc.path,
+                        format!(
+                            "Status changes from '{from}' to '{to}', which the ADR lifecycle doesn't allow (from '{from}': {allowed})"
+                        ),
+                        *line,
+                        1,
+                        Severity::Warning,
+                    ));
+                }
+            }
+
+            for &(relation, target, line) in &adr.relations {
+                let label = relation.label();
+                if target == adr.number {
+                    violations.push(self.create_violation_for_file(
+                        &adr.doc.path,
+                        format!("{label} ADR {target}, which is this ADR"),
+                        line,
+                        1,
+                        Severity::Warning,
+                    ));
+                    continue;
+                }
+                let Some(other) = by_number.get(&target) else {
+                    violations.push(self.create_violation_for_file(
+                        &adr.doc.path,
+                        format!("{label} ADR {target}, but no ADR is numbered {target}"),
+                        line,
+                        1,
+                        Severity::Warning,
+                    ));
+                    continue;
+                };
+
+                let (newer, older) = match relation {
+                    Relation::SupersededBy | Relation::AmendedBy => (target, adr.number),
+                    Relation::Supersedes | Relation::Amends => (adr.number, target),
+                };
+                graph.entry(newer).or_default().insert(older);
+                if matches!(relation, Relation::SupersededBy | Relation::Supersedes) {
+                    superseded_by.entry(older).or_insert(newer);
+                }
+
+                if relation == Relation::SupersededBy
+                    && let Some((status, _)) = other.status()
+                    && !self.can_supersede(status)
+                {
+                    violations.push(self.create_violation_for_file(
+                        &adr.doc.path,
+                        format!(
+                            "Superseded by ADR {target}, whose status '{status}' cannot replace another ADR"
+                        ),
+                        line,
+                        1,
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        // A superseded ADR should say so in its status
+        if self.statuses().contains("superseded") {
+            for (older, newer) in &superseded_by {
+                if let Some(adr) = by_number.get(older)
+                    && let Some((status, line)) = adr.status()
+                    && status != "superseded"
+                {
+                    violations.push(self.create_violation_for_file(
+                        &adr.doc.path,
+                        format!(
+                            "ADR {older} is superseded by ADR {newer}, but its status is '{status}'"
+                        ),
+                        *line,
+                        1,
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        for cycle in find_cycles(&graph) {
+            let Some(adr) = by_number.get(&cycle[0]) else {
+                continue;
+            };
+            let path = cycle
+                .iter()
+                .chain(cycle.first())
+                .map(|number| format!("ADR {number}"))
+                .collect::<Vec<_>>()
+                .join(" → ");
+            violations.push(self.create_violation_for_file(
+                &adr.doc.path,
+                format!("Supersede/amend relations form a cycle: {path}"),
+                1,
+                1,
+                Severity::Warning,
+            ));
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn create_nygard_adr(number: u32, status: &str, extra_content: &str) -> Document {
+        let content = format!(
+            r#"# {number}. Decision {number}
+
+Date: 2024-01-15
+
+## Status
+
+{status}
+
+## Context
+
+Context here.
+{extra_content}
+
+## Decision
+
+Decision here.
+
+## Consequences
+
+Consequences here.
+"#
+        );
+        Document::new(
+            content,
+            PathBuf::from(format!("adr/{number:04}-decision.md")),
+        )
+        .unwrap()
+    }
+
+    fn messages(docs: &[Document]) -> Vec<String> {
+        Adr018::default()
+            .check_collection(docs)
+            .unwrap()
+            .into_iter()
+            .map(|v| format!("{}: {}", v.path.unwrap().display(), v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_consistent_supersede_chain() {
+        let docs = vec![
+            create_nygard_adr(
+                1,
+                "Accepted\n\nSuperseded by [2. Decision 2](0002-decision.md)",
+                "",
+            ),
+            create_nygard_adr(2, "Accepted\n\nSupersedes ADR-0001", ""),
+            create_nygard_adr(3, "Proposed\n\nAmends ADR 2", ""),
+        ];
+        assert!(messages(&docs).is_empty(), "{:?}", messages(&docs));
+    }
+
+    #[test]
+    fn test_cycle() {
+        let docs = vec![
+            create_nygard_adr(1, "Superseded by ADR-2", ""),
+            create_nygard_adr(2, "Superseded by ADR-1", ""),
+        ];
+        assert_eq!(
+            messages(&docs),
+            vec![
+                "adr/0001-decision.md: Supersede/amend relations form a cycle: ADR 1 → ADR 2 → ADR 1"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dangling_and_non_superseding_targets() {
+        let docs = vec![
+            create_nygard_adr(1, "Superseded by ADR-0002", ""),
+            create_nygard_adr(2, "Rejected", ""),
+            create_nygard_adr(3, "Superseded by ADR-0009", ""),
+        ];
+        assert_eq!(
+            messages(&docs),
+            vec![
+                "adr/0001-decision.md: Superseded by ADR 2, whose status 'rejected' cannot replace another ADR",
+                "adr/0003-decision.md: Superseded by ADR 9, but no ADR is numbered 9",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_superseded_adr_keeps_old_status() {
+        let docs = vec![
+            create_nygard_adr(1, "Accepted", ""),
+            create_nygard_adr(2, "Accepted\n\nSupersedes ADR 1", ""),
+        ];
+        assert_eq!(
+            messages(&docs),
+            vec![
+                "adr/0001-decision.md: ADR 1 is superseded by ADR 2, but its status is 'accepted'"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_transitions() {
+        let docs = vec![create_nygard_adr(1, "Proposed\n\nDeprecated", "")];
+        let violations = Adr018::default().check_collection(&docs).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 9);
+        assert!(
+            violations[0]
+                .message
+                .contains("Status changes from 'proposed' to 'deprecated'")
+        );
+
+        let config: toml::Value =
+            toml::from_str("transitions = { proposed = [\"deprecated\"] }").unwrap();
+        let rule = Adr018::from_config(&config);
+        assert!(rule.check_collection(&docs).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_madr_frontmatter_relation() {
+        let madr = Document::new(
+            "---\nstatus: superseded by ADR-0002\ndate: 2024-01-15\n---\n\n# Use Rust\n"
+                .to_string(),
+            PathBuf::from("adr/0001-use-rust.md"),
+        )
+        .unwrap();
+        let docs = vec![madr, create_nygard_adr(2, "Proposed", "")];
+        assert_eq!(
+            messages(&docs),
+            vec![
+                "adr/0001-use-rust.md: Superseded by ADR 2, whose status 'proposed' cannot replace another ADR"
+            ]
+        );
+    }
+}
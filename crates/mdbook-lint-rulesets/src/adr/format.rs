@@ -3,6 +3,7 @@
 //! Provides functionality to detect whether an ADR follows the Nygard format
 //! or the MADR 4.0 format.
 
+use mdbook_lint_core::kind::ADR_DIRECTORY_NAMES;
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -132,14 +133,12 @@ pub fn is_adr_document(content: &str, file_path: Option<&std::path::Path>) -> bo
     has_nygard_title_near_top(content)
 }
 
-/// Known directory names that indicate a document is an ADR.
-const ADR_DIRECTORY_NAMES: &[&str] = &["adr", "adrs", "decisions", "architecture-decisions"];
-
 /// Check whether any path segment is a known ADR directory.
 ///
 /// The path is normalized to forward slashes so it matches on every platform
 /// (e.g. a `docs\decisions\0001.md` string on Linux still matches `decisions`).
-fn path_in_adr_dir(path: &std::path::Path) -> bool {
+/// The CLI also uses this to turn on the ADR rules for `docs/adr/` and similar.
+pub fn path_in_adr_dir(path: &std::path::Path) -> bool {
     let normalized = path.to_string_lossy().replace('\\', "/").to_lowercase();
     normalized
         .split('/')
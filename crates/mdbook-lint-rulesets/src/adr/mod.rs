@@ -95,6 +95,7 @@
 //! | ADR011 | adr-sequential-numbering | ADR numbers are sequential with no gaps |
 //! | ADR012 | adr-no-duplicate-numbers | Each ADR number is unique |
 //! | ADR013 | adr-valid-adr-links | Links to other ADRs point to existing files |
+//! | ADR018 | adr-status-lifecycle | Supersede/amend relations and status changes follow the lifecycle |
 //!
 //! ## Content Quality Rules
 //!
@@ -135,6 +136,7 @@ mod adr014;
 mod adr015;
 mod adr016;
 mod adr017;
+mod adr018;
 
 use crate::{RuleProvider, RuleRegistry};
 use mdbook_lint_core::Config;
@@ -156,6 +158,7 @@ pub use adr014::Adr014;
 pub use adr015::Adr015;
 pub use adr016::Adr016;
 pub use adr017::Adr017;
+pub use adr018::Adr018;
 pub use format::AdrFormat;
 pub use frontmatter::AdrFrontmatter;
 
@@ -172,13 +175,27 @@ impl RuleProvider for AdrRuleProvider {
     }
 
     fn description(&self) -> &'static str {
-        "Architecture Decision Record linting rules (ADR001-ADR017)"
+        "Architecture Decision Record linting rules (ADR001-ADR018)"
     }
 
     fn version(&self) -> &'static str {
         "0.1.0"
     }
 
+    fn config_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "properties": {
+                "ADR": {
+                    "type": "object",
+                    "description": "Settings shared by every ADR rule; an `[ADRxxx]` table overrides them",
+                    "properties": {
+                        "format": {"type": "string", "enum": ["auto", "nygard", "madr"]}
+                    }
+                }
+            }
+        }))
+    }
+
     fn register_rules(&self, registry: &mut RuleRegistry) {
         // Single-document rules
         registry.register(Box::new(Adr001::default()));
@@ -202,6 +219,7 @@ impl RuleProvider for AdrRuleProvider {
         registry.register_collection_rule(Box::new(Adr011));
         registry.register_collection_rule(Box::new(Adr012));
         registry.register_collection_rule(Box::new(Adr013));
+        registry.register_collection_rule(Box::new(Adr018::default()));
     }
 
     fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
@@ -242,13 +260,17 @@ impl RuleProvider for AdrRuleProvider {
         registry.register_collection_rule(Box::new(Adr011));
         registry.register_collection_rule(Box::new(Adr012));
         registry.register_collection_rule(Box::new(Adr013));
+        registry.register_collection_rule(Box::new(match cfg("ADR018") {
+            Some(c) => Adr018::from_config(c),
+            None => Adr018::default(),
+        }));
     }
 
     fn rule_ids(&self) -> Vec<&'static str> {
         vec![
             "ADR001", "ADR002", "ADR003", "ADR004", "ADR005", "ADR006", "ADR007", "ADR008",
             "ADR009", "ADR010", "ADR011", "ADR012", "ADR013", "ADR014", "ADR015", "ADR016",
-            "ADR017",
+            "ADR017", "ADR018",
         ]
     }
 }
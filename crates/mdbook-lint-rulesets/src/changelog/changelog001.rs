@@ -0,0 +1,117 @@
+//! CHANGELOG001: Unreleased section
+//!
+//! Validates that a changelog has an `Unreleased` section above its releases,
+//! where upcoming changes are collected until the next version is cut.
+
+use super::ChangelogScope;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CHANGELOG001: Validates that an `Unreleased` section comes first
+#[derive(Debug, Clone, Default)]
+pub struct Changelog001 {
+    scope: ChangelogScope,
+}
+
+impl Changelog001 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `files`: changelog file names (default `["CHANGELOG.md"]`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            scope: ChangelogScope::from_config(config),
+        }
+    }
+}
+
+impl Rule for Changelog001 {
+    fn id(&self) -> &'static str {
+        "CHANGELOG001"
+    }
+
+    fn name(&self) -> &'static str {
+        "changelog-unreleased"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelog should have an Unreleased section before its releases"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option("files", OptionKind::StringList, "Changelog file names")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(changelog) = self.scope.parse(document) else {
+            return Ok(Vec::new());
+        };
+
+        let violation = match changelog.releases.iter().position(|r| r.is_unreleased()) {
+            None => Some((
+                "Changelog has no 'Unreleased' section".to_string(),
+                changelog.releases.first().map_or(1, |r| r.line),
+            )),
+            Some(0) => None,
+            Some(position) => Some((
+                format!(
+                    "'Unreleased' section should come before release {}",
+                    changelog.releases[0].name
+                ),
+                changelog.releases[position].line,
+            )),
+        };
+
+        Ok(violation
+            .map(|(message, line)| self.create_violation(message, line, 1, Severity::Warning))
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("CHANGELOG.md")).unwrap();
+        Changelog001::default().check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_unreleased_first() {
+        assert!(check("# Changelog\n\n## [Unreleased]\n\n## [1.0.0] - 2024-01-31\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_unreleased() {
+        let violations = check("# Changelog\n\n## [1.0.0] - 2024-01-31\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(
+            violations[0].message,
+            "Changelog has no 'Unreleased' section"
+        );
+    }
+
+    #[test]
+    fn test_unreleased_after_release() {
+        let violations = check("# Changelog\n\n## [1.0.0] - 2024-01-31\n\n## [Unreleased]\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 5);
+        assert_eq!(
+            violations[0].message,
+            "'Unreleased' section should come before release 1.0.0"
+        );
+    }
+}
@@ -0,0 +1,147 @@
+//! CHANGELOG002: Version order
+//!
+//! Validates that every release in a changelog is a valid SemVer version and
+//! that releases are listed newest first.
+
+use super::{ChangelogScope, Version};
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CHANGELOG002: Validates that versions are SemVer in descending order
+///
+/// A leading `v` is allowed, and versions are compared by SemVer precedence,
+/// so `1.0.0-rc.1` belongs below `1.0.0`.
+#[derive(Debug, Clone, Default)]
+pub struct Changelog002 {
+    scope: ChangelogScope,
+}
+
+impl Changelog002 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `files`: changelog file names (default `["CHANGELOG.md"]`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            scope: ChangelogScope::from_config(config),
+        }
+    }
+}
+
+impl Rule for Changelog002 {
+    fn id(&self) -> &'static str {
+        "CHANGELOG002"
+    }
+
+    fn name(&self) -> &'static str {
+        "changelog-version-order"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelog versions should be valid SemVer in descending order"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option("files", OptionKind::StringList, "Changelog file names")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(changelog) = self.scope.parse(document) else {
+            return Ok(Vec::new());
+        };
+
+        let mut violations = Vec::new();
+        let mut previous: Option<(Version, &str)> = None;
+
+        for release in changelog.releases.iter().filter(|r| !r.is_unreleased()) {
+            let Some(version) = Version::parse(&release.name) else {
+                violations.push(self.create_violation(
+                    format!("'{}' is not a valid SemVer version", release.name),
+                    release.line,
+                    release.name_column,
+                    Severity::Warning,
+                ));
+                continue;
+            };
+
+            if let Some((newer, newer_name)) = &previous
+                && version >= *newer
+            {
+                let message = if version == *newer {
+                    format!("Version {} is listed more than once", release.name)
+                } else {
+                    format!(
+                        "Version {} should come before {newer_name} (newest versions first)",
+                        release.name
+                    )
+                };
+                violations.push(self.create_violation(
+                    message,
+                    release.line,
+                    release.name_column,
+                    Severity::Warning,
+                ));
+                continue;
+            }
+            previous = Some((version, &release.name));
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn messages(content: &str) -> Vec<String> {
+        let doc = Document::new(content.to_string(), PathBuf::from("CHANGELOG.md")).unwrap();
+        Changelog002::default()
+            .check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_descending_versions() {
+        let content = "## [Unreleased]\n\n## [1.10.0]\n\n## [1.9.0]\n\n## [1.0.0]\n\n## [1.0.0-rc.1]\n\n## v0.1.0\n";
+        assert!(messages(content).is_empty());
+    }
+
+    #[test]
+    fn test_out_of_order_and_duplicates() {
+        let content = "## [1.1.0]\n\n## [1.2.0]\n\n## [1.0.0]\n\n## [1.0.0]\n";
+        assert_eq!(
+            messages(content),
+            vec![
+                "Version 1.2.0 should come before 1.1.0 (newest versions first)",
+                "Version 1.0.0 is listed more than once",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_invalid_version() {
+        let doc = Document::new(
+            "## [Unreleased]\n\n## [1.2]\n".to_string(),
+            PathBuf::from("CHANGELOG.md"),
+        )
+        .unwrap();
+        let violations = Changelog002::default().check(&doc).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (3, 5));
+        assert_eq!(violations[0].message, "'1.2' is not a valid SemVer version");
+    }
+}
@@ -0,0 +1,120 @@
+//! CHANGELOG003: Release dates
+//!
+//! Validates that every release in a changelog is dated with an ISO 8601
+//! calendar date (`YYYY-MM-DD`), as in `## [1.2.0] - 2024-01-31`.
+
+use super::ChangelogScope;
+use crate::util::is_iso_date;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CHANGELOG003: Validates that releases have an ISO 8601 date
+#[derive(Debug, Clone, Default)]
+pub struct Changelog003 {
+    scope: ChangelogScope,
+}
+
+impl Changelog003 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `files`: changelog file names (default `["CHANGELOG.md"]`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            scope: ChangelogScope::from_config(config),
+        }
+    }
+}
+
+impl Rule for Changelog003 {
+    fn id(&self) -> &'static str {
+        "CHANGELOG003"
+    }
+
+    fn name(&self) -> &'static str {
+        "changelog-release-date"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelog releases should have an ISO 8601 (YYYY-MM-DD) date"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option("files", OptionKind::StringList, "Changelog file names")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(changelog) = self.scope.parse(document) else {
+            return Ok(Vec::new());
+        };
+
+        let violations = changelog
+            .releases
+            .iter()
+            .filter(|release| !release.is_unreleased())
+            .filter_map(|release| match &release.date {
+                None => Some(self.create_violation(
+                    format!("Release {} has no date", release.name),
+                    release.line,
+                    1,
+                    Severity::Warning,
+                )),
+                Some(date) if !is_iso_date(date) => Some(self.create_violation(
+                    format!(
+                        "Release date '{date}' for {} is not an ISO 8601 date (YYYY-MM-DD)",
+                        release.name
+                    ),
+                    release.line,
+                    release.date_column,
+                    Severity::Warning,
+                )),
+                Some(_) => None,
+            })
+            .collect();
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("CHANGELOG.md")).unwrap();
+        Changelog003::default().check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_iso_dates() {
+        let content =
+            "## [Unreleased]\n\n## [1.1.0] - 2024-02-29\n\n## 1.0.0 - 2023-12-31 [YANKED]\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_and_invalid_dates() {
+        let content = "## [1.2.0]\n\n## [1.1.0] - 2023-02-29\n\n## [1.0.0] - 31/12/2022\n";
+        let violations = check(content);
+        let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Release 1.2.0 has no date",
+                "Release date '2023-02-29' for 1.1.0 is not an ISO 8601 date (YYYY-MM-DD)",
+                "Release date '31/12/2022' for 1.0.0 is not an ISO 8601 date (YYYY-MM-DD)",
+            ]
+        );
+        assert_eq!((violations[2].line, violations[2].column), (5, 14));
+    }
+}
@@ -0,0 +1,163 @@
+//! CHANGELOG004: Change categories
+//!
+//! Validates that the `###` headings grouping changes within a release use
+//! the Keep a Changelog categories: Added, Changed, Deprecated, Removed,
+//! Fixed, and Security.
+
+use super::ChangelogScope;
+use crate::util::string_list;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// Categories allowed unless `categories` is configured
+const DEFAULT_CATEGORIES: &[&str] = &[
+    "Added",
+    "Changed",
+    "Deprecated",
+    "Removed",
+    "Fixed",
+    "Security",
+];
+
+/// CHANGELOG004: Validates category headings within releases
+///
+/// Categories are compared case-insensitively. A category repeated within
+/// one release is also reported, since its entries belong in one list.
+#[derive(Debug, Clone)]
+pub struct Changelog004 {
+    scope: ChangelogScope,
+    categories: Vec<String>,
+}
+
+impl Default for Changelog004 {
+    fn default() -> Self {
+        Self {
+            scope: ChangelogScope::default(),
+            categories: DEFAULT_CATEGORIES.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+}
+
+impl Changelog004 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `files`: changelog file names (default `["CHANGELOG.md"]`)
+    /// - `categories`: allowed category headings
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            scope: ChangelogScope::from_config(config),
+            ..Self::default()
+        };
+        if let Some(categories) = string_list(config, &["categories"]) {
+            rule.categories = categories;
+        }
+        rule
+    }
+}
+
+impl Rule for Changelog004 {
+    fn id(&self) -> &'static str {
+        "CHANGELOG004"
+    }
+
+    fn name(&self) -> &'static str {
+        "changelog-categories"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelog changes should be grouped under the allowed category headings"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option("files", OptionKind::StringList, "Changelog file names")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+            .option(
+                "categories",
+                OptionKind::StringList,
+                "Allowed category headings",
+            )
+            .defaults_to(r#"["Added", "Changed", "Deprecated", "Removed", "Fixed", "Security"]"#)
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(changelog) = self.scope.parse(document) else {
+            return Ok(Vec::new());
+        };
+
+        let mut violations = Vec::new();
+        for release in &changelog.releases {
+            let mut seen: Vec<String> = Vec::new();
+            for (line, category) in &release.categories {
+                let key = category.to_lowercase();
+                let message = if !self.categories.iter().any(|c| c.to_lowercase() == key) {
+                    format!(
+                        "Unknown changelog category '{category}' (expected one of: {})",
+                        self.categories.join(", ")
+                    )
+                } else if seen.contains(&key) {
+                    format!(
+                        "Category '{category}' appears more than once in {}",
+                        release.name
+                    )
+                } else {
+                    seen.push(key);
+                    continue;
+                };
+                violations.push(self.create_violation(message, *line, 5, Severity::Warning));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn messages(rule: &Changelog004, content: &str) -> Vec<String> {
+        let doc = Document::new(content.to_string(), PathBuf::from("CHANGELOG.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_allowed_categories() {
+        let content = "## [Unreleased]\n\n### Added\n\n## [1.0.0] - 2024-01-31\n\n### fixed\n\n### Security\n";
+        assert!(messages(&Changelog004::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_and_repeated_categories() {
+        let content = "## [1.0.0] - 2024-01-31\n\n### Added\n\n### Bugfixes\n\n### Added\n\n## [0.9.0] - 2024-01-01\n\n### Added\n";
+        assert_eq!(
+            messages(&Changelog004::default(), content),
+            vec![
+                "Unknown changelog category 'Bugfixes' (expected one of: Added, Changed, Deprecated, Removed, Fixed, Security)",
+                "Category 'Added' appears more than once in 1.0.0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_configured_categories() {
+        let config: toml::Value =
+            toml::from_str("categories = [\"Features\", \"Bug Fixes\"]").unwrap();
+        let rule = Changelog004::from_config(&config);
+        let content = "## [1.0.0] - 2024-01-31\n\n### Features\n\n### Bug Fixes\n\n### Added\n";
+        assert_eq!(messages(&rule, content).len(), 1);
+    }
+}
@@ -0,0 +1,139 @@
+//! CHANGELOG005: Version link references
+//!
+//! Validates that each version heading written as `## [1.2.0]` has a
+//! matching link reference definition, such as
+//! `[1.2.0]: https://github.com/owner/repo/compare/v1.1.0...v1.2.0`, so the
+//! heading links to the changes in that release.
+
+use super::ChangelogScope;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CHANGELOG005: Validates that every version has a link reference
+///
+/// Headings with an inline link, as in `## [1.2.0](url)`, need no
+/// definition. Set `unreleased = false` to leave `## [Unreleased]` unlinked.
+#[derive(Debug, Clone)]
+pub struct Changelog005 {
+    scope: ChangelogScope,
+    unreleased: bool,
+}
+
+impl Default for Changelog005 {
+    fn default() -> Self {
+        Self {
+            scope: ChangelogScope::default(),
+            unreleased: true,
+        }
+    }
+}
+
+impl Changelog005 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `files`: changelog file names (default `["CHANGELOG.md"]`)
+    /// - `unreleased`: require a link for the Unreleased section (default true)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            scope: ChangelogScope::from_config(config),
+            ..Self::default()
+        };
+        if let Some(unreleased) = config.get("unreleased").and_then(|v| v.as_bool()) {
+            rule.unreleased = unreleased;
+        }
+        rule
+    }
+}
+
+impl Rule for Changelog005 {
+    fn id(&self) -> &'static str {
+        "CHANGELOG005"
+    }
+
+    fn name(&self) -> &'static str {
+        "changelog-version-links"
+    }
+
+    fn description(&self) -> &'static str {
+        "Changelog versions should have a link reference definition"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Links)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option("files", OptionKind::StringList, "Changelog file names")
+            .defaults_to(r#"["CHANGELOG.md"]"#)
+            .option(
+                "unreleased",
+                OptionKind::Boolean,
+                "Require a link for the Unreleased section",
+            )
+            .defaults_to("true")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(changelog) = self.scope.parse(document) else {
+            return Ok(Vec::new());
+        };
+
+        let violations = changelog
+            .releases
+            .iter()
+            .filter(|release| !release.inline_link)
+            .filter(|release| self.unreleased || !release.is_unreleased())
+            .filter(|release| !changelog.link_refs.contains(&release.name.to_lowercase()))
+            .map(|release| {
+                self.create_violation(
+                    format!(
+                        "Version {} has no link reference (add '[{}]: <url>')",
+                        release.name, release.name
+                    ),
+                    release.line,
+                    release.name_column,
+                    Severity::Warning,
+                )
+            })
+            .collect();
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &Changelog005, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("CHANGELOG.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_all_versions_linked() {
+        let content = "## [Unreleased]\n\n## [1.1.0](https://example.com/v1.1.0) - 2024-02-01\n\n## [1.0.0] - 2024-01-31\n\n[unreleased]: https://example.com/compare/v1.1.0...HEAD\n[1.0.0]: https://example.com/releases/v1.0.0\n";
+        assert!(check(&Changelog005::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_links() {
+        let content = "## [Unreleased]\n\n## 1.1.0 - 2024-02-01\n\n## [1.0.0] - 2024-01-31\n\n[1.0.0]: https://example.com/releases/v1.0.0\n";
+        let violations = check(&Changelog005::default(), content);
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[1].message,
+            "Version 1.1.0 has no link reference (add '[1.1.0]: <url>')"
+        );
+        assert_eq!((violations[1].line, violations[1].column), (3, 4));
+
+        let config: toml::Value = toml::from_str("unreleased = false").unwrap();
+        assert_eq!(check(&Changelog005::from_config(&config), content).len(), 1);
+    }
+}
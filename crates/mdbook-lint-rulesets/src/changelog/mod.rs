@@ -0,0 +1,418 @@
+//! Changelog linting rules
+//!
+//! This module provides rules for changelogs written in the
+//! [Keep a Changelog](https://keepachangelog.com/en/1.1.0/) format: one
+//! `## [version] - date` section per release, newest first, with changes
+//! grouped under `### Added`, `### Fixed`, and similar headings. Only files
+//! named like a changelog (`CHANGELOG.md` by default) are checked.
+//!
+//! # Available Rules
+//!
+//! | Rule | Name | Description |
+//! |------|------|-------------|
+//! | CHANGELOG001 | changelog-unreleased | An `Unreleased` section comes before the releases |
+//! | CHANGELOG002 | changelog-version-order | Versions are valid SemVer in descending order |
+//! | CHANGELOG003 | changelog-release-date | Each release has an ISO 8601 (`YYYY-MM-DD`) date |
+//! | CHANGELOG004 | changelog-categories | Change groups use the allowed category headings |
+//! | CHANGELOG005 | changelog-version-links | Each version has a link reference definition |
+//!
+//! # Configuration
+//!
+//! ```toml
+//! # Provider-level settings (affect all changelog rules)
+//! [CHANGELOG]
+//! files = ["CHANGELOG.md", "HISTORY.md"]
+//!
+//! [CHANGELOG004]
+//! categories = ["Added", "Changed", "Fixed", "Performance"]
+//! ```
+
+mod changelog001;
+mod changelog002;
+mod changelog003;
+mod changelog004;
+mod changelog005;
+
+use crate::util::string_list;
+use crate::{RuleProvider, RuleRegistry};
+use mdbook_lint_core::{Config, Document};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+pub use changelog001::Changelog001;
+pub use changelog002::Changelog002;
+pub use changelog003::Changelog003;
+pub use changelog004::Changelog004;
+pub use changelog005::Changelog005;
+
+/// File names checked unless `files` is configured
+const DEFAULT_FILES: &[&str] = &["CHANGELOG.md"];
+
+/// A release heading: `## [1.2.0](url) - 2024-01-31`, `## 1.2.0`, ...
+static RELEASE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^##\s+(?:\[([^\]]+)\](\([^)]*\))?|(\S+))\s*(?:[-–—]\s*(\S+))?")
+        .expect("Invalid regex")
+});
+
+/// A link reference definition: `[1.2.0]: https://...`
+static LINK_REF_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^ {0,3}\[([^\]]+)\]:\s*\S").expect("Invalid regex"));
+
+/// Which documents the changelog rules check
+///
+/// File names in `files` are compared case-insensitively, so the default
+/// also matches `Changelog.md` and `changelog.md`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangelogScope {
+    files: Vec<String>,
+}
+
+impl Default for ChangelogScope {
+    fn default() -> Self {
+        Self {
+            files: DEFAULT_FILES.iter().map(|f| f.to_lowercase()).collect(),
+        }
+    }
+}
+
+impl ChangelogScope {
+    /// Read `files` from a rule or `[CHANGELOG]` configuration
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut scope = Self::default();
+        if let Some(files) = string_list(config, &["files"]) {
+            scope.files = files.iter().map(|f| f.to_lowercase()).collect();
+        }
+        scope
+    }
+
+    /// Parse `document` if it is a changelog, or return `None`
+    pub fn parse(&self, document: &Document) -> Option<Changelog> {
+        let name = document.path.file_name()?.to_str()?.to_lowercase();
+        self.files
+            .contains(&name)
+            .then(|| Changelog::parse(document))
+    }
+}
+
+/// A `## ...` section of a changelog
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Release {
+    /// 1-based line of the heading
+    pub line: usize,
+    /// The version as written, or `Unreleased`
+    pub name: String,
+    /// 1-based column where `name` starts
+    pub name_column: usize,
+    /// Whether the heading links the version inline, as in `[1.0.0](url)`
+    pub inline_link: bool,
+    /// The text after the ` - ` separator, if any
+    pub date: Option<String>,
+    /// 1-based column where `date` starts
+    pub date_column: usize,
+    /// `### ...` headings in this section, with their line numbers
+    pub categories: Vec<(usize, String)>,
+}
+
+impl Release {
+    /// Whether this is the `Unreleased` section
+    pub fn is_unreleased(&self) -> bool {
+        self.name.eq_ignore_ascii_case("unreleased")
+    }
+}
+
+/// The release sections and link references of a changelog
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Changelog {
+    /// Release sections in document order
+    pub releases: Vec<Release>,
+    /// Labels of link reference definitions, lowercased
+    pub link_refs: HashSet<String>,
+}
+
+impl Changelog {
+    /// Collect release headings, category headings, and link references,
+    /// skipping fenced code blocks
+    pub fn parse(document: &Document) -> Self {
+        let mut changelog = Self::default();
+        let index = document.index();
+
+        for (i, line) in document.lines.iter().enumerate() {
+            let line_number = i + 1;
+            if index.is_code_block_line(line_number) || document.is_frontmatter_line(line_number) {
+                continue;
+            }
+
+            if let Some(caps) = RELEASE_REGEX.captures(line) {
+                let name = caps.get(1).or_else(|| caps.get(3)).expect("name group");
+                let date = caps.get(4);
+                changelog.releases.push(Release {
+                    line: line_number,
+                    name: name.as_str().trim().to_string(),
+                    name_column: line[..name.start()].chars().count() + 1,
+                    inline_link: caps.get(2).is_some(),
+                    date: date.map(|d| d.as_str().to_string()),
+                    date_column: date.map_or(0, |d| line[..d.start()].chars().count() + 1),
+                    categories: Vec::new(),
+                });
+            } else if let Some(heading) = line.strip_prefix("### ") {
+                if let Some(release) = changelog.releases.last_mut() {
+                    release.categories.push((
+                        line_number,
+                        heading.trim().trim_end_matches('#').trim().to_string(),
+                    ));
+                }
+            } else if let Some(caps) = LINK_REF_REGEX.captures(line) {
+                changelog.link_refs.insert(caps[1].trim().to_lowercase());
+            }
+        }
+
+        changelog
+    }
+}
+
+/// A semantic version, ordered by SemVer 2.0.0 precedence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    major: u64,
+    minor: u64,
+    patch: u64,
+    pre: Vec<String>,
+}
+
+impl Version {
+    /// Parse `1.2.3`, `v1.2.3`, or `1.2.3-rc.1+build`; build metadata is
+    /// ignored for ordering
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.strip_prefix(['v', 'V']).unwrap_or(text);
+        let text = text.split_once('+').map_or(text, |(version, _)| version);
+        let (core, pre) = match text.split_once('-') {
+            Some((core, pre)) => (core, Some(pre)),
+            None => (text, None),
+        };
+
+        let numbers: Vec<u64> = core
+            .split('.')
+            .map(|part| {
+                let valid = !part.is_empty()
+                    && part.bytes().all(|b| b.is_ascii_digit())
+                    && (part == "0" || !part.starts_with('0'));
+                valid.then(|| part.parse().ok()).flatten()
+            })
+            .collect::<Option<_>>()?;
+        let [major, minor, patch] = numbers[..] else {
+            return None;
+        };
+
+        let pre = match pre {
+            Some(pre) => {
+                let identifiers: Vec<String> = pre.split('.').map(String::from).collect();
+                let valid = identifiers.iter().all(|id| {
+                    !id.is_empty() && id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-')
+                });
+                if !valid {
+                    return None;
+                }
+                identifiers
+            }
+            None => Vec::new(),
+        };
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                // A pre-release comes before its release
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => {
+                    for (a, b) in self.pre.iter().zip(&other.pre) {
+                        let ordering = match (a.parse::<u64>(), b.parse::<u64>()) {
+                            (Ok(a), Ok(b)) => a.cmp(&b),
+                            (Ok(_), Err(_)) => Ordering::Less,
+                            (Err(_), Ok(_)) => Ordering::Greater,
+                            (Err(_), Err(_)) => a.cmp(b),
+                        };
+                        if ordering != Ordering::Equal {
+                            return ordering;
+                        }
+                    }
+                    self.pre.len().cmp(&other.pre.len())
+                }
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Provider for Keep a Changelog rules (CHANGELOG001-CHANGELOG005)
+pub struct ChangelogRuleProvider;
+
+impl RuleProvider for ChangelogRuleProvider {
+    fn provider_id(&self) -> &'static str {
+        "changelog"
+    }
+
+    fn description(&self) -> &'static str {
+        "Keep a Changelog rules (CHANGELOG001-CHANGELOG005)"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn config_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "properties": {
+                "CHANGELOG": {
+                    "type": "object",
+                    "description": "Settings shared by every changelog rule",
+                    "properties": {
+                        "files": {"type": "array", "items": {"type": "string"}}
+                    }
+                }
+            }
+        }))
+    }
+
+    fn register_rules(&self, registry: &mut RuleRegistry) {
+        registry.register(Box::new(Changelog001::default()));
+        registry.register(Box::new(Changelog002::default()));
+        registry.register(Box::new(Changelog003::default()));
+        registry.register(Box::new(Changelog004::default()));
+        registry.register(Box::new(Changelog005::default()));
+    }
+
+    fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
+        // A provider-level `[CHANGELOG]` block (e.g. `files = ["HISTORY.md"]`)
+        // applies to every changelog rule; a per-rule block overrides it.
+        let provider_cfg = config.and_then(|c| c.rule_configs.get("CHANGELOG"));
+        let cfg = |id: &str| config.and_then(|c| c.rule_configs.get(id)).or(provider_cfg);
+
+        registry.register(Box::new(match cfg("CHANGELOG001") {
+            Some(c) => Changelog001::from_config(c),
+            None => Changelog001::default(),
+        }));
+        registry.register(Box::new(match cfg("CHANGELOG002") {
+            Some(c) => Changelog002::from_config(c),
+            None => Changelog002::default(),
+        }));
+        registry.register(Box::new(match cfg("CHANGELOG003") {
+            Some(c) => Changelog003::from_config(c),
+            None => Changelog003::default(),
+        }));
+        registry.register(Box::new(match cfg("CHANGELOG004") {
+            Some(c) => Changelog004::from_config(c),
+            None => Changelog004::default(),
+        }));
+        registry.register(Box::new(match cfg("CHANGELOG005") {
+            Some(c) => Changelog005::from_config(c),
+            None => Changelog005::default(),
+        }));
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        vec![
+            "CHANGELOG001",
+            "CHANGELOG002",
+            "CHANGELOG003",
+            "CHANGELOG004",
+            "CHANGELOG005",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn doc(path: &str, content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from(path)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_changelog() {
+        let content = "# Changelog\n\n## [Unreleased]\n\n### Added\n\n- x\n\n```markdown\n## [9.9.9]\n```\n\n## [1.0.0](https://example.com) - 2024-01-31\n\n### Fixed ###\n\n## 0.9.0\n\n[unreleased]: https://example.com/compare\n";
+        let changelog = ChangelogScope::default()
+            .parse(&doc("CHANGELOG.md", content))
+            .unwrap();
+
+        let names: Vec<&str> = changelog.releases.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Unreleased", "1.0.0", "0.9.0"]);
+        assert!(changelog.releases[0].is_unreleased());
+        assert_eq!(
+            changelog.releases[0].categories,
+            vec![(5, "Added".to_string())]
+        );
+
+        let release = &changelog.releases[1];
+        assert_eq!((release.line, release.name_column), (13, 5));
+        assert!(release.inline_link);
+        assert_eq!(release.date.as_deref(), Some("2024-01-31"));
+        assert_eq!(release.date_column, 35);
+        assert_eq!(release.categories, vec![(15, "Fixed".to_string())]);
+
+        assert_eq!(changelog.releases[2].date, None);
+        assert!(changelog.link_refs.contains("unreleased"));
+    }
+
+    #[test]
+    fn test_scope_files() {
+        let scope = ChangelogScope::default();
+        assert!(scope.parse(&doc("project/Changelog.md", "")).is_some());
+        assert!(scope.parse(&doc("guide/intro.md", "")).is_none());
+
+        let config: toml::Value = toml::from_str("files = [\"HISTORY.md\"]").unwrap();
+        let scope = ChangelogScope::from_config(&config);
+        assert!(scope.parse(&doc("HISTORY.md", "")).is_some());
+        assert!(scope.parse(&doc("CHANGELOG.md", "")).is_none());
+    }
+
+    #[test]
+    fn test_version_order() {
+        let v = |s: &str| Version::parse(s).unwrap();
+        assert!(v("1.10.0") > v("1.9.0"));
+        assert!(v("v2.0.0") > v("2.0.0-rc.1"));
+        assert!(v("1.0.0-rc.10") > v("1.0.0-rc.2"));
+        assert!(v("1.0.0-beta") > v("1.0.0-alpha.1"));
+        assert!(v("1.0.0-alpha.1") > v("1.0.0-alpha"));
+        assert_eq!(v("1.0.0+build.5"), v("1.0.0"));
+        for invalid in ["1.0", "1.0.0.0", "01.0.0", "1.0.0-", "latest"] {
+            assert_eq!(Version::parse(invalid), None, "{invalid}");
+        }
+    }
+
+    #[test]
+    fn test_provider_files_reach_rules() {
+        let config: Config = toml::from_str("[CHANGELOG]\nfiles = [\"HISTORY.md\"]\n").unwrap();
+        let mut registry = mdbook_lint_core::PluginRegistry::new();
+        registry
+            .register_provider(Box::new(ChangelogRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+
+        let content = "# History\n\n## 1.0.0\n";
+        let violations = engine.lint_document(&doc("HISTORY.md", content)).unwrap();
+        assert!(violations.iter().any(|v| v.rule_id == "CHANGELOG001"));
+        assert!(violations.iter().any(|v| v.rule_id == "CHANGELOG003"));
+        let violations = engine.lint_document(&doc("CHANGELOG.md", content)).unwrap();
+        assert!(violations.is_empty());
+    }
+}
@@ -4,7 +4,7 @@
 //! that shouldn't appear in production documentation.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -188,7 +188,27 @@ impl Rule for CONTENT001 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.11.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.11.0")
+            .option(
+                "markers",
+                OptionKind::StringList,
+                "Custom markers to detect",
+            )
+            .defaults_to("[]")
+            .option(
+                "include_defaults",
+                OptionKind::Boolean,
+                "Also check the built-in markers",
+            )
+            .defaults_to("true")
+            .option(
+                "check_code_blocks",
+                OptionKind::Boolean,
+                "Scan inside code blocks",
+            )
+            .defaults_to("false")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -4,7 +4,7 @@
 //! "coming soon", etc. that shouldn't appear in production documentation.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -221,7 +221,21 @@ impl Rule for CONTENT002 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.12.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.12.0")
+            .option(
+                "check_code_blocks",
+                OptionKind::Boolean,
+                "Scan inside code blocks",
+            )
+            .defaults_to("false")
+            .option(
+                "allow_example_urls",
+                OptionKind::Boolean,
+                "Allow `example.com` URLs",
+            )
+            .defaults_to("true")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -3,9 +3,9 @@
 //! Flags chapters that are too short and might be stubs or incomplete.
 //! Short chapters can indicate work-in-progress content that needs expansion.
 
-use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
+use mdbook_lint_core::{Document, DocumentKind};
 
 /// Default minimum word count for a chapter
 const DEFAULT_MIN_WORDS: usize = 50;
@@ -133,7 +133,22 @@ impl Rule for CONTENT003 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.12.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.12.0")
+            .applies_to(&[DocumentKind::Chapter])
+            .option(
+                "min_words",
+                OptionKind::IntegerAtLeast(0),
+                "Word count below which a chapter is flagged",
+            )
+            .defaults_to("50")
+            .option(
+                "include_code_blocks",
+                OptionKind::Boolean,
+                "Count words inside code blocks",
+            )
+            .defaults_to("false")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -1,11 +1,12 @@
 //! CONTENT004: Heading capitalization consistency
 //!
 //! Checks that headings use consistent capitalization style throughout
-//! the document (e.g., Title Case vs sentence case).
+//! the document (e.g., Title Case vs sentence case), and fixes headings that
+//! don't. Trailing punctuation is MD026's job.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Severity, Violation};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
 
@@ -36,17 +37,29 @@ pub enum CapitalizationStyle {
 /// This rule ensures headings use a consistent capitalization style.
 /// By default, it detects the style from the first heading and expects
 /// all subsequent headings to follow the same pattern.
+///
+/// Each violation carries a fix that rewrites the heading in the expected
+/// style. Acronyms, words with inner capitals such as `mdBook`, and words
+/// that don't start with a letter are left alone, and words in `exceptions`
+/// are always written as listed. Raising words to Title Case is safe;
+/// lowering them to sentence case is unsafe, since proper nouns missing from
+/// `exceptions` would be lowercased too.
 #[derive(Clone, Default)]
 pub struct CONTENT004 {
     /// Required capitalization style
     style: CapitalizationStyle,
+    /// Words always written as given, such as product names
+    exceptions: Vec<String>,
 }
 
 impl CONTENT004 {
     /// Create with a specific capitalization style
     #[allow(dead_code)]
     pub fn with_style(style: CapitalizationStyle) -> Self {
-        Self { style }
+        Self {
+            style,
+            ..Self::default()
+        }
     }
 
     /// Create an instance from rule configuration.
@@ -55,8 +68,16 @@ impl CONTENT004 {
     /// - `style`: `"title"`/`"title_case"`, `"sentence"`/`"sentence_case"`, or
     ///   `"consistent"` (default: use whatever style the first heading uses).
     ///   Unrecognized values fall back to the default.
+    /// - `exceptions`: words always written as given, such as `"mdBook"` or
+    ///   `"Rust"`; they are ignored when detecting a heading's style
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::default();
+        if let Some(exceptions) = config.get("exceptions").and_then(|v| v.as_array()) {
+            rule.exceptions = exceptions
+                .iter()
+                .filter_map(|v| v.as_str().map(str::to_string))
+                .collect();
+        }
         if let Some(style) = config.get("style").and_then(|v| v.as_str()) {
             rule.style = match style.to_lowercase().replace(['-', ' '], "_").as_str() {
                 "title" | "title_case" => CapitalizationStyle::TitleCase,
@@ -89,6 +110,98 @@ impl CONTENT004 {
         TITLE_CASE_EXCEPTIONS.contains(&word.to_lowercase().as_str())
     }
 
+    /// The configured spelling of `word`, ignoring case and surrounding
+    /// punctuation
+    fn configured_spelling(&self, word: &str) -> Option<&str> {
+        let bare = word.trim_matches(|c: char| !c.is_alphanumeric());
+        self.exceptions
+            .iter()
+            .find(|exception| exception.eq_ignore_ascii_case(bare))
+            .map(String::as_str)
+    }
+
+    /// Whether a word's case is fixed regardless of style: acronyms, words
+    /// with capitals after the first letter, words not starting with a
+    /// letter, and configured exceptions
+    fn keeps_case(&self, word: &str) -> bool {
+        self.is_acronym(word)
+            || word.chars().skip(1).any(char::is_uppercase)
+            || !word.chars().next().is_some_and(char::is_alphabetic)
+            || self.configured_spelling(word).is_some()
+    }
+
+    /// `text` rewritten in `style`
+    fn convert(&self, text: &str, style: CapitalizationStyle) -> String {
+        let words: Vec<&str> = text.split(' ').collect();
+        let last = words.len().saturating_sub(1);
+        let mut after_colon = false;
+        let converted: Vec<String> = words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                let subtitle = after_colon;
+                after_colon = word.ends_with(':');
+                if let Some(spelling) = self.configured_spelling(word) {
+                    let bare = word.trim_matches(|c: char| !c.is_alphanumeric());
+                    return word.replacen(bare, spelling, 1);
+                }
+                if word.is_empty() || self.keeps_case(word) {
+                    return word.to_string();
+                }
+                let capitalize = match style {
+                    CapitalizationStyle::TitleCase => {
+                        i == 0 || i == last || subtitle || !self.is_exception(word)
+                    }
+                    _ => i == 0 || subtitle,
+                };
+                let mut chars = word.chars();
+                let first = chars.next().expect("word is not empty");
+                if capitalize {
+                    first.to_uppercase().chain(chars).collect()
+                } else {
+                    first.to_lowercase().chain(chars).collect()
+                }
+            })
+            .collect();
+        converted.join(" ")
+    }
+
+    /// A violation for `text` on `line`, with a fix rewriting it in `style`
+    fn violation(
+        &self,
+        message: String,
+        line: &str,
+        line_num: usize,
+        text: &str,
+        style: CapitalizationStyle,
+    ) -> Violation {
+        let mut violation = self.create_violation(message, line_num, 1, Severity::Warning);
+        let converted = self.convert(text, style);
+        if let Some(offset) = line.find(text)
+            && converted != text
+        {
+            let column = line[..offset].chars().count() + 1;
+            violation.fix = Some(Fix {
+                description: format!("Change heading to '{converted}'"),
+                replacement: Some(converted),
+                start: Position {
+                    line: line_num,
+                    column,
+                },
+                end: Position {
+                    line: line_num,
+                    column: column + text.chars().count(),
+                },
+                safety: if style == CapitalizationStyle::TitleCase {
+                    FixSafety::Safe
+                } else {
+                    FixSafety::Unsafe
+                },
+            });
+        }
+        violation
+    }
+
     /// Get significant words (excluding acronyms and exceptions except first word)
     fn get_significant_words<'a>(&self, text: &'a str) -> Vec<(usize, &'a str)> {
         text.split_whitespace()
@@ -103,7 +216,7 @@ impl CONTENT004 {
                     return false;
                 }
                 // Skip exception words
-                if self.is_exception(word) {
+                if self.is_exception(word) || self.configured_spelling(word).is_some() {
                     return false;
                 }
                 // Skip non-alphabetic words
@@ -148,8 +261,8 @@ impl CONTENT004 {
         let mut checkable_non_first = 0;
 
         for word in words.iter().skip(1) {
-            // Skip acronyms
-            if self.is_acronym(word) {
+            // Skip acronyms and configured exceptions
+            if self.is_acronym(word) || self.configured_spelling(word).is_some() {
                 continue;
             }
             // Skip non-alphabetic
@@ -196,7 +309,26 @@ impl Rule for CONTENT004 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.12.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.12.0")
+            .option(
+                "style",
+                OptionKind::Choice(&[
+                    "consistent",
+                    "title",
+                    "title_case",
+                    "sentence",
+                    "sentence_case",
+                ]),
+                "Heading capitalization; `consistent` matches the first heading",
+            )
+            .defaults_to(r#""consistent""#)
+            .option(
+                "exceptions",
+                OptionKind::StringList,
+                "Words always written as given, such as product names",
+            )
+            .defaults_to("[]")
     }
 
     fn check_with_ast<'a>(
@@ -240,14 +372,15 @@ impl Rule for CONTENT004 {
                                     CapitalizationStyle::SentenceCase => "sentence case",
                                     CapitalizationStyle::Consistent => unreachable!(),
                                 };
-                                violations.push(self.create_violation(
+                                violations.push(self.violation(
                                     format!(
                                         "Heading '{}' uses inconsistent capitalization (expected {})",
                                         text, expected_name
                                     ),
+                                    line,
                                     line_num,
-                                    1,
-                                    Severity::Warning,
+                                    &text,
+                                    expected,
                                 ));
                             }
                         } else {
@@ -256,21 +389,23 @@ impl Rule for CONTENT004 {
                     }
                     CapitalizationStyle::TitleCase => {
                         if !self.is_title_case(&text) {
-                            violations.push(self.create_violation(
+                            violations.push(self.violation(
                                 format!("Heading '{}' should use Title Case", text),
+                                line,
                                 line_num,
-                                1,
-                                Severity::Warning,
+                                &text,
+                                CapitalizationStyle::TitleCase,
                             ));
                         }
                     }
                     CapitalizationStyle::SentenceCase => {
                         if !self.is_sentence_case(&text) {
-                            violations.push(self.create_violation(
+                            violations.push(self.violation(
                                 format!("Heading '{}' should use sentence case", text),
+                                line,
                                 line_num,
-                                1,
-                                Severity::Warning,
+                                &text,
+                                CapitalizationStyle::SentenceCase,
                             ));
                         }
                     }
@@ -437,4 +572,44 @@ mod tests {
         assert!(!rule.is_acronym("Api"));
         assert!(!rule.is_acronym("A")); // Single letter not acronym
     }
+
+    #[test]
+    fn test_fix_to_title_case() {
+        let doc = create_test_document(
+            "# Intro\n\n## Getting started with the mdBook CLI: a tour of it\n",
+        );
+        let violations = CONTENT004::with_style(CapitalizationStyle::TitleCase)
+            .check(&doc)
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(
+            fix.replacement.as_deref(),
+            Some("Getting Started with the mdBook CLI: A Tour of It")
+        );
+        assert_eq!((fix.start.column, fix.end.column), (4, 53));
+        assert_eq!(fix.safety, FixSafety::Safe);
+    }
+
+    #[test]
+    fn test_fix_to_sentence_case_with_exceptions() {
+        let config: toml::Value =
+            toml::from_str("style = \"sentence\"\nexceptions = [\"Rust\", \"Linux\", \"github\"]")
+                .unwrap();
+        let rule = CONTENT004::from_config(&config);
+
+        // Exceptions don't count as capitalized words
+        let doc = create_test_document(
+            "# Installing Rust on Linux\n\n# Using Github Actions With Rust\n",
+        );
+        let violations = rule.check(&doc).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(
+            fix.replacement.as_deref(),
+            Some("Using github actions with Rust")
+        );
+        assert_eq!(fix.safety, FixSafety::Unsafe);
+    }
 }
@@ -4,7 +4,7 @@
 //! Jumping directly to subheadings without context can confuse readers.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -133,7 +133,21 @@ impl Rule for CONTENT005 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.12.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.12.0")
+            .option(
+                "min_words",
+                OptionKind::IntegerAtLeast(0),
+                "Words required in the introduction paragraph",
+            )
+            .defaults_to("10")
+            .option(
+                "min_intro_words",
+                OptionKind::IntegerAtLeast(0),
+                "Older name for `min_words`",
+            )
+            .defaults_to("10")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -4,7 +4,7 @@
 //! "config" and "configuration", or "setup" and "set up" inconsistently.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use std::collections::HashMap;
 
@@ -200,7 +200,20 @@ impl Rule for CONTENT007 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.14.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.14.0")
+            .option(
+                "term_groups",
+                OptionKind::Any,
+                "Groups of terms that should be used consistently, replacing the built-in groups",
+            )
+            .option(
+                "min_occurrences",
+                OptionKind::IntegerAtLeast(1),
+                "Times a group's terms must appear before an inconsistency is reported",
+            )
+            .defaults_to("1")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
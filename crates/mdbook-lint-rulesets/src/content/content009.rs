@@ -4,7 +4,7 @@
 //! indicate overly complex document structure that's hard to navigate.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -74,7 +74,15 @@ impl Rule for CONTENT009 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.14.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.14.0")
+            .option(
+                "max_depth",
+                OptionKind::IntegerBetween(1, 6),
+                "Deepest heading level allowed",
+            )
+            .defaults_to("4")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -0,0 +1,307 @@
+//! CONTENT012: Project vocabulary
+//!
+//! Enforces a style vocabulary defined in the `[vocabulary]` table of
+//! `.mdbook-lint.toml`, in the spirit of Vale's substitution and existence
+//! checks: banned words and phrases, preferred replacements, and the exact
+//! spelling of product names. The rule does nothing until a vocabulary is
+//! configured.
+
+use crate::prose::{is_embedded, match_case, prose_lines, term_pattern};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+use regex::Regex;
+
+/// What a vocabulary entry asks for
+#[derive(Debug, Clone)]
+enum Action {
+    /// The term should not appear; the optional text explains why
+    Ban(Option<String>),
+    /// The term should be replaced with the given text
+    Replace(String),
+    /// The term must be written exactly like this
+    Name(String),
+}
+
+/// A single vocabulary entry
+#[derive(Debug, Clone)]
+struct Entry {
+    /// Case-insensitive matcher for the term
+    pattern: Regex,
+    action: Action,
+}
+
+impl Entry {
+    fn new(term: &str, action: Action) -> Option<Self> {
+        term_pattern(term).map(|pattern| Self { pattern, action })
+    }
+}
+
+/// CONTENT012: Enforces the configured project vocabulary
+#[derive(Debug, Clone, Default)]
+pub struct CONTENT012 {
+    entries: Vec<Entry>,
+}
+
+impl CONTENT012 {
+    /// Create an instance from the `[vocabulary]` configuration table.
+    ///
+    /// Recognized keys:
+    /// - `banned`: array of words and phrases to avoid, or a table mapping
+    ///   each one to an explanation shown in the message.
+    /// - `replace`: table mapping discouraged terms to preferred ones
+    ///   (`utilize = "use"`). These are auto-fixable.
+    /// - `names`: array of product and project names with their required
+    ///   capitalization (`"GitHub"`). These are auto-fixable.
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut entries = Vec::new();
+
+        match config.get("banned") {
+            Some(toml::Value::Array(terms)) => {
+                entries.extend(
+                    terms
+                        .iter()
+                        .filter_map(|t| t.as_str())
+                        .filter_map(|t| Entry::new(t, Action::Ban(None))),
+                );
+            }
+            Some(toml::Value::Table(terms)) => {
+                entries.extend(terms.iter().filter_map(|(term, reason)| {
+                    let reason = reason.as_str().map(String::from);
+                    Entry::new(term, Action::Ban(reason))
+                }));
+            }
+            _ => {}
+        }
+
+        if let Some(replace) = config.get("replace").and_then(|v| v.as_table()) {
+            entries.extend(replace.iter().filter_map(|(term, preferred)| {
+                Entry::new(term, Action::Replace(preferred.as_str()?.to_string()))
+            }));
+        }
+
+        if let Some(names) = config.get("names").and_then(|v| v.as_array()) {
+            entries.extend(
+                names
+                    .iter()
+                    .filter_map(|n| n.as_str())
+                    .filter_map(|n| Entry::new(n, Action::Name(n.to_string()))),
+            );
+        }
+
+        Self { entries }
+    }
+}
+
+impl Rule for CONTENT012 {
+    fn id(&self) -> &'static str {
+        "CONTENT012"
+    }
+
+    fn name(&self) -> &'static str {
+        "vocabulary"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prose should follow the project vocabulary"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "banned",
+                OptionKind::Any,
+                "Words and phrases to avoid, as a list or a table of explanations",
+            )
+            .option(
+                "replace",
+                OptionKind::Any,
+                "Table mapping discouraged terms to preferred ones",
+            )
+            .option(
+                "names",
+                OptionKind::StringList,
+                "Product and project names with their required capitalization",
+            )
+    }
+
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        if self.entries.is_empty() {
+            return Ok(violations);
+        }
+
+        for (line_number, masked) in prose_lines(document) {
+            let mut line_violations = Vec::new();
+
+            for entry in &self.entries {
+                for m in entry.pattern.find_iter(&masked) {
+                    let matched = m.as_str();
+
+                    // Skip domain names and paths such as github.com or docs/utilize.md
+                    if is_embedded(&masked, m.start(), m.end()) {
+                        continue;
+                    }
+
+                    let (message, replacement) = match &entry.action {
+                        Action::Ban(None) => (format!("Avoid '{matched}'"), None),
+                        Action::Ban(Some(reason)) => (format!("Avoid '{matched}': {reason}"), None),
+                        Action::Replace(preferred) => {
+                            let preferred = match_case(matched, preferred);
+                            (
+                                format!("Use '{preferred}' instead of '{matched}'"),
+                                Some(preferred),
+                            )
+                        }
+                        Action::Name(name) => {
+                            if matched == name {
+                                continue;
+                            }
+                            (
+                                format!("'{matched}' should be written as '{name}'"),
+                                Some(name.clone()),
+                            )
+                        }
+                    };
+
+                    let column = masked[..m.start()].chars().count() + 1;
+                    let violation = match replacement {
+                        Some(replacement) => {
+                            let fix = Fix {
+                                description: format!("Replace '{matched}' with '{replacement}'"),
+                                replacement: Some(replacement),
+                                start: Position {
+                                    line: line_number,
+                                    column,
+                                },
+                                end: Position {
+                                    line: line_number,
+                                    column: column + matched.chars().count(),
+                                },
+                                safety: FixSafety::Suggestion,
+                            };
+                            self.create_violation_with_fix(
+                                message,
+                                line_number,
+                                column,
+                                Severity::Warning,
+                                fix,
+                            )
+                        }
+                        None => {
+                            self.create_violation(message, line_number, column, Severity::Warning)
+                        }
+                    };
+                    line_violations.push(violation);
+                }
+            }
+
+            line_violations.sort_by_key(|v| v.column);
+            violations.extend(line_violations);
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn rule(config: &str) -> CONTENT012 {
+        CONTENT012::from_config(&toml::from_str(config).unwrap())
+    }
+
+    fn check(rule: &CONTENT012, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_no_vocabulary_configured() {
+        let violations = check(&CONTENT012::default(), "Simply utilize github.\n");
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_banned_terms() {
+        let rule = rule("banned = [\"simply\", \"of course\"]");
+        let violations = check(&rule, "Simply run it.\nThis is, of\tcourse, easy.\n");
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].message, "Avoid 'Simply'");
+        assert_eq!(violations[1].line, 2);
+        assert_eq!(violations[1].column, 10);
+        assert!(violations.iter().all(|v| v.fix.is_none()));
+    }
+
+    #[test]
+    fn test_banned_with_reason() {
+        let rule = rule("[banned]\nobviously = \"it may not be obvious to the reader\"");
+        let violations = check(&rule, "This is obviously right.\n");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Avoid 'obviously': it may not be obvious to the reader"
+        );
+    }
+
+    #[test]
+    fn test_replacements_with_fix() {
+        let rule = rule("[replace]\nutilize = \"use\"\n\"in order to\" = \"to\"");
+        let violations = check(&rule, "Utilize the CLI in order to lint.\n");
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].message, "Use 'Use' instead of 'Utilize'");
+        let fix = violations[1].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("to"));
+        assert_eq!(fix.start.column, 17);
+        assert_eq!(fix.end.column, 28);
+        assert_eq!(fix.safety, FixSafety::Suggestion);
+    }
+
+    #[test]
+    fn test_product_names() {
+        let rule = rule("names = [\"GitHub\", \"mdBook\"]");
+        let violations = check(
+            &rule,
+            "Host the MDBook on Github. GitHub and mdBook are fine.\n",
+        );
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[0].message,
+            "'MDBook' should be written as 'mdBook'"
+        );
+        assert_eq!(
+            violations[1].fix.as_ref().unwrap().replacement.as_deref(),
+            Some("GitHub")
+        );
+    }
+
+    #[test]
+    fn test_code_urls_and_paths_skipped() {
+        let rule = rule("names = [\"GitHub\"]\n[replace]\nutilize = \"use\"");
+        let content = "See `github` at https://github.com/utilize, github.com, \
+                       and [the guide](utilize.md).\n\n```\nutilize github\n```\n";
+        assert!(check(&rule, content).is_empty());
+    }
+
+    #[test]
+    fn test_non_word_terms() {
+        let rule = rule("names = [\"C++\"]");
+        let violations = check(&rule, "Written in c++ mostly.\n");
+        assert_eq!(violations.len(), 1);
+    }
+}
@@ -0,0 +1,418 @@
+//! CONTENT013: Glossary consistency
+//!
+//! Reads the book's glossary and checks that every chapter writes its terms
+//! the same way: `GitHub` rather than `Github`, `mdBook` rather than
+//! `MdBook`. This rule analyzes all documents in a collection and does
+//! nothing until a glossary is configured.
+
+use crate::prose::{is_embedded, prose_lines, term_pattern};
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity};
+use mdbook_lint_core::{Document, Result, Violation};
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// A bold term at the start of a list item or paragraph: `- **mdBook**: ...`
+static BOLD_TERM_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(?:[-*+]\s+)?(?:\*\*([^*]+)\*\*|__([^_]+)__)").expect("Invalid regex")
+});
+
+/// A glossary term and how it must be written
+#[derive(Debug, Clone)]
+struct Term {
+    /// Case-insensitive matcher for the term or alias
+    pattern: Regex,
+    /// The spelling chapters should use
+    canonical: String,
+    /// Whether the pattern matches a different spelling, not just a
+    /// different casing, of `canonical`
+    alias: bool,
+}
+
+/// CONTENT013: Checks chapters against the glossary
+///
+/// Casing differences are fixed automatically. A term written in lowercase
+/// apart from its first letter, such as a glossary heading "Preprocessor",
+/// may start with either case so it can begin a sentence.
+#[derive(Debug, Clone, Default)]
+pub struct CONTENT013 {
+    glossary: Option<PathBuf>,
+}
+
+impl CONTENT013 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `glossary`: path to the glossary, a Markdown chapter or a TOML file
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            glossary: config
+                .get("glossary")
+                .and_then(|v| v.as_str())
+                .map(PathBuf::from),
+        }
+    }
+
+    /// Terms from a Markdown glossary: `##` and deeper headings, bold text
+    /// starting a list item or paragraph, and definition list terms
+    fn markdown_terms(content: &str) -> Vec<(String, Option<String>)> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut terms = Vec::new();
+        let mut in_code_block = false;
+
+        for (i, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+
+            let term = if trimmed.starts_with("##") {
+                Some(trimmed.trim_start_matches('#').trim().trim_end_matches('#'))
+            } else if let Some(caps) = BOLD_TERM_REGEX.captures(line) {
+                caps.get(1).or_else(|| caps.get(2)).map(|m| m.as_str())
+            } else if lines
+                .get(i + 1)
+                .is_some_and(|next| next.trim_start().starts_with(": "))
+            {
+                Some(trimmed)
+            } else {
+                None
+            };
+
+            // Single letters are section headings of an alphabetical glossary
+            if let Some(term) = term.map(|t| t.trim().trim_end_matches(':').trim())
+                && term.chars().count() > 1
+            {
+                terms.push((term.to_string(), None));
+            }
+        }
+
+        terms
+    }
+
+    /// Terms from a TOML glossary: a `terms` array of spellings and an
+    /// `aliases` table mapping other spellings to them
+    fn toml_terms(content: &str) -> std::result::Result<Vec<(String, Option<String>)>, String> {
+        let value: toml::Value =
+            toml::from_str(content).map_err(|e| e.to_string().trim().to_string())?;
+        let mut terms: Vec<(String, Option<String>)> = value
+            .get("terms")
+            .and_then(|v| v.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|t| t.as_str().map(|t| (t.to_string(), None)))
+            .collect();
+        if let Some(aliases) = value.get("aliases").and_then(|v| v.as_table()) {
+            terms.extend(aliases.iter().filter_map(|(alias, canonical)| {
+                Some((alias.clone(), Some(canonical.as_str()?.to_string())))
+            }));
+        }
+        Ok(terms)
+    }
+
+    /// Build matchers, longest first so "GitHub Actions" wins over "GitHub"
+    fn build_terms(entries: Vec<(String, Option<String>)>) -> Vec<Term> {
+        let mut terms: Vec<Term> = entries
+            .into_iter()
+            .filter_map(|(term, canonical)| {
+                let alias = canonical.is_some();
+                Some(Term {
+                    pattern: term_pattern(&term)?,
+                    canonical: canonical.unwrap_or(term),
+                    alias,
+                })
+            })
+            .collect();
+        terms.sort_by_key(|t| std::cmp::Reverse(t.pattern.as_str().len()));
+        terms
+    }
+
+    /// Whether `matched` is an acceptable way to write `canonical`
+    fn is_consistent(matched: &str, canonical: &str) -> bool {
+        if matched == canonical {
+            return true;
+        }
+        let mut canonical_chars = canonical.chars();
+        let mut matched_chars = matched.chars();
+        let (Some(first), Some(matched_first)) = (canonical_chars.next(), matched_chars.next())
+        else {
+            return false;
+        };
+        let rest = canonical_chars.as_str();
+        !rest.chars().any(char::is_uppercase)
+            && first.to_lowercase().eq(matched_first.to_lowercase())
+            && matched_chars.as_str() == rest
+    }
+
+    /// Check one chapter against the glossary terms
+    fn check_document(&self, document: &Document, terms: &[Term]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for (line_number, masked) in prose_lines(document) {
+            let mut covered: Vec<(usize, usize)> = Vec::new();
+            let mut line_violations = Vec::new();
+
+            for term in terms {
+                for m in term.pattern.find_iter(&masked) {
+                    if covered.iter().any(|&(s, e)| m.start() < e && s < m.end())
+                        || is_embedded(&masked, m.start(), m.end())
+                    {
+                        continue;
+                    }
+                    covered.push((m.start(), m.end()));
+
+                    let matched = m.as_str();
+                    if !term.alias && Self::is_consistent(matched, &term.canonical) {
+                        continue;
+                    }
+
+                    let column = masked[..m.start()].chars().count() + 1;
+                    let message = if term.alias {
+                        format!(
+                            "Use '{}' instead of '{matched}' (see glossary)",
+                            term.canonical
+                        )
+                    } else {
+                        format!(
+                            "'{matched}' should be written as '{}' (see glossary)",
+                            term.canonical
+                        )
+                    };
+                    let mut violation = self.create_violation_for_file(
+                        &document.path,
+                        message,
+                        line_number,
+                        column,
+                        Severity::Warning,
+                    );
+
+                    // Only casing corrections are safe to apply unreviewed
+                    if !term.alias {
+                        violation.fix = Some(Fix {
+                            description: format!("Replace '{matched}' with '{}'", term.canonical),
+                            replacement: Some(term.canonical.clone()),
+                            start: Position {
+                                line: line_number,
+                                column,
+                            },
+                            end: Position {
+                                line: line_number,
+                                column: column + matched.chars().count(),
+                            },
+                            safety: FixSafety::Safe,
+                        });
+                    }
+                    line_violations.push(violation);
+                }
+            }
+
+            line_violations.sort_by_key(|v| v.column);
+            violations.extend(line_violations);
+        }
+
+        violations
+    }
+
+    /// Whether `path` is the configured glossary
+    fn is_glossary(path: &Path, glossary: &Path) -> bool {
+        path == glossary || path.ends_with(glossary)
+    }
+}
+
+impl CollectionRule for CONTENT013 {
+    fn id(&self) -> &'static str {
+        "CONTENT013"
+    }
+
+    fn name(&self) -> &'static str {
+        "glossary-consistency"
+    }
+
+    fn description(&self) -> &'static str {
+        "Glossary terms should be written consistently across chapters"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "glossary",
+                OptionKind::String,
+                "Path to the glossary, a Markdown chapter or a TOML file",
+            )
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let Some(glossary) = &self.glossary else {
+            return Ok(Vec::new());
+        };
+
+        // Prefer the linted copy of a Markdown glossary over reading the disk
+        let content = match documents
+            .iter()
+            .find(|doc| Self::is_glossary(&doc.path, glossary))
+        {
+            Some(doc) => doc.content.clone(),
+            None => match std::fs::read_to_string(glossary) {
+                Ok(content) => content,
+                Err(e) => {
+                    return Ok(vec![self.create_violation_for_file(
+                        glossary,
+                        format!("Glossary could not be read: {e}"),
+                        1,
+                        1,
+                        Severity::Warning,
+                    )]);
+                }
+            },
+        };
+
+        let is_toml = glossary.extension().is_some_and(|ext| ext == "toml");
+        let entries = if is_toml {
+            match Self::toml_terms(&content) {
+                Ok(entries) => entries,
+                Err(e) => {
+                    return Ok(vec![self.create_violation_for_file(
+                        glossary,
+                        format!("Glossary is not valid TOML: {e}"),
+                        1,
+                        1,
+                        Severity::Warning,
+                    )]);
+                }
+            }
+        } else {
+            Self::markdown_terms(&content)
+        };
+        let terms = Self::build_terms(entries);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        Ok(documents
+            .iter()
+            .filter(|doc| !Self::is_glossary(&doc.path, glossary))
+            .flat_map(|doc| self.check_document(doc, &terms))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn doc(path: &str, content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from(path)).unwrap()
+    }
+
+    fn rule(glossary: &str) -> CONTENT013 {
+        CONTENT013 {
+            glossary: Some(PathBuf::from(glossary)),
+        }
+    }
+
+    const GLOSSARY: &str = "# Glossary\n\n## A\n\n## GitHub Actions\n\nCI service.\n\n- **mdBook**: the book tool\n\nPreprocessor\n: Transforms chapters before rendering.\n";
+
+    #[test]
+    fn test_glossary_terms() {
+        let terms: Vec<String> = CONTENT013::markdown_terms(GLOSSARY)
+            .into_iter()
+            .map(|(term, _)| term)
+            .collect();
+        assert_eq!(terms, vec!["GitHub Actions", "mdBook", "Preprocessor"]);
+    }
+
+    #[test]
+    fn test_inconsistent_usage_across_chapters() {
+        let docs = vec![
+            doc("src/glossary.md", GLOSSARY),
+            doc(
+                "src/intro.md",
+                "# Intro\n\nThis Mdbook uses a preprocessor. Preprocessors run first.\n",
+            ),
+            doc(
+                "src/ci.md",
+                "# CI\n\nSet up github actions, not `github actions`.\n\nSee mdbook.github.io.\n",
+            ),
+        ];
+        let violations = rule("src/glossary.md").check_collection(&docs).unwrap();
+
+        let messages: Vec<String> = violations
+            .iter()
+            .map(|v| format!("{}: {}", v.path.as_ref().unwrap().display(), v.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "src/intro.md: 'Mdbook' should be written as 'mdBook' (see glossary)",
+                "src/ci.md: 'github actions' should be written as 'GitHub Actions' (see glossary)",
+            ]
+        );
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("mdBook"));
+        assert_eq!((fix.start.column, fix.end.column), (6, 12));
+        assert_eq!(fix.safety, FixSafety::Safe);
+    }
+
+    #[test]
+    fn test_toml_glossary_aliases() {
+        let dir = tempfile::tempdir().unwrap();
+        let glossary = dir.path().join("glossary.toml");
+        std::fs::write(
+            &glossary,
+            "terms = [\"email\", \"API\"]\n\n[aliases]\n\"e-mail\" = \"email\"\n",
+        )
+        .unwrap();
+
+        let docs = vec![doc("intro.md", "Send an E-mail to the api.\n")];
+        let violations = CONTENT013 {
+            glossary: Some(glossary),
+        }
+        .check_collection(&docs)
+        .unwrap();
+        let messages: Vec<String> = violations
+            .iter()
+            .map(|v| format!("{}: {}", v.path.as_ref().unwrap().display(), v.message))
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "intro.md: Use 'email' instead of 'E-mail' (see glossary)",
+                "intro.md: 'api' should be written as 'API' (see glossary)",
+            ]
+        );
+        assert!(violations[0].fix.is_none());
+        assert!(violations[1].fix.is_some());
+    }
+
+    #[test]
+    fn test_no_glossary_configured() {
+        let docs = vec![doc("intro.md", "Github\n")];
+        assert!(
+            CONTENT013::default()
+                .check_collection(&docs)
+                .unwrap()
+                .is_empty()
+        );
+
+        let config: toml::Value = toml::from_str("glossary = \"missing/glossary.md\"").unwrap();
+        let violations = CONTENT013::from_config(&config)
+            .check_collection(&docs)
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].path,
+            Some(PathBuf::from("missing/glossary.md"))
+        );
+        assert!(
+            violations[0]
+                .message
+                .starts_with("Glossary could not be read")
+        );
+    }
+}
@@ -0,0 +1,478 @@
+//! CONTENT014: Duplicate content
+//!
+//! Finds paragraphs and sections that are copied, with small edits, across
+//! chapters. Copies drift apart as one is updated and the other isn't, so
+//! shared text belongs in one file pulled in with `{{#include}}`.
+//!
+//! Each block of prose is reduced to a MinHash signature of its word
+//! shingles, and candidate pairs are found with locality-sensitive hashing,
+//! so books with thousands of paragraphs don't need every pair compared.
+//! This rule analyzes all documents in a collection and is opt-in: it does
+//! nothing until it has a `[CONTENT014]` table or is listed in
+//! `enabled-rules`.
+
+use crate::prose::prose_lines;
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Number of MinHash functions in a signature
+const SIGNATURE_LEN: usize = 64;
+/// Signature rows per LSH band; 16 bands of 4 rows find pairs above about
+/// 60% similarity
+const BAND_ROWS: usize = 4;
+
+/// A paragraph or section of prose
+#[derive(Debug, Clone)]
+struct Block {
+    /// Index of the document in the collection
+    doc: usize,
+    /// 1-based first line
+    line: usize,
+    /// 1-based last line
+    end_line: usize,
+    /// Section heading text, or `None` for a paragraph
+    heading: Option<String>,
+    signature: Vec<u64>,
+}
+
+impl Block {
+    fn describe(&self) -> String {
+        match &self.heading {
+            Some(heading) => format!("Section '{heading}'"),
+            None => "Paragraph".to_string(),
+        }
+    }
+
+    fn contains(&self, other: &Block) -> bool {
+        self.doc == other.doc && self.line <= other.line && other.end_line <= self.end_line
+    }
+}
+
+/// CONTENT014: Reports near-duplicate paragraphs and sections across chapters
+///
+/// Similarity is the estimated Jaccard similarity of the blocks' word
+/// shingles. When a whole section is duplicated, the section is reported
+/// once rather than each of its paragraphs.
+#[derive(Debug, Clone)]
+pub struct CONTENT014 {
+    enabled: bool,
+    /// Minimum similarity, from 0.0 to 1.0, to report a pair
+    threshold: f64,
+    /// Blocks with fewer words are ignored
+    min_words: usize,
+    /// Words per shingle
+    shingle_size: usize,
+}
+
+impl Default for CONTENT014 {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            threshold: 0.8,
+            min_words: 30,
+            shingle_size: 5,
+        }
+    }
+}
+
+impl CONTENT014 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `threshold`: minimum similarity to report, 0.0 to 1.0 (default 0.8)
+    /// - `min-words`: ignore blocks with fewer words (default 30)
+    /// - `shingle-size`: words per shingle (default 5)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            enabled: true,
+            ..Self::default()
+        };
+        if let Some(threshold) = config
+            .get("threshold")
+            .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+        {
+            rule.threshold = threshold.clamp(0.0, 1.0);
+        }
+        let usize_key = |keys: [&str; 2]| {
+            keys.iter()
+                .find_map(|key| config.get(*key))
+                .and_then(|v| v.as_integer())
+                .and_then(|n| usize::try_from(n).ok())
+                .filter(|n| *n > 0)
+        };
+        if let Some(min_words) = usize_key(["min-words", "min_words"]) {
+            rule.min_words = min_words;
+        }
+        if let Some(shingle_size) = usize_key(["shingle-size", "shingle_size"]) {
+            rule.shingle_size = shingle_size;
+        }
+        rule
+    }
+
+    /// An instance that runs with the default settings
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Split a document into paragraphs and sections with their words
+    fn blocks(&self, doc_index: usize, document: &Document) -> Vec<Block> {
+        let prose: HashMap<usize, String> = prose_lines(document).into_iter().collect();
+        let heading_level = |line: &str| {
+            let level = line.bytes().take_while(|b| *b == b'#').count();
+            ((1..=6).contains(&level) && line[level..].starts_with([' ', '\t'])).then_some(level)
+        };
+
+        let mut blocks = Vec::new();
+        let mut headings: Vec<(usize, usize, String)> = Vec::new();
+        let mut lines: Vec<(usize, Vec<String>)> = Vec::new();
+        let mut paragraph: Vec<(usize, Vec<String>)> = Vec::new();
+
+        for line_number in 1..=document.lines.len() {
+            let text = prose.get(&line_number).map(|t| t.trim_start());
+            match text {
+                Some(text) if !text.is_empty() => {
+                    if let Some(level) = heading_level(text) {
+                        self.push_block(
+                            doc_index,
+                            std::mem::take(&mut paragraph),
+                            None,
+                            &mut blocks,
+                        );
+                        let title = text[level..].trim().trim_end_matches('#').trim();
+                        headings.push((line_number, level, title.to_string()));
+                    } else {
+                        let words = words(text);
+                        lines.push((line_number, words.clone()));
+                        paragraph.push((line_number, words));
+                    }
+                }
+                _ => self.push_block(doc_index, std::mem::take(&mut paragraph), None, &mut blocks),
+            }
+        }
+        self.push_block(doc_index, paragraph, None, &mut blocks);
+
+        // A section runs until the next heading at the same or a higher level
+        for (i, (start, level, title)) in headings.iter().enumerate() {
+            let end = headings[i + 1..]
+                .iter()
+                .find(|(_, next_level, _)| next_level <= level)
+                .map_or(usize::MAX, |(line, _, _)| line - 1);
+            let body: Vec<(usize, Vec<String>)> = lines
+                .iter()
+                .filter(|(line, _)| line > start && *line <= end)
+                .cloned()
+                .collect();
+            let end = body.last().map_or(*start, |(line, _)| *line);
+            let mut section = vec![(*start, Vec::new())];
+            section.extend(body);
+            section.push((end, Vec::new()));
+            self.push_block(doc_index, section, Some(title.clone()), &mut blocks);
+        }
+
+        blocks
+    }
+
+    /// Add a block if it has enough words
+    fn push_block(
+        &self,
+        doc: usize,
+        lines: Vec<(usize, Vec<String>)>,
+        heading: Option<String>,
+        blocks: &mut Vec<Block>,
+    ) {
+        let (Some((line, _)), Some((end_line, _))) = (lines.first(), lines.last()) else {
+            return;
+        };
+        let (line, end_line) = (*line, *end_line);
+        let words: Vec<String> = lines.into_iter().flat_map(|(_, words)| words).collect();
+        if words.len() < self.min_words.max(self.shingle_size) {
+            return;
+        }
+        blocks.push(Block {
+            doc,
+            line,
+            end_line,
+            heading,
+            signature: self.signature(&words),
+        });
+    }
+
+    /// MinHash signature of the word shingles
+    fn signature(&self, words: &[String]) -> Vec<u64> {
+        let shingles: BTreeSet<u64> = words
+            .windows(self.shingle_size)
+            .map(|window| fnv1a(window.join(" ").as_bytes()))
+            .collect();
+        (0..SIGNATURE_LEN as u64)
+            .map(|i| {
+                let seed = splitmix64(i.wrapping_add(1));
+                shingles
+                    .iter()
+                    .map(|shingle| splitmix64(shingle ^ seed))
+                    .min()
+                    .unwrap_or(u64::MAX)
+            })
+            .collect()
+    }
+
+    /// Pairs of blocks in different documents that share an LSH band
+    fn candidates(blocks: &[Block]) -> BTreeSet<(usize, usize)> {
+        let mut buckets: HashMap<(usize, bool, u64), Vec<usize>> = HashMap::new();
+        for (index, block) in blocks.iter().enumerate() {
+            for (band, rows) in block.signature.chunks(BAND_ROWS).enumerate() {
+                let key = rows.iter().fold(0, |hash, row| splitmix64(hash ^ row));
+                buckets
+                    .entry((band, block.heading.is_some(), key))
+                    .or_default()
+                    .push(index);
+            }
+        }
+
+        let mut pairs = BTreeSet::new();
+        for bucket in buckets.values() {
+            for (n, &a) in bucket.iter().enumerate() {
+                for &b in &bucket[n + 1..] {
+                    if blocks[a].doc != blocks[b].doc {
+                        pairs.insert((a.min(b), a.max(b)));
+                    }
+                }
+            }
+        }
+        pairs
+    }
+}
+
+/// Lowercase words of a line of prose
+fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// FNV-1a hash, stable across runs and platforms
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// SplitMix64 finalizer, used to derive independent hash functions
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+fn similarity(a: &Block, b: &Block) -> f64 {
+    let same = a
+        .signature
+        .iter()
+        .zip(&b.signature)
+        .filter(|(x, y)| x == y)
+        .count();
+    same as f64 / SIGNATURE_LEN as f64
+}
+
+impl CollectionRule for CONTENT014 {
+    fn id(&self) -> &'static str {
+        "CONTENT014"
+    }
+
+    fn name(&self) -> &'static str {
+        "duplicate-content"
+    }
+
+    fn description(&self) -> &'static str {
+        "Paragraphs and sections should not be duplicated across chapters"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "threshold",
+                OptionKind::NumberBetween(0.0, 1.0),
+                "Minimum similarity to report, 0.0 to 1.0",
+            )
+            .defaults_to("0.8")
+            .option(
+                "min-words",
+                OptionKind::IntegerAtLeast(1),
+                "Ignore blocks with fewer words",
+            )
+            .defaults_to("30")
+            .option(
+                "shingle-size",
+                OptionKind::IntegerAtLeast(1),
+                "Words per shingle",
+            )
+            .defaults_to("5")
+            .either_case()
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let blocks: Vec<Block> = documents
+            .iter()
+            .enumerate()
+            .flat_map(|(index, document)| self.blocks(index, document))
+            .collect();
+
+        // Best earlier match for each block, keyed by the later block so
+        // each copy is reported once against its original
+        let mut matches: BTreeMap<usize, (usize, f64)> = BTreeMap::new();
+        for (a, b) in Self::candidates(&blocks) {
+            let score = similarity(&blocks[a], &blocks[b]);
+            if score < self.threshold {
+                continue;
+            }
+            let (original, copy) = if blocks[a].doc < blocks[b].doc {
+                (a, b)
+            } else {
+                (b, a)
+            };
+            let best = matches.entry(copy).or_insert((original, score));
+            // On a tie prefer the tighter block, the section itself over
+            // the chapter that contains only it
+            let span = |index: usize| blocks[index].end_line - blocks[index].line;
+            if score > best.1 || (score == best.1 && span(original) < span(best.0)) {
+                *best = (original, score);
+            }
+        }
+
+        // Report whole sections first; skip paragraphs inside them
+        let mut reported: Vec<(usize, usize)> = Vec::new();
+        let mut ordered: Vec<(usize, usize, f64)> = matches
+            .into_iter()
+            .map(|(copy, (original, score))| (copy, original, score))
+            .collect();
+        ordered.sort_by_key(|(copy, _, _)| {
+            let block = &blocks[*copy];
+            (
+                block.heading.is_none(),
+                std::cmp::Reverse(block.end_line - block.line),
+                block.doc,
+                block.line,
+            )
+        });
+
+        let mut violations = Vec::new();
+        for (copy, original, score) in ordered {
+            let (copy_block, original_block) = (&blocks[copy], &blocks[original]);
+            if reported
+                .iter()
+                .any(|&(c, o)| blocks[c].contains(copy_block) && blocks[o].contains(original_block))
+            {
+                continue;
+            }
+            reported.push((copy, original));
+
+            let original_doc = &documents[original_block.doc];
+            violations.push(self.create_violation_for_file(
+                &documents[copy_block.doc].path,
+                format!(
+                    "{} is {:.0}% similar to {}:{}; consider moving the shared text into a file pulled in with {{{{#include}}}}",
+                    copy_block.describe(),
+                    score * 100.0,
+                    original_doc.path.display(),
+                    original_block.line
+                ),
+                copy_block.line,
+                1,
+                Severity::Warning,
+            ));
+        }
+
+        violations.sort_by(|a, b| a.message.cmp(&b.message).then(a.line.cmp(&b.line)));
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    const SETUP: &str = "Before you begin, install the toolchain with rustup and make sure \
+        cargo is on your path. Then clone the repository, change into its directory, and \
+        run the build once so every dependency is downloaded and compiled before the \
+        first chapter's examples are tried.";
+
+    const OTHER: &str = "This chapter talks about something else, with enough words to be \
+        considered by the rule but no overlap with the setup instructions at all, covering \
+        renderers, themes, preprocessors, and the search index instead, along with a few \
+        notes on publishing the finished book to a static host.";
+
+    fn doc(path: &str, content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from(path)).unwrap()
+    }
+
+    fn messages(rule: &CONTENT014, docs: &[Document]) -> Vec<String> {
+        rule.check_collection(docs)
+            .unwrap()
+            .into_iter()
+            .map(|v| format!("{}: {}", v.path.unwrap().display(), v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let docs = vec![doc("a.md", SETUP), doc("b.md", SETUP)];
+        assert!(messages(&CONTENT014::default(), &docs).is_empty());
+    }
+
+    #[test]
+    fn test_near_duplicate_paragraph() {
+        let edited = SETUP.replace("the repository", "the project repository");
+        let docs = vec![
+            doc("intro.md", &format!("# Intro\n\nWelcome.\n\n{SETUP}\n")),
+            doc(
+                "guide.md",
+                &format!("# Guide\n\nA different opening paragraph entirely.\n\n{edited}\n"),
+            ),
+            doc("other.md", &format!("# Other\n\n{OTHER}\n")),
+        ];
+        let violations = CONTENT014::enabled().check_collection(&docs).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 5);
+        assert_eq!(violations[0].path, Some(PathBuf::from("guide.md")));
+        assert!(
+            violations[0].message.starts_with("Paragraph is "),
+            "{}",
+            violations[0].message
+        );
+        assert!(violations[0].message.contains("similar to intro.md:5"));
+    }
+
+    #[test]
+    fn test_duplicate_section_reported_once() {
+        let section = format!("## Installing\n\n{SETUP}\n\n{}\n", SETUP.to_uppercase());
+        let docs = vec![
+            doc("a.md", &format!("# A\n\n{section}")),
+            doc("b.md", &format!("# B\n\n{OTHER}\n\n{section}")),
+        ];
+        let found = messages(&CONTENT014::enabled(), &docs);
+        assert_eq!(found.len(), 1, "{found:?}");
+        assert!(found[0].starts_with("b.md: Section 'Installing' is 100% similar to a.md:3"));
+    }
+
+    #[test]
+    fn test_configuration() {
+        let config: toml::Value = toml::from_str("threshold = 1.0\nmin-words = 1000").unwrap();
+        let rule = CONTENT014::from_config(&config);
+        let docs = vec![doc("a.md", SETUP), doc("b.md", SETUP)];
+        assert!(messages(&rule, &docs).is_empty());
+        assert_eq!(messages(&CONTENT014::enabled(), &docs).len(), 1);
+    }
+}
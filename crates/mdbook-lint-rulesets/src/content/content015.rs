@@ -0,0 +1,199 @@
+//! CONTENT015: Sentence length
+//!
+//! Long sentences are the most common reason prose is hard to follow. This
+//! rule reports sentences over a word limit and chapters whose average
+//! sentence runs long, for teams that keep a prose style budget. It is
+//! opt-in and reports at Info severity.
+
+use super::readability::{MIN_SENTENCES, sentences};
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CONTENT015: Reports long sentences and a long average sentence length
+///
+/// The average is only checked in chapters with at least five sentences.
+#[derive(Debug, Clone)]
+pub struct CONTENT015 {
+    enabled: bool,
+    /// Words allowed in one sentence
+    max_words: usize,
+    /// Average words per sentence allowed in a chapter
+    max_average: f64,
+}
+
+impl Default for CONTENT015 {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_words: 35,
+            max_average: 20.0,
+        }
+    }
+}
+
+impl CONTENT015 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `max-words`: words allowed in one sentence (default 35)
+    /// - `max-average`: average words per sentence allowed in a chapter
+    ///   (default 20)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::enabled();
+        let get = |key: &str| {
+            config
+                .get(key)
+                .or_else(|| config.get(key.replace('-', "_")))
+        };
+        if let Some(max) = get("max-words")
+            .and_then(|v| v.as_integer())
+            .and_then(|n| usize::try_from(n).ok())
+        {
+            rule.max_words = max;
+        }
+        if let Some(max) = get("max-average")
+            .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+        {
+            rule.max_average = max;
+        }
+        rule
+    }
+
+    /// An instance that runs with the default limits
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Rule for CONTENT015 {
+    fn id(&self) -> &'static str {
+        "CONTENT015"
+    }
+
+    fn name(&self) -> &'static str {
+        "sentence-length"
+    }
+
+    fn description(&self) -> &'static str {
+        "Sentences should stay within the configured length"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "max-words",
+                OptionKind::IntegerAtLeast(1),
+                "Words allowed in one sentence",
+            )
+            .defaults_to("35")
+            .option(
+                "max-average",
+                OptionKind::Number,
+                "Average words per sentence allowed in a chapter",
+            )
+            .defaults_to("20")
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let sentences = sentences(document);
+        let mut violations = Vec::new();
+
+        if sentences.len() >= MIN_SENTENCES {
+            let words: usize = sentences.iter().map(|s| s.words.len()).sum();
+            let average = words as f64 / sentences.len() as f64;
+            if average > self.max_average {
+                let (line, column) = sentences[0].start();
+                violations.push(self.create_violation(
+                    format!(
+                        "Average sentence length is {average:.1} words (max {})",
+                        self.max_average
+                    ),
+                    line,
+                    column,
+                    Severity::Info,
+                ));
+            }
+        }
+
+        for sentence in &sentences {
+            if sentence.words.len() > self.max_words {
+                let (line, column) = sentence.start();
+                violations.push(self.create_violation(
+                    format!(
+                        "Sentence has {} words (max {})",
+                        sentence.words.len(),
+                        self.max_words
+                    ),
+                    line,
+                    column,
+                    Severity::Info,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &CONTENT015, content: &str) -> Vec<(usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let long = "word ".repeat(50);
+        assert!(check(&CONTENT015::default(), &format!("{long}.\n")).is_empty());
+    }
+
+    #[test]
+    fn test_long_sentence() {
+        let content = format!(
+            "# Title\n\nShort one. {} end.\n",
+            "and then another clause ".repeat(10)
+        );
+        assert_eq!(
+            check(&CONTENT015::enabled(), &content),
+            vec![(3, "Sentence has 41 words (max 35)".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_average_length() {
+        let sentence = "This sentence has exactly eight words in it. ";
+        let content = format!("{}\n", sentence.repeat(5));
+        assert!(check(&CONTENT015::enabled(), &content).is_empty());
+
+        let config: toml::Value = toml::from_str("max-average = 6\nmax_words = 7").unwrap();
+        let found = check(&CONTENT015::from_config(&config), &content);
+        assert_eq!(found.len(), 6);
+        assert_eq!(found[0].1, "Average sentence length is 8.0 words (max 6)");
+        assert_eq!(found[1].1, "Sentence has 8 words (max 7)");
+    }
+}
@@ -0,0 +1,283 @@
+//! CONTENT016: Passive voice
+//!
+//! Passive sentences hide who does what ("the file is generated" rather
+//! than "mdBook generates the file"), which matters in instructions. This
+//! rule finds a form of "to be" or "to get" followed by a past participle.
+//! It is a heuristic, so it is opt-in, reports at Info severity, and only
+//! reports once passive sentences exceed a share of the chapter.
+
+use super::readability::sentences;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use regex::Regex;
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+/// An auxiliary, an optional adverb, and a candidate participle
+static PASSIVE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:am|is|are|was|were|be|been|being|get|gets|got|gotten|isn't|aren't|wasn't|weren't)\s+(?:[a-z]+ly\s+)?([a-z]+)\b",
+    )
+    .unwrap()
+});
+
+/// Irregular past participles
+const IRREGULAR: &[&str] = &[
+    "begun",
+    "bought",
+    "bound",
+    "broken",
+    "brought",
+    "built",
+    "caught",
+    "chosen",
+    "done",
+    "drawn",
+    "driven",
+    "felt",
+    "found",
+    "forgotten",
+    "given",
+    "held",
+    "hidden",
+    "kept",
+    "known",
+    "laid",
+    "led",
+    "left",
+    "lost",
+    "made",
+    "meant",
+    "paid",
+    "put",
+    "read",
+    "run",
+    "said",
+    "seen",
+    "sent",
+    "set",
+    "shown",
+    "shut",
+    "sold",
+    "spent",
+    "split",
+    "spoken",
+    "taken",
+    "taught",
+    "thought",
+    "thrown",
+    "told",
+    "understood",
+    "won",
+    "worn",
+    "written",
+];
+
+/// Words ending in "ed" that are not participles
+const NOT_PARTICIPLES: &[&str] = &[
+    "bed", "exceed", "feed", "indeed", "need", "proceed", "red", "seed", "shed", "speed",
+    "succeed", "hundred",
+];
+
+/// CONTENT016: Reports passive constructions when they exceed a share of sentences
+///
+/// With `max-percent = 0` every passive construction is reported.
+#[derive(Debug, Clone)]
+pub struct CONTENT016 {
+    enabled: bool,
+    /// Share of sentences, in percent, that may be passive
+    max_percent: f64,
+    /// Participles never treated as passive, lowercased
+    ignore: HashSet<String>,
+}
+
+impl Default for CONTENT016 {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_percent: 10.0,
+            ignore: HashSet::new(),
+        }
+    }
+}
+
+impl CONTENT016 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `max-percent`: share of sentences that may be passive before any
+    ///   are reported (default 10)
+    /// - `ignore`: participles that are never reported, such as `"based"`
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::enabled();
+        let get = |key: &str| {
+            config
+                .get(key)
+                .or_else(|| config.get(key.replace('-', "_")))
+        };
+        if let Some(max) = get("max-percent")
+            .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+        {
+            rule.max_percent = max;
+        }
+        if let Some(ignore) = get("ignore").and_then(|v| v.as_array()) {
+            rule.ignore = ignore
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(str::to_lowercase)
+                .collect();
+        }
+        rule
+    }
+
+    /// An instance that runs with the default settings
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    fn is_participle(&self, word: &str) -> bool {
+        let word = word.to_lowercase();
+        if self.ignore.contains(&word) || NOT_PARTICIPLES.contains(&word.as_str()) {
+            return false;
+        }
+        IRREGULAR.contains(&word.as_str()) || (word.len() > 4 && word.ends_with("ed"))
+    }
+}
+
+impl Rule for CONTENT016 {
+    fn id(&self) -> &'static str {
+        "CONTENT016"
+    }
+
+    fn name(&self) -> &'static str {
+        "passive-voice"
+    }
+
+    fn description(&self) -> &'static str {
+        "Passive voice should stay within the configured share of sentences"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "max-percent",
+                OptionKind::NumberBetween(0.0, 100.0),
+                "Share of sentences that may be passive before any are reported",
+            )
+            .defaults_to("10")
+            .option(
+                "ignore",
+                OptionKind::StringList,
+                "Participles that are never reported",
+            )
+            .defaults_to("[]")
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let sentences = sentences(document);
+        let mut passive = Vec::new();
+        for sentence in &sentences {
+            let found = PASSIVE_REGEX
+                .captures_iter(&sentence.text)
+                .find(|caps| self.is_participle(&caps[1]));
+            if let Some(caps) = found {
+                let whole = caps.get(0).unwrap();
+                passive.push((
+                    sentence.position_at(whole.start()),
+                    whole.as_str().to_string(),
+                ));
+            }
+        }
+
+        if passive.is_empty() {
+            return Ok(Vec::new());
+        }
+        let percent = passive.len() as f64 * 100.0 / sentences.len() as f64;
+        if percent <= self.max_percent {
+            return Ok(Vec::new());
+        }
+
+        Ok(passive
+            .into_iter()
+            .map(|((line, column), phrase)| {
+                let phrase = phrase.split_whitespace().collect::<Vec<_>>().join(" ");
+                self.create_violation(
+                    format!(
+                        "Passive voice: '{phrase}' ({percent:.0}% of sentences are passive, max {}%)",
+                        self.max_percent
+                    ),
+                    line,
+                    column,
+                    Severity::Info,
+                )
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &CONTENT016, content: &str) -> Vec<(usize, usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_passive_share() {
+        let content = "The book is built by mdBook. You run the linter.\nThe output was\nquickly written to disk. It is indeed fast. Rules need config.\n";
+        assert!(check(&CONTENT016::default(), content).is_empty());
+
+        let found = check(&CONTENT016::enabled(), content);
+        assert_eq!(
+            found,
+            vec![
+                (
+                    1,
+                    10,
+                    "Passive voice: 'is built' (40% of sentences are passive, max 10%)".to_string()
+                ),
+                (
+                    2,
+                    12,
+                    "Passive voice: 'was quickly written' (40% of sentences are passive, max 10%)"
+                        .to_string()
+                ),
+            ]
+        );
+
+        let config: toml::Value = toml::from_str("max-percent = 50").unwrap();
+        assert!(check(&CONTENT016::from_config(&config), content).is_empty());
+    }
+
+    #[test]
+    fn test_ignore() {
+        let config: toml::Value = toml::from_str("max-percent = 0\nignore = [\"based\"]").unwrap();
+        let rule = CONTENT016::from_config(&config);
+        assert!(check(&rule, "The layout is based on the theme.\n").is_empty());
+        assert_eq!(check(&rule, "Images are resized on build.\n").len(), 1);
+    }
+}
@@ -0,0 +1,175 @@
+//! CONTENT017: Reading ease
+//!
+//! Computes the Flesch reading ease score of a chapter from its average
+//! sentence length and syllables per word. Scores run from about 0 (very
+//! hard) to 100 (very easy); plain English sits around 60. This rule is
+//! opt-in and reports chapters below a minimum score at Info severity.
+
+use super::readability::{MIN_SENTENCES, sentences, syllables};
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// CONTENT017: Reports chapters whose Flesch reading ease is below a minimum
+///
+/// Chapters with fewer than five sentences are not scored.
+#[derive(Debug, Clone)]
+pub struct CONTENT017 {
+    enabled: bool,
+    /// Lowest acceptable score
+    min_score: f64,
+}
+
+impl Default for CONTENT017 {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_score: 50.0,
+        }
+    }
+}
+
+impl CONTENT017 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `min-score`: lowest acceptable Flesch reading ease (default 50)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::enabled();
+        if let Some(min) = config
+            .get("min-score")
+            .or_else(|| config.get("min_score"))
+            .and_then(|v| v.as_float().or_else(|| v.as_integer().map(|i| i as f64)))
+        {
+            rule.min_score = min;
+        }
+        rule
+    }
+
+    /// An instance that runs with the default minimum
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+}
+
+impl Rule for CONTENT017 {
+    fn id(&self) -> &'static str {
+        "CONTENT017"
+    }
+
+    fn name(&self) -> &'static str {
+        "reading-ease"
+    }
+
+    fn description(&self) -> &'static str {
+        "Chapters should meet the configured Flesch reading ease score"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "min-score",
+                OptionKind::Number,
+                "Lowest acceptable Flesch reading ease",
+            )
+            .defaults_to("50")
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let sentences = sentences(document);
+        if sentences.len() < MIN_SENTENCES {
+            return Ok(Vec::new());
+        }
+
+        let words: Vec<&String> = sentences.iter().flat_map(|s| &s.words).collect();
+        let syllable_count: usize = words.iter().map(|word| syllables(word)).sum();
+        let words_per_sentence = words.len() as f64 / sentences.len() as f64;
+        let syllables_per_word = syllable_count as f64 / words.len() as f64;
+        let score = 206.835 - 1.015 * words_per_sentence - 84.6 * syllables_per_word;
+
+        if score >= self.min_score {
+            return Ok(Vec::new());
+        }
+        let (line, column) = sentences[0].start();
+        Ok(vec![self.create_violation(
+            format!(
+                "Flesch reading ease is {score:.1} (min {}): {words_per_sentence:.1} words per sentence, {syllables_per_word:.2} syllables per word",
+                self.min_score
+            ),
+            line,
+            column,
+            Severity::Info,
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &CONTENT017, content: &str) -> Vec<String> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.message)
+            .collect()
+    }
+
+    const EASY: &str = "The cat sat on the mat. It was a good day. We ran to the park. \
+        The sun was out. Then we went home.\n";
+    const HARD: &str = "Configuration inheritance necessitates comprehensive understanding. \
+        Preprocessors manipulate intermediate representations systematically. \
+        Documentation generation requires considerable infrastructure investment. \
+        Internationalization complicates typographical consistency considerably. \
+        Accessibility considerations influence architectural decisions fundamentally.\n";
+
+    #[test]
+    fn test_scores() {
+        assert!(check(&CONTENT017::default(), HARD).is_empty());
+        assert!(check(&CONTENT017::enabled(), EASY).is_empty());
+
+        let found = check(&CONTENT017::enabled(), HARD);
+        assert_eq!(found.len(), 1);
+        assert!(
+            found[0].starts_with("Flesch reading ease is -"),
+            "{}",
+            found[0]
+        );
+        assert!(found[0].contains("(min 50): 5.4 words per sentence"));
+    }
+
+    #[test]
+    fn test_short_chapters_are_not_scored() {
+        assert!(
+            check(
+                &CONTENT017::enabled(),
+                "Internationalization complicates everything.\n"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_min_score() {
+        let config: toml::Value = toml::from_str("min-score = 120").unwrap();
+        assert_eq!(check(&CONTENT017::from_config(&config), EASY).len(), 1);
+    }
+}
@@ -0,0 +1,433 @@
+//! CONTENT018: Inclusive language
+//!
+//! Flags terms that many readers find exclusionary, such as "whitelist" or
+//! "master/slave", and suggests alternatives. A default list ships with the
+//! rule; projects can extend it, change suggestions or severities per term,
+//! or turn terms off. Code, blockquotes, and text in double quotes are
+//! skipped, since quoted material from elsewhere can't be reworded. The rule
+//! is opt-in, since words like "master" are common in existing books.
+
+use crate::prose::{is_embedded, match_case, prose_lines, term_pattern};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use regex::Regex;
+use std::collections::{BTreeMap, HashSet};
+
+/// Comment that suppresses the rule on its own line and the line after
+const IGNORE_COMMENT: &str = "<!-- inclusive-language-ignore -->";
+
+/// Default terms and suggested replacements
+const DEFAULT_TERMS: &[(&str, &[&str])] = &[
+    ("whitelist", &["allowlist"]),
+    ("whitelists", &["allowlists"]),
+    ("whitelisted", &["allowlisted"]),
+    ("whitelisting", &["allowlisting"]),
+    ("blacklist", &["denylist", "blocklist"]),
+    ("blacklists", &["denylists", "blocklists"]),
+    ("blacklisted", &["denylisted", "blocked"]),
+    ("blacklisting", &["denylisting", "blocking"]),
+    ("master", &["main", "primary", "leader"]),
+    ("slave", &["replica", "secondary", "follower"]),
+    ("slaves", &["replicas", "secondaries", "followers"]),
+    ("sanity check", &["quick check", "confidence check"]),
+    ("sanity checks", &["quick checks", "confidence checks"]),
+    ("sanity-check", &["check", "verify"]),
+    ("dummy", &["placeholder", "sample"]),
+    ("grandfathered", &["legacy", "exempt"]),
+    ("man-hours", &["person-hours", "work hours"]),
+    ("manpower", &["workforce", "staff"]),
+    ("cripple", &["disable", "impair"]),
+    ("crippled", &["disabled", "impaired"]),
+    ("man-in-the-middle", &["on-path", "adversary-in-the-middle"]),
+    ("guys", &["folks", "everyone"]),
+];
+
+/// A term to flag
+#[derive(Debug, Clone)]
+struct Term {
+    /// Case-insensitive matcher for the term
+    pattern: Regex,
+    suggestions: Vec<String>,
+    severity: Severity,
+}
+
+/// How a term is configured before its pattern is built
+#[derive(Debug, Clone)]
+struct TermConfig {
+    suggestions: Vec<String>,
+    severity: Option<Severity>,
+}
+
+/// CONTENT018: Flags non-inclusive terms and suggests alternatives
+#[derive(Debug, Clone, Default)]
+pub struct CONTENT018 {
+    enabled: bool,
+    terms: Vec<Term>,
+}
+
+fn default_terms() -> BTreeMap<String, TermConfig> {
+    DEFAULT_TERMS
+        .iter()
+        .map(|(term, suggestions)| {
+            let config = TermConfig {
+                suggestions: suggestions.iter().map(|s| s.to_string()).collect(),
+                severity: None,
+            };
+            (term.to_string(), config)
+        })
+        .collect()
+}
+
+impl CONTENT018 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `defaults`: whether to include the built-in term list (default
+    ///   true)
+    /// - `severity`: `"info"`, `"warning"` (default), or `"error"` for
+    ///   every term
+    /// - `allow`: terms that are never reported
+    /// - `terms`: table of terms to add or change. Each value is a
+    ///   suggestion, an array of suggestions, a table with `suggest` and
+    ///   `severity`, or `false` to turn a built-in term off.
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut terms = if config.get("defaults").and_then(|v| v.as_bool()) == Some(false) {
+            BTreeMap::new()
+        } else {
+            default_terms()
+        };
+
+        let severity = config
+            .get("severity")
+            .and_then(|v| v.clone().try_into::<Severity>().ok())
+            .unwrap_or(Severity::Warning);
+
+        let allow: HashSet<String> = config
+            .get("allow")
+            .and_then(|v| v.as_array())
+            .map(|terms| {
+                terms
+                    .iter()
+                    .filter_map(|t| t.as_str())
+                    .map(str::to_lowercase)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let Some(table) = config.get("terms").and_then(|v| v.as_table()) {
+            for (term, value) in table {
+                let term = term.to_lowercase();
+                let suggestions = |value: &toml::Value| -> Option<Vec<String>> {
+                    match value {
+                        toml::Value::String(s) => Some(vec![s.clone()]),
+                        toml::Value::Array(items) => Some(
+                            items
+                                .iter()
+                                .filter_map(|i| i.as_str().map(String::from))
+                                .collect(),
+                        ),
+                        _ => None,
+                    }
+                };
+                match value {
+                    toml::Value::Boolean(false) => {
+                        terms.remove(&term);
+                    }
+                    toml::Value::Table(entry) => {
+                        let existing = terms.remove(&term);
+                        let config = TermConfig {
+                            suggestions: entry
+                                .get("suggest")
+                                .and_then(suggestions)
+                                .or_else(|| existing.map(|e| e.suggestions))
+                                .unwrap_or_default(),
+                            severity: entry
+                                .get("severity")
+                                .and_then(|v| v.clone().try_into::<Severity>().ok()),
+                        };
+                        terms.insert(term, config);
+                    }
+                    other => {
+                        if let Some(suggestions) = suggestions(other) {
+                            let severity = terms.get(&term).and_then(|e| e.severity);
+                            terms.insert(
+                                term,
+                                TermConfig {
+                                    suggestions,
+                                    severity,
+                                },
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        Self::from_terms(terms, &allow, severity)
+    }
+
+    /// An instance that runs with the built-in term list
+    pub fn enabled() -> Self {
+        Self::from_terms(default_terms(), &HashSet::new(), Severity::Warning)
+    }
+
+    fn from_terms(
+        terms: BTreeMap<String, TermConfig>,
+        allow: &HashSet<String>,
+        severity: Severity,
+    ) -> Self {
+        // Longer terms first, so "sanity checks" is reported rather than
+        // "sanity check" inside it
+        let mut terms: Vec<(String, TermConfig)> = terms
+            .into_iter()
+            .filter(|(term, _)| !allow.contains(term))
+            .collect();
+        terms.sort_by_key(|(term, _)| std::cmp::Reverse(term.len()));
+
+        let terms = terms
+            .into_iter()
+            .filter_map(|(term, config)| {
+                Some(Term {
+                    pattern: term_pattern(&term)?,
+                    suggestions: config.suggestions,
+                    severity: config.severity.unwrap_or(severity),
+                })
+            })
+            .collect();
+        Self {
+            enabled: true,
+            terms,
+        }
+    }
+}
+
+/// Blank out text inside double quotes, keeping columns intact
+fn mask_quotes(line: &str) -> String {
+    let mut chars: Vec<char> = line.chars().collect();
+    let mut open: Option<(usize, char)> = None;
+    for i in 0..chars.len() {
+        match (open, chars[i]) {
+            (None, '"') => open = Some((i, '"')),
+            (None, '“') => open = Some((i, '”')),
+            (Some((start, close)), c) if c == close => {
+                chars[start..=i].fill(' ');
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    chars.into_iter().collect()
+}
+
+fn suggestion_text(suggestions: &[String]) -> String {
+    let quoted: Vec<String> = suggestions.iter().map(|s| format!("'{s}'")).collect();
+    match quoted.as_slice() {
+        [] => String::new(),
+        [one] => one.clone(),
+        [rest @ .., last] => format!("{} or {last}", rest.join(", ")),
+    }
+}
+
+impl Rule for CONTENT018 {
+    fn id(&self) -> &'static str {
+        "CONTENT018"
+    }
+
+    fn name(&self) -> &'static str {
+        "inclusive-language"
+    }
+
+    fn description(&self) -> &'static str {
+        "Prose should use inclusive language"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "defaults",
+                OptionKind::Boolean,
+                "Include the built-in term list",
+            )
+            .defaults_to("true")
+            .option(
+                "severity",
+                OptionKind::Choice(&["info", "warning", "error"]),
+                "Severity for every term",
+            )
+            .defaults_to(r#""warning""#)
+            .option(
+                "allow",
+                OptionKind::StringList,
+                "Terms that are never reported",
+            )
+            .defaults_to("[]")
+            .option("terms", OptionKind::Any, "Table of terms to add or change")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        if !self.enabled || self.terms.is_empty() {
+            return Ok(violations);
+        }
+
+        let ignored = |line_number: usize| {
+            let has_comment = |n: usize| {
+                n >= 1
+                    && document
+                        .lines
+                        .get(n - 1)
+                        .is_some_and(|line| line.contains(IGNORE_COMMENT))
+            };
+            has_comment(line_number) || has_comment(line_number - 1)
+        };
+
+        for (line_number, masked) in prose_lines(document) {
+            if masked.trim_start().starts_with('>') || ignored(line_number) {
+                continue;
+            }
+            let masked = mask_quotes(&masked);
+
+            let mut taken: Vec<(usize, usize)> = Vec::new();
+            let mut line_violations = Vec::new();
+            for term in &self.terms {
+                for m in term.pattern.find_iter(&masked) {
+                    if is_embedded(&masked, m.start(), m.end())
+                        || taken.iter().any(|&(s, e)| m.start() < e && s < m.end())
+                    {
+                        continue;
+                    }
+                    taken.push((m.start(), m.end()));
+
+                    let matched = m.as_str();
+                    let suggestions: Vec<String> = term
+                        .suggestions
+                        .iter()
+                        .map(|s| match_case(matched, s))
+                        .collect();
+                    let message = if suggestions.is_empty() {
+                        format!("'{matched}' may be exclusionary")
+                    } else {
+                        format!(
+                            "'{matched}' may be exclusionary; consider {}",
+                            suggestion_text(&suggestions)
+                        )
+                    };
+                    let column = masked[..m.start()].chars().count() + 1;
+                    line_violations.push(self.create_violation(
+                        message,
+                        line_number,
+                        column,
+                        term.severity,
+                    ));
+                }
+            }
+
+            line_violations.sort_by_key(|v| v.column);
+            violations.extend(line_violations);
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &CONTENT018, content: &str) -> Vec<(usize, usize, String, Severity)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.message, v.severity))
+            .collect()
+    }
+
+    fn rule(toml: &str) -> CONTENT018 {
+        CONTENT018::from_config(&toml::from_str(toml).unwrap())
+    }
+
+    #[test]
+    fn test_default_terms() {
+        let content = "Add the host to the Whitelist and run sanity checks.\n\n```\nmaster = true\n```\n\nUse `master` or github.com/org/master.\n";
+        let found = check(&CONTENT018::enabled(), content);
+        assert_eq!(
+            found,
+            vec![
+                (
+                    1,
+                    21,
+                    "'Whitelist' may be exclusionary; consider 'Allowlist'".to_string(),
+                    Severity::Warning
+                ),
+                (
+                    1,
+                    39,
+                    "'sanity checks' may be exclusionary; consider 'quick checks' or 'confidence checks'"
+                        .to_string(),
+                    Severity::Warning
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_quoted_material_is_skipped() {
+        let content = "> The master copy is kept offsite.\n\nThe paper calls it a \"master node\" and we do not.\n\nA master node.\n";
+        let found = check(&CONTENT018::enabled(), content);
+        assert_eq!(found.len(), 1);
+        assert_eq!((found[0].0, found[0].1), (5, 3));
+    }
+
+    #[test]
+    fn test_configured_terms_and_severity() {
+        let rule = rule(
+            r#"
+            severity = "info"
+            allow = ["dummy"]
+
+            [terms]
+            master = false
+            guys = { suggest = ["everyone"], severity = "error" }
+            "cakewalk" = "easy task"
+            "#,
+        );
+        let found = check(&rule, "Hey guys, the master dummy build is a cakewalk.\n");
+        let found: Vec<(&str, Severity)> = found
+            .iter()
+            .map(|(_, _, message, severity)| (message.as_str(), *severity))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "'guys' may be exclusionary; consider 'everyone'",
+                    Severity::Error
+                ),
+                (
+                    "'cakewalk' may be exclusionary; consider 'easy task'",
+                    Severity::Info
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_defaults_off_and_ignore_comment() {
+        let rule = rule("defaults = false\n[terms]\ncakewalk = \"easy task\"");
+        assert!(check(&rule, "The whitelist.\n").is_empty());
+
+        let content = "<!-- inclusive-language-ignore -->\nThe whitelist flag is named by upstream.\n\nThe whitelist.\n";
+        let found = check(&CONTENT018::enabled(), content);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, 4);
+    }
+}
@@ -0,0 +1,310 @@
+//! CONTENT019: Punctuation style
+//!
+//! Chapters written in different editors end up mixing straight and curly
+//! quotes, `--` and `—`, and stray non-breaking spaces that look like
+//! ordinary spaces in the source. This rule holds prose to one convention,
+//! ASCII or typographic, and fixes what doesn't match. It is opt-in; code,
+//! URLs, and markup are skipped.
+
+use crate::prose::prose_lines;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+
+/// Non-breaking spaces: no-break space and narrow no-break space
+const NON_BREAKING_SPACES: &[char] = &['\u{00A0}', '\u{202F}'];
+
+/// Punctuation convention enforced in prose
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    /// Straight quotes, `--`, and plain spaces
+    Ascii,
+    /// Curly quotes and `—`
+    Typographic,
+}
+
+/// CONTENT019: Reports punctuation that doesn't follow the configured style
+///
+/// In the `ascii` style, curly quotes, em dashes, and non-breaking spaces are
+/// replaced with their ASCII forms. In the `typographic` style, straight
+/// quotes become curly quotes, chosen by what comes before them, and a `--`
+/// between words or spaces becomes an em dash; `--flag` is left alone.
+/// Quote direction is a guess, so typographic quote fixes are unsafe.
+#[derive(Debug, Clone)]
+pub struct CONTENT019 {
+    enabled: bool,
+    style: Style,
+}
+
+impl Default for CONTENT019 {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            style: Style::Ascii,
+        }
+    }
+}
+
+impl CONTENT019 {
+    /// Create an enabled instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `style`: `"ascii"` (default) or `"typographic"`
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::enabled();
+        if config.get("style").and_then(|v| v.as_str()) == Some("typographic") {
+            rule.style = Style::Typographic;
+        }
+        rule
+    }
+
+    /// An instance that runs with the default settings
+    pub fn enabled() -> Self {
+        Self {
+            enabled: true,
+            ..Self::default()
+        }
+    }
+
+    /// Replacements on a masked prose line: 0-based char index, length in
+    /// chars, the text found, its replacement, and whether the fix is safe
+    fn scan(&self, line: &str) -> Vec<(usize, usize, String, &'static str, bool)> {
+        let chars: Vec<char> = line.chars().collect();
+        let mut found = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let c = chars[i];
+            let before = i.checked_sub(1).map(|j| chars[j]);
+            let replacement = match (self.style, c) {
+                (Style::Ascii, '\u{201C}' | '\u{201D}') => Some(("\"", 1, true)),
+                (Style::Ascii, '\u{2018}' | '\u{2019}') => Some(("'", 1, true)),
+                (Style::Ascii, '\u{2014}') => Some(("--", 1, true)),
+                (Style::Ascii, c) if NON_BREAKING_SPACES.contains(&c) => Some((" ", 1, true)),
+                (Style::Typographic, '"') => Some((
+                    if Self::opens(before) {
+                        "\u{201C}"
+                    } else {
+                        "\u{201D}"
+                    },
+                    1,
+                    false,
+                )),
+                (Style::Typographic, '\'') => Some((
+                    if Self::opens(before) {
+                        "\u{2018}"
+                    } else {
+                        "\u{2019}"
+                    },
+                    1,
+                    false,
+                )),
+                (Style::Typographic, '-') if Self::is_double_hyphen(&chars, i) => {
+                    Some(("\u{2014}", 2, true))
+                }
+                _ => None,
+            };
+
+            match replacement {
+                Some((replacement, len, safe)) => {
+                    let text: String = chars[i..i + len].iter().collect();
+                    found.push((i, len, text, replacement, safe));
+                    i += len;
+                }
+                None => i += 1,
+            }
+        }
+        found
+    }
+
+    /// Whether a quote after `before` opens a quotation
+    fn opens(before: Option<char>) -> bool {
+        before.is_none_or(|c| {
+            c.is_whitespace() || matches!(c, '(' | '[' | '{' | '\u{2014}' | '\u{2013}' | '-')
+        })
+    }
+
+    /// Whether a run of exactly two hyphens at `i` is used as a dash: between
+    /// two words, or with spaces on both sides
+    fn is_double_hyphen(chars: &[char], i: usize) -> bool {
+        if chars.get(i + 1) != Some(&'-') || chars.get(i + 2) == Some(&'-') {
+            return false;
+        }
+        let before = i.checked_sub(1).map(|j| chars[j]);
+        let after = chars.get(i + 2).copied();
+        if before == Some('-') {
+            return false;
+        }
+        match (before, after) {
+            (Some(b), Some(a)) if b.is_alphanumeric() && a.is_alphanumeric() => true,
+            (Some(b), a) if b.is_whitespace() => a.is_none_or(char::is_whitespace),
+            _ => false,
+        }
+    }
+
+    fn message(&self, text: &str, replacement: &str) -> String {
+        let name = |s: &str| match s {
+            "\u{00A0}" | "\u{202F}" => "non-breaking space",
+            " " => "regular space",
+            "\"" | "'" => "straight quote",
+            "\u{201C}" | "\u{201D}" | "\u{2018}" | "\u{2019}" => "curly quote",
+            "\u{2014}" => "em dash",
+            _ => "double hyphen",
+        };
+        match text {
+            "\u{00A0}" | "\u{202F}" => {
+                format!("Use a regular space instead of a {}", name(text))
+            }
+            _ => format!(
+                "Use the {} '{replacement}' instead of the {} '{text}'",
+                name(replacement),
+                name(text)
+            ),
+        }
+    }
+}
+
+impl Rule for CONTENT019 {
+    fn id(&self) -> &'static str {
+        "CONTENT019"
+    }
+
+    fn name(&self) -> &'static str {
+        "punctuation-style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Quotes, dashes, and spaces in prose should follow one punctuation style"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "style",
+                OptionKind::Choice(&["ascii", "typographic"]),
+                "Punctuation convention to enforce",
+            )
+            .defaults_to("ascii")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        if !self.enabled {
+            return Ok(Vec::new());
+        }
+
+        let mut violations = Vec::new();
+        for (line, masked) in prose_lines(document) {
+            for (index, len, text, replacement, safe) in self.scan(&masked) {
+                let column = index + 1;
+                let mut violation = self.create_violation(
+                    self.message(&text, replacement),
+                    line,
+                    column,
+                    Severity::Warning,
+                );
+                violation.fix = Some(Fix {
+                    description: format!("Replace '{text}' with '{replacement}'"),
+                    replacement: Some(replacement.to_string()),
+                    start: Position { line, column },
+                    end: Position {
+                        line,
+                        column: column + len,
+                    },
+                    safety: if safe {
+                        FixSafety::Safe
+                    } else {
+                        FixSafety::Unsafe
+                    },
+                });
+                violations.push(violation);
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::FixEngine;
+    use std::path::PathBuf;
+
+    fn check(rule: &CONTENT019, content: &str) -> Vec<(usize, usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.fix.unwrap().replacement.unwrap()))
+            .collect()
+    }
+
+    fn typographic() -> CONTENT019 {
+        CONTENT019::from_config(&toml::from_str("style = \"typographic\"").unwrap())
+    }
+
+    #[test]
+    fn test_opt_in() {
+        assert!(
+            check(
+                &CONTENT019::default(),
+                "It\u{2019}s \u{201C}done\u{201D}.\n"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_ascii_style() {
+        let content = "It\u{2019}s \u{201C}done\u{201D}\u{2014}mostly.\n\n10\u{00A0}km and `\u{201C}code\u{201D}`.\n";
+        assert_eq!(
+            check(&CONTENT019::enabled(), content),
+            vec![
+                (1, 3, "'".to_string()),
+                (1, 6, "\"".to_string()),
+                (1, 11, "\"".to_string()),
+                (1, 12, "--".to_string()),
+                (3, 3, " ".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_typographic_style() {
+        let content =
+            "He said \"don't\" -- then left--quietly.\n\nRun `lint \"x\"` with --fix.\n\n---\n";
+        assert_eq!(
+            check(&typographic(), content),
+            vec![
+                (1, 9, "\u{201C}".to_string()),
+                (1, 13, "\u{2019}".to_string()),
+                (1, 15, "\u{201D}".to_string()),
+                (1, 17, "\u{2014}".to_string()),
+                (1, 29, "\u{2014}".to_string()),
+            ]
+        );
+        assert!(
+            check(
+                &typographic(),
+                "\u{201C}Fine\u{201D} \u{2014} and 10\u{00A0}km.\n"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_fixes_apply() {
+        let content = "Say \u{201C}hi\u{201D}\u{2014}now.\n";
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = CONTENT019::enabled().check(&doc).unwrap();
+        let fixed = FixEngine::new().apply(content, &violations).content;
+        assert_eq!(fixed, "Say \"hi\"--now.\n");
+    }
+}
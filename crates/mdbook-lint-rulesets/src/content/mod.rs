@@ -1,7 +1,12 @@
 //! Content quality linting rules (CONTENT001+)
 //!
 //! This module contains rules for detecting content quality issues
-//! such as TODO comments, placeholder text, and incomplete sections.
+//! such as TODO comments, placeholder text, and incomplete sections,
+//! plus CONTENT012, which enforces a vocabulary configured under
+//! `[vocabulary]`, and CONTENT013, which checks chapters against the book's
+//! glossary. CONTENT014 (duplicate text across chapters), the readability
+//! rules CONTENT015-CONTENT017, CONTENT018 (inclusive language), and
+//! CONTENT019 (punctuation style) are opt-in.
 
 mod content001;
 mod content002;
@@ -13,6 +18,15 @@ mod content007;
 mod content009;
 mod content010;
 mod content011;
+mod content012;
+mod content013;
+mod content014;
+mod content015;
+mod content016;
+mod content017;
+mod content018;
+mod content019;
+mod readability;
 
 use crate::{RuleProvider, RuleRegistry};
 use mdbook_lint_core::Config;
@@ -33,6 +47,31 @@ impl RuleProvider for ContentRuleProvider {
         "0.12.0"
     }
 
+    fn config_schema(&self) -> Option<serde_json::Value> {
+        use mdbook_lint_core::rule::Rule;
+
+        // `[vocabulary]` takes the same options as `[CONTENT012]`
+        let options: serde_json::Map<String, serde_json::Value> = content012::CONTENT012::default()
+            .metadata()
+            .options
+            .iter()
+            .map(|option| {
+                let mut schema = option.kind.json_schema();
+                schema["description"] = option.description.into();
+                (option.name.to_string(), schema)
+            })
+            .collect();
+        Some(serde_json::json!({
+            "properties": {
+                "vocabulary": {
+                    "type": "object",
+                    "description": "Words and names to enforce (CONTENT012)",
+                    "properties": options,
+                }
+            }
+        }))
+    }
+
     fn register_rules(&self, registry: &mut RuleRegistry) {
         registry.register(Box::new(content001::CONTENT001::default()));
         registry.register(Box::new(content002::CONTENT002::default()));
@@ -44,6 +83,14 @@ impl RuleProvider for ContentRuleProvider {
         registry.register(Box::new(content009::CONTENT009::default()));
         registry.register(Box::new(content010::CONTENT010));
         registry.register(Box::new(content011::CONTENT011));
+        registry.register(Box::new(content012::CONTENT012::default()));
+        registry.register_collection_rule(Box::new(content013::CONTENT013::default()));
+        registry.register_collection_rule(Box::new(content014::CONTENT014::default()));
+        registry.register(Box::new(content015::CONTENT015::default()));
+        registry.register(Box::new(content016::CONTENT016::default()));
+        registry.register(Box::new(content017::CONTENT017::default()));
+        registry.register(Box::new(content018::CONTENT018::default()));
+        registry.register(Box::new(content019::CONTENT019::default()));
     }
 
     fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
@@ -95,6 +142,65 @@ impl RuleProvider for ContentRuleProvider {
 
         registry.register(Box::new(content010::CONTENT010));
         registry.register(Box::new(content011::CONTENT011));
+
+        // CONTENT012 reads the top-level `[vocabulary]` table
+        let content012 = match cfg("vocabulary").or_else(|| cfg("CONTENT012")) {
+            Some(c) => content012::CONTENT012::from_config(c),
+            None => content012::CONTENT012::default(),
+        };
+        registry.register(Box::new(content012));
+
+        let content013 = match cfg("CONTENT013") {
+            Some(c) => content013::CONTENT013::from_config(c),
+            None => content013::CONTENT013::default(),
+        };
+        registry.register_collection_rule(Box::new(content013));
+
+        // Opt-in rules run once they have a table or are in `enabled-rules`
+        let opted_in =
+            |id: &str| config.is_some_and(|c| c.enabled_rules.iter().any(|enabled| enabled == id));
+
+        let content014 = match cfg("CONTENT014") {
+            Some(c) => content014::CONTENT014::from_config(c),
+            None if opted_in("CONTENT014") => content014::CONTENT014::enabled(),
+            None => content014::CONTENT014::default(),
+        };
+        registry.register_collection_rule(Box::new(content014));
+
+        let content015 = match cfg("CONTENT015") {
+            Some(c) => content015::CONTENT015::from_config(c),
+            None if opted_in("CONTENT015") => content015::CONTENT015::enabled(),
+            None => content015::CONTENT015::default(),
+        };
+        registry.register(Box::new(content015));
+
+        let content016 = match cfg("CONTENT016") {
+            Some(c) => content016::CONTENT016::from_config(c),
+            None if opted_in("CONTENT016") => content016::CONTENT016::enabled(),
+            None => content016::CONTENT016::default(),
+        };
+        registry.register(Box::new(content016));
+
+        let content017 = match cfg("CONTENT017") {
+            Some(c) => content017::CONTENT017::from_config(c),
+            None if opted_in("CONTENT017") => content017::CONTENT017::enabled(),
+            None => content017::CONTENT017::default(),
+        };
+        registry.register(Box::new(content017));
+
+        let content018 = match cfg("CONTENT018") {
+            Some(c) => content018::CONTENT018::from_config(c),
+            None if opted_in("CONTENT018") => content018::CONTENT018::enabled(),
+            None => content018::CONTENT018::default(),
+        };
+        registry.register(Box::new(content018));
+
+        let content019 = match cfg("CONTENT019") {
+            Some(c) => content019::CONTENT019::from_config(c),
+            None if opted_in("CONTENT019") => content019::CONTENT019::enabled(),
+            None => content019::CONTENT019::default(),
+        };
+        registry.register(Box::new(content019));
     }
 
     fn rule_ids(&self) -> Vec<&'static str> {
@@ -109,6 +215,14 @@ impl RuleProvider for ContentRuleProvider {
             "CONTENT009",
             "CONTENT010",
             "CONTENT011",
+            "CONTENT012",
+            "CONTENT013",
+            "CONTENT014",
+            "CONTENT015",
+            "CONTENT016",
+            "CONTENT017",
+            "CONTENT018",
+            "CONTENT019",
         ]
     }
 }
@@ -157,4 +271,93 @@ mod provider_tests {
             "max_depth config must thread through the provider and suppress the violation"
         );
     }
+
+    /// The top-level `[vocabulary]` table configures CONTENT012.
+    #[test]
+    fn test_vocabulary_table_threads_through_provider() {
+        let config: Config = toml::from_str(
+            r#"
+            enabled-rules = ["CONTENT012"]
+
+            [vocabulary]
+            banned = ["simply"]
+
+            [vocabulary.replace]
+            utilize = "use"
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(ContentRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+
+        let doc = Document::new(
+            "# Setup\n\nSimply utilize the CLI.\n".to_string(),
+            PathBuf::from("setup.md"),
+        )
+        .unwrap();
+        let violations = engine.lint_document_with_config(&doc, &config).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().all(|v| v.rule_id == "CONTENT012"));
+        assert!(violations[1].fix.is_some());
+    }
+
+    /// Opt-in rules run when listed in `enabled-rules`, even without a table.
+    #[test]
+    fn test_opt_in_rules_enabled_by_rule_list() {
+        let long = format!("{} end.\n", "word ".repeat(40));
+        let doc = Document::new(long, PathBuf::from("chapter.md")).unwrap();
+
+        let count = |toml: &str| {
+            let config: Config = toml::from_str(toml).unwrap();
+            let mut registry = PluginRegistry::new();
+            registry
+                .register_provider(Box::new(ContentRuleProvider))
+                .unwrap();
+            let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+            engine
+                .lint_document_with_config(&doc, &config)
+                .unwrap()
+                .into_iter()
+                .filter(|v| v.rule_id == "CONTENT015")
+                .count()
+        };
+
+        assert_eq!(count(""), 0, "CONTENT015 is off until opted in");
+        assert_eq!(count("enabled-rules = [\"CONTENT015\"]"), 1);
+        assert_eq!(count("[CONTENT015]\nmax-words = 50"), 0);
+    }
+
+    /// CONTENT018 stays quiet on common words until opted in
+    #[test]
+    fn test_inclusive_language_is_opt_in() {
+        let doc = Document::new(
+            "# Setup\n\nPush to the master branch.\n".to_string(),
+            PathBuf::from("chapter.md"),
+        )
+        .unwrap();
+
+        let count = |toml: &str| {
+            let config: Config = toml::from_str(toml).unwrap();
+            let mut registry = PluginRegistry::new();
+            registry
+                .register_provider(Box::new(ContentRuleProvider))
+                .unwrap();
+            let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+            engine
+                .lint_document_with_config(&doc, &config)
+                .unwrap()
+                .into_iter()
+                .filter(|v| v.rule_id == "CONTENT018")
+                .count()
+        };
+
+        assert_eq!(count(""), 0);
+        assert_eq!(count("enabled-rules = [\"CONTENT018\"]"), 1);
+        assert_eq!(count("[CONTENT018]\nseverity = \"info\""), 1);
+    }
 }
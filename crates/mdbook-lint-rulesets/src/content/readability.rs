@@ -0,0 +1,255 @@
+//! Sentences and syllables for the readability rules (CONTENT015-CONTENT017)
+//!
+//! Sentences are split from the masked prose of [`prose_lines`], so code,
+//! URLs, and markup never count as words. Headings, list items, and table
+//! rows end the sentence before them, since they rarely end in punctuation.
+
+use crate::prose::prose_lines;
+use mdbook_lint_core::Document;
+
+/// Documents with fewer sentences are too short for a meaningful average
+pub(super) const MIN_SENTENCES: usize = 5;
+
+/// Abbreviations whose trailing period doesn't end a sentence
+const ABBREVIATIONS: &[&str] = &[
+    "e.g", "i.e", "etc", "vs", "cf", "approx", "fig", "no", "mr", "mrs", "ms", "dr", "st",
+];
+
+/// A sentence of prose with the source position of each character
+#[derive(Debug, Clone)]
+pub(super) struct Sentence {
+    /// Sentence text, with line breaks replaced by spaces
+    pub text: String,
+    /// 1-based line and column of each character of `text`
+    positions: Vec<(usize, usize)>,
+    /// Words, lowercased
+    pub words: Vec<String>,
+}
+
+impl Sentence {
+    /// Line and column of the first character
+    pub fn start(&self) -> (usize, usize) {
+        self.positions[0]
+    }
+
+    /// Line and column of the character at byte offset `offset` of `text`
+    pub fn position_at(&self, offset: usize) -> (usize, usize) {
+        let index = self.text[..offset].chars().count();
+        self.positions[index.min(self.positions.len() - 1)]
+    }
+
+    fn push(&mut self, ch: char, line: usize, column: usize) {
+        self.text.push(ch);
+        self.positions.push((line, column));
+    }
+}
+
+/// Split a document's prose into sentences
+pub(super) fn sentences(document: &Document) -> Vec<Sentence> {
+    let mut sentences = Vec::new();
+    let mut current: Option<Sentence> = None;
+    let mut previous_line = 0;
+
+    for (line_number, masked) in prose_lines(document) {
+        let trimmed = masked.trim();
+        let starts_block = line_number != previous_line + 1
+            || trimmed.is_empty()
+            || trimmed.starts_with('#')
+            || trimmed.starts_with('|')
+            || trimmed.chars().all(|c| c == '=' || c == '-' || c == ' ');
+        previous_line = line_number;
+        if starts_block {
+            finish(&mut current, &mut sentences);
+        }
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with('|') {
+            continue;
+        }
+
+        let (skip, is_item) = block_prefix(&masked);
+        if is_item {
+            finish(&mut current, &mut sentences);
+        }
+
+        let chars: Vec<char> = masked.chars().collect();
+        for (index, &ch) in chars.iter().enumerate().skip(skip) {
+            let column = index + 1;
+            match current.as_mut() {
+                Some(sentence) => sentence.push(ch, line_number, column),
+                None if ch.is_alphanumeric() || ch == '"' || ch == '(' => {
+                    let mut sentence = Sentence {
+                        text: String::new(),
+                        positions: Vec::new(),
+                        words: Vec::new(),
+                    };
+                    sentence.push(ch, line_number, column);
+                    current = Some(sentence);
+                }
+                None => continue,
+            }
+
+            let next = chars.get(index + 1).copied();
+            if matches!(ch, '.' | '!' | '?')
+                && next
+                    .is_none_or(|c| c.is_whitespace() || matches!(c, '"' | '\'' | ')' | '*' | '_'))
+                && !(ch == '.' && current.as_ref().is_some_and(ends_with_abbreviation))
+            {
+                finish(&mut current, &mut sentences);
+            }
+        }
+        if let Some(sentence) = current.as_mut() {
+            sentence.push(' ', line_number, chars.len() + 1);
+        }
+    }
+    finish(&mut current, &mut sentences);
+
+    sentences
+}
+
+/// End the sentence in progress, keeping it if it has any words
+fn finish(current: &mut Option<Sentence>, sentences: &mut Vec<Sentence>) {
+    if let Some(mut sentence) = current.take() {
+        let trimmed = sentence.text.trim_end().len();
+        sentence.text.truncate(trimmed);
+        sentence.positions.truncate(sentence.text.chars().count());
+        sentence.words = words(&sentence.text);
+        if !sentence.words.is_empty() {
+            sentences.push(sentence);
+        }
+    }
+}
+
+/// Characters of blockquote and list markers at the start of a line, and
+/// whether the line starts a list item
+fn block_prefix(line: &str) -> (usize, bool) {
+    let mut rest = line.trim_start();
+    let mut skipped = line.len() - rest.len();
+    while let Some(stripped) = rest.strip_prefix('>') {
+        let stripped_trimmed = stripped.trim_start();
+        skipped += rest.len() - stripped_trimmed.len();
+        rest = stripped_trimmed;
+    }
+
+    let digits = rest.chars().take_while(char::is_ascii_digit).count();
+    let marker = if rest.starts_with(['-', '*', '+']) {
+        1
+    } else if digits > 0 && rest[digits..].starts_with(['.', ')']) {
+        digits + 1
+    } else {
+        0
+    };
+    let is_item = marker > 0 && rest[marker..].starts_with([' ', '\t']);
+    if is_item {
+        skipped += marker;
+    }
+    // All skipped characters are ASCII, so bytes and characters agree
+    (skipped, is_item)
+}
+
+fn ends_with_abbreviation(sentence: &Sentence) -> bool {
+    let last = sentence
+        .text
+        .rsplit(|c: char| c.is_whitespace() || c == '(')
+        .next()
+        .unwrap_or("")
+        .trim_end_matches('.')
+        .to_lowercase();
+    ABBREVIATIONS.contains(&last.as_str())
+        || (last.len() == 1 && last.chars().all(char::is_alphabetic))
+}
+
+/// Lowercase words of a sentence
+pub(super) fn words(text: &str) -> Vec<String> {
+    text.split(|c: char| !(c.is_alphanumeric() || c == '\'' || c == '-'))
+        .map(|word| word.trim_matches(|c| c == '\'' || c == '-'))
+        .filter(|word| word.chars().any(char::is_alphanumeric))
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Estimated syllables in a word: groups of vowels, less a silent final `e`
+pub(super) fn syllables(word: &str) -> usize {
+    let letters: Vec<char> = word
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    if letters.len() <= 3 {
+        return 1;
+    }
+
+    let is_vowel = |c: char| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y');
+    let mut count = 0;
+    let mut previous_vowel = false;
+    for &c in &letters {
+        let vowel = is_vowel(c);
+        if vowel && !previous_vowel {
+            count += 1;
+        }
+        previous_vowel = vowel;
+    }
+
+    let n = letters.len();
+    // A final consonant + "le" is its own syllable, as in "table"
+    let syllabic_le = letters[n - 2] == 'l' && !is_vowel(letters[n - 3]);
+    if letters[n - 1] == 'e' && !syllabic_le {
+        count -= usize::from(count > 1);
+    } else if letters.ends_with(&['e', 's']) || letters.ends_with(&['e', 'd']) {
+        // "makes", "named"; but not "passes", "needed"
+        let before = letters[n - 3];
+        if !matches!(before, 's' | 'x' | 'z' | 'c' | 'g' | 't' | 'd') && !is_vowel(before) {
+            count -= usize::from(count > 1);
+        }
+    }
+    count.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn split(content: &str) -> Vec<(String, (usize, usize))> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        sentences(&doc)
+            .into_iter()
+            .map(|s| (s.text.clone(), s.start()))
+            .collect()
+    }
+
+    #[test]
+    fn test_sentences() {
+        let found = split(
+            "# Title\n\nFirst sentence, e.g. with an abbreviation. Second\nspans lines!\n\n- A list item\n- Another `code.` item\n\n> Quoted text. Version 1.2 works.\n\n```\nNot. Prose.\n```\n",
+        );
+        let texts: Vec<&str> = found.iter().map(|(t, _)| t.as_str()).collect();
+        assert_eq!(
+            texts,
+            vec![
+                "First sentence, e.g. with an abbreviation.",
+                "Second spans lines!",
+                "A list item",
+                "Another         item",
+                "Quoted text.",
+                "Version 1.2 works.",
+            ]
+        );
+        assert_eq!(found[1].1, (3, 44));
+        assert_eq!(found[2].1, (6, 3));
+        assert_eq!(found[4].1, (9, 3));
+    }
+
+    #[test]
+    fn test_syllables() {
+        for (word, expected) in [
+            ("the", 1),
+            ("table", 2),
+            ("make", 1),
+            ("named", 1),
+            ("needed", 2),
+            ("readability", 5),
+            ("documentation", 5),
+        ] {
+            assert_eq!(syllables(word), expected, "{word}");
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! FRONTMATTER001: Frontmatter must be valid
+//!
+//! Flags YAML or TOML frontmatter that fails to parse, or that parses to
+//! something other than a mapping of keys to values. Static site generators
+//! and mdBook preprocessors that read frontmatter usually fail hard on such
+//! blocks.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// FRONTMATTER001: Validates frontmatter syntax
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FRONTMATTER001;
+
+impl Rule for FRONTMATTER001 {
+    fn id(&self) -> &'static str {
+        "FRONTMATTER001"
+    }
+
+    fn name(&self) -> &'static str {
+        "frontmatter-valid"
+    }
+
+    fn description(&self) -> &'static str {
+        "Frontmatter must be valid YAML or TOML containing key/value pairs"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some(front_matter) = document.front_matter() else {
+            return Ok(Vec::new());
+        };
+
+        let message = match front_matter.parse() {
+            Ok(serde_json::Value::Object(_)) => return Ok(Vec::new()),
+            Ok(_) => format!(
+                "{} frontmatter must be a mapping of keys to values",
+                front_matter.format
+            ),
+            Err(e) => format!("Invalid {} frontmatter: {e}", front_matter.format),
+        };
+
+        Ok(vec![self.create_violation(
+            message,
+            front_matter.start_line,
+            1,
+            Severity::Error,
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        FRONTMATTER001.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_valid_yaml_and_toml() {
+        assert!(check("---\ntitle: Hello\n---\n\n# Hello\n").is_empty());
+        assert!(check("+++\ntitle = \"Hello\"\n+++\n\n# Hello\n").is_empty());
+        assert!(check("---\n---\n\n# Empty\n").is_empty());
+    }
+
+    #[test]
+    fn test_no_frontmatter() {
+        assert!(check("# Hello\n\n---\n\nBody\n").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_yaml() {
+        let violations = check("---\ntitle: [unclosed\n---\n\n# Hello\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert!(violations[0].message.contains("Invalid YAML frontmatter"));
+    }
+
+    #[test]
+    fn test_invalid_toml() {
+        let violations = check("+++\ntitle = \n+++\n");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Invalid TOML frontmatter"));
+    }
+
+    #[test]
+    fn test_non_mapping_yaml() {
+        let violations = check("---\n- one\n- two\n---\n");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("must be a mapping"));
+    }
+}
@@ -0,0 +1,179 @@
+//! FRONTMATTER002: Required frontmatter keys
+//!
+//! Ensures that every document with frontmatter defines the configured keys.
+//! With `require-frontmatter = true`, documents without any frontmatter are
+//! flagged as well. Does nothing until `required-keys` is configured.
+
+use super::parsed_front_matter;
+use crate::util::string_list;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// FRONTMATTER002: Checks that required frontmatter keys are present
+#[derive(Debug, Clone, Default)]
+pub struct FRONTMATTER002 {
+    /// Keys every frontmatter block must define
+    required_keys: Vec<String>,
+    /// Whether documents without frontmatter are violations
+    require_frontmatter: bool,
+}
+
+impl FRONTMATTER002 {
+    /// Create with a list of required keys
+    #[allow(dead_code)]
+    pub fn with_required_keys(keys: &[&str]) -> Self {
+        Self {
+            required_keys: keys.iter().map(|k| k.to_string()).collect(),
+            require_frontmatter: false,
+        }
+    }
+
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `required-keys`: keys that must be present (default: none)
+    /// - `require-frontmatter`: flag documents with no frontmatter (default false)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+
+        if let Some(keys) = string_list(config, &["required-keys", "required_keys"]) {
+            rule.required_keys = keys;
+        }
+
+        if let Some(require) = config
+            .get("require-frontmatter")
+            .or_else(|| config.get("require_frontmatter"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.require_frontmatter = require;
+        }
+
+        rule
+    }
+}
+
+impl Rule for FRONTMATTER002 {
+    fn id(&self) -> &'static str {
+        "FRONTMATTER002"
+    }
+
+    fn name(&self) -> &'static str {
+        "frontmatter-required-keys"
+    }
+
+    fn description(&self) -> &'static str {
+        "Frontmatter must define all required keys"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "required-keys",
+                OptionKind::StringList,
+                "Keys that must be present",
+            )
+            .defaults_to("[]")
+            .option(
+                "require-frontmatter",
+                OptionKind::Boolean,
+                "Flag documents with no frontmatter",
+            )
+            .defaults_to("false")
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if document.front_matter().is_none() {
+            if self.require_frontmatter {
+                return Ok(vec![self.create_violation(
+                    "Document has no frontmatter".to_string(),
+                    1,
+                    1,
+                    Severity::Warning,
+                )]);
+            }
+            return Ok(Vec::new());
+        }
+
+        // Unparseable frontmatter is reported by FRONTMATTER001
+        let Some((front_matter, map)) = parsed_front_matter(document) else {
+            return Ok(Vec::new());
+        };
+
+        let violations = self
+            .required_keys
+            .iter()
+            .filter(|key| !map.contains_key(key.as_str()))
+            .map(|key| {
+                self.create_violation(
+                    format!("Frontmatter is missing required key '{key}'"),
+                    front_matter.start_line,
+                    1,
+                    Severity::Warning,
+                )
+            })
+            .collect();
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn doc(content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from("test.md")).unwrap()
+    }
+
+    #[test]
+    fn test_no_required_keys_configured() {
+        let rule = FRONTMATTER002::default();
+        assert!(rule.check(&doc("---\nfoo: 1\n---\n")).unwrap().is_empty());
+        assert!(rule.check(&doc("# No frontmatter\n")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        let rule = FRONTMATTER002::with_required_keys(&["title", "date", "author"]);
+        let violations = rule
+            .check(&doc("---\ntitle: Hello\n---\n\n# Hello\n"))
+            .unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'date'"));
+        assert!(violations[1].message.contains("'author'"));
+    }
+
+    #[test]
+    fn test_toml_keys_present() {
+        let rule = FRONTMATTER002::with_required_keys(&["title"]);
+        let violations = rule.check(&doc("+++\ntitle = \"Hi\"\n+++\n")).unwrap();
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_require_frontmatter() {
+        let config: toml::Value =
+            toml::from_str("required_keys = [\"title\"]\nrequire_frontmatter = true").unwrap();
+        let rule = FRONTMATTER002::from_config(&config);
+
+        let violations = rule.check(&doc("# No frontmatter\n")).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("no frontmatter"));
+    }
+
+    #[test]
+    fn test_invalid_frontmatter_left_to_frontmatter001() {
+        let rule = FRONTMATTER002::with_required_keys(&["title"]);
+        let violations = rule.check(&doc("---\ntitle: [\n---\n")).unwrap();
+        assert!(violations.is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+//! FRONTMATTER003: Frontmatter date format
+//!
+//! Ensures date fields in frontmatter use ISO 8601 so they sort correctly and
+//! can be parsed by downstream tools. By default the `date` key may hold
+//! either a calendar date (`2024-01-15`) or an RFC 3339 date-time
+//! (`2024-01-15T10:30:00Z`).
+
+use super::{is_iso_datetime, parsed_front_matter};
+use crate::util::is_iso_date;
+use crate::util::string_list;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// Accepted shape for frontmatter date values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateFormat {
+    /// `YYYY-MM-DD` only
+    Date,
+    /// RFC 3339 date-time only
+    DateTime,
+    /// Either a date or a date-time
+    #[default]
+    Any,
+}
+
+impl DateFormat {
+    fn matches(self, value: &str) -> bool {
+        match self {
+            DateFormat::Date => is_iso_date(value),
+            DateFormat::DateTime => is_iso_datetime(value),
+            DateFormat::Any => is_iso_date(value) || is_iso_datetime(value),
+        }
+    }
+
+    fn expected(self) -> &'static str {
+        match self {
+            DateFormat::Date => "an ISO 8601 date (YYYY-MM-DD)",
+            DateFormat::DateTime => "an RFC 3339 date-time (YYYY-MM-DDTHH:MM:SSZ)",
+            DateFormat::Any => "an ISO 8601 date or date-time",
+        }
+    }
+}
+
+/// FRONTMATTER003: Validates date fields in frontmatter
+#[derive(Debug, Clone)]
+pub struct FRONTMATTER003 {
+    /// Keys holding dates
+    date_keys: Vec<String>,
+    /// Accepted date shape
+    format: DateFormat,
+}
+
+impl Default for FRONTMATTER003 {
+    fn default() -> Self {
+        Self {
+            date_keys: vec!["date".to_string()],
+            format: DateFormat::default(),
+        }
+    }
+}
+
+impl FRONTMATTER003 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `date-keys`: keys whose values must be dates (default `["date"]`)
+    /// - `format`: `"date"`, `"datetime"`, or `"any"` (default `"any"`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+
+        if let Some(keys) = string_list(config, &["date-keys", "date_keys"]) {
+            rule.date_keys = keys;
+        }
+
+        if let Some(format) = config.get("format").and_then(|v| v.as_str()) {
+            rule.format = match format.to_lowercase().as_str() {
+                "date" => DateFormat::Date,
+                "datetime" | "date-time" => DateFormat::DateTime,
+                _ => DateFormat::Any,
+            };
+        }
+
+        rule
+    }
+}
+
+impl Rule for FRONTMATTER003 {
+    fn id(&self) -> &'static str {
+        "FRONTMATTER003"
+    }
+
+    fn name(&self) -> &'static str {
+        "frontmatter-date-format"
+    }
+
+    fn description(&self) -> &'static str {
+        "Frontmatter dates should use ISO 8601 format"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "date-keys",
+                OptionKind::StringList,
+                "Keys whose values must be dates",
+            )
+            .defaults_to(r#"["date"]"#)
+            .option(
+                "format",
+                OptionKind::Choice(&["date", "datetime", "date-time", "any"]),
+                "Accepted date format",
+            )
+            .defaults_to(r#""any""#)
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let Some((front_matter, map)) = parsed_front_matter(document) else {
+            return Ok(Vec::new());
+        };
+
+        let mut violations = Vec::new();
+        for key in &self.date_keys {
+            let Some(value) = map.get(key) else {
+                continue;
+            };
+
+            let valid = value.as_str().is_some_and(|s| self.format.matches(s));
+            if !valid {
+                let line = front_matter
+                    .key_line(key)
+                    .unwrap_or(front_matter.start_line);
+                violations.push(self.create_violation(
+                    format!(
+                        "Frontmatter key '{key}' should be {}, found {value}",
+                        self.format.expected()
+                    ),
+                    line,
+                    1,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &FRONTMATTER003, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_valid_dates() {
+        let rule = FRONTMATTER003::default();
+        assert!(check(&rule, "---\ndate: 2024-01-15\n---\n").is_empty());
+        assert!(check(&rule, "---\ndate: 2024-01-15T09:00:00Z\n---\n").is_empty());
+        assert!(check(&rule, "+++\ndate = 2024-01-15\n+++\n").is_empty());
+        assert!(check(&rule, "---\ntitle: No date\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_invalid_date() {
+        let rule = FRONTMATTER003::default();
+        let violations = check(&rule, "---\ntitle: x\ndate: 15/01/2024\n---\n");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("'date'"));
+        assert!(violations[0].message.contains("15/01/2024"));
+    }
+
+    #[test]
+    fn test_non_string_date() {
+        let rule = FRONTMATTER003::default();
+        let violations = check(&rule, "---\ndate: 20240115\n---\n");
+        assert_eq!(violations.len(), 1);
+    }
+
+    #[test]
+    fn test_configured_keys_and_format() {
+        let config: toml::Value =
+            toml::from_str("date-keys = [\"published\", \"updated\"]\nformat = \"date\"").unwrap();
+        let rule = FRONTMATTER003::from_config(&config);
+
+        let violations = check(
+            &rule,
+            "---\ndate: whenever\npublished: 2024-01-15\nupdated: 2024-01-15T10:00:00Z\n---\n",
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("'updated'"));
+    }
+}
@@ -0,0 +1,323 @@
+//! FRONTMATTER004: Frontmatter schema
+//!
+//! Validates frontmatter values against a configured schema mapping keys to
+//! value types, and optionally rejects keys the schema does not mention
+//! (other than the `mdbook-lint` overrides key).
+//! Does nothing until a schema is configured.
+
+use super::{is_iso_datetime, parsed_front_matter};
+use crate::util::is_iso_date;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::config::FRONT_MATTER_KEY;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// Value types a schema entry can require
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    /// Any string
+    String,
+    /// Whole number
+    Integer,
+    /// Any number
+    Number,
+    /// `true` or `false`
+    Boolean,
+    /// List of values
+    Array,
+    /// Nested key/value mapping
+    Table,
+    /// ISO 8601 date or date-time string
+    Date,
+}
+
+impl ValueType {
+    /// Parse a schema type name
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "string" => Some(ValueType::String),
+            "integer" | "int" => Some(ValueType::Integer),
+            "number" | "float" => Some(ValueType::Number),
+            "boolean" | "bool" => Some(ValueType::Boolean),
+            "array" | "list" => Some(ValueType::Array),
+            "table" | "object" | "map" => Some(ValueType::Table),
+            "date" | "datetime" => Some(ValueType::Date),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ValueType::String => "string",
+            ValueType::Integer => "integer",
+            ValueType::Number => "number",
+            ValueType::Boolean => "boolean",
+            ValueType::Array => "array",
+            ValueType::Table => "table",
+            ValueType::Date => "date",
+        }
+    }
+
+    fn matches(self, value: &Value) -> bool {
+        match self {
+            ValueType::String => value.is_string(),
+            ValueType::Integer => value.is_i64() || value.is_u64(),
+            ValueType::Number => value.is_number(),
+            ValueType::Boolean => value.is_boolean(),
+            ValueType::Array => value.is_array(),
+            ValueType::Table => value.is_object(),
+            ValueType::Date => value
+                .as_str()
+                .is_some_and(|s| is_iso_date(s) || is_iso_datetime(s)),
+        }
+    }
+}
+
+/// FRONTMATTER004: Validates frontmatter against a schema
+#[derive(Debug, Clone)]
+pub struct FRONTMATTER004 {
+    /// Expected type for each key
+    schema: BTreeMap<String, ValueType>,
+    /// Allowed values for enumerated keys
+    allowed_values: BTreeMap<String, Vec<String>>,
+    /// Whether keys missing from the schema are allowed
+    allow_unknown_keys: bool,
+}
+
+impl Default for FRONTMATTER004 {
+    fn default() -> Self {
+        Self {
+            schema: BTreeMap::new(),
+            allowed_values: BTreeMap::new(),
+            allow_unknown_keys: true,
+        }
+    }
+}
+
+impl FRONTMATTER004 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `schema`: table mapping keys to `"string"`, `"integer"`, `"number"`,
+    ///   `"boolean"`, `"array"`, `"table"`, or `"date"`; unknown type names
+    ///   are ignored
+    /// - `allowed-values`: table mapping keys to the list of accepted strings
+    /// - `allow-unknown-keys`: permit keys not listed in `schema` (default true)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+
+        if let Some(schema) = config.get("schema").and_then(|v| v.as_table()) {
+            rule.schema = schema
+                .iter()
+                .filter_map(|(key, ty)| {
+                    let ty = ValueType::from_name(ty.as_str()?)?;
+                    Some((key.clone(), ty))
+                })
+                .collect();
+        }
+
+        if let Some(allowed) = config
+            .get("allowed-values")
+            .or_else(|| config.get("allowed_values"))
+            .and_then(|v| v.as_table())
+        {
+            rule.allowed_values = allowed
+                .iter()
+                .filter_map(|(key, values)| {
+                    let values = values
+                        .as_array()?
+                        .iter()
+                        .filter_map(|v| v.as_str().map(String::from))
+                        .collect();
+                    Some((key.clone(), values))
+                })
+                .collect();
+        }
+
+        if let Some(allow) = config
+            .get("allow-unknown-keys")
+            .or_else(|| config.get("allow_unknown_keys"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.allow_unknown_keys = allow;
+        }
+
+        rule
+    }
+}
+
+impl Rule for FRONTMATTER004 {
+    fn id(&self) -> &'static str {
+        "FRONTMATTER004"
+    }
+
+    fn name(&self) -> &'static str {
+        "frontmatter-schema"
+    }
+
+    fn description(&self) -> &'static str {
+        "Frontmatter values must match the configured schema"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "schema",
+                OptionKind::Any,
+                "Table mapping keys to value types",
+            )
+            .option(
+                "allowed-values",
+                OptionKind::Any,
+                "Table mapping keys to the list of accepted strings",
+            )
+            .option(
+                "allow-unknown-keys",
+                OptionKind::Boolean,
+                "Permit keys not listed in `schema`",
+            )
+            .defaults_to("true")
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if self.schema.is_empty() && self.allowed_values.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let Some((front_matter, map)) = parsed_front_matter(document) else {
+            return Ok(Vec::new());
+        };
+
+        let mut violations = Vec::new();
+        let mut report = |key: &str, message: String| {
+            let line = front_matter
+                .key_line(key)
+                .unwrap_or(front_matter.start_line);
+            violations.push(self.create_violation(message, line, 1, Severity::Warning));
+        };
+
+        for (key, value) in &map {
+            if let Some(ty) = self.schema.get(key) {
+                if !ty.matches(value) {
+                    report(
+                        key,
+                        format!(
+                            "Frontmatter key '{key}' should be of type {}, found {value}",
+                            ty.name()
+                        ),
+                    );
+                    continue;
+                }
+            } else if !self.allow_unknown_keys
+                && !self.allowed_values.contains_key(key)
+                && key != FRONT_MATTER_KEY
+            {
+                report(key, format!("Frontmatter key '{key}' is not in the schema"));
+                continue;
+            }
+
+            if let Some(allowed) = self.allowed_values.get(key) {
+                let valid = value
+                    .as_str()
+                    .is_some_and(|s| allowed.iter().any(|a| a == s));
+                if !valid {
+                    report(
+                        key,
+                        format!(
+                            "Frontmatter key '{key}' has value {value}, expected one of: {}",
+                            allowed.join(", ")
+                        ),
+                    );
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn rule(config: &str) -> FRONTMATTER004 {
+        FRONTMATTER004::from_config(&toml::from_str(config).unwrap())
+    }
+
+    fn check(rule: &FRONTMATTER004, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_no_schema_configured() {
+        let rule = FRONTMATTER004::default();
+        assert!(check(&rule, "---\nanything: [1, 2]\n---\n").is_empty());
+    }
+
+    #[test]
+    fn test_type_mismatches() {
+        let rule = rule(
+            r#"
+            [schema]
+            title = "string"
+            weight = "integer"
+            draft = "boolean"
+            tags = "array"
+            date = "date"
+            "#,
+        );
+
+        let ok = "---\ntitle: Hi\nweight: 3\ndraft: false\ntags: [a]\ndate: 2024-01-01\n---\n";
+        assert!(check(&rule, ok).is_empty());
+
+        let bad = "---\ntitle: 42\nweight: heavy\ndraft: \"no\"\ntags: a\ndate: soon\n---\n";
+        let violations = check(&rule, bad);
+        assert_eq!(violations.len(), 5);
+        let weight = violations
+            .iter()
+            .find(|v| v.message.contains("'weight'"))
+            .unwrap();
+        assert_eq!(weight.line, 3);
+        assert!(weight.message.contains("should be of type integer"));
+    }
+
+    #[test]
+    fn test_unknown_keys() {
+        let rule = rule("allow-unknown-keys = false\n[schema]\ntitle = \"string\"");
+        let violations = check(
+            &rule,
+            "---\ntitle: Hi\nlayout: post\nmdbook-lint:\n  disable: [MD013]\n---\n",
+        );
+
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0]
+                .message
+                .contains("'layout' is not in the schema")
+        );
+    }
+
+    #[test]
+    fn test_allowed_values() {
+        let rule = rule("[allowed-values]\nstatus = [\"draft\", \"published\"]");
+
+        assert!(check(&rule, "---\nstatus: draft\n---\n").is_empty());
+        let violations = check(&rule, "+++\nstatus = \"archived\"\n+++\n");
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0]
+                .message
+                .contains("expected one of: draft, published")
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Frontmatter linting rules
+//!
+//! This module provides rules for books whose chapters carry YAML (`---`) or
+//! TOML (`+++`) frontmatter. Documents without frontmatter are ignored unless a
+//! rule is configured to require it.
+//!
+//! # Available Rules
+//!
+//! | Rule | Name | Description |
+//! |------|------|-------------|
+//! | FRONTMATTER001 | frontmatter-valid | Frontmatter parses and is a key/value mapping |
+//! | FRONTMATTER002 | frontmatter-required-keys | Configured keys are present |
+//! | FRONTMATTER003 | frontmatter-date-format | Date fields use ISO 8601 |
+//! | FRONTMATTER004 | frontmatter-schema | Values match configured types |
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [FRONTMATTER002]
+//! required-keys = ["title", "date"]
+//! require-frontmatter = false
+//!
+//! [FRONTMATTER003]
+//! date-keys = ["date", "updated"]
+//! format = "date"  # "date", "datetime", or "any"
+//!
+//! [FRONTMATTER004]
+//! allow-unknown-keys = false
+//!
+//! [FRONTMATTER004.schema]
+//! title = "string"
+//! tags = "array"
+//! draft = "boolean"
+//! ```
+
+mod frontmatter001;
+mod frontmatter002;
+mod frontmatter003;
+mod frontmatter004;
+
+use crate::util::is_iso_date;
+use crate::{RuleProvider, RuleRegistry};
+use mdbook_lint_core::{Config, Document, FrontMatter};
+
+pub use frontmatter001::FRONTMATTER001;
+pub use frontmatter002::FRONTMATTER002;
+pub use frontmatter003::{DateFormat, FRONTMATTER003};
+pub use frontmatter004::{FRONTMATTER004, ValueType};
+
+/// Parse a document's frontmatter into a key/value map
+///
+/// Returns `None` when the document has no frontmatter or it does not parse
+/// to a mapping; FRONTMATTER001 reports those cases so the other rules stay
+/// quiet.
+pub(crate) fn parsed_front_matter(
+    document: &Document,
+) -> Option<(FrontMatter, serde_json::Map<String, serde_json::Value>)> {
+    let front_matter = document.front_matter()?;
+    match front_matter.parse().ok()? {
+        serde_json::Value::Object(map) => Some((front_matter, map)),
+        _ => None,
+    }
+}
+
+/// Check whether `value` is an RFC 3339 date-time
+/// (`YYYY-MM-DDTHH:MM[:SS[.fff]]` with an optional `Z` or `±HH:MM` offset)
+///
+/// A space is accepted in place of the `T` separator, as TOML and most
+/// static site generators allow.
+pub(crate) fn is_iso_datetime(value: &str) -> bool {
+    let Some((date, time)) = value.split_once(['T', 't', ' ']) else {
+        return false;
+    };
+    if !is_iso_date(date) {
+        return false;
+    }
+
+    // Split off the timezone offset, if any
+    let (clock, offset) = if let Some(clock) = time.strip_suffix(['Z', 'z']) {
+        (clock, None)
+    } else if let Some(idx) = time.rfind(['+', '-']) {
+        (&time[..idx], Some(&time[idx + 1..]))
+    } else {
+        (time, None)
+    };
+
+    let two_digits = |s: &str, max: u32| {
+        s.len() == 2 && s.bytes().all(|b| b.is_ascii_digit()) && s.parse::<u32>().unwrap() <= max
+    };
+
+    if let Some(offset) = offset {
+        match offset.split_once(':') {
+            Some((h, m)) if two_digits(h, 23) && two_digits(m, 59) => {}
+            _ => return false,
+        }
+    }
+
+    let (clock, fraction) = clock.split_once('.').unwrap_or((clock, "0"));
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return false;
+    }
+
+    let parts: Vec<&str> = clock.split(':').collect();
+    match parts.as_slice() {
+        [h, m] => two_digits(h, 23) && two_digits(m, 59),
+        [h, m, s] => two_digits(h, 23) && two_digits(m, 59) && two_digits(s, 60),
+        _ => false,
+    }
+}
+
+/// Provider for frontmatter rules (FRONTMATTER001+)
+pub struct FrontMatterRuleProvider;
+
+impl RuleProvider for FrontMatterRuleProvider {
+    fn provider_id(&self) -> &'static str {
+        "frontmatter"
+    }
+
+    fn description(&self) -> &'static str {
+        "Frontmatter validation rules (FRONTMATTER001-FRONTMATTER004)"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn register_rules(&self, registry: &mut RuleRegistry) {
+        registry.register(Box::new(FRONTMATTER001));
+        registry.register(Box::new(FRONTMATTER002::default()));
+        registry.register(Box::new(FRONTMATTER003::default()));
+        registry.register(Box::new(FRONTMATTER004::default()));
+    }
+
+    fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
+        let cfg = |id: &str| config.and_then(|c| c.rule_configs.get(id));
+
+        registry.register(Box::new(FRONTMATTER001));
+
+        let frontmatter002 = match cfg("FRONTMATTER002") {
+            Some(c) => FRONTMATTER002::from_config(c),
+            None => FRONTMATTER002::default(),
+        };
+        registry.register(Box::new(frontmatter002));
+
+        let frontmatter003 = match cfg("FRONTMATTER003") {
+            Some(c) => FRONTMATTER003::from_config(c),
+            None => FRONTMATTER003::default(),
+        };
+        registry.register(Box::new(frontmatter003));
+
+        let frontmatter004 = match cfg("FRONTMATTER004") {
+            Some(c) => FRONTMATTER004::from_config(c),
+            None => FRONTMATTER004::default(),
+        };
+        registry.register(Box::new(frontmatter004));
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        vec![
+            "FRONTMATTER001",
+            "FRONTMATTER002",
+            "FRONTMATTER003",
+            "FRONTMATTER004",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::PluginRegistry;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_is_iso_datetime() {
+        assert!(is_iso_datetime("2024-01-15T10:30:00Z"));
+        assert!(is_iso_datetime("2024-01-15T10:30:00.123+02:00"));
+        assert!(is_iso_datetime("2024-01-15 10:30"));
+        assert!(!is_iso_datetime("2024-01-15"));
+        assert!(!is_iso_datetime("2024-01-15T25:00:00Z"));
+        assert!(!is_iso_datetime("2024-01-15T10:30:00+2"));
+    }
+
+    /// Config set on the provider reaches the individual rules.
+    #[test]
+    fn test_provider_threads_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [FRONTMATTER002]
+            required-keys = ["title", "author"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+
+        let doc = Document::new(
+            "---\ntitle: Intro\n---\n\n# Intro\n".to_string(),
+            PathBuf::from("intro.md"),
+        )
+        .unwrap();
+        let violations = engine.lint_document_with_config(&doc, &config).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule_id, "FRONTMATTER002");
+        assert!(violations[0].message.contains("author"));
+    }
+
+    #[test]
+    fn test_no_frontmatter_is_ignored_by_default() {
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(FrontMatterRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine().unwrap();
+
+        let violations = engine
+            .lint_content("# Chapter\n\nNo frontmatter here.\n", "chapter.md")
+            .unwrap();
+        assert!(violations.is_empty());
+    }
+}
@@ -99,6 +99,7 @@
 //! - **MD030**: Fix spaces after list markers
 //! - **MD034**: Wrap bare URLs in angle brackets
 //! - **MD047**: Ensure files end with single newline
+//! - **MD053**: Remove unused reference definitions
 //!
 //! # Configuration
 //!
@@ -143,7 +144,7 @@ pub use mdbook_lint_core::{
 /// This is the recommended way to create a lint engine for most use cases.
 /// It registers:
 /// - Standard markdown rules (MD001-MD059)
-/// - mdBook-specific rules (MDBOOK001-MDBOOK025)
+/// - mdBook-specific rules (MDBOOK001-MDBOOK039)
 /// - Content quality rules (if the `content` feature is enabled)
 ///
 /// # Example
@@ -179,6 +180,22 @@ pub fn create_default_engine() -> Result<LintEngine> {
     registry.create_engine()
 }
 
+// Named rule profiles (minimal, recommended, strict)
+pub mod profiles;
+
+// Shared helpers for prose-oriented rules
+#[cfg(any(feature = "content", feature = "spelling"))]
+mod prose;
+
+// Helpers shared across rule families
+#[cfg(any(
+    feature = "frontmatter",
+    feature = "rfc",
+    feature = "changelog",
+    feature = "adr"
+))]
+mod util;
+
 // Standard markdown rules
 #[cfg(feature = "standard")]
 pub mod standard;
@@ -197,8 +214,38 @@ pub mod content;
 #[cfg(feature = "content")]
 pub use content::ContentRuleProvider;
 
+// Frontmatter validation rules (optional, off by default)
+#[cfg(feature = "frontmatter")]
+pub mod frontmatter;
+#[cfg(feature = "frontmatter")]
+pub use frontmatter::FrontMatterRuleProvider;
+
+// Spelling rules (optional, off by default)
+#[cfg(feature = "spelling")]
+pub mod spelling;
+#[cfg(feature = "spelling")]
+pub use spelling::SpellingRuleProvider;
+
 // ADR (Architecture Decision Record) rules (optional, off by default)
 #[cfg(feature = "adr")]
 pub mod adr;
 #[cfg(feature = "adr")]
 pub use adr::AdrRuleProvider;
+
+// RFC and design document rules (optional, off by default)
+#[cfg(feature = "rfc")]
+pub mod rfc;
+#[cfg(feature = "rfc")]
+pub use rfc::RfcRuleProvider;
+
+// Keep a Changelog rules (optional, off by default)
+#[cfg(feature = "changelog")]
+pub mod changelog;
+#[cfg(feature = "changelog")]
+pub use changelog::ChangelogRuleProvider;
+
+// README quality rules (optional, off by default)
+#[cfg(feature = "readme")]
+pub mod readme;
+#[cfg(feature = "readme")]
+pub use readme::ReadmeRuleProvider;
@@ -3,6 +3,7 @@
 //! This rule validates that internal links (relative paths) resolve to existing files.
 
 use comrak::nodes::NodeValue;
+use mdbook_lint_core::asset::page_source;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
@@ -98,36 +99,23 @@ fn validate_internal_link<'a>(
     }
 
     // Use book source directory from document if available (preprocessor mode),
-    // otherwise try to discover it by looking for SUMMARY.md
-    let book_src_dir = document
-        .book_src_dir
-        .clone()
-        .or_else(|| find_book_src_directory(&document.path));
+    // otherwise discover it from book.toml or SUMMARY.md
+    let book_src_dir = document.src_dir();
 
     // Check if this link goes outside the book source directory
     // Links like ../std/, ../reference/, ../nomicon/ are external doc links
     // that resolve at runtime when hosted alongside other Rust docs
-    if is_external_doc_link(path_part, &document.path, book_src_dir.as_deref()) {
+    if is_external_doc_link(path_part, &document.path, book_src_dir) {
         return Ok(None);
     }
 
     // Resolve the target path relative to the current document
-    let target_path = resolve_link_path(&document.path, path_part, book_src_dir.as_deref());
-
-    // Check if the target file exists
-    // For mdBook, .html links in source files refer to .md files that get compiled to .html
-    let file_exists = if target_path.exists() {
-        true
-    } else if path_part.ends_with(".html") {
-        // Try the corresponding .md file
-        let md_path = path_part.replace(".html", ".md");
-        let md_target = resolve_link_path(&document.path, &md_path, book_src_dir.as_deref());
-        md_target.exists()
-    } else {
-        false
-    };
+    let target_path = resolve_link_path(&document.path, path_part, book_src_dir);
 
-    if !file_exists {
+    // Check if the target file exists the way mdBook finds it: .html links
+    // refer to the .md files they are built from, and directory links to the
+    // directory's index.md or README.md
+    if page_source(&target_path).is_none() {
         let (line, column) = document.node_position(node).unwrap_or((1, 1));
 
         return Ok(Some(MDBOOK002.create_violation(
@@ -194,22 +182,6 @@ fn normalize_path(path: &Path) -> PathBuf {
     components.iter().collect()
 }
 
-/// Find the book's source directory by looking for SUMMARY.md
-fn find_book_src_directory(current_doc_path: &Path) -> Option<PathBuf> {
-    let mut current = current_doc_path.parent();
-
-    while let Some(dir) = current {
-        // Check if SUMMARY.md exists in this directory
-        if dir.join("SUMMARY.md").exists() {
-            return Some(dir.to_path_buf());
-        }
-        // Move up one directory
-        current = dir.parent();
-    }
-
-    None
-}
-
 /// Resolve a link path relative to the current document
 fn resolve_link_path(
     current_doc_path: &Path,
@@ -385,7 +357,7 @@ mod tests {
     }
 
     #[test]
-    fn test_find_book_src_directory() -> mdbook_lint_core::error::Result<()> {
+    fn test_book_src_directory_discovery() -> mdbook_lint_core::error::Result<()> {
         let temp_dir = TempDir::new()?;
         let src_dir = temp_dir.path().join("src");
         fs::create_dir_all(&src_dir)?;
@@ -399,13 +371,13 @@ mod tests {
         fs::write(&chapter_path, "# Section")?;
 
         // Test finding book source from nested chapter
-        let found = find_book_src_directory(&chapter_path);
+        let found = src_dir_of(&chapter_path);
         assert_eq!(found, Some(src_dir.clone()));
 
         // Test from file in same directory as SUMMARY.md
         let root_file = src_dir.join("README.md");
         fs::write(&root_file, "# README")?;
-        let found = find_book_src_directory(&root_file);
+        let found = src_dir_of(&root_file);
         assert_eq!(found, Some(src_dir.clone()));
 
         // Test when no SUMMARY.md exists
@@ -413,12 +385,52 @@ mod tests {
         fs::create_dir_all(&other_dir)?;
         let other_file = other_dir.join("file.md");
         fs::write(&other_file, "# File")?;
-        let found = find_book_src_directory(&other_file);
+        let found = src_dir_of(&other_file);
         assert_eq!(found, None);
 
         Ok(())
     }
 
+    fn src_dir_of(path: &Path) -> Option<PathBuf> {
+        let document = Document::new(String::new(), path.to_path_buf()).ok()?;
+        document.src_dir().map(Path::to_path_buf)
+    }
+
+    #[test]
+    fn test_mdbook002_book_toml_src_and_index_pages() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let src_dir = temp_dir.path().join("docs");
+        fs::create_dir_all(src_dir.join("guide/setup"))?;
+        fs::create_dir_all(src_dir.join("empty"))?;
+        fs::write(
+            temp_dir.path().join("book.toml"),
+            "[book]\nsrc = \"docs\"\n",
+        )?;
+        fs::write(src_dir.join("SUMMARY.md"), "- [Guide](guide/README.md)")?;
+        fs::write(src_dir.join("intro.md"), "# Intro")?;
+        fs::write(src_dir.join("guide/README.md"), "# Guide")?;
+        // A sub-page SUMMARY.md does not make guide/ its own book
+        fs::write(src_dir.join("guide/SUMMARY.md"), "# Contents")?;
+
+        let content = r#"# Setup
+
+[Guide](../)
+[Guide index](../index.html)
+[Intro](/intro.md)
+[Intro page](../../intro.html)
+[Empty section](../../empty/)
+"#;
+        let doc_path = src_dir.join("guide/setup/linux.md");
+        fs::write(&doc_path, content)?;
+        let document = Document::new(content.to_string(), doc_path)?;
+
+        let violations = MDBOOK002.check(&document)?;
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("../../empty/"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_mdbook002_absolute_paths_with_book_structure() -> mdbook_lint_core::error::Result<()> {
         let temp_dir = TempDir::new()?;
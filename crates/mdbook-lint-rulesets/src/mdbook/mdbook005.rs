@@ -4,7 +4,7 @@
 //! in SUMMARY.md. Only scans within the directory containing SUMMARY.md, not parent or
 //! sibling directories. Orphaned files can indicate incomplete documentation or forgotten content.
 
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
     violation::{Severity, Violation},
@@ -173,7 +173,19 @@ impl Rule for MDBOOK005 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::MdBook).introduced_in("mdbook-lint v0.2.0")
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.2.0")
+            .option(
+                "ignore_patterns",
+                OptionKind::StringList,
+                "Glob patterns for orphan files to skip",
+            )
+            .defaults_to("[]")
+            .option("exclude_readme", OptionKind::Boolean, "Ignore README.md")
+            .defaults_to("true")
+            .option("check_nested", OptionKind::Boolean, "Scan subdirectories")
+            .defaults_to("true")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -188,6 +200,14 @@ impl Rule for MDBOOK005 {
             return Ok(violations);
         }
 
+        // A SUMMARY.md below the book's source directory is one of its
+        // chapters, not the summary mdBook reads
+        if let Some(src_dir) = document.src_dir()
+            && document.path.parent() != Some(src_dir)
+        {
+            return Ok(violations);
+        }
+
         // Find the book source directory
         // SUMMARY.md should be in the book's src directory
         let book_src_dir = if document.path.is_absolute() {
@@ -586,6 +606,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_mdbook005_skips_sub_page_summary() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(root.join("book.toml"), "[book]\n")?;
+        create_test_document(
+            "# Summary\n\n- [Guide](guide/README.md)\n",
+            &root.join("src/SUMMARY.md"),
+        )?;
+        create_test_document("# Guide", &root.join("src/guide/README.md"))?;
+        create_test_document("# Other", &root.join("src/guide/other.md"))?;
+
+        // guide/SUMMARY.md is a chapter listing the section's pages
+        let doc = create_test_document("# Contents\n", &root.join("src/guide/SUMMARY.md"))?;
+        assert!(MDBOOK005::default().check(&doc)?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_mdbook005_scope_limited_to_src_dir() -> mdbook_lint_core::error::Result<()> {
         let temp_dir = TempDir::new()?;
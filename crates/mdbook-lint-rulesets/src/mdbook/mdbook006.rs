@@ -4,6 +4,7 @@
 //! in target files. It complements MDBOOK002 by focusing on the anchor validation that MDBOOK002 skips.
 
 use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::asset::page_source;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
@@ -116,14 +117,13 @@ impl MDBOOK006 {
             return Ok(None);
         }
 
-        // Resolve the target file path relative to current document
-        let target_path = self.resolve_target_path(&document.path, file_path);
-
-        // Check if target file exists
-        if !target_path.exists() {
+        // Resolve the target file path relative to current document, and
+        // then to the chapter mdBook builds that page from
+        let target_path = self.resolve_target_path(document, file_path);
+        let Some(target_path) = page_source(&target_path) else {
             // File doesn't exist - this should be caught by MDBOOK002, so we skip it
             return Ok(None);
-        }
+        };
 
         // Get anchors from the target file
         let anchors = match self.get_file_anchors(&target_path)? {
@@ -171,8 +171,8 @@ impl MDBOOK006 {
     }
 
     /// Resolve target file path relative to current document
-    fn resolve_target_path(&self, current_doc_path: &Path, link_path: &str) -> PathBuf {
-        let current_dir = current_doc_path.parent().unwrap_or(Path::new("."));
+    fn resolve_target_path(&self, document: &Document, link_path: &str) -> PathBuf {
+        let current_dir = document.path.parent().unwrap_or(Path::new("."));
 
         if let Some(stripped) = link_path.strip_prefix("./") {
             // Explicit relative path: ./file.md
@@ -181,8 +181,11 @@ impl MDBOOK006 {
             // Parent directory path: ../file.md
             current_dir.join(link_path)
         } else if let Some(stripped) = link_path.strip_prefix('/') {
-            // Absolute path (relative to project root)
-            PathBuf::from(stripped)
+            // Absolute path (relative to the book's source directory)
+            match document.src_dir() {
+                Some(src_dir) => src_dir.join(stripped),
+                None => PathBuf::from(stripped),
+            }
         } else {
             // Implicit relative path: file.md
             current_dir.join(link_path)
@@ -633,6 +636,27 @@ See [nested section](guide/deep.md#nested-section).
         Ok(())
     }
 
+    #[test]
+    fn test_mdbook006_index_pages_and_rooted_links() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        fs::write(root.join("book.toml"), "[book]\nsrc = \"docs\"\n")?;
+        create_test_document("# Summary\n", &root.join("docs/SUMMARY.md"))?;
+        create_test_document("# Guide\n\n## Setup\n", &root.join("docs/guide/README.md"))?;
+
+        let source_content = r#"# Chapter
+
+See [setup](guide/#setup), [the same](guide/index.html#setup),
+[rooted](/guide/README.md#setup), and [missing](guide/#install).
+"#;
+        let doc = create_test_document(source_content, &root.join("docs/chapter.md"))?;
+
+        let violations = MDBOOK006::default().check(&doc)?;
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'install'"));
+        Ok(())
+    }
+
     #[test]
     fn test_issue_410_duplicate_heading_fragments() -> mdbook_lint_core::error::Result<()> {
         let temp_dir = TempDir::new()?;
@@ -2,6 +2,8 @@
 //!
 //! This rule validates that all include directives point to existing files with correct
 //! syntax, preventing build failures and broken includes in mdBook projects.
+//! `{{#include}}`, `{{#rustdoc_include}}`, and `{{#playground}}` are checked, with
+//! paths resolved relative to the chapter that contains them.
 
 use comrak::nodes::AstNode;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
@@ -9,6 +11,7 @@ use mdbook_lint_core::{
     Document,
     violation::{Severity, Violation},
 };
+use regex::Regex;
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, RwLock};
@@ -32,8 +35,12 @@ use std::{fs, io};
 /// - Basic file includes: `{{#include file.txt}}`
 /// - Line ranges: `{{#include file.rs:10:20}}`
 /// - Named anchors: `{{#include file.rs:anchor_name}}`
+/// - Open-ended ranges: `{{#include file.rs:10:}}` and `{{#include file.rs::20}}`
 /// - Relative paths: `{{#include ../other/file.md}}`
 /// - Rust-specific: `{{#rustdoc_include file.rs}}`
+/// - Playground embeds: `{{#playground file.rs editable}}`
+///
+/// Escaped directives (`\{{#include ...}}`) are left alone, as mdBook does.
 #[derive(Default)]
 pub struct MDBOOK007 {
     /// Cache of file existence and content to avoid repeated filesystem access
@@ -109,52 +116,55 @@ struct IncludeDirective {
 impl MDBOOK007 {
     /// Find all include directives in markdown content
     fn find_include_directives(&self, content: &str) -> Vec<IncludeDirective> {
-        let mut directives = Vec::new();
+        content
+            .lines()
+            .enumerate()
+            .flat_map(|(line_number, line)| self.parse_include_directives(line, line_number + 1))
+            .collect()
+    }
 
-        for (line_number, line) in content.lines().enumerate() {
-            // Look for include directive patterns
-            // Pattern: {{#include file.txt}} or {{#include file.rs:10:20}} or {{#include file.rs:anchor}}
-            if let Some(directive) = self.parse_include_directive(line, line_number + 1) {
-                directives.push(directive);
-            }
-        }
+    /// Parse every include-type directive on a single line
+    fn parse_include_directives(&self, line: &str, line_number: usize) -> Vec<IncludeDirective> {
+        let mut directives = Vec::new();
+        let mut search_from = 0;
 
-        directives
-    }
+        while let Some(offset) = line[search_from..].find("{{#") {
+            let start = search_from + offset;
+            let Some(end) = line[start..].find("}}").map(|end| start + end) else {
+                break;
+            };
+            search_from = end + 2;
 
-    /// Parse a single include directive from a line
-    fn parse_include_directive(&self, line: &str, line_number: usize) -> Option<IncludeDirective> {
-        // Look for patterns like {{#include ...}} or {{#rustdoc_include ...}}
-        let trimmed = line.trim();
+            // `\{{#include ...}}` is an escaped directive that mdBook renders literally
+            if line[..start].ends_with('\\') {
+                continue;
+            }
 
-        // Find the start of a directive
-        if let Some(start) = trimmed.find("{{#")
-            && let Some(end) = trimmed[start..].find("}}")
-        {
-            let directive_content = &trimmed[start + 3..start + end];
+            let directive_content = &line[start + 3..end];
             let parts: Vec<&str> = directive_content.split_whitespace().collect();
+            if parts.len() < 2 {
+                continue;
+            }
 
-            if parts.len() >= 2 {
-                let directive_type = parts[0];
-
-                // Only process include-type directives
-                if directive_type == "include" || directive_type == "rustdoc_include" {
-                    let file_spec = parts[1];
-                    let (file_path, range_or_anchor) = self.parse_file_spec(file_spec);
+            let directive_type = parts[0];
+            let (file_path, range_or_anchor) = match directive_type {
+                "include" | "rustdoc_include" => self.parse_file_spec(parts[1]),
+                // Playground embeds take a whole file followed by attributes
+                "playground" => (parts[1], None),
+                _ => continue,
+            };
 
-                    return Some(IncludeDirective {
-                        full_match: trimmed[start..start + end + 2].to_string(),
-                        directive_type: directive_type.to_string(),
-                        file_path: file_path.to_string(),
-                        range_or_anchor,
-                        line_number,
-                        column: start + 1,
-                    });
-                }
-            }
+            directives.push(IncludeDirective {
+                full_match: line[start..end + 2].to_string(),
+                directive_type: directive_type.to_string(),
+                file_path: file_path.to_string(),
+                range_or_anchor,
+                line_number,
+                column: line[..start].chars().count() + 1,
+            });
         }
 
-        None
+        directives
     }
 
     /// Parse file specification to extract path and range/anchor
@@ -348,11 +358,12 @@ impl MDBOOK007 {
         let line_count = content.lines().count();
 
         let (start_line, end_line) = if let Some(colon_pos) = range_spec.find(':') {
-            // Range format "start:end"
+            // Range format "start:end"; either side may be omitted
             let start_str = &range_spec[..colon_pos];
             let end_str = &range_spec[colon_pos + 1..];
 
             let start = match start_str.parse::<usize>() {
+                _ if start_str.is_empty() => 1,
                 Ok(n) if n > 0 => n,
                 _ => {
                     return Ok(Some(self.create_violation(
@@ -365,6 +376,7 @@ impl MDBOOK007 {
             };
 
             let end = match end_str.parse::<usize>() {
+                _ if end_str.is_empty() => start.max(line_count),
                 Ok(n) if n > 0 => n,
                 _ => {
                     return Ok(Some(self.create_violation(
@@ -431,34 +443,18 @@ impl MDBOOK007 {
         content: &str,
         anchor: &str,
     ) -> mdbook_lint_core::error::Result<Option<Violation>> {
-        // Look for the anchor in the file content
-        // Anchors are typically comments like "// ANCHOR: anchor_name" or "<!-- ANCHOR: anchor_name -->"
-        let anchor_patterns = [
-            format!("// ANCHOR: {anchor}"),
-            format!("# ANCHOR: {anchor}"),
-            format!("<!-- ANCHOR: {anchor} -->"),
-            format!("<!-- anchor: {anchor} -->"),
-        ];
-
-        let mut found = false;
-        for line in content.lines() {
-            for pattern in &anchor_patterns {
-                if line.contains(pattern) {
-                    found = true;
-                    break;
-                }
-            }
-            if found {
-                break;
-            }
-        }
-
-        if !found {
+        // mdBook recognizes `ANCHOR: name` anywhere on a line, so any comment
+        // syntax works (`// ANCHOR: name`, `# ANCHOR: name`, `<!-- ANCHOR: name -->`)
+        let anchor_pattern =
+            Regex::new(&format!(r"ANCHOR:\s*{}(?:[^\w-]|$)", regex::escape(anchor))).map_err(
+                |e| mdbook_lint_core::MdBookLintError::rule_error("MDBOOK007", e.to_string()),
+            )?;
+
+        if !content.lines().any(|line| anchor_pattern.is_match(line)) {
             return Ok(Some(self.create_violation(
                 format!(
-                    "Anchor '{}' not found in included file. Expected patterns: {}",
-                    anchor,
-                    anchor_patterns.join(", ")
+                    "Anchor '{}' not found in included file '{}'. Expected a line containing 'ANCHOR: {}'",
+                    anchor, directive.file_path, anchor
                 ),
                 directive.line_number,
                 directive.column,
@@ -855,4 +851,78 @@ More content here."#;
         assert!(!rule.looks_like_malformed_line_range("anchor_name"));
         assert!(!rule.looks_like_malformed_line_range("valid-anchor"));
     }
+
+    #[test]
+    fn test_mdbook007_open_ended_ranges() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_document("1\n2\n3\n4\n5", &root.join("lines.txt"))?;
+
+        let source_content =
+            "{{#include lines.txt:3:}}\n{{#include lines.txt::4}}\n{{#include lines.txt:9:}}\n";
+        let doc = create_test_document(source_content, &root.join("chapter.md"))?;
+
+        let violations = MDBOOK007::default().check(&doc)?;
+        assert_eq!(violations.len(), 1, "{violations:?}");
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("does not exist"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mdbook007_playground() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_document("fn main() {}", &root.join("examples/hello.rs"))?;
+
+        let source_content =
+            "{{#playground examples/hello.rs editable}}\n\n{{#playground examples/missing.rs}}\n";
+        let doc = create_test_document(source_content, &root.join("chapter.md"))?;
+
+        let violations = MDBOOK007::default().check(&doc)?;
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert!(violations[0].message.contains("examples/missing.rs"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mdbook007_multiple_and_escaped_directives() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_document("content", &root.join("a.txt"))?;
+
+        let source_content =
+            "  See {{#include a.txt}} and {{#include b.txt}}, not \\{{#include c.txt}}.\n";
+        let doc = create_test_document(source_content, &root.join("chapter.md"))?;
+
+        let violations = MDBOOK007::default().check(&doc)?;
+        assert_eq!(violations.len(), 1, "{violations:?}");
+        assert!(violations[0].message.contains("'b.txt'"));
+        assert_eq!(violations[0].column, 30);
+        Ok(())
+    }
+
+    #[test]
+    fn test_mdbook007_anchor_comment_styles() -> mdbook_lint_core::error::Result<()> {
+        let temp_dir = TempDir::new()?;
+        let root = temp_dir.path();
+        create_test_document(
+            "/* ANCHOR: setup */\nlet x = 1;\n/* ANCHOR_END: setup */\n-- ANCHOR:query\n",
+            &root.join("example.rs"),
+        )?;
+
+        let source_content = "{{#rustdoc_include example.rs:setup}}\n{{#include example.rs:query}}\n{{#include example.rs:teardown}}\n";
+        let doc = create_test_document(source_content, &root.join("chapter.md"))?;
+
+        let violations = MDBOOK007::default().check(&doc)?;
+        assert_eq!(violations.len(), 1, "{violations:?}");
+        assert_eq!(violations[0].line, 3);
+        assert!(
+            violations[0]
+                .message
+                .contains("Anchor 'teardown' not found")
+        );
+        Ok(())
+    }
 }
@@ -1,13 +1,13 @@
 //! MDBOOK016: Validate Rust code block attributes
 //!
 //! Validates that Rust code blocks use valid mdBook/rustdoc attributes
-//! like `ignore`, `should_panic`, `no_run`, `compile_fail`, etc.
+//! like `ignore`, `should_panic`, `no_run`, `compile_fail`, etc., and that
+//! they are not combined in ways where one silently cancels another.
 
+use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
-use regex::Regex;
-use std::sync::LazyLock;
 
 /// Valid Rust code block attributes recognized by mdBook and rustdoc
 const VALID_RUST_ATTRIBUTES: &[&str] = &[
@@ -32,8 +32,35 @@ const VALID_RUST_ATTRIBUTES: &[&str] = &[
     "plain",
 ];
 
-/// Regex to match code block opening with language tag
-static CODE_BLOCK_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^```(\S+)?").unwrap());
+/// Attribute pairs where the first makes the second meaningless
+const CONTRADICTIONS: &[(&str, &str, &str)] = &[
+    ("ignore", "no_run", "the block is never compiled"),
+    ("ignore", "should_panic", "the block is never run"),
+    ("ignore", "compile_fail", "the block is never compiled"),
+    (
+        "compile_fail",
+        "no_run",
+        "the block is expected not to compile",
+    ),
+    ("compile_fail", "should_panic", "the block is never run"),
+    ("no_run", "should_panic", "the panic is never checked"),
+    (
+        "noplayground",
+        "editable",
+        "there is no playground to edit in",
+    ),
+    ("noplaypen", "editable", "there is no playground to edit in"),
+    (
+        "noplayground",
+        "mdbook-runnable",
+        "the playground is turned off",
+    ),
+    (
+        "noplaypen",
+        "mdbook-runnable",
+        "the playground is turned off",
+    ),
+];
 
 /// MDBOOK016: Validates Rust code block attributes
 ///
@@ -44,16 +71,62 @@ pub struct MDBOOK016;
 
 impl MDBOOK016 {
     /// Parse attributes from a language tag like "rust,ignore,should_panic"
+    ///
+    /// mdBook and rustdoc accept spaces between attributes as well as commas.
     fn parse_attributes<'a>(&self, lang_tag: &'a str) -> Vec<&'a str> {
-        lang_tag.split(',').map(|s| s.trim()).collect()
+        lang_tag
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .collect()
     }
 
     /// Check if a tag indicates this is a Rust code block
     fn is_rust_code_block(&self, lang_tag: &str) -> bool {
-        let first_part = lang_tag.split(',').next().unwrap_or("");
+        let first_part = self
+            .parse_attributes(lang_tag)
+            .first()
+            .copied()
+            .unwrap_or("");
         first_part == "rust" || first_part == "rs"
     }
 
+    /// Messages for attribute combinations where one cancels another
+    fn find_contradictions(&self, attrs: &[&str]) -> Vec<String> {
+        let mut messages: Vec<String> = CONTRADICTIONS
+            .iter()
+            .filter(|(winner, loser, _)| attrs.contains(winner) && attrs.contains(loser))
+            .map(|(winner, loser, reason)| {
+                format!("Attribute '{loser}' has no effect with '{winner}': {reason}")
+            })
+            .collect();
+
+        if attrs.contains(&"mdbook-runnable") && !attrs.contains(&"ignore") {
+            messages.push(
+                "Attribute 'mdbook-runnable' has no effect without 'ignore': \
+                 the block is already runnable"
+                    .to_string(),
+            );
+        }
+
+        let editions: Vec<&str> = attrs
+            .iter()
+            .copied()
+            .filter(|a| a.starts_with("edition"))
+            .collect();
+        if editions.len() > 1 {
+            messages.push(format!(
+                "Conflicting editions {}: only one edition applies",
+                editions
+                    .iter()
+                    .map(|e| format!("'{e}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+
+        messages
+    }
+
     /// Validate a single attribute
     fn validate_attribute(&self, attr: &str) -> Option<String> {
         // Skip empty attributes
@@ -106,7 +179,7 @@ impl MDBOOK016 {
     }
 }
 
-impl Rule for MDBOOK016 {
+impl AstRule for MDBOOK016 {
     fn id(&self) -> &'static str {
         "MDBOOK016"
     }
@@ -123,50 +196,38 @@ impl Rule for MDBOOK016 {
         RuleMetadata::stable(RuleCategory::MdBook).introduced_in("mdbook-lint v0.12.0")
     }
 
-    fn check_with_ast<'a>(
+    fn check_ast<'a>(
         &self,
-        document: &Document,
-        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+        _document: &Document,
+        ast: &'a AstNode<'a>,
     ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
         let mut violations = Vec::new();
-        let mut in_code_block = false;
-
-        for (line_idx, line) in document.lines.iter().enumerate() {
-            let line_num = line_idx + 1;
-            let trimmed = line.trim();
-
-            // Check for code block start
-            if let Some(caps) = CODE_BLOCK_REGEX.captures(trimmed) {
-                if in_code_block {
-                    // This is actually closing a code block
-                    in_code_block = false;
-                    continue;
-                }
 
-                in_code_block = true;
-
-                // Check if there's a language tag
-                if let Some(lang_match) = caps.get(1) {
-                    let lang_tag = lang_match.as_str();
-
-                    // Only validate Rust code blocks
-                    if self.is_rust_code_block(lang_tag) {
-                        let attrs = self.parse_attributes(lang_tag);
-
-                        for attr in attrs {
-                            if let Some(error_msg) = self.validate_attribute(attr) {
-                                violations.push(self.create_violation(
-                                    error_msg,
-                                    line_num,
-                                    1,
-                                    Severity::Warning,
-                                ));
-                            }
-                        }
-                    }
+        for node in ast.descendants() {
+            let data = node.data.borrow();
+            let NodeValue::CodeBlock(block) = &data.value else {
+                continue;
+            };
+            // Only validate fenced Rust code blocks
+            if !block.fenced || !self.is_rust_code_block(&block.info) {
+                continue;
+            }
+            let line_num = data.sourcepos.start.line;
+            let attrs = self.parse_attributes(&block.info);
+
+            for attr in &attrs {
+                if let Some(error_msg) = self.validate_attribute(attr) {
+                    violations.push(self.create_violation(
+                        error_msg,
+                        line_num,
+                        1,
+                        Severity::Warning,
+                    ));
                 }
-            } else if trimmed == "```" || trimmed.starts_with("~~~") {
-                in_code_block = !in_code_block;
+            }
+
+            for message in self.find_contradictions(&attrs) {
+                violations.push(self.create_violation(message, line_num, 1, Severity::Warning));
             }
         }
 
@@ -177,6 +238,7 @@ impl Rule for MDBOOK016 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::rule::Rule;
     use std::path::PathBuf;
 
     fn create_test_document(content: &str) -> Document {
@@ -344,8 +406,14 @@ fn main() {}
         let doc = create_test_document(content);
         let rule = MDBOOK016;
         let violations = rule.check(&doc).unwrap();
-        assert_eq!(violations.len(), 1);
+        // `should_panic` is also cancelled by `ignore`
+        assert_eq!(violations.len(), 2);
         assert!(violations[0].message.contains("badattr"));
+        assert!(
+            violations[1]
+                .message
+                .contains("'should_panic' has no effect")
+        );
     }
 
     #[test]
@@ -376,4 +444,84 @@ fn main() {}
         assert_eq!(violations.len(), 1);
         assert!(violations[0].message.contains("no_run"));
     }
+
+    #[test]
+    fn test_contradictory_attributes() {
+        let content = "# Code
+
+```rust,ignore,no_run
+fn main() {}
+```
+
+```rust no_run should_panic
+fn main() { panic!(); }
+```
+
+```rust,noplayground,editable
+fn main() {}
+```
+";
+        let doc = create_test_document(content);
+        let violations = MDBOOK016.check(&doc).unwrap();
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].line, 3);
+        assert!(
+            violations[0]
+                .message
+                .contains("'no_run' has no effect with 'ignore'")
+        );
+        assert_eq!(violations[1].line, 7);
+        assert!(violations[1].message.contains("panic is never checked"));
+        assert!(violations[2].message.contains("'editable'"));
+    }
+
+    #[test]
+    fn test_mdbook_runnable_and_editions() {
+        let content = "# Code
+
+```rust,ignore,mdbook-runnable
+fn main() {}
+```
+
+```rust,mdbook-runnable
+fn main() {}
+```
+
+```rust,edition2018,edition2021
+fn main() {}
+```
+";
+        let doc = create_test_document(content);
+        let violations = MDBOOK016.check(&doc).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 7);
+        assert!(violations[0].message.contains("without 'ignore'"));
+        assert_eq!(violations[1].line, 11);
+        assert!(
+            violations[1]
+                .message
+                .contains("'edition2018', 'edition2021'")
+        );
+    }
+
+    #[test]
+    fn test_fences_inside_other_blocks_ignored() {
+        let content = "# Code
+
+~~~markdown
+```rust,badattr
+fn main() {}
+```
+~~~
+
+  ```rust,norun
+  fn main() {}
+  ```
+";
+        let doc = create_test_document(content);
+        let violations = MDBOOK016.check(&doc).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 9);
+        assert!(violations[0].message.contains("no_run"));
+    }
 }
@@ -4,7 +4,7 @@
 //! of a chapter file for consistency and to ensure it's processed early.
 
 use mdbook_lint_core::Document;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 use regex::Regex;
 use std::sync::LazyLock;
@@ -72,7 +72,15 @@ impl Rule for MDBOOK022 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::MdBook).introduced_in("mdbook-lint v0.12.0")
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.12.0")
+            .option(
+                "max_line",
+                OptionKind::IntegerAtLeast(1),
+                "Last line at which `{{#title}}` counts as near the top",
+            )
+            .defaults_to("5")
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -2,9 +2,9 @@
 //!
 //! Validates that chapter titles in SUMMARY.md match the H1 headers in the linked files.
 
-use mdbook_lint_core::Document;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
+use mdbook_lint_core::{Document, LintContext};
 use std::path::PathBuf;
 
 /// MDBOOK023: Validates that chapter titles in SUMMARY.md match H1 headers
@@ -142,13 +142,24 @@ impl Rule for MDBOOK023 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::MdBook).introduced_in("mdbook-lint v0.11.0")
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.11.0")
+            .filesystem()
     }
 
     fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        self.check_with_context(document, ast, &LintContext::for_document(document))
+    }
+
+    fn check_with_context<'a>(
         &self,
         document: &Document,
         _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+        context: &LintContext,
     ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
         let mut violations = Vec::new();
 
@@ -158,13 +169,12 @@ impl Rule for MDBOOK023 {
         }
 
         // Determine the source directory
-        let src_dir = self.src_dir.clone().unwrap_or_else(|| {
-            document
-                .path
-                .parent()
-                .map(|p| p.to_path_buf())
-                .unwrap_or_default()
-        });
+        let src_dir = self
+            .src_dir
+            .clone()
+            .or_else(|| context.src_dir().map(|p| p.to_path_buf()))
+            .or_else(|| document.path.parent().map(|p| p.to_path_buf()))
+            .unwrap_or_default();
 
         // Extract all chapter entries from SUMMARY.md
         let chapters = self.extract_chapters(&document.content);
@@ -177,7 +187,7 @@ impl Rule for MDBOOK023 {
             // Try to read the linked file and check H1 header match
             // If file doesn't exist, that's handled by MDBOOK002
             // If no H1 header found, that's handled by other rules (MD041)
-            if let Ok(content) = std::fs::read_to_string(&chapter_path)
+            if let Some(content) = context.read_file(&chapter_path)
                 && let Some(h1_title) = self.extract_h1_header(&content)
                 && !self.titles_match(&chapter.title, &h1_title)
             {
@@ -0,0 +1,320 @@
+//! MDBOOK026: SUMMARY.md chapter ordering and numbering
+//!
+//! Books often number chapter files (`01-intro.md`, `02-setup.md`) so they
+//! sort in reading order on disk. This rule checks that those number
+//! prefixes, as listed in SUMMARY.md, are unique, increasing, and free of
+//! gaps, and flags draft chapters that do not have a file yet.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// A chapter entry: optional list marker, `[title](path)`
+static CHAPTER_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\s*(?:[-*+]\s+)?)\[([^\]]*)\]\(([^)]*)\)").unwrap());
+
+/// A number prefix on a file or directory name, such as `01-` or `2_`
+static NUMBER_PREFIX_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d+)[-_.\s]").unwrap());
+
+/// MDBOOK026: Validates chapter numbering and draft chapters in SUMMARY.md
+///
+/// Number prefixes are compared between chapters whose numbered file or
+/// directory lives in the same directory, in the order SUMMARY.md lists them.
+/// For `guide/README.md` style chapters, the directory name carries the
+/// number.
+#[derive(Clone)]
+pub struct MDBOOK026 {
+    /// Whether a jump of more than one between consecutive prefixes is reported
+    check_gaps: bool,
+    /// Severity for draft chapters; `None` disables the check
+    draft_severity: Option<Severity>,
+}
+
+impl Default for MDBOOK026 {
+    fn default() -> Self {
+        Self {
+            check_gaps: true,
+            draft_severity: Some(Severity::Warning),
+        }
+    }
+}
+
+impl MDBOOK026 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `check_gaps`: report gaps in number prefixes (default true).
+    /// - `draft_severity`: `"info"`, `"warning"` (default), or `"error"` for
+    ///   draft chapters, or `"off"` to allow them.
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+
+        if let Some(check_gaps) = config
+            .get("check_gaps")
+            .or_else(|| config.get("check-gaps"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.check_gaps = check_gaps;
+        }
+
+        if let Some(value) = config
+            .get("draft_severity")
+            .or_else(|| config.get("draft-severity"))
+        {
+            rule.draft_severity = match value.as_str() {
+                Some("off" | "none" | "allow") => None,
+                _ => value
+                    .clone()
+                    .try_into::<Severity>()
+                    .ok()
+                    .or(rule.draft_severity),
+            };
+        }
+
+        rule
+    }
+}
+
+/// A numbered chapter seen earlier in the same directory
+struct Numbered {
+    number: u64,
+    prefix: String,
+    line: usize,
+}
+
+/// Find the path component carrying a number prefix, returning its parent
+/// directory (used to group siblings), the prefix text, and its value
+fn number_prefix(path: &str) -> Option<(String, String, u64)> {
+    let path = path.split(['#', '?']).next().unwrap_or(path);
+    let path = path.trim_start_matches("./");
+    let mut components: Vec<&str> = path.split('/').filter(|c| !c.is_empty()).collect();
+
+    // `02-guide/README.md` and `02-guide/index.md` are numbered by directory
+    if components.len() > 1
+        && components
+            .last()
+            .is_some_and(|f| f.eq_ignore_ascii_case("README.md") || f == &"index.md")
+    {
+        components.pop();
+    }
+
+    let name = components.pop()?;
+    let captures = NUMBER_PREFIX_REGEX.captures(name)?;
+    let prefix = captures.get(1)?.as_str();
+    let number = prefix.parse().ok()?;
+    Some((components.join("/"), prefix.to_string(), number))
+}
+
+impl Rule for MDBOOK026 {
+    fn id(&self) -> &'static str {
+        "MDBOOK026"
+    }
+
+    fn name(&self) -> &'static str {
+        "summary-ordering"
+    }
+
+    fn description(&self) -> &'static str {
+        "SUMMARY.md chapters should be numbered in order and not be drafts"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "check_gaps",
+                OptionKind::Boolean,
+                "Report gaps in number prefixes",
+            )
+            .defaults_to("true")
+            .option(
+                "draft_severity",
+                OptionKind::Choice(&["info", "warning", "error", "off", "none", "allow"]),
+                "Severity for draft chapters",
+            )
+            .defaults_to(r#""warning""#)
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        let is_summary = document
+            .path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name == "SUMMARY.md");
+        if !is_summary {
+            return Ok(violations);
+        }
+
+        // Last numbered chapter per directory, plus every number seen there
+        let mut last: HashMap<String, Numbered> = HashMap::new();
+        let mut seen: HashMap<(String, u64), usize> = HashMap::new();
+
+        for (idx, line) in document.lines.iter().enumerate() {
+            let line_num = idx + 1;
+            let Some(captures) = CHAPTER_REGEX.captures(line) else {
+                continue;
+            };
+            let column = captures[1].chars().count() + 1;
+            let title = &captures[2];
+            let path = captures[3].trim();
+
+            if path.is_empty() {
+                if let Some(severity) = self.draft_severity {
+                    violations.push(self.create_violation(
+                        format!("Draft chapter '{title}' has no file"),
+                        line_num,
+                        column,
+                        severity,
+                    ));
+                }
+                continue;
+            }
+
+            let Some((dir, prefix, number)) = number_prefix(path) else {
+                continue;
+            };
+
+            if let Some(first_line) = seen.get(&(dir.clone(), number)) {
+                violations.push(self.create_violation(
+                    format!(
+                        "Chapter '{title}' reuses number prefix {prefix} (already used on line {first_line})"
+                    ),
+                    line_num,
+                    column,
+                    Severity::Warning,
+                ));
+            } else if let Some(previous) = last.get(&dir) {
+                if number < previous.number {
+                    violations.push(self.create_violation(
+                        format!(
+                            "Chapter '{title}' with number prefix {prefix} is listed after {} (line {}); chapters should be in number order",
+                            previous.prefix, previous.line
+                        ),
+                        line_num,
+                        column,
+                        Severity::Warning,
+                    ));
+                } else if self.check_gaps && number > previous.number + 1 {
+                    violations.push(self.create_violation(
+                        format!(
+                            "Gap in number prefixes: {} is followed by {prefix}",
+                            previous.prefix
+                        ),
+                        line_num,
+                        column,
+                        Severity::Warning,
+                    ));
+                }
+            }
+
+            seen.entry((dir.clone(), number)).or_insert(line_num);
+            last.insert(
+                dir,
+                Numbered {
+                    number,
+                    prefix,
+                    line: line_num,
+                },
+            );
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &MDBOOK026, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("src/SUMMARY.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_ordered_chapters() {
+        let content = "# Summary\n\n[Intro](00-intro.md)\n\n\
+                       - [Setup](01-setup.md)\n\
+                       - [Guide](02-guide/README.md)\n  \
+                         - [Basics](02-guide/01-basics.md)\n  \
+                         - [Advanced](02-guide/02-advanced.md)\n\
+                       - [Reference](03-reference.md)\n\
+                       - [FAQ](faq.md)\n";
+        assert!(check(&MDBOOK026::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_duplicates_order_and_gaps() {
+        let content = "# Summary\n\n\
+                       - [One](01-one.md)\n\
+                       - [Also one](01-also-one.md)\n\
+                       - [Three](03-three.md)\n\
+                       - [Two](02-two.md)\n";
+        let violations = check(&MDBOOK026::default(), content);
+
+        assert_eq!(violations.len(), 3);
+        assert_eq!(violations[0].line, 4);
+        assert!(violations[0].message.contains("reuses number prefix 01"));
+        assert!(violations[0].message.contains("line 3"));
+        assert_eq!(violations[1].line, 5);
+        assert_eq!(
+            violations[1].message,
+            "Gap in number prefixes: 01 is followed by 03"
+        );
+        assert_eq!(violations[2].line, 6);
+        assert!(violations[2].message.contains("listed after 03"));
+        assert_eq!(violations[2].column, 3);
+    }
+
+    #[test]
+    fn test_directories_numbered_independently() {
+        let content = "- [A](a/01-x.md)\n- [B](b/01-y.md)\n- [A2](a/02-z.md)\n";
+        assert!(check(&MDBOOK026::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_draft_chapters() {
+        let content = "# Summary\n\n- [Done](done.md)\n- [Coming soon]()\n";
+        let violations = check(&MDBOOK026::default(), content);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Draft chapter 'Coming soon' has no file"
+        );
+        assert_eq!(violations[0].severity, Severity::Warning);
+
+        let config: toml::Value = toml::from_str("draft-severity = \"info\"").unwrap();
+        let violations = check(&MDBOOK026::from_config(&config), content);
+        assert_eq!(violations[0].severity, Severity::Info);
+
+        let config: toml::Value = toml::from_str("draft_severity = \"off\"").unwrap();
+        assert!(check(&MDBOOK026::from_config(&config), content).is_empty());
+    }
+
+    #[test]
+    fn test_gaps_can_be_allowed() {
+        let content = "- [One](01-one.md)\n- [Five](05-five.md)\n";
+        let config: toml::Value = toml::from_str("check-gaps = false").unwrap();
+        assert!(check(&MDBOOK026::from_config(&config), content).is_empty());
+        assert_eq!(check(&MDBOOK026::default(), content).len(), 1);
+    }
+
+    #[test]
+    fn test_non_summary_files_ignored() {
+        let doc =
+            Document::new("- [Later]()\n".to_string(), PathBuf::from("src/chapter.md")).unwrap();
+        assert!(MDBOOK026::default().check(&doc).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+//! MDBOOK027: Malformed mdBook directives
+//!
+//! mdBook only expands `{{#...}}` directives it recognizes. A typo such as
+//! `{{#includ file.rs}}`, a missing `#`, or a missing closing `}}` is passed
+//! through untouched and shows up literally in the rendered book, which is
+//! easy to miss in review.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::edit_distance::levenshtein;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Directives built into mdBook, plus the widely used `template` preprocessor
+const KNOWN_DIRECTIVES: &[&str] = &[
+    "include",
+    "rustdoc_include",
+    "playground",
+    "title",
+    "template",
+];
+
+/// An opening directive: `{{#name`
+static DIRECTIVE_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{#([A-Za-z_][\w-]*)?").unwrap());
+
+/// A known directive name written without `#`: `{{include file.rs}}`
+static MISSING_HASH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\{\{\s*(include|rustdoc_include|playground|title)\s+[^}]*\}\}").unwrap()
+});
+
+/// MDBOOK027: Detects misspelled, unknown, and unclosed mdBook directives
+///
+/// Inside code blocks and code spans only near-misses of known directives are
+/// reported, since Handlebars examples like `{{#each items}}` are common in
+/// documentation.
+#[derive(Clone)]
+pub struct MDBOOK027 {
+    /// Directive names accepted in addition to the built-in ones
+    known: Vec<String>,
+}
+
+impl Default for MDBOOK027 {
+    fn default() -> Self {
+        Self {
+            known: KNOWN_DIRECTIVES.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+}
+
+impl MDBOOK027 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `known_directives`: extra directive names provided by third-party
+    ///   preprocessors (for example `["quiz", "tabs"]`).
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+        if let Some(extra) = config
+            .get("known_directives")
+            .or_else(|| config.get("known-directives"))
+            .and_then(|v| v.as_array())
+        {
+            rule.known
+                .extend(extra.iter().filter_map(|v| v.as_str()).map(String::from));
+        }
+        rule
+    }
+
+    /// The closest known directive within a small edit distance
+    fn suggestion(&self, name: &str) -> Option<&str> {
+        self.known
+            .iter()
+            .map(|known| (known, levenshtein(name, known)))
+            .filter(|(known, distance)| *distance > 0 && *distance <= 2.min(known.len() / 3 + 1))
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(known, _)| known.as_str())
+    }
+}
+
+impl Rule for MDBOOK027 {
+    fn id(&self) -> &'static str {
+        "MDBOOK027"
+    }
+
+    fn name(&self) -> &'static str {
+        "directive-syntax"
+    }
+
+    fn description(&self) -> &'static str {
+        "mdBook directives should be well-formed and known"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "known_directives",
+                OptionKind::StringList,
+                "Extra directive names provided by third-party preprocessors",
+            )
+            .defaults_to("[]")
+            .either_case()
+    }
+
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        let index = document.index();
+
+        for (idx, line) in document.lines.iter().enumerate() {
+            let line_num = idx + 1;
+            let in_code = |pos: usize| index.is_in_code(line_num, pos);
+            let column_of = |pos: usize| line[..pos].chars().count() + 1;
+
+            for m in DIRECTIVE_REGEX.captures_iter(line) {
+                let whole = m.get(0).unwrap();
+                let start = whole.start();
+                if line[..start].ends_with('\\') {
+                    continue;
+                }
+
+                let Some(name) = m.get(1) else {
+                    if !in_code(start) {
+                        violations.push(self.create_violation(
+                            "Directive '{{#' is missing a name".to_string(),
+                            line_num,
+                            column_of(start),
+                            Severity::Warning,
+                        ));
+                    }
+                    continue;
+                };
+
+                if !self.known.iter().any(|k| k == name.as_str()) {
+                    if let Some(suggestion) = self.suggestion(name.as_str()) {
+                        let column = column_of(name.start());
+                        let fix = Fix {
+                            description: format!("Replace '{}' with '{suggestion}'", name.as_str()),
+                            replacement: Some(suggestion.to_string()),
+                            start: Position {
+                                line: line_num,
+                                column,
+                            },
+                            end: Position {
+                                line: line_num,
+                                column: column + name.as_str().chars().count(),
+                            },
+                            safety: FixSafety::Suggestion,
+                        };
+                        violations.push(self.create_violation_with_fix(
+                            format!(
+                                "Unknown directive '{{{{#{}}}}}', did you mean '{{{{#{suggestion}}}}}'?",
+                                name.as_str()
+                            ),
+                            line_num,
+                            column_of(start),
+                            Severity::Warning,
+                            fix,
+                        ));
+                    } else if !in_code(start) {
+                        violations.push(self.create_violation(
+                            format!(
+                                "Unknown directive '{{{{#{}}}}}' will be rendered literally",
+                                name.as_str()
+                            ),
+                            line_num,
+                            column_of(start),
+                            Severity::Warning,
+                        ));
+                    }
+                }
+
+                // The directive must be closed on the same line, before the next one opens
+                let rest = &line[whole.end()..];
+                let closed = match (rest.find("}}"), rest.find("{{")) {
+                    (Some(close), Some(open)) => close < open,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                };
+                if !closed && !in_code(start) {
+                    violations.push(self.create_violation(
+                        format!(
+                            "Directive '{{{{#{}' is missing its closing '}}}}'",
+                            name.as_str()
+                        ),
+                        line_num,
+                        column_of(start),
+                        Severity::Error,
+                    ));
+                }
+            }
+
+            for m in MISSING_HASH_REGEX.captures_iter(line) {
+                let whole = m.get(0).unwrap();
+                if in_code(whole.start()) || line[..whole.start()].ends_with('\\') {
+                    continue;
+                }
+                violations.push(self.create_violation(
+                    format!(
+                        "Directive '{}' is missing '#' after '{{{{' and will be rendered literally",
+                        &m[1]
+                    ),
+                    line_num,
+                    column_of(whole.start()),
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &MDBOOK027, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_valid_directives() {
+        let content = "{{#title My Chapter}}\n\n# Chapter\n\n\
+                       {{#include file.rs}} and {{#rustdoc_include lib.rs:2:5}}\n\n\
+                       {{#playground example.rs editable}}\n";
+        assert!(check(&MDBOOK027::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_misspelled_directive_with_fix() {
+        let violations = check(&MDBOOK027::default(), "Text\n\n{{#includ file.rs}}\n");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Unknown directive '{{#includ}}', did you mean '{{#include}}'?"
+        );
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("include"));
+        assert_eq!((fix.start.column, fix.end.column), (4, 10));
+    }
+
+    #[test]
+    fn test_unknown_directive() {
+        let violations = check(&MDBOOK027::default(), "{{#quiz quiz.toml}}\n");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("rendered literally"));
+
+        let config: toml::Value = toml::from_str("known-directives = [\"quiz\"]").unwrap();
+        assert!(check(&MDBOOK027::from_config(&config), "{{#quiz quiz.toml}}\n").is_empty());
+    }
+
+    #[test]
+    fn test_unclosed_and_missing_hash() {
+        let violations = check(
+            &MDBOOK027::default(),
+            "{{#include file.rs}\n\nSee {{include file.rs}}.\n",
+        );
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert!(violations[0].message.contains("missing its closing"));
+        assert_eq!(violations[1].line, 3);
+        assert_eq!(violations[1].column, 5);
+        assert!(violations[1].message.contains("missing '#'"));
+    }
+
+    #[test]
+    fn test_code_only_reports_near_misses() {
+        let content = "Use `{{#each items}}` in templates.\n\n\
+                       ```handlebars\n{{#if cond}}\n{{#each items}}\n{{/each}}\n```\n\n\
+                       ```rust\n{{#inlcude main.rs}}\n```\n";
+        let violations = check(&MDBOOK027::default(), content);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 10);
+        assert!(
+            violations[0]
+                .message
+                .contains("did you mean '{{#include}}'")
+        );
+    }
+
+    #[test]
+    fn test_escaped_directives_ignored() {
+        let content = "Write \\{{#includ file.rs}} or \\{{include x}} literally.\n";
+        assert!(check(&MDBOOK027::default(), content).is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+//! MDBOOK028: Heading IDs should be unique within a chapter
+//!
+//! mdBook gives every heading an HTML `id` derived from its text, or taken
+//! from an explicit `{#id}` attribute. When two headings produce the same ID,
+//! mdBook silently renames the later one (`setup`, `setup-1`, ...) or emits
+//! the same ID twice, and links to the anchor land on the wrong heading.
+
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::outline::{heading_text, mdbook_slug};
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// A trailing heading attribute block: `{#custom-id .class}`
+static HEADING_ATTRIBUTES_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*\{[^{}]*#([\w-]+)[^{}]*\}\s*$").unwrap());
+
+/// MDBOOK028: Detects headings whose generated or explicit IDs collide
+pub struct MDBOOK028;
+
+/// A heading and the ID mdBook assigns to it
+struct HeadingId {
+    line: usize,
+    column: usize,
+    /// ID derived from the text, before mdBook's duplicate numbering
+    base: String,
+    /// Explicit `{#id}` attribute, if any
+    explicit: Option<String>,
+}
+
+impl MDBOOK028 {
+    fn collect_headings<'a>(node: &'a AstNode<'a>, headings: &mut Vec<HeadingId>) {
+        if let NodeValue::Heading(_) = node.data.borrow().value {
+            let pos = node.data.borrow().sourcepos;
+            let text = heading_text(node);
+            let (text, explicit) = match HEADING_ATTRIBUTES_REGEX.captures(&text) {
+                Some(caps) => (
+                    text[..caps.get(0).unwrap().start()].to_string(),
+                    Some(caps[1].to_string()),
+                ),
+                None => (text, None),
+            };
+            headings.push(HeadingId {
+                line: pos.start.line,
+                column: pos.start.column,
+                base: mdbook_slug(&text),
+                explicit,
+            });
+            return;
+        }
+        for child in node.children() {
+            Self::collect_headings(child, headings);
+        }
+    }
+}
+
+impl AstRule for MDBOOK028 {
+    fn id(&self) -> &'static str {
+        "MDBOOK028"
+    }
+
+    fn name(&self) -> &'static str {
+        "heading-id-uniqueness"
+    }
+
+    fn description(&self) -> &'static str {
+        "Heading IDs should be unique within a chapter"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_ast<'a>(&self, _document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        let mut headings = Vec::new();
+        Self::collect_headings(ast, &mut headings);
+
+        // Explicit IDs are used verbatim and claimed first
+        let mut claimed: HashMap<String, usize> = HashMap::new();
+        for heading in &headings {
+            let Some(id) = &heading.explicit else {
+                continue;
+            };
+            match claimed.get(id) {
+                Some(first) => violations.push(self.create_violation(
+                    format!("Heading ID '{id}' is already set on the heading at line {first}"),
+                    heading.line,
+                    heading.column,
+                    Severity::Warning,
+                )),
+                None => {
+                    claimed.insert(id.clone(), heading.line);
+                }
+            }
+        }
+
+        // Generated IDs follow mdBook's numbering: `id`, `id-1`, `id-2`, ...
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        let mut first_with_base: HashMap<&str, usize> = HashMap::new();
+        for heading in headings.iter().filter(|h| h.explicit.is_none()) {
+            if heading.base.is_empty() {
+                continue;
+            }
+
+            let count = counts.entry(&heading.base).or_insert(0);
+            let id = if *count == 0 {
+                heading.base.clone()
+            } else {
+                format!("{}-{count}", heading.base)
+            };
+            *count += 1;
+
+            if let Some(first) = first_with_base.get(heading.base.as_str()) {
+                violations.push(self.create_violation(
+                    format!(
+                        "Duplicate heading ID '{}' (first used at line {first}); mdBook renames this one to '{id}', so links to '#{}' never reach it",
+                        heading.base, heading.base
+                    ),
+                    heading.line,
+                    heading.column,
+                    Severity::Warning,
+                ));
+            } else {
+                first_with_base.insert(&heading.base, heading.line);
+            }
+
+            if let Some(other) = claimed.get(&id) {
+                violations.push(self.create_violation(
+                    format!("Heading ID '{id}' collides with the heading at line {other}"),
+                    heading.line,
+                    heading.column,
+                    Severity::Warning,
+                ));
+            } else {
+                claimed.insert(id, heading.line);
+            }
+        }
+
+        violations.sort_by_key(|v| (v.line, v.column));
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        MDBOOK028.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_unique_headings() {
+        let content = "# Guide\n\n## Install\n\n## Configure `mdbook`\n\n## Usage {#how-to}\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_generated_ids() {
+        let content = "# Guide\n\n## Setup\n\nText\n\n## Setup!\n";
+        let violations = check(content);
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 7);
+        assert!(
+            violations[0]
+                .message
+                .contains("Duplicate heading ID 'setup'")
+        );
+        assert!(
+            violations[0]
+                .message
+                .contains("renames this one to 'setup-1'")
+        );
+    }
+
+    #[test]
+    fn test_explicit_id_collisions() {
+        let content = "# Guide {#intro}\n\n## Intro\n\n## Overview {#intro}\n";
+        let violations = check(content);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(
+            violations[0].message,
+            "Heading ID 'intro' collides with the heading at line 1"
+        );
+        assert_eq!(violations[1].line, 5);
+        assert!(violations[1].message.contains("already set"));
+    }
+
+    #[test]
+    fn test_numbered_id_collision() {
+        // The second "Step" becomes "step-1", which a "Step 1" heading already uses
+        let content = "## Step 1\n\n## Step\n\n## Step\n";
+        let violations = check(content);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 5);
+        assert!(
+            violations
+                .iter()
+                .any(|v| v.message == "Heading ID 'step-1' collides with the heading at line 1")
+        );
+    }
+
+    #[test]
+    fn test_ids_match_mdbook() {
+        // mdBook only lowercases ASCII letters and keeps outer hyphens, so
+        // every one of these headings gets its own ID
+        let content = "# Über\n\n## über\n\n## -Foo-\n\n## Foo\n";
+        assert!(check(content).is_empty());
+
+        let violations = check("## C++ & Rust\n\n## C & Rust\n");
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0]
+                .message
+                .contains("Duplicate heading ID 'c--rust'")
+        );
+    }
+}
@@ -0,0 +1,383 @@
+//! MDBOOK029: Code block languages should be on an allowlist
+//!
+//! mdBook highlights code with a bundled highlight.js that only knows a fixed
+//! set of languages. A misspelled tag such as `rsut` or `shell-session` is not
+//! an error anywhere: the block is silently rendered without highlighting, and
+//! `mdbook test` skips Rust blocks whose tag it does not recognize.
+
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::edit_distance::typo_distance;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+
+/// Languages bundled with mdBook's highlight.js, with their aliases, plus
+/// tags commonly used for unhighlighted output
+const DEFAULT_LANGUAGES: &[&str] = &[
+    "apache",
+    "apacheconf",
+    "armasm",
+    "arm",
+    "bash",
+    "sh",
+    "zsh",
+    "c",
+    "h",
+    "coffeescript",
+    "coffee",
+    "cpp",
+    "cc",
+    "c++",
+    "hpp",
+    "cxx",
+    "csharp",
+    "cs",
+    "css",
+    "d",
+    "diff",
+    "patch",
+    "go",
+    "golang",
+    "handlebars",
+    "hbs",
+    "haskell",
+    "hs",
+    "http",
+    "ini",
+    "toml",
+    "java",
+    "javascript",
+    "js",
+    "jsx",
+    "json",
+    "julia",
+    "kotlin",
+    "kt",
+    "less",
+    "lua",
+    "makefile",
+    "make",
+    "markdown",
+    "md",
+    "nginx",
+    "nim",
+    "nix",
+    "objectivec",
+    "objc",
+    "perl",
+    "pl",
+    "php",
+    "plaintext",
+    "text",
+    "txt",
+    "properties",
+    "python",
+    "py",
+    "r",
+    "ruby",
+    "rb",
+    "rust",
+    "rs",
+    "scala",
+    "scss",
+    "shell",
+    "console",
+    "shellsession",
+    "sql",
+    "swift",
+    "typescript",
+    "ts",
+    "x86asm",
+    "xml",
+    "html",
+    "svg",
+    "yaml",
+    "yml",
+];
+
+/// rustdoc attributes that mark an otherwise untagged block as Rust
+const RUST_ATTRIBUTES: &[&str] = &[
+    "ignore",
+    "should_panic",
+    "no_run",
+    "compile_fail",
+    "edition2015",
+    "edition2018",
+    "edition2021",
+    "edition2024",
+    "editable",
+    "noplayground",
+];
+
+/// MDBOOK029: Detects fenced code blocks whose language is not allowed
+///
+/// Only the first word of the info string is checked, so attributes such as
+/// `rust,ignore` or `console title="Output"` are left alone. Blocks without a
+/// language are MD040's concern and are not reported.
+#[derive(Clone)]
+pub struct MDBOOK029 {
+    /// Allowed languages, lowercased
+    languages: Vec<String>,
+}
+
+impl Default for MDBOOK029 {
+    fn default() -> Self {
+        Self {
+            languages: DEFAULT_LANGUAGES.iter().map(|l| l.to_string()).collect(),
+        }
+    }
+}
+
+impl MDBOOK029 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `allowed_languages`: replaces the built-in list
+    /// - `additional_languages`: adds to the list (for example `["mermaid"]`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let list = |key: &str| {
+            config
+                .get(key)
+                .or_else(|| config.get(key.replace('_', "-")))
+                .and_then(|v| v.as_array())
+                .map(|values| {
+                    values
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(str::to_lowercase)
+                        .collect::<Vec<_>>()
+                })
+        };
+
+        let mut rule = Self::default();
+        if let Some(allowed) = list("allowed_languages") {
+            rule.languages = allowed;
+        }
+        if let Some(additional) = list("additional_languages") {
+            rule.languages.extend(additional);
+        }
+        rule
+    }
+
+    fn is_allowed(&self, language: &str) -> bool {
+        let language = language.to_lowercase();
+        self.languages.contains(&language)
+            || (RUST_ATTRIBUTES.contains(&language.as_str())
+                && self.languages.iter().any(|l| l == "rust"))
+    }
+
+    /// The closest allowed language, if the tag looks like a typo of one
+    fn suggestion(&self, language: &str) -> Option<&str> {
+        let language = language.to_lowercase();
+        self.languages
+            .iter()
+            .map(|allowed| (allowed, typo_distance(&language, allowed)))
+            .filter(|(allowed, distance)| {
+                let max = match allowed.chars().count() {
+                    0..=2 => 0,
+                    3..=5 => 1,
+                    _ => 2,
+                };
+                *distance > 0 && *distance <= max
+            })
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(allowed, _)| allowed.as_str())
+    }
+}
+
+/// The language tag of a fenced code block's info string
+fn language_of(info: &str) -> Option<&str> {
+    let language = info.split(|c: char| c == ',' || c.is_whitespace()).next()?;
+    // Pandoc-style `{.rust}` attributes are not a language tag
+    (!language.is_empty() && !language.starts_with('{')).then_some(language)
+}
+
+impl AstRule for MDBOOK029 {
+    fn id(&self) -> &'static str {
+        "MDBOOK029"
+    }
+
+    fn name(&self) -> &'static str {
+        "code-block-languages"
+    }
+
+    fn description(&self) -> &'static str {
+        "Code block languages should be on the allowlist"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "allowed_languages",
+                OptionKind::StringList,
+                "Languages to allow, replacing the built-in list",
+            )
+            .option(
+                "additional_languages",
+                OptionKind::StringList,
+                "Languages to add to the built-in list",
+            )
+            .defaults_to("[]")
+            .either_case()
+    }
+
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for node in ast.descendants() {
+            let data = node.data.borrow();
+            let NodeValue::CodeBlock(block) = &data.value else {
+                continue;
+            };
+            if !block.fenced {
+                continue;
+            }
+            let Some(language) = language_of(&block.info) else {
+                continue;
+            };
+            if self.is_allowed(language) {
+                continue;
+            }
+
+            let line_num = data.sourcepos.start.line;
+            let line = document
+                .lines
+                .get(line_num - 1)
+                .map(String::as_str)
+                .unwrap_or("");
+            // The tag follows the fence, which starts at the node's column
+            let fence_start = data.sourcepos.start.column.saturating_sub(1);
+            let column = line
+                .get(fence_start..)
+                .and_then(|fence| fence.find(language))
+                .map(|offset| line[..fence_start + offset].chars().count() + 1);
+
+            match (self.suggestion(language), column) {
+                (Some(suggestion), Some(column)) => {
+                    let fix = Fix {
+                        description: format!("Replace '{language}' with '{suggestion}'"),
+                        replacement: Some(suggestion.to_string()),
+                        start: Position {
+                            line: line_num,
+                            column,
+                        },
+                        end: Position {
+                            line: line_num,
+                            column: column + language.chars().count(),
+                        },
+                        safety: FixSafety::Suggestion,
+                    };
+                    violations.push(self.create_violation_with_fix(
+                        format!(
+                            "Unknown code block language '{language}', did you mean '{suggestion}'?"
+                        ),
+                        line_num,
+                        column,
+                        Severity::Warning,
+                        fix,
+                    ));
+                }
+                (suggestion, column) => {
+                    let message = match suggestion {
+                        Some(suggestion) => format!(
+                            "Unknown code block language '{language}', did you mean '{suggestion}'?"
+                        ),
+                        None => {
+                            format!("Code block language '{language}' is not in the allowed list")
+                        }
+                    };
+                    violations.push(self.create_violation(
+                        message,
+                        line_num,
+                        column.unwrap_or(1),
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(rule: &MDBOOK029, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_known_languages() {
+        let content = "```rust,ignore\nfn main() {}\n```\n\n\
+                       ```Bash\nls\n```\n\n\
+                       ~~~toml\n[book]\n~~~\n\n\
+                       ```should_panic\npanic!()\n```\n\n\
+                       ```\nplain\n```\n\n    indented\n";
+        assert!(check(&MDBOOK029::default(), content).is_empty());
+    }
+
+    #[test]
+    fn test_typo_with_fix() {
+        let violations = check(
+            &MDBOOK029::default(),
+            "Text\n\n```rsut\nfn main() {}\n```\n",
+        );
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Unknown code block language 'rsut', did you mean 'rust'?"
+        );
+        assert_eq!((violations[0].line, violations[0].column), (3, 4));
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("rust"));
+        assert_eq!((fix.start.column, fix.end.column), (4, 8));
+        assert_eq!(fix.safety, FixSafety::Suggestion);
+    }
+
+    #[test]
+    fn test_nested_fence_column() {
+        let violations = check(
+            &MDBOOK029::default(),
+            "- Item\n\n  ```shell-session\n  $ ls\n  ```\n",
+        );
+
+        assert_eq!(violations.len(), 1);
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("shellsession"));
+        assert_eq!((fix.start.line, fix.start.column), (3, 6));
+    }
+
+    #[test]
+    fn test_unknown_language_without_suggestion() {
+        let violations = check(&MDBOOK029::default(), "```mermaid\ngraph TD\n```\n");
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_none());
+        assert!(violations[0].message.contains("not in the allowed list"));
+    }
+
+    #[test]
+    fn test_config() {
+        let config: toml::Value = toml::from_str("additional-languages = [\"Mermaid\"]").unwrap();
+        let rule = MDBOOK029::from_config(&config);
+        assert!(check(&rule, "```mermaid\ngraph TD\n```\n").is_empty());
+
+        let config: toml::Value =
+            toml::from_str("allowed_languages = [\"rust\", \"text\"]").unwrap();
+        let rule = MDBOOK029::from_config(&config);
+        let violations = check(&rule, "```rust\n```\n\n```bash\nls\n```\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 4);
+    }
+}
@@ -0,0 +1,371 @@
+//! MDBOOK030: Images should exist and have meaningful alt text
+//!
+//! mdBook copies everything under `src/` into the output, but it does not
+//! check that an image referenced from a chapter is there: a missing file is
+//! only noticed as a broken image in the browser. MD045 checks that alt text
+//! is present; this rule also checks that it says something, since alt text
+//! such as `img` or `diagram.png` is no help to a screen reader.
+
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Default minimum alt text length, in characters
+const DEFAULT_MIN_ALT_LENGTH: usize = 3;
+
+/// Extensions that make alt text look like a file name
+const IMAGE_EXTENSIONS: &[&str] = &[
+    "png", "jpg", "jpeg", "gif", "svg", "webp", "avif", "bmp", "ico",
+];
+
+/// An `<img>` tag in raw HTML
+static IMG_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?is)<img\b[^>]*>").unwrap());
+
+/// An attribute inside an `<img>` tag
+static ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)\b(src|alt)\s*=\s*(?:"([^"]*)"|'([^']*)'|([^\s>]+))"#).unwrap()
+});
+
+/// MDBOOK030: Detects missing image files and unhelpful alt text
+///
+/// Local image paths are resolved relative to the chapter, and paths that
+/// start with `/` relative to the book's `src/` directory. A relative path
+/// that is not found next to the chapter is also looked up from `src/`. Both
+/// Markdown images and `<img>` tags in raw HTML are checked. Empty alt text
+/// is left to MD045.
+#[derive(Clone)]
+pub struct MDBOOK030 {
+    /// Minimum alt text length; 0 disables the check
+    min_alt_length: usize,
+    /// Flag alt text that is just the image's file name
+    check_filename_alt: bool,
+}
+
+impl Default for MDBOOK030 {
+    fn default() -> Self {
+        Self {
+            min_alt_length: DEFAULT_MIN_ALT_LENGTH,
+            check_filename_alt: true,
+        }
+    }
+}
+
+/// An image referenced by a Markdown image or an `<img>` tag
+pub(super) struct Image {
+    pub(super) url: String,
+    pub(super) alt: String,
+    pub(super) line: usize,
+    pub(super) column: usize,
+}
+
+impl MDBOOK030 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `min_alt_length`: minimum alt text length in characters (0 disables)
+    /// - `check_filename_alt`: flag alt text that is the file name (default true)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let get = |key: &str| {
+            config
+                .get(key)
+                .or_else(|| config.get(key.replace('_', "-")))
+        };
+
+        let mut rule = Self::default();
+        if let Some(length) = get("min_alt_length").and_then(|v| v.as_integer()) {
+            rule.min_alt_length = length.max(0) as usize;
+        }
+        if let Some(check) = get("check_filename_alt").and_then(|v| v.as_bool()) {
+            rule.check_filename_alt = check;
+        }
+        rule
+    }
+
+    fn check_image(&self, document: &Document, image: &Image, violations: &mut Vec<Violation>) {
+        let Some(asset) = document.asset(&image.url) else {
+            return;
+        };
+
+        if !asset.exists() {
+            violations.push(self.create_violation(
+                format!("Image file not found: '{}'", image.url),
+                image.line,
+                image.column,
+                Severity::Error,
+            ));
+        }
+
+        let alt = image.alt.trim();
+        if alt.is_empty() {
+            return;
+        }
+        if self.check_filename_alt && is_filename_alt(alt, asset.reference()) {
+            violations.push(self.create_violation(
+                format!("Alt text '{alt}' is the image file name; describe the image instead"),
+                image.line,
+                image.column,
+                Severity::Warning,
+            ));
+        } else if alt.chars().count() < self.min_alt_length {
+            violations.push(self.create_violation(
+                format!(
+                    "Alt text '{alt}' is too short ({} characters, minimum {})",
+                    alt.chars().count(),
+                    self.min_alt_length
+                ),
+                image.line,
+                image.column,
+                Severity::Warning,
+            ));
+        }
+    }
+}
+
+/// Whether alt text is the image's file name, with or without extension
+fn is_filename_alt(alt: &str, path: &str) -> bool {
+    let normalize = |s: &str| {
+        s.to_lowercase()
+            .replace(['-', '_'], " ")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+    };
+    let alt_path = Path::new(alt);
+    let has_image_extension = alt_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| IMAGE_EXTENSIONS.contains(&e.to_lowercase().as_str()))
+        && !alt.contains(' ');
+    if has_image_extension {
+        return true;
+    }
+
+    let path = Path::new(path);
+    let alt = normalize(alt);
+    [path.file_name(), path.file_stem()]
+        .into_iter()
+        .flatten()
+        .any(|name| normalize(&name.to_string_lossy()) == alt)
+}
+
+/// Plain text of an image's description
+fn alt_text<'a>(node: &'a AstNode<'a>) -> String {
+    let mut text = String::new();
+    for child in node.descendants().skip(1) {
+        match &child.data.borrow().value {
+            NodeValue::Text(t) => text.push_str(t),
+            NodeValue::Code(code) => text.push_str(&code.literal),
+            NodeValue::SoftBreak | NodeValue::LineBreak => text.push(' '),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// `<img>` tags in a raw HTML node starting at `line`
+fn html_images(html: &str, line: usize, column: usize) -> Vec<Image> {
+    IMG_TAG
+        .find_iter(html)
+        .filter_map(|tag| {
+            let mut url = None;
+            let mut alt = String::new();
+            for attr in ATTRIBUTE.captures_iter(tag.as_str()) {
+                let value = attr
+                    .get(2)
+                    .or_else(|| attr.get(3))
+                    .or_else(|| attr.get(4))
+                    .map_or("", |m| m.as_str())
+                    .to_string();
+                if attr[1].eq_ignore_ascii_case("src") {
+                    url = Some(value);
+                } else {
+                    alt = value;
+                }
+            }
+
+            let before = &html[..tag.start()];
+            let offset = before.matches('\n').count();
+            let column = match before.rfind('\n') {
+                Some(newline) => before[newline + 1..].chars().count() + 1,
+                None => column + before.chars().count(),
+            };
+            Some(Image {
+                url: url?,
+                alt,
+                line: line + offset,
+                column,
+            })
+        })
+        .collect()
+}
+
+/// Markdown images and `<img>` tags in raw HTML, in document order
+pub(super) fn images<'a>(ast: &'a AstNode<'a>) -> Vec<Image> {
+    let mut images = Vec::new();
+    for node in ast.descendants() {
+        let data = node.data.borrow();
+        let (line, column) = (data.sourcepos.start.line, data.sourcepos.start.column);
+        match &data.value {
+            NodeValue::Image(link) => images.push(Image {
+                url: link.url.clone(),
+                alt: alt_text(node),
+                line,
+                column,
+            }),
+            NodeValue::HtmlBlock(html) => images.extend(html_images(&html.literal, line, column)),
+            NodeValue::HtmlInline(html) => images.extend(html_images(html, line, column)),
+            _ => {}
+        }
+    }
+    images
+}
+
+impl AstRule for MDBOOK030 {
+    fn id(&self) -> &'static str {
+        "MDBOOK030"
+    }
+
+    fn name(&self) -> &'static str {
+        "image-validation"
+    }
+
+    fn description(&self) -> &'static str {
+        "Images should exist and have meaningful alt text"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "min_alt_length",
+                OptionKind::IntegerAtLeast(0),
+                "Minimum alt text length in characters (0 disables)",
+            )
+            .defaults_to("3")
+            .option(
+                "check_filename_alt",
+                OptionKind::Boolean,
+                "Flag alt text that is the file name",
+            )
+            .defaults_to("true")
+            .either_case()
+    }
+
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for image in images(ast) {
+            self.check_image(document, &image, &mut violations);
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// A book with `src/SUMMARY.md`, `src/images/logo.png`, and
+    /// `src/guide/local.png`
+    fn book() -> TempDir {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("images")).unwrap();
+        fs::create_dir_all(src.join("guide")).unwrap();
+        fs::write(src.join("SUMMARY.md"), "# Summary\n").unwrap();
+        fs::write(src.join("images/logo.png"), "").unwrap();
+        fs::write(src.join("images/my diagram.svg"), "").unwrap();
+        fs::write(src.join("guide/local.png"), "").unwrap();
+        dir
+    }
+
+    fn check(rule: &MDBOOK030, book: &TempDir, chapter: &str, content: &str) -> Vec<Violation> {
+        let path = book.path().join("src").join(chapter);
+        let doc = Document::new(content.to_string(), path).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_existing_images() {
+        let book = book();
+        let content = "# Guide\n\n\
+                       ![Local screenshot](local.png)\n\n\
+                       ![Project logo](../images/logo.png \"Logo\")\n\n\
+                       ![Project logo](/images/logo.png)\n\n\
+                       ![Project logo](images/logo.png)\n\n\
+                       ![Architecture](../images/my%20diagram.svg#layer)\n\n\
+                       ![Badge](https://example.com/badge.svg)\n\n\
+                       ![Pixel](data:image/png;base64,AAAA)\n";
+        assert!(check(&MDBOOK030::default(), &book, "guide/setup.md", content).is_empty());
+    }
+
+    #[test]
+    fn test_missing_images() {
+        let book = book();
+        let content = "# Guide\n\n![Project logo](../images/missing.png)\n\n\
+                       <p align=\"center\">\n  <img src=\"/images/gone.svg\" alt=\"Diagram of the flow\">\n</p>\n";
+        let violations = check(&MDBOOK030::default(), &book, "guide/setup.md", content);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[0].message,
+            "Image file not found: '../images/missing.png'"
+        );
+        assert_eq!((violations[0].line, violations[0].column), (3, 1));
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert_eq!(
+            violations[1].message,
+            "Image file not found: '/images/gone.svg'"
+        );
+        assert_eq!((violations[1].line, violations[1].column), (6, 3));
+    }
+
+    #[test]
+    fn test_alt_text_quality() {
+        let book = book();
+        let content = "![logo](/images/logo.png)\n\n\
+                       ![logo.png](/images/logo.png)\n\n\
+                       ![UI](/images/logo.png)\n\n\
+                       ![My_Diagram](/images/my%20diagram.svg)\n\n\
+                       ![](/images/logo.png)\n\n\
+                       <img src=\"/images/logo.png\" alt=\"screenshot.PNG\">\n";
+        let violations = check(&MDBOOK030::default(), &book, "intro.md", content);
+
+        let lines: Vec<usize> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![1, 3, 5, 7, 11]);
+        assert!(violations[0].message.contains("is the image file name"));
+        assert_eq!(
+            violations[2].message,
+            "Alt text 'UI' is too short (2 characters, minimum 3)"
+        );
+    }
+
+    #[test]
+    fn test_config() {
+        let book = book();
+        let config: toml::Value =
+            toml::from_str("min-alt-length = 10\ncheck-filename-alt = false").unwrap();
+        let rule = MDBOOK030::from_config(&config);
+
+        let violations = check(
+            &rule,
+            &book,
+            "intro.md",
+            "![logo](/images/logo.png)\n\n![The project logo](/images/logo.png)\n",
+        );
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "Alt text 'logo' is too short (4 characters, minimum 10)"
+        );
+    }
+}
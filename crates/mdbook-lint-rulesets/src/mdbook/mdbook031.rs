@@ -0,0 +1,383 @@
+//! MDBOOK031: Images should stay within a size budget
+//!
+//! A screenshot saved at full resolution can easily weigh several megabytes,
+//! and every reader downloads it with the chapter. This rule reports local
+//! images that are larger than a configured number of bytes or pixels. It is
+//! opt-in: nothing is checked until at least one limit is configured.
+
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+use super::mdbook030::images;
+
+/// How much of a file is read to find its dimensions; JPEG metadata can push
+/// the frame header well past the start of the file
+const HEADER_LIMIT: usize = 256 * 1024;
+
+/// MDBOOK031: Detects local images over a byte size or pixel dimension limit
+///
+/// Dimensions are read from the file header for PNG, JPEG, GIF, and WebP
+/// images, and from the `width`/`height` (or `viewBox`) of SVG files. Missing
+/// images are MDBOOK030's concern and are not reported here.
+#[derive(Clone, Default)]
+pub struct MDBOOK031 {
+    max_bytes: Option<u64>,
+    max_width: Option<u32>,
+    max_height: Option<u32>,
+}
+
+impl MDBOOK031 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `max_bytes`: file size limit, as a number of bytes or a string such
+    ///   as `"500KB"` or `"1.5MB"` (1 KB = 1024 bytes)
+    /// - `max_width`, `max_height`: dimension limits in pixels
+    pub fn from_config(config: &toml::Value) -> Self {
+        let get = |key: &str| {
+            config
+                .get(key)
+                .or_else(|| config.get(key.replace('_', "-")))
+        };
+        let pixels = |key: &str| {
+            get(key)
+                .and_then(|v| v.as_integer())
+                .and_then(|v| u32::try_from(v).ok())
+        };
+
+        Self {
+            max_bytes: get("max_bytes").and_then(|value| match value {
+                toml::Value::Integer(bytes) => u64::try_from(*bytes).ok(),
+                toml::Value::String(size) => parse_size(size),
+                _ => None,
+            }),
+            max_width: pixels("max_width"),
+            max_height: pixels("max_height"),
+        }
+    }
+
+    fn is_configured(&self) -> bool {
+        self.max_bytes.is_some() || self.max_width.is_some() || self.max_height.is_some()
+    }
+}
+
+/// Parse a size such as `512000`, `500KB`, `1.5 MB`, or `2MiB` into bytes
+fn parse_size(size: &str) -> Option<u64> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let number: f64 = number.parse().ok()?;
+    let multiplier = match unit.trim().to_ascii_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "k" | "kb" | "kib" => 1024.0,
+        "m" | "mb" | "mib" => 1024.0 * 1024.0,
+        "g" | "gb" | "gib" => 1024.0 * 1024.0 * 1024.0,
+        _ => return None,
+    };
+    Some((number * multiplier) as u64)
+}
+
+/// A byte count for messages, such as `1.5 MB`
+fn format_size(bytes: u64) -> String {
+    const KB: f64 = 1024.0;
+    match bytes as f64 {
+        b if b < KB => format!("{bytes} B"),
+        b if b < KB * KB => format!("{:.1} KB", b / KB),
+        b => format!("{:.1} MB", b / (KB * KB)),
+    }
+}
+
+/// Width and height of an image, read from the start of its file
+fn image_dimensions(bytes: &[u8]) -> Option<(u32, u32)> {
+    let be16 = |i: usize| Some(u16::from_be_bytes(bytes.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le16 = |i: usize| Some(u16::from_le_bytes(bytes.get(i..i + 2)?.try_into().ok()?) as u32);
+    let le24 = |i: usize| {
+        let b = bytes.get(i..i + 3)?;
+        Some(u32::from(b[0]) | u32::from(b[1]) << 8 | u32::from(b[2]) << 16)
+    };
+
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        let width = u32::from_be_bytes(bytes.get(16..20)?.try_into().ok()?);
+        let height = u32::from_be_bytes(bytes.get(20..24)?.try_into().ok()?);
+        Some((width, height))
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some((le16(6)?, le16(8)?))
+    } else if bytes.starts_with(&[0xFF, 0xD8]) {
+        // Walk the JPEG segments to the first start-of-frame marker
+        let mut i = 2;
+        while i + 4 <= bytes.len() {
+            if bytes[i] != 0xFF {
+                return None;
+            }
+            let marker = bytes[i + 1];
+            if marker == 0xFF {
+                i += 1;
+                continue;
+            }
+            if matches!(marker, 0xC0..=0xCF) && !matches!(marker, 0xC4 | 0xC8 | 0xCC) {
+                return Some((be16(i + 7)?, be16(i + 5)?));
+            }
+            if matches!(marker, 0x01 | 0xD0..=0xD9) {
+                i += 2;
+            } else {
+                i += 2 + be16(i + 2)? as usize;
+            }
+        }
+        None
+    } else if bytes.starts_with(b"RIFF") && bytes.get(8..12) == Some(b"WEBP") {
+        match bytes.get(12..16)? {
+            b"VP8 " => Some((le16(26)? & 0x3FFF, le16(28)? & 0x3FFF)),
+            b"VP8L" => {
+                let b = bytes.get(21..25)?;
+                let width = 1 + (u32::from(b[0]) | (u32::from(b[1]) & 0x3F) << 8);
+                let height = 1
+                    + (u32::from(b[1]) >> 6
+                        | u32::from(b[2]) << 2
+                        | (u32::from(b[3]) & 0x0F) << 10);
+                Some((width, height))
+            }
+            b"VP8X" => Some((1 + le24(24)?, 1 + le24(27)?)),
+            _ => None,
+        }
+    } else {
+        svg_dimensions(&String::from_utf8_lossy(bytes))
+    }
+}
+
+/// Dimensions from an SVG root element's `width`/`height`, or its `viewBox`
+fn svg_dimensions(text: &str) -> Option<(u32, u32)> {
+    let start = text.find("<svg")?;
+    let tag = &text[start..start + text[start..].find('>')?];
+    let attribute = |name: &str| {
+        let at = tag.find(&format!(" {name}="))? + name.len() + 2;
+        let quote = tag[at..].chars().next()?;
+        let value = &tag[at + 1..];
+        Some(&value[..value.find(quote)?])
+    };
+    let pixels = |value: &str| {
+        let value = value.trim();
+        value
+            .strip_suffix("px")
+            .unwrap_or(value)
+            .parse::<f64>()
+            .ok()
+            .map(|v| v.round() as u32)
+    };
+
+    if let (Some(width), Some(height)) = (
+        attribute("width").and_then(pixels),
+        attribute("height").and_then(pixels),
+    ) {
+        return Some((width, height));
+    }
+    let view_box: Vec<f64> = attribute("viewBox")?
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter_map(|v| v.parse().ok())
+        .collect();
+    match view_box[..] {
+        [_, _, width, height] => Some((width.round() as u32, height.round() as u32)),
+        _ => None,
+    }
+}
+
+impl AstRule for MDBOOK031 {
+    fn id(&self) -> &'static str {
+        "MDBOOK031"
+    }
+
+    fn name(&self) -> &'static str {
+        "image-budget"
+    }
+
+    fn description(&self) -> &'static str {
+        "Images should stay within the configured size and dimension limits"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .opt_in()
+            .option(
+                "max_bytes",
+                OptionKind::Any,
+                "File size limit, in bytes or as a string such as `\"500KB\"`",
+            )
+            .option(
+                "max_width",
+                OptionKind::IntegerAtLeast(1),
+                "Width limit in pixels",
+            )
+            .option(
+                "max_height",
+                OptionKind::IntegerAtLeast(1),
+                "Height limit in pixels",
+            )
+            .either_case()
+    }
+
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        if !self.is_configured() {
+            return Ok(violations);
+        }
+
+        for image in images(ast) {
+            let Some(asset) = document.asset(&image.url) else {
+                continue;
+            };
+
+            if let (Some(max), Some(size)) = (self.max_bytes, asset.size())
+                && size > max
+            {
+                violations.push(self.create_violation(
+                    format!(
+                        "Image '{}' is {}, over the {} limit",
+                        image.url,
+                        format_size(size),
+                        format_size(max)
+                    ),
+                    image.line,
+                    image.column,
+                    Severity::Warning,
+                ));
+            }
+
+            if self.max_width.is_none() && self.max_height.is_none() {
+                continue;
+            }
+            let Some((width, height)) = asset
+                .read_head(HEADER_LIMIT)
+                .and_then(|head| image_dimensions(&head))
+            else {
+                continue;
+            };
+            let mut exceeded = Vec::new();
+            if let Some(max) = self.max_width.filter(|max| width > *max) {
+                exceeded.push(format!("width {max}"));
+            }
+            if let Some(max) = self.max_height.filter(|max| height > *max) {
+                exceeded.push(format!("height {max}"));
+            }
+            if !exceeded.is_empty() {
+                violations.push(self.create_violation(
+                    format!(
+                        "Image '{}' is {width}x{height} pixels, over the maximum {}",
+                        image.url,
+                        exceeded.join(" and ")
+                    ),
+                    image.line,
+                    image.column,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn png(width: u32, height: u32, size: usize) -> Vec<u8> {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        bytes.extend(width.to_be_bytes());
+        bytes.extend(height.to_be_bytes());
+        bytes.resize(size.max(bytes.len()), 0);
+        bytes
+    }
+
+    fn check(rule: &MDBOOK031, content: &str) -> Vec<Violation> {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("images")).unwrap();
+        fs::write(src.join("SUMMARY.md"), "# Summary\n").unwrap();
+        fs::write(src.join("images/small.png"), png(640, 480, 1000)).unwrap();
+        fs::write(src.join("images/huge.png"), png(4000, 3000, 600 * 1024)).unwrap();
+        let doc = Document::new(content.to_string(), src.join("chapter.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    const CONTENT: &str = "![Small](images/small.png)\n\n\
+                           ![Huge screenshot](images/huge.png)\n\n\
+                           <img src=\"/images/huge.png\" alt=\"Again\">\n\n\
+                           ![Missing](images/missing.png)\n";
+
+    #[test]
+    fn test_opt_in() {
+        assert!(check(&MDBOOK031::default(), CONTENT).is_empty());
+    }
+
+    #[test]
+    fn test_byte_budget() {
+        let config: toml::Value = toml::from_str("max-bytes = \"500KB\"").unwrap();
+        let violations = check(&MDBOOK031::from_config(&config), CONTENT);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[0].message,
+            "Image 'images/huge.png' is 600.0 KB, over the 500.0 KB limit"
+        );
+        assert_eq!((violations[0].line, violations[0].column), (3, 1));
+        assert_eq!(violations[1].line, 5);
+    }
+
+    #[test]
+    fn test_dimension_budget() {
+        let config: toml::Value = toml::from_str("max_width = 1600\nmax_height = 1200").unwrap();
+        let violations = check(&MDBOOK031::from_config(&config), CONTENT);
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(
+            violations[0].message,
+            "Image 'images/huge.png' is 4000x3000 pixels, over the maximum width 1600 and height 1200"
+        );
+    }
+
+    #[test]
+    fn test_image_dimensions() {
+        assert_eq!(image_dimensions(&png(800, 600, 0)), Some((800, 600)));
+        assert_eq!(
+            image_dimensions(b"GIF89a\x20\x03\x58\x02\0\0"),
+            Some((800, 600))
+        );
+
+        let jpeg = [
+            0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x04, 0x00, 0x00, // APP0 segment
+            0xFF, 0xC0, 0x00, 0x11, 0x08, 0x02, 0x58, 0x03, 0x20, // SOF0: 600x800
+        ];
+        assert_eq!(image_dimensions(&jpeg), Some((800, 600)));
+
+        let mut webp = b"RIFF\0\0\0\0WEBPVP8X\x0a\0\0\0\0\0\0\0".to_vec();
+        webp.extend([0x1F, 0x03, 0x00, 0x57, 0x02, 0x00]);
+        assert_eq!(image_dimensions(&webp), Some((800, 600)));
+
+        assert_eq!(
+            image_dimensions(br#"<?xml version="1.0"?><svg width="800px" height="600">"#),
+            Some((800, 600))
+        );
+        assert_eq!(
+            image_dimensions(br#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 1024 768">"#),
+            Some((1024, 768))
+        );
+        assert_eq!(image_dimensions(b"not an image"), None);
+    }
+
+    #[test]
+    fn test_parse_size() {
+        assert_eq!(parse_size("512000"), Some(512_000));
+        assert_eq!(parse_size("500KB"), Some(512_000));
+        assert_eq!(parse_size("1.5 MB"), Some(1_572_864));
+        assert_eq!(parse_size("2MiB"), Some(2_097_152));
+        assert_eq!(parse_size("10 parsecs"), None);
+    }
+}
@@ -0,0 +1,201 @@
+//! MDBOOK032: Line endings should follow the configured style
+//!
+//! A book edited on both Windows and Unix machines easily ends up with a mix
+//! of `\r\n` and `\n` line endings, sometimes within one file. Git then shows
+//! whole-file diffs for a one-line change, and tools that split on `\n` leave
+//! stray `\r` characters in headings and code. This rule reports lines whose
+//! ending doesn't match the configured style and fixes them in place.
+
+use comrak::nodes::AstNode;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+use mdbook_lint_core::{Document, LineEnding};
+
+/// Which line ending the rule enforces
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum Style {
+    /// Whatever the file's first line uses
+    #[default]
+    Consistent,
+    /// Always `\n`
+    Lf,
+    /// Always `\r\n`
+    CrLf,
+}
+
+/// MDBOOK032: Detects lines whose ending doesn't match the configured style
+///
+/// Each offending line gets its own fix, which removes or inserts the `\r`
+/// before its `\n`, so the fix never touches the text of the line. The last
+/// line of a file is only checked if it ends with a line break.
+#[derive(Clone, Default)]
+pub struct MDBOOK032 {
+    style: Style,
+}
+
+impl MDBOOK032 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `style`: `"consistent"` (default; match the file's first line),
+    ///   `"lf"`, or `"crlf"`
+    pub fn from_config(config: &toml::Value) -> Self {
+        let style = match config
+            .get("style")
+            .and_then(|v| v.as_str())
+            .map(str::to_ascii_lowercase)
+            .as_deref()
+        {
+            Some("lf" | "unix") => Style::Lf,
+            Some("crlf" | "windows") => Style::CrLf,
+            _ => Style::Consistent,
+        };
+        Self { style }
+    }
+
+    fn expected(&self, document: &Document) -> LineEnding {
+        match self.style {
+            Style::Consistent => document.line_ending(),
+            Style::Lf => LineEnding::Lf,
+            Style::CrLf => LineEnding::CrLf,
+        }
+    }
+}
+
+impl Rule for MDBOOK032 {
+    fn id(&self) -> &'static str {
+        "MDBOOK032"
+    }
+
+    fn name(&self) -> &'static str {
+        "line-endings"
+    }
+
+    fn description(&self) -> &'static str {
+        "Line endings should follow the configured style"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "lf", "crlf", "unix", "windows"]),
+                "Line ending to require; `consistent` matches the first line",
+            )
+            .defaults_to(r#""consistent""#)
+    }
+
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        let expected = self.expected(document);
+        let mut violations = Vec::new();
+
+        let terminated = document.content.split_inclusive('\n').zip(&document.lines);
+        for (index, (raw, line)) in terminated.enumerate() {
+            let found = if raw.ends_with("\r\n") {
+                LineEnding::CrLf
+            } else if raw.ends_with('\n') {
+                LineEnding::Lf
+            } else {
+                continue;
+            };
+            if found == expected {
+                continue;
+            }
+
+            // The `\r`, if any, sits right after the line's text
+            let line_number = index + 1;
+            let column = line.chars().count() + 1;
+            let (description, end_column, replacement) = match expected {
+                LineEnding::Lf => ("Remove the carriage return", column + 1, None),
+                LineEnding::CrLf => ("Insert a carriage return", column, Some("\r".to_string())),
+            };
+            violations.push(self.create_violation_with_fix(
+                format!("Line ends with {found}, expected {expected}"),
+                line_number,
+                column,
+                Severity::Warning,
+                Fix {
+                    description: description.to_string(),
+                    replacement,
+                    start: Position {
+                        line: line_number,
+                        column,
+                    },
+                    end: Position {
+                        line: line_number,
+                        column: end_column,
+                    },
+                    safety: FixSafety::Safe,
+                },
+            ));
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::FixEngine;
+    use std::path::PathBuf;
+
+    fn check(rule: &MDBOOK032, content: &str) -> Vec<Violation> {
+        let document = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        rule.check(&document).unwrap()
+    }
+
+    fn fix(rule: &MDBOOK032, content: &str) -> String {
+        FixEngine::new()
+            .apply(content, &check(rule, content))
+            .content
+    }
+
+    fn rule(style: &str) -> MDBOOK032 {
+        MDBOOK032::from_config(&toml::from_str(&format!("style = \"{style}\"")).unwrap())
+    }
+
+    #[test]
+    fn test_consistent_follows_first_line() {
+        let rule = MDBOOK032::default();
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+        assert!(check(&rule, "# Title\r\n\r\nText\r\n").is_empty());
+
+        let violations = check(&rule, "# Title\r\n\nText é\n");
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].message, "Line ends with LF, expected CRLF");
+        assert_eq!((violations[0].line, violations[0].column), (2, 1));
+        assert_eq!((violations[1].line, violations[1].column), (3, 7));
+    }
+
+    #[test]
+    fn test_fix_to_lf() {
+        let rule = rule("lf");
+        assert_eq!(
+            fix(&rule, "# Title\r\n\r\nText é\r\nend"),
+            "# Title\n\nText é\nend"
+        );
+        assert!(check(&rule, "# Title\n\nText\n").is_empty());
+    }
+
+    #[test]
+    fn test_fix_to_crlf() {
+        let rule = rule("CRLF");
+        assert_eq!(fix(&rule, "# Title\n\r\nText\n"), "# Title\r\n\r\nText\r\n");
+    }
+
+    #[test]
+    fn test_unterminated_last_line_is_ignored() {
+        assert!(check(&rule("crlf"), "# Title").is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+//! MDBOOK033: Sections should be nested under a level-2 heading
+//!
+//! mdBook builds a chapter's in-page navigation from its headings, and a
+//! level-3 or deeper section that follows the title directly, with no `##`
+//! above it, has nothing to hang from. It shows up at the wrong depth or
+//! out of place, and readers skimming the outline lose track of where they
+//! are. This rule reports the first heading of each such orphaned run.
+
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// MDBOOK033: Detects level-3 and deeper sections that are not under a level-2 heading
+///
+/// Only the outermost orphan is reported; headings nested inside it are part
+/// of the same problem and are fixed along with it.
+#[derive(Clone, Default)]
+pub struct MDBOOK033;
+
+impl Rule for MDBOOK033 {
+    fn id(&self) -> &'static str {
+        "MDBOOK033"
+    }
+
+    fn name(&self) -> &'static str {
+        "orphan-section"
+    }
+
+    fn description(&self) -> &'static str {
+        "Level-3 and deeper sections should be nested under a level-2 heading"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        let outline = document.outline();
+        let mut violations = Vec::new();
+
+        for (index, heading) in outline.iter().enumerate() {
+            if heading.level < 3 {
+                continue;
+            }
+            // Outermost orphan: its parent, if any, is the chapter title
+            if outline.parent(index).is_some_and(|parent| parent.level > 1) {
+                continue;
+            }
+            violations.push(self.create_violation(
+                format!(
+                    "Level-{} heading '{}' is not under a level-2 heading",
+                    heading.level, heading.title
+                ),
+                heading.line,
+                1,
+                Severity::Warning,
+            ));
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<(usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        MDBOOK033
+            .check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_nested_sections_pass() {
+        assert!(check("# Title\n\n## Setup\n\n### Linux\n\n#### Arch\n\n## Usage\n").is_empty());
+        assert!(check("Intro without headings.\n").is_empty());
+    }
+
+    #[test]
+    fn test_orphan_sections() {
+        let found = check(
+            "# Title\n\n### Before any section\n\n#### Nested\n\n## Setup\n\n### Fine\n\n# Part Two\n\n#### Deep\n",
+        );
+        assert_eq!(
+            found,
+            vec![
+                (
+                    3,
+                    "Level-3 heading 'Before any section' is not under a level-2 heading"
+                        .to_string()
+                ),
+                (
+                    13,
+                    "Level-4 heading 'Deep' is not under a level-2 heading".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_orphan_without_title() {
+        let found = check("### Fragment\n\nText.\n\n### Another\n");
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[1].0, 5);
+    }
+}
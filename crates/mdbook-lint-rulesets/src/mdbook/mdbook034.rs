@@ -0,0 +1,167 @@
+//! MDBOOK034: Every source chapter should be translated
+//!
+//! In a book translated into language directories, `src/intro.md` is
+//! expected at `src/de/intro.md` and at the same path under every other
+//! language. A missing translation leaves that edition with a broken link
+//! or an untranslated gap. This rule analyzes all documents in a collection.
+
+use super::translations::{Layout, configured_languages};
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+
+/// MDBOOK034: Reports source chapters missing from a language directory
+///
+/// Each missing translation is reported on the source chapter, once per
+/// language.
+#[derive(Debug, Clone, Default)]
+pub struct MDBOOK034 {
+    /// Language directories to check; detected when empty
+    languages: Vec<String>,
+}
+
+impl MDBOOK034 {
+    /// Create from a `[MDBOOK034]` table
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            languages: configured_languages(config),
+        }
+    }
+}
+
+impl CollectionRule for MDBOOK034 {
+    fn id(&self) -> &'static str {
+        "MDBOOK034"
+    }
+
+    fn name(&self) -> &'static str {
+        "translation-missing"
+    }
+
+    fn description(&self) -> &'static str {
+        "Every source chapter should have a translation in each language directory"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "languages",
+                OptionKind::StringList,
+                "Language directories in the source directory",
+            )
+            .defaults_to("detected")
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for layout in Layout::find(documents, &self.languages) {
+            for (source, relative) in layout.sources(documents) {
+                for language in &layout.languages {
+                    let translated = layout.translated_path(language, &relative);
+                    if documents.iter().any(|d| d.path == translated) || translated.is_file() {
+                        continue;
+                    }
+                    violations.push(self.create_violation_for_file(
+                        &source.path,
+                        format!(
+                            "No '{language}' translation: {} does not exist",
+                            translated.display()
+                        ),
+                        1,
+                        1,
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    use tempfile::TempDir;
+
+    fn book(files: &[&str]) -> (TempDir, Vec<Document>) {
+        let dir = TempDir::new().unwrap();
+        let documents = files
+            .iter()
+            .map(|file| {
+                let path = dir.path().join("src").join(file);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(&path, "# Title\n").unwrap();
+                Document::new("# Title\n".to_string(), path).unwrap()
+            })
+            .collect();
+        (dir, documents)
+    }
+
+    fn messages(rule: &MDBOOK034, documents: &[Document], root: &Path) -> Vec<String> {
+        rule.check_collection(documents)
+            .unwrap()
+            .into_iter()
+            .map(|v| {
+                format!("{}: {}", v.path.unwrap().display(), v.message)
+                    .replace(&root.display().to_string(), "")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_complete_translations_pass() {
+        let (dir, documents) = book(&[
+            "SUMMARY.md",
+            "intro.md",
+            "guide/setup.md",
+            "de/SUMMARY.md",
+            "de/intro.md",
+            "de/guide/setup.md",
+        ]);
+        assert!(messages(&MDBOOK034::default(), &documents, dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_missing_translation() {
+        let (dir, documents) = book(&[
+            "SUMMARY.md",
+            "intro.md",
+            "guide/setup.md",
+            "de/SUMMARY.md",
+            "de/intro.md",
+            "ja/SUMMARY.md",
+            "ja/intro.md",
+            "ja/guide/setup.md",
+        ]);
+        assert_eq!(
+            messages(&MDBOOK034::default(), &documents, dir.path()),
+            vec!["/src/guide/setup.md: No 'de' translation: /src/de/guide/setup.md does not exist"]
+        );
+    }
+
+    #[test]
+    fn test_translations_found_on_disk() {
+        let (dir, mut documents) =
+            book(&["SUMMARY.md", "intro.md", "de/SUMMARY.md", "de/intro.md"]);
+        // A book loaded from the source SUMMARY.md has no translated chapters
+        documents.retain(|d| !d.path.starts_with(dir.path().join("src/de")));
+        assert!(messages(&MDBOOK034::default(), &documents, dir.path()).is_empty());
+    }
+
+    #[test]
+    fn test_configured_languages() {
+        let (dir, documents) = book(&["SUMMARY.md", "intro.md", "de/SUMMARY.md", "de/intro.md"]);
+        let config: toml::Value = toml::from_str("languages = [\"de\", \"fr\"]").unwrap();
+        let rule = MDBOOK034::from_config(&config);
+        // `fr` has no directory, so there is nothing to compare against
+        assert!(messages(&rule, &documents, dir.path()).is_empty());
+
+        fs::create_dir_all(dir.path().join("src/fr")).unwrap();
+        assert_eq!(messages(&rule, &documents, dir.path()).len(), 2);
+    }
+}
@@ -0,0 +1,200 @@
+//! MDBOOK035: Translated chapters should keep the source's heading structure
+//!
+//! A translation that adds, drops, or re-levels sections drifts out of step
+//! with the source: its in-page navigation differs between editions and
+//! later updates to the source are hard to carry over. Heading text is
+//! translated, so only the sequence of heading levels is compared. This rule
+//! analyzes all documents in a collection.
+
+use super::translations::{Layout, configured_languages};
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+
+/// MDBOOK035: Reports translated chapters whose headings differ from the source
+///
+/// Only the first difference in each chapter is reported, since everything
+/// after a missing or extra heading is shifted by it.
+#[derive(Debug, Clone, Default)]
+pub struct MDBOOK035 {
+    /// Language directories to check; detected when empty
+    languages: Vec<String>,
+}
+
+impl MDBOOK035 {
+    /// Create from a `[MDBOOK035]` table
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            languages: configured_languages(config),
+        }
+    }
+
+    fn compare(&self, source: &Document, translated: &Document) -> Option<Violation> {
+        let expected = source.outline().headings();
+        let found = translated.outline().headings();
+        let source_path = source.path.display();
+
+        let mismatch = expected
+            .iter()
+            .zip(found)
+            .find(|(expected, found)| expected.level != found.level);
+        let (message, line) = match mismatch {
+            Some((expected, found)) => (
+                format!(
+                    "Level-{} heading '{}' does not match the level-{} heading '{}' at {source_path}:{}",
+                    found.level, found.title, expected.level, expected.title, expected.line
+                ),
+                found.line,
+            ),
+            None if found.len() > expected.len() => {
+                let extra = &found[expected.len()];
+                (
+                    format!(
+                        "Level-{} heading '{}' has no counterpart in {source_path}",
+                        extra.level, extra.title
+                    ),
+                    extra.line,
+                )
+            }
+            None if found.len() < expected.len() => {
+                let missing = &expected[found.len()];
+                (
+                    format!(
+                        "Missing the level-{} heading '{}' at {source_path}:{}",
+                        missing.level, missing.title, missing.line
+                    ),
+                    found.last().map_or(1, |heading| heading.line),
+                )
+            }
+            None => return None,
+        };
+
+        Some(self.create_violation_for_file(&translated.path, message, line, 1, Severity::Warning))
+    }
+}
+
+impl CollectionRule for MDBOOK035 {
+    fn id(&self) -> &'static str {
+        "MDBOOK035"
+    }
+
+    fn name(&self) -> &'static str {
+        "translation-heading-structure"
+    }
+
+    fn description(&self) -> &'static str {
+        "Translated chapters should have the same heading levels as the source"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "languages",
+                OptionKind::StringList,
+                "Language directories in the source directory",
+            )
+            .defaults_to("detected")
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for layout in Layout::find(documents, &self.languages) {
+            for (source, relative) in layout.sources(documents) {
+                if source
+                    .path
+                    .file_name()
+                    .is_some_and(|name| name == "SUMMARY.md")
+                {
+                    continue;
+                }
+                for language in &layout.languages {
+                    let path = layout.translated_path(language, &relative);
+                    // Missing translations are reported by MDBOOK034
+                    let Some(translated) = Layout::load(documents, &path) else {
+                        continue;
+                    };
+                    violations.extend(self.compare(source, &translated));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check(source: &str, translated: &str) -> Vec<(usize, String)> {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        fs::create_dir_all(src.join("de")).unwrap();
+        fs::write(src.join("SUMMARY.md"), "- [Intro](intro.md)\n").unwrap();
+        fs::write(src.join("de/SUMMARY.md"), "- [Intro](intro.md)\n").unwrap();
+        fs::write(src.join("de/intro.md"), translated).unwrap();
+        let documents = vec![Document::new(source.to_string(), src.join("intro.md")).unwrap()];
+
+        let prefix = format!("{}/", src.display());
+        MDBOOK035::default()
+            .check_collection(&documents)
+            .unwrap()
+            .into_iter()
+            .map(|v| {
+                let message = format!("{}: {}", v.path.unwrap().display(), v.message);
+                (v.line, message.replace(&prefix, ""))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_matching_structure_passes() {
+        assert!(
+            check(
+                "# Intro\n\n## Setup\n\n### Linux\n",
+                "# Einführung\n\n## Einrichtung\n\n### Linux\n"
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_level_mismatch() {
+        assert_eq!(
+            check(
+                "# Intro\n\n## Setup\n\n### Linux\n",
+                "# Einführung\n\n## Einrichtung\n\n## Linux\n"
+            ),
+            vec![(
+                5,
+                "de/intro.md: Level-2 heading 'Linux' does not match the level-3 heading 'Linux' at intro.md:5"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_extra_and_missing_headings() {
+        let extra = check("# Intro\n", "# Einführung\n\n## Hinweis\n");
+        assert_eq!(
+            extra,
+            vec![(
+                3,
+                "de/intro.md: Level-2 heading 'Hinweis' has no counterpart in intro.md".to_string()
+            )]
+        );
+
+        let missing = check("# Intro\n\n## Setup\n", "# Einführung\n");
+        assert_eq!(
+            missing,
+            vec![(
+                1,
+                "de/intro.md: Missing the level-2 heading 'Setup' at intro.md:3".to_string()
+            )]
+        );
+    }
+}
@@ -0,0 +1,233 @@
+//! MDBOOK036: Links in a translation should stay in its language
+//!
+//! A translated chapter linking to `../intro.md` sends readers from the
+//! German edition to the English source, usually because the link was
+//! copied from the source with one `../` too many, or into another
+//! translation. This rule checks links to chapters (`.md` files) in
+//! translated chapters; images and other assets are shared between
+//! languages and may point anywhere. It analyzes all documents in a
+//! collection.
+
+use super::translations::{Layout, configured_languages};
+use comrak::nodes::NodeValue;
+use mdbook_lint_core::asset::{local_reference, normalize};
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+/// MDBOOK036: Reports chapter links that leave a translation's language directory
+///
+/// Root-relative links (`/intro.md`) are resolved by the renderer against
+/// the site root and are not checked.
+#[derive(Debug, Clone, Default)]
+pub struct MDBOOK036 {
+    /// Language directories to check; detected when empty
+    languages: Vec<String>,
+}
+
+impl MDBOOK036 {
+    /// Create from a `[MDBOOK036]` table
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            languages: configured_languages(config),
+        }
+    }
+
+    fn check_chapter(&self, layout: &Layout, language: &str, chapter: &Document) -> Vec<Violation> {
+        let Some(dir) = chapter.path.parent() else {
+            return Vec::new();
+        };
+        let arena = comrak::Arena::new();
+        let ast = chapter.parse_ast(&arena);
+        let mut violations = Vec::new();
+
+        for node in ast.descendants() {
+            let url = match &node.data.borrow().value {
+                NodeValue::Link(link) => link.url.clone(),
+                _ => continue,
+            };
+            let Some(reference) = local_reference(&url) else {
+                continue;
+            };
+            if reference.starts_with('/')
+                || Path::new(&reference).extension().is_none_or(|e| e != "md")
+            {
+                continue;
+            }
+            let target = normalize(&dir.join(&reference));
+            let Ok(relative) = target.strip_prefix(&layout.root) else {
+                continue;
+            };
+
+            let message = match layout.language_of(&target) {
+                Some((other, _)) if other == language => continue,
+                Some((other, within)) => format!(
+                    "Link '{url}' points into the '{other}' translation; link to {} instead",
+                    display(&Path::new(language).join(within))
+                ),
+                None => format!(
+                    "Link '{url}' points to the source chapter {}; link to {} instead",
+                    display(relative),
+                    display(&Path::new(language).join(relative))
+                ),
+            };
+            let (line, column) = chapter.node_position(node).unwrap_or((1, 1));
+            violations.push(self.create_violation_for_file(
+                &chapter.path,
+                message,
+                line,
+                column,
+                Severity::Warning,
+            ));
+        }
+
+        violations
+    }
+}
+
+/// A path relative to the source directory, with `/` separators
+fn display(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+impl CollectionRule for MDBOOK036 {
+    fn id(&self) -> &'static str {
+        "MDBOOK036"
+    }
+
+    fn name(&self) -> &'static str {
+        "translation-link-language"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links in translated chapters should point to chapters in the same language"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Links)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "languages",
+                OptionKind::StringList,
+                "Language directories in the source directory",
+            )
+            .defaults_to("detected")
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        for layout in Layout::find(documents, &self.languages) {
+            // Translations in the collection, and those on disk for books
+            // loaded from the source SUMMARY.md
+            let mut chapters: BTreeSet<PathBuf> = layout
+                .translations(documents)
+                .into_iter()
+                .map(|(document, _, _)| document.path.clone())
+                .collect();
+            for (_, relative) in layout.sources(documents) {
+                for language in &layout.languages {
+                    chapters.insert(layout.translated_path(language, &relative));
+                }
+            }
+
+            for path in chapters {
+                let Some((language, _)) = layout.language_of(&path) else {
+                    continue;
+                };
+                if let Some(chapter) = Layout::load(documents, &path) {
+                    violations.extend(self.check_chapter(&layout, language, &chapter));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn check(files: &[(&str, &str)]) -> Vec<(usize, String)> {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        let mut documents = Vec::new();
+        for (file, content) in files {
+            let path = src.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, content).unwrap();
+            documents.push(Document::new(content.to_string(), path).unwrap());
+        }
+
+        let prefix = format!("{}/", src.display());
+        MDBOOK036::default()
+            .check_collection(&documents)
+            .unwrap()
+            .into_iter()
+            .map(|v| {
+                let message = format!("{}: {}", v.path.unwrap().display(), v.message);
+                (v.line, message.replace(&prefix, ""))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_same_language_links_pass() {
+        let found = check(&[
+            (
+                "SUMMARY.md",
+                "- [Intro](intro.md)\n- [Setup](guide/setup.md)\n",
+            ),
+            ("intro.md", "# Intro\n\nSee [setup](guide/setup.md).\n"),
+            ("guide/setup.md", "# Setup\n"),
+            (
+                "de/SUMMARY.md",
+                "- [Einführung](intro.md)\n- [Einrichtung](guide/setup.md)\n",
+            ),
+            (
+                "de/intro.md",
+                "# Einführung\n\nSiehe [Einrichtung](guide/setup.md#linux), [Logo](../images/logo.png), [Start](/index.html) und [Web](https://example.com/a.md).\n",
+            ),
+            (
+                "de/guide/setup.md",
+                "# Einrichtung\n\n[Zurück](../intro.md)\n",
+            ),
+        ]);
+        assert!(found.is_empty(), "{found:?}");
+    }
+
+    #[test]
+    fn test_links_leaving_the_language() {
+        let found = check(&[
+            ("SUMMARY.md", "- [Intro](intro.md)\n"),
+            ("intro.md", "# Intro\n"),
+            ("de/SUMMARY.md", "- [Einführung](intro.md)\n"),
+            (
+                "de/intro.md",
+                "# Einführung\n\nSiehe [Intro](../intro.md).\n\nOder [Japanisch](../ja/intro.md).\n",
+            ),
+            ("ja/SUMMARY.md", "- [はじめに](intro.md)\n"),
+            ("ja/intro.md", "# はじめに\n"),
+        ]);
+        assert_eq!(
+            found,
+            vec![
+                (
+                    3,
+                    "de/intro.md: Link '../intro.md' points to the source chapter intro.md; link to de/intro.md instead"
+                        .to_string()
+                ),
+                (
+                    5,
+                    "de/intro.md: Link '../ja/intro.md' points into the 'ja' translation; link to de/intro.md instead"
+                        .to_string()
+                ),
+            ]
+        );
+    }
+}
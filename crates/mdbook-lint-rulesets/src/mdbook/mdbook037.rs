@@ -0,0 +1,289 @@
+//! MDBOOK037: Raw HTML the book's other renderers cannot show
+//!
+//! mdBook passes raw HTML straight through to its HTML output, where scripts,
+//! embedded frames, and style blocks work. Books that are also built as EPUB
+//! or PDF lose them: e-readers refuse scripts and frames, and PDF backends
+//! drop interactive elements or the HTML altogether, leaving gaps in the
+//! text. This rule reports those elements for the renderers the book is
+//! built with. Unlike MD033 it says nothing about HTML the renderers handle.
+
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document, LintContext,
+    violation::{Severity, Violation},
+};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::sync::LazyLock;
+
+/// Elements each renderer cannot show
+const RENDERERS: &[(&str, &[&str])] = &[
+    (
+        "epub",
+        &[
+            "script", "style", "iframe", "object", "embed", "form", "input", "button", "select",
+            "textarea", "canvas",
+        ],
+    ),
+    (
+        "pdf",
+        &[
+            "script", "style", "iframe", "object", "embed", "form", "input", "button", "select",
+            "textarea", "canvas", "video", "audio",
+        ],
+    ),
+];
+
+/// Opening tags, with the element name captured
+static OPENING_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"<([A-Za-z][A-Za-z0-9-]*)[\s/>]").expect("Invalid regex"));
+
+/// MDBOOK037: Reports raw HTML elements that break the EPUB or PDF renderer
+///
+/// The renderers come from the `renderers` option, or else from the
+/// `[output]` tables in `book.toml`. Settings are read from `[MDBOOK-html]`
+/// as well as `[MDBOOK037]`.
+#[derive(Debug, Clone, Default)]
+pub struct MDBOOK037 {
+    /// Renderers to check for; taken from `book.toml` when `None`
+    renderers: Option<Vec<String>>,
+    /// Elements allowed per renderer, lowercase
+    allow: BTreeMap<String, Vec<String>>,
+}
+
+impl MDBOOK037 {
+    /// Create from a `[MDBOOK-html]` or `[MDBOOK037]` table
+    pub fn from_config(config: &toml::Value) -> Self {
+        let strings = |value: &toml::Value| -> Vec<String> {
+            value
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|v| v.as_str().map(str::to_lowercase))
+                .collect()
+        };
+        Self {
+            renderers: config.get("renderers").map(strings),
+            allow: config
+                .get("allow")
+                .and_then(|v| v.as_table())
+                .into_iter()
+                .flatten()
+                .map(|(renderer, elements)| (renderer.to_lowercase(), strings(elements)))
+                .collect(),
+        }
+    }
+
+    /// Renderers the book is built with, among those this rule knows
+    fn active_renderers(&self, context: &LintContext) -> Vec<&'static str> {
+        let configured: Vec<String> = match &self.renderers {
+            Some(renderers) => renderers.clone(),
+            None => context
+                .book_setting("output")
+                .and_then(|output| output.as_table())
+                .map(|output| output.keys().cloned().collect())
+                .unwrap_or_default(),
+        };
+        RENDERERS
+            .iter()
+            .map(|(name, _)| *name)
+            .filter(|name| configured.iter().any(|c| c == name))
+            .collect()
+    }
+
+    /// Renderers among `active` that cannot show `element`
+    fn breaks<'r>(&self, element: &str, active: &[&'r str]) -> Vec<&'r str> {
+        active
+            .iter()
+            .copied()
+            .filter(|renderer| {
+                let unsupported = RENDERERS
+                    .iter()
+                    .find(|(name, _)| name == renderer)
+                    .is_some_and(|(_, elements)| elements.contains(&element));
+                let allowed = self
+                    .allow
+                    .get(*renderer)
+                    .is_some_and(|allowed| allowed.iter().any(|a| a == element));
+                unsupported && !allowed
+            })
+            .collect()
+    }
+}
+
+impl AstRule for MDBOOK037 {
+    fn id(&self) -> &'static str {
+        "MDBOOK037"
+    }
+
+    fn name(&self) -> &'static str {
+        "renderer-incompatible-html"
+    }
+
+    fn description(&self) -> &'static str {
+        "Raw HTML should be supported by every renderer the book is built with"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .filesystem()
+            .option(
+                "renderers",
+                OptionKind::StringList,
+                "Renderers to check for: epub, pdf",
+            )
+            .defaults_to("the [output] tables in book.toml")
+            .option(
+                "allow",
+                OptionKind::Any,
+                "Elements to allow, as lists keyed by renderer",
+            )
+    }
+
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        self.check_ast_with_context(document, ast, &LintContext::for_document(document))
+    }
+
+    fn check_ast_with_context<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+        context: &LintContext,
+    ) -> Result<Vec<Violation>> {
+        let active = self.active_renderers(context);
+        if active.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut violations = Vec::new();
+        for node in ast.descendants() {
+            let html = match &node.data.borrow().value {
+                NodeValue::HtmlBlock(block) => block.literal.clone(),
+                NodeValue::HtmlInline(html) => html.clone(),
+                _ => continue,
+            };
+            let Some((line, column)) = document.node_position(node) else {
+                continue;
+            };
+
+            for captures in OPENING_TAG.captures_iter(&html) {
+                let element = captures[1].to_lowercase();
+                let broken = self.breaks(&element, &active);
+                if broken.is_empty() {
+                    continue;
+                }
+                let offset = captures.get(0).map_or(0, |m| m.start());
+                let before = &html[..offset];
+                let (tag_line, tag_column) = match before.rfind('\n') {
+                    Some(newline) => (line + before.matches('\n').count(), offset - newline),
+                    None => (line, column + offset),
+                };
+                violations.push(self.create_violation(
+                    format!(
+                        "<{element}> is not supported by the {} output",
+                        broken.join(" and ")
+                    ),
+                    tag_line,
+                    tag_column,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(rule: &MDBOOK037, content: &str) -> Vec<(usize, usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        rule.check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.message))
+            .collect()
+    }
+
+    fn rule(config: &str) -> MDBOOK037 {
+        MDBOOK037::from_config(&toml::from_str(config).unwrap())
+    }
+
+    #[test]
+    fn test_html_only_books_pass() {
+        let content = "# Demo\n\n<script>run()</script>\n";
+        assert!(check(&MDBOOK037::default(), content).is_empty());
+        assert!(check(&rule("renderers = [\"html\"]"), content).is_empty());
+    }
+
+    #[test]
+    fn test_incompatible_elements() {
+        let content = "# Demo\n\n<div class=\"note\">\n<iframe src=\"https://example.com\"></iframe>\n</div>\n\nPress <button>Run</button> or <kbd>Ctrl</kbd>.\n\n<video src=\"a.mp4\"></video>\n\n```html\n<script></script>\n```\n";
+        let found = check(&rule("renderers = [\"epub\", \"pdf\"]"), content);
+        assert_eq!(
+            found,
+            vec![
+                (
+                    4,
+                    1,
+                    "<iframe> is not supported by the epub and pdf output".to_string()
+                ),
+                (
+                    7,
+                    7,
+                    "<button> is not supported by the epub and pdf output".to_string()
+                ),
+                (
+                    9,
+                    1,
+                    "<video> is not supported by the pdf output".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_allowlist_per_renderer() {
+        let config = "renderers = [\"epub\", \"pdf\"]\n[allow]\nepub = [\"style\"]\npdf = [\"STYLE\", \"iframe\"]\n";
+        let content = "<style>\np { color: red }\n</style>\n\n<iframe src=\"x.html\"></iframe>\n";
+        assert_eq!(
+            check(&rule(config), content),
+            vec![(
+                5,
+                1,
+                "<iframe> is not supported by the epub output".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_renderers_from_book_toml() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(dir.path().join("src")).unwrap();
+        std::fs::write(
+            dir.path().join("book.toml"),
+            "[book]\n[output.html]\n[output.epub]\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("src/SUMMARY.md"), "").unwrap();
+        let doc = Document::new(
+            "<script>run()</script>\n".to_string(),
+            dir.path().join("src/chapter.md"),
+        )
+        .unwrap();
+
+        let violations = MDBOOK037::default().check(&doc).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            violations[0].message,
+            "<script> is not supported by the epub output"
+        );
+    }
+}
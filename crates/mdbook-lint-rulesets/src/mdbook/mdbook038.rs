@@ -0,0 +1,334 @@
+//! MDBOOK038: Math delimiters should be balanced and supported
+//!
+//! mdBook renders math with MathJax (`output.html.mathjax-support`), which
+//! takes `\\( \\)` for inline math and `\\[ \\]` or `$$` for display math,
+//! and the `katex` preprocessor adds `$` for inline math. A delimiter that is
+//! never closed, or one the book has not enabled, reaches the reader as raw
+//! TeX. This rule checks prose only; code blocks and code spans are skipped.
+
+use crate::prose::prose_lines;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document, LintContext,
+    violation::{Severity, Violation},
+};
+
+/// Math support enabled in `book.toml`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MathSupport {
+    mathjax: bool,
+    katex: bool,
+}
+
+impl MathSupport {
+    /// Read from the book's `book.toml`; `None` outside a book
+    fn of(context: &LintContext) -> Option<Self> {
+        context.book_config()?;
+        Some(Self {
+            mathjax: context
+                .book_setting("output.html.mathjax-support")
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false),
+            katex: context.book_setting("preprocessor.katex").is_some(),
+        })
+    }
+}
+
+/// A math delimiter found in prose
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Delimiter {
+    /// `\\(` or `\\[`, with the bracket character
+    Open(char),
+    /// `\\)` or `\\]`, with the opening bracket it matches
+    Close(char),
+    /// `$$`
+    Display,
+}
+
+/// MDBOOK038: Reports unbalanced math delimiters and math the book can't render
+///
+/// `\\(` and `\\[` must close within their paragraph and `$$` within the
+/// chapter. Whether math is enabled is only checked inside a book with a
+/// `book.toml`. Lone `$` signs, such as prices, are not treated as math: an
+/// inline `$...$` span must start and end next to non-space text and not be
+/// followed by a digit.
+#[derive(Clone, Default)]
+pub struct MDBOOK038;
+
+impl MDBOOK038 {
+    /// Delimiters on a masked prose line, with 1-based columns, and the
+    /// columns of inline `$...$` spans
+    fn scan(line: &str) -> (Vec<(Delimiter, usize)>, Vec<usize>) {
+        let chars: Vec<char> = line.chars().collect();
+        let mut delimiters = Vec::new();
+        let mut dollars = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            match (chars[i], chars.get(i + 1), chars.get(i + 2)) {
+                ('\\', Some('\\'), Some(&bracket @ ('(' | '['))) => {
+                    delimiters.push((Delimiter::Open(bracket), i + 1));
+                    i += 3;
+                }
+                ('\\', Some('\\'), Some(&bracket @ (')' | ']'))) => {
+                    let open = if bracket == ')' { '(' } else { '[' };
+                    delimiters.push((Delimiter::Close(open), i + 1));
+                    i += 3;
+                }
+                // Escaped characters, such as `\$`
+                ('\\', Some(_), _) => i += 2,
+                ('$', Some('$'), _) => {
+                    delimiters.push((Delimiter::Display, i + 1));
+                    i += 2;
+                }
+                ('$', _, _) => {
+                    dollars.push(i);
+                    i += 1;
+                }
+                _ => i += 1,
+            }
+        }
+
+        // Pair single dollars into inline spans
+        let mut spans = Vec::new();
+        let mut pending = dollars.into_iter().peekable();
+        while let Some(open) = pending.next() {
+            if chars.get(open + 1).is_none_or(|c| c.is_whitespace()) {
+                continue;
+            }
+            let close = pending.clone().find(|&close| {
+                !chars[close - 1].is_whitespace()
+                    && chars.get(close + 1).is_none_or(|c| !c.is_ascii_digit())
+            });
+            if let Some(close) = close {
+                while pending.next_if(|&next| next <= close).is_some() {}
+                spans.push(open + 1);
+            }
+        }
+        (delimiters, spans)
+    }
+
+    fn unclosed(&self, open: char, line: usize, column: usize) -> Violation {
+        let (kind, close) = if open == '(' {
+            ("Inline", ')')
+        } else {
+            ("Display", ']')
+        };
+        self.create_violation(
+            format!(
+                "{kind} math opened with `\\\\{open}` is not closed with `\\\\{close}` in this paragraph"
+            ),
+            line,
+            column,
+            Severity::Warning,
+        )
+    }
+}
+
+impl Rule for MDBOOK038 {
+    fn id(&self) -> &'static str {
+        "MDBOOK038"
+    }
+
+    fn name(&self) -> &'static str {
+        "math-delimiters"
+    }
+
+    fn description(&self) -> &'static str {
+        "Math delimiters should be balanced and supported by the book's math renderer"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::MdBook)
+            .introduced_in("mdbook-lint v0.15.0")
+            .filesystem()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        self.check_with_context(document, ast, &LintContext::for_document(document))
+    }
+
+    fn check_with_context<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+        context: &LintContext,
+    ) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+        // Open `\\(` and `\\[` in the current paragraph
+        let mut open: Vec<(char, usize, usize)> = Vec::new();
+        // The `$$` block being read
+        let mut display: Option<(usize, usize)> = None;
+        // First use of each delimiter, for the math support check
+        let mut first_use: Vec<(&str, usize, usize)> = Vec::new();
+        let mut use_of = |delimiter: &'static str, line: usize, column: usize| {
+            if !first_use.iter().any(|(d, _, _)| *d == delimiter) {
+                first_use.push((delimiter, line, column));
+            }
+        };
+
+        for (line, text) in prose_lines(document) {
+            if text.trim().is_empty() && display.is_none() {
+                for (bracket, line, column) in open.drain(..) {
+                    violations.push(self.unclosed(bracket, line, column));
+                }
+                continue;
+            }
+
+            let (delimiters, spans) = Self::scan(&text);
+            for (delimiter, column) in delimiters {
+                match delimiter {
+                    Delimiter::Display => {
+                        if display.take().is_none() {
+                            use_of("$$", line, column);
+                            display = Some((line, column));
+                        }
+                    }
+                    // TeX inside a `$$` block is not prose
+                    _ if display.is_some() => {}
+                    Delimiter::Open(bracket) => {
+                        use_of(if bracket == '(' { "\\\\(" } else { "\\\\[" }, line, column);
+                        open.push((bracket, line, column));
+                    }
+                    Delimiter::Close(bracket) => {
+                        if open.last().is_some_and(|(b, _, _)| *b == bracket) {
+                            open.pop();
+                        } else {
+                            let close = if bracket == '(' { ')' } else { ']' };
+                            violations.push(self.create_violation(
+                                format!("`\\\\{close}` closes math that was never opened with `\\\\{bracket}`"),
+                                line,
+                                column,
+                                Severity::Warning,
+                            ));
+                        }
+                    }
+                }
+            }
+            if display.is_none()
+                && let Some(&column) = spans.first()
+            {
+                use_of("$", line, column);
+            }
+        }
+
+        for (bracket, line, column) in open {
+            violations.push(self.unclosed(bracket, line, column));
+        }
+        if let Some((line, column)) = display {
+            violations.push(self.create_violation(
+                "Math block opened with `$$` is never closed".to_string(),
+                line,
+                column,
+                Severity::Warning,
+            ));
+        }
+
+        if let Some(support) = MathSupport::of(context) {
+            for (delimiter, line, column) in first_use {
+                let message = match delimiter {
+                    "$" if support.katex => continue,
+                    "$" if support.mathjax => "Inline `$...$` math only renders with the katex preprocessor; write `\\\\(...\\\\)` for MathJax".to_string(),
+                    _ if support.mathjax || support.katex => continue,
+                    _ => format!(
+                        "Math delimiter `{delimiter}` renders as plain text: math is not enabled in book.toml (set output.html.mathjax-support = true or add [preprocessor.katex])"
+                    ),
+                };
+                violations.push(self.create_violation(message, line, column, Severity::Warning));
+            }
+        }
+
+        violations.sort_by_key(|v| (v.line, v.column));
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::PathBuf;
+    use tempfile::TempDir;
+
+    fn check(content: &str) -> Vec<(usize, usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        MDBOOK038
+            .check_with_context(&doc, None, &LintContext::detached())
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.message))
+            .collect()
+    }
+
+    fn check_in_book(book_toml: &str, content: &str) -> Vec<String> {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::write(dir.path().join("book.toml"), book_toml).unwrap();
+        fs::write(dir.path().join("src/SUMMARY.md"), "").unwrap();
+        let doc = Document::new(content.to_string(), dir.path().join("src/math.md")).unwrap();
+        MDBOOK038
+            .check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_balanced_math_passes() {
+        let content = "# Math\n\nInline \\\\( a^2 + b^2 \\\\) and display:\n\n\\\\[\n\\sum_{i=1}^n i\n\\\\]\n\n$$\nx = 1\n$$\n\n`\\\\(` in code, prices of $5 and $10, and an escaped \\$.\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_unbalanced_delimiters() {
+        let content =
+            "# Math\n\nInline \\\\( a^2 never closes.\n\nStray \\\\] here.\n\n$$\nx = 1\n";
+        assert_eq!(
+            check(content),
+            vec![
+                (
+                    3,
+                    8,
+                    "Inline math opened with `\\\\(` is not closed with `\\\\)` in this paragraph"
+                        .to_string()
+                ),
+                (
+                    5,
+                    7,
+                    "`\\\\]` closes math that was never opened with `\\\\[`".to_string()
+                ),
+                (
+                    7,
+                    1,
+                    "Math block opened with `$$` is never closed".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_math_support_from_book_toml() {
+        let content = "# Math\n\nEuler: $e^{i\\pi} + 1 = 0$.\n\n$$\nx\n$$\n";
+
+        let none = check_in_book("[book]\n", content);
+        assert_eq!(none.len(), 2);
+        assert!(none[0].starts_with("Math delimiter `$` renders as plain text"));
+        assert!(none[1].starts_with("Math delimiter `$$` renders as plain text"));
+
+        let mathjax = check_in_book("[output.html]\nmathjax-support = true\n", content);
+        assert_eq!(
+            mathjax,
+            vec![
+                "Inline `$...$` math only renders with the katex preprocessor; write `\\\\(...\\\\)` for MathJax"
+            ]
+        );
+
+        assert!(check_in_book("[preprocessor.katex]\n", content).is_empty());
+    }
+}
@@ -0,0 +1,232 @@
+//! MDBOOK039: Footnote references and definitions should match
+//!
+//! mdBook renders `[^id]` as a footnote link only when the chapter defines
+//! `[^id]: ...`. A reference without a definition is printed literally, a
+//! definition nobody references is dropped from the page, and a second
+//! definition of the same label is ignored. This rule pairs them up within
+//! each chapter, skipping code blocks and code spans.
+
+use comrak::nodes::AstNode;
+use mdbook_lint_core::error::Result;
+use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// Footnote definitions (`[^id]: text`), with the label captured
+static DEFINITION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s{0,3}\[\^([^\]\s]+)\]:").expect("Invalid regex"));
+
+/// Footnote references (`[^id]`), with the label captured
+static REFERENCE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[\^([^\]\s]+)\]").expect("Invalid regex"));
+
+/// A footnote label as written, with its 1-based position
+struct Label {
+    text: String,
+    line: usize,
+    column: usize,
+}
+
+/// MDBOOK039: Reports undefined, unused, and duplicate footnotes
+///
+/// Labels are matched case-insensitively, as mdBook does. Duplicate
+/// definitions point back at the first one, which is the one mdBook uses.
+/// Nothing is reported when the `footnotes` extension is turned off.
+#[derive(Clone, Default)]
+pub struct MDBOOK039;
+
+impl MDBOOK039 {
+    /// Footnote definitions and references in the document, outside code
+    fn collect(document: &Document) -> (Vec<Label>, Vec<Label>) {
+        let index = document.index();
+        let mut definitions = Vec::new();
+        let mut references = Vec::new();
+
+        for (idx, line) in document.lines.iter().enumerate() {
+            let line_number = idx + 1;
+            if index.is_code_block_line(line_number) || document.is_frontmatter_line(line_number) {
+                continue;
+            }
+            let column = |offset: usize| line[..offset].chars().count() + 1;
+
+            let mut body_start = 0;
+            if let Some(captures) = DEFINITION.captures(line) {
+                let label = captures.get(1).expect("label group");
+                definitions.push(Label {
+                    text: label.as_str().to_string(),
+                    line: line_number,
+                    column: column(label.start() - 2),
+                });
+                body_start = captures.get(0).map_or(0, |m| m.end());
+            }
+
+            for captures in REFERENCE.captures_iter(&line[body_start..]) {
+                let whole = captures.get(0).expect("whole match");
+                let start = body_start + whole.start();
+                if line[..start].ends_with('\\') || index.is_in_code(line_number, start) {
+                    continue;
+                }
+                references.push(Label {
+                    text: captures[1].to_string(),
+                    line: line_number,
+                    column: column(start),
+                });
+            }
+        }
+
+        (definitions, references)
+    }
+}
+
+impl Rule for MDBOOK039 {
+    fn id(&self) -> &'static str {
+        "MDBOOK039"
+    }
+
+    fn name(&self) -> &'static str {
+        "footnotes"
+    }
+
+    fn description(&self) -> &'static str {
+        "Footnote references should have exactly one definition, and definitions should be referenced"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Links).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> Result<Vec<Violation>> {
+        // Without the extension `[^id]` is ordinary text
+        if !document.extensions().footnotes {
+            return Ok(Vec::new());
+        }
+
+        let (definitions, references) = Self::collect(document);
+        let mut violations = Vec::new();
+
+        let mut defined: HashMap<String, &Label> = HashMap::new();
+        for definition in &definitions {
+            let key = definition.text.to_lowercase();
+            if let Some(first) = defined.get(&key) {
+                violations.push(self.create_violation(
+                    format!(
+                        "Footnote '[^{}]' is already defined on line {}",
+                        definition.text, first.line
+                    ),
+                    definition.line,
+                    definition.column,
+                    Severity::Warning,
+                ));
+            } else {
+                defined.insert(key, definition);
+            }
+        }
+
+        for reference in &references {
+            if !defined.contains_key(&reference.text.to_lowercase()) {
+                violations.push(self.create_violation(
+                    format!(
+                        "Footnote reference '[^{}]' has no definition",
+                        reference.text
+                    ),
+                    reference.line,
+                    reference.column,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        for definition in &definitions {
+            let key = definition.text.to_lowercase();
+            let first = defined
+                .get(&key)
+                .is_some_and(|first| first.line == definition.line);
+            if first
+                && !references
+                    .iter()
+                    .any(|reference| reference.text.to_lowercase() == key)
+            {
+                violations.push(self.create_violation(
+                    format!(
+                        "Footnote '[^{}]' is defined but never referenced",
+                        definition.text
+                    ),
+                    definition.line,
+                    definition.column,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        violations.sort_by_key(|v| (v.line, v.column));
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::MarkdownExtensions;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<(usize, usize, String)> {
+        let doc = Document::new(content.to_string(), PathBuf::from("chapter.md")).unwrap();
+        MDBOOK039
+            .check(&doc)
+            .unwrap()
+            .into_iter()
+            .map(|v| (v.line, v.column, v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_matched_footnotes_pass() {
+        let content = "# Notes\n\nA claim[^1] and another[^Source].\n\n[^1]: First note.\n[^source]: Second note, see also[^1].\n\n```markdown\nAn example[^missing].\n```\n\nInline `[^code]`, escaped \\[^text], and an inline note^[like this].\n";
+        assert!(check(content).is_empty(), "{:?}", check(content));
+    }
+
+    #[test]
+    fn test_undefined_unused_and_duplicate() {
+        let content = "# Notes\n\nA claim[^1] and a typo[^2].\n\n[^1]: First note.\n[^1]: Second definition.\n[^old]: Left over.\n";
+        assert_eq!(
+            check(content),
+            vec![
+                (
+                    3,
+                    23,
+                    "Footnote reference '[^2]' has no definition".to_string()
+                ),
+                (
+                    6,
+                    1,
+                    "Footnote '[^1]' is already defined on line 5".to_string()
+                ),
+                (
+                    7,
+                    1,
+                    "Footnote '[^old]' is defined but never referenced".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skipped_without_footnotes_extension() {
+        let doc = Document::new("A typo[^2].\n".to_string(), PathBuf::from("chapter.md"))
+            .unwrap()
+            .with_extensions(MarkdownExtensions {
+                footnotes: false,
+                ..MarkdownExtensions::default()
+            });
+        assert!(MDBOOK039.check(&doc).unwrap().is_empty());
+    }
+}
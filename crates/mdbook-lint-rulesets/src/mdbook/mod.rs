@@ -1,4 +1,4 @@
-//! mdBook-specific linting rules (MDBOOK001-025)
+//! mdBook-specific linting rules (MDBOOK001-039)
 //!
 //! This module contains implementations of mdBook-specific linting rules
 //! that extend standard markdown linting for mdBook projects.
@@ -21,6 +21,21 @@ mod mdbook021;
 mod mdbook022;
 mod mdbook023;
 mod mdbook025;
+mod mdbook026;
+mod mdbook027;
+mod mdbook028;
+mod mdbook029;
+mod mdbook030;
+mod mdbook031;
+mod mdbook032;
+mod mdbook033;
+mod mdbook034;
+mod mdbook035;
+mod mdbook036;
+mod mdbook037;
+mod mdbook038;
+mod mdbook039;
+mod translations;
 
 use crate::{RuleProvider, RuleRegistry};
 use mdbook_lint_core::Config;
@@ -41,6 +56,31 @@ impl RuleProvider for MdBookRuleProvider {
         "0.4.1"
     }
 
+    fn config_schema(&self) -> Option<serde_json::Value> {
+        use mdbook_lint_core::rule::Rule;
+
+        // `[MDBOOK-html]` takes the same options as `[MDBOOK037]`
+        let options: serde_json::Map<String, serde_json::Value> = mdbook037::MDBOOK037::default()
+            .metadata()
+            .options
+            .iter()
+            .map(|option| {
+                let mut schema = option.kind.json_schema();
+                schema["description"] = option.description.into();
+                (option.name.to_string(), schema)
+            })
+            .collect();
+        Some(serde_json::json!({
+            "properties": {
+                "MDBOOK-html": {
+                    "type": "object",
+                    "description": "Raw HTML checks for EPUB and PDF output (MDBOOK037)",
+                    "properties": options,
+                }
+            }
+        }))
+    }
+
     fn register_rules(&self, registry: &mut RuleRegistry) {
         registry.register(Box::new(mdbook001::MDBOOK001));
         registry.register(Box::new(mdbook002::MDBOOK002));
@@ -60,6 +100,20 @@ impl RuleProvider for MdBookRuleProvider {
         registry.register(Box::new(mdbook022::MDBOOK022::default()));
         registry.register(Box::new(mdbook023::MDBOOK023::default()));
         registry.register(Box::new(mdbook025::MDBOOK025));
+        registry.register(Box::new(mdbook026::MDBOOK026::default()));
+        registry.register(Box::new(mdbook027::MDBOOK027::default()));
+        registry.register(Box::new(mdbook028::MDBOOK028));
+        registry.register(Box::new(mdbook029::MDBOOK029::default()));
+        registry.register(Box::new(mdbook030::MDBOOK030::default()));
+        registry.register(Box::new(mdbook031::MDBOOK031::default()));
+        registry.register(Box::new(mdbook032::MDBOOK032::default()));
+        registry.register(Box::new(mdbook033::MDBOOK033));
+        registry.register_collection_rule(Box::new(mdbook034::MDBOOK034::default()));
+        registry.register_collection_rule(Box::new(mdbook035::MDBOOK035::default()));
+        registry.register_collection_rule(Box::new(mdbook036::MDBOOK036::default()));
+        registry.register(Box::new(mdbook037::MDBOOK037::default()));
+        registry.register(Box::new(mdbook038::MDBOOK038));
+        registry.register(Box::new(mdbook039::MDBOOK039));
     }
 
     fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
@@ -95,6 +149,81 @@ impl RuleProvider for MdBookRuleProvider {
 
         registry.register(Box::new(mdbook023::MDBOOK023::default()));
         registry.register(Box::new(mdbook025::MDBOOK025));
+
+        // MDBOOK026 - SUMMARY.md ordering (supports check_gaps/draft_severity)
+        let mdbook026 = match config.and_then(|c| c.rule_configs.get("MDBOOK026")) {
+            Some(cfg) => mdbook026::MDBOOK026::from_config(cfg),
+            None => mdbook026::MDBOOK026::default(),
+        };
+        registry.register(Box::new(mdbook026));
+
+        // MDBOOK027 - directive syntax (supports known_directives)
+        let mdbook027 = match config.and_then(|c| c.rule_configs.get("MDBOOK027")) {
+            Some(cfg) => mdbook027::MDBOOK027::from_config(cfg),
+            None => mdbook027::MDBOOK027::default(),
+        };
+        registry.register(Box::new(mdbook027));
+
+        registry.register(Box::new(mdbook028::MDBOOK028));
+
+        // MDBOOK029 - code block languages (supports allowed_languages/additional_languages)
+        let mdbook029 = match config.and_then(|c| c.rule_configs.get("MDBOOK029")) {
+            Some(cfg) => mdbook029::MDBOOK029::from_config(cfg),
+            None => mdbook029::MDBOOK029::default(),
+        };
+        registry.register(Box::new(mdbook029));
+
+        // MDBOOK030 - image existence and alt text (supports min_alt_length/check_filename_alt)
+        let mdbook030 = match config.and_then(|c| c.rule_configs.get("MDBOOK030")) {
+            Some(cfg) => mdbook030::MDBOOK030::from_config(cfg),
+            None => mdbook030::MDBOOK030::default(),
+        };
+        registry.register(Box::new(mdbook030));
+
+        // MDBOOK031 - image size budget, opt-in (supports max_bytes/max_width/max_height)
+        let mdbook031 = match config.and_then(|c| c.rule_configs.get("MDBOOK031")) {
+            Some(cfg) => mdbook031::MDBOOK031::from_config(cfg),
+            None => mdbook031::MDBOOK031::default(),
+        };
+        registry.register(Box::new(mdbook031));
+
+        // MDBOOK032 - line endings (supports style = consistent/lf/crlf)
+        let mdbook032 = match config.and_then(|c| c.rule_configs.get("MDBOOK032")) {
+            Some(cfg) => mdbook032::MDBOOK032::from_config(cfg),
+            None => mdbook032::MDBOOK032::default(),
+        };
+        registry.register(Box::new(mdbook032));
+
+        registry.register(Box::new(mdbook033::MDBOOK033));
+
+        let rule_config = |id: &str| config.and_then(|c| c.rule_configs.get(id));
+
+        // MDBOOK034-036 - translations in language directories (support languages)
+        let mdbook034 = match rule_config("MDBOOK034") {
+            Some(cfg) => mdbook034::MDBOOK034::from_config(cfg),
+            None => mdbook034::MDBOOK034::default(),
+        };
+        registry.register_collection_rule(Box::new(mdbook034));
+        let mdbook035 = match rule_config("MDBOOK035") {
+            Some(cfg) => mdbook035::MDBOOK035::from_config(cfg),
+            None => mdbook035::MDBOOK035::default(),
+        };
+        registry.register_collection_rule(Box::new(mdbook035));
+        let mdbook036 = match rule_config("MDBOOK036") {
+            Some(cfg) => mdbook036::MDBOOK036::from_config(cfg),
+            None => mdbook036::MDBOOK036::default(),
+        };
+        registry.register_collection_rule(Box::new(mdbook036));
+
+        // MDBOOK037 reads the `[MDBOOK-html]` table (supports renderers/allow)
+        let mdbook037 = match rule_config("MDBOOK-html").or_else(|| rule_config("MDBOOK037")) {
+            Some(cfg) => mdbook037::MDBOOK037::from_config(cfg),
+            None => mdbook037::MDBOOK037::default(),
+        };
+        registry.register(Box::new(mdbook037));
+
+        registry.register(Box::new(mdbook038::MDBOOK038));
+        registry.register(Box::new(mdbook039::MDBOOK039));
     }
 
     fn rule_ids(&self) -> Vec<&'static str> {
@@ -117,6 +246,20 @@ impl RuleProvider for MdBookRuleProvider {
             "MDBOOK022",
             "MDBOOK023",
             "MDBOOK025",
+            "MDBOOK026",
+            "MDBOOK027",
+            "MDBOOK028",
+            "MDBOOK029",
+            "MDBOOK030",
+            "MDBOOK031",
+            "MDBOOK032",
+            "MDBOOK033",
+            "MDBOOK034",
+            "MDBOOK035",
+            "MDBOOK036",
+            "MDBOOK037",
+            "MDBOOK038",
+            "MDBOOK039",
         ]
     }
 }
@@ -0,0 +1,230 @@
+//! Translated books laid out as mirrors of the source tree
+//!
+//! MDBOOK034-036 check books whose translations live in language directories
+//! inside the source directory: `src/intro.md` is translated in
+//! `src/de/intro.md` and `src/ja/intro.md`. A language directory is one
+//! listed in the rule's `languages` option or, when none are listed, a
+//! subdirectory named like a language code (`de`, `pt-BR`, `zh-Hant`) with
+//! its own `SUMMARY.md`.
+//!
+//! Translated chapters are looked up in the collection first and then on
+//! disk, since a book loaded from its source `SUMMARY.md` does not include
+//! them.
+
+use mdbook_lint_core::Document;
+use regex::Regex;
+use std::collections::BTreeSet;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Directory names recognized as languages without configuration
+static LANGUAGE_CODE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^[a-z]{2}(?:[-_](?:[A-Z]{2}|[A-Z][a-z]{3}))?$").expect("Invalid regex")
+});
+
+/// Language codes from a rule's `languages` option
+pub(crate) fn configured_languages(config: &toml::Value) -> Vec<String> {
+    config
+        .get("languages")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str().map(str::to_string))
+        .collect()
+}
+
+/// A source directory with its language directories
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Layout {
+    /// The source directory, holding the original chapters
+    pub root: PathBuf,
+    /// Names of the language directories in `root`, sorted
+    pub languages: Vec<String>,
+}
+
+impl Layout {
+    /// Every translated layout among `documents`
+    ///
+    /// `configured` languages replace detection when not empty.
+    pub fn find(documents: &[Document], configured: &[String]) -> Vec<Self> {
+        let paths: BTreeSet<&Path> = documents.iter().map(|d| d.path.as_path()).collect();
+        let has_summary = |dir: &Path| {
+            paths.contains(dir.join("SUMMARY.md").as_path()) || dir.join("SUMMARY.md").is_file()
+        };
+        let is_language = |dir: &Path| {
+            let Some(name) = dir.file_name().and_then(|n| n.to_str()) else {
+                return false;
+            };
+            if configured.is_empty() {
+                LANGUAGE_CODE.is_match(name) && has_summary(dir)
+            } else {
+                configured.iter().any(|language| language == name)
+            }
+        };
+
+        // A translated chapter's own source directory is its language
+        // directory; the layout's root is the one above it
+        let roots: BTreeSet<PathBuf> = documents
+            .iter()
+            .filter_map(|document| document.src_dir())
+            .map(|dir| match dir.parent() {
+                Some(parent) if is_language(dir) && has_summary(parent) => parent.to_path_buf(),
+                _ => dir.to_path_buf(),
+            })
+            .collect();
+
+        roots
+            .into_iter()
+            .filter_map(|root| {
+                let languages: BTreeSet<String> = std::fs::read_dir(&root)
+                    .into_iter()
+                    .flatten()
+                    .flatten()
+                    .map(|entry| entry.path())
+                    .filter(|path| path.is_dir())
+                    .chain(
+                        paths
+                            .iter()
+                            .filter_map(|path| path.strip_prefix(&root).ok())
+                            .filter(|rest| rest.components().count() > 1)
+                            .filter_map(|rest| rest.components().next())
+                            .map(|first| root.join(first)),
+                    )
+                    .filter(|dir| is_language(dir))
+                    .filter_map(|dir| Some(dir.file_name()?.to_str()?.to_string()))
+                    .collect();
+                (!languages.is_empty()).then(|| Self {
+                    root,
+                    languages: languages.into_iter().collect(),
+                })
+            })
+            .collect()
+    }
+
+    /// Source chapters with their paths relative to the root
+    pub fn sources<'a>(&self, documents: &'a [Document]) -> Vec<(&'a Document, PathBuf)> {
+        documents
+            .iter()
+            .filter_map(|document| {
+                let relative = document.path.strip_prefix(&self.root).ok()?;
+                (self.language_of(&document.path).is_none())
+                    .then(|| (document, relative.to_path_buf()))
+            })
+            .collect()
+    }
+
+    /// Translated chapters with their language and the relative path of the
+    /// source chapter they translate
+    pub fn translations<'a>(
+        &self,
+        documents: &'a [Document],
+    ) -> Vec<(&'a Document, &str, PathBuf)> {
+        documents
+            .iter()
+            .filter_map(|document| {
+                let (language, relative) = self.language_of(&document.path)?;
+                Some((document, language, relative))
+            })
+            .collect()
+    }
+
+    /// The language directory `path` is in and its path relative to it
+    pub fn language_of(&self, path: &Path) -> Option<(&str, PathBuf)> {
+        let rest = path.strip_prefix(&self.root).ok()?;
+        let mut components = rest.components();
+        let first = components.next()?.as_os_str().to_str()?;
+        let language = self.languages.iter().find(|language| *language == first)?;
+        let relative = components.as_path();
+        (!relative.as_os_str().is_empty()).then(|| (language.as_str(), relative.to_path_buf()))
+    }
+
+    /// Path of the `language` version of the chapter at `relative`
+    pub fn translated_path(&self, language: &str, relative: &Path) -> PathBuf {
+        self.root.join(language).join(relative)
+    }
+
+    /// The chapter at `path`, from the collection or from disk
+    pub fn load<'a>(documents: &'a [Document], path: &Path) -> Option<Chapter<'a>> {
+        if let Some(document) = documents.iter().find(|d| d.path == path) {
+            return Some(Chapter::Linted(document));
+        }
+        let bytes = std::fs::read(path).ok()?;
+        Document::from_bytes(&bytes, path.to_path_buf())
+            .ok()
+            .map(|document| Chapter::Read(Box::new(document)))
+    }
+}
+
+/// A chapter found by [`Layout::load`]
+pub(crate) enum Chapter<'a> {
+    /// In the linted collection
+    Linted(&'a Document),
+    /// Read from disk
+    Read(Box<Document>),
+}
+
+impl Deref for Chapter<'_> {
+    type Target = Document;
+
+    fn deref(&self) -> &Document {
+        match self {
+            Chapter::Linted(document) => document,
+            Chapter::Read(document) => document,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_language_codes() {
+        for name in ["de", "pt-BR", "zh_CN", "zh-Hant"] {
+            assert!(LANGUAGE_CODE.is_match(name), "{name}");
+        }
+        for name in ["api", "images", "DE", "pt-br"] {
+            assert!(!LANGUAGE_CODE.is_match(name), "{name}");
+        }
+    }
+
+    #[test]
+    fn test_find_detects_language_directories() {
+        let dir = TempDir::new().unwrap();
+        let src = dir.path().join("src");
+        for path in [
+            "SUMMARY.md",
+            "intro.md",
+            "de/SUMMARY.md",
+            "ja/SUMMARY.md",
+            "ui/page.md",
+        ] {
+            let path = src.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "# Title\n").unwrap();
+        }
+        let documents = vec![
+            Document::new("# Intro\n".to_string(), src.join("intro.md")).unwrap(),
+            Document::new("# Einführung\n".to_string(), src.join("de/intro.md")).unwrap(),
+        ];
+
+        let layouts = Layout::find(&documents, &[]);
+        assert_eq!(
+            layouts,
+            vec![Layout {
+                root: src.clone(),
+                languages: vec!["de".to_string(), "ja".to_string()],
+            }]
+        );
+        let layout = &layouts[0];
+        assert_eq!(layout.sources(&documents).len(), 1);
+        assert_eq!(
+            layout.language_of(&src.join("de/intro.md")),
+            Some(("de", PathBuf::from("intro.md")))
+        );
+        assert!(Layout::find(&documents, &["fr".to_string()]).is_empty());
+    }
+}
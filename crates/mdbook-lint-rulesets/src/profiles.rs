@@ -0,0 +1,171 @@
+//! Named rule profiles
+//!
+//! A profile is a curated starting point: which rules run, how severe their
+//! findings are, and which options they use. New projects can pick one
+//! instead of facing every rule at once:
+//!
+//! - `minimal`: only rules that catch broken output, such as dead links,
+//!   missing includes, and malformed `SUMMARY.md`
+//! - `recommended`: the default rules without the noisiest style checks,
+//!   with broken links reported as errors
+//! - `strict`: every default rule, with broken structure and links reported
+//!   as errors
+//!
+//! Profiles are written in the same TOML as a config file, so a project's own
+//! settings are merged on top of them key by key.
+//!
+//! ```rust
+//! use mdbook_lint_rulesets::profiles;
+//!
+//! let recommended = profiles::profile("recommended").unwrap();
+//! let config = recommended.config().unwrap();
+//! assert!(config.disabled_rules.contains(&"MD013".to_string()));
+//! ```
+
+use mdbook_lint_core::{Config, MdBookLintError, Result};
+
+/// A named set of rules, severities, and rule options
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Profile {
+    /// Name used by `profile = "..."` and `--profile`
+    pub name: &'static str,
+    /// One-line summary for help output
+    pub description: &'static str,
+    /// The profile's settings, in config file TOML
+    pub source: &'static str,
+}
+
+impl Profile {
+    /// The profile's settings as a core configuration
+    pub fn config(&self) -> Result<Config> {
+        toml::from_str(self.source).map_err(|e| {
+            MdBookLintError::config_error(format!("Invalid built-in profile '{}': {e}", self.name))
+        })
+    }
+}
+
+/// The built-in profiles, from least to most thorough
+pub const PROFILES: &[Profile] = &[
+    Profile {
+        name: "minimal",
+        description: "Only rules that catch broken links, includes, and structure",
+        source: MINIMAL,
+    },
+    Profile {
+        name: "recommended",
+        description: "Default rules without the noisiest style checks; broken links are errors",
+        source: RECOMMENDED,
+    },
+    Profile {
+        name: "strict",
+        description: "All rules enabled, with broken structure and links reported as errors",
+        source: STRICT,
+    },
+];
+
+const MINIMAL: &str = r#"
+enabled-rules = [
+    "MD011",      # reversed link syntax
+    "MD042",      # empty links
+    "MD051",      # link fragments that match no heading
+    "MD052",      # undefined reference labels
+    "MD056",      # table rows with the wrong number of cells
+    "MDBOOK002",  # internal links to missing files
+    "MDBOOK003",  # malformed SUMMARY.md
+    "MDBOOK007",  # missing include files
+    "MDBOOK012",  # include line ranges out of bounds
+    "MDBOOK027",  # misspelled or unclosed directives
+    "MDBOOK028",  # duplicate heading IDs
+]
+"#;
+
+const RECOMMENDED: &str = r#"
+disabled-rules = [
+    "MD013",       # line length; prose is easier to edit unwrapped
+    "MD033",       # inline HTML is common and supported in mdBook
+    "MD036",       # emphasis used as a heading
+    "CONTENT003",  # minimum chapter length
+    "CONTENT004",  # heading capitalization
+    "CONTENT005",  # introduction before the first subheading
+]
+
+[severity]
+MD051 = "error"
+MD052 = "error"
+MDBOOK002 = "error"
+MDBOOK003 = "error"
+MDBOOK007 = "error"
+MDBOOK027 = "error"
+
+# Chapters often repeat headings such as "Examples" under different sections
+[MD024]
+siblings_only = true
+"#;
+
+const STRICT: &str = r#"
+[severity]
+MD001 = "error"
+MD025 = "error"
+MD040 = "error"
+MD042 = "error"
+MD045 = "error"
+MD051 = "error"
+MD052 = "error"
+MDBOOK002 = "error"
+MDBOOK003 = "error"
+MDBOOK007 = "error"
+MDBOOK027 = "error"
+MDBOOK028 = "error"
+"#;
+
+/// Look up a built-in profile by name
+pub fn profile(name: &str) -> Option<&'static Profile> {
+    PROFILES.iter().find(|profile| profile.name == name)
+}
+
+/// Names of the built-in profiles, for error messages
+pub fn profile_names() -> Vec<&'static str> {
+    PROFILES.iter().map(|profile| profile.name).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(all(feature = "standard", feature = "mdbook", feature = "content"))]
+    #[test]
+    fn test_profiles_name_known_rules() {
+        use crate::{ContentRuleProvider, MdBookRuleProvider, RuleProvider, StandardRuleProvider};
+
+        let known: Vec<&str> = [
+            StandardRuleProvider.rule_ids(),
+            MdBookRuleProvider.rule_ids(),
+            ContentRuleProvider.rule_ids(),
+        ]
+        .concat();
+
+        for profile in PROFILES {
+            let config = profile.config().unwrap();
+            let named = config
+                .enabled_rules
+                .iter()
+                .chain(&config.disabled_rules)
+                .chain(config.severity.keys())
+                .chain(config.rule_configs.keys());
+            for rule in named {
+                assert!(
+                    known.contains(&rule.as_str()),
+                    "profile '{}' names unknown rule {rule}",
+                    profile.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_profile_lookup() {
+        assert_eq!(profile("minimal").unwrap().name, "minimal");
+        assert!(profile("everything").is_none());
+        assert_eq!(profile_names(), vec!["minimal", "recommended", "strict"]);
+    }
+}
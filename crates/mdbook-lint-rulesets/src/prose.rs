@@ -0,0 +1,239 @@
+//! Helpers for rules that inspect prose
+//!
+//! Prose rules look at words, not markup, so they share one definition of
+//! which lines and spans are prose: fenced and indented code blocks, HTML
+//! comments, frontmatter, and link reference definitions are skipped, and
+//! code spans, URLs, HTML tags, link destinations, and mdBook directives are
+//! blanked out.
+
+use mdbook_lint_core::Document;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Spans on a single line that never contain prose
+static MASK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"\{\{.*?\}\}",                    // mdBook directives
+        r"|<!--.*?-->",                    // inline HTML comments
+        r"|</?[A-Za-z][^>]*>",             // HTML tags and autolinks
+        r"|\]\([^)]*\)",                   // inline link destinations
+        r"|\]\[[^\]]*\]",                  // reference link labels
+        r"|(?:https?|ftp)://\S+|www\.\S+", // bare URLs
+        r"|\S+@\S+\.\S+",                  // email addresses
+    ))
+    .unwrap()
+});
+
+/// Link reference definitions (`[label]: url`)
+static REFERENCE_DEFINITION_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s{0,3}\[[^\]]+\]:\s").unwrap());
+
+/// Collect the prose lines of a document
+///
+/// Returns 1-based line numbers with each line's text masked by
+/// [`mask_line`]. Masked text has the same number of characters as the
+/// original line, so character columns carry over unchanged.
+pub(crate) fn prose_lines(document: &Document) -> Vec<(usize, String)> {
+    let mut lines = Vec::new();
+    let mut fence: Option<(char, usize)> = None;
+    let mut in_html_comment = false;
+
+    for (idx, line) in document.lines.iter().enumerate() {
+        let line_number = idx + 1;
+        let trimmed = line.trim_start();
+
+        // Fenced code blocks close on a fence of the same character at
+        // least as long as the opening one
+        let fence_char = trimmed.chars().next().filter(|c| *c == '`' || *c == '~');
+        if let Some(c) = fence_char {
+            let len = trimmed.chars().take_while(|ch| *ch == c).count();
+            if len >= 3 {
+                match fence {
+                    None => {
+                        fence = Some((c, len));
+                        continue;
+                    }
+                    Some((open_char, open_len)) if open_char == c && len >= open_len => {
+                        fence = None;
+                        continue;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        if fence.is_some() {
+            continue;
+        }
+
+        // Multi-line HTML comments
+        if in_html_comment {
+            if line.contains("-->") {
+                in_html_comment = false;
+            }
+            continue;
+        }
+        if let Some(start) = line.find("<!--")
+            && !line[start..].contains("-->")
+        {
+            in_html_comment = true;
+        }
+
+        if document.is_frontmatter_line(line_number)
+            || line.starts_with("    ")
+            || line.starts_with('\t')
+            || REFERENCE_DEFINITION_REGEX.is_match(line)
+        {
+            continue;
+        }
+
+        let mut masked = mask_line(line);
+        if in_html_comment && let Some(start) = masked.find("<!--") {
+            masked.truncate(start);
+        }
+        lines.push((line_number, masked));
+    }
+
+    lines
+}
+
+/// Replace code spans and non-prose spans with spaces, keeping columns intact
+pub(crate) fn mask_line(line: &str) -> String {
+    let mut chars: Vec<char> = line.chars().collect();
+
+    // Code spans: a run of backticks closed by a run of the same length
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i] != '`' {
+            i += 1;
+            continue;
+        }
+        let open = i;
+        while i < chars.len() && chars[i] == '`' {
+            i += 1;
+        }
+        let ticks = i - open;
+        let mut j = i;
+        let mut close = None;
+        while j < chars.len() {
+            if chars[j] == '`' {
+                let run = j;
+                while j < chars.len() && chars[j] == '`' {
+                    j += 1;
+                }
+                if j - run == ticks {
+                    close = Some(j);
+                    break;
+                }
+            } else {
+                j += 1;
+            }
+        }
+        if let Some(end) = close {
+            chars[open..end].fill(' ');
+            i = end;
+        }
+    }
+
+    let masked: String = chars.into_iter().collect();
+    MASK_REGEX
+        .replace_all(&masked, |caps: &regex::Captures| {
+            " ".repeat(caps[0].chars().count())
+        })
+        .into_owned()
+}
+
+/// Case-insensitive matcher for a word or phrase
+///
+/// Words may be separated by any whitespace. The pattern is only anchored on
+/// word boundaries where the term itself starts or ends with a word
+/// character, so terms like "C++" still match.
+pub(crate) fn term_pattern(term: &str) -> Option<Regex> {
+    let words: Vec<String> = term.split_whitespace().map(regex::escape).collect();
+    if words.is_empty() {
+        return None;
+    }
+
+    let is_word = |c: Option<char>| c.is_some_and(|c| c.is_alphanumeric() || c == '_');
+    let start = if is_word(term.trim().chars().next()) {
+        r"\b"
+    } else {
+        ""
+    };
+    let end = if is_word(term.trim().chars().next_back()) {
+        r"\b"
+    } else {
+        ""
+    };
+
+    Regex::new(&format!(r"(?i){start}{}{end}", words.join(r"\s+"))).ok()
+}
+
+/// Whether the match `start..end` in `masked` is part of a domain name or
+/// path, such as github.com or docs/utilize.md
+pub(crate) fn is_embedded(masked: &str, start: usize, end: usize) -> bool {
+    let before = masked[..start].chars().next_back();
+    let after = masked[end..].chars().next();
+    before.is_some_and(|c| matches!(c, '.' | '/' | '_' | '-' | '@'))
+        || after.is_some_and(|c| matches!(c, '/' | '_' | '-' | '@'))
+        || (after == Some('.')
+            && masked[end + 1..]
+                .chars()
+                .next()
+                .is_some_and(char::is_alphanumeric))
+}
+
+/// Apply the capitalization of `original` to `correction`
+pub(crate) fn match_case(original: &str, correction: &str) -> String {
+    let mut chars = original.chars();
+    let first_upper = chars.next().is_some_and(char::is_uppercase);
+    if first_upper && original.chars().count() > 1 && !original.chars().any(char::is_lowercase) {
+        return correction.to_uppercase();
+    }
+    if first_upper {
+        let mut fixed = correction.chars();
+        if let Some(first) = fixed.next() {
+            return first.to_uppercase().chain(fixed).collect();
+        }
+    }
+    correction.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_mask_line_keeps_columns() {
+        let line = "Run `cargo build` or see <https://example.com> and [docs](a/b.md).";
+        let masked = mask_line(line);
+
+        assert_eq!(masked.chars().count(), line.chars().count());
+        assert!(!masked.contains("cargo"));
+        assert!(!masked.contains("example"));
+        assert!(!masked.contains("b.md"));
+        assert!(masked.contains("docs"));
+        assert_eq!(masked.find("see"), line.find("see"));
+    }
+
+    #[test]
+    fn test_prose_lines_skip_non_prose() {
+        let content = "---\ntitle: x\n---\n\nProse one.\n\n```rust\ncode\n```\n\n\
+                       <!--\nhidden\n-->\n\n    indented\n\n[ref]: https://example.com\n\nProse two.\n";
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+
+        let lines: Vec<usize> = prose_lines(&doc)
+            .into_iter()
+            .filter(|(_, text)| !text.trim().is_empty())
+            .map(|(line, _)| line)
+            .collect();
+        assert_eq!(lines, vec![5, 19]);
+    }
+
+    #[test]
+    fn test_match_case() {
+        assert_eq!(match_case("Teh", "the"), "The");
+        assert_eq!(match_case("TEH", "the"), "THE");
+        assert_eq!(match_case("teh", "the"), "the");
+    }
+}
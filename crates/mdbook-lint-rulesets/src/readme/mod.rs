@@ -0,0 +1,132 @@
+//! README linting rules
+//!
+//! This module provides rules for a project's `README.md`: the first page
+//! visitors see on a code host. They only check files named `README.md`
+//! (ignoring case), and skip READMEs that are chapters of an mdBook, where
+//! `README.md` is the book's introduction rather than a project README.
+//!
+//! # Available Rules
+//!
+//! | Rule | Name | Description |
+//! |------|------|-------------|
+//! | README001 | readme-title | The README starts with a level-1 title |
+//! | README002 | readme-description | A description comes before the first `##` section |
+//! | README003 | readme-badge-syntax | Badges are well-formed `[![alt](image)](link)` |
+//! | README004 | readme-license-section | The README has a License section |
+//! | README005 | readme-relative-links | Relative links point to files in the repository |
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [README004]
+//! headings = ["License", "Licensing"]
+//! ```
+
+mod readme001;
+mod readme002;
+mod readme003;
+mod readme004;
+mod readme005;
+
+use crate::{RuleProvider, RuleRegistry};
+use mdbook_lint_core::{Config, Document};
+
+pub use readme001::Readme001;
+pub use readme002::Readme002;
+pub use readme003::Readme003;
+pub use readme004::Readme004;
+pub use readme005::Readme005;
+
+/// Whether `document` is a project README
+///
+/// A `README.md` inside a book's source directory is a chapter (mdBook's
+/// index page) and is not checked.
+pub fn is_readme(document: &Document) -> bool {
+    document
+        .path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.eq_ignore_ascii_case("readme.md"))
+        && document.src_dir().is_none()
+}
+
+/// Provider for README rules (README001-README005)
+pub struct ReadmeRuleProvider;
+
+impl RuleProvider for ReadmeRuleProvider {
+    fn provider_id(&self) -> &'static str {
+        "readme"
+    }
+
+    fn description(&self) -> &'static str {
+        "README quality rules (README001-README005)"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn register_rules(&self, registry: &mut RuleRegistry) {
+        registry.register(Box::new(Readme001));
+        registry.register(Box::new(Readme002));
+        registry.register(Box::new(Readme003));
+        registry.register(Box::new(Readme004::default()));
+        registry.register(Box::new(Readme005));
+    }
+
+    fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
+        registry.register(Box::new(Readme001));
+        registry.register(Box::new(Readme002));
+        registry.register(Box::new(Readme003));
+        registry.register(Box::new(
+            match config.and_then(|c| c.rule_configs.get("README004")) {
+                Some(c) => Readme004::from_config(c),
+                None => Readme004::default(),
+            },
+        ));
+        registry.register(Box::new(Readme005));
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        vec![
+            "README001",
+            "README002",
+            "README003",
+            "README004",
+            "README005",
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn doc(path: PathBuf) -> Document {
+        Document::new("# Project\n".to_string(), path).unwrap()
+    }
+
+    #[test]
+    fn test_is_readme() {
+        assert!(is_readme(&doc(PathBuf::from("README.md"))));
+        assert!(is_readme(&doc(PathBuf::from("crates/core/Readme.md"))));
+        assert!(!is_readme(&doc(PathBuf::from("docs/intro.md"))));
+        assert!(!is_readme(&doc(PathBuf::from("README.txt"))));
+    }
+
+    #[test]
+    fn test_book_readme_is_a_chapter() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("SUMMARY.md"), "# Summary\n").unwrap();
+        assert!(!is_readme(&doc(dir.path().join("README.md"))));
+
+        let chapter = Document::with_book_src_dir(
+            "# Intro\n".to_string(),
+            PathBuf::from("book/src/README.md"),
+            Some(PathBuf::from("book/src")),
+        )
+        .unwrap();
+        assert!(!is_readme(&chapter));
+    }
+}
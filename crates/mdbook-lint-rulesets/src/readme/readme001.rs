@@ -0,0 +1,106 @@
+//! README001: README title
+//!
+//! Validates that a README opens with a level-1 heading naming the project.
+//! Badges, logos, and HTML may come before it.
+
+use super::is_readme;
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+
+/// README001: Validates that the first heading is a level-1 title
+pub struct Readme001;
+
+impl AstRule for Readme001 {
+    fn id(&self) -> &'static str {
+        "README001"
+    }
+
+    fn name(&self) -> &'static str {
+        "readme-title"
+    }
+
+    fn description(&self) -> &'static str {
+        "README should start with a level-1 title"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_ast<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if !is_readme(document) {
+            return Ok(Vec::new());
+        }
+
+        let first_heading = ast
+            .children()
+            .find(|node| matches!(node.data.borrow().value, NodeValue::Heading(_)));
+
+        let violation = match first_heading {
+            None => Some(("README has no title (a level-1 heading)".to_string(), 1)),
+            Some(heading) if Document::heading_level(heading) != Some(1) => Some((
+                format!(
+                    "README should start with a level-1 title, found level-{} heading '{}'",
+                    Document::heading_level(heading).unwrap_or_default(),
+                    document.node_text(heading).trim()
+                ),
+                document.node_position(heading).map_or(1, |(line, _)| line),
+            )),
+            Some(_) => None,
+        };
+
+        Ok(violation
+            .map(|(message, line)| self.create_violation(message, line, 1, Severity::Warning))
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(path: &str, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from(path)).unwrap();
+        Readme001.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_title() {
+        let content =
+            "[![CI](https://example.com/ci.svg)](https://example.com)\n\n# Project\n\n## Usage\n";
+        assert!(check("README.md", content).is_empty());
+        assert!(check("README.md", "Project\n=======\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_title() {
+        let violations = check("README.md", "Some text\n");
+        assert_eq!(
+            violations[0].message,
+            "README has no title (a level-1 heading)"
+        );
+
+        let violations = check("README.md", "Intro\n\n## Usage\n\n# Project\n");
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(
+            violations[0].message,
+            "README should start with a level-1 title, found level-2 heading 'Usage'"
+        );
+    }
+
+    #[test]
+    fn test_other_files_ignored() {
+        assert!(check("docs/usage.md", "## Usage\n").is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+//! README002: README description
+//!
+//! Validates that a README says what the project is before its first `##`
+//! section. Badges and images alone don't count as a description.
+
+use super::is_readme;
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+
+/// README002: Validates that a description comes before the first section
+pub struct Readme002;
+
+impl Readme002 {
+    /// Whether a block has text outside of images and HTML tags
+    fn has_description<'a>(node: &'a AstNode<'a>) -> bool {
+        match &node.data.borrow().value {
+            NodeValue::Heading(_) | NodeValue::Image(_) => false,
+            NodeValue::Text(text) => !text.trim().is_empty(),
+            NodeValue::Code(code) => !code.literal.trim().is_empty(),
+            NodeValue::HtmlBlock(html) => {
+                let trimmed = html.literal.trim_start();
+                !trimmed.starts_with("<!--") && !strip_tags(&html.literal).trim().is_empty()
+            }
+            _ => node.children().any(Self::has_description),
+        }
+    }
+}
+
+/// Text left in an HTML fragment once its tags are removed
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+impl AstRule for Readme002 {
+    fn id(&self) -> &'static str {
+        "README002"
+    }
+
+    fn name(&self) -> &'static str {
+        "readme-description"
+    }
+
+    fn description(&self) -> &'static str {
+        "README should describe the project before its first section"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_ast<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if !is_readme(document) {
+            return Ok(Vec::new());
+        }
+
+        let mut title_line = 1;
+        for node in ast.children() {
+            match Document::heading_level(node) {
+                Some(1) => {
+                    title_line = document.node_position(node).map_or(1, |(line, _)| line);
+                }
+                Some(_) => break,
+                None if Self::has_description(node) => return Ok(Vec::new()),
+                None => {}
+            }
+        }
+
+        Ok(vec![self.create_violation(
+            "README has no description before its first section".to_string(),
+            title_line,
+            1,
+            Severity::Warning,
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("README.md")).unwrap();
+        Readme002.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_description() {
+        assert!(check("# Project\n\nA linter for books.\n\n## Usage\n").is_empty());
+        assert!(check("<p align=\"center\">A linter for books.</p>\n\n## Usage\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_description() {
+        let content = "# Project\n\n[![CI](https://example.com/ci.svg)](https://example.com)\n\n<!-- badges -->\n\n## Usage\n\nRun it.\n";
+        let violations = check(content);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+        assert_eq!(
+            violations[0].message,
+            "README has no description before its first section"
+        );
+    }
+}
@@ -0,0 +1,142 @@
+//! README003: Badge syntax
+//!
+//! Validates badges: images wrapped in a link, written as
+//! `[![alt](image)](link)`. A missing bracket or parenthesis renders the
+//! badge as literal text, and empty parts leave a broken image or link.
+
+use super::is_readme;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A complete badge: `[![alt](image)](link)`, with inline or reference
+/// destinations and optional titles
+static BADGE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"^\[!\[([^\]]*)\](?:\(\s*([^)\s]*)(?:\s+"[^"]*")?\s*\)|\[([^\]]*)\])\](?:\(\s*([^)\s]*)(?:\s+"[^"]*")?\s*\)|\[([^\]]*)\])"#,
+    )
+    .expect("Invalid regex")
+});
+
+/// README003: Validates `[![alt](image)](link)` badges
+pub struct Readme003;
+
+impl Readme003 {
+    /// The problem with the badge at the start of `text`, if any
+    fn badge_problem(text: &str) -> Option<&'static str> {
+        let Some(caps) = BADGE_REGEX.captures(text) else {
+            return Some("Malformed badge (expected '[![alt](image)](link)')");
+        };
+        let image = caps
+            .get(2)
+            .or_else(|| caps.get(3))
+            .map_or("", |m| m.as_str());
+        let link = caps
+            .get(4)
+            .or_else(|| caps.get(5))
+            .map_or("", |m| m.as_str());
+        if caps[1].trim().is_empty() {
+            Some("Badge has no alt text")
+        } else if image.is_empty() {
+            Some("Badge has no image URL")
+        } else if link.is_empty() {
+            Some("Badge has no link target")
+        } else {
+            None
+        }
+    }
+}
+
+impl AstRule for Readme003 {
+    fn id(&self) -> &'static str {
+        "README003"
+    }
+
+    fn name(&self) -> &'static str {
+        "readme-badge-syntax"
+    }
+
+    fn description(&self) -> &'static str {
+        "README badges should be well-formed [![alt](image)](link)"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: &'a AstNode<'a>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if !is_readme(document) {
+            return Ok(Vec::new());
+        }
+
+        let index = document.index();
+        let mut violations = Vec::new();
+        for (i, line) in document.lines.iter().enumerate() {
+            let line_number = i + 1;
+            if index.is_code_block_line(line_number) {
+                continue;
+            }
+            for (offset, _) in line.match_indices("[![") {
+                if index.is_in_code(line_number, offset) {
+                    continue;
+                }
+                if let Some(problem) = Self::badge_problem(&line[offset..]) {
+                    violations.push(self.create_violation(
+                        problem.to_string(),
+                        line_number,
+                        line[..offset].chars().count() + 1,
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("README.md")).unwrap();
+        Readme003.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_valid_badges() {
+        let content = "# Project\n\n[![CI](https://example.com/ci.svg)](https://example.com/ci) [![Docs][docs-badge]][docs]\n\n`[![not a badge`\n\n```markdown\n[![broken](\n```\n";
+        assert!(check(content).is_empty());
+    }
+
+    #[test]
+    fn test_broken_badges() {
+        let content = "# Project\n\n[![CI](https://example.com/ci.svg)(https://example.com/ci)\n[![](https://example.com/a.svg)](https://example.com)\n[![Crates](https://example.com/c.svg)]()\n[![Docs]()](https://example.com)\n";
+        let violations = check(content);
+        let found: Vec<(usize, &str)> = violations
+            .iter()
+            .map(|v| (v.line, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, "Malformed badge (expected '[![alt](image)](link)')"),
+                (4, "Badge has no alt text"),
+                (5, "Badge has no link target"),
+                (6, "Badge has no image URL"),
+            ]
+        );
+    }
+}
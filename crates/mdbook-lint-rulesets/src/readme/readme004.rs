@@ -0,0 +1,134 @@
+//! README004: License section
+//!
+//! Validates that a README tells readers how the project is licensed, in a
+//! section whose heading mentions the license.
+
+use super::is_readme;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+
+/// Heading words accepted unless `headings` is configured
+const DEFAULT_HEADINGS: &[&str] = &["License", "Licence"];
+
+/// README004: Validates that the README has a License section
+///
+/// A heading at any level counts when it contains one of the configured
+/// words, ignoring case, so "License and attribution" is accepted.
+#[derive(Debug, Clone)]
+pub struct Readme004 {
+    headings: Vec<String>,
+}
+
+impl Default for Readme004 {
+    fn default() -> Self {
+        Self {
+            headings: DEFAULT_HEADINGS.iter().map(|h| h.to_string()).collect(),
+        }
+    }
+}
+
+impl Readme004 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `headings`: words a license heading contains
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+        if let Some(headings) = config.get("headings").and_then(|v| v.as_array()) {
+            rule.headings = headings
+                .iter()
+                .filter_map(|h| h.as_str().map(String::from))
+                .collect();
+        }
+        rule
+    }
+}
+
+impl AstRule for Readme004 {
+    fn id(&self) -> &'static str {
+        "README004"
+    }
+
+    fn name(&self) -> &'static str {
+        "readme-license-section"
+    }
+
+    fn description(&self) -> &'static str {
+        "README should have a License section"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "headings",
+                OptionKind::StringList,
+                "Words a license heading contains",
+            )
+            .defaults_to(r#"["License", "Licence"]"#)
+    }
+
+    fn check_ast<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if !is_readme(document) {
+            return Ok(Vec::new());
+        }
+
+        let has_license = document.headings(ast).into_iter().any(|heading| {
+            let text = document.node_text(heading).to_lowercase();
+            self.headings
+                .iter()
+                .any(|word| text.contains(&word.to_lowercase()))
+        });
+        if has_license {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![self.create_violation(
+            format!("README has no '{}' section", self.headings.join("' or '")),
+            1,
+            1,
+            Severity::Warning,
+        )])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::path::PathBuf;
+
+    fn check(rule: &Readme004, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("README.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_license_section() {
+        let rule = Readme004::default();
+        assert!(check(&rule, "# Project\n\n## License\n\nMIT\n").is_empty());
+        assert!(check(&rule, "# Project\n\n### Licence and credits\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_license_section() {
+        let violations = check(&Readme004::default(), "# Project\n\nLicensed under MIT.\n");
+        assert_eq!(
+            violations[0].message,
+            "README has no 'License' or 'Licence' section"
+        );
+
+        let config: toml::Value = toml::from_str("headings = [\"Copying\"]").unwrap();
+        let rule = Readme004::from_config(&config);
+        assert!(check(&rule, "# Project\n\n## Copying\n").is_empty());
+        assert_eq!(check(&rule, "# Project\n\n## License\n").len(), 1);
+    }
+}
@@ -0,0 +1,128 @@
+//! README005: Relative links
+//!
+//! Validates that relative links and images in a README point to files or
+//! directories that exist in the repository, as they would on a code host.
+
+use super::is_readme;
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::asset::local_reference;
+use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::{
+    Document,
+    violation::{Severity, Violation},
+};
+use std::path::{Path, PathBuf};
+
+/// README005: Validates that relative links resolve within the repository
+///
+/// Paths starting with `/` are resolved from the repository root, the
+/// nearest directory above the README that contains `.git`; they are skipped
+/// when no repository is found.
+pub struct Readme005;
+
+impl Readme005 {
+    /// Where a link from the README at `readme` points on disk
+    fn resolve(readme: &Path, reference: &str) -> Option<PathBuf> {
+        let readme_dir = readme.parent().unwrap_or(Path::new("."));
+        match reference.strip_prefix('/') {
+            Some(rooted) => readme_dir
+                .ancestors()
+                .find(|dir| dir.join(".git").exists())
+                .map(|root| root.join(rooted)),
+            None => Some(readme_dir.join(reference)),
+        }
+    }
+}
+
+impl AstRule for Readme005 {
+    fn id(&self) -> &'static str {
+        "README005"
+    }
+
+    fn name(&self) -> &'static str {
+        "readme-relative-links"
+    }
+
+    fn description(&self) -> &'static str {
+        "README relative links should point to files in the repository"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Links).introduced_in("mdbook-lint v0.15.0")
+    }
+
+    fn check_ast<'a>(
+        &self,
+        document: &Document,
+        ast: &'a AstNode<'a>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if !is_readme(document) {
+            return Ok(Vec::new());
+        }
+
+        let mut violations = Vec::new();
+        for node in ast.descendants() {
+            let url = match &node.data.borrow().value {
+                NodeValue::Link(link) | NodeValue::Image(link) => link.url.clone(),
+                _ => continue,
+            };
+            let Some(reference) = local_reference(&url).filter(|r| !r.is_empty()) else {
+                continue;
+            };
+            let Some(target) = Self::resolve(&document.path, &reference) else {
+                continue;
+            };
+            if !target.exists() {
+                let (line, column) = document.node_position(node).unwrap_or((1, 1));
+                violations.push(self.create_violation(
+                    format!("Relative link '{url}' does not point to a file in the repository"),
+                    line,
+                    column,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::rule::Rule;
+    use std::fs;
+
+    #[test]
+    fn test_relative_links() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        fs::create_dir_all(dir.path().join("docs/guide")).unwrap();
+        fs::write(dir.path().join("LICENSE"), "MIT").unwrap();
+        fs::write(dir.path().join("docs/logo.png"), "").unwrap();
+
+        let content = "# Project\n\n![logo](docs/logo.png)\n\nSee the [guide](docs/guide/), [license](./LICENSE#L1), [home](https://example.com), and [usage](#usage).\n\nAlso [contributing](CONTRIBUTING.md) and [changes](/CHANGELOG.md?plain=1).\n";
+        let doc = Document::new(content.to_string(), dir.path().join("README.md")).unwrap();
+        let violations = Readme005.check(&doc).unwrap();
+
+        let found: Vec<(usize, usize, &str)> = violations
+            .iter()
+            .map(|v| (v.line, v.column, v.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    7,
+                    6,
+                    "Relative link 'CONTRIBUTING.md' does not point to a file in the repository"
+                ),
+                (
+                    7,
+                    42,
+                    "Relative link '/CHANGELOG.md?plain=1' does not point to a file in the repository"
+                ),
+            ]
+        );
+    }
+}
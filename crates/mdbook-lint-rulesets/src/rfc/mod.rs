@@ -0,0 +1,224 @@
+//! RFC and design document linting rules
+//!
+//! This module provides rules for RFC-style design documents: numbered
+//! proposals such as `rfcs/0012-async-plugins.md` that move through a review
+//! process. Only documents in an RFC directory whose file name starts with a
+//! number are checked, so a `README.md` or index page next to them is left
+//! alone.
+//!
+//! # Available Rules
+//!
+//! | Rule | Name | Description |
+//! |------|------|-------------|
+//! | RFC001 | rfc-required-sections | Summary, Motivation, Alternatives, and Unresolved Questions are present |
+//! | RFC002 | rfc-status-frontmatter | Front matter declares a recognized `status` |
+//! | RFC003 | rfc-sequential-numbering | RFC numbers are unique and have no gaps (collection) |
+//! | RFC004 | rfc-valid-links | Links to other RFCs point to existing documents (collection) |
+//!
+//! # Configuration
+//!
+//! ```toml
+//! # Provider-level settings (affect all RFC rules)
+//! [RFC]
+//! directories = ["rfcs", "docs/design"]
+//!
+//! [RFC001]
+//! required-sections = ["Summary", "Motivation", "Alternatives"]
+//!
+//! [RFC002]
+//! statuses = ["draft", "accepted", "rejected"]
+//! ```
+
+mod rfc001;
+mod rfc002;
+mod rfc003;
+mod rfc004;
+
+use crate::util::string_list;
+use crate::{RuleProvider, RuleRegistry};
+use mdbook_lint_core::{Config, Document};
+use std::path::Path;
+
+pub use rfc001::Rfc001;
+pub use rfc002::Rfc002;
+pub use rfc003::Rfc003;
+pub use rfc004::Rfc004;
+
+/// Directories that hold RFCs unless `directories` is configured
+const DEFAULT_DIRECTORIES: &[&str] = &["rfcs", "rfc"];
+
+/// Which documents the RFC rules check
+///
+/// Entries in `directories` may span several path segments, such as
+/// `docs/design`, and match anywhere in a document's path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RfcScope {
+    directories: Vec<String>,
+}
+
+impl Default for RfcScope {
+    fn default() -> Self {
+        Self {
+            directories: DEFAULT_DIRECTORIES.iter().map(|d| d.to_string()).collect(),
+        }
+    }
+}
+
+impl RfcScope {
+    /// Read `directories` from a rule or `[RFC]` configuration
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut scope = Self::default();
+        if let Some(directories) = string_list(config, &["directories"]) {
+            scope.directories = directories
+                .iter()
+                .map(|dir| dir.replace('\\', "/").trim_matches('/').to_lowercase())
+                .filter(|dir| !dir.is_empty())
+                .collect();
+        }
+        scope
+    }
+
+    /// Whether `path` is inside one of the RFC directories
+    pub fn contains(&self, path: &Path) -> bool {
+        let normalized = format!(
+            "/{}",
+            path.to_string_lossy().replace('\\', "/").to_lowercase()
+        );
+        self.directories
+            .iter()
+            .any(|dir| normalized.contains(&format!("/{dir}/")))
+    }
+
+    /// The RFC number of a document in scope, or `None` if it isn't an RFC
+    pub fn rfc_number(&self, document: &Document) -> Option<u32> {
+        if self.contains(&document.path) {
+            rfc_number(&document.path)
+        } else {
+            None
+        }
+    }
+}
+
+/// The number at the start of an RFC file name, as in `0012-async-plugins.md`
+pub fn rfc_number(path: &Path) -> Option<u32> {
+    let name = path.file_name()?.to_str()?;
+    let (number, rest) = name.split_once(['-', '_'])?;
+    if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit()) || rest.is_empty() {
+        return None;
+    }
+    number.parse().ok()
+}
+
+/// Provider for RFC and design document rules (RFC001-RFC004)
+pub struct RfcRuleProvider;
+
+impl RuleProvider for RfcRuleProvider {
+    fn provider_id(&self) -> &'static str {
+        "rfc"
+    }
+
+    fn description(&self) -> &'static str {
+        "RFC and design document rules (RFC001-RFC004)"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn config_schema(&self) -> Option<serde_json::Value> {
+        Some(serde_json::json!({
+            "properties": {
+                "RFC": {
+                    "type": "object",
+                    "description": "Settings shared by every RFC rule",
+                    "properties": {
+                        "directories": {"type": "array", "items": {"type": "string"}}
+                    }
+                }
+            }
+        }))
+    }
+
+    fn register_rules(&self, registry: &mut RuleRegistry) {
+        registry.register(Box::new(Rfc001::default()));
+        registry.register(Box::new(Rfc002::default()));
+        registry.register_collection_rule(Box::new(Rfc003::default()));
+        registry.register_collection_rule(Box::new(Rfc004::default()));
+    }
+
+    fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
+        // A provider-level `[RFC]` block (e.g. `directories = ["design"]`)
+        // applies to every RFC rule; a per-rule `[RFCxxx]` block overrides it.
+        let provider_cfg = config.and_then(|c| c.rule_configs.get("RFC"));
+        let cfg = |id: &str| config.and_then(|c| c.rule_configs.get(id)).or(provider_cfg);
+
+        registry.register(Box::new(match cfg("RFC001") {
+            Some(c) => Rfc001::from_config(c),
+            None => Rfc001::default(),
+        }));
+        registry.register(Box::new(match cfg("RFC002") {
+            Some(c) => Rfc002::from_config(c),
+            None => Rfc002::default(),
+        }));
+        registry.register_collection_rule(Box::new(match cfg("RFC003") {
+            Some(c) => Rfc003::from_config(c),
+            None => Rfc003::default(),
+        }));
+        registry.register_collection_rule(Box::new(match cfg("RFC004") {
+            Some(c) => Rfc004::from_config(c),
+            None => Rfc004::default(),
+        }));
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        vec!["RFC001", "RFC002", "RFC003", "RFC004"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_rfc_number() {
+        assert_eq!(rfc_number(Path::new("rfcs/0012-async.md")), Some(12));
+        assert_eq!(rfc_number(Path::new("rfcs/3_plugins.md")), Some(3));
+        assert_eq!(rfc_number(Path::new("rfcs/README.md")), None);
+        assert_eq!(rfc_number(Path::new("rfcs/2024.md")), None);
+    }
+
+    #[test]
+    fn test_scope_directories() {
+        let default = RfcScope::default();
+        assert!(default.contains(Path::new("project/rfcs/0001-x.md")));
+        assert!(!default.contains(Path::new("docs/guide/0001-x.md")));
+
+        let config: toml::Value = toml::from_str("directories = [\"docs/design/\"]").unwrap();
+        let scope = RfcScope::from_config(&config);
+        assert!(scope.contains(Path::new("docs/design/0001-x.md")));
+        assert!(!scope.contains(Path::new("rfcs/0001-x.md")));
+
+        let doc = Document::new(String::new(), PathBuf::from("docs/design/README.md")).unwrap();
+        assert_eq!(scope.rfc_number(&doc), None);
+    }
+
+    #[test]
+    fn test_provider_directories_reach_rules() {
+        let config: Config = toml::from_str("[RFC]\ndirectories = [\"design\"]\n").unwrap();
+        let mut registry = mdbook_lint_core::PluginRegistry::new();
+        registry
+            .register_provider(Box::new(RfcRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+
+        let doc = Document::new(
+            "# Async plugins\n".to_string(),
+            PathBuf::from("design/0001-async-plugins.md"),
+        )
+        .unwrap();
+        let violations = engine.lint_document(&doc).unwrap();
+        assert!(violations.iter().any(|v| v.rule_id == "RFC001"));
+        assert!(violations.iter().any(|v| v.rule_id == "RFC002"));
+    }
+}
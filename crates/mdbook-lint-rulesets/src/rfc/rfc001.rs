@@ -0,0 +1,190 @@
+//! RFC001: Required RFC sections
+//!
+//! Validates that an RFC has the sections reviewers expect: Summary,
+//! Motivation, Alternatives, and Unresolved Questions by default.
+
+use super::RfcScope;
+use crate::util::string_list;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// Sections every RFC needs unless `required-sections` is configured
+const DEFAULT_SECTIONS: &[&str] = &[
+    "Summary",
+    "Motivation",
+    "Alternatives",
+    "Unresolved Questions",
+];
+
+/// RFC001: Validates that an RFC has its required sections
+///
+/// A section is present when any heading contains its name, ignoring case,
+/// so "Rationale and alternatives" satisfies "Alternatives".
+#[derive(Debug, Clone)]
+pub struct Rfc001 {
+    scope: RfcScope,
+    sections: Vec<String>,
+}
+
+impl Default for Rfc001 {
+    fn default() -> Self {
+        Self {
+            scope: RfcScope::default(),
+            sections: DEFAULT_SECTIONS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Rfc001 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `directories`: directories that hold RFCs (default `["rfcs", "rfc"]`)
+    /// - `required-sections`: section names every RFC needs
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            scope: RfcScope::from_config(config),
+            ..Self::default()
+        };
+        if let Some(sections) = string_list(config, &["required-sections", "required_sections"]) {
+            rule.sections = sections;
+        }
+        rule
+    }
+}
+
+impl Rule for Rfc001 {
+    fn id(&self) -> &'static str {
+        "RFC001"
+    }
+
+    fn name(&self) -> &'static str {
+        "rfc-required-sections"
+    }
+
+    fn description(&self) -> &'static str {
+        "RFC must have the required sections"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "directories",
+                OptionKind::StringList,
+                "Directories that hold RFCs",
+            )
+            .defaults_to(r#"["rfcs", "rfc"]"#)
+            .option(
+                "required-sections",
+                OptionKind::StringList,
+                "Section names every RFC needs",
+            )
+            .defaults_to(r#"["Summary", "Motivation", "Alternatives", "Unresolved Questions"]"#)
+            .either_case()
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if self.scope.rfc_number(document).is_none() {
+            return Ok(Vec::new());
+        }
+
+        let arena = comrak::Arena::new();
+        let ast = document.parse_ast(&arena);
+        let headings: Vec<String> = document
+            .headings(ast)
+            .into_iter()
+            .map(|heading| document.node_text(heading).to_lowercase())
+            .collect();
+
+        let violations = self
+            .sections
+            .iter()
+            .filter(|section| {
+                let section = section.to_lowercase();
+                !headings.iter().any(|heading| heading.contains(&section))
+            })
+            .map(|section| {
+                self.create_violation(
+                    format!("RFC is missing a '{section}' section"),
+                    1,
+                    1,
+                    Severity::Warning,
+                )
+            })
+            .collect();
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn doc(path: &str, content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from(path)).unwrap()
+    }
+
+    #[test]
+    fn test_all_sections_present() {
+        let content = "# Async plugins\n\n## Summary\n\n## Motivation\n\n## Rationale and alternatives\n\n## Unresolved questions\n";
+        let violations = Rfc001::default()
+            .check(&doc("rfcs/0001-async-plugins.md", content))
+            .unwrap();
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_missing_sections() {
+        let content = "# Async plugins\n\n## Summary\n\n```markdown\n## Motivation\n```\n";
+        let violations = Rfc001::default()
+            .check(&doc("rfcs/0001-async-plugins.md", content))
+            .unwrap();
+        let messages: Vec<&str> = violations.iter().map(|v| v.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "RFC is missing a 'Motivation' section",
+                "RFC is missing a 'Alternatives' section",
+                "RFC is missing a 'Unresolved Questions' section",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_outside_rfc_directory_or_unnumbered() {
+        let rule = Rfc001::default();
+        assert!(
+            rule.check(&doc("guide/0001-intro.md", "# Intro\n"))
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            rule.check(&doc("rfcs/README.md", "# RFCs\n"))
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_configured_sections() {
+        let config: toml::Value = toml::from_str(
+            "directories = [\"design\"]\nrequired-sections = [\"Summary\", \"Drawbacks\"]",
+        )
+        .unwrap();
+        let rule = Rfc001::from_config(&config);
+        let violations = rule
+            .check(&doc("design/0002-cache.md", "# Cache\n\n## Summary\n"))
+            .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("'Drawbacks'"));
+    }
+}
@@ -0,0 +1,190 @@
+//! RFC002: RFC status in front matter
+//!
+//! Validates that an RFC declares where it stands in the review process with
+//! a `status` key in its YAML or TOML front matter.
+
+use super::RfcScope;
+use crate::util::string_list;
+use comrak::nodes::AstNode;
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+
+/// Statuses accepted unless `statuses` is configured
+const DEFAULT_STATUSES: &[&str] = &[
+    "draft",
+    "proposed",
+    "accepted",
+    "rejected",
+    "postponed",
+    "withdrawn",
+    "implemented",
+];
+
+/// RFC002: Validates the `status` front matter key of an RFC
+#[derive(Debug, Clone)]
+pub struct Rfc002 {
+    scope: RfcScope,
+    /// Recognized status values (lowercase for comparison)
+    statuses: Vec<String>,
+}
+
+impl Default for Rfc002 {
+    fn default() -> Self {
+        Self {
+            scope: RfcScope::default(),
+            statuses: DEFAULT_STATUSES.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl Rfc002 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `directories`: directories that hold RFCs (default `["rfcs", "rfc"]`)
+    /// - `statuses`: recognized status values, compared case-insensitively
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            scope: RfcScope::from_config(config),
+            ..Self::default()
+        };
+        if let Some(statuses) = string_list(config, &["statuses"]) {
+            rule.statuses = statuses.iter().map(|s| s.to_lowercase()).collect();
+        }
+        rule
+    }
+}
+
+impl Rule for Rfc002 {
+    fn id(&self) -> &'static str {
+        "RFC002"
+    }
+
+    fn name(&self) -> &'static str {
+        "rfc-status-frontmatter"
+    }
+
+    fn description(&self) -> &'static str {
+        "RFC front matter must declare a recognized status"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "directories",
+                OptionKind::StringList,
+                "Directories that hold RFCs",
+            )
+            .defaults_to(r#"["rfcs", "rfc"]"#)
+            .option(
+                "statuses",
+                OptionKind::StringList,
+                "Recognized status values",
+            )
+            .defaults_to(concat!(
+                r#"["draft", "proposed", "accepted", "rejected", "#,
+                r#""postponed", "withdrawn", "implemented"]"#
+            ))
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        if self.scope.rfc_number(document).is_none() {
+            return Ok(Vec::new());
+        }
+
+        let Some(front_matter) = document.front_matter() else {
+            return Ok(vec![self.create_violation(
+                "RFC has no front matter declaring its status".to_string(),
+                1,
+                1,
+                Severity::Warning,
+            )]);
+        };
+
+        // Front matter that doesn't parse is left to FRONTMATTER001
+        let Ok(value) = front_matter.parse() else {
+            return Ok(Vec::new());
+        };
+
+        let violation = match value.get("status") {
+            None => Some((
+                "RFC front matter is missing 'status'".to_string(),
+                front_matter.start_line,
+            )),
+            Some(status) => {
+                let status = status.as_str().unwrap_or_default();
+                (!self.statuses.contains(&status.to_lowercase())).then(|| {
+                    (
+                        format!(
+                            "Unrecognized RFC status '{status}' (expected one of: {})",
+                            self.statuses.join(", ")
+                        ),
+                        front_matter
+                            .key_line("status")
+                            .unwrap_or(front_matter.start_line),
+                    )
+                })
+            }
+        };
+
+        Ok(violation
+            .map(|(message, line)| self.create_violation(message, line, 1, Severity::Warning))
+            .into_iter()
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &Rfc002, content: &str) -> Vec<Violation> {
+        let doc =
+            Document::new(content.to_string(), PathBuf::from("rfcs/0001-plugins.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_valid_status() {
+        let rule = Rfc002::default();
+        assert!(check(&rule, "---\nstatus: Accepted\n---\n\n# Plugins\n").is_empty());
+        assert!(check(&rule, "+++\nstatus = \"draft\"\n+++\n\n# Plugins\n").is_empty());
+    }
+
+    #[test]
+    fn test_missing_status() {
+        let rule = Rfc002::default();
+        let violations = check(&rule, "# Plugins\n");
+        assert_eq!(
+            violations[0].message,
+            "RFC has no front matter declaring its status"
+        );
+
+        let violations = check(&rule, "---\ntitle: Plugins\n---\n\n# Plugins\n");
+        assert_eq!(
+            violations[0].message,
+            "RFC front matter is missing 'status'"
+        );
+        assert_eq!(violations[0].line, 1);
+    }
+
+    #[test]
+    fn test_unrecognized_status() {
+        let config: toml::Value = toml::from_str("statuses = [\"open\", \"closed\"]").unwrap();
+        let rule = Rfc002::from_config(&config);
+        let violations = check(&rule, "---\ntitle: Plugins\nstatus: accepted\n---\n");
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(
+            violations[0].message,
+            "Unrecognized RFC status 'accepted' (expected one of: open, closed)"
+        );
+    }
+}
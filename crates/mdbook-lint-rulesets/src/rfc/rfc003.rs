@@ -0,0 +1,192 @@
+//! RFC003: Sequential RFC numbering
+//!
+//! Validates that RFC numbers are unique and, by default, sequential with no
+//! gaps. This rule analyzes all RFC documents in a collection.
+
+use super::RfcScope;
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+use std::collections::BTreeMap;
+
+/// RFC003: Validates that RFC numbers are unique and sequential
+///
+/// RFCs are numbered by the prefix of their file name. Numbering may start at
+/// 0 (often a template) or 1. Projects that number RFCs by pull request can
+/// set `sequential = false` to check only for duplicates.
+#[derive(Debug, Clone)]
+pub struct Rfc003 {
+    scope: RfcScope,
+    sequential: bool,
+}
+
+impl Default for Rfc003 {
+    fn default() -> Self {
+        Self {
+            scope: RfcScope::default(),
+            sequential: true,
+        }
+    }
+}
+
+impl Rfc003 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys:
+    /// - `directories`: directories that hold RFCs (default `["rfcs", "rfc"]`)
+    /// - `sequential`: report gaps in the numbering (default true)
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self {
+            scope: RfcScope::from_config(config),
+            ..Self::default()
+        };
+        if let Some(sequential) = config.get("sequential").and_then(|v| v.as_bool()) {
+            rule.sequential = sequential;
+        }
+        rule
+    }
+}
+
+impl CollectionRule for Rfc003 {
+    fn id(&self) -> &'static str {
+        "RFC003"
+    }
+
+    fn name(&self) -> &'static str {
+        "rfc-sequential-numbering"
+    }
+
+    fn description(&self) -> &'static str {
+        "RFC numbers should be unique and sequential"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "directories",
+                OptionKind::StringList,
+                "Directories that hold RFCs",
+            )
+            .defaults_to(r#"["rfcs", "rfc"]"#)
+            .option(
+                "sequential",
+                OptionKind::Boolean,
+                "Report gaps in the numbering",
+            )
+            .defaults_to("true")
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        let mut by_number: BTreeMap<u32, Vec<&Document>> = BTreeMap::new();
+        for doc in documents {
+            if let Some(number) = self.scope.rfc_number(doc) {
+                by_number.entry(number).or_default().push(doc);
+            }
+        }
+
+        for (number, docs) in &by_number {
+            for doc in &docs[1..] {
+                violations.push(self.create_violation_for_file(
+                    &doc.path,
+                    format!(
+                        "RFC number {number} is already used by {}",
+                        docs[0].path.display()
+                    ),
+                    1,
+                    1,
+                    Severity::Warning,
+                ));
+            }
+        }
+
+        if !self.sequential {
+            return Ok(violations);
+        }
+
+        let mut expected = match by_number.keys().next() {
+            Some(0) => 0,
+            Some(_) => 1,
+            None => return Ok(violations),
+        };
+        for (&number, docs) in &by_number {
+            if number > expected {
+                let missing = if number - expected == 1 {
+                    format!("Missing RFC number {expected}")
+                } else {
+                    format!("Missing RFC numbers {expected}-{}", number - 1)
+                };
+                violations.push(self.create_violation_for_file(
+                    &docs[0].path,
+                    format!("{missing} (gap before RFC {number})"),
+                    1,
+                    1,
+                    Severity::Warning,
+                ));
+            }
+            expected = number + 1;
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn rfcs(paths: &[&str]) -> Vec<Document> {
+        paths
+            .iter()
+            .map(|path| Document::new("# RFC\n".to_string(), PathBuf::from(path)).unwrap())
+            .collect()
+    }
+
+    fn messages(rule: &Rfc003, docs: &[Document]) -> Vec<String> {
+        rule.check_collection(docs)
+            .unwrap()
+            .into_iter()
+            .map(|v| format!("{}: {}", v.path.unwrap().display(), v.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_sequential() {
+        let docs = rfcs(&[
+            "rfcs/0000-template.md",
+            "rfcs/0001-a.md",
+            "rfcs/0002-b.md",
+            "rfcs/README.md",
+            "guide/0007-unrelated.md",
+        ]);
+        assert!(messages(&Rfc003::default(), &docs).is_empty());
+    }
+
+    #[test]
+    fn test_gaps_and_duplicates() {
+        let docs = rfcs(&[
+            "rfcs/0001-a.md",
+            "rfcs/0002-b.md",
+            "rfcs/0002-c.md",
+            "rfcs/0005-d.md",
+        ]);
+        assert_eq!(
+            messages(&Rfc003::default(), &docs),
+            vec![
+                "rfcs/0002-c.md: RFC number 2 is already used by rfcs/0002-b.md",
+                "rfcs/0005-d.md: Missing RFC numbers 3-4 (gap before RFC 5)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_non_sequential_numbering() {
+        let config: toml::Value = toml::from_str("sequential = false").unwrap();
+        let docs = rfcs(&["rfcs/0101-a.md", "rfcs/0245-b.md"]);
+        assert!(messages(&Rfc003::from_config(&config), &docs).is_empty());
+        assert_eq!(messages(&Rfc003::default(), &docs).len(), 2);
+    }
+}
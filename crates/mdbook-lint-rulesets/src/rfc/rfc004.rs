@@ -0,0 +1,176 @@
+//! RFC004: Valid RFC links
+//!
+//! Validates that links from one RFC to another point to an existing RFC.
+//! This rule analyzes all RFC documents in a collection.
+
+use super::{RfcScope, rfc_number};
+use mdbook_lint_core::rule::{CollectionRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::Severity;
+use mdbook_lint_core::{Document, Result, Violation};
+use regex::Regex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Regex for a markdown link to a `.md` file, capturing the target path
+static MD_LINK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\[[^\]]*\]\(\s*([^)\s#]+\.md)(?:#[^)\s]*)?\s*\)").expect("Invalid regex")
+});
+
+/// RFC004: Validates that links to other RFCs resolve
+///
+/// Only links whose file name starts with an RFC number, such as
+/// `0007-caching.md`, are checked. A target is valid when it is one of the
+/// linted RFCs or exists on disk.
+#[derive(Debug, Clone, Default)]
+pub struct Rfc004 {
+    scope: RfcScope,
+}
+
+impl Rfc004 {
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized key:
+    /// - `directories`: directories that hold RFCs (default `["rfcs", "rfc"]`)
+    pub fn from_config(config: &toml::Value) -> Self {
+        Self {
+            scope: RfcScope::from_config(config),
+        }
+    }
+
+    /// Resolve a relative link against the directory of the linking document
+    fn resolve(source: &Path, link: &str) -> PathBuf {
+        let mut resolved = source.parent().map(Path::to_path_buf).unwrap_or_default();
+        for component in link.split(['/', '\\']) {
+            match component {
+                "" | "." => {}
+                ".." => {
+                    resolved.pop();
+                }
+                other => resolved.push(other),
+            }
+        }
+        resolved
+    }
+
+    fn normalize(path: &Path) -> String {
+        path.to_string_lossy().replace('\\', "/").to_lowercase()
+    }
+}
+
+impl CollectionRule for Rfc004 {
+    fn id(&self) -> &'static str {
+        "RFC004"
+    }
+
+    fn name(&self) -> &'static str {
+        "rfc-valid-links"
+    }
+
+    fn description(&self) -> &'static str {
+        "Links to other RFCs should point to existing documents"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Links)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "directories",
+                OptionKind::StringList,
+                "Directories that hold RFCs",
+            )
+            .defaults_to(r#"["rfcs", "rfc"]"#)
+    }
+
+    fn check_collection(&self, documents: &[Document]) -> Result<Vec<Violation>> {
+        let mut violations = Vec::new();
+
+        let rfcs: Vec<&Document> = documents
+            .iter()
+            .filter(|doc| self.scope.rfc_number(doc).is_some())
+            .collect();
+        let known: HashSet<String> = rfcs.iter().map(|doc| Self::normalize(&doc.path)).collect();
+
+        for doc in rfcs {
+            let mut in_code_block = false;
+            for (index, line) in doc.lines.iter().enumerate() {
+                let trimmed = line.trim_start();
+                if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                    in_code_block = !in_code_block;
+                    continue;
+                }
+                if in_code_block {
+                    continue;
+                }
+
+                for caps in MD_LINK_REGEX.captures_iter(line) {
+                    let link = caps.get(1).expect("capture group 1");
+                    if link.as_str().contains("://")
+                        || rfc_number(Path::new(link.as_str())).is_none()
+                    {
+                        continue;
+                    }
+                    let target = Self::resolve(&doc.path, link.as_str());
+                    if known.contains(&Self::normalize(&target)) || target.exists() {
+                        continue;
+                    }
+                    let column = line[..link.start()].chars().count() + 1;
+                    violations.push(self.create_violation_for_file(
+                        &doc.path,
+                        format!("Link to RFC '{}' does not match any RFC", link.as_str()),
+                        index + 1,
+                        column,
+                        Severity::Warning,
+                    ));
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rfc(path: &str, content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from(path)).unwrap()
+    }
+
+    #[test]
+    fn test_links_between_rfcs() {
+        let docs = vec![
+            rfc(
+                "rfcs/0001-plugins.md",
+                "# Plugins\n\nSee [caching](0002-caching.md#summary).\n",
+            ),
+            rfc(
+                "rfcs/0002-caching.md",
+                "# Caching\n\nBuilds on [RFC 1](./0001-plugins.md) and [the guide](../guide/intro.md).\n",
+            ),
+        ];
+        assert!(
+            Rfc004::default()
+                .check_collection(&docs)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_broken_rfc_link() {
+        let docs = vec![rfc(
+            "rfcs/0001-plugins.md",
+            "# Plugins\n\n```markdown\n[old](0003-old.md)\n```\n\nReplaces [RFC 9](0009-missing.md).\n",
+        )];
+        let violations = Rfc004::default().check_collection(&docs).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!((violations[0].line, violations[0].column), (7, 18));
+        assert!(
+            violations[0]
+                .message
+                .contains("'0009-missing.md' does not match any RFC")
+        );
+    }
+}
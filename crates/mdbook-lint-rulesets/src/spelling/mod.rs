@@ -0,0 +1,100 @@
+//! Spelling rules
+//!
+//! This module provides spell checking for prose. Code, URLs, and HTML are
+//! skipped. Without configuration only a built-in list of common typos is
+//! checked; add dictionaries to flag every unknown word.
+//!
+//! # Available Rules
+//!
+//! | Rule | Name | Description |
+//! |------|------|-------------|
+//! | SPELL001 | spelling | Words in prose are spelled correctly |
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [SPELL001]
+//! dictionary = ["/usr/share/dict/words", "book-words.txt"]
+//! words = ["mdBook", "rustdoc", "Ferris"]
+//! min-length = 3
+//!
+//! [SPELL001.corrections]
+//! colour = "color"
+//! ```
+
+mod spell001;
+mod typos;
+
+use crate::{RuleProvider, RuleRegistry};
+use mdbook_lint_core::Config;
+
+pub use spell001::SPELL001;
+
+/// Provider for spelling rules (SPELL001+)
+pub struct SpellingRuleProvider;
+
+impl RuleProvider for SpellingRuleProvider {
+    fn provider_id(&self) -> &'static str {
+        "spelling"
+    }
+
+    fn description(&self) -> &'static str {
+        "Spelling rules (SPELL001)"
+    }
+
+    fn version(&self) -> &'static str {
+        "0.1.0"
+    }
+
+    fn register_rules(&self, registry: &mut RuleRegistry) {
+        registry.register(Box::new(SPELL001::default()));
+    }
+
+    fn register_rules_with_config(&self, registry: &mut RuleRegistry, config: Option<&Config>) {
+        let spell001 = match config.and_then(|c| c.rule_configs.get("SPELL001")) {
+            Some(c) => SPELL001::from_config(c),
+            None => SPELL001::default(),
+        };
+        registry.register(Box::new(spell001));
+    }
+
+    fn rule_ids(&self) -> Vec<&'static str> {
+        vec!["SPELL001"]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mdbook_lint_core::{Document, PluginRegistry};
+    use std::path::PathBuf;
+
+    /// Config set on the provider reaches the rule.
+    #[test]
+    fn test_provider_threads_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [SPELL001]
+            words = ["teh"]
+            "#,
+        )
+        .unwrap();
+
+        let mut registry = PluginRegistry::new();
+        registry
+            .register_provider(Box::new(SpellingRuleProvider))
+            .unwrap();
+        let engine = registry.create_engine_with_config(Some(&config)).unwrap();
+
+        let doc = Document::new(
+            "# Intro\n\nTeh end, we recieve.\n".to_string(),
+            PathBuf::from("intro.md"),
+        )
+        .unwrap();
+        let violations = engine.lint_document_with_config(&doc, &config).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].rule_id, "SPELL001");
+        assert!(violations[0].message.contains("recieve"));
+    }
+}
@@ -0,0 +1,406 @@
+//! SPELL001: Spelling
+//!
+//! Flags misspelled words in prose. Code spans, code blocks, URLs, HTML,
+//! link destinations, and mdBook `{{#...}}` directives are never checked.
+//!
+//! Out of the box only the built-in list of common typos is used, and each
+//! hit carries a suggested fix. Configuring one or more `dictionary` word lists turns on
+//! full checking, where every word missing from the dictionaries and the
+//! project `words` list is reported.
+
+use super::typos::COMMON_TYPOS;
+use crate::prose::{match_case, prose_lines};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// Words: a letter followed by letters and apostrophes
+static WORD_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\p{L}[\p{L}'’]*").unwrap());
+
+/// SPELL001: Detects misspelled words in prose
+#[derive(Debug, Clone)]
+pub struct SPELL001 {
+    /// Known misspellings (lowercase) and their corrections
+    corrections: HashMap<String, String>,
+    /// Accepted words (lowercase) from the configured dictionaries; `None`
+    /// when no dictionary is configured
+    dictionary: Option<HashSet<String>>,
+    /// Dictionaries that could not be loaded
+    dictionary_errors: Vec<String>,
+    /// Project word list (lowercase)
+    words: HashSet<String>,
+    /// Shortest word checked against the dictionaries
+    min_length: usize,
+}
+
+impl Default for SPELL001 {
+    fn default() -> Self {
+        Self {
+            corrections: COMMON_TYPOS
+                .iter()
+                .map(|(typo, fix)| (typo.to_string(), fix.to_string()))
+                .collect(),
+            dictionary: None,
+            dictionary_errors: Vec::new(),
+            words: HashSet::new(),
+            min_length: 3,
+        }
+    }
+}
+
+impl SPELL001 {
+    /// Create with an in-memory dictionary
+    #[allow(dead_code)]
+    pub fn with_dictionary(words: &[&str]) -> Self {
+        Self {
+            dictionary: Some(words.iter().map(|w| w.to_lowercase()).collect()),
+            ..Self::default()
+        }
+    }
+
+    /// Create an instance from rule configuration.
+    ///
+    /// Recognized keys (both `snake_case` and `kebab-case` accepted):
+    /// - `dictionary`: path, or array of paths, to word lists with one word
+    ///   per line. Hunspell `.dic` files work too. Relative paths are resolved
+    ///   against the working directory.
+    /// - `words`: project-specific words to accept.
+    /// - `corrections`: table of extra misspellings and their corrections.
+    /// - `builtin-corrections`: use the built-in typo list (default true).
+    /// - `min-length`: shortest word checked against the dictionaries (default 3).
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::default();
+
+        if let Some(false) = config
+            .get("builtin-corrections")
+            .or_else(|| config.get("builtin_corrections"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.corrections.clear();
+        }
+
+        if let Some(corrections) = config.get("corrections").and_then(|v| v.as_table()) {
+            for (typo, fix) in corrections {
+                if let Some(fix) = fix.as_str() {
+                    rule.corrections
+                        .insert(typo.to_lowercase(), fix.to_string());
+                }
+            }
+        }
+
+        if let Some(words) = config.get("words").and_then(|v| v.as_array()) {
+            rule.words = words
+                .iter()
+                .filter_map(|w| w.as_str().map(str::to_lowercase))
+                .collect();
+        }
+
+        if let Some(min_length) = config
+            .get("min-length")
+            .or_else(|| config.get("min_length"))
+            .and_then(|v| v.as_integer())
+        {
+            rule.min_length = min_length.max(1) as usize;
+        }
+
+        let paths: Vec<&str> = match config.get("dictionary") {
+            Some(toml::Value::String(path)) => vec![path.as_str()],
+            Some(toml::Value::Array(paths)) => paths.iter().filter_map(|p| p.as_str()).collect(),
+            _ => Vec::new(),
+        };
+        if !paths.is_empty() {
+            let mut dictionary = HashSet::new();
+            let mut loaded = false;
+            for path in paths {
+                match load_word_list(Path::new(path)) {
+                    Ok(words) => {
+                        dictionary.extend(words);
+                        loaded = true;
+                    }
+                    Err(e) => rule
+                        .dictionary_errors
+                        .push(format!("Could not read spelling dictionary '{path}': {e}")),
+                }
+            }
+            // Without any readable dictionary every word would be unknown
+            if loaded {
+                rule.dictionary = Some(dictionary);
+            }
+        }
+
+        rule
+    }
+
+    /// Check a single word, returning a message and optional correction
+    fn check_word(&self, word: &str) -> Option<(String, Option<String>)> {
+        let lower = word.to_lowercase();
+        if self.words.contains(&lower) {
+            return None;
+        }
+
+        // camelCase and other mixed-case tokens are identifiers, not prose
+        let rest_has_upper = word.chars().skip(1).any(char::is_uppercase);
+        let all_upper = !word.chars().any(char::is_lowercase);
+        if rest_has_upper && !all_upper {
+            return None;
+        }
+
+        if let Some(correction) = self.corrections.get(&lower) {
+            let correction = match_case(word, correction);
+            return Some((
+                format!("Possible misspelling '{word}', did you mean '{correction}'?"),
+                Some(correction),
+            ));
+        }
+
+        let dictionary = self.dictionary.as_ref()?;
+        // Acronyms are checked only through the corrections list
+        if all_upper || word.chars().count() < self.min_length || dictionary.contains(&lower) {
+            return None;
+        }
+
+        Some((format!("Unknown word '{word}'"), None))
+    }
+}
+
+/// Load a word list, skipping comments and the hunspell word count line
+fn load_word_list(path: &Path) -> std::io::Result<Vec<String>> {
+    let content = std::fs::read_to_string(path)?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter(|line| line.parse::<usize>().is_err())
+        .map(|line| line.split('/').next().unwrap_or(line).to_lowercase())
+        .collect())
+}
+
+impl Rule for SPELL001 {
+    fn id(&self) -> &'static str {
+        "SPELL001"
+    }
+
+    fn name(&self) -> &'static str {
+        "spelling"
+    }
+
+    fn description(&self) -> &'static str {
+        "Words in prose should be spelled correctly"
+    }
+
+    fn metadata(&self) -> RuleMetadata {
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.15.0")
+            .option(
+                "dictionary",
+                OptionKind::Any,
+                "Path, or list of paths, to word lists",
+            )
+            .option(
+                "words",
+                OptionKind::StringList,
+                "Project-specific words to accept",
+            )
+            .defaults_to("[]")
+            .option(
+                "corrections",
+                OptionKind::Any,
+                "Table of extra misspellings and their corrections",
+            )
+            .option(
+                "builtin-corrections",
+                OptionKind::Boolean,
+                "Use the built-in typo list",
+            )
+            .defaults_to("true")
+            .option(
+                "min-length",
+                OptionKind::IntegerAtLeast(1),
+                "Shortest word checked",
+            )
+            .defaults_to("3")
+            .either_case()
+    }
+
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_with_ast<'a>(
+        &self,
+        document: &Document,
+        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
+    ) -> mdbook_lint_core::error::Result<Vec<Violation>> {
+        let mut violations: Vec<Violation> = self
+            .dictionary_errors
+            .iter()
+            .map(|e| self.create_violation(e.clone(), 1, 1, Severity::Error))
+            .collect();
+
+        for (line_number, masked) in prose_lines(document) {
+            for m in WORD_REGEX.find_iter(&masked) {
+                let before = masked[..m.start()].chars().next_back();
+                let after = masked[m.end()..].chars().next();
+
+                // Parts of identifiers, paths, file extensions, and numbers
+                if before.is_some_and(|c| {
+                    c.is_ascii_digit() || matches!(c, '_' | '/' | '\\' | '.' | '$' | '#' | '@')
+                }) || after.is_some_and(|c| {
+                    c.is_ascii_digit() || matches!(c, '_' | '/' | '\\' | '(' | '@')
+                }) || (after == Some('.')
+                    && masked[m.end() + 1..]
+                        .chars()
+                        .next()
+                        .is_some_and(char::is_alphanumeric))
+                {
+                    continue;
+                }
+
+                let word = m
+                    .as_str()
+                    .trim_end_matches(['\'', '’'])
+                    .trim_end_matches("'s")
+                    .trim_end_matches("’s");
+                if word.is_empty() {
+                    continue;
+                }
+
+                let Some((message, correction)) = self.check_word(word) else {
+                    continue;
+                };
+
+                let column = masked[..m.start()].chars().count() + 1;
+                match correction {
+                    Some(replacement) => {
+                        let fix = Fix {
+                            description: format!("Replace '{word}' with '{replacement}'"),
+                            replacement: Some(replacement),
+                            start: Position {
+                                line: line_number,
+                                column,
+                            },
+                            end: Position {
+                                line: line_number,
+                                column: column + word.chars().count(),
+                            },
+                            safety: FixSafety::Suggestion,
+                        };
+                        violations.push(self.create_violation_with_fix(
+                            message,
+                            line_number,
+                            column,
+                            Severity::Warning,
+                            fix,
+                        ));
+                    }
+                    None => violations.push(self.create_violation(
+                        message,
+                        line_number,
+                        column,
+                        Severity::Warning,
+                    )),
+                }
+            }
+        }
+
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn check(rule: &SPELL001, content: &str) -> Vec<Violation> {
+        let doc = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        rule.check(&doc).unwrap()
+    }
+
+    #[test]
+    fn test_common_typo_with_fix() {
+        let violations = check(&SPELL001::default(), "# Intro\n\nWe recieve teh data.\n");
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].line, 3);
+        assert_eq!(violations[0].column, 4);
+        assert!(violations[0].message.contains("did you mean 'receive'"));
+
+        let fix = violations[1].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("the"));
+        assert_eq!(fix.start.column, 12);
+        assert_eq!(fix.end.column, 15);
+        assert_eq!(fix.safety, FixSafety::Suggestion);
+    }
+
+    #[test]
+    fn test_no_dictionary_ignores_unknown_words() {
+        let violations = check(&SPELL001::default(), "Zorblax frobnicates quux.\n");
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn test_code_and_urls_skipped() {
+        let rule = SPELL001::with_dictionary(&["see", "and", "the", "docs"]);
+        let content = "See `teh_thing` and <https://exmaple.com/teh> \
+                       [the docs](https://exmaple.com/recieve).\n\n\
+                       ```rust\nlet teh = recieve();\n```\n\n\
+                       {{#include teh/file.rs}}\n";
+        let violations = check(&rule, content);
+        assert!(violations.is_empty(), "{violations:?}");
+    }
+
+    #[test]
+    fn test_dictionary_mode() {
+        let rule = SPELL001::with_dictionary(&["the", "book", "is", "great", "and"]);
+        let violations = check(&rule, "The book is graet. It uses HTML and mdBook.\n");
+
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].message, "Unknown word 'graet'");
+        assert!(violations[0].fix.is_none());
+        assert_eq!(violations[1].message, "Unknown word 'uses'");
+    }
+
+    #[test]
+    fn test_config_words_and_corrections() {
+        let dir = tempfile::tempdir().unwrap();
+        let dic = dir.path().join("en.dic");
+        std::fs::write(&dic, "3\nthe/S\nbook\n# comment\nis\n").unwrap();
+
+        let config: toml::Value = toml::from_str(&format!(
+            r#"
+            dictionary = "{}"
+            words = ["Ferris"]
+            builtin-corrections = false
+            [corrections]
+            colour = "color"
+            "#,
+            dic.display()
+        ))
+        .unwrap();
+        let rule = SPELL001::from_config(&config);
+
+        let violations = check(&rule, "The book is Ferris teh colour.\n");
+        assert_eq!(violations.len(), 2);
+        assert_eq!(violations[0].message, "Unknown word 'teh'");
+        assert_eq!(
+            violations[1].fix.as_ref().unwrap().replacement.as_deref(),
+            Some("color")
+        );
+    }
+
+    #[test]
+    fn test_missing_dictionary_reported() {
+        let config: toml::Value =
+            toml::from_str("dictionary = \"/nonexistent/words.txt\"").unwrap();
+        let violations = check(&SPELL001::from_config(&config), "Hello.\n");
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].severity, Severity::Error);
+        assert!(violations[0].message.contains("/nonexistent/words.txt"));
+    }
+}
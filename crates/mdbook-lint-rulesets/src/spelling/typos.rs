@@ -0,0 +1,115 @@
+//! Built-in list of common misspellings
+//!
+//! Every entry is an unambiguous typo with a single obvious correction, so
+//! the list is safe to apply with `--fix`. Words that are valid English in
+//! some other sense (e.g. "form" for "from") are deliberately left out.
+
+/// Lowercase misspelling and its correction
+pub(crate) const COMMON_TYPOS: &[(&str, &str)] = &[
+    ("accomodate", "accommodate"),
+    ("acheive", "achieve"),
+    ("acknowlege", "acknowledge"),
+    ("adress", "address"),
+    ("agian", "again"),
+    ("alot", "a lot"),
+    ("apparant", "apparent"),
+    ("appearence", "appearance"),
+    ("arguement", "argument"),
+    ("assosiated", "associated"),
+    ("asyncronous", "asynchronous"),
+    ("availabe", "available"),
+    ("becuase", "because"),
+    ("beggining", "beginning"),
+    ("beleive", "believe"),
+    ("calender", "calendar"),
+    ("compatability", "compatibility"),
+    ("compatable", "compatible"),
+    ("completly", "completely"),
+    ("concensus", "consensus"),
+    ("configuraton", "configuration"),
+    ("correspondance", "correspondence"),
+    ("definately", "definitely"),
+    ("dependancy", "dependency"),
+    ("desciption", "description"),
+    ("developement", "development"),
+    ("diffrent", "different"),
+    ("doesnt", "doesn't"),
+    ("enviroment", "environment"),
+    ("existance", "existence"),
+    ("explaination", "explanation"),
+    ("familar", "familiar"),
+    ("finaly", "finally"),
+    ("foward", "forward"),
+    ("funtion", "function"),
+    ("goverment", "government"),
+    ("gaurantee", "guarantee"),
+    ("heirarchy", "hierarchy"),
+    ("identifer", "identifier"),
+    ("immediatly", "immediately"),
+    ("implmentation", "implementation"),
+    ("independant", "independent"),
+    ("initalize", "initialize"),
+    ("intial", "initial"),
+    ("knowlege", "knowledge"),
+    ("langauge", "language"),
+    ("lenght", "length"),
+    ("libary", "library"),
+    ("maintainance", "maintenance"),
+    ("managment", "management"),
+    ("neccessary", "necessary"),
+    ("necessery", "necessary"),
+    ("occassion", "occasion"),
+    ("occured", "occurred"),
+    ("occurence", "occurrence"),
+    ("occuring", "occurring"),
+    ("paramter", "parameter"),
+    ("perfomance", "performance"),
+    ("persistant", "persistent"),
+    ("posible", "possible"),
+    ("preceed", "precede"),
+    ("prefered", "preferred"),
+    ("priviledge", "privilege"),
+    ("proccess", "process"),
+    ("publically", "publicly"),
+    ("realy", "really"),
+    ("recieve", "receive"),
+    ("recieved", "received"),
+    ("recomend", "recommend"),
+    ("refered", "referred"),
+    ("reponse", "response"),
+    ("repositary", "repository"),
+    ("retreive", "retrieve"),
+    ("seperate", "separate"),
+    ("seperately", "separately"),
+    ("similiar", "similar"),
+    ("sucessful", "successful"),
+    ("succesful", "successful"),
+    ("supress", "suppress"),
+    ("suport", "support"),
+    ("teh", "the"),
+    ("thier", "their"),
+    ("threshhold", "threshold"),
+    ("tommorow", "tomorrow"),
+    ("truely", "truly"),
+    ("unecessary", "unnecessary"),
+    ("untill", "until"),
+    ("usefull", "useful"),
+    ("wich", "which"),
+    ("writting", "writing"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::COMMON_TYPOS;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_typos_are_lowercase_and_unique() {
+        let mut seen = HashSet::new();
+        for (typo, correction) in COMMON_TYPOS {
+            assert_eq!(*typo, typo.to_lowercase(), "{typo} must be lowercase");
+            assert_ne!(typo, correction);
+            assert!(seen.insert(*typo), "duplicate typo entry {typo}");
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Blank-line checks and fixes shared by MD022, MD031, and MD032
+//!
+//! These rules often want a blank line in the same place, such as between a
+//! heading and the fence right below it. Building every fix here keeps those
+//! fixes identical, so the fix engine inserts the line once and `--fix`
+//! settles in a single run. A blank line inserted inside a blockquote keeps
+//! the quote's `>` markers, since a truly empty line would end the quote.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::violation::{Fix, FixSafety, Position};
+
+/// Check if a line is considered "blank" for the purposes of spacing rules.
+/// A line is blank if:
+/// - It's empty or contains only whitespace
+/// - It's a blockquote line with no content after the `>` marker (e.g., `>`, `> `)
+pub(crate) fn is_blank_line(line: &str) -> bool {
+    line.chars().all(|c| c == '>' || c.is_whitespace())
+}
+
+/// Whether the line before `line` (1-based) is blank or `line` opens the document
+pub(crate) fn has_blank_line_before(document: &Document, line: usize) -> bool {
+    line <= 1
+        || document
+            .lines
+            .get(line - 2)
+            .is_none_or(|prev| is_blank_line(prev))
+}
+
+/// Whether the line after `line` (1-based) is blank or `line` ends the document
+pub(crate) fn has_blank_line_after(document: &Document, line: usize) -> bool {
+    document
+        .lines
+        .get(line)
+        .is_none_or(|next| is_blank_line(next))
+}
+
+/// Fix that inserts a blank line between `line` (1-based) and the next line.
+///
+/// When both lines are inside a blockquote, the blank line repeats the
+/// markers they share.
+pub(crate) fn insert_blank_line_after(document: &Document, line: usize, description: &str) -> Fix {
+    let current = document.lines.get(line - 1).map_or("", String::as_str);
+    let depth = match document.lines.get(line) {
+        Some(next) => quote_depth(current).min(quote_depth(next)),
+        None => quote_depth(current),
+    };
+    let end = Position {
+        line,
+        column: current.chars().count() + 1,
+    };
+
+    Fix {
+        description: description.to_string(),
+        replacement: Some(format!("\n{}", vec![">"; depth].join(" "))),
+        start: end,
+        end,
+        safety: FixSafety::Safe,
+    }
+}
+
+/// Number of blockquote markers opening a line
+fn quote_depth(line: &str) -> usize {
+    let mut depth = 0;
+    let mut rest = line.trim_start();
+    while let Some(after) = rest.strip_prefix('>') {
+        depth += 1;
+        rest = after.trim_start();
+    }
+    depth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn document(content: &str) -> Document {
+        Document::new(content.to_string(), PathBuf::from("test.md")).unwrap()
+    }
+
+    #[test]
+    fn test_is_blank_line() {
+        // Regular blank lines
+        assert!(is_blank_line(""));
+        assert!(is_blank_line("   "));
+        assert!(is_blank_line("\t"));
+
+        // Blockquote blank lines
+        assert!(is_blank_line(">"));
+        assert!(is_blank_line("> "));
+        assert!(is_blank_line(">  "));
+        assert!(is_blank_line(" > "));
+        assert!(is_blank_line("  >"));
+
+        // Nested blockquote blank lines
+        assert!(is_blank_line("> >"));
+        assert!(is_blank_line(">> "));
+        assert!(is_blank_line("> > "));
+
+        // Non-blank lines
+        assert!(!is_blank_line("text"));
+        assert!(!is_blank_line("> text"));
+        assert!(!is_blank_line("> > text"));
+        assert!(!is_blank_line(">text")); // No space after >, but has content
+    }
+
+    #[test]
+    fn test_insert_blank_line_keeps_quote_markers() {
+        let doc = document("> > Text\n> > # Heading\n> Outer\nPlain\n");
+        let replacement = |line| insert_blank_line_after(&doc, line, "").replacement.unwrap();
+        assert_eq!(replacement(1), "\n> >");
+        assert_eq!(replacement(2), "\n>");
+        assert_eq!(replacement(3), "\n");
+    }
+
+    #[test]
+    fn test_insert_blank_line_uses_char_columns() {
+        let doc = document("# Héading ü\nText\n");
+        let fix = insert_blank_line_after(&doc, 1, "");
+        assert_eq!(fix.start.column, 12);
+    }
+}
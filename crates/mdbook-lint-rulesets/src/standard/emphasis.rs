@@ -0,0 +1,142 @@
+//! Emphasis spans shared by MD049 and MD050
+//!
+//! Both rules read emphasis from the AST rather than scanning for `*` and
+//! `_`, so intra-word underscores (`snake_case_name`), code spans, and
+//! unclosed markers never count. mdBook passes TeX through to MathJax, where
+//! `_` means a subscript, so emphasis that comrak finds inside math
+//! delimiters is skipped as well.
+
+use comrak::nodes::{AstNode, NodeValue};
+use mdbook_lint_core::Document;
+use mdbook_lint_core::violation::{Fix, FixSafety, Position};
+
+/// An emphasis (`*a*`, `_a_`) or strong (`**a**`, `__a__`) span in the source
+pub(crate) struct EmphasisSpan {
+    /// Marker character, `*` or `_`
+    pub marker: char,
+    /// 1-based line and char column of the opening marker
+    pub line: usize,
+    pub column: usize,
+    /// 1-based line and char column just past the closing marker
+    end_line: usize,
+    end_column: usize,
+    /// The span's source text, markers included
+    source: String,
+    /// Characters next to the span, which decide whether `_` would work
+    before: Option<char>,
+    after: Option<char>,
+}
+
+impl EmphasisSpan {
+    /// Fix that swaps the span's markers for `marker`, or `None` when the
+    /// new markers would not read back as the same span: `_` inside a word
+    /// stops being emphasis, and `marker` next to the span, inside or out,
+    /// runs together with it (`*_x_*` would become `**x**`)
+    pub fn fix(&self, marker: char, width: usize, description: String) -> Option<Fix> {
+        let in_word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+        if marker == '_' && (in_word(self.before) || in_word(self.after)) {
+            return None;
+        }
+
+        let delimiter = marker.to_string().repeat(width);
+        let inner = self
+            .source
+            .chars()
+            .skip(width)
+            .take(self.source.chars().count().saturating_sub(2 * width))
+            .collect::<String>();
+        let touching = [
+            self.before,
+            self.after,
+            inner.chars().next(),
+            inner.chars().next_back(),
+        ];
+        if touching.contains(&Some(marker)) {
+            return None;
+        }
+        Some(Fix {
+            description,
+            replacement: Some(format!("{delimiter}{inner}{delimiter}")),
+            start: Position {
+                line: self.line,
+                column: self.column,
+            },
+            end: Position {
+                line: self.end_line,
+                column: self.end_column,
+            },
+            safety: FixSafety::Safe,
+        })
+    }
+}
+
+/// Emphasis spans in document order: strong spans when `strong` is set,
+/// otherwise regular emphasis
+pub(crate) fn emphasis_spans<'a>(
+    document: &Document,
+    ast: &'a AstNode<'a>,
+    strong: bool,
+) -> Vec<EmphasisSpan> {
+    let offset = document.frontmatter_ast_offset(ast);
+    let index = document.index();
+    let width = if strong { 2 } else { 1 };
+
+    ast.descendants()
+        .filter_map(|node| {
+            let data = node.data.borrow();
+            let wanted = match data.value {
+                NodeValue::Strong => strong,
+                NodeValue::Emph => !strong,
+                _ => false,
+            };
+            if !wanted {
+                return None;
+            }
+
+            let start = data.sourcepos.start;
+            let end = data.sourcepos.end;
+            let (line, end_line) = (start.line + offset, end.line + offset);
+            let first = document.lines.get(line - 1)?;
+            let last = document.lines.get(end_line - 1)?;
+            let open = start.column.checked_sub(1)?;
+            let close = end.column;
+            if !first.is_char_boundary(open) || !last.is_char_boundary(close) {
+                return None;
+            }
+
+            let marker = first[open..].chars().next()?;
+            let markers = marker.to_string().repeat(width);
+            if !matches!(marker, '*' | '_')
+                || !first[open..].starts_with(&markers)
+                || !last[..close].ends_with(&markers)
+                || index.is_in_math(line, open)
+            {
+                return None;
+            }
+
+            let source = if line == end_line {
+                first[open..close].to_string()
+            } else {
+                let mut source = first[open..].to_string();
+                for middle in &document.lines[line..end_line - 1] {
+                    source.push('\n');
+                    source.push_str(middle);
+                }
+                source.push('\n');
+                source.push_str(&last[..close]);
+                source
+            };
+
+            Some(EmphasisSpan {
+                marker,
+                line,
+                column: first[..open].chars().count() + 1,
+                end_line,
+                end_column: last[..close].chars().count() + 1,
+                source,
+                before: first[..open].chars().next_back(),
+                after: last[close..].chars().next(),
+            })
+        })
+        .collect()
+}
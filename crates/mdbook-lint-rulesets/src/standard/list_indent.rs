@@ -0,0 +1,60 @@
+//! Shared fix for rules that correct list item indentation
+//!
+//! Moving only a list item's marker line would leave its paragraphs, nested
+//! lists, and code blocks behind at the old indentation, where they either
+//! fall out of the item or change meaning. MD005 and MD007 instead move the
+//! whole item.
+
+use mdbook_lint_core::Document;
+use mdbook_lint_core::violation::{Fix, FixSafety, Position};
+
+/// Fix that moves the list item on lines `start..=end` (1-based) from `from`
+/// to `to` columns of indentation.
+///
+/// Continuation lines shift by the same amount, so nested items and fenced
+/// code keep their position relative to the item. Blank and tab-indented
+/// continuation lines are left as they are. The fix is unsafe because a
+/// different indent can change which list an item belongs to.
+pub(crate) fn reindent_item(
+    document: &Document,
+    start: usize,
+    end: usize,
+    from: usize,
+    to: usize,
+    description: String,
+) -> Fix {
+    let lines = &document.lines;
+    // Trailing blank lines are unchanged, so keep them out of the fix
+    let end = (start..=end.min(lines.len()))
+        .rev()
+        .find(|&n| !lines[n - 1].trim().is_empty())
+        .unwrap_or(start);
+    let delta = to as isize - from as isize;
+
+    let mut replacement = " ".repeat(to);
+    replacement.push_str(lines[start - 1].trim_start());
+    for line in &lines[start..end] {
+        replacement.push('\n');
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        if line.trim().is_empty() || line[spaces..].starts_with('\t') {
+            replacement.push_str(line);
+        } else {
+            replacement.push_str(&" ".repeat(spaces.saturating_add_signed(delta)));
+            replacement.push_str(&line[spaces..]);
+        }
+    }
+
+    Fix {
+        description,
+        replacement: Some(replacement),
+        start: Position {
+            line: start,
+            column: 1,
+        },
+        end: Position {
+            line: end,
+            column: lines[end - 1].chars().count() + 1,
+        },
+        safety: FixSafety::Unsafe,
+    }
+}
@@ -3,7 +3,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
     Document,
     rule::{AstRule, RuleCategory, RuleMetadata},
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// MD001: Heading levels should only increment by one level at a time
@@ -151,6 +151,7 @@ impl AstRule for MD001 {
                             line,
                             column: line_content.len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
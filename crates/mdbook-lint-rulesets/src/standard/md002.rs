@@ -5,10 +5,10 @@
 
 use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that the first heading is a top-level heading
@@ -67,6 +67,12 @@ impl AstRule for MD002 {
             Some("MD041"),
         )
         .introduced_in("markdownlint v0.1.0")
+        .option(
+            "level",
+            OptionKind::IntegerBetween(1, 6),
+            "Level the first heading must have",
+        )
+        .defaults_to("1")
     }
 
     fn can_fix(&self) -> bool {
@@ -128,6 +134,7 @@ impl AstRule for MD002 {
                     line,
                     column: line_content.len() + 1,
                 },
+                safety: FixSafety::Safe,
             };
 
             violations.push(self.create_violation_with_fix(
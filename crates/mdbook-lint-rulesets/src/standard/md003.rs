@@ -6,10 +6,12 @@
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::rule::{OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 use serde::{Deserialize, Serialize};
 
+use super::blank_lines::is_blank_line;
+
 /// Configuration for MD003 heading style consistency
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Md003Config {
@@ -81,7 +83,20 @@ impl mdbook_lint_core::rule::AstRule for MD003 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Structure).introduced_in("markdownlint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("markdownlint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&[
+                    "consistent",
+                    "atx",
+                    "atx_closed",
+                    "setext",
+                    "setext_with_atx",
+                ]),
+                "Heading style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -92,8 +107,10 @@ impl mdbook_lint_core::rule::AstRule for MD003 {
         let mut violations = Vec::new();
         let mut headings = Vec::new();
 
-        // Collect all headings with their styles
-        self.collect_headings(ast, document, &mut headings);
+        // Collect all headings with their styles. comrak numbers lines after
+        // frontmatter from 1, so shift them back onto the source.
+        let frontmatter_offset = document.frontmatter_ast_offset(ast);
+        self.collect_headings(ast, document, frontmatter_offset, &mut headings);
 
         if headings.is_empty() {
             return Ok(violations);
@@ -105,19 +122,27 @@ impl mdbook_lint_core::rule::AstRule for MD003 {
         // Check each heading against the expected style
         for heading in &headings {
             if !self.is_valid_style(&heading.style, &expected_style, heading.level) {
-                // Create fix to convert heading style
-                let fix = self.create_heading_fix(document, heading, &expected_style);
-
-                violations.push(self.create_violation_with_fix(
-                    format!(
-                        "Expected '{}' style heading but found '{}' style",
-                        expected_style, heading.style
-                    ),
-                    heading.line,
-                    heading.column,
-                    Severity::Error,
-                    fix,
-                ));
+                let message = format!(
+                    "Expected '{}' style heading but found '{}' style",
+                    expected_style, heading.style
+                );
+                violations.push(
+                    match self.create_heading_fix(document, heading, &expected_style) {
+                        Some(fix) => self.create_violation_with_fix(
+                            message,
+                            heading.line,
+                            heading.column,
+                            Severity::Error,
+                            fix,
+                        ),
+                        None => self.create_violation(
+                            message,
+                            heading.line,
+                            heading.column,
+                            Severity::Error,
+                        ),
+                    },
+                );
             }
         }
 
@@ -131,71 +156,93 @@ impl MD003 {
         &self,
         node: &'a AstNode<'a>,
         document: &Document,
+        frontmatter_offset: usize,
         headings: &mut Vec<HeadingInfo>,
     ) {
         if let NodeValue::Heading(heading_data) = &node.data.borrow().value {
             let position = node.data.borrow().sourcepos;
-            let style = self.determine_heading_style(node, document, position.start.line);
-            headings.push(HeadingInfo {
-                level: heading_data.level,
-                style,
-                line: position.start.line,
-                column: position.start.column,
-            });
+            let line = position.start.line + frontmatter_offset;
+            if let Some(heading) = self.parse_heading(
+                document,
+                line,
+                position.start.column,
+                heading_data.level,
+                heading_data.setext,
+            ) {
+                headings.push(heading);
+            }
         }
 
         // Recursively process child nodes
         for child in node.children() {
-            self.collect_headings(child, document, headings);
+            self.collect_headings(child, document, frontmatter_offset, headings);
         }
     }
 
-    /// Determine the style of a specific heading
-    fn determine_heading_style(
+    /// Read a heading's style, text, and attributes from its source lines
+    fn parse_heading(
         &self,
-        _node: &AstNode,
         document: &Document,
-        line_number: usize,
-    ) -> HeadingStyle {
-        // Get the line content (convert to 0-based indexing)
-        let line_index = line_number.saturating_sub(1);
-        if line_index >= document.lines.len() {
-            return HeadingStyle::Atx;
-        }
-
-        let line = &document.lines[line_index];
-        let trimmed = line.trim();
-
-        // Check if it's ATX style (starts with #)
-        if trimmed.starts_with('#') {
-            // Check if it's ATX closed (ends with #)
-            if trimmed.ends_with('#') && trimmed.len() > 1 {
-                // Make sure it's not just a line of # characters
-                let content = trimmed.trim_start_matches('#').trim_end_matches('#').trim();
-                if !content.is_empty() {
-                    return HeadingStyle::AtxClosed;
-                }
+        line: usize,
+        byte_column: usize,
+        level: u8,
+        setext: bool,
+    ) -> Option<HeadingInfo> {
+        let source = document.lines.get(line - 1)?;
+        let split = (0..byte_column.min(source.len() + 1))
+            .rev()
+            .find(|&idx| source.is_char_boundary(idx))
+            .unwrap_or(0);
+        let (prefix, rest) = source.split_at(split);
+
+        let (style, end_line, text) = if setext {
+            // The text runs until the `===` or `---` underline
+            let underline = (line..document.lines.len())
+                .find(|&idx| {
+                    let content = strip_container(&document.lines[idx]);
+                    !content.is_empty()
+                        && (content.chars().all(|c| c == '=') || content.chars().all(|c| c == '-'))
+                })
+                .map_or(line, |idx| idx + 1);
+            let mut text = rest.trim().to_string();
+            for continued in &document.lines[line..underline.saturating_sub(1).max(line)] {
+                text.push(' ');
+                text.push_str(strip_container(continued));
             }
-            return HeadingStyle::Atx;
-        }
+            (HeadingStyle::Setext, underline, text)
+        } else {
+            let content = rest.trim_start_matches('#').trim();
+            let (content, closed) = strip_closing_sequence(content);
+            let style = if closed {
+                HeadingStyle::AtxClosed
+            } else {
+                HeadingStyle::Atx
+            };
+            (style, line, content.to_string())
+        };
 
-        // Check if it's Setext style (next line has === or ---)
-        if line_index + 1 < document.lines.len() {
-            let next_line = &document.lines[line_index + 1];
-            let next_trimmed = next_line.trim();
-
-            if !next_trimmed.is_empty() {
-                let first_char = next_trimmed.chars().next().unwrap();
-                if (first_char == '=' || first_char == '-')
-                    && next_trimmed.chars().all(|c| c == first_char)
-                {
-                    return HeadingStyle::Setext;
-                }
-            }
-        }
+        // `{#id .class}` attributes stay at the end of the heading line
+        let (text, attributes) = split_attributes(&text);
+        let (text, closed_before_attributes) = strip_closing_sequence(text);
+        let style = if closed_before_attributes && style == HeadingStyle::Atx {
+            HeadingStyle::AtxClosed
+        } else {
+            style
+        };
 
-        // Default to ATX if we can't determine (shouldn't happen with valid markdown)
-        HeadingStyle::Atx
+        Some(HeadingInfo {
+            level,
+            style,
+            line,
+            column: prefix.chars().count() + 1,
+            end_line,
+            text: text.to_string(),
+            attributes: attributes.map(str::to_string),
+            continuation: prefix
+                .chars()
+                .map(|c| if c == '>' { '>' } else { ' ' })
+                .collect(),
+        })
     }
 
     /// Determine the expected style for the document
@@ -233,121 +280,125 @@ impl MD003 {
                     matches!(actual, HeadingStyle::Atx)
                 }
             }
+            // Setext has no form for levels 3+, so any ATX style will do
+            HeadingStyle::Setext if level > 2 => {
+                matches!(actual, HeadingStyle::Atx | HeadingStyle::AtxClosed)
+            }
             _ => actual == expected,
         }
     }
 
     /// Create a fix to convert a heading to the expected style
+    ///
+    /// Returns `None` when the heading can't be written in that style yet: an
+    /// empty heading has no setext form, and setext text right below a
+    /// paragraph would join it.
     fn create_heading_fix(
         &self,
         document: &Document,
         heading: &HeadingInfo,
         expected_style: &HeadingStyle,
-    ) -> Fix {
-        let line_idx = heading.line.saturating_sub(1);
-
-        // Get the heading text content
-        let heading_text = self.extract_heading_text(document, heading);
-
-        // Generate the replacement based on expected style
+    ) -> Option<Fix> {
+        let hashes = "#".repeat(heading.level as usize);
+        let atx = if heading.text.is_empty() {
+            hashes.clone()
+        } else {
+            format!("{hashes} {}", heading.text)
+        };
+        let attributes = heading
+            .attributes
+            .as_ref()
+            .map(|attributes| format!(" {attributes}"))
+            .unwrap_or_default();
+        let text = &heading.text;
+
+        let setext = heading.level <= 2
+            && matches!(
+                expected_style,
+                HeadingStyle::Setext | HeadingStyle::SetextWithAtx
+            );
         let replacement = match expected_style {
-            HeadingStyle::Atx => {
-                format!("{} {}\n", "#".repeat(heading.level as usize), heading_text)
-            }
-            HeadingStyle::AtxClosed => {
+            _ if setext => {
+                let follows_paragraph = heading.line > 1
+                    && !is_blank_line(&document.lines[heading.line - 2])
+                    && !self.is_block_boundary(&document.lines[heading.line - 2]);
+                if text.is_empty() || follows_paragraph {
+                    return None;
+                }
+                let underline = if heading.level == 1 { "=" } else { "-" };
+                let width = text.chars().count() + attributes.chars().count();
                 format!(
-                    "{} {} {}\n",
-                    "#".repeat(heading.level as usize),
-                    heading_text,
-                    "#".repeat(heading.level as usize)
+                    "{text}{attributes}\n{}{}\n",
+                    heading.continuation,
+                    underline.repeat(width.max(3))
                 )
             }
-            HeadingStyle::Setext => {
-                if heading.level <= 2 {
-                    let underline = if heading.level == 1 { "=" } else { "-" };
-                    format!(
-                        "{}\n{}\n",
-                        heading_text,
-                        underline.repeat(heading_text.len())
-                    )
-                } else {
-                    // Setext only supports levels 1 and 2, use ATX for higher levels
-                    format!("{} {}\n", "#".repeat(heading.level as usize), heading_text)
-                }
-            }
-            HeadingStyle::SetextWithAtx => {
-                if heading.level <= 2 {
-                    let underline = if heading.level == 1 { "=" } else { "-" };
-                    format!(
-                        "{}\n{}\n",
-                        heading_text,
-                        underline.repeat(heading_text.len())
-                    )
-                } else {
-                    format!("{} {}\n", "#".repeat(heading.level as usize), heading_text)
-                }
-            }
+            HeadingStyle::AtxClosed => format!("{atx} {hashes}{attributes}\n"),
+            _ => format!("{atx}{attributes}\n"),
         };
 
-        // Determine the range to replace
-        let (start_line, end_line) =
-            if heading.style == HeadingStyle::Setext && line_idx + 1 < document.lines.len() {
-                // Setext headings span two lines
-                (heading.line, heading.line + 1)
-            } else {
-                (heading.line, heading.line)
-            };
-
-        Fix {
+        let end_line = document.lines.get(heading.end_line - 1)?;
+        Some(Fix {
             description: format!("Convert to {} style", expected_style),
             replacement: Some(replacement),
             start: Position {
-                line: start_line,
-                column: 1,
+                line: heading.line,
+                column: heading.column,
             },
             end: Position {
-                line: end_line,
-                column: if end_line > start_line && end_line <= document.lines.len() {
-                    document.lines[end_line - 1].len() + 1
-                } else {
-                    document.lines[line_idx].len() + 1
-                },
+                line: heading.end_line,
+                column: end_line.chars().count() + 1,
             },
-        }
+            safety: FixSafety::Safe,
+        })
     }
 
-    /// Extract the text content of a heading
-    fn extract_heading_text(&self, document: &Document, heading: &HeadingInfo) -> String {
-        let line_idx = heading.line.saturating_sub(1);
-        if line_idx >= document.lines.len() {
-            return String::new();
-        }
+    /// Whether a line ends the block before it, so setext text can follow
+    fn is_block_boundary(&self, line: &str) -> bool {
+        let content = strip_container(line);
+        content.starts_with('#')
+            || content.starts_with("```")
+            || content.starts_with("~~~")
+            || (content.len() >= 3
+                && (content.chars().all(|c| c == '=') || content.chars().all(|c| c == '-')))
+    }
+}
 
-        let line = &document.lines[line_idx];
-        let trimmed = line.trim();
+/// Strip blockquote markers and indentation from a heading line
+fn strip_container(line: &str) -> &str {
+    line.trim_start_matches(|c: char| c == '>' || c.is_whitespace())
+        .trim_end()
+}
 
-        match heading.style {
-            HeadingStyle::Atx => {
-                // Remove leading # and space
-                trimmed.trim_start_matches('#').trim().to_string()
-            }
-            HeadingStyle::AtxClosed => {
-                // Remove leading and trailing # and spaces
-                trimmed
-                    .trim_start_matches('#')
-                    .trim_end_matches('#')
-                    .trim()
-                    .to_string()
-            }
-            HeadingStyle::Setext => {
-                // Setext headings have the text on the current line
-                trimmed.to_string()
-            }
-            HeadingStyle::SetextWithAtx => {
-                // Same as Setext for extraction
-                trimmed.to_string()
-            }
-        }
+/// Split a trailing closing `#` sequence off ATX heading text. The sequence
+/// only counts when a space separates it from the text.
+fn strip_closing_sequence(text: &str) -> (&str, bool) {
+    let without = text.trim_end_matches('#');
+    if without.len() == text.len() {
+        (text, false)
+    } else if without.is_empty() {
+        ("", true)
+    } else if without.ends_with([' ', '\t']) && !without.trim_end().ends_with('\\') {
+        (without.trim_end(), true)
+    } else {
+        (text, false)
+    }
+}
+
+/// Split a trailing `{#id .class key=value}` attribute block off heading text
+fn split_attributes(text: &str) -> (&str, Option<&str>) {
+    let trimmed = text.trim_end();
+    if let Some(body) = trimmed.strip_suffix('}')
+        && let Some(open) = body.rfind('{')
+        && !body[open + 1..].contains('{')
+        && body[open + 1..]
+            .split_whitespace()
+            .next()
+            .is_some_and(|first| first.starts_with(['#', '.']) || first.contains('='))
+    {
+        (trimmed[..open].trim_end(), Some(&trimmed[open..]))
+    } else {
+        (text, None)
     }
 }
 
@@ -356,8 +407,17 @@ impl MD003 {
 struct HeadingInfo {
     level: u8,
     style: HeadingStyle,
+    /// First line of the heading, and the 1-based char column where it starts
     line: usize,
     column: usize,
+    /// Last line: the underline of a setext heading
+    end_line: usize,
+    /// Heading text without markers or attributes
+    text: String,
+    /// Trailing attribute block such as `{#custom-id}`
+    attributes: Option<String>,
+    /// Prefix for a second line inside the heading's container, such as `> `
+    continuation: String,
 }
 
 /// The different heading styles in Markdown
@@ -647,8 +707,8 @@ Some content here.
         let rule = MD003::with_config(config);
         let violations = rule.check(&doc).unwrap();
 
-        // All headings should have violations and fixes
-        assert_eq!(violations.len(), 3);
+        // Levels 1 and 2 should have violations and fixes
+        assert_eq!(violations.len(), 2);
 
         // Check first heading fix (level 1 to Setext)
         assert!(violations[0].fix.is_some());
@@ -664,10 +724,7 @@ Some content here.
         let fix2 = violations[1].fix.as_ref().unwrap();
         assert_eq!(fix2.replacement, Some("Section A\n---------\n".to_string()));
 
-        // Check third heading fix (level 3 can't be Setext, should be ATX)
-        assert!(violations[2].fix.is_some());
-        let fix3 = violations[2].fix.as_ref().unwrap();
-        assert_eq!(fix3.replacement, Some("### Subsection\n".to_string()));
+        // Level 3 can't be Setext, so it stays ATX
     }
 
     #[test]
@@ -779,4 +836,56 @@ Setext Section
         let fix2 = violations[1].fix.as_ref().unwrap();
         assert!(fix2.replacement.as_ref().unwrap().contains("-"));
     }
+
+    fn fix(style: &str, content: &str) -> String {
+        let rule = MD003::with_config(Md003Config {
+            style: style.to_string(),
+        });
+        mdbook_lint_core::FixEngine::new()
+            .fix_until_stable(content, |content| {
+                rule.check(&create_test_document(content))
+            })
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn test_md003_fix_round_trip_keeps_attributes() {
+        let atx = "# Title {#top .intro}\n\n## C\\# and F# ##\n\n### Deeper\n";
+        let setext =
+            "Title {#top .intro}\n===================\n\nC\\# and F#\n----------\n\n### Deeper\n";
+        assert_eq!(fix("setext", atx), setext);
+        assert_eq!(
+            fix("atx", setext),
+            "# Title {#top .intro}\n\n## C\\# and F#\n\n### Deeper\n"
+        );
+        assert_eq!(
+            fix("atx_closed", atx),
+            "# Title # {#top .intro}\n\n## C\\# and F# ##\n\n### Deeper ###\n"
+        );
+    }
+
+    #[test]
+    fn test_md003_fix_in_containers_and_frontmatter() {
+        let content = "---\ntitle: Guide\n---\n\n> Quoted\n> title\n> ------\n\n- # In a list\n";
+        assert_eq!(
+            fix("atx", content),
+            "---\ntitle: Guide\n---\n\n> ## Quoted title\n\n- # In a list\n"
+        );
+        assert_eq!(
+            fix("setext", "> ## Quoted\n\n- # In a list\n"),
+            "> Quoted\n> ------\n\n- In a list\n  =========\n"
+        );
+    }
+
+    #[test]
+    fn test_md003_no_setext_fix_below_paragraph() {
+        let doc = create_test_document("Some text\n# Title\n");
+        let rule = MD003::with_config(Md003Config {
+            style: "setext".to_string(),
+        });
+        let violations = rule.check(&doc).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].fix.is_none());
+    }
 }
@@ -4,10 +4,10 @@
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// List marker styles for unordered lists
@@ -106,7 +106,14 @@ impl AstRule for MD004 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "asterisk", "plus", "dash"]),
+                "List marker",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -228,6 +235,7 @@ impl MD004 {
                     line: line_number,
                     column: 1,
                 },
+                safety: FixSafety::Safe,
             };
         }
 
@@ -261,6 +269,7 @@ impl MD004 {
                     line: line_number,
                     column: line.len() + 1,
                 },
+                safety: FixSafety::Safe,
             }
         } else {
             Fix {
@@ -274,6 +283,7 @@ impl MD004 {
                     line: line_number,
                     column: 1,
                 },
+                safety: FixSafety::Safe,
             }
         }
     }
@@ -7,9 +7,11 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
 };
 
+use super::list_indent::reindent_item;
+
 /// Rule to check for consistent list item indentation
 pub struct MD005;
 
@@ -36,12 +38,14 @@ impl AstRule for MD005 {
 
     fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
+        // comrak numbers lines after frontmatter from 1
+        let offset = document.frontmatter_ast_offset(ast);
 
         // Find all list nodes
         for node in ast.descendants() {
             if let NodeValue::List(list_data) = &node.data.borrow().value {
                 // Check indentation consistency within this list
-                violations.extend(self.check_list_indentation(document, node, list_data)?);
+                violations.extend(self.check_list_indentation(document, node, list_data, offset)?);
             }
         }
 
@@ -56,6 +60,7 @@ impl MD005 {
         document: &Document,
         list_node: &'a AstNode<'a>,
         _list_data: &comrak::nodes::NodeList,
+        offset: usize,
     ) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
         let mut expected_indent: Option<usize> = None;
@@ -64,6 +69,7 @@ impl MD005 {
         for child in list_node.children() {
             if let NodeValue::Item(_) = &child.data.borrow().value
                 && let Some((line_num, _)) = document.node_position(child)
+                && let line_num = line_num + offset
                 && let Some(line) = document.lines.get(line_num - 1)
             {
                 let actual_indent = self.get_line_indentation(line);
@@ -74,33 +80,16 @@ impl MD005 {
                 } else if let Some(expected) = expected_indent
                     && actual_indent != expected
                 {
-                    // Check if this item's indentation matches
-                    // Create fix by adjusting indentation to match expected
-                    let spaces_to_adjust = actual_indent.abs_diff(expected);
-
-                    let fixed_line = if actual_indent > expected {
-                        // Remove extra spaces
-                        format!("{}\n", &line[spaces_to_adjust..])
-                    } else {
-                        // Add missing spaces
-                        format!("{}{}\n", " ".repeat(spaces_to_adjust), line)
-                    };
-
-                    let fix = Fix {
-                        description: format!(
-                            "Adjust indentation from {} to {} spaces",
-                            actual_indent, expected
-                        ),
-                        replacement: Some(fixed_line),
-                        start: Position {
-                            line: line_num,
-                            column: 1,
-                        },
-                        end: Position {
-                            line: line_num,
-                            column: line.len() + 1,
-                        },
-                    };
+                    // Move the whole item, including its continuation lines
+                    let end_line = child.data.borrow().sourcepos.end.line + offset;
+                    let fix = reindent_item(
+                        document,
+                        line_num,
+                        end_line,
+                        actual_indent,
+                        expected,
+                        format!("Adjust indentation from {actual_indent} to {expected} spaces"),
+                    );
 
                     violations.push(self.create_violation_with_fix(
                         format!(
@@ -136,7 +125,9 @@ impl MD005 {
 mod tests {
     use super::*;
     use mdbook_lint_core::Document;
+    use mdbook_lint_core::FixEngine;
     use mdbook_lint_core::rule::Rule;
+    use mdbook_lint_core::violation::FixSafety;
     use std::path::PathBuf;
 
     #[test]
@@ -336,7 +327,7 @@ Some text here.
         assert_eq!(fix.description, "Adjust indentation from 0 to 2 spaces");
         assert_eq!(
             fix.replacement,
-            Some("  - Item 3 (wrong indentation)\n".to_string())
+            Some("  - Item 3 (wrong indentation)".to_string())
         );
     }
 
@@ -361,7 +352,7 @@ Some text here.
         assert_eq!(fix.description, "Adjust indentation from 1 to 0 spaces");
         assert_eq!(
             fix.replacement,
-            Some("- Item 3 (inconsistent at same level)\n".to_string())
+            Some("- Item 3 (inconsistent at same level)".to_string())
         );
     }
 
@@ -384,7 +375,7 @@ Some text here.
         assert_eq!(fix.description, "Adjust indentation from 1 to 0 spaces");
         assert_eq!(
             fix.replacement,
-            Some("2. Second item (wrong indentation)\n".to_string())
+            Some("2. Second item (wrong indentation)".to_string())
         );
     }
 
@@ -408,7 +399,7 @@ Some text here.
         assert_eq!(fix.description, "Adjust indentation from 3 to 2 spaces");
         assert_eq!(
             fix.replacement,
-            Some("  - Nested item B (inconsistent)\n".to_string())
+            Some("  - Nested item B (inconsistent)".to_string())
         );
     }
 
@@ -439,7 +430,7 @@ Some text here.
         );
         assert_eq!(
             violations[0].fix.as_ref().unwrap().replacement,
-            Some("- Item 2 (1 space)\n".to_string())
+            Some("- Item 2 (1 space)".to_string())
         );
 
         assert_eq!(
@@ -448,7 +439,7 @@ Some text here.
         );
         assert_eq!(
             violations[1].fix.as_ref().unwrap().replacement,
-            Some("- Item 3 (2 spaces)\n".to_string())
+            Some("- Item 3 (2 spaces)".to_string())
         );
 
         assert_eq!(
@@ -457,7 +448,7 @@ Some text here.
         );
         assert_eq!(
             violations[2].fix.as_ref().unwrap().replacement,
-            Some("- Item 4 (3 spaces)\n".to_string())
+            Some("- Item 4 (3 spaces)".to_string())
         );
     }
 
@@ -479,7 +470,7 @@ Some text here.
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(
             fix.replacement,
-            Some("- Item with *italic* text (wrong indent)\n".to_string())
+            Some("- Item with *italic* text (wrong indent)".to_string())
         );
     }
 
@@ -503,7 +494,7 @@ Some text here.
         assert_eq!(fix.description, "Adjust indentation from 1 to 0 spaces");
         assert_eq!(
             fix.replacement,
-            Some("- Item 2 (1 space - inconsistent)\n".to_string())
+            Some("- Item 2 (1 space - inconsistent)".to_string())
         );
     }
 
@@ -512,4 +503,30 @@ Some text here.
         let rule = MD005;
         assert!(mdbook_lint_core::AstRule::can_fix(&rule));
     }
+
+    #[test]
+    fn test_md005_fix_moves_continuation_lines_and_code() {
+        let content = "---\ntitle: Lists\n---\n\n- Item 1\n - Item 2\n\n   ```sh\n   make\n   ```\n   - Nested\n- Item 3\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = MD005.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 6);
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.safety, FixSafety::Unsafe);
+
+        let fixed = FixEngine::new()
+            .with_unsafe_fixes(true)
+            .apply(content, &violations)
+            .content;
+        assert_eq!(
+            fixed,
+            "---\ntitle: Lists\n---\n\n- Item 1\n- Item 2\n\n  ```sh\n  make\n  ```\n  - Nested\n- Item 3\n"
+        );
+        // Without unsafe fixes the indentation is left alone
+        assert_eq!(
+            FixEngine::new().apply(content, &violations).content,
+            content
+        );
+    }
 }
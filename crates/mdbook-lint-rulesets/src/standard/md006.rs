@@ -1,7 +1,7 @@
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 
 /// MD006 - Consider starting bulleted lists at the beginning of the line
 pub struct MD006;
@@ -80,6 +80,7 @@ impl Rule for MD006 {
                                 line: line_number,
                                 column: line.len() + 1,
                             },
+                            safety: FixSafety::Safe,
                         };
 
                         violations.push(
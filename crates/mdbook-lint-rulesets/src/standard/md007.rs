@@ -1,8 +1,11 @@
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Severity, Violation};
+use std::collections::HashMap;
+
+use super::list_indent::reindent_item;
 
 /// MD007 - Unordered list indentation
 pub struct MD007 {
@@ -204,6 +207,25 @@ impl AstRule for MD007 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Formatting)
+            .option(
+                "indent",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces per nesting level",
+            )
+            .defaults_to("2")
+            .option(
+                "start-indent",
+                OptionKind::IntegerAtLeast(0),
+                "Spaces before top-level items when `start-indented` is set",
+            )
+            .defaults_to("2")
+            .option(
+                "start-indented",
+                OptionKind::Boolean,
+                "Indent top-level items",
+            )
+            .defaults_to("false")
+            .either_case()
     }
 
     fn can_fix(&self) -> bool {
@@ -227,6 +249,20 @@ impl AstRule for MD007 {
             .map(|(start, end)| (start + frontmatter_offset, end + frontmatter_offset))
             .collect();
 
+        // Last line of each list item, keyed by the line of its marker
+        let item_ends: HashMap<usize, usize> = ast
+            .descendants()
+            .filter_map(|node| {
+                let data = node.data.borrow();
+                matches!(data.value, NodeValue::Item(_)).then(|| {
+                    (
+                        data.sourcepos.start.line + frontmatter_offset,
+                        data.sourcepos.end.line + frontmatter_offset,
+                    )
+                })
+            })
+            .collect();
+
         let mut list_stack: Vec<(usize, char, bool)> = Vec::new(); // (indent, marker, is_ordered)
 
         for (line_number, line) in lines.iter().enumerate() {
@@ -240,12 +276,24 @@ impl AstRule for MD007 {
                 continue;
             }
 
-            // Skip lines inside code blocks
+            // Skip lines inside code blocks. A block no more indented than the
+            // outermost list marker is not part of the list and ends it.
             let in_code_block = code_block_lines
                 .iter()
                 .any(|(start, end)| line_number >= *start && line_number <= *end);
 
             if in_code_block {
+                let opens_block = code_block_lines
+                    .iter()
+                    .any(|(start, _)| *start == line_number);
+                let block_indent = line.len() - line.trim_start().len();
+                if opens_block
+                    && list_stack
+                        .first()
+                        .is_some_and(|&(indent, _, _)| block_indent <= indent)
+                {
+                    list_stack.clear();
+                }
                 continue;
             }
 
@@ -263,25 +311,16 @@ impl AstRule for MD007 {
                     let expected_indent = self.calculate_expected_indent(current_depth);
 
                     if indent != expected_indent {
-                        // Create fix by replacing current indentation with expected indentation
-                        let fixed_line =
-                            format!("{}{}", " ".repeat(expected_indent), &line[indent..]);
-
-                        let fix = Fix {
-                            description: format!(
-                                "Fix indentation from {} to {} spaces",
-                                indent, expected_indent
-                            ),
-                            replacement: Some(fixed_line),
-                            start: Position {
-                                line: line_number,
-                                column: 1,
-                            },
-                            end: Position {
-                                line: line_number,
-                                column: line.len() + 1,
-                            },
-                        };
+                        // Move the whole item, including its continuation lines
+                        let end_line = item_ends.get(&line_number).copied().unwrap_or(line_number);
+                        let fix = reindent_item(
+                            document,
+                            line_number,
+                            end_line,
+                            indent,
+                            expected_indent,
+                            format!("Fix indentation from {indent} to {expected_indent} spaces"),
+                        );
 
                         violations.push(self.create_violation_with_fix(
                             format!(
@@ -493,13 +532,13 @@ mod tests {
 
         assert_eq!(violations.len(), 2);
 
-        // First violation: 5 spaces should be 2
+        // First violation: 5 spaces should be 2, moving the nested item along
         assert!(violations[0].fix.is_some());
         let fix1 = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix1.description, "Fix indentation from 5 to 2 spaces");
         assert_eq!(
             fix1.replacement,
-            Some("  * Too many spaces (5)".to_string())
+            Some("  * Too many spaces (5)\n      * Way too many spaces (9)".to_string())
         );
 
         // Second violation: 9 spaces should be 4
@@ -529,7 +568,7 @@ mod tests {
         assert_eq!(fix1.description, "Fix indentation from 2 to 4 spaces");
         assert_eq!(
             fix1.replacement,
-            Some("    * Wrong for 4-space indent".to_string())
+            Some("    * Wrong for 4-space indent\n        * Wrong again".to_string())
         );
 
         // Should expect 8 spaces for second level
@@ -565,12 +604,15 @@ mod tests {
             Some("  * No indent (wrong when start_indented)".to_string())
         );
 
-        // Second item should also have 2 spaces
+        // Second item should also have 2 spaces, moving its nested items along
         let fix2 = violations[1].fix.as_ref().unwrap();
         assert_eq!(fix2.description, "Fix indentation from 0 to 2 spaces");
         assert_eq!(
             fix2.replacement,
-            Some("  * Another no indent (also wrong)".to_string())
+            Some(
+                "  * Another no indent (also wrong)\n    * Correct for level 0 with start_indented\n      * Correct for level 1 (4 spaces = 2 base + 2 indent)"
+                    .to_string()
+            )
         );
     }
 
@@ -640,7 +682,7 @@ mod tests {
         let fix1 = violations[0].fix.as_ref().unwrap();
         assert_eq!(
             fix1.replacement,
-            Some("  + Plus item (wrong indent)".to_string())
+            Some("  + Plus item (wrong indent)\n    * Star item (wrong indent)".to_string())
         );
 
         let fix2 = violations[1].fix.as_ref().unwrap();
@@ -747,4 +789,42 @@ Another list:
             "Should not flag items in indented code blocks"
         );
     }
+    #[test]
+    fn test_md007_fenced_code_block_ends_list() {
+        // The fence at column 1 closes the first list, so the indented item
+        // after it starts a new top-level list
+        let content = "- First\n```\ncode\n```\n\n  - Second\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = MD007::new().check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 6);
+        assert!(violations[0].message.contains("Expected 0 spaces, found 2"));
+
+        // A fence indented under the item stays inside it
+        let content = "- First\n\n  ```\n  code\n  ```\n\n  - Nested\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        assert!(MD007::new().check(&document).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_md007_fix_moves_continuation_lines_and_code() {
+        let content = "* Item\n   * Nested\n\n     More text\n\n     ```sh\n     make\n       indented\n     ```\n* Next\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = MD007::new().check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.safety, mdbook_lint_core::violation::FixSafety::Unsafe);
+        assert_eq!((fix.start.line, fix.end.line), (2, 9));
+
+        let fixed = mdbook_lint_core::FixEngine::new()
+            .with_unsafe_fixes(true)
+            .apply(content, &violations)
+            .content;
+        assert_eq!(
+            fixed,
+            "* Item\n  * Nested\n\n    More text\n\n    ```sh\n    make\n      indented\n    ```\n* Next\n"
+        );
+    }
 }
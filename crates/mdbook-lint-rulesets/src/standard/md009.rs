@@ -57,10 +57,10 @@
 //! - You need to preserve exact whitespace for technical documentation
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for trailing spaces at the end of lines
@@ -141,7 +141,27 @@ impl AstRule for MD009 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("markdownlint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("markdownlint v0.1.0")
+            .option(
+                "br-spaces",
+                OptionKind::IntegerAtLeast(0),
+                "Trailing spaces allowed for a line break",
+            )
+            .defaults_to("2")
+            .option(
+                "list-item-empty-lines",
+                OptionKind::Boolean,
+                "Allow spaces on empty lines in list items",
+            )
+            .defaults_to("false")
+            .option(
+                "strict",
+                OptionKind::Boolean,
+                "Report line-break spaces too",
+            )
+            .defaults_to("false")
+            .either_case()
     }
 
     fn can_fix(&self) -> bool {
@@ -215,6 +235,7 @@ impl AstRule for MD009 {
                     line: line_num,
                     column: line.len() + 1,
                 },
+                safety: FixSafety::Safe,
             };
 
             violations.push(self.create_violation_with_fix(
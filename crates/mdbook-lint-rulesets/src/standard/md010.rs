@@ -37,15 +37,22 @@
 //! [rules.MD010]
 //! code_blocks = true  # Check for tabs in code blocks (default: true)
 //! spaces_per_tab = 4  # Number of spaces to replace each tab with (default: 4)
+//! ignore_code_languages = ["go", "make", "makefile", "tsv"]  # Code blocks where tabs are kept
 //! ```
 //!
 //! ## Automatic Fix
 //!
 //! This rule supports automatic fixing. The fix will:
 //!
-//! - Replace each tab character with the configured number of spaces
-//! - Preserve the visual indentation of your content
-//! - Handle tabs in all contexts (text, lists, code blocks)
+//! - Replace tabs in indentation, blockquote markers, and after list markers
+//!   by advancing to the next tab stop, so nested content stays nested
+//! - Replace other tabs in prose with the configured number of spaces
+//! - Expand tabs in code blocks to tab stops, keeping columns aligned
+//! - Leave code blocks in languages where tabs matter untouched
+//!
+//! CommonMark reads tabs in indentation as advancing to a multiple of 4
+//! columns, so with any other `spaces_per_tab` the fix can change how a line
+//! nests and is only applied with `--fix-unsafe`.
 //!
 //! ## When to Disable
 //!
@@ -53,21 +60,37 @@
 //!
 //! - Your project standard requires tabs
 //! - You're working with tab-delimited data files
-//! - You're documenting makefiles or other tab-sensitive formats
 
+use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
+/// Tab stop width CommonMark uses when a tab is part of a line's indentation
+const COMMONMARK_TAB_STOP: usize = 4;
+
 /// Rule to check for hard tab characters
 pub struct MD010 {
     /// Number of spaces that a tab character is equivalent to (for reporting)
     spaces_per_tab: usize,
     /// Whether to check for tabs inside code blocks (default: true)
     code_blocks: bool,
+    /// Code block languages where tabs are meaningful and left alone
+    ignore_code_languages: Vec<String>,
+}
+
+/// How a line's tabs are replaced
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum LineKind {
+    /// Markdown text, including fence lines
+    Prose,
+    /// Content of a fenced code block
+    FencedCode,
+    /// Content of an indented code block
+    IndentedCode,
 }
 
 impl MD010 {
@@ -76,6 +99,10 @@ impl MD010 {
         Self {
             spaces_per_tab: 4,
             code_blocks: true,
+            ignore_code_languages: ["go", "make", "makefile", "tsv"]
+                .iter()
+                .map(|language| language.to_string())
+                .collect(),
         }
     }
 
@@ -84,7 +111,7 @@ impl MD010 {
     pub fn with_spaces_per_tab(spaces_per_tab: usize) -> Self {
         Self {
             spaces_per_tab,
-            code_blocks: true,
+            ..Self::new()
         }
     }
 
@@ -95,6 +122,13 @@ impl MD010 {
         self
     }
 
+    /// Set the code block languages whose tabs are left alone
+    #[allow(dead_code)]
+    pub fn with_ignore_code_languages(mut self, languages: Vec<String>) -> Self {
+        self.ignore_code_languages = languages;
+        self
+    }
+
     /// Create MD010 from configuration
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::new();
@@ -104,7 +138,7 @@ impl MD010 {
             .or_else(|| config.get("spaces_per_tab"))
             .and_then(|v| v.as_integer())
         {
-            rule.spaces_per_tab = spaces as usize;
+            rule.spaces_per_tab = spaces.max(1) as usize;
         }
 
         if let Some(code_blocks) = config
@@ -115,8 +149,123 @@ impl MD010 {
             rule.code_blocks = code_blocks;
         }
 
+        if let Some(languages) = config
+            .get("ignore-code-languages")
+            .or_else(|| config.get("ignore_code_languages"))
+            .and_then(|v| v.as_array())
+        {
+            rule.ignore_code_languages = languages
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_lowercase())
+                .collect();
+        }
+
         rule
     }
+
+    /// Classify every line, or `None` for lines in code blocks that are not
+    /// checked
+    fn line_kinds<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Vec<Option<LineKind>> {
+        let mut kinds = vec![Some(LineKind::Prose); document.lines.len()];
+        let offset = document.frontmatter_ast_offset(ast);
+
+        for node in ast.descendants() {
+            let data = node.data.borrow();
+            let NodeValue::CodeBlock(block) = &data.value else {
+                continue;
+            };
+            let start = data.sourcepos.start.line + offset;
+            let end = (data.sourcepos.end.line + offset).min(kinds.len());
+            if start == 0 || start > end {
+                continue;
+            }
+
+            let language = block
+                .info
+                .split_whitespace()
+                .next()
+                .unwrap_or("")
+                .trim_matches(|c| c == '{' || c == '}' || c == '.')
+                .to_lowercase();
+            if block.fenced && self.ignore_code_languages.contains(&language) {
+                // Tabs are meaningful here, fences included
+                kinds[start - 1..end].fill(None);
+                continue;
+            }
+
+            let (content, kind) = if block.fenced {
+                // The fence lines are checked as prose
+                let fence = char::from(block.fence_char)
+                    .to_string()
+                    .repeat(block.fence_length);
+                let closed = end > start
+                    && document.lines[end - 1]
+                        .trim_start_matches([' ', '\t', '>'])
+                        .starts_with(&fence);
+                let last = if closed { end - 1 } else { end };
+                (start + 1..=last, LineKind::FencedCode)
+            } else {
+                (start..=end, LineKind::IndentedCode)
+            };
+            for line in content {
+                kinds[line - 1] = self.code_blocks.then_some(kind);
+            }
+        }
+
+        kinds
+    }
+
+    /// Fix that replaces every tab on a line
+    fn fix_line(&self, line_num: usize, line: &str, kind: LineKind) -> Fix {
+        let prefix = match kind {
+            LineKind::Prose => structural_prefix_len(line),
+            LineKind::FencedCode => line.len(),
+            LineKind::IndentedCode => line.len() - line.trim_start_matches([' ', '\t']).len(),
+        };
+        let tab_stops_everywhere = kind != LineKind::Prose;
+
+        let mut fixed = String::with_capacity(line.len() + self.spaces_per_tab);
+        let mut column = 0;
+        for (idx, ch) in line.char_indices() {
+            if ch != '\t' {
+                fixed.push(ch);
+                column += 1;
+                continue;
+            }
+            let width = if idx < prefix || tab_stops_everywhere {
+                self.spaces_per_tab - column % self.spaces_per_tab
+            } else {
+                self.spaces_per_tab
+            };
+            fixed.push_str(&" ".repeat(width));
+            column += width;
+        }
+
+        // Outside fenced code, tabs in indentation decide how a line nests,
+        // and CommonMark reads them with 4-column tab stops
+        let changes_nesting = kind != LineKind::FencedCode
+            && self.spaces_per_tab != COMMONMARK_TAB_STOP
+            && line[..prefix].contains('\t');
+
+        Fix {
+            description: format!("Replace tab with {} spaces", self.spaces_per_tab),
+            replacement: Some(fixed),
+            start: Position {
+                line: line_num,
+                column: 1,
+            },
+            end: Position {
+                line: line_num,
+                column: line.chars().count() + 1,
+            },
+            safety: if changes_nesting {
+                FixSafety::Unsafe
+            } else {
+                FixSafety::Safe
+            },
+        }
+    }
 }
 
 impl Default for MD010 {
@@ -125,7 +274,7 @@ impl Default for MD010 {
     }
 }
 
-impl Rule for MD010 {
+impl AstRule for MD010 {
     fn id(&self) -> &'static str {
         "MD010"
     }
@@ -139,58 +288,42 @@ impl Rule for MD010 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("markdownlint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("markdownlint v0.1.0")
+            .option(
+                "spaces-per-tab",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces a tab is replaced with when fixing",
+            )
+            .defaults_to("4")
+            .option(
+                "code-blocks",
+                OptionKind::Boolean,
+                "Check inside code blocks",
+            )
+            .defaults_to("true")
+            .option(
+                "ignore-code-languages",
+                OptionKind::StringList,
+                "Code block languages where tabs are left alone",
+            )
+            .defaults_to(r#"["go", "make", "makefile", "tsv"]"#)
+            .either_case()
     }
 
-    fn check_with_ast<'a>(
-        &self,
-        document: &Document,
-        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
-    ) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
-        let mut in_code_block = false;
+        let kinds = self.line_kinds(document, ast);
 
         for (line_number, line) in document.lines.iter().enumerate() {
             let line_num = line_number + 1; // Convert to 1-based line numbers
-
-            // Track fenced code block boundaries
-            let trimmed = line.trim_start();
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_code_block = !in_code_block;
-                // Still check the fence line itself for tabs
-            }
-
-            // Skip code block content if code_blocks is false
-            if !self.code_blocks
-                && in_code_block
-                && !trimmed.starts_with("```")
-                && !trimmed.starts_with("~~~")
-            {
+            let Some(kind) = kinds[line_number] else {
                 continue;
-            }
+            };
 
             // Check for tab characters
             if let Some(tab_pos) = line.find('\t') {
-                let column = tab_pos + 1; // Convert to 1-based column
-
-                // Create replacement string with spaces
-                let replacement = " ".repeat(self.spaces_per_tab);
-
-                // Find all tabs in the line to create a complete fix
-                let fixed_line = line.replace('\t', &replacement);
-
-                let fix = Fix {
-                    description: format!("Replace tab with {} spaces", self.spaces_per_tab),
-                    replacement: Some(fixed_line),
-                    start: Position {
-                        line: line_num,
-                        column: 1,
-                    },
-                    end: Position {
-                        line: line_num,
-                        column: line.len() + 1,
-                    },
-                };
+                let column = line[..tab_pos].chars().count() + 1; // Convert to 1-based column
 
                 violations.push(self.create_violation_with_fix(
                     format!(
@@ -200,7 +333,7 @@ impl Rule for MD010 {
                     line_num,
                     column,
                     Severity::Warning,
-                    fix,
+                    self.fix_line(line_num, line, kind),
                 ));
             }
         }
@@ -213,6 +346,32 @@ impl Rule for MD010 {
     }
 }
 
+/// Byte length of the part of a line that sets its nesting: indentation,
+/// blockquote markers, and list markers with the whitespace after them
+fn structural_prefix_len(line: &str) -> usize {
+    let mut pos = 0;
+    loop {
+        pos = line.len() - line[pos..].trim_start_matches([' ', '\t']).len();
+        let rest = &line[pos..];
+
+        if rest.starts_with('>') {
+            pos += 1;
+            continue;
+        }
+
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        let marker = match rest.as_bytes().get(digits) {
+            Some(b'.' | b')') if (1..=9).contains(&digits) => digits + 1,
+            Some(b'-' | b'*' | b'+') if digits == 0 => 1,
+            _ => return pos,
+        };
+        if !rest[marker..].starts_with([' ', '\t']) {
+            return pos;
+        }
+        pos += marker;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -452,4 +611,74 @@ mod tests {
             Some("Before        middle        after.".to_string())
         );
     }
+
+    fn fix(rule: &MD010, content: &str, unsafe_fixes: bool) -> String {
+        let document = create_test_document(content);
+        let violations = rule.check(&document).unwrap();
+        mdbook_lint_core::FixEngine::new()
+            .with_unsafe_fixes(unsafe_fixes)
+            .apply(content, &violations)
+            .content
+    }
+
+    #[test]
+    fn test_md010_fix_list_indentation_uses_tab_stops() {
+        let content = "-\tItem\n\t-\tNested\n\n> 1.\tQuoted\n\nName\tValue\n";
+        assert_eq!(
+            fix(&MD010::new(), content, false),
+            "-   Item\n    -   Nested\n\n> 1.    Quoted\n\nName    Value\n"
+        );
+    }
+
+    #[test]
+    fn test_md010_fix_other_tab_widths_is_unsafe() {
+        let content = "- Item\n\t- Nested\n\nText\twith tab.\n";
+        let rule = MD010::with_spaces_per_tab(2);
+        let document = create_test_document(content);
+        let violations = rule.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        let safety = |idx: usize| violations[idx].fix.as_ref().unwrap().safety;
+        assert_eq!(safety(0), FixSafety::Unsafe);
+        assert_eq!(safety(1), FixSafety::Safe);
+        assert_eq!(
+            fix(&rule, content, false),
+            "- Item\n\t- Nested\n\nText  with tab.\n"
+        );
+        assert_eq!(
+            fix(&rule, content, true),
+            "- Item\n  - Nested\n\nText  with tab.\n"
+        );
+    }
+
+    #[test]
+    fn test_md010_fix_code_blocks_align_to_tab_stops() {
+        let content = "```rust\nlet a\t= 1;\n\tlet bb\t= 2;\n```\n";
+        assert_eq!(
+            fix(&MD010::new(), content, false),
+            "```rust\nlet a   = 1;\n    let bb  = 2;\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_md010_ignores_languages_where_tabs_matter() {
+        let content = "```makefile\nall:\n\tcargo build\n```\n\n~~~Go\nfunc main() {\n\treturn\n}\n~~~\n\n```text\n\tflagged\n```\n";
+        let document = create_test_document(content);
+        let violations = MD010::new().check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 13);
+    }
+
+    #[test]
+    fn test_md010_ignore_code_languages_config() {
+        let config: toml::Value = toml::from_str(r#"ignore_code_languages = ["Text"]"#).unwrap();
+        let rule = MD010::from_config(&config);
+        let content = "```makefile\nall:\n\tcargo build\n```\n\n```text\n\tskipped\n```\n";
+        let document = create_test_document(content);
+        let violations = rule.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+    }
 }
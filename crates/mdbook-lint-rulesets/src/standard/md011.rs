@@ -7,7 +7,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for reversed link syntax
@@ -86,6 +86,7 @@ impl AstRule for MD011 {
                                 line: line_number + 1,
                                 column: end_pos + 1, // +1 because end_pos is 0-based position of ']'
                             },
+                            safety: FixSafety::Safe,
                         };
 
                         violations.push(self.create_violation_with_fix(
@@ -3,10 +3,10 @@
 //! This rule checks for multiple consecutive blank lines in the document.
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for multiple consecutive blank lines
@@ -59,7 +59,14 @@ impl Rule for MD012 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("markdownlint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("markdownlint v0.1.0")
+            .option(
+                "maximum",
+                OptionKind::IntegerAtLeast(1),
+                "Consecutive blank lines allowed",
+            )
+            .defaults_to("1")
     }
 
     fn can_fix(&self) -> bool {
@@ -106,6 +113,7 @@ impl Rule for MD012 {
                             line: fix_end_line + 1, // +1 to include the whole line
                             column: 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
@@ -144,6 +152,7 @@ impl Rule for MD012 {
                     line: fix_end_line + 1,
                     column: 1,
                 },
+                safety: FixSafety::Safe,
             };
 
             violations.push(self.create_violation_with_fix(
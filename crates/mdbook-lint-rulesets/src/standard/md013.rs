@@ -1,9 +1,10 @@
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
+use std::collections::HashMap;
 
 /// Line length calculation mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -15,10 +16,27 @@ pub enum LengthMode {
     Visual,
 }
 
+/// A run of prose lines that can be re-wrapped as a single unit
+struct ReflowParagraph {
+    /// First line of the paragraph (0-based index into `Document::lines`)
+    start: usize,
+    /// Last line of the paragraph (0-based, inclusive)
+    end: usize,
+    /// Prefix for the first output line (indentation plus any list marker)
+    first_prefix: String,
+    /// Prefix for every following output line
+    rest_prefix: String,
+}
+
 /// MD013: Line length should not exceed a specified limit
 ///
 /// This rule is triggered when lines exceed a specified length.
 /// The default line length is 80 characters.
+///
+/// Violations on plain prose paragraphs carry a fix that re-wraps the
+/// paragraph at the configured length. Because reflowing rewrites whole
+/// paragraphs (and therefore their diff history), the CLI only applies this
+/// fix with `--fix-unsafe`.
 pub struct MD013 {
     /// Maximum allowed line length
     pub line_length: usize,
@@ -210,6 +228,279 @@ impl MD013 {
         };
         !after_colon.trim().is_empty()
     }
+
+    /// Split a list item line into its marker prefix and content
+    ///
+    /// The prefix includes leading indentation, the bullet or ordinal marker,
+    /// the whitespace after it and a task-list checkbox if present, so that
+    /// reflowing never separates a marker from its item.
+    fn split_list_marker(line: &str) -> Option<(&str, &str)> {
+        let indent = line.len() - line.trim_start().len();
+        let rest = &line[indent..];
+
+        let marker_len = if rest.starts_with(['-', '*', '+']) {
+            1
+        } else {
+            let digits = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+            if digits == 0 || digits > 9 || !rest[digits..].starts_with(['.', ')']) {
+                return None;
+            }
+            digits + 1
+        };
+
+        let after_marker = &rest[marker_len..];
+        let spaces = after_marker.len() - after_marker.trim_start_matches(' ').len();
+        if spaces == 0 || after_marker.trim().is_empty() {
+            return None;
+        }
+
+        let mut prefix_len = indent + marker_len + spaces;
+        let content = &line[prefix_len..];
+        for checkbox in ["[ ] ", "[x] ", "[X] "] {
+            if content.starts_with(checkbox) {
+                prefix_len += checkbox.len();
+                break;
+            }
+        }
+
+        Some(line.split_at(prefix_len))
+    }
+
+    /// Whether a line is a thematic break or setext heading underline
+    fn is_rule_or_underline(trimmed: &str) -> bool {
+        let compact: String = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+        let Some(first) = compact.chars().next() else {
+            return false;
+        };
+        matches!(first, '-' | '=' | '*' | '_') && compact.chars().all(|c| c == first)
+    }
+
+    /// Whether a line is a block construct that must never be reflowed
+    fn is_non_prose_line(line: &str) -> bool {
+        let trimmed = line.trim();
+        trimmed.starts_with(['#', '>', '<', '|'])
+            || trimmed.starts_with("```")
+            || trimmed.starts_with("~~~")
+            || trimmed.starts_with("{{")
+            || trimmed.contains(" | ")
+            || Self::is_reference_definition(trimmed)
+            || Self::is_rule_or_underline(trimmed)
+    }
+
+    /// Whether a word would be parsed as a block marker if it started a line
+    fn starts_block(word: &str) -> bool {
+        if word.starts_with(['#', '>', '<', '|'])
+            || word.starts_with("```")
+            || word.starts_with("~~~")
+        {
+            return true;
+        }
+        if matches!(word, "-" | "+" | "*") || Self::is_rule_or_underline(word) {
+            return true;
+        }
+        let digits = word.chars().take_while(|c| c.is_ascii_digit()).count();
+        digits > 0 && matches!(&word[digits..], "." | ")")
+    }
+
+    /// Find the prose paragraphs in a document that can be safely re-wrapped
+    ///
+    /// Code blocks, tables, headings, HTML, blockquotes, reference
+    /// definitions, frontmatter and paragraphs containing hard line breaks
+    /// are never returned.
+    fn reflow_paragraphs(document: &Document) -> Vec<ReflowParagraph> {
+        let lines = &document.lines;
+
+        // Lines that belong to frontmatter or fenced code blocks
+        let mut excluded = vec![false; lines.len()];
+        if let Some((start, end)) = document.frontmatter_line_range() {
+            for flag in &mut excluded[start - 1..end] {
+                *flag = true;
+            }
+        }
+        let mut fence: Option<&str> = None;
+        for (idx, line) in lines.iter().enumerate() {
+            let trimmed = line.trim_start();
+            match fence {
+                Some(marker) => {
+                    excluded[idx] = true;
+                    if trimmed.starts_with(marker) {
+                        fence = None;
+                    }
+                }
+                None => {
+                    if trimmed.starts_with("```") {
+                        fence = Some("```");
+                        excluded[idx] = true;
+                    } else if trimmed.starts_with("~~~") {
+                        fence = Some("~~~");
+                        excluded[idx] = true;
+                    }
+                }
+            }
+        }
+
+        let is_candidate = |idx: usize| {
+            !excluded[idx] && !lines[idx].trim().is_empty() && !Self::is_non_prose_line(&lines[idx])
+        };
+
+        let mut paragraphs = Vec::new();
+        let mut idx = 0;
+        while idx < lines.len() {
+            // A paragraph starts on a prose line; four spaces of indentation
+            // without a list marker is an indented code block.
+            let line = &lines[idx];
+            let list_item = Self::split_list_marker(line);
+            let indent = line.len() - line.trim_start().len();
+            if !is_candidate(idx) || (list_item.is_none() && indent >= 4) {
+                idx += 1;
+                continue;
+            }
+
+            let start = idx;
+            let mut end = idx;
+            while end + 1 < lines.len()
+                && is_candidate(end + 1)
+                && Self::split_list_marker(&lines[end + 1]).is_none()
+            {
+                end += 1;
+            }
+            idx = end + 1;
+
+            // Text followed by `===`/`---` is a setext heading, not a paragraph
+            if idx < lines.len() && !excluded[idx] && Self::is_rule_or_underline(lines[idx].trim())
+            {
+                idx += 1;
+                continue;
+            }
+
+            // Hard line breaks are significant and would be lost by reflowing
+            let has_hard_break = lines[start..end]
+                .iter()
+                .any(|l| l.ends_with("  ") || l.ends_with('\\'));
+            if has_hard_break {
+                continue;
+            }
+
+            // Whitespace runs inside code spans are significant
+            let has_spaced_code = lines[start..=end]
+                .iter()
+                .any(|l| l.contains('`') && l.trim().contains("  "));
+            if has_spaced_code {
+                continue;
+            }
+
+            // List continuation lines align with the item content, not the
+            // task-list checkbox
+            let (first_prefix, rest_prefix) = match list_item {
+                Some((prefix, _)) => {
+                    let marker = ["[ ] ", "[x] ", "[X] "]
+                        .iter()
+                        .find_map(|checkbox| prefix.strip_suffix(checkbox))
+                        .unwrap_or(prefix);
+                    (prefix.to_string(), " ".repeat(marker.len()))
+                }
+                None => {
+                    let prefix = line[..indent].to_string();
+                    (prefix.clone(), prefix)
+                }
+            };
+
+            paragraphs.push(ReflowParagraph {
+                start,
+                end,
+                first_prefix,
+                rest_prefix,
+            });
+        }
+
+        paragraphs
+    }
+
+    /// Re-wrap the words of a paragraph at the configured line length
+    fn wrap_words(&self, words: &[&str], first_prefix: &str, rest_prefix: &str) -> Vec<String> {
+        let mut wrapped = Vec::new();
+        let mut current = first_prefix.to_string();
+        let mut has_word = false;
+
+        for word in words {
+            if !has_word {
+                current.push_str(word);
+                has_word = true;
+                continue;
+            }
+
+            let candidate = format!("{current} {word}");
+            // Never start a line with something that would change the block
+            // structure, even if that leaves the line too long.
+            if self.calculate_length(&candidate) <= self.line_length || Self::starts_block(word) {
+                current = candidate;
+            } else {
+                wrapped.push(std::mem::replace(
+                    &mut current,
+                    format!("{rest_prefix}{word}"),
+                ));
+            }
+        }
+
+        wrapped.push(current);
+        wrapped
+    }
+
+    /// Build a reflow fix for each paragraph that contains an over-long line
+    ///
+    /// The returned map is keyed by the 1-based line number of the first
+    /// over-long line in each paragraph; that violation carries the fix.
+    fn reflow_fixes(&self, document: &Document) -> HashMap<usize, Fix> {
+        let mut fixes = HashMap::new();
+
+        for paragraph in Self::reflow_paragraphs(document) {
+            let lines = &document.lines[paragraph.start..=paragraph.end];
+            let Some(first_long) = lines.iter().position(|line| {
+                !self.should_ignore_line(line, false, false)
+                    && self.calculate_length(line) > self.line_length
+            }) else {
+                continue;
+            };
+
+            let mut words: Vec<&str> = Vec::new();
+            for (offset, line) in lines.iter().enumerate() {
+                let content = match (offset, Self::split_list_marker(line)) {
+                    (0, Some((_, content))) => content,
+                    _ => line.as_str(),
+                };
+                words.extend(content.split_whitespace());
+            }
+
+            let wrapped = self.wrap_words(&words, &paragraph.first_prefix, &paragraph.rest_prefix);
+            if wrapped
+                .iter()
+                .map(String::as_str)
+                .eq(lines.iter().map(String::as_str))
+            {
+                continue;
+            }
+
+            let last_line = &document.lines[paragraph.end];
+            fixes.insert(
+                paragraph.start + first_long + 1,
+                Fix {
+                    description: format!("Reflow paragraph to {} characters", self.line_length),
+                    replacement: Some(wrapped.join("\n")),
+                    start: Position {
+                        line: paragraph.start + 1,
+                        column: 1,
+                    },
+                    end: Position {
+                        line: paragraph.end + 1,
+                        column: last_line.chars().count() + 1,
+                    },
+                    safety: FixSafety::Unsafe,
+                },
+            );
+        }
+
+        fixes
+    }
 }
 
 impl Default for MD013 {
@@ -232,7 +523,41 @@ impl Rule for MD013 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("markdownlint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("markdownlint v0.1.0")
+            .option(
+                "line-length",
+                OptionKind::IntegerAtLeast(20),
+                "Maximum line length",
+            )
+            .defaults_to("80")
+            .option(
+                "ignore-code-blocks",
+                OptionKind::Boolean,
+                "Skip code blocks",
+            )
+            .defaults_to("true")
+            .option("ignore-tables", OptionKind::Boolean, "Skip tables")
+            .defaults_to("true")
+            .option("ignore-headings", OptionKind::Boolean, "Skip headings")
+            .defaults_to("true")
+            .option(
+                "ignore-reference-definitions",
+                OptionKind::Boolean,
+                "Skip link reference definitions",
+            )
+            .defaults_to("false")
+            .option(
+                "length-mode",
+                OptionKind::Choice(&["strict", "visual"]),
+                "Count characters, or display width",
+            )
+            .defaults_to(r#""strict""#)
+            .either_case()
+    }
+
+    fn can_fix(&self) -> bool {
+        true
     }
 
     fn check_with_ast<'a>(
@@ -244,6 +569,7 @@ impl Rule for MD013 {
         let mut violations = Vec::new();
         let mut in_code_block = false;
         let mut in_table = false;
+        let mut fixes = self.reflow_fixes(document);
 
         for (line_number, line) in document.lines.iter().enumerate() {
             let line_num = line_number + 1; // Convert to 1-based
@@ -281,12 +607,18 @@ impl Rule for MD013 {
                     ),
                 };
 
-                violations.push(self.create_violation(
-                    message,
-                    line_num,
-                    self.line_length + 1, // Point to the first character that exceeds limit
-                    Severity::Warning,
-                ));
+                let column = self.line_length + 1; // Point to the first character that exceeds limit
+                let violation = match fixes.remove(&line_num) {
+                    Some(fix) => self.create_violation_with_fix(
+                        message,
+                        line_num,
+                        column,
+                        Severity::Warning,
+                        fix,
+                    ),
+                    None => self.create_violation(message, line_num, column, Severity::Warning),
+                };
+                violations.push(violation);
             }
         }
 
@@ -554,4 +886,69 @@ This is a normal line that should be checked if it's too long."#;
         let line = "This is a normal line without any URLs in it.";
         assert_eq!(rule.visual_length(line), line.len());
     }
+
+    #[test]
+    fn test_md013_reflow_paragraph_fix() {
+        let content = "# Title\n\nThe quick brown fox jumps over the lazy dog and keeps running far away\ninto the forest.\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let rule = MD013::with_line_length(40);
+        let violations = rule.check(&document).unwrap();
+
+        assert!(rule.can_fix());
+        assert_eq!(violations.len(), 1);
+        let fix = violations[0]
+            .fix
+            .as_ref()
+            .expect("paragraph should be reflowed");
+        assert_eq!(fix.start, Position { line: 3, column: 1 });
+        assert_eq!(
+            fix.end,
+            Position {
+                line: 4,
+                column: 17
+            }
+        );
+        assert_eq!(
+            fix.replacement.as_deref(),
+            Some(
+                "The quick brown fox jumps over the lazy\ndog and keeps running far away into the\nforest."
+            )
+        );
+    }
+
+    #[test]
+    fn test_md013_reflow_list_item_indentation() {
+        let content = "- [ ] one two three four five six seven eight nine ten eleven\n  twelve\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let rule = MD013::with_line_length(30);
+        let violations = rule.check(&document).unwrap();
+
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(
+            fix.replacement.as_deref(),
+            Some("- [ ] one two three four five\n  six seven eight nine ten\n  eleven twelve")
+        );
+        assert_eq!(fix.safety, FixSafety::Unsafe);
+    }
+
+    #[test]
+    fn test_md013_reflow_skips_non_prose() {
+        let long = "word ".repeat(20);
+        let content = format!(
+            "---\ntitle: {long}\n---\n\n> {long}\n\n    {long}\n\n<div>{long}</div>\n\nSetext {long}\n======\n\nHard break {long}  \nnext line\n"
+        );
+        let document = Document::new(content, PathBuf::from("test.md")).unwrap();
+        let rule = MD013::new();
+        let violations = rule.check(&document).unwrap();
+
+        assert!(!violations.is_empty());
+        assert!(violations.iter().all(|v| v.fix.is_none()));
+    }
+
+    #[test]
+    fn test_md013_reflow_avoids_block_markers_at_line_start() {
+        let rule = MD013::with_line_length(10);
+        let wrapped = rule.wrap_words(&["alpha", "beta", "-", "gamma", "1.", "delta"], "", "");
+        assert_eq!(wrapped, vec!["alpha beta -", "gamma 1.", "delta"]);
+    }
 }
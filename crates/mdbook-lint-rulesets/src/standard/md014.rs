@@ -8,7 +8,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that shell commands don't include dollar signs
@@ -115,6 +115,7 @@ impl AstRule for MD014 {
                                         line: base_line + lines.len(),
                                         column: lines.last().map(|l| l.len() + 1).unwrap_or(1),
                                     },
+                                    safety: FixSafety::Safe,
                                 };
 
                                 violations.push(self.create_violation_with_fix(
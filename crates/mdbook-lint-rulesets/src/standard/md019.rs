@@ -7,7 +7,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for multiple spaces after hash on ATX style headings
@@ -86,6 +86,7 @@ impl Rule for MD019 {
                                 line: line_num,
                                 column: line.len() + 1,
                             },
+                            safety: FixSafety::Safe,
                         };
 
                         violations.push(self.create_violation_with_fix(
@@ -7,7 +7,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for spaces inside hashes on closed ATX style headings
@@ -85,6 +85,7 @@ impl Rule for MD020 {
                                     line: line_num,
                                     column: line.len() + 1,
                                 },
+                                safety: FixSafety::Safe,
                             };
 
                             violations.push(self.create_violation_with_fix(
@@ -7,9 +7,11 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
 };
 
+use super::blank_lines::{has_blank_line_after, has_blank_line_before, insert_blank_line_after};
+
 /// MD022: Headings should be surrounded by blank lines
 ///
 /// This rule checks that headings have blank lines before and after them,
@@ -48,70 +50,40 @@ impl AstRule for MD022 {
 
         // Find all heading nodes in the AST
         for node in ast.descendants() {
-            if let NodeValue::Heading(_) = &node.data.borrow().value
+            if let NodeValue::Heading(heading) = &node.data.borrow().value
                 && let Some((line, column)) = document.node_position(node)
             {
                 let line = line + frontmatter_offset;
-                // Check for blank line before the heading
-                if !self.has_blank_line_before(document, line) {
-                    // Create fix to add blank line before heading
-                    let fix = if line > 1 {
-                        // Get the previous line to determine where to insert the blank line
-                        let prev_line_idx = line - 2; // Convert to 0-based index
-                        let prev_line = &document.lines[prev_line_idx];
-
-                        Fix {
-                            description: "Add blank line before heading".to_string(),
-                            replacement: Some(format!("{}\n", prev_line)),
-                            start: Position {
-                                line: line - 1,
-                                column: 1,
-                            },
-                            end: Position {
-                                line: line - 1,
-                                column: prev_line.len() + 1,
-                            },
-                        }
-                    } else {
-                        // Can't add blank line before first line
-                        Fix {
-                            description: "Cannot add blank line before first heading".to_string(),
-                            replacement: None,
-                            start: Position { line, column },
-                            end: Position { line, column },
-                        }
-                    };
+                // A setext heading ends on its underline
+                let end_line = if heading.setext {
+                    self.find_setext_underline(document, line)
+                } else {
+                    line
+                };
 
+                // Check for blank line before the heading
+                if !has_blank_line_before(document, line) {
                     violations.push(self.create_violation_with_fix(
                         "Heading should be preceded by a blank line".to_string(),
                         line,
                         column,
                         Severity::Warning,
-                        fix,
+                        insert_blank_line_after(
+                            document,
+                            line - 1,
+                            "Add blank line before heading",
+                        ),
                     ));
                 }
 
                 // Check for blank line after the heading
-                if !self.has_blank_line_after(document, line) {
-                    // Create fix to add blank line after heading
-                    let current_line = &document.lines[line - 1]; // Convert to 0-based
-
-                    let fix = Fix {
-                        description: "Add blank line after heading".to_string(),
-                        replacement: Some(format!("{}\n", current_line)),
-                        start: Position { line, column: 1 },
-                        end: Position {
-                            line,
-                            column: current_line.len() + 1,
-                        },
-                    };
-
+                if !has_blank_line_after(document, end_line) {
                     violations.push(self.create_violation_with_fix(
                         "Heading should be followed by a blank line".to_string(),
                         line,
                         column,
                         Severity::Warning,
-                        fix,
+                        insert_blank_line_after(document, end_line, "Add blank line after heading"),
                     ));
                 }
             }
@@ -122,105 +94,30 @@ impl AstRule for MD022 {
 }
 
 impl MD022 {
-    /// Check if there's a blank line before the given line number
-    fn has_blank_line_before(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the first line, no blank line needed
-        if line_num <= 1 {
-            return true;
-        }
-
-        // Check if the previous line is blank
-        if let Some(prev_line) = document.lines.get(line_num - 2) {
-            is_blank_line(prev_line)
-        } else {
-            true // Start of document
-        }
-    }
-
-    /// Check if there's a blank line after the given line number
-    fn has_blank_line_after(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the last line, no blank line needed
-        if line_num >= document.lines.len() {
-            return true;
-        }
-
-        // Check if the next line is blank
-        if let Some(next_line) = document.lines.get(line_num) {
-            is_blank_line(next_line)
-        } else {
-            true // End of document
-        }
-    }
-}
-
-/// Check if a line is considered "blank" for the purposes of spacing rules.
-/// A line is blank if:
-/// - It's empty or contains only whitespace
-/// - It's a blockquote line with no content after the `>` marker (e.g., `>`, `> `)
-fn is_blank_line(line: &str) -> bool {
-    let trimmed = line.trim();
-
-    // Empty line
-    if trimmed.is_empty() {
-        return true;
-    }
-
-    // Blockquote blank line: just `>` followed by nothing or whitespace
-    // This handles nested blockquotes too (e.g., `> >`, `>> >`)
-    let mut chars = trimmed.chars().peekable();
-    while let Some(ch) = chars.next() {
-        if ch == '>' {
-            // Continue checking for more `>` or whitespace
-            while let Some(&next_ch) = chars.peek() {
-                if next_ch == ' ' {
-                    chars.next();
-                } else {
-                    break;
-                }
-            }
-        } else {
-            // Found non-blockquote content
-            return false;
-        }
+    /// Find the `===` or `---` line that closes a setext heading
+    fn find_setext_underline(&self, document: &Document, start_line: usize) -> usize {
+        document
+            .lines
+            .iter()
+            .enumerate()
+            .skip(start_line)
+            .find(|(_, line)| {
+                let trimmed = line.trim();
+                !trimmed.is_empty()
+                    && (trimmed.chars().all(|c| c == '=') || trimmed.chars().all(|c| c == '-'))
+            })
+            .map_or(start_line, |(idx, _)| idx + 1)
     }
-
-    // If we consumed all characters and they were all `>` and whitespace, it's blank
-    true
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::FixEngine;
     use mdbook_lint_core::rule::Rule;
     use mdbook_lint_core::test_helpers::*;
     use std::path::PathBuf;
 
-    #[test]
-    fn test_is_blank_line() {
-        // Regular blank lines
-        assert!(is_blank_line(""));
-        assert!(is_blank_line("   "));
-        assert!(is_blank_line("\t"));
-
-        // Blockquote blank lines
-        assert!(is_blank_line(">"));
-        assert!(is_blank_line("> "));
-        assert!(is_blank_line(">  "));
-        assert!(is_blank_line(" > "));
-        assert!(is_blank_line("  >"));
-
-        // Nested blockquote blank lines
-        assert!(is_blank_line("> >"));
-        assert!(is_blank_line(">> "));
-        assert!(is_blank_line("> > "));
-
-        // Non-blank lines
-        assert!(!is_blank_line("text"));
-        assert!(!is_blank_line("> text"));
-        assert!(!is_blank_line("> > text"));
-        assert!(!is_blank_line(">text")); // No space after >, but has content
-    }
-
     #[test]
     fn test_md022_heading_in_blockquote_with_blank_lines() {
         // Issue #275: Headings inside blockquotes with blank lines (>) should be valid
@@ -475,9 +372,13 @@ Content after."#;
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Add blank line before heading");
-        assert_eq!(fix.replacement, Some("Some text before.\n".to_string()));
+        assert_eq!(fix.replacement, Some("\n".to_string()));
         assert_eq!(fix.start.line, 1);
-        assert_eq!(fix.start.column, 1);
+        assert_eq!(fix.start.column, 18);
+        assert_eq!(
+            FixEngine::new().apply(content, &violations).content,
+            "Some text before.\n\n# Title\n\nContent after."
+        );
     }
 
     #[test]
@@ -493,9 +394,13 @@ Content immediately after."#;
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Add blank line after heading");
-        assert_eq!(fix.replacement, Some("# Title\n".to_string()));
+        assert_eq!(fix.replacement, Some("\n".to_string()));
         assert_eq!(fix.start.line, 1);
-        assert_eq!(fix.start.column, 1);
+        assert_eq!(fix.start.column, 8);
+        assert_eq!(
+            FixEngine::new().apply(content, &violations).content,
+            "# Title\n\nContent immediately after."
+        );
     }
 
     #[test]
@@ -562,4 +467,15 @@ More content"#;
             assert!(fix.description.contains("blank line"));
         }
     }
+
+    #[test]
+    fn test_md022_setext_heading_fix_goes_after_underline() {
+        let content = "Intro\n\nTitle\n=====\nText\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = MD022.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        let fixed = FixEngine::new().apply(content, &violations).content;
+        assert_eq!(fixed, "Intro\n\nTitle\n=====\n\nText\n");
+    }
 }
@@ -2,12 +2,12 @@
 //!
 //! This rule checks that headings are not indented with spaces or tabs.
 
-use comrak::nodes::{AstNode, NodeValue};
+use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that headings start at the beginning of the line
@@ -34,19 +34,18 @@ impl AstRule for MD023 {
         true
     }
 
-    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, _ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
-
-        // Get code block line ranges from AST
-        let code_block_ranges = self.get_code_block_line_ranges(ast);
+        let index = document.index();
 
         for (line_number, line) in document.lines.iter().enumerate() {
             let line_num = line_number + 1; // Convert to 1-based line numbers
 
-            // Skip lines inside code blocks
-            if code_block_ranges
-                .iter()
-                .any(|(start, end)| line_num >= *start && line_num <= *end)
+            // Skip frontmatter, where `#` starts a YAML comment, and lines
+            // inside code and HTML blocks
+            if document.is_frontmatter_line(line_num)
+                || index.is_code_block_line(line_num)
+                || index.is_html_block_line(line_num)
             {
                 continue;
             }
@@ -75,6 +74,7 @@ impl AstRule for MD023 {
                         line: line_num,
                         column: line.len() + 1,
                     },
+                    safety: FixSafety::Safe,
                 };
 
                 violations.push(self.create_violation_with_fix(
@@ -98,33 +98,6 @@ impl AstRule for MD023 {
     }
 }
 
-impl MD023 {
-    /// Get all code block line ranges from the AST
-    fn get_code_block_line_ranges<'a>(&self, ast: &'a AstNode<'a>) -> Vec<(usize, usize)> {
-        let mut ranges = Vec::new();
-        self.collect_code_block_ranges(ast, &mut ranges);
-        ranges
-    }
-
-    /// Recursively collect code block ranges from AST nodes
-    #[allow(clippy::only_used_in_recursion)]
-    fn collect_code_block_ranges<'a>(
-        &self,
-        node: &'a AstNode<'a>,
-        ranges: &mut Vec<(usize, usize)>,
-    ) {
-        if let NodeValue::CodeBlock(_) = &node.data.borrow().value {
-            let sourcepos = node.data.borrow().sourcepos;
-            if sourcepos.start.line > 0 && sourcepos.end.line > 0 {
-                ranges.push((sourcepos.start.line, sourcepos.end.line));
-            }
-        }
-        for child in node.children() {
-            self.collect_code_block_ranges(child, ranges);
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -419,4 +392,14 @@ mod tests {
             assert!(!fix.replacement.as_ref().unwrap().starts_with(" "));
         }
     }
+
+    #[test]
+    fn test_md023_skips_frontmatter_code_and_html_blocks() {
+        let content = "---\ntitle: Test\n  # a YAML comment\n---\n\n# Heading\n\n    # indented code\n\n<div>\n  # inside html\n</div>\n\n  # Indented heading\n";
+        let document = create_test_document(content);
+        let violations = MD023.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 14);
+    }
 }
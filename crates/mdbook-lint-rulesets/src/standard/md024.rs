@@ -4,10 +4,10 @@
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 use std::collections::HashMap;
 
@@ -65,7 +65,15 @@ impl AstRule for MD024 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "siblings_only",
+                OptionKind::Boolean,
+                "Only compare headings with the same parent",
+            )
+            .defaults_to("false")
+            .either_case()
     }
 
     fn can_fix(&self) -> bool {
@@ -146,6 +154,7 @@ impl MD024 {
                             line,
                             column: line_content.len() + 1,
                         },
+                        safety: FixSafety::Unsafe,
                     };
 
                     violations.push(self.create_violation_with_fix(
@@ -230,6 +239,7 @@ impl MD024 {
                             line,
                             column: line_content.len() + 1,
                         },
+                        safety: FixSafety::Unsafe,
                     };
 
                     violations.push(self.create_violation_with_fix(
@@ -4,10 +4,10 @@
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
-    Document,
-    violation::{Fix, Position, Severity, Violation},
+    Document, DocumentKind,
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that documents have only one H1 heading
@@ -60,7 +60,21 @@ impl AstRule for MD025 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.1.0")
+        // A fragment's headings belong to the chapter that includes it
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.1.0")
+            .applies_to(&[
+                DocumentKind::Chapter,
+                DocumentKind::Summary,
+                DocumentKind::Readme,
+                DocumentKind::Adr,
+            ])
+            .option(
+                "level",
+                OptionKind::IntegerBetween(1, 6),
+                "Level of the title heading",
+            )
+            .defaults_to("1")
     }
 
     fn can_fix(&self) -> bool {
@@ -116,6 +130,7 @@ impl AstRule for MD025 {
                         line: *line,
                         column: line_content.len() + 1,
                     },
+                    safety: FixSafety::Unsafe,
                 };
 
                 violations.push(self.create_violation_with_fix(
@@ -4,10 +4,10 @@
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that headings do not end with punctuation
@@ -131,7 +131,14 @@ impl AstRule for MD026 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "punctuation",
+                OptionKind::String,
+                "Trailing characters to report",
+            )
+            .defaults_to(r#"".,;:!""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -207,6 +214,7 @@ impl AstRule for MD026 {
                             line,
                             column: line_content.len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
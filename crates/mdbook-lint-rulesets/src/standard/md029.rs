@@ -5,10 +5,10 @@
 
 use comrak::nodes::{AstNode, ListType, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Configuration for ordered list prefix style
@@ -18,10 +18,28 @@ pub enum OrderedListStyle {
     Sequential,
     /// All ones: 1, 1, 1, 1...
     AllOnes,
+    /// All zeros: 0, 0, 0, 0...
+    AllZeros,
+    /// Each list either sequential or all ones, decided per list
+    OneOrOrdered,
     /// Use whatever style is found first in the document
     Consistent,
 }
 
+/// An ordered list item's marker
+struct ListItem {
+    /// 1-based line of the marker
+    line: usize,
+    /// Byte offset of the marker in its line
+    offset: usize,
+    /// The number as written
+    prefix: String,
+    /// Width from the marker to the item's content
+    padding: usize,
+    /// Last line of the item
+    end_line: usize,
+}
+
 /// Rule to check for ordered list item prefix consistency
 pub struct MD029 {
     style: OrderedListStyle,
@@ -47,8 +65,10 @@ impl MD029 {
 
         if let Some(style_str) = config.get("style").and_then(|v| v.as_str()) {
             rule.style = match style_str.to_lowercase().as_str() {
-                "sequential" => OrderedListStyle::Sequential,
-                "all_ones" | "all-ones" => OrderedListStyle::AllOnes,
+                "sequential" | "ordered" => OrderedListStyle::Sequential,
+                "all_ones" | "all-ones" | "one" => OrderedListStyle::AllOnes,
+                "zero" => OrderedListStyle::AllZeros,
+                "one_or_ordered" | "one-or-ordered" => OrderedListStyle::OneOrOrdered,
                 "consistent" => OrderedListStyle::Consistent,
                 _ => OrderedListStyle::Consistent, // Default fallback
             };
@@ -78,7 +98,24 @@ impl AstRule for MD029 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&[
+                    "consistent",
+                    "one",
+                    "ordered",
+                    "zero",
+                    "one_or_ordered",
+                    "one-or-ordered",
+                    "sequential",
+                    "all_ones",
+                    "all-ones",
+                ]),
+                "Ordered list numbering",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -113,14 +150,22 @@ impl MD029 {
         let mut violations = Vec::new();
         let mut list_items = Vec::new();
 
-        // Collect all list items with their line numbers and prefixes
+        // Collect all list items with their markers
         for child in list_node.children() {
-            if let NodeValue::Item(_) = &child.data.borrow().value
-                && let Some((line_num, _)) = document.node_position(child)
+            let data = child.data.borrow();
+            if let NodeValue::Item(item) = &data.value
+                && let Some((line_num, column)) = document.node_position(child)
                 && let Some(line) = document.lines.get(line_num - 1)
-                && let Some(prefix) = self.extract_list_prefix(line)
+                && let Some(rest) = line.get(column - 1..)
+                && let Some(prefix) = self.extract_list_prefix(rest)
             {
-                list_items.push((line_num, prefix));
+                list_items.push(ListItem {
+                    line: line_num,
+                    offset: column - 1,
+                    prefix,
+                    padding: item.padding,
+                    end_line: data.sourcepos.end.line,
+                });
             }
         }
 
@@ -129,86 +174,135 @@ impl MD029 {
             return Ok(violations);
         }
 
+        let prefixes: Vec<(usize, String)> = list_items
+            .iter()
+            .map(|item| (item.line, item.prefix.clone()))
+            .collect();
+
         // Determine the expected style for this list
         let expected_style = match &self.style {
-            OrderedListStyle::Sequential => OrderedListStyle::Sequential,
-            OrderedListStyle::AllOnes => OrderedListStyle::AllOnes,
             OrderedListStyle::Consistent => {
                 if let Some(style) = detected_style {
                     style.clone()
                 } else {
                     // Detect style from this list
-                    let detected = self.detect_list_style(&list_items);
+                    let detected = self.detect_list_style(&prefixes);
                     *detected_style = Some(detected.clone());
                     detected
                 }
             }
+            OrderedListStyle::OneOrOrdered => self.detect_list_style(&prefixes),
+            style => style.clone(),
         };
 
+        // Ordered lists may count from zero
+        let first = usize::from(list_items[0].prefix != "0");
+
         // Check each item against the expected style
-        for (i, (line_num, actual_prefix)) in list_items.iter().enumerate() {
+        for (i, item) in list_items.iter().enumerate() {
             let expected_prefix = match expected_style {
-                OrderedListStyle::Sequential => (i + 1).to_string(),
-                OrderedListStyle::AllOnes => "1".to_string(),
-                OrderedListStyle::Consistent => {
-                    // This case is handled by detecting the style first
-                    continue;
-                }
+                OrderedListStyle::Sequential => (first + i).to_string(),
+                OrderedListStyle::AllZeros => "0".to_string(),
+                _ => "1".to_string(),
             };
-
-            if actual_prefix != &expected_prefix {
-                // Create fix by renumbering the list item
-                let line_content = &document.lines[*line_num - 1];
-                let trimmed = line_content.trim_start();
-                let indent = &line_content[..line_content.len() - trimmed.len()];
-
-                // Find where the number ends (at the dot)
-                if let Some(dot_pos) = trimmed.find('.') {
-                    let after_dot = &trimmed[dot_pos..];
-                    let fixed_line = format!("{}{}{}\n", indent, expected_prefix, after_dot);
-
-                    let fix = Fix {
-                        description: format!(
-                            "Change list item prefix from '{}' to '{}'",
-                            actual_prefix, expected_prefix
-                        ),
-                        replacement: Some(fixed_line),
-                        start: Position {
-                            line: *line_num,
-                            column: 1,
-                        },
-                        end: Position {
-                            line: *line_num,
-                            column: line_content.len() + 1,
-                        },
-                    };
-
-                    violations.push(self.create_violation_with_fix(
-                        format!(
-                            "Ordered list item prefix inconsistent: expected '{expected_prefix}', found '{actual_prefix}'"
-                        ),
-                        *line_num,
-                        1,
-                        Severity::Warning,
-                        fix,
-                    ));
-                } else {
-                    // Shouldn't happen if extract_list_prefix worked correctly
-                    violations.push(self.create_violation(
-                        format!(
-                            "Ordered list item prefix inconsistent: expected '{expected_prefix}', found '{actual_prefix}'"
-                        ),
-                        *line_num,
-                        1,
-                        Severity::Warning,
-                    ));
-                }
+            if item.prefix == expected_prefix {
+                continue;
             }
+
+            let fix = self.renumber(document, item, &expected_prefix);
+            violations.push(self.create_violation_with_fix(
+                format!(
+                    "Ordered list item prefix inconsistent: expected '{expected_prefix}', found '{}'",
+                    item.prefix
+                ),
+                item.line,
+                1,
+                Severity::Warning,
+                fix,
+            ));
         }
 
         Ok(violations)
     }
 
+    /// A fix giving `item` the number `prefix`
+    ///
+    /// When the number changes width, the item's continuation lines, nested
+    /// lists and code blocks included, are re-indented to stay inside the
+    /// item. Lines indented less than the item's content are lazy
+    /// continuations and are left alone.
+    fn renumber(&self, document: &Document, item: &ListItem, prefix: &str) -> Fix {
+        let line = &document.lines[item.line - 1];
+        let column = line[..item.offset].chars().count() + 1;
+        let width = |s: &str| s.chars().count();
+        let start = Position {
+            line: item.line,
+            column,
+        };
+        let description = format!(
+            "Change list item prefix from '{}' to '{prefix}'",
+            item.prefix
+        );
+
+        let delta = prefix.len() as isize - item.prefix.len() as isize;
+        let content_indent = column - 1 + item.padding;
+        let continuation = (item.line + 1..=item.end_line.min(document.lines.len()))
+            .map(|n| &document.lines[n - 1])
+            .collect::<Vec<_>>();
+        let shifts = delta != 0
+            && line[..item.offset].chars().all(|c| c == ' ')
+            && continuation
+                .iter()
+                .any(|l| Self::indent(l) >= content_indent);
+
+        if !shifts {
+            return Fix {
+                description,
+                replacement: Some(prefix.to_string()),
+                start,
+                end: Position {
+                    line: item.line,
+                    column: column + item.prefix.len(),
+                },
+                safety: FixSafety::Safe,
+            };
+        }
+
+        let mut replacement = format!("{prefix}{}", &line[item.offset + item.prefix.len()..]);
+        for continued in &continuation {
+            replacement.push('\n');
+            let indent = Self::indent(continued);
+            if indent >= content_indent {
+                let new_indent = (indent as isize + delta) as usize;
+                replacement.push_str(&" ".repeat(new_indent));
+                replacement.push_str(&continued[indent..]);
+            } else {
+                replacement.push_str(continued);
+            }
+        }
+        let last = continuation.last().copied().unwrap_or(line);
+        Fix {
+            description,
+            replacement: Some(replacement),
+            start,
+            end: Position {
+                line: item.line + continuation.len(),
+                column: width(last) + 1,
+            },
+            safety: FixSafety::Safe,
+        }
+    }
+
+    /// Leading spaces on a line, or zero for blank lines and tab indents
+    fn indent(line: &str) -> usize {
+        let spaces = line.len() - line.trim_start_matches(' ').len();
+        if line.trim().is_empty() || line[spaces..].starts_with('\t') {
+            0
+        } else {
+            spaces
+        }
+    }
+
     /// Extract the numeric prefix from a list item line
     fn extract_list_prefix(&self, line: &str) -> Option<String> {
         let trimmed = line.trim_start();
@@ -234,6 +328,9 @@ impl MD029 {
         if items.iter().all(|(_, prefix)| prefix == "1") {
             return OrderedListStyle::AllOnes;
         }
+        if items.iter().all(|(_, prefix)| prefix == "0") {
+            return OrderedListStyle::AllZeros;
+        }
 
         // Check if items are sequential starting from 1
         for (i, (_, prefix)) in items.iter().enumerate() {
@@ -541,19 +638,13 @@ Text here.
         assert!(violations[0].fix.is_some());
         let fix1 = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix1.description, "Change list item prefix from '1' to '2'");
-        assert_eq!(
-            fix1.replacement,
-            Some("2. Second item (should be 2)\n".to_string())
-        );
+        assert_eq!(fix1.replacement, Some("2".to_string()));
 
         // Check second fix (item 3)
         assert!(violations[1].fix.is_some());
         let fix2 = violations[1].fix.as_ref().unwrap();
         assert_eq!(fix2.description, "Change list item prefix from '1' to '3'");
-        assert_eq!(
-            fix2.replacement,
-            Some("3. Third item (should be 3)\n".to_string())
-        );
+        assert_eq!(fix2.replacement, Some("3".to_string()));
     }
 
     #[test]
@@ -574,18 +665,12 @@ Text here.
         assert!(violations[0].fix.is_some());
         let fix1 = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix1.description, "Change list item prefix from '2' to '1'");
-        assert_eq!(
-            fix1.replacement,
-            Some("1. Second item (should be 1)\n".to_string())
-        );
+        assert_eq!(fix1.replacement, Some("1".to_string()));
 
         assert!(violations[1].fix.is_some());
         let fix2 = violations[1].fix.as_ref().unwrap();
         assert_eq!(fix2.description, "Change list item prefix from '3' to '1'");
-        assert_eq!(
-            fix2.replacement,
-            Some("1. Third item (should be 1)\n".to_string())
-        );
+        assert_eq!(fix2.replacement, Some("1".to_string()));
     }
 
     #[test]
@@ -605,11 +690,13 @@ Text here.
         // Check that indentation is preserved
         assert!(violations[0].fix.is_some());
         let fix1 = violations[0].fix.as_ref().unwrap();
-        assert_eq!(fix1.replacement, Some("  2. Second item\n".to_string()));
+        assert_eq!(fix1.replacement, Some("2".to_string()));
+        assert_eq!(fix1.start.column, 3);
 
         assert!(violations[1].fix.is_some());
         let fix2 = violations[1].fix.as_ref().unwrap();
-        assert_eq!(fix2.replacement, Some("  3. Third item\n".to_string()));
+        assert_eq!(fix2.replacement, Some("3".to_string()));
+        assert_eq!(fix2.start.column, 3);
     }
 
     #[test]
@@ -617,4 +704,60 @@ Text here.
         let rule = MD029::new();
         assert!(mdbook_lint_core::AstRule::can_fix(&rule));
     }
+
+    fn fix(rule: &MD029, content: &str) -> String {
+        mdbook_lint_core::FixEngine::new()
+            .fix_until_stable(content, |content| {
+                let document = Document::new(content.to_string(), PathBuf::from("test.md"))?;
+                rule.check(&document)
+            })
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn test_md029_fix_nested_and_loose_lists() {
+        let content = "1. First\n\n   ```sh\n   2. not an item\n   ```\n\n1. Second\n   1. Nested\n   1. Nested again\n\n> 1. Quoted\n> 1. Quoted again\n";
+        assert_eq!(
+            fix(&MD029::with_style(OrderedListStyle::Sequential), content),
+            "1. First\n\n   ```sh\n   2. not an item\n   ```\n\n2. Second\n   1. Nested\n   2. Nested again\n\n> 1. Quoted\n> 2. Quoted again\n"
+        );
+    }
+
+    #[test]
+    fn test_md029_fix_reindents_when_width_changes() {
+        let items: String = (1..=9).map(|_| "1. Step\n").collect();
+        let content = format!("{items}1. Tenth\n\n   ```sh\n   make\n   ```\n1. Eleventh\n");
+        let expected: String = (1..=9).map(|n| format!("{n}. Step\n")).collect();
+        let renumbered =
+            format!("{expected}10. Tenth\n\n    ```sh\n    make\n    ```\n11. Eleventh\n");
+        assert_eq!(
+            fix(&MD029::with_style(OrderedListStyle::Sequential), &content),
+            renumbered
+        );
+        assert_eq!(
+            fix(&MD029::with_style(OrderedListStyle::AllOnes), &renumbered),
+            content
+        );
+    }
+
+    #[test]
+    fn test_md029_zero_and_one_or_ordered_styles() {
+        let config = |style: &str| {
+            MD029::from_config(&toml::from_str(&format!("style = \"{style}\"")).unwrap())
+        };
+        assert_eq!(fix(&config("zero"), "1. a\n2. b\n"), "0. a\n0. b\n");
+        assert_eq!(fix(&config("ordered"), "0. a\n0. b\n"), "0. a\n1. b\n");
+
+        // Each list picks its own style
+        let content = "1. a\n1. b\n\ntext\n\n1. c\n2. d\n";
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        assert!(
+            config("one_or_ordered")
+                .check(&document)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(MD029::new().check(&document).unwrap().len(), 1);
+    }
 }
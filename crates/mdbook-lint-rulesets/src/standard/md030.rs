@@ -4,10 +4,10 @@
 //! Unordered lists should have one space after the marker, and ordered lists should have one space after the period.
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Configuration for spaces after list markers
@@ -105,7 +105,33 @@ impl Rule for MD030 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "ul_single",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces after the marker of single-line unordered items",
+            )
+            .defaults_to("1")
+            .option(
+                "ol_single",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces after the marker of single-line ordered items",
+            )
+            .defaults_to("1")
+            .option(
+                "ul_multi",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces after the marker of multi-line unordered items",
+            )
+            .defaults_to("1")
+            .option(
+                "ol_multi",
+                OptionKind::IntegerAtLeast(1),
+                "Spaces after the marker of multi-line ordered items",
+            )
+            .defaults_to("1")
+            .either_case()
     }
 
     fn can_fix(&self) -> bool {
@@ -217,6 +243,7 @@ impl MD030 {
                         line: line_num,
                         column: line.len() + 1,
                     },
+                    safety: FixSafety::Safe,
                 };
 
                 return Some(self.create_violation_with_fix(
@@ -263,6 +290,7 @@ impl MD030 {
                         line: line_num,
                         column: line.len() + 1,
                     },
+                    safety: FixSafety::Safe,
                 };
 
                 return Some(self.create_violation_with_fix(
@@ -7,9 +7,11 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
 };
 
+use super::blank_lines::{has_blank_line_after, has_blank_line_before, insert_blank_line_after};
+
 /// MD031: Fenced code blocks should be surrounded by blank lines
 ///
 /// This rule checks that fenced code blocks (```) have blank lines before and after them,
@@ -40,6 +42,8 @@ impl AstRule for MD031 {
     fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
         let code_blocks = document.code_blocks(ast);
+        // comrak numbers lines after frontmatter from 1
+        let frontmatter_offset = document.frontmatter_ast_offset(ast);
 
         for code_block in code_blocks {
             // Only check fenced code blocks, not indented ones
@@ -47,62 +51,36 @@ impl AstRule for MD031 {
                 && code_block_data.fenced
                 && let Some((line, column)) = document.node_position(code_block)
             {
-                // Check for blank line before the code block
-                if !self.has_blank_line_before(document, line) {
-                    // Create fix by inserting a blank line before the code block
-                    let fix = Fix {
-                        description: "Add blank line before fenced code block".to_string(),
-                        replacement: Some("\n".to_string()),
-                        start: Position {
-                            line: line - 1,
-                            column: if line > 1 {
-                                document.lines.get(line - 2).map_or(1, |l| l.len() + 1)
-                            } else {
-                                1
-                            },
-                        },
-                        end: Position {
-                            line: line - 1,
-                            column: if line > 1 {
-                                document.lines.get(line - 2).map_or(1, |l| l.len() + 1)
-                            } else {
-                                1
-                            },
-                        },
-                    };
+                let line = line + frontmatter_offset;
 
+                // Check for blank line before the code block
+                if !has_blank_line_before(document, line) {
                     violations.push(self.create_violation_with_fix(
                         "Fenced code block should be preceded by a blank line".to_string(),
                         line,
                         column,
                         Severity::Warning,
-                        fix,
+                        insert_blank_line_after(
+                            document,
+                            line - 1,
+                            "Add blank line before fenced code block",
+                        ),
                     ));
                 }
 
                 // Check for blank line after the code block
                 let end_line = self.find_code_block_end_line(document, line);
-                if !self.has_blank_line_after(document, end_line) {
-                    // Create fix by inserting a blank line after the code block
-                    let fix = Fix {
-                        description: "Add blank line after fenced code block".to_string(),
-                        replacement: Some("\n".to_string()),
-                        start: Position {
-                            line: end_line,
-                            column: document.lines.get(end_line - 1).map_or(1, |l| l.len() + 1),
-                        },
-                        end: Position {
-                            line: end_line,
-                            column: document.lines.get(end_line - 1).map_or(1, |l| l.len() + 1),
-                        },
-                    };
-
+                if !has_blank_line_after(document, end_line) {
                     violations.push(self.create_violation_with_fix(
                         "Fenced code block should be followed by a blank line".to_string(),
                         end_line,
                         1,
                         Severity::Warning,
-                        fix,
+                        insert_blank_line_after(
+                            document,
+                            end_line,
+                            "Add blank line after fenced code block",
+                        ),
                     ));
                 }
             }
@@ -113,36 +91,6 @@ impl AstRule for MD031 {
 }
 
 impl MD031 {
-    /// Check if there's a blank line before the given line number
-    fn has_blank_line_before(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the first line, no blank line needed
-        if line_num <= 1 {
-            return true;
-        }
-
-        // Check if the previous line is blank
-        if let Some(prev_line) = document.lines.get(line_num - 2) {
-            is_blank_line(prev_line)
-        } else {
-            true // Start of document
-        }
-    }
-
-    /// Check if there's a blank line after the given line number
-    fn has_blank_line_after(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the last line, no blank line needed
-        if line_num >= document.lines.len() {
-            return true;
-        }
-
-        // Check if the next line is blank
-        if let Some(next_line) = document.lines.get(line_num) {
-            is_blank_line(next_line)
-        } else {
-            true // End of document
-        }
-    }
-
     /// Find the end line of a code block starting at the given line
     fn find_code_block_end_line(&self, document: &Document, start_line: usize) -> usize {
         let start_idx = start_line - 1; // Convert to 0-based
@@ -211,41 +159,6 @@ fn strip_blockquote_markers(line: &str) -> &str {
     &line[pos..]
 }
 
-/// Check if a line is considered "blank" for the purposes of spacing rules.
-/// A line is blank if:
-/// - It's empty or contains only whitespace
-/// - It's a blockquote line with no content after the `>` marker (e.g., `>`, `> `)
-fn is_blank_line(line: &str) -> bool {
-    let trimmed = line.trim();
-
-    // Empty line
-    if trimmed.is_empty() {
-        return true;
-    }
-
-    // Blockquote blank line: just `>` followed by nothing or whitespace
-    // This handles nested blockquotes too (e.g., `> >`, `>> >`)
-    let mut chars = trimmed.chars().peekable();
-    while let Some(ch) = chars.next() {
-        if ch == '>' {
-            // Continue checking for more `>` or whitespace
-            while let Some(&next_ch) = chars.peek() {
-                if next_ch == ' ' {
-                    chars.next();
-                } else {
-                    break;
-                }
-            }
-        } else {
-            // Found non-blockquote content
-            return false;
-        }
-    }
-
-    // If we consumed all characters and they were all `>` and whitespace, it's blank
-    true
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,7 +7,11 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
+};
+
+use super::blank_lines::{
+    has_blank_line_after, has_blank_line_before, insert_blank_line_after, is_blank_line,
 };
 
 /// MD032: Lists should be surrounded by blank lines
@@ -39,6 +43,8 @@ impl AstRule for MD032 {
 
     fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
+        // comrak numbers lines after frontmatter from 1
+        let frontmatter_offset = document.frontmatter_ast_offset(ast);
 
         // Find all list nodes in the AST
         for node in ast.descendants() {
@@ -47,68 +53,36 @@ impl AstRule for MD032 {
                 if !self.is_nested_list(node)
                     && let Some((start_line, start_column)) = document.node_position(node)
                 {
-                    // Check for blank line before the list
-                    if !self.has_blank_line_before(document, start_line) {
-                        // Create fix by inserting a blank line before the list
-                        let fix = Fix {
-                            description: "Add blank line before list".to_string(),
-                            replacement: Some("\n".to_string()),
-                            start: Position {
-                                line: start_line - 1,
-                                column: if start_line > 1 {
-                                    document
-                                        .lines
-                                        .get(start_line - 2)
-                                        .map_or(1, |l| l.len() + 1)
-                                } else {
-                                    1
-                                },
-                            },
-                            end: Position {
-                                line: start_line - 1,
-                                column: if start_line > 1 {
-                                    document
-                                        .lines
-                                        .get(start_line - 2)
-                                        .map_or(1, |l| l.len() + 1)
-                                } else {
-                                    1
-                                },
-                            },
-                        };
+                    let start_line = start_line + frontmatter_offset;
 
+                    // Check for blank line before the list
+                    if !has_blank_line_before(document, start_line) {
                         violations.push(self.create_violation_with_fix(
                             "List should be preceded by a blank line".to_string(),
                             start_line,
                             start_column,
                             Severity::Warning,
-                            fix,
+                            insert_blank_line_after(
+                                document,
+                                start_line - 1,
+                                "Add blank line before list",
+                            ),
                         ));
                     }
 
                     // Find the end line of the list by checking all its descendants
-                    let end_line = self.find_list_end_line(document, node);
-                    if !self.has_blank_line_after(document, end_line) {
-                        // Create fix by inserting a blank line after the list
-                        let fix = Fix {
-                            description: "Add blank line after list".to_string(),
-                            replacement: Some("\n".to_string()),
-                            start: Position {
-                                line: end_line,
-                                column: document.lines.get(end_line - 1).map_or(1, |l| l.len() + 1),
-                            },
-                            end: Position {
-                                line: end_line,
-                                column: document.lines.get(end_line - 1).map_or(1, |l| l.len() + 1),
-                            },
-                        };
-
+                    let end_line = self.find_list_end_line(document, node, frontmatter_offset);
+                    if !has_blank_line_after(document, end_line) {
                         violations.push(self.create_violation_with_fix(
                             "List should be followed by a blank line".to_string(),
                             end_line,
                             1,
                             Severity::Warning,
-                            fix,
+                            insert_blank_line_after(
+                                document,
+                                end_line,
+                                "Add blank line after list",
+                            ),
                         ));
                     }
                 }
@@ -141,45 +115,24 @@ impl MD032 {
         false
     }
 
-    /// Check if there's a blank line before the given line number
-    fn has_blank_line_before(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the first line, no blank line needed
-        if line_num <= 1 {
-            return true;
-        }
-
-        // Check if the previous line is blank
-        if let Some(prev_line) = document.lines.get(line_num - 2) {
-            prev_line.trim().is_empty()
-        } else {
-            true // Start of document
-        }
-    }
-
-    /// Check if there's a blank line after the given line number
-    fn has_blank_line_after(&self, document: &Document, line_num: usize) -> bool {
-        // If this is the last line, no blank line needed
-        if line_num >= document.lines.len() {
-            return true;
-        }
-
-        // Check if the next line is blank
-        if let Some(next_line) = document.lines.get(line_num) {
-            next_line.trim().is_empty()
-        } else {
-            true // End of document
-        }
-    }
-
-    /// Find the end line of a list by examining all its descendants
-    fn find_list_end_line<'a>(&self, document: &Document, list_node: &'a AstNode<'a>) -> usize {
-        let mut max_line = 1;
-
-        // Walk through all descendants to find the maximum line number
-        for descendant in list_node.descendants() {
-            if let Some((line, _)) = document.node_position(descendant) {
-                max_line = max_line.max(line);
-            }
+    /// Find the last non-blank line of a list from the extent of its descendants
+    fn find_list_end_line<'a>(
+        &self,
+        document: &Document,
+        list_node: &'a AstNode<'a>,
+        frontmatter_offset: usize,
+    ) -> usize {
+        let start_line = list_node.data.borrow().sourcepos.start.line + frontmatter_offset;
+        let mut max_line = list_node
+            .descendants()
+            .map(|descendant| descendant.data.borrow().sourcepos.end.line + frontmatter_offset)
+            .max()
+            .unwrap_or(start_line)
+            .min(document.lines.len());
+
+        // Items can extend over the blank lines that follow them
+        while max_line > start_line && is_blank_line(&document.lines[max_line - 1]) {
+            max_line -= 1;
         }
 
         max_line
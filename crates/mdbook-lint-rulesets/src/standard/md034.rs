@@ -2,16 +2,62 @@
 //!
 //! This rule checks for bare URLs that should be enclosed in angle brackets.
 
-use comrak::nodes::{AstNode, NodeValue};
+use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for bare URLs without angle brackets
-pub struct MD034;
+pub struct MD034 {
+    /// How fixes format a bare URL
+    style: UrlStyle,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UrlStyle {
+    /// Wrap the URL in angle brackets (<https://example.com>)
+    Angle,
+    /// Use the URL as the text of an inline link ([https://example.com](https://example.com))
+    Link,
+}
+
+impl MD034 {
+    /// Create a new MD034 rule that fixes bare URLs with angle brackets
+    pub fn new() -> Self {
+        Self {
+            style: UrlStyle::Angle,
+        }
+    }
+
+    /// Create a new MD034 rule with a specific fix style
+    #[allow(dead_code)]
+    pub fn with_style(style: UrlStyle) -> Self {
+        Self { style }
+    }
+
+    /// Create MD034 from configuration
+    pub fn from_config(config: &toml::Value) -> Self {
+        let mut rule = Self::new();
+
+        if let Some(style_str) = config.get("style").and_then(|v| v.as_str()) {
+            rule.style = match style_str.to_lowercase().as_str() {
+                "link" => UrlStyle::Link,
+                _ => UrlStyle::Angle, // Default fallback
+            };
+        }
+
+        rule
+    }
+}
+
+impl Default for MD034 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl AstRule for MD034 {
     fn id(&self) -> &'static str {
@@ -27,26 +73,33 @@ impl AstRule for MD034 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["angle", "link"]),
+                "How fixes format a bare URL",
+            )
+            .defaults_to(r#""angle""#)
     }
 
     fn can_fix(&self) -> bool {
         true
     }
 
-    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, _ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
-
-        // Get code block line ranges from AST
-        let code_block_ranges = self.get_code_block_line_ranges(ast);
+        let index = document.index();
 
         for (line_number, line) in document.lines.iter().enumerate() {
             let line_num = line_number + 1; // 1-based line numbers
 
-            // Skip lines inside code blocks
-            if code_block_ranges
-                .iter()
-                .any(|(start, end)| line_num >= *start && line_num <= *end)
+            // Code blocks (fenced and indented) and HTML blocks never contain
+            // bare URLs: they are shown verbatim, and `<url>` would not be a
+            // link there
+            if document.is_frontmatter_line(line_num)
+                || index.is_code_block_line(line_num)
+                || index.is_html_block_line(line_num)
             {
                 continue;
             }
@@ -66,21 +119,21 @@ impl AstRule for MD034 {
                 }
             }
 
-            // Parse the line character by character looking for bare URLs
-            let chars: Vec<char> = line.chars().collect();
+            // Parse the line character by character looking for bare URLs,
+            // keeping each character's byte offset for the code span lookup
+            let (offsets, chars): (Vec<usize>, Vec<char>) = line.char_indices().unzip();
             let mut i = 0;
 
             while i < chars.len() {
                 // Skip inline code spans
-                if chars[i] == '`' {
-                    i += 1;
-                    // Find the closing backtick
-                    while i < chars.len() && chars[i] != '`' {
+                if let Some((_, end)) = index
+                    .code_spans(line_num)
+                    .iter()
+                    .find(|(start, _)| *start == offsets[i])
+                {
+                    while i < chars.len() && offsets[i] < *end {
                         i += 1;
                     }
-                    if i < chars.len() {
-                        i += 1; // Skip closing backtick
-                    }
                     continue;
                 }
 
@@ -120,32 +173,46 @@ impl AstRule for MD034 {
                 if i + 7 < chars.len() && self.starts_with_url_scheme(&chars, i) {
                     let start_pos = i;
                     let url = self.extract_url(&chars, i);
+                    let url_len = url.chars().count();
 
                     if !url.is_empty() {
-                        // Create fix to wrap URL in angle brackets
+                        let (description, replacement, suggestion) = match self.style {
+                            UrlStyle::Angle => (
+                                "Wrap URL in angle brackets",
+                                format!("<{url}>"),
+                                "wrapping in angle brackets",
+                            ),
+                            UrlStyle::Link => (
+                                "Convert URL to a link",
+                                format!("[{}]({url})", escape_link_text(&url)),
+                                "converting to a link",
+                            ),
+                        };
+                        let message =
+                            format!("Bare URL used: {url}. Consider {suggestion}: {replacement}");
+
                         let fix = Fix {
-                            description: "Wrap URL in angle brackets".to_string(),
-                            replacement: Some(format!("<{}>", url)),
+                            description: description.to_string(),
+                            replacement: Some(replacement),
                             start: Position {
-                                line: line_number + 1,
+                                line: line_num,
                                 column: start_pos + 1,
                             },
                             end: Position {
-                                line: line_number + 1,
-                                column: start_pos + url.len() + 1,
+                                line: line_num,
+                                column: start_pos + url_len + 1,
                             },
+                            safety: FixSafety::Safe,
                         };
 
                         violations.push(self.create_violation_with_fix(
-                            format!(
-                                "Bare URL used: {url}. Consider wrapping in angle brackets: <{url}>"
-                            ),
-                            line_number + 1, // 1-based line numbers
-                            start_pos + 1,   // 1-based column
+                            message,
+                            line_num,
+                            start_pos + 1, // 1-based column
                             Severity::Warning,
                             fix,
                         ));
-                        i = start_pos + url.len();
+                        i = start_pos + url_len;
                     } else {
                         i += 1;
                     }
@@ -160,31 +227,6 @@ impl AstRule for MD034 {
 }
 
 impl MD034 {
-    /// Get all code block line ranges from the AST
-    fn get_code_block_line_ranges<'a>(&self, ast: &'a AstNode<'a>) -> Vec<(usize, usize)> {
-        let mut ranges = Vec::new();
-        self.collect_code_block_ranges(ast, &mut ranges);
-        ranges
-    }
-
-    /// Recursively collect code block ranges from AST nodes
-    #[allow(clippy::only_used_in_recursion)]
-    fn collect_code_block_ranges<'a>(
-        &self,
-        node: &'a AstNode<'a>,
-        ranges: &mut Vec<(usize, usize)>,
-    ) {
-        if let NodeValue::CodeBlock(_) = &node.data.borrow().value {
-            let sourcepos = node.data.borrow().sourcepos;
-            if sourcepos.start.line > 0 && sourcepos.end.line > 0 {
-                ranges.push((sourcepos.start.line, sourcepos.end.line));
-            }
-        }
-        for child in node.children() {
-            self.collect_code_block_ranges(child, ranges);
-        }
-    }
-
     /// Check if the character sequence starts with a URL scheme
     fn starts_with_url_scheme(&self, chars: &[char], pos: usize) -> bool {
         let schemes = ["http://", "https://", "ftp://", "mailto:"];
@@ -211,14 +253,27 @@ impl MD034 {
     fn extract_url(&self, chars: &[char], start: usize) -> String {
         let mut url = String::new();
         let mut i = start;
+        // Parentheses opened inside the URL, as in wiki/Rust_(language)
+        let mut open_parens = 0;
 
         // Extract until we hit whitespace or certain delimiters
         while i < chars.len() {
             let ch = chars[i];
-            if ch.is_whitespace() || ch == ')' || ch == ']' || ch == '>' || ch == '"' || ch == '\''
+            if ch.is_whitespace()
+                || ch == ']'
+                || ch == '<'
+                || ch == '>'
+                || ch == '"'
+                || ch == '\''
+                || (ch == ')' && open_parens == 0)
             {
                 break;
             }
+            match ch {
+                '(' => open_parens += 1,
+                ')' => open_parens -= 1,
+                _ => {}
+            }
             url.push(ch);
             i += 1;
         }
@@ -242,6 +297,19 @@ impl MD034 {
     }
 }
 
+/// Escape characters that would turn a URL used as link text into emphasis,
+/// code, or a nested link
+fn escape_link_text(url: &str) -> String {
+    let mut text = String::with_capacity(url.len());
+    for ch in url.chars() {
+        if matches!(ch, '\\' | '*' | '_' | '`' | '[' | ']') {
+            text.push('\\');
+        }
+        text.push(ch);
+    }
+    text
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -263,7 +331,7 @@ These URLs are properly formatted and should not trigger violations:
 Text with <https://wrapped-url.com> in angle brackets.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 0);
@@ -278,7 +346,7 @@ This has a bare URL: https://example.com that should be wrapped.
 Some content here.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -301,7 +369,7 @@ Second URL: http://second.com there.
 And an email: mailto:test@example.com end.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 3);
@@ -323,7 +391,7 @@ But this https://bare.com is not.
 Another [link](mailto:test@example.com) is good.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -351,7 +419,7 @@ Another https://bare-url2.com should be detected.
 Final https://final-url.com should be detected.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 3);
@@ -370,7 +438,7 @@ See https://other.com; it has details.
 The URL is https://final.com: very useful.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 4);
@@ -389,7 +457,7 @@ This https://example.com/path?param=value&other=test#anchor is complex.
 This ftp://files.example.com/path/file.txt is an FTP URL.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 2);
@@ -416,7 +484,7 @@ This is not a URL: http:something or https:other
 Normal text without URLs should be fine.
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 0);
@@ -426,7 +494,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_simple_url() {
         let content = "Visit https://example.com for more info.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -440,7 +508,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_multiple_urls() {
         let content = "Check https://first.com and http://second.com for details.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 2);
@@ -459,7 +527,7 @@ Normal text without URLs should be fine.
         let content =
             "API docs: https://api.example.com/v1/users?limit=10&offset=0#pagination here.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -474,7 +542,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_ftp_url() {
         let content = "Download from ftp://files.example.com/path/file.txt today.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -489,7 +557,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_mailto() {
         let content = "Contact us at mailto:support@example.com for help.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -504,7 +572,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_url_with_trailing_punctuation() {
         let content = "Visit https://example.com. Also check https://test.com, please.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 2);
@@ -535,7 +603,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_position_accuracy() {
         let content = "Text before https://example.com text after.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -550,7 +618,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_multiple_lines() {
         let content = "First line with https://first.com\nSecond line with http://second.com\nThird line with ftp://third.com";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 3);
@@ -593,7 +661,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_url_at_start_of_line() {
         let content = "https://example.com is a great site.";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -606,7 +674,7 @@ Normal text without URLs should be fine.
     fn test_md034_fix_url_at_end_of_line() {
         let content = "Check out this site: https://example.com";
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         assert_eq!(violations.len(), 1);
@@ -629,7 +697,7 @@ But this bare URL https://bare.com should be detected.
 [nested-brackets]: https://should-not-work[].com
 "#;
         let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
-        let rule = MD034;
+        let rule = MD034::default();
         let violations = rule.check(&document).unwrap();
 
         // Should only detect the bare URL, not the reference definitions
@@ -637,4 +705,47 @@ But this bare URL https://bare.com should be detected.
         assert!(violations[0].message.contains("https://bare.com"));
         assert_eq!(violations[0].line, 9);
     }
+
+    fn fix(rule: &MD034, content: &str) -> String {
+        let document = Document::new(content.to_string(), PathBuf::from("test.md")).unwrap();
+        let violations = rule.check(&document).unwrap();
+        mdbook_lint_core::FixEngine::new()
+            .apply(content, &violations)
+            .content
+    }
+
+    #[test]
+    fn test_md034_fix_link_style() {
+        let rule = MD034::with_style(UrlStyle::Link);
+        assert_eq!(
+            fix(&rule, "See https://example.com/some_path*x for details.\n"),
+            "See [https://example.com/some\\_path\\*x](https://example.com/some_path*x) for details.\n"
+        );
+
+        let config: toml::Value = toml::from_str(r#"style = "link""#).unwrap();
+        let rule = MD034::from_config(&config);
+        assert_eq!(
+            fix(&rule, "Mail mailto:me@example.com today.\n"),
+            "Mail [mailto:me@example.com](mailto:me@example.com) today.\n"
+        );
+    }
+
+    #[test]
+    fn test_md034_skips_indented_code_html_and_frontmatter() {
+        let content = "---\nurl: https://front.example.com\n---\n\nText https://a.com here.\n\n    curl https://indented.example.com\n\n<div>\n<a href=https://html.example.com>x</a>\n</div>\n\nUse ``https://code.example.com`` and `` `x` https://span.example.com ``.\n";
+        assert_eq!(
+            fix(&MD034::default(), content),
+            content.replace("Text https://a.com", "Text <https://a.com>")
+        );
+    }
+
+    #[test]
+    fn test_md034_fix_balanced_parentheses_and_unicode() {
+        let content =
+            "Ünïcode https://en.wikipedia.org/wiki/Rust_(language) (see https://example.com).\n";
+        assert_eq!(
+            fix(&MD034::default(), content),
+            "Ünïcode <https://en.wikipedia.org/wiki/Rust_(language)> (see <https://example.com>).\n"
+        );
+    }
 }
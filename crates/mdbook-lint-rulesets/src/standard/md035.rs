@@ -1,7 +1,7 @@
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 
 /// MD035 - Horizontal rule style
 pub struct MD035 {
@@ -132,6 +132,12 @@ impl Rule for MD035 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Formatting)
+            .option(
+                "style",
+                OptionKind::String,
+                "Horizontal rule to require, such as `---`, or `consistent`",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -191,6 +197,7 @@ impl Rule for MD035 {
                         line: line_number,
                         column: line_content.len() + 1,
                     },
+                    safety: FixSafety::Safe,
                 };
 
                 violations.push(self.create_violation_with_fix(
@@ -1,6 +1,6 @@
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::violation::{Severity, Violation};
 
 /// MD036 - Emphasis used instead of a heading
@@ -115,6 +115,12 @@ impl Rule for MD036 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Structure)
+            .option(
+                "punctuation",
+                OptionKind::String,
+                "Trailing characters that mark a paragraph rather than a heading",
+            )
+            .defaults_to(r#"".,;:!?。，；：！？""#)
     }
 
     fn check_with_ast<'a>(
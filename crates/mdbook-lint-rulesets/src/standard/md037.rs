@@ -1,7 +1,7 @@
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 
 /// MD037 - Spaces inside emphasis markers
 pub struct MD037;
@@ -176,6 +176,7 @@ impl MD037 {
                                         line: line_number,
                                         column: line.len() + 1,
                                     },
+                                    safety: FixSafety::Safe,
                                 };
 
                                 violations.push(self.create_violation_with_fix(
@@ -300,6 +301,7 @@ impl MD037 {
                                         line: line_number,
                                         column: line.len() + 1,
                                     },
+                                    safety: FixSafety::Safe,
                                 };
 
                                 violations.push(self.create_violation_with_fix(
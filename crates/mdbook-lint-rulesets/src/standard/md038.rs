@@ -1,7 +1,7 @@
 use mdbook_lint_core::Document;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
-use mdbook_lint_core::violation::{Fix, Position, Severity, Violation};
+use mdbook_lint_core::violation::{Fix, FixSafety, Position, Severity, Violation};
 
 /// MD038 - Spaces inside code span elements
 pub struct MD038;
@@ -76,6 +76,7 @@ impl MD038 {
                                     line: line_number,
                                     column: line.len() + 1,
                                 },
+                                safety: FixSafety::Safe,
                             };
 
                             violations.push(self.create_violation_with_fix(
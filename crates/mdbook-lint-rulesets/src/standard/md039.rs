@@ -6,7 +6,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check for spaces inside link text
@@ -66,6 +66,7 @@ impl MD039 {
                                 line: line_number,
                                 column: line.len() + 1,
                             },
+                            safety: FixSafety::Safe,
                         };
 
                         violations.push(self.create_violation_with_fix(
@@ -135,30 +136,6 @@ impl MD039 {
         let text: String = link_text.iter().collect();
         text.trim().to_string()
     }
-
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-                continue;
-            }
-        }
-
-        in_code_block
-    }
 }
 
 impl Rule for MD039 {
@@ -189,7 +166,7 @@ impl Rule for MD039 {
     ) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
         let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
+        let in_code_block = document.index().code_block_lines();
 
         for (line_number, line) in lines.iter().enumerate() {
             let line_number = line_number + 1;
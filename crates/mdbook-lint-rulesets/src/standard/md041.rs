@@ -5,7 +5,7 @@
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
-    Document,
+    Document, DocumentKind,
     violation::{Severity, Violation},
 };
 
@@ -75,7 +75,16 @@ impl Rule for MD041 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.1.0")
+        // Fragments are included mid-chapter, where a top-level heading
+        // would be out of place
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.1.0")
+            .applies_to(&[
+                DocumentKind::Chapter,
+                DocumentKind::Summary,
+                DocumentKind::Readme,
+                DocumentKind::Adr,
+            ])
     }
 
     fn check_with_ast<'a>(
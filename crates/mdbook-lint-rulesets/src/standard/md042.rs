@@ -53,16 +53,14 @@ impl MD042 {
     /// Walk AST and find all link violations
     fn check_node<'a>(&self, node: &'a AstNode<'a>, violations: &mut Vec<Violation>) {
         match &node.data.borrow().value {
-            NodeValue::Link(_) => {
-                if self.is_empty_link(node) {
-                    let (line, column) = self.get_position(node);
-                    violations.push(self.create_violation(
-                        "Found empty link".to_string(),
-                        line,
-                        column,
-                        Severity::Warning,
-                    ));
-                }
+            NodeValue::Link(_) if self.is_empty_link(node) => {
+                let (line, column) = self.get_position(node);
+                violations.push(self.create_violation(
+                    "Found empty link".to_string(),
+                    line,
+                    column,
+                    Severity::Warning,
+                ));
             }
             NodeValue::Image(_)
                 // Also check images for empty alt text
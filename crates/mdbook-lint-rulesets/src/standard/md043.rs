@@ -1,19 +1,31 @@
 //! MD043: Required heading structure
 //!
 //! This rule checks that headings follow a required structure/hierarchy pattern.
+//!
+//! A required heading is either plain text, which matches a heading of any
+//! level, or markdownlint-style text with a level prefix (`## Examples`).
+//! Matching ignores case. The wildcards `*` (zero or more headings), `+` (one
+//! or more) and `?` (exactly one) match headings that are not listed; without
+//! them, the listed headings must come first and any others may follow.
+//!
+//! `[MD043.paths]` maps file globs to their own required headings. The
+//! longest matching glob wins, and files that match none use `headings`.
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
     violation::{Severity, Violation},
 };
+use std::path::Path;
 
 /// Rule to check required heading structure
 pub struct MD043 {
     /// Required heading patterns
     headings: Vec<String>,
+    /// Required heading patterns for files matching a glob
+    paths: Vec<(String, Vec<String>)>,
 }
 
 impl MD043 {
@@ -21,32 +33,100 @@ impl MD043 {
     pub fn new() -> Self {
         Self {
             headings: Vec::new(), // No required structure by default
+            paths: Vec::new(),
         }
     }
 
     /// Create a new MD043 rule with required heading structure
     #[allow(dead_code)]
     pub fn with_headings(headings: Vec<String>) -> Self {
-        Self { headings }
+        Self {
+            headings,
+            paths: Vec::new(),
+        }
     }
 
     /// Create MD043 from configuration
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::new();
 
-        if let Some(headings_value) = config.get("headings")
-            && let Some(headings_array) = headings_value.as_array()
+        // `required_headings` and `headers` are older names for `headings`
+        if let Some(headings) = ["headings", "required_headings", "headers"]
+            .iter()
+            .find_map(|key| config.get(*key))
+            .and_then(Self::heading_list)
         {
-            rule.headings = headings_array
+            rule.headings = headings;
+        }
+
+        if let Some(paths) = config.get("paths").and_then(|v| v.as_table()) {
+            rule.paths = paths
                 .iter()
-                .filter_map(|v| v.as_str())
-                .map(|s| s.to_string())
+                .filter_map(|(glob, headings)| Some((glob.clone(), Self::heading_list(headings)?)))
                 .collect();
         }
 
         rule
     }
 
+    /// Parse an array of heading patterns
+    fn heading_list(value: &toml::Value) -> Option<Vec<String>> {
+        Some(
+            value
+                .as_array()?
+                .iter()
+                .filter_map(|v| v.as_str())
+                .map(|s| s.to_string())
+                .collect(),
+        )
+    }
+
+    /// Required headings for a file: those of the longest matching glob in
+    /// `paths`, or `headings` when none match
+    fn headings_for(&self, path: &Path) -> &[String] {
+        self.paths
+            .iter()
+            .filter(|(glob, _)| Self::path_matches(path, glob))
+            .max_by_key(|(glob, _)| glob.len())
+            .map_or(&self.headings, |(_, headings)| headings)
+    }
+
+    /// Match a path against a glob the way `ignore-paths` does: a trailing
+    /// `/` matches everything under a directory, a pattern also matches
+    /// deeper in the tree, and `*` does not cross path separators
+    fn path_matches(path: &Path, glob: &str) -> bool {
+        use glob::{MatchOptions, Pattern};
+
+        let options = MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+        let normalized = path
+            .to_string_lossy()
+            .replace('\\', "/")
+            .trim_start_matches("./")
+            .to_string();
+
+        let mut pattern = glob.replace('\\', "/").trim_start_matches("./").to_string();
+        if pattern.ends_with('/') {
+            pattern.push_str("**");
+        }
+        let mut candidates = vec![pattern.clone()];
+        if !pattern.starts_with("**/") && !pattern.starts_with('/') {
+            candidates.push(format!("**/{pattern}"));
+        }
+
+        candidates.iter().any(|candidate| {
+            Pattern::new(candidate).is_ok_and(|p| p.matches_with(&normalized, options))
+        })
+    }
+
+    /// Whether a required heading is a wildcard
+    fn is_wildcard(pattern: &str) -> bool {
+        matches!(pattern.trim(), "*" | "+" | "?")
+    }
+
     /// Get line and column position for a node
     fn get_position<'a>(&self, node: &'a AstNode<'a>) -> (usize, usize) {
         let data = node.data.borrow();
@@ -74,11 +154,84 @@ impl MD043 {
         }
     }
 
-    /// Check if a heading text matches a required pattern
-    fn matches_pattern(&self, heading_text: &str, pattern: &str) -> bool {
-        // For now, implement exact match (case-insensitive)
-        // Could be extended to support regex patterns in the future
-        heading_text.trim().to_lowercase() == pattern.trim().to_lowercase()
+    /// Check if a heading matches a required pattern
+    ///
+    /// A pattern with a `#` prefix must also match the heading level.
+    fn matches_pattern(&self, level: usize, heading_text: &str, pattern: &str) -> bool {
+        let mut pattern = pattern.trim();
+        let hashes = pattern.chars().take_while(|&c| c == '#').count();
+        if hashes > 0 && pattern[hashes..].starts_with(char::is_whitespace) {
+            if hashes != level {
+                return false;
+            }
+            pattern = pattern[hashes..].trim();
+        }
+        heading_text.trim().to_lowercase() == pattern.to_lowercase()
+    }
+
+    /// Check headings against required patterns that contain wildcards
+    ///
+    /// Stops at the first mismatch, since later headings can't be lined up
+    /// with the pattern after that.
+    fn check_with_wildcards(
+        &self,
+        required: &[String],
+        document_headings: &[(usize, String, usize)],
+    ) -> Vec<Violation> {
+        let mut i = 0;
+        let mut match_any = false;
+
+        for (line, text, level) in document_headings {
+            let Some(expected) = required.get(i).map(|s| s.trim()) else {
+                if match_any {
+                    continue;
+                }
+                return vec![self.create_violation(
+                    format!("Unexpected heading '{text}' after the required headings"),
+                    *line,
+                    1,
+                    Severity::Warning,
+                )];
+            };
+            i += 1;
+
+            match expected {
+                "*" => {
+                    // Zero or more: this heading may already be the next one
+                    match required.get(i) {
+                        Some(next) if self.matches_pattern(*level, text, next) => {
+                            i += 1;
+                            match_any = false;
+                        }
+                        _ => match_any = true,
+                    }
+                }
+                "+" => match_any = true,
+                "?" => match_any = false,
+                _ if self.matches_pattern(*level, text, expected) => match_any = false,
+                _ if match_any => i -= 1,
+                _ => {
+                    return vec![self.create_violation(
+                        format!("Expected heading '{expected}' but found '{text}'"),
+                        *line,
+                        1,
+                        Severity::Warning,
+                    )];
+                }
+            }
+        }
+
+        // Anything left over must be a trailing `*`
+        match required[i.min(required.len())..] {
+            [] => Vec::new(),
+            [ref only] if only.trim() == "*" => Vec::new(),
+            [ref missing, ..] => vec![self.create_violation(
+                format!("Missing required heading '{}'", missing.trim()),
+                1,
+                1,
+                Severity::Warning,
+            )],
+        }
     }
 
     /// Walk AST and collect headings, then validate structure
@@ -116,26 +269,44 @@ impl AstRule for MD043 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Structure).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Structure)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option("headings", OptionKind::Any, "Required headings, in order")
+            .option(
+                "paths",
+                OptionKind::Any,
+                "Table mapping glob patterns to the headings they require",
+            )
+            .option(
+                "required_headings",
+                OptionKind::Any,
+                "Older name for `headings`",
+            )
+            .option("headers", OptionKind::Any, "Older name for `headings`")
     }
 
-    fn check_ast<'a>(&self, _document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
+        let required = self.headings_for(&document.path);
 
         // If no required structure is configured, skip checking
-        if self.headings.is_empty() {
+        if required.is_empty() {
             return Ok(violations);
         }
 
         let mut document_headings = Vec::new();
         self.check_node(ast, &mut document_headings);
 
+        if required.iter().any(|h| Self::is_wildcard(h)) {
+            return Ok(self.check_with_wildcards(required, &document_headings));
+        }
+
         // Check if document has the required number of headings
-        if document_headings.len() < self.headings.len() {
+        if document_headings.len() < required.len() {
             violations.push(self.create_violation(
                 format!(
                     "Document should have at least {} headings but found {}",
-                    self.headings.len(),
+                    required.len(),
                     document_headings.len()
                 ),
                 1,
@@ -146,10 +317,10 @@ impl AstRule for MD043 {
         }
 
         // Check each required heading
-        for (i, required_heading) in self.headings.iter().enumerate() {
+        for (i, required_heading) in required.iter().enumerate() {
             if i < document_headings.len() {
-                let (line, actual_text, _level) = &document_headings[i];
-                if !self.matches_pattern(actual_text, required_heading) {
+                let (line, actual_text, level) = &document_headings[i];
+                if !self.matches_pattern(*level, actual_text, required_heading) {
                     violations.push(self.create_violation(
                         format!("Expected heading '{required_heading}' but found '{actual_text}'"),
                         *line,
@@ -433,4 +604,110 @@ mod tests {
         let violations = rule.check(&document).unwrap();
         assert_eq!(violations.len(), 0); // Should handle whitespace properly
     }
+
+    fn headings(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_md043_heading_level_prefix() {
+        let content = "# Name\n\n### Synopsis\n";
+        let document = create_test_document(content);
+
+        let rule = MD043::with_headings(headings(&["# Name", "## Synopsis"]));
+        let violations = rule.check(&document).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 3);
+
+        let rule = MD043::with_headings(headings(&["# Name", "### synopsis"]));
+        assert!(rule.check(&document).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_md043_wildcards() {
+        let rule = MD043::with_headings(headings(&["# Name", "*", "## Examples", "+"]));
+
+        let content = "# Name\n\n## Notes\n\n## More\n\n## Examples\n\n## See Also\n";
+        assert!(
+            rule.check(&create_test_document(content))
+                .unwrap()
+                .is_empty()
+        );
+
+        // `*` matches nothing, but `+` needs at least one heading
+        let content = "# Name\n\n## Examples\n";
+        let violations = rule.check(&create_test_document(content)).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(
+            violations[0]
+                .message
+                .contains("Missing required heading '+'")
+        );
+
+        let content = "# Title\n\n## Examples\n\n## More\n";
+        let violations = rule.check(&create_test_document(content)).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 1);
+        assert!(violations[0].message.contains("Expected heading '# Name'"));
+    }
+
+    #[test]
+    fn test_md043_wildcard_rejects_extra_headings() {
+        let rule = MD043::with_headings(headings(&["?", "## Usage"]));
+        assert!(
+            rule.check(&create_test_document("# Tool\n\n## Usage\n"))
+                .unwrap()
+                .is_empty()
+        );
+
+        let content = "# Tool\n\n## Usage\n\n## Extra\n";
+        let violations = rule.check(&create_test_document(content)).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].line, 5);
+    }
+
+    #[test]
+    fn test_md043_paths_from_config() {
+        let config: toml::Value = toml::from_str(
+            r###"
+            headings = ["# Title"]
+
+            [paths]
+            "src/reference/" = ["# Name", "## Synopsis", "## Examples"]
+            "src/reference/internal/*.md" = ["# Name"]
+            "###,
+        )
+        .unwrap();
+        let rule = MD043::from_config(&config);
+        let content = "# Name\n\n## Synopsis\n\n## Examples\n";
+
+        let reference = Document::new(
+            content.to_string(),
+            PathBuf::from("book/src/reference/cli.md"),
+        )
+        .unwrap();
+        assert!(rule.check(&reference).unwrap().is_empty());
+
+        // Files outside the glob use the top-level headings
+        let guide = Document::new(content.to_string(), PathBuf::from("src/guide.md")).unwrap();
+        let violations = rule.check(&guide).unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("Expected heading '# Title'"));
+
+        // The longest matching glob wins
+        let internal = Document::new(
+            "# Name\n".to_string(),
+            PathBuf::from("src/reference/internal/x.md"),
+        )
+        .unwrap();
+        assert!(rule.check(&internal).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_md043_legacy_key_names() {
+        let config: toml::Value = toml::from_str(r##"required_headings = ["# Title"]"##).unwrap();
+        let rule = MD043::from_config(&config);
+        let violations = rule.check(&create_test_document("# Other\n")).unwrap();
+        assert_eq!(violations.len(), 1);
+    }
 }
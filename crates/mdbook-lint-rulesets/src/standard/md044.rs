@@ -2,12 +2,18 @@
 //!
 //! This rule checks that proper names (like company names, product names, etc.)
 //! are capitalized correctly throughout the document.
+//!
+//! Names come from the `names` option, a list of correctly capitalized names
+//! as in markdownlint, or a table mapping lowercase names to their correct
+//! form. `code_blocks` (default `false`) also checks code blocks and code
+//! spans, and `html_elements` (default `true`) checks HTML tags and blocks.
+//! Fixes only rewrite prose, never code.
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 use std::collections::HashMap;
 
@@ -15,6 +21,10 @@ use std::collections::HashMap;
 pub struct MD044 {
     /// Map of lowercase names to their correct capitalization
     proper_names: HashMap<String, String>,
+    /// Also check code blocks and code spans
+    code_blocks: bool,
+    /// Also check HTML tags and HTML blocks
+    html_elements: bool,
 }
 
 impl MD044 {
@@ -72,30 +82,57 @@ impl MD044 {
         proper_names.insert("uri".to_string(), "URI".to_string());
         proper_names.insert("uuid".to_string(), "UUID".to_string());
 
-        Self { proper_names }
+        Self::with_names(proper_names)
     }
 
     /// Create a new MD044 rule with custom proper names
     #[allow(dead_code)]
     pub fn with_names(proper_names: HashMap<String, String>) -> Self {
-        Self { proper_names }
+        Self {
+            proper_names,
+            code_blocks: false,
+            html_elements: true,
+        }
     }
 
     /// Create MD044 from configuration
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::new();
 
-        if let Some(names_value) = config.get("names")
-            && let Some(names_table) = names_value.as_table()
-        {
-            // Clear default names and use only configured ones
-            rule.proper_names.clear();
-            for (key, value) in names_table {
-                if let Some(correct_name) = value.as_str() {
-                    rule.proper_names
-                        .insert(key.to_lowercase(), correct_name.to_string());
-                }
+        // Configured names replace the defaults
+        match config.get("names") {
+            Some(toml::Value::Array(names)) => {
+                rule.proper_names = names
+                    .iter()
+                    .filter_map(|name| name.as_str())
+                    .filter(|name| !name.is_empty())
+                    .map(|name| (name.to_lowercase(), name.to_string()))
+                    .collect();
+            }
+            Some(toml::Value::Table(names)) => {
+                rule.proper_names = names
+                    .iter()
+                    .filter_map(|(key, value)| {
+                        Some((key.to_lowercase(), value.as_str()?.to_string()))
+                    })
+                    .collect();
             }
+            _ => {}
+        }
+
+        if let Some(code_blocks) = config
+            .get("code_blocks")
+            .or_else(|| config.get("code-blocks"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.code_blocks = code_blocks;
+        }
+        if let Some(html_elements) = config
+            .get("html_elements")
+            .or_else(|| config.get("html-elements"))
+            .and_then(|v| v.as_bool())
+        {
+            rule.html_elements = html_elements;
         }
 
         rule
@@ -108,7 +145,10 @@ impl MD044 {
     }
 
     /// Check a line for proper name violations
-    fn check_line_names(&self, line: &str, line_number: usize) -> Vec<Violation> {
+    ///
+    /// `in_code` marks a line inside a code block: its matches are reported
+    /// but not fixed.
+    fn check_line_names(&self, line: &str, line_number: usize, in_code: bool) -> Vec<Violation> {
         let mut violations = Vec::new();
 
         // Skip empty lines
@@ -164,12 +204,22 @@ impl MD044 {
                         let is_file_extension =
                             char_pos > 0 && line_chars.get(char_pos - 1) == Some(&'.');
 
-                        // Skip if this appears to be in a code span or URL context
+                        // Skip if this appears to be in a URL context, or in a
+                        // code span or HTML tag that is not being checked
+                        let in_code_span = !in_code && self.is_in_code_span(line, safe_byte_pos);
                         if !is_file_extension
-                            && !self.is_in_code_span(line, safe_byte_pos)
+                            && (self.code_blocks || !in_code_span)
+                            && (self.html_elements || !self.is_in_html_tag(line, safe_byte_pos))
                             && !self.is_in_url_context(line, safe_byte_pos)
                         {
-                            matches.push((safe_byte_pos, actual_text, correct.clone()));
+                            let fixable = !in_code && !in_code_span;
+                            matches.push((
+                                safe_byte_pos,
+                                char_pos,
+                                actual_text,
+                                correct.clone(),
+                                fixable,
+                            ));
                         }
                     }
                 }
@@ -180,21 +230,89 @@ impl MD044 {
         }
 
         // Sort matches by position to maintain text order
-        matches.sort_by_key(|(pos, _, _)| *pos);
+        matches.sort_by_key(|(pos, ..)| *pos);
 
         // Create violations in order
-        for (pos, actual_text, correct) in matches {
-            violations.push(self.create_violation(
-                format!("Proper name '{actual_text}' should be capitalized as '{correct}'"),
+        for (pos, char_pos, actual_text, correct, fixable) in matches {
+            let message =
+                format!("Proper name '{actual_text}' should be capitalized as '{correct}'");
+            let column = pos + 1; // Convert to 1-based column
+            if !fixable {
+                violations.push(self.create_violation(
+                    message,
+                    line_number,
+                    column,
+                    Severity::Warning,
+                ));
+                continue;
+            }
+
+            let fix = Fix {
+                description: format!("Replace '{actual_text}' with '{correct}'"),
+                replacement: Some(correct.clone()),
+                start: Position {
+                    line: line_number,
+                    column: char_pos + 1,
+                },
+                end: Position {
+                    line: line_number,
+                    column: char_pos + actual_text.chars().count() + 1,
+                },
+                safety: FixSafety::Safe,
+            };
+            violations.push(self.create_violation_with_fix(
+                message,
                 line_number,
-                pos + 1, // Convert to 1-based column
+                column,
                 Severity::Warning,
+                fix,
             ));
         }
 
         violations
     }
 
+    /// Check if a position is inside an HTML tag such as `<img alt="...">`
+    fn is_in_html_tag(&self, line: &str, pos: usize) -> bool {
+        let mut search = 0;
+        while let Some(open) = line[search..].find('<').map(|i| search + i) {
+            let starts_tag = Self::starts_with_html_tag(&line[open..]);
+            let Some(close) = line[open..].find('>').map(|i| open + i) else {
+                return false;
+            };
+            if starts_tag && pos > open && pos < close {
+                return true;
+            }
+            search = open + 1;
+        }
+        false
+    }
+
+    /// Whether text starts with an HTML tag or comment, rather than an
+    /// autolink like `<https://...>`
+    fn starts_with_html_tag(text: &str) -> bool {
+        let Some(rest) = text.strip_prefix('<') else {
+            return false;
+        };
+        if rest.starts_with('!') {
+            return true;
+        }
+        let name = rest.strip_prefix('/').unwrap_or(rest);
+        let name_len = name
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(name.len());
+        name.starts_with(|c: char| c.is_ascii_alphabetic())
+            && name[name_len..]
+                .chars()
+                .next()
+                .is_none_or(|c| c.is_whitespace() || c == '>' || c == '/')
+    }
+
+    /// Whether a line starts an HTML block
+    fn starts_html_block(line: &str) -> bool {
+        Self::starts_with_html_tag(line.trim_start())
+    }
+
     /// Check if a position is inside a code span
     fn is_in_code_span(&self, line: &str, pos: usize) -> bool {
         let chars: Vec<char> = line.chars().collect();
@@ -384,30 +502,6 @@ impl MD044 {
             None
         }
     }
-
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-                continue;
-            }
-        }
-
-        in_code_block
-    }
 }
 
 impl Default for MD044 {
@@ -430,7 +524,30 @@ impl Rule for MD044 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Content).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Content)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "names",
+                OptionKind::Any,
+                "Proper names, as a list or a table, replacing the defaults",
+            )
+            .option(
+                "code_blocks",
+                OptionKind::Boolean,
+                "Check inside code blocks",
+            )
+            .defaults_to("false")
+            .option(
+                "html_elements",
+                OptionKind::Boolean,
+                "Check inside HTML elements",
+            )
+            .defaults_to("true")
+            .either_case()
+    }
+
+    fn can_fix(&self) -> bool {
+        true
     }
 
     fn check_with_ast<'a>(
@@ -440,17 +557,34 @@ impl Rule for MD044 {
     ) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
         let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
+        let in_code_block = document.index().code_block_lines();
+
+        let mut in_html_block = false;
 
         for (line_number, line) in lines.iter().enumerate() {
             let line_number = line_number + 1;
 
-            // Skip lines inside code blocks
+            // Code blocks are only checked with `code_blocks`, and never the
+            // fence lines, whose info strings name languages
             if in_code_block[line_number - 1] {
+                let trimmed = line.trim_start();
+                if self.code_blocks && !trimmed.starts_with("```") && !trimmed.starts_with("~~~") {
+                    violations.extend(self.check_line_names(line, line_number, true));
+                }
                 continue;
             }
 
-            violations.extend(self.check_line_names(line, line_number));
+            // HTML blocks run until the next blank line
+            if line.trim().is_empty() {
+                in_html_block = false;
+            } else if Self::starts_html_block(line) {
+                in_html_block = true;
+            }
+            if in_html_block && !self.html_elements {
+                continue;
+            }
+
+            violations.extend(self.check_line_names(line, line_number, false));
         }
 
         Ok(violations)
@@ -1013,4 +1147,59 @@ These should all be flagged since they're not in URLs.
         // Should have no violations - html is inside a URL
         assert_eq!(violations.len(), 0, "html in URL should not be flagged");
     }
+
+    fn rule_from_toml(config: &str) -> MD044 {
+        MD044::from_config(&toml::from_str::<toml::Value>(config).unwrap())
+    }
+
+    #[test]
+    fn test_md044_names_list_from_config() {
+        let rule = rule_from_toml(r#"names = ["mdBook", "GitHub"]"#);
+        let document = create_test_document("Build the mdbook with javascript on Github.\n");
+        let violations = rule.check(&document).unwrap();
+
+        // Configured names replace the defaults, so javascript is not flagged
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("'mdBook'"));
+        assert!(violations[1].message.contains("'GitHub'"));
+    }
+
+    #[test]
+    fn test_md044_fix_replaces_name_in_prose() {
+        let rule = MD044::new();
+        let document = create_test_document("Héllo from github today.\n");
+        let violations = rule.check(&document).unwrap();
+        assert_eq!(violations.len(), 1);
+
+        let fix = violations[0].fix.as_ref().unwrap();
+        assert_eq!(fix.replacement.as_deref(), Some("GitHub"));
+        assert_eq!((fix.start.line, fix.start.column), (1, 12));
+        assert_eq!(fix.end.column, 18);
+    }
+
+    #[test]
+    fn test_md044_code_blocks_option_reports_without_fix() {
+        let content = "Use `github` here.\n\n```javascript\nlet site = \"github\";\n```\n";
+        let document = create_test_document(content);
+
+        assert!(MD044::new().check(&document).unwrap().is_empty());
+
+        let rule = rule_from_toml("code_blocks = true");
+        let violations = rule.check(&document).unwrap();
+        let lines: Vec<usize> = violations.iter().map(|v| v.line).collect();
+        assert_eq!(lines, vec![1, 4]);
+        assert!(violations.iter().all(|v| v.fix.is_none()));
+    }
+
+    #[test]
+    fn test_md044_html_elements_option() {
+        let content = "<div class=\"github\">\nPowered by github\n</div>\n\nSee <a title=\"github\">this</a> and <https://github.com>.\n";
+        let document = create_test_document(content);
+
+        let violations = MD044::new().check(&document).unwrap();
+        assert_eq!(violations.len(), 3);
+
+        let rule = rule_from_toml("html-elements = false");
+        assert!(rule.check(&document).unwrap().is_empty());
+    }
 }
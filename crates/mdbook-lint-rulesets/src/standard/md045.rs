@@ -7,7 +7,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that images have alternate text
@@ -98,6 +98,7 @@ impl MD045 {
                     line,
                     column: line_content.len() + 1,
                 },
+                safety: FixSafety::Suggestion,
             };
 
             violations.push(self.create_violation_with_fix(
@@ -4,10 +4,10 @@
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check code block style consistency
@@ -155,6 +155,7 @@ impl MD046 {
                         .map(|l| l.len() + 1)
                         .unwrap_or(1),
                 },
+                safety: FixSafety::Safe,
             })
         } else {
             None
@@ -248,7 +249,14 @@ impl AstRule for MD046 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "fenced", "indented"]),
+                "Code block style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
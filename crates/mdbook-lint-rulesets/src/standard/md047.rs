@@ -67,8 +67,8 @@
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
-    Document,
-    violation::{Fix, Position, Severity, Violation},
+    Document, DocumentKind,
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that files end with a single newline
@@ -115,7 +115,16 @@ impl Rule for MD047 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        // A fragment's last line lands mid-chapter, where the newline after
+        // it is up to the including file
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .applies_to(&[
+                DocumentKind::Chapter,
+                DocumentKind::Summary,
+                DocumentKind::Readme,
+                DocumentKind::Adr,
+            ])
     }
 
     fn check_with_ast<'a>(
@@ -137,6 +146,7 @@ impl Rule for MD047 {
                     replacement: Some("\n".to_string()),
                     start: Position { line: 1, column: 1 },
                     end: Position { line: 1, column: 1 },
+                    safety: FixSafety::Safe,
                 }
             } else if !document.content.ends_with('\n') {
                 // No trailing newline: add one
@@ -152,6 +162,7 @@ impl Rule for MD047 {
                         line: line_number,
                         column: last_line_len,
                     },
+                    safety: FixSafety::Safe,
                 }
             } else {
                 // Multiple trailing newlines: remove extras
@@ -164,7 +175,7 @@ impl Rule for MD047 {
                 let start_line = line_count - trailing_newlines + 2;
                 Fix {
                     description: "Remove extra trailing newlines".to_string(),
-                    replacement: Some("\n".to_string()),
+                    replacement: Some(String::new()),
                     start: Position {
                         line: start_line,
                         column: 1,
@@ -173,6 +184,7 @@ impl Rule for MD047 {
                         line: line_count + 1,
                         column: 1,
                     },
+                    safety: FixSafety::Safe,
                 }
             };
 
@@ -196,6 +208,7 @@ impl Rule for MD047 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::FixEngine;
     use mdbook_lint_core::rule::Rule;
     use std::path::PathBuf;
 
@@ -417,8 +430,8 @@ mod tests {
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Remove extra trailing newlines");
-        // The fix replaces the extra newlines with a single newline
-        assert_eq!(fix.replacement, Some("\n".to_string()));
+        // The fix removes the newlines after the first one
+        assert_eq!(fix.replacement, Some(String::new()));
         assert_eq!(fix.start.line, 4);
         assert_eq!(fix.start.column, 1);
     }
@@ -435,8 +448,8 @@ mod tests {
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Remove extra trailing newlines");
-        // The fix replaces extra newlines with a single newline
-        assert_eq!(fix.replacement, Some("\n".to_string()));
+        // The fix removes the newlines after the first one
+        assert_eq!(fix.replacement, Some(String::new()));
         // Should be at the position after the first trailing newline
         assert_eq!(fix.start.line, 2);
         assert_eq!(fix.start.column, 1);
@@ -522,10 +535,14 @@ mod tests {
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Remove extra trailing newlines");
-        // The fix replaces extra newlines with a single newline
-        assert_eq!(fix.replacement, Some("\n".to_string()));
+        // The fix removes the newlines after the first one
+        assert_eq!(fix.replacement, Some(String::new()));
         assert_eq!(fix.start.line, 2);
         assert_eq!(fix.start.column, 1);
+        assert_eq!(
+            FixEngine::new().apply(content, &violations).content,
+            "Content\n"
+        );
     }
 
     #[test]
@@ -2,13 +2,16 @@
 //!
 //! This rule checks that emphasis markers (italics) use a consistent style throughout the document.
 
+use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
 };
 
+use super::emphasis::emphasis_spans;
+
 /// Rule to check emphasis style consistency
 pub struct MD049 {
     /// Preferred emphasis style
@@ -54,245 +57,6 @@ impl MD049 {
 
         rule
     }
-
-    /// Find emphasis markers in a line and check for style violations
-    fn check_line_emphasis(
-        &self,
-        line: &str,
-        line_number: usize,
-        expected_style: Option<EmphasisStyle>,
-    ) -> (Vec<Violation>, Option<EmphasisStyle>, Option<String>) {
-        let mut violations = Vec::new();
-        let mut detected_style = expected_style;
-        let mut fixed_line = None;
-        let mut line_chars = line.chars().collect::<Vec<char>>();
-
-        // Get inline code span ranges to exclude from emphasis checking
-        let code_span_ranges = self.get_inline_code_spans(line);
-
-        // Find emphasis markers - look for single * or _ that aren't part of strong emphasis
-        let chars: Vec<char> = line.chars().collect();
-        let mut i = 0;
-        let mut replacements = Vec::new(); // Track replacements for fixing
-
-        while i < chars.len() {
-            // Skip if we're inside a code span
-            if self.is_inside_code_span(i, &code_span_ranges) {
-                i += 1;
-                continue;
-            }
-
-            if chars[i] == '*' || chars[i] == '_' {
-                let marker = chars[i];
-
-                // Skip if this is part of strong emphasis (** or __)
-                if i + 1 < chars.len() && chars[i + 1] == marker {
-                    i += 2;
-                    continue;
-                }
-
-                // Skip if preceded by strong emphasis marker
-                if i > 0 && chars[i - 1] == marker {
-                    i += 1;
-                    continue;
-                }
-
-                // Look for closing marker
-                if let Some(end_pos) =
-                    self.find_closing_emphasis_marker(&chars, i + 1, marker, &code_span_ranges)
-                {
-                    let current_style = if marker == '*' {
-                        EmphasisStyle::Asterisk
-                    } else {
-                        EmphasisStyle::Underscore
-                    };
-
-                    // Establish or check style consistency
-                    if let Some(ref expected) = detected_style {
-                        if *expected != current_style {
-                            let expected_marker = if *expected == EmphasisStyle::Asterisk {
-                                '*'
-                            } else {
-                                '_'
-                            };
-
-                            // Track replacements for fixing
-                            replacements.push((i, expected_marker));
-                            replacements.push((end_pos, expected_marker));
-
-                            // Create fix
-                            let mut fixed_chars = line_chars.clone();
-                            fixed_chars[i] = expected_marker;
-                            fixed_chars[end_pos] = expected_marker;
-                            let fixed_str: String = fixed_chars.iter().collect();
-
-                            let fix = Fix {
-                                description: format!(
-                                    "Change emphasis marker from '{}' to '{}'",
-                                    marker, expected_marker
-                                ),
-                                replacement: Some(format!("{}\n", fixed_str)),
-                                start: Position {
-                                    line: line_number,
-                                    column: 1,
-                                },
-                                end: Position {
-                                    line: line_number,
-                                    column: line.len() + 1,
-                                },
-                            };
-
-                            violations.push(self.create_violation_with_fix(
-                                format!(
-                                    "Emphasis style inconsistent - expected '{expected_marker}' but found '{marker}'"
-                                ),
-                                line_number,
-                                i + 1, // Convert to 1-based column
-                                Severity::Warning,
-                                fix,
-                            ));
-                        }
-                    } else {
-                        // First emphasis found - establish the style
-                        detected_style = Some(current_style);
-                    }
-
-                    i = end_pos + 1;
-                } else {
-                    i += 1;
-                }
-            } else {
-                i += 1;
-            }
-        }
-
-        // Apply all replacements to create fixed line
-        if !replacements.is_empty() {
-            for (pos, new_char) in replacements {
-                line_chars[pos] = new_char;
-            }
-            fixed_line = Some(line_chars.iter().collect());
-        }
-
-        (violations, detected_style, fixed_line)
-    }
-
-    /// Find the closing emphasis marker
-    fn find_closing_emphasis_marker(
-        &self,
-        chars: &[char],
-        start: usize,
-        marker: char,
-        code_span_ranges: &[(usize, usize)],
-    ) -> Option<usize> {
-        let mut i = start;
-
-        while i < chars.len() {
-            // Skip if we're inside a code span
-            if self.is_inside_code_span(i, code_span_ranges) {
-                i += 1;
-                continue;
-            }
-
-            if chars[i] == marker {
-                // Make sure this isn't part of strong emphasis
-                if i + 1 < chars.len() && chars[i + 1] == marker {
-                    i += 2;
-                    continue;
-                }
-                if i > 0 && chars[i - 1] == marker {
-                    i += 1;
-                    continue;
-                }
-                return Some(i);
-            }
-            i += 1;
-        }
-
-        None
-    }
-
-    /// Get inline code span ranges (backtick spans) in a line
-    fn get_inline_code_spans(&self, line: &str) -> Vec<(usize, usize)> {
-        let mut code_spans = Vec::new();
-        let chars: Vec<char> = line.chars().collect();
-        let mut i = 0;
-
-        while i < chars.len() {
-            if chars[i] == '`' {
-                // Count consecutive backticks
-                let mut backtick_count = 0;
-                let start = i;
-                while i < chars.len() && chars[i] == '`' {
-                    backtick_count += 1;
-                    i += 1;
-                }
-
-                // Look for matching closing backticks
-                let mut j = i;
-                while j < chars.len() {
-                    if chars[j] == '`' {
-                        // Count consecutive closing backticks
-                        let mut closing_count = 0;
-                        let _closing_start = j;
-                        while j < chars.len() && chars[j] == '`' {
-                            closing_count += 1;
-                            j += 1;
-                        }
-
-                        // If we found matching backticks, record the span
-                        if closing_count == backtick_count {
-                            code_spans.push((start, j - 1));
-                            i = j;
-                            break;
-                        }
-                    } else {
-                        j += 1;
-                    }
-                }
-
-                // If no closing backticks found, move past the opening backticks
-                if j >= chars.len() {
-                    break;
-                }
-            } else {
-                i += 1;
-            }
-        }
-
-        code_spans
-    }
-
-    /// Check if a character position is inside any code span
-    fn is_inside_code_span(&self, pos: usize, code_spans: &[(usize, usize)]) -> bool {
-        code_spans
-            .iter()
-            .any(|&(start, end)| pos >= start && pos <= end)
-    }
-
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-                continue;
-            }
-        }
-
-        in_code_block
-    }
 }
 
 impl Default for MD049 {
@@ -301,7 +65,7 @@ impl Default for MD049 {
     }
 }
 
-impl Rule for MD049 {
+impl AstRule for MD049 {
     fn id(&self) -> &'static str {
         "MD049"
     }
@@ -315,44 +79,57 @@ impl Rule for MD049 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "asterisk", "underscore"]),
+                "Emphasis style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
         true
     }
 
-    fn check_with_ast<'a>(
-        &self,
-        document: &Document,
-        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
-    ) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
-        let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
-
         let mut expected_style = match self.style {
-            EmphasisStyle::Asterisk => Some(EmphasisStyle::Asterisk),
-            EmphasisStyle::Underscore => Some(EmphasisStyle::Underscore),
+            EmphasisStyle::Asterisk => Some('*'),
+            EmphasisStyle::Underscore => Some('_'),
             EmphasisStyle::Consistent => None, // Detect from first usage
         };
 
-        for (line_number, line) in lines.iter().enumerate() {
-            let line_number = line_number + 1;
-
-            // Skip lines inside code blocks
-            if in_code_block[line_number - 1] {
+        for span in emphasis_spans(document, ast, false) {
+            // The first emphasis establishes the style
+            let expected_marker = *expected_style.get_or_insert(span.marker);
+            if span.marker == expected_marker {
                 continue;
             }
 
-            let (line_violations, detected_style, _fixed_line) =
-                self.check_line_emphasis(line, line_number, expected_style);
-            violations.extend(line_violations);
-
-            // Update expected style if we detected one
-            if expected_style.is_none() && detected_style.is_some() {
-                expected_style = detected_style;
-            }
+            let message = format!(
+                "Emphasis style inconsistent - expected '{expected_marker}' but found '{}'",
+                span.marker
+            );
+            let fix = span.fix(
+                expected_marker,
+                1,
+                format!(
+                    "Change emphasis marker from '{}' to '{expected_marker}'",
+                    span.marker
+                ),
+            );
+            violations.push(match fix {
+                Some(fix) => self.create_violation_with_fix(
+                    message,
+                    span.line,
+                    span.column,
+                    Severity::Warning,
+                    fix,
+                ),
+                None => self.create_violation(message, span.line, span.column, Severity::Warning),
+            });
         }
 
         Ok(violations)
@@ -589,10 +366,7 @@ And `checked_*` with _different emphasis style_.
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Change emphasis marker from '_' to '*'");
-        assert_eq!(
-            fix.replacement,
-            Some("This has *emphasis* and more *italic text* here.\n".to_string())
-        );
+        assert_eq!(fix.replacement, Some("*italic text*".to_string()));
     }
 
     #[test]
@@ -609,10 +383,7 @@ And `checked_*` with _different emphasis style_.
 
         let fix = violations[0].fix.as_ref().unwrap();
         assert_eq!(fix.description, "Change emphasis marker from '*' to '_'");
-        assert_eq!(
-            fix.replacement,
-            Some("This has _emphasis_ and more _italic text_ here.\n".to_string())
-        );
+        assert_eq!(fix.replacement, Some("_italic text_".to_string()));
     }
 
     #[test]
@@ -646,4 +417,47 @@ And _another one_.
         let rule = MD049::new();
         assert!(Rule::can_fix(&rule));
     }
+
+    fn fix(rule: &MD049, content: &str) -> String {
+        let document = create_test_document(content);
+        let violations = rule.check(&document).unwrap();
+        mdbook_lint_core::FixEngine::new()
+            .apply(content, &violations)
+            .content
+    }
+
+    #[test]
+    fn test_md049_fix_skips_code_math_and_intraword() {
+        let rule = MD049::with_style(EmphasisStyle::Asterisk);
+        let content = "Use _this_ with snake_case_name, `_code_`, $x_i + y_j$,\n\\\\( a _b_ c \\\\), and a _multi\nline_ span with **_nested_** text.\n\n$$\nx _y_ z\n$$\n";
+        assert_eq!(
+            fix(&rule, content),
+            "Use *this* with snake_case_name, `_code_`, $x_i + y_j$,\n\\\\( a _b_ c \\\\), and a *multi\nline* span with **_nested_** text.\n\n$$\nx _y_ z\n$$\n"
+        );
+    }
+
+    #[test]
+    fn test_md049_no_fix_next_to_target_marker() {
+        // Rewriting either span would merge the markers into strong emphasis
+        for content in ["A *_x_* b.\n", "A _*x*_ b.\n"] {
+            for style in [EmphasisStyle::Asterisk, EmphasisStyle::Underscore] {
+                let rule = MD049::with_style(style);
+                let violations = rule.check(&create_test_document(content)).unwrap();
+                assert_eq!(violations.len(), 1, "{content:?}");
+                assert!(violations[0].fix.is_none(), "{content:?}");
+                assert_eq!(fix(&rule, content), content);
+            }
+        }
+    }
+
+    #[test]
+    fn test_md049_no_underscore_fix_inside_words() {
+        let rule = MD049::with_style(EmphasisStyle::Underscore);
+        let document = create_test_document("Some *text* and un*frigging*believable.\n");
+        let violations = rule.check(&document).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].fix.is_some());
+        assert!(violations[1].fix.is_none());
+    }
 }
@@ -2,13 +2,16 @@
 //!
 //! This rule checks that strong emphasis markers (bold text) are used consistently throughout the document.
 
+use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{AstRule, OptionKind, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Severity, Violation},
 };
 
+use super::emphasis::emphasis_spans;
+
 /// Rule to check strong emphasis style consistency
 pub struct MD050 {
     /// Preferred strong emphasis style
@@ -54,148 +57,6 @@ impl MD050 {
 
         rule
     }
-
-    /// Find strong emphasis markers in a line and check for style violations
-    fn check_line_strong(
-        &self,
-        line: &str,
-        line_number: usize,
-        expected_style: Option<StrongStyle>,
-    ) -> (Vec<Violation>, Option<StrongStyle>) {
-        let mut violations = Vec::new();
-        let mut detected_style = expected_style;
-
-        // Find strong emphasis markers - look for double ** or __
-        let chars: Vec<char> = line.chars().collect();
-        let mut i = 0;
-
-        while i < chars.len() {
-            if (chars[i] == '*' || chars[i] == '_')
-                && i + 1 < chars.len()
-                && chars[i + 1] == chars[i]
-            {
-                let marker = chars[i];
-
-                // Look for closing marker pair
-                if let Some(end_pos) = self.find_closing_strong_marker(&chars, i + 2, marker) {
-                    let current_style = if marker == '*' {
-                        StrongStyle::Asterisk
-                    } else {
-                        StrongStyle::Underscore
-                    };
-
-                    // Establish or check style consistency
-                    if let Some(ref expected) = detected_style {
-                        if *expected != current_style {
-                            let expected_marker = if *expected == StrongStyle::Asterisk {
-                                "**"
-                            } else {
-                                "__"
-                            };
-                            let found_marker = if marker == '*' { "**" } else { "__" };
-                            // Get the text between the markers
-                            let text_start = i + 2;
-                            let text: String = chars[text_start..end_pos].iter().collect();
-
-                            // Create fix by replacing the current strong with expected style
-                            let fixed_strong =
-                                format!("{}{}{}", expected_marker, text, expected_marker);
-                            let original_strong =
-                                format!("{}{}{}", found_marker, text, found_marker);
-
-                            // Find the actual position in the line to replace
-                            let line_content = line;
-                            let mut fixed_line = line_content.to_string();
-                            if let Some(pos) = fixed_line.find(&original_strong) {
-                                fixed_line
-                                    .replace_range(pos..pos + original_strong.len(), &fixed_strong);
-                            }
-
-                            let fix = Fix {
-                                description: format!(
-                                    "Change strong emphasis style from '{}' to '{}'",
-                                    found_marker, expected_marker
-                                ),
-                                replacement: Some(format!("{}\n", fixed_line)),
-                                start: Position {
-                                    line: line_number,
-                                    column: 1,
-                                },
-                                end: Position {
-                                    line: line_number,
-                                    column: line_content.len() + 1,
-                                },
-                            };
-
-                            violations.push(self.create_violation_with_fix(
-                                format!(
-                                    "Strong emphasis style inconsistent - expected '{expected_marker}' but found '{found_marker}'"
-                                ),
-                                line_number,
-                                i + 1, // Convert to 1-based column
-                                Severity::Warning,
-                                fix,
-                            ));
-                        }
-                    } else {
-                        // First strong emphasis found - establish the style
-                        detected_style = Some(current_style);
-                    }
-
-                    i = end_pos + 2;
-                } else {
-                    i += 2;
-                }
-            } else {
-                i += 1;
-            }
-        }
-
-        (violations, detected_style)
-    }
-
-    /// Find the closing strong emphasis marker pair
-    fn find_closing_strong_marker(
-        &self,
-        chars: &[char],
-        start: usize,
-        marker: char,
-    ) -> Option<usize> {
-        let mut i = start;
-
-        while i + 1 < chars.len() {
-            if chars[i] == marker && chars[i + 1] == marker {
-                return Some(i);
-            }
-            i += 1;
-        }
-
-        None
-    }
-
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-                continue;
-            }
-        }
-
-        in_code_block
-    }
 }
 
 impl Default for MD050 {
@@ -204,7 +65,7 @@ impl Default for MD050 {
     }
 }
 
-impl Rule for MD050 {
+impl AstRule for MD050 {
     fn id(&self) -> &'static str {
         "MD050"
     }
@@ -218,44 +79,57 @@ impl Rule for MD050 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "asterisk", "underscore"]),
+                "Strong emphasis style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
         true
     }
 
-    fn check_with_ast<'a>(
-        &self,
-        document: &Document,
-        _ast: Option<&'a comrak::nodes::AstNode<'a>>,
-    ) -> Result<Vec<Violation>> {
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
-        let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
-
         let mut expected_style = match self.style {
-            StrongStyle::Asterisk => Some(StrongStyle::Asterisk),
-            StrongStyle::Underscore => Some(StrongStyle::Underscore),
+            StrongStyle::Asterisk => Some('*'),
+            StrongStyle::Underscore => Some('_'),
             StrongStyle::Consistent => None, // Detect from first usage
         };
 
-        for (line_number, line) in lines.iter().enumerate() {
-            let line_number = line_number + 1;
-
-            // Skip lines inside code blocks
-            if in_code_block[line_number - 1] {
+        for span in emphasis_spans(document, ast, true) {
+            // The first strong emphasis establishes the style
+            let expected = *expected_style.get_or_insert(span.marker);
+            if span.marker == expected {
                 continue;
             }
 
-            let (line_violations, detected_style) =
-                self.check_line_strong(line, line_number, expected_style);
-            violations.extend(line_violations);
-
-            // Update expected style if we detected one
-            if expected_style.is_none() && detected_style.is_some() {
-                expected_style = detected_style;
-            }
+            let expected_marker = expected.to_string().repeat(2);
+            let found_marker = span.marker.to_string().repeat(2);
+            let message = format!(
+                "Strong emphasis style inconsistent - expected '{expected_marker}' but found '{found_marker}'"
+            );
+            let fix = span.fix(
+                expected,
+                2,
+                format!(
+                    "Change strong emphasis style from '{found_marker}' to '{expected_marker}'"
+                ),
+            );
+            violations.push(match fix {
+                Some(fix) => self.create_violation_with_fix(
+                    message,
+                    span.line,
+                    span.column,
+                    Severity::Warning,
+                    fix,
+                ),
+                None => self.create_violation(message, span.line, span.column, Severity::Warning),
+            });
         }
 
         Ok(violations)
@@ -500,10 +374,7 @@ Back to **original** again.
             fix.description,
             "Change strong emphasis style from '__' to '**'"
         );
-        assert_eq!(
-            fix.replacement,
-            Some("Then uses **different** style.\n".to_string())
-        );
+        assert_eq!(fix.replacement, Some("**different**".to_string()));
     }
 
     #[test]
@@ -511,4 +382,44 @@ Back to **original** again.
         let rule = MD050::new();
         assert!(Rule::can_fix(&rule));
     }
+
+    #[test]
+    fn test_md050_fix_to_underscore() {
+        let content = "Some **bold** and **more _mixed_ text**, `**code**`, and a**b**c.\n";
+        let document = create_test_document(content);
+        let rule = MD050::with_style(StrongStyle::Underscore);
+        let violations = rule.check(&document).unwrap();
+
+        // The intra-word span can't use underscores
+        assert_eq!(violations.len(), 3);
+        assert!(violations[2].fix.is_none());
+        let fixed = mdbook_lint_core::FixEngine::new()
+            .apply(content, &violations)
+            .content;
+        assert_eq!(
+            fixed,
+            "Some __bold__ and __more _mixed_ text__, `**code**`, and a**b**c.\n"
+        );
+    }
+
+    #[test]
+    fn test_md050_no_fix_next_to_target_marker() {
+        // Rewriting these spans would run their markers into the neighbours'
+        for content in [
+            "A **__x__** b.\n",
+            "A __**x**__ b.\n",
+            "A *__x__* b.\n",
+            "A _**x**_ b.\n",
+        ] {
+            for style in [StrongStyle::Asterisk, StrongStyle::Underscore] {
+                let rule = MD050::with_style(style);
+                let violations = rule.check(&create_test_document(content)).unwrap();
+                assert!(violations.iter().all(|v| v.fix.is_none()), "{content:?}");
+                let fixed = mdbook_lint_core::FixEngine::new()
+                    .apply(content, &violations)
+                    .content;
+                assert_eq!(fixed, content);
+            }
+        }
+    }
 }
@@ -18,21 +18,48 @@
 //!
 //! \[Link\](#invalid-fragment)
 //! ```
+//!
+//! Heading fragments are generated the way mdBook generates them by default,
+//! lowercased. Set `anchor_style = "github"` for files that are also read on
+//! GitHub, whose slugs differ for non-ASCII letters and duplicate headings.
 
 use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
+use mdbook_lint_core::outline::mdbook_slug;
 use mdbook_lint_core::{
     Document, Violation,
-    rule::{Rule, RuleCategory, RuleMetadata},
+    rule::{OptionKind, Rule, RuleCategory, RuleMetadata},
     violation::Severity,
 };
 
 use std::collections::{HashMap, HashSet};
 
+/// Algorithm used to turn heading text into a fragment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnchorStyle {
+    /// mdBook's HTML renderer
+    #[default]
+    MdBook,
+    /// GitHub's Markdown renderer (github-slugger)
+    GitHub,
+}
+
+impl AnchorStyle {
+    /// Parse a configured style name
+    pub fn parse(style: &str) -> Option<Self> {
+        match style.to_lowercase().as_str() {
+            "mdbook" => Some(Self::MdBook),
+            "github" => Some(Self::GitHub),
+            _ => None,
+        }
+    }
+}
+
 /// MD051 - Link fragments should be valid
 pub struct MD051 {
     ignore_case: bool,
     ignored_pattern: Option<String>,
+    anchor_style: AnchorStyle,
 }
 
 impl Default for MD051 {
@@ -47,9 +74,17 @@ impl MD051 {
         Self {
             ignore_case: false,
             ignored_pattern: None,
+            anchor_style: AnchorStyle::MdBook,
         }
     }
 
+    /// Set the algorithm used to generate heading fragments
+    #[allow(dead_code)]
+    pub fn anchor_style(mut self, anchor_style: AnchorStyle) -> Self {
+        self.anchor_style = anchor_style;
+        self
+    }
+
     /// Set whether to ignore case when comparing fragments
     #[allow(dead_code)]
     pub fn ignore_case(mut self, ignore_case: bool) -> Self {
@@ -61,14 +96,31 @@ impl MD051 {
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::new();
 
-        if let Some(ignore_case) = config.get("ignore_case").and_then(|v| v.as_bool()) {
+        if let Some(ignore_case) = config
+            .get("ignore_case")
+            .or_else(|| config.get("ignore-case"))
+            .and_then(|v| v.as_bool())
+        {
             rule.ignore_case = ignore_case;
         }
 
-        if let Some(ignored_pattern) = config.get("ignored_pattern").and_then(|v| v.as_str()) {
+        if let Some(ignored_pattern) = config
+            .get("ignored_pattern")
+            .or_else(|| config.get("ignored-pattern"))
+            .and_then(|v| v.as_str())
+        {
             rule.ignored_pattern = Some(ignored_pattern.to_string());
         }
 
+        if let Some(style) = config
+            .get("anchor_style")
+            .or_else(|| config.get("anchor-style"))
+            .and_then(|v| v.as_str())
+            .and_then(AnchorStyle::parse)
+        {
+            rule.anchor_style = style;
+        }
+
         rule
     }
 
@@ -85,48 +137,76 @@ impl MD051 {
         (pos.start.line, pos.start.column)
     }
 
-    /// Generate mdBook-style heading fragment from text
+    /// Generate the fragment for a heading, before duplicates are numbered
     ///
-    /// mdBook's slug generation algorithm:
-    /// 1. Convert to lowercase
-    /// 2. Replace spaces with hyphens
-    /// 3. Remove non-alphanumeric characters (except hyphens and underscores)
-    /// 4. Remove leading/trailing hyphens
-    /// 5. Does NOT consolidate multiple consecutive hyphens from spaces
+    /// mdBook keeps the case of non-ASCII letters, but links to the
+    /// lowercased fragment (`#übungen` for `# Übungen`) have always been
+    /// accepted, so that is the fragment generated here. The case-preserving
+    /// form is accepted alongside it.
     fn generate_heading_fragment(&self, text: &str) -> String {
-        let mut fragment = String::new();
+        match self.anchor_style {
+            AnchorStyle::MdBook => mdbook_slug(text).to_lowercase(),
+            AnchorStyle::GitHub => Self::github_fragment(text),
+        }
+    }
 
-        for ch in text.chars() {
-            if ch.is_alphanumeric() {
+    /// github-slugger: lowercase everything, turn each space into a hyphen,
+    /// and drop punctuation and symbols other than `-` and `_`
+    fn github_fragment(text: &str) -> String {
+        let mut fragment = String::new();
+        for ch in text.trim().chars() {
+            if ch.is_alphanumeric() || ch == '_' || ch == '-' {
                 fragment.extend(ch.to_lowercase());
-            } else if ch == '-' || ch == '_' {
-                // Preserve hyphens and underscores as-is
-                fragment.push(ch);
-            } else if ch.is_whitespace() {
-                // Replace whitespace (spaces, tabs) with hyphens
+            } else if ch == ' ' {
                 fragment.push('-');
             }
-            // Other characters (punctuation like +, &, etc.) are removed/ignored
         }
-
-        // Remove leading/trailing hyphens only
-        // Do NOT consolidate multiple consecutive hyphens - mdBook preserves them
-        fragment = fragment.trim_matches('-').to_string();
-
         fragment
     }
 
-    /// Extract text content from a heading node
+    /// Number a repeated fragment the way the anchor style does
+    ///
+    /// Both count from the second occurrence (`name`, `name-1`, `name-2`).
+    /// GitHub also skips numbered fragments another heading already produced,
+    /// while mdBook only counts repeats of the same base fragment.
+    fn unique_fragment(&self, base: String, counts: &mut HashMap<String, usize>) -> String {
+        match self.anchor_style {
+            AnchorStyle::MdBook => {
+                let count = counts.entry(base.clone()).or_insert(0);
+                let fragment = if *count == 0 {
+                    base
+                } else {
+                    format!("{base}-{count}")
+                };
+                *count += 1;
+                fragment
+            }
+            AnchorStyle::GitHub => {
+                let mut fragment = base.clone();
+                while counts.contains_key(&fragment) {
+                    let count = counts.entry(base.clone()).or_insert(0);
+                    *count += 1;
+                    fragment = format!("{base}-{count}");
+                }
+                counts.insert(fragment.clone(), 0);
+                fragment
+            }
+        }
+    }
+
+    /// Extract the rendered text of a heading node
+    ///
+    /// Images and inline HTML contribute no text, matching how both renderers
+    /// strip tags before generating the fragment.
     fn extract_heading_text<'a>(node: &'a AstNode<'a>) -> String {
         let mut text = String::new();
         for child in node.children() {
             match &child.data.borrow().value {
                 NodeValue::Text(t) => text.push_str(t),
                 NodeValue::Code(code) => text.push_str(&code.literal),
-                NodeValue::Emph | NodeValue::Strong => {
-                    text.push_str(&Self::extract_heading_text(child));
-                }
-                _ => {}
+                NodeValue::SoftBreak | NodeValue::LineBreak => text.push('\n'),
+                NodeValue::Image(_) | NodeValue::HtmlInline(_) => {}
+                _ => text.push_str(&Self::extract_heading_text(child)),
             }
         }
         text
@@ -156,18 +236,17 @@ impl MD051 {
             NodeValue::Heading(_) => {
                 let heading_text = Self::extract_heading_text(node);
                 let base_fragment = self.generate_heading_fragment(&heading_text);
-
-                // Handle duplicate fragments by appending an incrementing counter,
-                // matching mdBook's zero-based scheme: the first occurrence is bare,
-                // the second is "-1", the third "-2", and so on.
-                let count = heading_counts.entry(base_fragment.clone()).or_insert(0);
-                let fragment = if *count == 0 {
-                    base_fragment.clone()
-                } else {
-                    format!("{base_fragment}-{count}")
-                };
-                *count += 1;
-
+                let fragment = self.unique_fragment(base_fragment.clone(), heading_counts);
+
+                // The fragment mdBook itself writes keeps non-ASCII case
+                if self.anchor_style == AnchorStyle::MdBook {
+                    let exact = mdbook_slug(&heading_text);
+                    if exact != base_fragment
+                        && let Some(suffix) = fragment.strip_prefix(&base_fragment)
+                    {
+                        fragments.insert(format!("{exact}{suffix}"));
+                    }
+                }
                 fragments.insert(fragment);
 
                 // Check for custom anchor syntax {#custom-name}
@@ -476,8 +555,15 @@ impl MD051 {
                             }
 
                             // Check for basic case issues (contains uppercase when should be lowercase)
-                            // Only flag this if case sensitivity is enabled
-                            if !self.ignore_case && fragment != fragment.to_lowercase() {
+                            // Only flag this if case sensitivity is enabled. mdBook keeps the
+                            // case of non-ASCII letters, so only ASCII uppercase is suspicious.
+                            let has_uppercase = match self.anchor_style {
+                                AnchorStyle::MdBook => {
+                                    fragment.chars().any(|c| c.is_ascii_uppercase())
+                                }
+                                AnchorStyle::GitHub => fragment != fragment.to_lowercase(),
+                            };
+                            if !self.ignore_case && has_uppercase {
                                 is_suspicious = true;
                             }
 
@@ -562,6 +648,24 @@ impl Rule for MD051 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Links)
+            .option(
+                "ignore_case",
+                OptionKind::Boolean,
+                "Compare fragments case-insensitively",
+            )
+            .defaults_to("false")
+            .option(
+                "ignored_pattern",
+                OptionKind::String,
+                "Regular expression for fragments to skip",
+            )
+            .option(
+                "anchor_style",
+                OptionKind::Choice(&["mdbook", "github"]),
+                "How heading anchors are generated",
+            )
+            .defaults_to(r#""mdbook""#)
+            .either_case()
     }
 
     fn check_with_ast<'a>(
@@ -762,7 +866,7 @@ This is text with <span id="inline-id">inline HTML</span> and <a name="inline-na
 
 ## --Leading-And-Trailing--
 
-[Another link](#leading-and-trailing)
+[Another link](#--leading-and-trailing--)
 "#;
 
         assert_no_violations(MD051::new(), content);
@@ -1149,6 +1253,14 @@ Second.
         assert!(violations[0].message.contains("details-2"));
     }
 
+    /// Run the rule with the AST, as the engine does
+    fn check_ast(rule: &MD051, content: &str) -> Vec<Violation> {
+        let document = create_document(content);
+        let arena = comrak::Arena::new();
+        let ast = document.parse_ast(&arena);
+        rule.check_with_ast(&document, Some(ast)).unwrap()
+    }
+
     #[test]
     fn test_issue_399_unicode_headings() {
         let rule = MD051::new();
@@ -1162,6 +1274,78 @@ Second.
         let content = "# Übungen\n\n[link](#übungen)\n";
         assert_no_violations(rule, content);
     }
+
+    #[test]
+    fn test_mdbook_case_preserving_fragment_accepted() {
+        // mdBook writes the id with the case of non-ASCII letters intact
+        let content = "# Übungen\n\n[a](#übungen) [b](#Übungen)\n";
+        assert!(check_ast(&MD051::new(), content).is_empty());
+        assert_no_violations(MD051::new(), content);
+
+        let rule = MD051::new().anchor_style(AnchorStyle::GitHub);
+        assert_eq!(rule.generate_heading_fragment("Übungen"), "übungen");
+        assert_eq!(check_ast(&rule, content).len(), 1);
+    }
+
+    #[test]
+    fn test_mdbook_slugs_match_mdbook() {
+        // Cases from mdBook's own `normalize_id` and `unique_id_from_content` tests
+        let rule = MD051::new();
+        assert_eq!(
+            rule.generate_heading_fragment("`--passes`: add more rustdoc passes"),
+            "--passes-add-more-rustdoc-passes"
+        );
+        assert_eq!(
+            rule.generate_heading_fragment("Method-call 🐙 expressions \u{1f47c}"),
+            "method-call--expressions-"
+        );
+        assert_eq!(rule.generate_heading_fragment("_-_12345"), "_-_12345");
+        assert_eq!(
+            rule.generate_heading_fragment("中文標題 CJK title"),
+            "中文標題-cjk-title"
+        );
+
+        let content = "# Über\n\n# Über\n\n# Über\n\n[a](#Über) [b](#Über-1) [c](#Über-2)\n";
+        assert!(check_ast(&MD051::new(), content).is_empty());
+    }
+
+    #[test]
+    fn test_github_anchor_style() {
+        let rule = MD051::new().anchor_style(AnchorStyle::GitHub);
+        assert_eq!(rule.generate_heading_fragment("What's New?"), "whats-new");
+        assert_eq!(rule.generate_heading_fragment("Rocket 🚀"), "rocket-");
+
+        // A heading that produces an existing numbered fragment pushes the
+        // next duplicate further along
+        let content = "# Test\n\n# Test-1\n\n# Test\n\n[a](#test) [b](#test-1) [c](#test-2)\n";
+        let rule = MD051::new().anchor_style(AnchorStyle::GitHub);
+        assert!(check_ast(&rule, content).is_empty());
+
+        // mdBook gives both "Test" and "Test-1" the fragment "test-1"
+        let violations = check_ast(&MD051::new(), content);
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].message.contains("test-2"));
+    }
+
+    #[test]
+    fn test_anchor_style_config() {
+        let config: toml::Value = toml::from_str(r#"anchor_style = "github""#).unwrap();
+        assert_eq!(
+            MD051::from_config(&config).anchor_style,
+            AnchorStyle::GitHub
+        );
+        let config: toml::Value = toml::from_str(r#"anchor-style = "mdBook""#).unwrap();
+        assert_eq!(
+            MD051::from_config(&config).anchor_style,
+            AnchorStyle::MdBook
+        );
+    }
+
+    #[test]
+    fn test_link_text_in_heading() {
+        let content = "# See [the guide](guide.md) first\n\n[link](#see-the-guide-first)\n";
+        assert!(check_ast(&MD051::new(), content).is_empty());
+    }
 }
 
 #[cfg(test)]
@@ -1221,22 +1405,22 @@ mod property_tests {
             );
         }
 
-        /// Property: Generated slugs don't start or end with hyphens
+        /// Property: Each inner space or hyphen becomes exactly one hyphen,
+        /// including at the ends of the slug
         #[test]
-        fn slug_no_leading_trailing_hyphens(heading in heading_text_strategy()) {
+        fn slug_hyphen_count(heading in heading_text_strategy()) {
             let rule = MD051::new();
             let slug = rule.generate_heading_fragment(&heading);
+            let expected = heading
+                .trim()
+                .chars()
+                .filter(|&c| c == ' ' || c == '-')
+                .count();
 
-            if !slug.is_empty() {
-                prop_assert!(
-                    !slug.starts_with('-'),
-                    "Slug '{}' from heading '{}' starts with hyphen", slug, heading
-                );
-                prop_assert!(
-                    !slug.ends_with('-'),
-                    "Slug '{}' from heading '{}' ends with hyphen", slug, heading
-                );
-            }
+            prop_assert!(
+                slug.matches('-').count() == expected,
+                "Slug '{}' from heading '{}' should have {} hyphens", slug, heading, expected
+            );
         }
 
         /// Property: Alphanumeric characters are preserved (just lowercased)
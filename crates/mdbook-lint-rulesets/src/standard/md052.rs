@@ -1,7 +1,8 @@
 //! MD052 - Reference links and images should use a label that is defined
 //!
 //! This rule checks for reference links and images that use undefined labels.
-//! Uses byte-by-byte parsing for accurate context-aware detection.
+//! Uses byte-by-byte parsing, skipping brackets that the document index places
+//! in code, HTML blocks, or math.
 //!
 //! ## Correct
 //!
@@ -25,8 +26,8 @@
 use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
-    Document, Violation,
-    rule::{Rule, RuleCategory, RuleMetadata},
+    Document, DocumentIndex, Violation,
+    rule::{OptionKind, Rule, RuleCategory, RuleMetadata},
     violation::Severity,
 };
 use std::collections::HashSet;
@@ -167,7 +168,7 @@ impl MD052 {
     /// Parse reference definitions from document content
     fn collect_defined_labels(&self, document: &Document) -> HashSet<String> {
         let mut definitions = HashSet::new();
-        let mut parser = RefDefParser::new(document.content.as_bytes());
+        let mut parser = RefDefParser::new(document.content.as_bytes(), document.index());
 
         while let Some(def) = parser.next_definition() {
             definitions.insert(def.label.to_lowercase());
@@ -180,7 +181,7 @@ impl MD052 {
     fn check_reference_labels(&self, document: &Document) -> Vec<Violation> {
         let mut violations = Vec::new();
         let defined_labels = self.collect_defined_labels(document);
-        let mut parser = LinkParser::new(document.content.as_bytes());
+        let mut parser = LinkParser::new(document.content.as_bytes(), document.index());
 
         while let Some(link) = parser.next_link() {
             match link {
@@ -190,8 +191,9 @@ impl MD052 {
                     column,
                 } => {
                     let label_lower = label.to_lowercase();
-                    // Skip IPA phonetic transcriptions (e.g., [ˈkaza], [paʃ])
-                    if contains_ipa_characters(&label) {
+                    // Skip IPA phonetic transcriptions (e.g., [ˈkaza], [paʃ]) and
+                    // YAML flow lists in frontmatter
+                    if contains_ipa_characters(&label) || document.is_frontmatter_line(line) {
                         continue;
                     }
                     if !self.ignored_labels.contains(&label_lower)
@@ -212,7 +214,7 @@ impl MD052 {
                 } => {
                     let label_lower = label.to_lowercase();
                     // Skip IPA phonetic transcriptions
-                    if contains_ipa_characters(&label) {
+                    if contains_ipa_characters(&label) || document.is_frontmatter_line(line) {
                         continue;
                     }
                     if !self.ignored_labels.contains(&label_lower)
@@ -249,6 +251,18 @@ impl Rule for MD052 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Links)
+            .option(
+                "ignored_labels",
+                OptionKind::StringList,
+                "Reference labels to skip",
+            )
+            .defaults_to("[]")
+            .option(
+                "shortcut_syntax",
+                OptionKind::Boolean,
+                "Check shortcut references such as `[label]`",
+            )
+            .defaults_to("false")
     }
 
     fn check_with_ast<'a>(
@@ -271,14 +285,16 @@ struct RefDefinition {
 /// Parser for reference definitions like `[label]: url`
 struct RefDefParser<'a> {
     input: &'a [u8],
+    index: &'a DocumentIndex,
     pos: usize,
     line: usize,
 }
 
 impl<'a> RefDefParser<'a> {
-    fn new(input: &'a [u8]) -> Self {
+    fn new(input: &'a [u8], index: &'a DocumentIndex) -> Self {
         Self {
             input,
+            index,
             pos: 0,
             line: 1,
         }
@@ -298,8 +314,11 @@ impl<'a> RefDefParser<'a> {
                 break;
             }
 
-            // Check if line starts with '['
-            if self.current_byte() == Some(b'[') {
+            // Check if line starts with '[', outside code and HTML blocks
+            if self.current_byte() == Some(b'[')
+                && !self.index.is_code_block_line(self.line)
+                && !self.index.is_html_block_line(self.line)
+            {
                 if let Some(def) = self.try_parse_definition() {
                     return Some(def);
                 } else {
@@ -438,17 +457,15 @@ enum LinkType {
 /// Parser for links in markdown content
 struct LinkParser<'a> {
     input: &'a [u8],
+    index: &'a DocumentIndex,
     pos: usize,
     line: usize,
     line_start: usize,
-    in_code_block: bool,
-    in_inline_math: bool,
-    in_display_math: bool,
     on_heading_line: bool,
 }
 
 impl<'a> LinkParser<'a> {
-    fn new(input: &'a [u8]) -> Self {
+    fn new(input: &'a [u8], index: &'a DocumentIndex) -> Self {
         // Check if first line is a heading
         let first_line_is_heading = {
             let mut pos = 0;
@@ -460,12 +477,10 @@ impl<'a> LinkParser<'a> {
 
         Self {
             input,
+            index,
             pos: 0,
             line: 1,
             line_start: 0,
-            in_code_block: false,
-            in_inline_math: false,
-            in_display_math: false,
             on_heading_line: first_line_is_heading,
         }
     }
@@ -507,20 +522,8 @@ impl<'a> LinkParser<'a> {
     fn next_link(&mut self) -> Option<LinkType> {
         while self.pos < self.input.len() {
             match self.current_byte()? {
-                b'`' => {
-                    if self.is_code_fence() {
-                        self.toggle_code_block();
-                    } else if !self.in_code_block {
-                        self.skip_code_span();
-                    } else {
-                        self.pos += 1;
-                    }
-                }
-                b'$' if !self.in_code_block => {
-                    // Handle math blocks - skip content inside $...$ or $$...$$
-                    self.handle_math_delimiter();
-                }
-                b'[' if !self.in_code_block && !self.in_inline_math && !self.in_display_math => {
+                b'[' | b'!' if self.is_verbatim() => self.pos += 1,
+                b'[' => {
                     // Check for wiki-link syntax [[text]] - skip if next char is also [
                     if self.peek_byte(1) == Some(b'[') {
                         self.skip_wiki_link();
@@ -530,7 +533,7 @@ impl<'a> LinkParser<'a> {
                         self.pos += 1;
                     }
                 }
-                b'!' if !self.in_code_block && !self.in_inline_math && !self.in_display_math => {
+                b'!' => {
                     // Check for embedded wiki-link syntax ![[text]]
                     if self.peek_byte(1) == Some(b'[') && self.peek_byte(2) == Some(b'[') {
                         self.pos += 1; // Skip '!'
@@ -549,8 +552,6 @@ impl<'a> LinkParser<'a> {
                     self.line += 1;
                     self.pos += 1;
                     self.line_start = self.pos;
-                    // Inline math doesn't span lines
-                    self.in_inline_math = false;
                     // Update heading line status for the new line
                     self.on_heading_line = self.check_heading_line();
                 }
@@ -560,44 +561,11 @@ impl<'a> LinkParser<'a> {
         None
     }
 
-    /// Handle $ delimiter for math blocks
-    fn handle_math_delimiter(&mut self) {
-        // Check if this $ is escaped
-        if self.is_escaped() {
-            self.pos += 1;
-            return;
-        }
-
-        // Check for display math ($$)
-        if self.peek_byte(1) == Some(b'$') {
-            self.in_display_math = !self.in_display_math;
-            self.pos += 2;
-        } else {
-            // Inline math ($)
-            self.in_inline_math = !self.in_inline_math;
-            self.pos += 1;
-        }
-    }
-
-    /// Check if the current position is escaped by a backslash
-    fn is_escaped(&self) -> bool {
-        if self.pos == 0 {
-            return false;
-        }
-
-        // Count preceding backslashes
-        let mut backslash_count = 0;
-        let mut check_pos = self.pos - 1;
-        while self.input.get(check_pos) == Some(&b'\\') {
-            backslash_count += 1;
-            if check_pos == 0 {
-                break;
-            }
-            check_pos -= 1;
-        }
-
-        // Odd number of backslashes means the $ is escaped
-        backslash_count % 2 == 1
+    /// Whether the current position is in a code block, HTML block, code
+    /// span, or math, where brackets are never links
+    fn is_verbatim(&self) -> bool {
+        self.index
+            .is_verbatim(self.line, self.pos - self.line_start)
     }
 
     /// Check if the current position is preceded by a specific character
@@ -884,78 +852,6 @@ impl<'a> LinkParser<'a> {
         }
     }
 
-    fn skip_code_span(&mut self) {
-        let start = self.pos;
-        self.pos += 1;
-
-        // Count opening backticks
-        let mut backticks = 1;
-        while self.pos < self.input.len() && self.input[self.pos] == b'`' {
-            backticks += 1;
-            self.pos += 1;
-        }
-
-        // Find matching closing backticks
-        let mut found = 0;
-        while self.pos < self.input.len() {
-            let ch = self.input[self.pos];
-            if ch == b'`' {
-                found += 1;
-                self.pos += 1;
-                if found == backticks {
-                    return;
-                }
-            } else {
-                found = 0;
-                self.pos += 1;
-                if ch == b'\n' {
-                    self.line += 1;
-                    self.line_start = self.pos;
-                }
-            }
-        }
-
-        // If we didn't find closing backticks, reset
-        self.pos = start + 1;
-    }
-
-    fn is_code_fence(&mut self) -> bool {
-        let _start = self.pos;
-
-        // Check if we're at start of line (possibly with whitespace)
-        let mut line_pos = self.line_start;
-        while line_pos < self.pos {
-            match self.input.get(line_pos) {
-                Some(b' ') | Some(b'\t') => line_pos += 1,
-                _ => return false, // Non-whitespace before backticks
-            }
-        }
-
-        // Count consecutive backticks
-        let mut count = 0;
-        let mut pos = self.pos;
-        while pos < self.input.len() && self.input[pos] == b'`' {
-            count += 1;
-            pos += 1;
-        }
-
-        count >= 3
-    }
-
-    fn toggle_code_block(&mut self) {
-        self.in_code_block = !self.in_code_block;
-        // Skip the entire code fence line
-        while self.pos < self.input.len() {
-            let ch = self.input[self.pos];
-            self.pos += 1;
-            if ch == b'\n' {
-                self.line += 1;
-                self.line_start = self.pos;
-                break;
-            }
-        }
-    }
-
     fn is_likely_reference(&self) -> bool {
         // Simple heuristic: if followed by whitespace, punctuation, or end of line
         if self.pos >= self.input.len() {
@@ -1121,6 +1017,37 @@ mod tests {
         assert_no_violations(MD052::new(), content);
     }
 
+    #[test]
+    fn test_indented_tilde_and_html_blocks_ignored() {
+        let content = r#"Text
+
+    [indented][undefined]
+
+~~~
+[tilde][undefined]
+~~~
+
+<div>
+[html][undefined]
+</div>
+"#;
+
+        assert_no_violations(MD052::new(), content);
+    }
+
+    #[test]
+    fn test_definition_in_code_block_does_not_define() {
+        let content = r#"See [link][example].
+
+```
+[example]: https://example.com
+```
+"#;
+
+        let violation = assert_single_violation(MD052::new(), content);
+        assert!(violation.message.contains("example"));
+    }
+
     #[test]
     fn test_nested_brackets() {
         let content = r#"[Link with [nested] text][label]
@@ -1554,6 +1481,13 @@ The letter *o* can be [oː] or [ɔ].
         assert!(violation.message.contains("missing"));
     }
 
+    #[test]
+    fn test_frontmatter_flow_lists_ignored() {
+        let content =
+            "---\ntags: [rust, mdbook]\nmdbook-lint:\n  disable: [MD013]\n---\n\n# Title\n";
+        assert_no_violations(MD052::new(), content);
+    }
+
     #[test]
     fn test_reference_definition_with_code_in_blockquote() {
         // Reference with backticks in label inside blockquote (from discovery-mb2)
@@ -3,6 +3,9 @@
 //! This rule checks for unused or duplicated reference definitions.
 //! Note: This is a simplified implementation that works with basic patterns.
 //!
+//! With `consolidate_links` enabled, it also reports inline links that share
+//! a destination, so they can use one reference definition instead.
+//!
 //! ## Correct
 //!
 //! ```markdown
@@ -21,12 +24,12 @@
 //! [label]: https://duplicate.com
 //! ```
 
-use comrak::nodes::AstNode;
+use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
     Document, Violation,
-    rule::{Rule, RuleCategory, RuleMetadata},
-    violation::Severity,
+    rule::{AstRule, OptionKind, RuleCategory, RuleMetadata},
+    violation::{Fix, FixSafety, Position, Severity},
 };
 
 use std::collections::{HashMap, HashSet};
@@ -34,6 +37,35 @@ use std::collections::{HashMap, HashSet};
 /// MD053 - Link and image reference definitions should be needed
 pub struct MD053 {
     ignored_definitions: Vec<String>,
+    /// Report inline links that repeat a destination and fix them to share
+    /// a reference definition
+    consolidate_links: bool,
+}
+
+/// A reference definition, which may continue onto following lines
+struct Definition {
+    /// Label as written
+    label: String,
+    line: usize,
+    column: usize,
+    /// Last line of the definition, which differs from `line` when the
+    /// destination or title is on a line of its own
+    end_line: usize,
+    /// Destination and optional title, with whitespace collapsed
+    destination: String,
+}
+
+/// An inline link `[text](destination)` on a single line
+struct InlineLink {
+    url: String,
+    line: usize,
+    /// Char columns of the `]` closing the text and just past the final `)`
+    text_end: usize,
+    end: usize,
+    /// Link text, used to name a new reference definition
+    text: String,
+    /// Destination and optional title, with whitespace collapsed
+    destination: String,
 }
 
 impl Default for MD053 {
@@ -47,6 +79,7 @@ impl MD053 {
     pub fn new() -> Self {
         Self {
             ignored_definitions: vec!["//".to_string()], // Default ignores comment syntax
+            consolidate_links: false,
         }
     }
 
@@ -57,6 +90,13 @@ impl MD053 {
         self
     }
 
+    /// Set whether inline links that repeat a destination are reported
+    #[allow(dead_code)]
+    pub fn consolidate_links(mut self, consolidate: bool) -> Self {
+        self.consolidate_links = consolidate;
+        self
+    }
+
     /// Create MD053 from configuration
     pub fn from_config(config: &toml::Value) -> Self {
         let mut rule = Self::new();
@@ -71,20 +111,66 @@ impl MD053 {
                 .collect();
         }
 
+        if let Some(consolidate) = config.get("consolidate_links").and_then(|v| v.as_bool()) {
+            rule.consolidate_links = consolidate;
+        }
+
         rule
     }
 
-    /// Parse reference definitions from document content
-    fn collect_definitions(&self, document: &Document) -> Vec<(String, usize, usize)> {
-        let mut definitions = Vec::new();
+    /// Parse reference definitions from document content, outside code blocks
+    fn collect_definitions(
+        &self,
+        document: &Document,
+        code_lines: &HashSet<usize>,
+    ) -> Vec<Definition> {
+        let mut definitions: Vec<Definition> = Vec::new();
+        let lines = &document.lines;
 
-        for (line_num, line) in document.content.lines().enumerate() {
+        for (line_num, line) in lines.iter().enumerate() {
             let line_number = line_num + 1;
+            if code_lines.contains(&line_number)
+                || document.is_frontmatter_line(line_number)
+                || definitions
+                    .last()
+                    .is_some_and(|previous| previous.end_line >= line_number)
+            {
+                continue;
+            }
 
             // Check if line starts with [label]: (reference definition)
-            if let Some((label, column)) = self.parse_reference_definition(line) {
-                definitions.push((label.to_lowercase(), line_number, column));
+            // Footnote definitions ([^note]: text) are not link references
+            let Some((label, column)) = self
+                .parse_reference_definition(line)
+                .filter(|(label, _)| !label.starts_with('^'))
+            else {
+                continue;
+            };
+            let rest = line.find("]:").map_or("", |colon| line[colon + 2..].trim());
+
+            // The destination and the title may each sit on the next line
+            let mut end_line = line_number;
+            let mut destination = rest.to_string();
+            if destination.is_empty()
+                && let Some(next) = lines.get(end_line).filter(|next| !next.trim().is_empty())
+            {
+                destination = next.trim().to_string();
+                end_line += 1;
+            }
+            if !destination.contains(char::is_whitespace)
+                && let Some(next) = lines.get(end_line).filter(|next| is_title(next.trim()))
+            {
+                destination = format!("{destination} {}", next.trim());
+                end_line += 1;
             }
+
+            definitions.push(Definition {
+                label,
+                line: line_number,
+                column,
+                end_line,
+                destination: collapse_whitespace(&destination),
+            });
         }
 
         definitions
@@ -159,7 +245,7 @@ impl MD053 {
                     '[' if !in_backticks => {
                         // Try to parse reference link
                         if let Some(label) = self.parse_reference_usage(&line[i..]) {
-                            used_labels.insert(label.to_lowercase());
+                            used_labels.insert(normalize_label(&label));
 
                             // Skip past the parsed reference
                             while let Some((_, next_ch)) = chars.peek() {
@@ -178,7 +264,7 @@ impl MD053 {
                         // 2. The ][label] pattern is very specific and unlikely to appear in code
                         // 3. Backtick state may be incorrect due to line-by-line processing
                         if let Some(label) = self.parse_continuation_reference(&line[i..]) {
-                            used_labels.insert(label.to_lowercase());
+                            used_labels.insert(normalize_label(&label));
                         }
                     }
                     _ => {}
@@ -280,42 +366,219 @@ impl MD053 {
     }
 
     /// Check for unused and duplicate definitions
+    ///
+    /// Unused definitions, and duplicates with the same destination as the
+    /// first definition, get a fix that deletes them. When every definition
+    /// in a block goes, all of them share one fix that deletes the block and
+    /// a blank line next to it, so no run of blank lines is left behind.
     fn check_definitions(
         &self,
-        definitions: Vec<(String, usize, usize)>,
+        document: &Document,
+        definitions: &[Definition],
         used_labels: &HashSet<String>,
     ) -> Vec<Violation> {
-        let mut violations = Vec::new();
-        let mut seen_labels: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut seen_labels: HashMap<String, &Definition> = HashMap::new();
+        // Message and whether the definition can be deleted, per definition
+        let mut findings: Vec<Option<(String, bool)>> = Vec::new();
+
+        for definition in definitions {
+            let label = normalize_label(&definition.label);
 
-        for (label, line, column) in definitions {
             // Skip if label is in ignored list
             if self.ignored_definitions.contains(&label) {
+                findings.push(None);
                 continue;
             }
 
             // Check for duplicates
-            if let Some((first_line, _first_column)) = seen_labels.get(&label) {
-                violations.push(self.create_violation(
+            if let Some(first) = seen_labels.get(&label) {
+                findings.push(Some((
                     format!(
-                        "Reference definition '{label}' is duplicated (first defined at line {first_line})"
+                        "Reference definition '{label}' is duplicated (first defined at line {})",
+                        first.line
                     ),
-                    line,
-                    column,
-                    Severity::Warning,
-                ));
+                    first.destination == definition.destination,
+                )));
             } else {
-                seen_labels.insert(label.clone(), (line, column));
+                seen_labels.insert(label.clone(), definition);
 
                 // Check if unused
-                if !used_labels.contains(&label) {
-                    violations.push(self.create_violation(
+                if used_labels.contains(&label) {
+                    findings.push(None);
+                } else {
+                    findings.push(Some((
                         format!("Reference definition '{label}' is unused"),
-                        line,
-                        column,
+                        true,
+                    )));
+                }
+            }
+        }
+
+        let mut violations = Vec::new();
+        let mut block_start = 0;
+        for idx in 0..definitions.len() {
+            let block_continues = definitions
+                .get(idx + 1)
+                .is_some_and(|next| next.line == definitions[idx].end_line + 1);
+            if block_continues {
+                continue;
+            }
+
+            let block = block_start..idx + 1;
+            block_start = idx + 1;
+            let whole_block = findings[block.clone()]
+                .iter()
+                .all(|finding| finding.as_ref().is_some_and(|(_, removable)| *removable));
+
+            for (definition, finding) in definitions[block.clone()]
+                .iter()
+                .zip(&findings[block.clone()])
+            {
+                let Some((message, removable)) = finding else {
+                    continue;
+                };
+
+                let fix = if whole_block {
+                    let first = definitions[block.start].line;
+                    let last = definitions[block.end - 1].end_line;
+                    Some(delete_block(document, first, last))
+                } else if *removable {
+                    Some(delete_lines(
+                        document,
+                        definition.line,
+                        definition.end_line,
+                        "Remove reference definition",
+                    ))
+                } else {
+                    None
+                };
+
+                violations.push(match fix {
+                    Some(fix) => self.create_violation_with_fix(
+                        message.clone(),
+                        definition.line,
+                        definition.column,
+                        Severity::Warning,
+                        fix,
+                    ),
+                    None => self.create_violation(
+                        message.clone(),
+                        definition.line,
+                        definition.column,
+                        Severity::Warning,
+                    ),
+                });
+            }
+        }
+
+        violations
+    }
+
+    /// Report inline links that could share a reference definition
+    ///
+    /// A link whose destination already has a definition is fixed to use it.
+    /// When several links repeat a destination that has none, the first of
+    /// them gets a fix that adds definitions for all such destinations at the
+    /// end of the file, and the links are rewritten on the next fix pass.
+    /// Links are only rewritten once their definition exists, so a skipped
+    /// fix never leaves a reference without a definition.
+    fn check_inline_links(
+        &self,
+        document: &Document,
+        links: &[InlineLink],
+        definitions: &[Definition],
+    ) -> Vec<Violation> {
+        let mut existing: HashMap<&str, &str> = HashMap::new();
+        for definition in definitions {
+            existing
+                .entry(definition.destination.as_str())
+                .or_insert(definition.label.as_str());
+        }
+
+        let mut groups: Vec<(&str, Vec<&InlineLink>)> = Vec::new();
+        for link in links {
+            match groups
+                .iter_mut()
+                .find(|(destination, _)| *destination == link.destination)
+            {
+                Some((_, group)) => group.push(link),
+                None => groups.push((&link.destination, vec![link])),
+            }
+        }
+
+        // Name the definitions to add, avoiding labels already in use
+        let mut taken: HashSet<String> = definitions
+            .iter()
+            .map(|definition| normalize_label(&definition.label))
+            .collect();
+        let mut new_definitions = Vec::new();
+        for (destination, group) in &groups {
+            if group.len() < 2 || existing.contains_key(destination) {
+                continue;
+            }
+            let base = slug(&group[0].text);
+            let mut label = base.clone();
+            let mut suffix = 1;
+            while taken.contains(&label) {
+                suffix += 1;
+                label = format!("{base}-{suffix}");
+            }
+            taken.insert(label.clone());
+            new_definitions.push(format!("[{label}]: {destination}"));
+        }
+        let add_definitions = (!new_definitions.is_empty())
+            .then(|| append_definitions(document, definitions, &new_definitions));
+
+        let mut violations = Vec::new();
+        for (destination, group) in &groups {
+            if let Some(label) = existing.get(destination) {
+                for link in group {
+                    violations.push(self.create_violation_with_fix(
+                        format!(
+                            "Inline link to '{}' can use reference definition '{label}'",
+                            link.url
+                        ),
+                        link.line,
+                        link.text_end,
                         Severity::Warning,
+                        Fix {
+                            description: format!("Use reference definition '{label}'"),
+                            replacement: Some(format!("][{label}]")),
+                            start: Position {
+                                line: link.line,
+                                column: link.text_end,
+                            },
+                            end: Position {
+                                line: link.line,
+                                column: link.end,
+                            },
+                            safety: FixSafety::Safe,
+                        },
                     ));
                 }
+            } else if group.len() > 1 {
+                for (idx, link) in group.iter().enumerate() {
+                    let message = format!(
+                        "Inline link to '{}' is repeated {} times; use a shared reference definition",
+                        link.url,
+                        group.len()
+                    );
+                    violations.push(match add_definitions.clone().filter(|_| idx == 0) {
+                        Some(fix) => self.create_violation_with_fix(
+                            message,
+                            link.line,
+                            link.text_end,
+                            Severity::Warning,
+                            fix,
+                        ),
+                        None => self.create_violation(
+                            message,
+                            link.line,
+                            link.text_end,
+                            Severity::Warning,
+                        ),
+                    });
+                }
             }
         }
 
@@ -323,7 +586,7 @@ impl MD053 {
     }
 }
 
-impl Rule for MD053 {
+impl AstRule for MD053 {
     fn id(&self) -> &'static str {
         "MD053"
     }
@@ -338,25 +601,254 @@ impl Rule for MD053 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Links)
+            .option(
+                "ignored_definitions",
+                OptionKind::StringList,
+                "Definitions that may go unused",
+            )
+            .defaults_to(r#"["//"]"#)
+            .option(
+                "consolidate_links",
+                OptionKind::Boolean,
+                "Report inline links that repeat a destination",
+            )
+            .defaults_to("false")
     }
 
-    fn check_with_ast<'a>(
-        &self,
-        document: &Document,
-        _ast: Option<&'a AstNode<'a>>,
-    ) -> Result<Vec<Violation>> {
-        // This rule works entirely with document content, not AST
-        let definitions = self.collect_definitions(document);
-        let used_labels = self.collect_used_labels(document);
-        let violations = self.check_definitions(definitions, &used_labels);
+    fn can_fix(&self) -> bool {
+        true
+    }
+
+    fn check_ast<'a>(&self, document: &Document, ast: &'a AstNode<'a>) -> Result<Vec<Violation>> {
+        let offset = document.frontmatter_ast_offset(ast);
+        let code_lines: HashSet<usize> = ast
+            .descendants()
+            .filter_map(|node| {
+                let data = node.data.borrow();
+                matches!(data.value, NodeValue::CodeBlock(_))
+                    .then(|| data.sourcepos.start.line + offset..=data.sourcepos.end.line + offset)
+            })
+            .flatten()
+            .collect();
+
+        let definitions = self.collect_definitions(document, &code_lines);
+        let mut used_labels = self.collect_used_labels(document);
+        let mut link_violations = Vec::new();
+        if self.consolidate_links {
+            let links = inline_links(document, ast);
+            // Definitions that links are about to be fixed to use are not unused
+            for link in &links {
+                if let Some(definition) = definitions
+                    .iter()
+                    .find(|definition| definition.destination == link.destination)
+                {
+                    used_labels.insert(normalize_label(&definition.label));
+                }
+            }
+            link_violations = self.check_inline_links(document, &links, &definitions);
+        }
+
+        let mut violations = self.check_definitions(document, &definitions, &used_labels);
+        violations.extend(link_violations);
 
         Ok(violations)
     }
 }
 
+/// Lowercase a label and collapse its whitespace, as CommonMark matches labels
+fn normalize_label(label: &str) -> String {
+    collapse_whitespace(label).to_lowercase()
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Whether a line holds only a link title: `"title"`, `'title'`, or `(title)`
+fn is_title(text: &str) -> bool {
+    [('"', '"'), ('\'', '\''), ('(', ')')]
+        .iter()
+        .any(|&(open, close)| text.len() >= 2 && text.starts_with(open) && text.ends_with(close))
+}
+
+/// Label for a new definition, made from the link text
+fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for ch in text.chars() {
+        if ch.is_alphanumeric() {
+            slug.extend(ch.to_lowercase());
+        } else if (ch.is_whitespace() || ch == '-' || ch == '_') && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        "link".to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+/// Fix that deletes lines `first..=last` (1-based) and their line break
+fn delete_lines(document: &Document, first: usize, last: usize, description: &str) -> Fix {
+    let lines = &document.lines;
+    let line_end = |line: usize| Position {
+        line,
+        column: lines[line - 1].chars().count() + 1,
+    };
+    let (start, end) = if last < lines.len() {
+        (
+            Position {
+                line: first,
+                column: 1,
+            },
+            Position {
+                line: last + 1,
+                column: 1,
+            },
+        )
+    } else if first > 1 {
+        // The last line has no line break after it, so take the one before
+        (line_end(first - 1), line_end(last))
+    } else {
+        (Position { line: 1, column: 1 }, line_end(last))
+    };
+
+    Fix {
+        description: description.to_string(),
+        replacement: Some(String::new()),
+        start,
+        end,
+        safety: FixSafety::Safe,
+    }
+}
+
+/// Fix that deletes a whole block of definitions along with the blank line
+/// after it, or before it when the block ends the file
+fn delete_block(document: &Document, first: usize, last: usize) -> Fix {
+    let lines = &document.lines;
+    let blank = |line: usize| {
+        lines
+            .get(line - 1)
+            .is_some_and(|text| text.trim().is_empty())
+    };
+    let (first, last) = if blank(last + 1) {
+        (first, last + 1)
+    } else if last == lines.len() && first > 1 && blank(first - 1) {
+        (first - 1, last)
+    } else {
+        (first, last)
+    };
+
+    delete_lines(document, first, last, "Remove reference definitions")
+}
+
+/// Fix that adds reference definitions at the end of the file, after any
+/// definitions already there
+fn append_definitions(document: &Document, definitions: &[Definition], new: &[String]) -> Fix {
+    let lines = &document.lines;
+    let last = lines.len().max(1);
+    let last_text = lines.get(last - 1).map_or("", String::as_str);
+    let after_definition = definitions.last().is_some_and(|d| d.end_line == last);
+    let separator = if after_definition || last_text.trim().is_empty() {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    let end = Position {
+        line: last,
+        column: last_text.chars().count() + 1,
+    };
+
+    Fix {
+        description: "Add shared reference definitions".to_string(),
+        replacement: Some(format!("{separator}{}", new.join("\n"))),
+        start: end,
+        end,
+        safety: FixSafety::Safe,
+    }
+}
+
+/// Inline links on a single line, in document order
+fn inline_links<'a>(document: &Document, ast: &'a AstNode<'a>) -> Vec<InlineLink> {
+    let offset = document.frontmatter_ast_offset(ast);
+
+    ast.descendants()
+        .filter_map(|node| {
+            let data = node.data.borrow();
+            let NodeValue::Link(link) = &data.value else {
+                return None;
+            };
+            let (start, end) = (data.sourcepos.start, data.sourcepos.end);
+            if start.line != end.line {
+                return None;
+            }
+
+            let line = start.line + offset;
+            let text = document.lines.get(line - 1)?;
+            let open = start.column.checked_sub(1)?;
+            let close = end.column;
+            let source = text.get(open..close)?;
+            if !source.starts_with('[') || !source.ends_with(')') {
+                return None;
+            }
+            let text_end = link_text_end(source)?;
+            let destination = collapse_whitespace(&source[text_end + 2..source.len() - 1]);
+            if destination.is_empty() {
+                return None;
+            }
+
+            Some(InlineLink {
+                url: link.url.clone(),
+                line,
+                text_end: text[..open + text_end].chars().count() + 1,
+                end: text[..close].chars().count() + 1,
+                text: source[1..text_end].to_string(),
+                destination,
+            })
+        })
+        .collect()
+}
+
+/// Byte index of the `]` closing a link's text, when `(` follows it
+fn link_text_end(source: &str) -> Option<usize> {
+    let bytes = source.as_bytes();
+    let mut depth = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' => idx += 1,
+            b'`' => {
+                // Skip the code span, which closes with a run of the same length
+                let run = bytes[idx..].iter().take_while(|&&b| b == b'`').count();
+                let fence = &source[idx..idx + run];
+                idx += run;
+                match source[idx..].find(fence) {
+                    Some(end) => idx += end + run,
+                    None => continue,
+                }
+                continue;
+            }
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return (bytes.get(idx + 1) == Some(&b'(')).then_some(idx);
+                }
+            }
+            _ => {}
+        }
+        idx += 1;
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use mdbook_lint_core::rule::Rule;
     use mdbook_lint_core::test_helpers::*;
 
     #[test]
@@ -532,16 +1024,100 @@ for more info.
 [^siphash]: https://en.wikipedia.org/wiki/SipHash
 "#;
 
-        // This should have no violations - footnotes are not link references
-        // But our current implementation may not handle this correctly
+        // Footnote definitions are not link references, and must never be
+        // removed as unused ones
+        assert_no_violations(MD053::new(), content);
+    }
+
+    fn fix(rule: &MD053, content: &str) -> String {
+        mdbook_lint_core::FixEngine::new()
+            .fix_until_stable(content, |text| {
+                let document = create_document(text);
+                rule.check(&document)
+            })
+            .unwrap()
+            .content
+    }
+
+    #[test]
+    fn test_fix_removes_unused_definitions() {
         let rule = MD053::new();
-        let document =
-            Document::new(content.to_string(), std::path::PathBuf::from("test.md")).unwrap();
+        assert_eq!(
+            fix(
+                &rule,
+                "[Link][used]\n\n[used]: https://a.com\n[unused]: https://b.com\n"
+            ),
+            "[Link][used]\n\n[used]: https://a.com\n"
+        );
+        // A block that goes entirely takes a blank line with it
+        assert_eq!(
+            fix(
+                &rule,
+                "Text\n\n[a]: https://a.com\n[b]:\n  https://b.com\n  \"Title\"\n"
+            ),
+            "Text\n"
+        );
+        assert_eq!(
+            fix(&rule, "Text\n\n[a]: https://a.com\n\nMore [text].\n"),
+            "Text\n\nMore [text].\n"
+        );
+    }
+
+    #[test]
+    fn test_fix_removes_only_identical_duplicates() {
+        let content = "[a][] [b][]\n\n[a]: https://a.com\n[b]: https://b.com\n[A]: https://a.com\n[B]: https://other.com\n";
+        let document = create_document(content);
+        let violations = MD053::new().check(&document).unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].fix.is_some());
+        assert!(violations[1].fix.is_none());
+        assert_eq!(
+            fix(&MD053::new(), content),
+            "[a][] [b][]\n\n[a]: https://a.com\n[b]: https://b.com\n[B]: https://other.com\n"
+        );
+    }
+
+    #[test]
+    fn test_definitions_in_code_blocks_ignored() {
+        let content = "```markdown\n[example]: https://example.com\n```\n\n    [indented]: https://example.com\n";
+        assert_no_violations(MD053::new(), content);
+    }
+
+    #[test]
+    fn test_consolidate_links_disabled_by_default() {
+        let content = "[One](https://a.com) and [two](https://a.com)\n";
+        assert_no_violations(MD053::new(), content);
+    }
+
+    #[test]
+    fn test_fix_consolidates_repeated_links() {
+        let config: toml::Value = toml::from_str("consolidate_links = true").unwrap();
+        let rule = MD053::from_config(&config);
+        let content = "See [the `docs`](https://d.com).\n\nAlso [docs](https://d.com) and [other](https://o.com).\n\n[the-docs]: https://unrelated.com\n\nUse [the-docs][].\n";
+
+        let document = create_document(content);
         let violations = rule.check(&document).unwrap();
+        assert_eq!(violations.len(), 2);
+        assert!(violations[0].message.contains("repeated 2 times"));
+        assert!(violations[0].fix.is_some());
+        assert!(violations[1].fix.is_none());
+
+        assert_eq!(
+            fix(&rule, content),
+            "See [the `docs`][the-docs-2].\n\nAlso [docs][the-docs-2] and [other](https://o.com).\n\n[the-docs]: https://unrelated.com\n\nUse [the-docs][].\n\n[the-docs-2]: https://d.com\n"
+        );
+    }
 
-        // If we're reporting it as unused, that's a false positive for footnotes
-        // For now, document the current behavior
-        // TODO: Consider excluding footnote-style references (starting with ^)
-        println!("Footnote test violations: {:?}", violations.len());
+    #[test]
+    fn test_fix_uses_existing_definition() {
+        let rule = MD053::new().consolidate_links(true);
+        assert_eq!(
+            fix(
+                &rule,
+                "Read [this](https://e.com \"Title\").\n\n[Example]: https://e.com  \"Title\"\n"
+            ),
+            "Read [this][Example].\n\n[Example]: https://e.com  \"Title\"\n"
+        );
     }
 }
@@ -23,7 +23,7 @@ use comrak::nodes::AstNode;
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
     Document, Violation,
-    rule::{Rule, RuleCategory, RuleMetadata},
+    rule::{OptionKind, Rule, RuleCategory, RuleMetadata},
     violation::Severity,
 };
 
@@ -284,6 +284,18 @@ impl Rule for MD054 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Links)
+            .option("autolink", OptionKind::Boolean, "Allow autolinks")
+            .defaults_to("true")
+            .option("inline", OptionKind::Boolean, "Allow inline links")
+            .defaults_to("true")
+            .option("reference", OptionKind::Boolean, "Allow reference links")
+            .defaults_to("true")
+            .option(
+                "url_inline",
+                OptionKind::Boolean,
+                "Allow inline links whose text is the URL",
+            )
+            .defaults_to("true")
     }
 
     fn check_with_ast<'a>(
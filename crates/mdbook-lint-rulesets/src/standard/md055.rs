@@ -3,10 +3,10 @@
 //! This rule checks that table pipes are used consistently throughout the document.
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check table pipe style consistency
@@ -259,6 +259,7 @@ impl MD055 {
                             line: line_number,
                             column: line.len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
@@ -303,6 +304,7 @@ impl MD055 {
                     line: line_number,
                     column: line.len() + 1,
                 },
+                safety: FixSafety::Safe,
             };
 
             violations.push(self.create_violation_with_fix(
@@ -316,30 +318,6 @@ impl MD055 {
 
         (violations, detected_style)
     }
-
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-                continue;
-            }
-        }
-
-        in_code_block
-    }
 }
 
 impl Default for MD055 {
@@ -362,7 +340,18 @@ impl Rule for MD055 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.1.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.1.0")
+            .option(
+                "style",
+                OptionKind::Choice(&[
+                    "consistent",
+                    "leading_and_trailing",
+                    "no_leading_or_trailing",
+                ]),
+                "Table pipe style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -376,7 +365,7 @@ impl Rule for MD055 {
     ) -> Result<Vec<Violation>> {
         let mut violations = Vec::new();
         let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
+        let in_code_block = document.index().code_block_lines();
 
         // Find all table blocks first
         let table_blocks = self.find_table_blocks(&lines);
@@ -27,7 +27,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
     Document, Violation,
     rule::{Rule, RuleCategory, RuleMetadata},
-    violation::{Fix, Position, Severity},
+    violation::{Fix, FixSafety, Position, Severity},
 };
 
 /// MD056 - Table column count
@@ -153,6 +153,7 @@ impl MD056 {
                         line: *line,
                         column: *column,
                     },
+                    safety: FixSafety::Safe,
                 };
 
                 violations.push(self.create_violation_with_fix(
@@ -250,6 +251,7 @@ impl MD056 {
                             line: line_num + 1,
                             column: line.len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
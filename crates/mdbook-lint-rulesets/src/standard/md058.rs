@@ -7,7 +7,7 @@ use mdbook_lint_core::error::Result;
 use mdbook_lint_core::rule::{AstRule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
-    violation::{Fix, Position, Severity, Violation},
+    violation::{Fix, FixSafety, Position, Severity, Violation},
 };
 
 /// Rule to check that tables are surrounded by blank lines
@@ -85,6 +85,7 @@ impl MD058 {
                             line: start_line - 1,
                             column: document.lines[start_line - 2].len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
@@ -112,6 +113,7 @@ impl MD058 {
                             line: end_line,
                             column: document.lines[end_line - 1].len() + 1,
                         },
+                        safety: FixSafety::Safe,
                     };
 
                     violations.push(self.create_violation_with_fix(
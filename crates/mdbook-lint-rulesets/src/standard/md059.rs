@@ -23,7 +23,7 @@ use comrak::nodes::{AstNode, NodeValue};
 use mdbook_lint_core::error::Result;
 use mdbook_lint_core::{
     Document, Violation,
-    rule::{Rule, RuleCategory, RuleMetadata},
+    rule::{OptionKind, Rule, RuleCategory, RuleMetadata},
     violation::Severity,
 };
 
@@ -317,6 +317,12 @@ impl Rule for MD059 {
 
     fn metadata(&self) -> RuleMetadata {
         RuleMetadata::stable(RuleCategory::Accessibility)
+            .option(
+                "prohibited_texts",
+                OptionKind::StringList,
+                "Link texts to report",
+            )
+            .defaults_to(r#"["click here", "here", "link", "more"]"#)
     }
 
     fn check_with_ast<'a>(
@@ -8,7 +8,7 @@
 //! - `tight` - single-space padding around content
 
 use mdbook_lint_core::error::Result;
-use mdbook_lint_core::rule::{Rule, RuleCategory, RuleMetadata};
+use mdbook_lint_core::rule::{OptionKind, Rule, RuleCategory, RuleMetadata};
 use mdbook_lint_core::{
     Document,
     violation::{Severity, Violation},
@@ -67,29 +67,6 @@ impl MD060 {
         rule
     }
 
-    /// Get code block ranges to exclude from checking
-    fn get_code_block_ranges(&self, lines: &[&str]) -> Vec<bool> {
-        let mut in_code_block = vec![false; lines.len()];
-        let mut in_fenced_block = false;
-
-        for (i, line) in lines.iter().enumerate() {
-            let trimmed = line.trim();
-
-            // Check for fenced code blocks
-            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
-                in_fenced_block = !in_fenced_block;
-                in_code_block[i] = true;
-                continue;
-            }
-
-            if in_fenced_block {
-                in_code_block[i] = true;
-            }
-        }
-
-        in_code_block
-    }
-
     /// Check if a line is a table separator (like |---|---|)
     fn is_table_separator(&self, line: &str) -> bool {
         let trimmed = line.trim();
@@ -338,7 +315,14 @@ impl Rule for MD060 {
     }
 
     fn metadata(&self) -> RuleMetadata {
-        RuleMetadata::stable(RuleCategory::Formatting).introduced_in("mdbook-lint v0.5.0")
+        RuleMetadata::stable(RuleCategory::Formatting)
+            .introduced_in("mdbook-lint v0.5.0")
+            .option(
+                "style",
+                OptionKind::Choice(&["consistent", "aligned", "compact", "tight", "any"]),
+                "Table column style",
+            )
+            .defaults_to(r#""consistent""#)
     }
 
     fn can_fix(&self) -> bool {
@@ -357,10 +341,10 @@ impl Rule for MD060 {
 
         let mut violations = Vec::new();
         let lines: Vec<&str> = document.content.lines().collect();
-        let in_code_block = self.get_code_block_ranges(&lines);
+        let in_code_block = document.index().code_block_lines();
 
         // Find all table blocks
-        let table_blocks = self.find_table_blocks(&lines, &in_code_block);
+        let table_blocks = self.find_table_blocks(&lines, in_code_block);
 
         let mut document_style: Option<ColumnStyle> = match self.style {
             ColumnStyle::Aligned => Some(ColumnStyle::Aligned),
@@ -65,6 +65,13 @@ pub mod md058;
 pub mod md059;
 pub mod md060;
 
+// Shared by the MD022, MD031, and MD032 checks and fixes
+mod blank_lines;
+// Shared by the MD049 and MD050 checks and fixes
+mod emphasis;
+// Shared by the MD005 and MD007 fixes
+mod list_indent;
+
 use mdbook_lint_core::{Config, RuleProvider, RuleRegistry};
 
 /// Provider for standard markdown rules (MD001-MD060)
@@ -116,7 +123,7 @@ impl RuleProvider for StandardRuleProvider {
         registry.register(Box::new(md031::MD031));
         registry.register(Box::new(md032::MD032));
         registry.register(Box::new(md033::MD033));
-        registry.register(Box::new(md034::MD034));
+        registry.register(Box::new(md034::MD034::default()));
         registry.register(Box::new(md035::MD035::default()));
         registry.register(Box::new(md036::MD036::default()));
         registry.register(Box::new(md037::MD037));
@@ -143,6 +150,7 @@ impl RuleProvider for StandardRuleProvider {
         registry.register(Box::new(md058::MD058));
         registry.register(Box::new(md059::MD059::default()));
         registry.register(Box::new(md060::MD060::default()));
+        register_placeholders(registry);
     }
 
     fn rule_ids(&self) -> Vec<&'static str> {
@@ -286,7 +294,14 @@ impl RuleProvider for StandardRuleProvider {
         registry.register(Box::new(md031::MD031));
         registry.register(Box::new(md032::MD032));
         registry.register(Box::new(md033::MD033));
-        registry.register(Box::new(md034::MD034));
+
+        // MD034 - bare URLs
+        let md034 = if let Some(cfg) = config.and_then(|c| c.rule_configs.get("MD034")) {
+            md034::MD034::from_config(cfg)
+        } else {
+            md034::MD034::default()
+        };
+        registry.register(Box::new(md034));
 
         // MD035 - horizontal rule style
         let md035 = if let Some(cfg) = config.and_then(|c| c.rule_configs.get("MD035")) {
@@ -421,5 +436,15 @@ impl RuleProvider for StandardRuleProvider {
             md060::MD060::default()
         };
         registry.register(Box::new(md060));
+        register_placeholders(registry);
     }
 }
+
+/// Rule numbers that never run, kept so configs naming them get a notice
+fn register_placeholders(registry: &mut RuleRegistry) {
+    registry.register_placeholder(Box::new(md008::MD008));
+    registry.register_placeholder(Box::new(md015::MD015));
+    registry.register_placeholder(Box::new(md016::MD016));
+    registry.register_placeholder(Box::new(md017::MD017));
+    registry.register_placeholder(Box::new(md057::MD057));
+}
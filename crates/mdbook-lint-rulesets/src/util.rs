@@ -0,0 +1,69 @@
+//! Helpers shared by rule families that are built under different features
+
+/// Read a list of strings from a rule configuration key
+///
+/// The first of `keys` present in `config` is read, so a rule can accept
+/// both the kebab-case and snake_case spelling of an option.
+#[cfg(any(feature = "frontmatter", feature = "rfc", feature = "changelog"))]
+pub(crate) fn string_list(config: &toml::Value, keys: &[&str]) -> Option<Vec<String>> {
+    keys.iter()
+        .find_map(|key| config.get(*key))
+        .and_then(|v| v.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| item.as_str().map(String::from))
+                .collect()
+        })
+}
+
+/// Check whether `value` is a calendar date in `YYYY-MM-DD` form
+#[cfg(any(feature = "frontmatter", feature = "changelog", feature = "adr"))]
+pub(crate) fn is_iso_date(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() != 10 || bytes[4] != b'-' || bytes[7] != b'-' {
+        return false;
+    }
+
+    let number = |range: std::ops::Range<usize>| {
+        value
+            .get(range)
+            .filter(|s| s.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|s| s.parse::<u32>().ok())
+    };
+    let (Some(year), Some(month), Some(day)) = (number(0..4), number(5..7), number(8..10)) else {
+        return false;
+    };
+
+    let leap = (year % 4 == 0 && year % 100 != 0) || year % 400 == 0;
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if leap => 29,
+        2 => 28,
+        _ => return false,
+    };
+    (1..=days_in_month).contains(&day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_iso_date() {
+        assert!(is_iso_date("2024-01-15"));
+        assert!(is_iso_date("1999-12-31"));
+        assert!(is_iso_date("2024-02-29"));
+        assert!(!is_iso_date("2023-02-29"));
+        assert!(!is_iso_date("2024-13-01"));
+        assert!(!is_iso_date("2024-00-01"));
+        assert!(!is_iso_date("2024-01-32"));
+        assert!(!is_iso_date("2024-01-00"));
+        assert!(!is_iso_date("01-15-2024"));
+        assert!(!is_iso_date("2024/01/15"));
+        assert!(!is_iso_date("2024-1-5"));
+        assert!(!is_iso_date("15/01/2024"));
+        assert!(!is_iso_date("January 15, 2024"));
+    }
+}